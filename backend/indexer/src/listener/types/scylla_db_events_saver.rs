@@ -4,9 +4,11 @@ use crate::programs::{LaunchpoolProgram, LiquidityPoolProgram};
 use anyhow::Result as AnyResult;
 use async_trait::async_trait;
 use launchpool::types::{LaunchpoolStatus, PositionStatus};
+use liquidity_pool::types::SyncCpAmmEvent;
 use scylla::client::session::Session;
 use scylla::statement::batch::{Batch, BatchType};
 use scylla::value::CqlTimeuuid;
+use solana_sdk::pubkey::Pubkey;
 use std::marker::PhantomData;
 use tracing::debug;
 use utilities::math::U192;
@@ -33,12 +35,69 @@ impl<T: AnchorProgram + 'static> ScyllaDbEventsSaver<T> {
     }
 }
 
+impl ScyllaDbEventsSaver<LiquidityPoolProgram> {
+    async fn save_reconcile_cp_amm_event(
+        &self,
+        signature: &String,
+        event: &SyncCpAmmEvent,
+        destination_accounts: Option<(Pubkey, Pubkey)>,
+    ) -> AnyResult<()> {
+        let mut batch = Batch::new(BatchType::Unlogged);
+
+        batch.append_statement(
+            "INSERT INTO reconcile_cp_amm_events \
+            (signature, timestamp, event_id, cp_amm, signer, is_skim, destination_base_account, destination_quote_account, base_excess, quote_excess) \
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        );
+
+        batch.append_statement(
+            "INSERT INTO cp_amms_liquidity \
+            (cp_amm, liquidity)\
+            VALUES (?, ?)",
+        );
+
+        let cp_amm = event.cp_amm.to_string();
+        let base_excess: [u8; 8] = event.base_excess.to_be_bytes();
+        let quote_excess: [u8; 8] = event.quote_excess.to_be_bytes();
+        let liquidity = U192(event.constant_product_sqrt.value).to_little_endian();
+        // Syncs fold the excess into the liquidity, so they have no destination accounts
+        let (destination_base_account, destination_quote_account) = destination_accounts
+            .map(|(base_account, quote_account)| {
+                (
+                    Some(base_account.to_string()),
+                    Some(quote_account.to_string()),
+                )
+            })
+            .unwrap_or_default();
+
+        let values = (
+            (
+                signature,
+                event.timestamp,
+                Self::get_uuid(event.timestamp as u64),
+                &cp_amm,
+                event.signer.to_string(),
+                destination_accounts.is_some(),
+                destination_base_account,
+                destination_quote_account,
+                base_excess.as_slice(),
+                quote_excess.as_slice(),
+            ),
+            (&cp_amm, liquidity.as_slice()),
+        );
+        self.scylla_session.batch(&batch, values).await?;
+        debug!("Saving ReconcileCpAmmEvent from signature {}", signature);
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProgram> {
     async fn save_event(&self, signature: &String, event: LiquidityPoolProgram) -> AnyResult<()> {
         let scylla_session = &self.scylla_session;
         match event {
-            LiquidityPoolProgram::SwapInCpAmmEvent(event) => {
+            LiquidityPoolProgram::SwapInCpAmmEvent(event)
+            | LiquidityPoolProgram::SwapInStableAmmEvent(event) => {
                 let mut batch = Batch::new(BatchType::Unlogged);
 
                 batch.append_statement(
@@ -94,9 +153,80 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     (&cp_amm, liquidity.as_slice()),
                 );
                 scylla_session.batch(&batch, values).await?;
+                if let Some(referrer_account) = event.referrer_account {
+                    let referrer_fee_amount: [u8; 8] = event.referrer_fee_amount.to_be_bytes();
+                    scylla_session
+                        .query_unpaged(
+                            "INSERT INTO referrals_by_referrer \
+                            (signature, timestamp, event_id, referrer_account, swapper, cp_amm, swapped_amount, referrer_fee_amount, is_in_out) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                timestamp,
+                                event_id,
+                                referrer_account.to_string(),
+                                &swapper,
+                                &cp_amm,
+                                swapped_amount.as_slice(),
+                                referrer_fee_amount.as_slice(),
+                                is_in_out,
+                            ),
+                        )
+                        .await?;
+                }
                 debug!("Saving SwapInCpAmmEvent from signature {}", signature);
             }
-            LiquidityPoolProgram::ProvideToCpAmmEvent(event) => {
+            LiquidityPoolProgram::SwapInClAmmEvent(event) => {
+                let mut batch = Batch::new(BatchType::Unlogged);
+
+                batch.append_statement(
+                    "INSERT INTO trades_by_cp_amm \
+                    (signature, timestamp, event_id, swapper, cp_amm, swapped_amount, received_amount, is_in_out) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+
+                batch.append_statement(
+                    "INSERT INTO trades_by_user \
+                    (signature, timestamp, event_id, swapper, cp_amm, swapped_amount, received_amount, is_in_out) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+
+                let timestamp = event.timestamp;
+                let event_id = Self::get_uuid(event.timestamp as u64);
+                let swapper = event.swapper.to_string();
+                let cl_amm = event.cl_amm.to_string();
+
+                let swapped_amount: [u8; 8] = event.swapped_amount.to_be_bytes();
+                let received_amount: [u8; 8] = event.received_amount.to_be_bytes();
+                let is_in_out = event.is_in_out;
+
+                let values = (
+                    (
+                        &signature,
+                        timestamp,
+                        event_id,
+                        &swapper,
+                        &cl_amm,
+                        swapped_amount.as_slice(),
+                        received_amount.as_slice(),
+                        is_in_out,
+                    ),
+                    (
+                        &signature,
+                        timestamp,
+                        event_id,
+                        &swapper,
+                        &cl_amm,
+                        swapped_amount.as_slice(),
+                        received_amount.as_slice(),
+                        is_in_out,
+                    ),
+                );
+                scylla_session.batch(&batch, values).await?;
+                debug!("Saving SwapInClAmmEvent from signature {}", signature);
+            }
+            LiquidityPoolProgram::ProvideToCpAmmEvent(event)
+            | LiquidityPoolProgram::ProvideToStableAmmEvent(event) => {
                 let liquidity = U192(event.constant_product_sqrt.value).to_little_endian();
                 scylla_session
                     .query_unpaged(
@@ -106,7 +236,8 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     .await?;
                 debug!("Saving ProvideToCpAmmEvent from signature {}", signature);
             }
-            LiquidityPoolProgram::WithdrawFromCpAmmEvent(event) => {
+            LiquidityPoolProgram::WithdrawFromCpAmmEvent(event)
+            | LiquidityPoolProgram::WithdrawFromStableAmmEvent(event) => {
                 let liquidity = U192(event.constant_product_sqrt.value).to_little_endian();
                 scylla_session
                     .query_unpaged(
@@ -116,7 +247,10 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     .await?;
                 debug!("Saving WithdrawFromCpAmmEvent from signature {}", signature);
             }
-            LiquidityPoolProgram::CollectFeesFromCpAmmEvent(event) => {
+            LiquidityPoolProgram::CollectFeesFromCpAmmEvent(event)
+            | LiquidityPoolProgram::CollectFeesFromStableAmmEvent(event)
+            | LiquidityPoolProgram::CollectFeesFromClAmmEvent(event)
+            | LiquidityPoolProgram::ClaimFeesFromCpAmmEvent(event) => {
                 let withdrawn_protocol_base_fees: [u8; 8] =
                     event.withdrawn_protocol_base_fees.to_be_bytes();
                 let withdrawn_protocol_quote_fees: [u8; 8] =
@@ -147,7 +281,375 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     signature
                 );
             }
-            LiquidityPoolProgram::LaunchCpAmmEvent(event) => {
+            LiquidityPoolProgram::ClaimLpFeesFromCpAmmEvent(event) => {
+                let lp_tokens_minted: [u8; 8] = event.lp_tokens_minted.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO claim_lp_fees_from_cp_amm_event \
+                        (signature, timestamp, event_id, cp_amm, fee_authority, \
+                        destination_lp_account, lp_tokens_minted) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm.to_string(),
+                            event.fee_authority.to_string(),
+                            event.destination_lp_account.to_string(),
+                            lp_tokens_minted.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ClaimLpFeesFromCpAmmEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::LockCpAmmLpEvent(event) => {
+                let locked_lp_tokens: [u8; 8] = event.locked_lp_tokens.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO lock_cp_amm_lp_events \
+                        (signature, timestamp, event_id, cp_amm, owner, cp_amm_lp_lock, lp_mint, \
+                        locked_lp_tokens, start_timestamp, cliff_timestamp, end_timestamp) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_lp_lock.to_string(),
+                            event.lp_mint.to_string(),
+                            locked_lp_tokens.as_slice(),
+                            event.start_timestamp,
+                            event.cliff_timestamp,
+                            event.end_timestamp,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving LockCpAmmLpEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::ClaimFromCpAmmLpLockEvent(event) => {
+                let lp_tokens: [u8; 8] = event.lp_tokens.to_be_bytes();
+                let claimed_lp_tokens: [u8; 8] = event.claimed_lp_tokens.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO claim_from_cp_amm_lp_lock_events \
+                        (signature, timestamp, event_id, cp_amm, owner, cp_amm_lp_lock, \
+                        lp_tokens, claimed_lp_tokens) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_lp_lock.to_string(),
+                            lp_tokens.as_slice(),
+                            claimed_lp_tokens.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ClaimFromCpAmmLpLockEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::InitializeCpAmmFarmEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO initialize_cp_amm_farm_events \
+                        (signature, timestamp, event_id, cp_amm_farm, cp_amm, authority, lp_mint) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm_farm.to_string(),
+                            event.cp_amm.to_string(),
+                            event.authority.to_string(),
+                            event.lp_mint.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving InitializeCpAmmFarmEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::AddCpAmmFarmRewardEvent(event) => {
+                let reward_amount: [u8; 8] = event.reward_amount.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO add_cp_amm_farm_reward_events \
+                        (signature, timestamp, event_id, cp_amm_farm, authority, reward_mint, reward_index, reward_amount, start_timestamp, end_timestamp) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm_farm.to_string(),
+                            event.authority.to_string(),
+                            event.reward_mint.to_string(),
+                            event.reward_index as i16,
+                            reward_amount.as_slice(),
+                            event.start_timestamp,
+                            event.end_timestamp,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving AddCpAmmFarmRewardEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::DepositToCpAmmFarmEvent(event) => {
+                let lp_tokens: [u8; 8] = event.lp_tokens.to_be_bytes();
+                let position_staked_lp_tokens: [u8; 8] = event.position_staked_lp_tokens.to_be_bytes();
+                let farm_staked_lp_tokens: [u8; 8] = event.farm_staked_lp_tokens.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO deposit_to_cp_amm_farm_events \
+                        (signature, timestamp, event_id, cp_amm_farm, owner, cp_amm_farm_position, lp_tokens, position_staked_lp_tokens, farm_staked_lp_tokens) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm_farm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_farm_position.to_string(),
+                            lp_tokens.as_slice(),
+                            position_staked_lp_tokens.as_slice(),
+                            farm_staked_lp_tokens.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving DepositToCpAmmFarmEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::WithdrawFromCpAmmFarmEvent(event) => {
+                let lp_tokens: [u8; 8] = event.lp_tokens.to_be_bytes();
+                let position_staked_lp_tokens: [u8; 8] = event.position_staked_lp_tokens.to_be_bytes();
+                let farm_staked_lp_tokens: [u8; 8] = event.farm_staked_lp_tokens.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO withdraw_from_cp_amm_farm_events \
+                        (signature, timestamp, event_id, cp_amm_farm, owner, cp_amm_farm_position, lp_tokens, position_staked_lp_tokens, farm_staked_lp_tokens) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm_farm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_farm_position.to_string(),
+                            lp_tokens.as_slice(),
+                            position_staked_lp_tokens.as_slice(),
+                            farm_staked_lp_tokens.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving WithdrawFromCpAmmFarmEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::HarvestFromCpAmmFarmEvent(event) => {
+                let reward_amount: [u8; 8] = event.reward_amount.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO harvest_from_cp_amm_farm_events \
+                        (signature, timestamp, event_id, cp_amm_farm, owner, cp_amm_farm_position, reward_mint, reward_amount) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm_farm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_farm_position.to_string(),
+                            event.reward_mint.to_string(),
+                            reward_amount.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving HarvestFromCpAmmFarmEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::PlaceCpAmmLimitOrderEvent(event) => {
+                let amount: [u8; 8] = event.amount.to_be_bytes();
+                let min_received_amount: [u8; 8] = event.min_received_amount.to_be_bytes();
+                let target_base_quote_ratio_sqrt =
+                    U192(event.target_base_quote_ratio_sqrt.value).to_little_endian();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO place_cp_amm_limit_order_events \
+                        (signature, timestamp, event_id, cp_amm, owner, cp_amm_limit_order, id, is_in_out, amount, min_received_amount, target_base_quote_ratio_sqrt) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_limit_order.to_string(),
+                            event.id as i64,
+                            event.is_in_out,
+                            amount.as_slice(),
+                            min_received_amount.as_slice(),
+                            target_base_quote_ratio_sqrt.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving PlaceCpAmmLimitOrderEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::CancelCpAmmLimitOrderEvent(event) => {
+                let returned_amount: [u8; 8] = event.returned_amount.to_be_bytes();
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO cancel_cp_amm_limit_order_events \
+                        (signature, timestamp, event_id, cp_amm, owner, cp_amm_limit_order, id, returned_amount) \
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.cp_amm.to_string(),
+                            event.owner.to_string(),
+                            event.cp_amm_limit_order.to_string(),
+                            event.id as i64,
+                            returned_amount.as_slice(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving CancelCpAmmLimitOrderEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::FillCpAmmLimitOrderEvent(event) => {
+                let mut batch = Batch::new(BatchType::Unlogged);
+
+                batch.append_statement(
+                    "INSERT INTO fill_cp_amm_limit_order_events \
+                    (signature, timestamp, event_id, cp_amm, owner, cranker, cp_amm_limit_order, id, is_in_out, swapped_amount, received_amount, crank_fee) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+
+                batch.append_statement(
+                    "INSERT INTO trades_by_cp_amm \
+                    (signature, timestamp, event_id, swapper, cp_amm, swapped_amount, received_amount, is_in_out) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+
+                batch.append_statement(
+                    "INSERT INTO trades_by_user \
+                    (signature, timestamp, event_id, swapper, cp_amm, swapped_amount, received_amount, is_in_out) \
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+
+                batch.append_statement(
+                    "INSERT INTO cp_amms_liquidity \
+                    (cp_amm, liquidity)\
+                    VALUES (?, ?)",
+                );
+
+                let timestamp = event.timestamp;
+                let event_id = Self::get_uuid(event.timestamp as u64);
+                let owner = event.owner.to_string();
+                let cp_amm = event.cp_amm.to_string();
+
+                let swapped_amount: [u8; 8] = event.swapped_amount.to_be_bytes();
+                let received_amount: [u8; 8] = event.received_amount.to_be_bytes();
+                let crank_fee: [u8; 8] = event.crank_fee.to_be_bytes();
+                let is_in_out = event.is_in_out;
+
+                let liquidity = U192(event.constant_product_sqrt.value).to_little_endian();
+
+                let values = (
+                    (
+                        &signature,
+                        timestamp,
+                        event_id,
+                        &cp_amm,
+                        &owner,
+                        event.cranker.to_string(),
+                        event.cp_amm_limit_order.to_string(),
+                        event.id as i64,
+                        is_in_out,
+                        swapped_amount.as_slice(),
+                        received_amount.as_slice(),
+                        crank_fee.as_slice(),
+                    ),
+                    (
+                        &signature,
+                        timestamp,
+                        event_id,
+                        &owner,
+                        &cp_amm,
+                        swapped_amount.as_slice(),
+                        received_amount.as_slice(),
+                        is_in_out,
+                    ),
+                    (
+                        &signature,
+                        timestamp,
+                        event_id,
+                        &owner,
+                        &cp_amm,
+                        swapped_amount.as_slice(),
+                        received_amount.as_slice(),
+                        is_in_out,
+                    ),
+                    (&cp_amm, liquidity.as_slice()),
+                );
+                scylla_session.batch(&batch, values).await?;
+                debug!(
+                    "Saving FillCpAmmLimitOrderEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::SyncCpAmmEvent(event) => {
+                self.save_reconcile_cp_amm_event(signature, &event, None)
+                    .await?;
+            }
+            LiquidityPoolProgram::SkimCpAmmEvent(event) => {
+                // Skims carry the sync fields plus the accounts the excess was transferred to
+                let reconcile_event = SyncCpAmmEvent {
+                    signer: event.signer,
+                    cp_amm: event.cp_amm,
+                    base_excess: event.base_excess,
+                    quote_excess: event.quote_excess,
+                    base_liquidity: event.base_liquidity,
+                    quote_liquidity: event.quote_liquidity,
+                    constant_product_sqrt: event.constant_product_sqrt.clone(),
+                    base_quote_ratio_sqrt: event.base_quote_ratio_sqrt.clone(),
+                    timestamp: event.timestamp,
+                };
+                self.save_reconcile_cp_amm_event(
+                    signature,
+                    &reconcile_event,
+                    Some((
+                        event.destination_base_account,
+                        event.destination_quote_account,
+                    )),
+                )
+                .await?;
+            }
+            LiquidityPoolProgram::LaunchCpAmmEvent(event)
+            | LiquidityPoolProgram::LaunchStableAmmEvent(event) => {
                 let mut batch = Batch::new(BatchType::Unlogged);
 
                 batch.append_statement(
@@ -231,50 +733,265 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     ),
                     (&cp_amm, liquidity.as_slice()),
                 );
-
-                scylla_session.batch(&batch, values).await?;
-                debug!("Saving LaunchCpAmmEvent from signature {}", signature);
+
+                scylla_session.batch(&batch, values).await?;
+                debug!("Saving LaunchCpAmmEvent from signature {}", signature);
+            }
+            LiquidityPoolProgram::InitializeCpAmmEvent(event)
+            | LiquidityPoolProgram::InitializeStableAmmEvent(event) => {
+                let mut batch = Batch::new(BatchType::Unlogged);
+                batch.append_statement(
+                    "INSERT INTO uninitialized_cp_amms (signature, timestamp, event_id, creator, cp_amm, amms_config, base_mint, quote_mint, lp_mint) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                );
+                batch.append_statement(
+                    "INSERT INTO cp_amms_keys \
+                    (cp_amm, amms_config, base_mint, quote_mint, lp_mint) \
+                    VALUES (?, ?, ?, ?, ?)",
+                );
+
+                let values = (
+                    (
+                        &signature,
+                        event.timestamp,
+                        Self::get_uuid(event.timestamp as u64),
+                        event.creator.to_string(),
+                        event.cp_amm.to_string(),
+                        event.amms_config.to_string(),
+                        event.base_mint.to_string(),
+                        event.quote_mint.to_string(),
+                        event.lp_mint.to_string(),
+                    ),
+                    (
+                        event.cp_amm.to_string(),
+                        event.amms_config.to_string(),
+                        event.base_mint.to_string(),
+                        event.quote_mint.to_string(),
+                        event.lp_mint.to_string(),
+                    )
+                );
+
+                scylla_session.batch(&batch, values).await?;
+                debug!("Saving InitializeCpAmmEvent from signature {}", signature);
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigFeeAuthorityEvent(event) => {
+                scylla_session
+                        .query_unpaged(
+                            "INSERT INTO upd_amms_cfg_fee_auth_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_fee_authority) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                event.timestamp,
+                                Self::get_uuid(event.timestamp as u64),
+                                event.authority.to_string(),
+                                event.amms_config.to_string(),
+                                event.new_fee_authority.to_string(),
+                            ),
+                        )
+                        .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigFeeAuthorityEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigProtocolFeeRateEvent(event) => {
+                scylla_session
+                        .query_unpaged(
+                            "INSERT INTO upd_amms_cfg_protocol_fee_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_protocol_fee_rate_basis_points) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                event.timestamp,
+                                Self::get_uuid(event.timestamp as u64),
+                                event.authority.to_string(),
+                                event.amms_config.to_string(),
+                                event.new_protocol_fee_rate_basis_points as i16,
+                            ),
+                        )
+                        .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigProtocolFeeRateEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigProvidersFeeRateEvent(event) => {
+                scylla_session
+                        .query_unpaged(
+                            "INSERT INTO upd_amms_cfg_prov_fee_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_providers_fee_rate_basis_points) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                event.timestamp,
+                                Self::get_uuid(event.timestamp as u64),
+                                event.authority.to_string(),
+                                event.amms_config.to_string(),
+                                event.new_providers_fee_rate_basis_points as i16,
+                            ),
+                        )
+                        .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigProvidersFeeRateEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigPauseEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_pause_events \
+                            (signature, timestamp, event_id, authority, amms_config, swaps_paused, provides_paused) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.swaps_paused,
+                            event.provides_paused,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigPauseEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateCpAmmPauseEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_cp_amm_pause_events \
+                            (signature, timestamp, event_id, authority, cp_amm, amms_config, swaps_paused, provides_paused) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.cp_amm.to_string(),
+                            event.amms_config.to_string(),
+                            event.swaps_paused,
+                            event.provides_paused,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateCpAmmPauseEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigProtocolFeeModeEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_protocol_fee_mode_events \
+                            (signature, timestamp, event_id, authority, amms_config, protocol_fee_on_lp_enabled) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.protocol_fee_on_lp_enabled,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigProtocolFeeModeEvent from signature {}",
+                    signature
+                );
             }
-            LiquidityPoolProgram::InitializeCpAmmEvent(event) => {
-                let mut batch = Batch::new(BatchType::Unlogged);
-                batch.append_statement(
-                    "INSERT INTO uninitialized_cp_amms (signature, timestamp, event_id, creator, cp_amm, amms_config, base_mint, quote_mint, lp_mint) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            LiquidityPoolProgram::UpdateAmmsConfigFlashLoanFeeRateEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_flash_loan_fee_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_flash_loan_fee_rate_basis_points) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.new_flash_loan_fee_rate_basis_points as i16,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigFlashLoanFeeRateEvent from signature {}",
+                    signature
                 );
-                batch.append_statement(
-                    "INSERT INTO cp_amms_keys \
-                    (cp_amm, amms_config, base_mint, quote_mint, lp_mint) \
-                    VALUES (?, ?, ?, ?, ?)",
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigPoolCreationFeeEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_pool_creation_fee_events \
+                            (signature, timestamp, event_id, authority, amms_config, pool_creation_fee_amount, pool_creation_fee_mint) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.pool_creation_fee_amount as i64,
+                            event.pool_creation_fee_mint.map(|mint| mint.to_string()),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigPoolCreationFeeEvent from signature {}",
+                    signature
                 );
-
-                let values = (
-                    (
-                        &signature,
-                        event.timestamp,
-                        Self::get_uuid(event.timestamp as u64),
-                        event.creator.to_string(),
-                        event.cp_amm.to_string(),
-                        event.amms_config.to_string(),
-                        event.base_mint.to_string(),
-                        event.quote_mint.to_string(),
-                        event.lp_mint.to_string(),
-                    ),
-                    (
-                        event.cp_amm.to_string(),
-                        event.amms_config.to_string(),
-                        event.base_mint.to_string(),
-                        event.quote_mint.to_string(),
-                        event.lp_mint.to_string(),
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigCreatorRestrictionEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_creator_restriction_events \
+                            (signature, timestamp, event_id, authority, amms_config, creator_restricted) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.creator_restricted,
+                        ),
                     )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigCreatorRestrictionEvent from signature {}",
+                    signature
                 );
-
-                scylla_session.batch(&batch, values).await?;
-                debug!("Saving InitializeCpAmmEvent from signature {}", signature);
             }
-            LiquidityPoolProgram::UpdateAmmsConfigFeeAuthorityEvent(event) => {
+            LiquidityPoolProgram::UpdateAmmsConfigReferrerFeeShareEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO upd_amms_cfg_referrer_fee_share_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_referrer_fee_share_basis_points) \
+                            VALUES (?, ?, ?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            Self::get_uuid(event.timestamp as u64),
+                            event.authority.to_string(),
+                            event.amms_config.to_string(),
+                            event.new_referrer_fee_share_basis_points as i16,
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving UpdateAmmsConfigReferrerFeeShareEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::UpdateAmmsConfigFeeRatesTimelockEvent(event) => {
                 scylla_session
                         .query_unpaged(
-                            "INSERT INTO upd_amms_cfg_fee_auth_events \
-                            (signature, timestamp, event_id, authority, amms_config, new_fee_authority) \
+                            "INSERT INTO upd_amms_cfg_fee_timelock_events \
+                            (signature, timestamp, event_id, authority, amms_config, new_fee_rates_timelock_duration) \
                             VALUES (?, ?, ?, ?, ?, ?)",
                             (
                                 &signature,
@@ -282,54 +999,95 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                                 Self::get_uuid(event.timestamp as u64),
                                 event.authority.to_string(),
                                 event.amms_config.to_string(),
-                                event.new_fee_authority.to_string(),
+                                event.new_fee_rates_timelock_duration,
                             ),
                         )
                         .await?;
                 debug!(
-                    "Saving UpdateAmmsConfigFeeAuthorityEvent from signature {}",
+                    "Saving UpdateAmmsConfigFeeRatesTimelockEvent from signature {}",
                     signature
                 );
             }
-            LiquidityPoolProgram::UpdateAmmsConfigProtocolFeeRateEvent(event) => {
+            LiquidityPoolProgram::ScheduleAmmsConfigFeeRatesChangeEvent(event) => {
                 scylla_session
                         .query_unpaged(
-                            "INSERT INTO upd_amms_cfg_protocol_fee_events \
-                            (signature, timestamp, event_id, authority, amms_config, new_protocol_fee_rate_basis_points) \
-                            VALUES (?, ?, ?, ?, ?, ?)",
+                            "INSERT INTO sched_amms_cfg_fee_rates_events \
+                            (signature, timestamp, event_id, authority, amms_config, pending_providers_fee_rate_basis_points, pending_protocol_fee_rate_basis_points, effective_timestamp) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
                             (
                                 &signature,
                                 event.timestamp,
                                 Self::get_uuid(event.timestamp as u64),
                                 event.authority.to_string(),
                                 event.amms_config.to_string(),
-                                event.new_protocol_fee_rate_basis_points as i16,
+                                event.pending_providers_fee_rate_basis_points as i16,
+                                event.pending_protocol_fee_rate_basis_points as i16,
+                                event.effective_timestamp,
                             ),
                         )
                         .await?;
                 debug!(
-                    "Saving UpdateAmmsConfigProtocolFeeRateEvent from signature {}",
+                    "Saving ScheduleAmmsConfigFeeRatesChangeEvent from signature {}",
                     signature
                 );
             }
-            LiquidityPoolProgram::UpdateAmmsConfigProvidersFeeRateEvent(event) => {
+            LiquidityPoolProgram::CancelAmmsConfigFeeRatesChangeEvent(event) => {
                 scylla_session
                         .query_unpaged(
-                            "INSERT INTO upd_amms_cfg_prov_fee_events \
-                            (signature, timestamp, event_id, authority, amms_config, new_providers_fee_rate_basis_points) \
-                            VALUES (?, ?, ?, ?, ?, ?)",
+                            "INSERT INTO cancel_amms_cfg_fee_rates_events \
+                            (signature, timestamp, event_id, authority, amms_config, cancelled_providers_fee_rate_basis_points, cancelled_protocol_fee_rate_basis_points) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?)",
                             (
                                 &signature,
                                 event.timestamp,
                                 Self::get_uuid(event.timestamp as u64),
                                 event.authority.to_string(),
                                 event.amms_config.to_string(),
-                                event.new_providers_fee_rate_basis_points as i16,
+                                event.cancelled_providers_fee_rate_basis_points as i16,
+                                event.cancelled_protocol_fee_rate_basis_points as i16,
                             ),
                         )
                         .await?;
                 debug!(
-                    "Saving UpdateAmmsConfigProvidersFeeRateEvent from signature {}",
+                    "Saving CancelAmmsConfigFeeRatesChangeEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::ProposeAmmsConfigsManagerAuthorityEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO prop_amms_cfg_mgr_auth_events \
+                            (signature, timestamp, authority, pending_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.authority.to_string(),
+                            event.pending_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ProposeAmmsConfigsManagerAuthorityEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::CancelAmmsConfigsManagerAuthorityTransferEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO cancel_amms_cfg_mgr_auth_events \
+                            (signature, timestamp, authority, cancelled_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.authority.to_string(),
+                            event.cancelled_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving CancelAmmsConfigsManagerAuthorityTransferEvent from signature {}",
                     signature
                 );
             }
@@ -352,6 +1110,44 @@ impl EventsSaver<LiquidityPoolProgram> for ScyllaDbEventsSaver<LiquidityPoolProg
                     signature
                 );
             }
+            LiquidityPoolProgram::ProposeAmmsConfigsManagerHeadAuthorityEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO prop_amms_cfg_mgr_head_auth_events \
+                            (signature, timestamp, head_authority, pending_head_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.head_authority.to_string(),
+                            event.pending_head_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ProposeAmmsConfigsManagerHeadAuthorityEvent from signature {}",
+                    signature
+                );
+            }
+            LiquidityPoolProgram::CancelAmmsConfigsManagerHeadAuthorityTransferEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO cancel_amms_cfg_mgr_head_auth_events \
+                            (signature, timestamp, head_authority, cancelled_head_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.head_authority.to_string(),
+                            event.cancelled_head_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving CancelAmmsConfigsManagerHeadAuthorityTransferEvent from signature {}",
+                    signature
+                );
+            }
             LiquidityPoolProgram::UpdateAmmsConfigsManagerHeadAuthorityEvent(event) => {
                 scylla_session
                     .query_unpaged(
@@ -567,6 +1363,56 @@ impl EventsSaver<LaunchpoolProgram> for ScyllaDbEventsSaver<LaunchpoolProgram> {
                     signature
                 );
             }
+            LaunchpoolProgram::InitializeLaunchpoolMigrationEvent(event) => {
+                scylla_session
+                        .query_unpaged(
+                            "INSERT INTO launchpool_migrations \
+                            (signature, timestamp, authority, launchpool, launchpool_migration, migration_authority, \
+                            cp_amm, quote_mint, base_share_basis_points, base_amount, quote_amount) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                event.timestamp,
+                                event.authority.to_string(),
+                                event.launchpool.to_string(),
+                                event.launchpool_migration.to_string(),
+                                event.migration_authority.to_string(),
+                                event.cp_amm.to_string(),
+                                event.quote_mint.to_string(),
+                                event.base_share_basis_points as i16,
+                                event.base_amount.to_be_bytes().as_slice(),
+                                event.quote_amount.to_be_bytes().as_slice(),
+                            ),
+                        )
+                        .await?;
+                debug!(
+                    "Saving InitializeLaunchpoolMigrationEvent from signature {}",
+                    signature
+                );
+            }
+            LaunchpoolProgram::MigrateLaunchpoolToCpAmmEvent(event) => {
+                scylla_session
+                        .query_unpaged(
+                            "INSERT INTO migrate_launchpool_to_cp_amm_events \
+                            (signature, timestamp, signer, launchpool, launchpool_migration, cp_amm, base_amount, quote_amount) \
+                            VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                            (
+                                &signature,
+                                event.timestamp as i64,
+                                event.signer.to_string(),
+                                event.launchpool.to_string(),
+                                event.launchpool_migration.to_string(),
+                                event.cp_amm.to_string(),
+                                event.base_amount.to_be_bytes().as_slice(),
+                                event.quote_amount.to_be_bytes().as_slice(),
+                            ),
+                        )
+                        .await?;
+                debug!(
+                    "Saving MigrateLaunchpoolToCpAmmEvent from signature {}",
+                    signature
+                );
+            }
             LaunchpoolProgram::InitializeLaunchpoolsConfigEvent(event) => {
                 scylla_session
                         .query_unpaged(
@@ -679,6 +1525,44 @@ impl EventsSaver<LaunchpoolProgram> for ScyllaDbEventsSaver<LaunchpoolProgram> {
                     signature
                 );
             }
+            LaunchpoolProgram::ProposeLaunchpoolsConfigsManagerAuthorityEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO propose_lp_cfg_mgr_auth_events \
+                            (signature, timestamp, authority, pending_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.authority.to_string(),
+                            event.pending_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ProposeLaunchpoolsConfigsManagerAuthorityEvent from signature {}",
+                    signature
+                );
+            }
+            LaunchpoolProgram::CancelLaunchpoolsConfigsManagerAuthorityTransferEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO cancel_lp_cfg_mgr_auth_events \
+                            (signature, timestamp, authority, cancelled_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.authority.to_string(),
+                            event.cancelled_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving CancelLaunchpoolsConfigsManagerAuthorityTransferEvent from signature {}",
+                    signature
+                );
+            }
             LaunchpoolProgram::UpdateLaunchpoolsConfigsManagerAuthorityEvent(event) => {
                 scylla_session
                     .query_unpaged(
@@ -698,6 +1582,44 @@ impl EventsSaver<LaunchpoolProgram> for ScyllaDbEventsSaver<LaunchpoolProgram> {
                     signature
                 );
             }
+            LaunchpoolProgram::ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO propose_lp_cfg_mgr_head_auth_events \
+                            (signature, timestamp, head_authority, pending_head_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.head_authority.to_string(),
+                            event.pending_head_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent from signature {}",
+                    signature
+                );
+            }
+            LaunchpoolProgram::CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent(event) => {
+                scylla_session
+                    .query_unpaged(
+                        "INSERT INTO cancel_lp_cfg_mgr_head_auth_events \
+                            (signature, timestamp, head_authority, cancelled_head_authority) \
+                            VALUES (?, ?, ?, ?)",
+                        (
+                            &signature,
+                            event.timestamp,
+                            event.head_authority.to_string(),
+                            event.cancelled_head_authority.to_string(),
+                        ),
+                    )
+                    .await?;
+                debug!(
+                    "Saving CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent from signature {}",
+                    signature
+                );
+            }
             LaunchpoolProgram::UpdateLaunchpoolsConfigsManagerHeadAuthorityEvent(event) => {
                 scylla_session
                     .query_unpaged(
@@ -2,12 +2,17 @@
 use crate::define_program_events_enum;
 use launchpool::types::{
     CloseStakePositionEvent, CollectProtocolRewardEvent, IncreaseStakePositionEvent,
-    InitializeLaunchpoolEvent, InitializeLaunchpoolsConfigEvent,
-    InitializeLaunchpoolsConfigsManagerEvent, LaunchLaunchpoolEvent, OpenStakePositionEvent,
+    InitializeLaunchpoolEvent, InitializeLaunchpoolMigrationEvent,
+    InitializeLaunchpoolsConfigEvent, InitializeLaunchpoolsConfigsManagerEvent,
+    LaunchLaunchpoolEvent, MigrateLaunchpoolToCpAmmEvent, OpenStakePositionEvent,
     UpdateLaunchpoolsConfigDurationEvent, UpdateLaunchpoolsConfigPositionSizesEvent,
     UpdateLaunchpoolsConfigProtocolRewardShareEvent, UpdateLaunchpoolsConfigRewardAuthorityEvent,
     UpdateLaunchpoolsConfigsManagerAuthorityEvent,
     UpdateLaunchpoolsConfigsManagerHeadAuthorityEvent,
+    ProposeLaunchpoolsConfigsManagerAuthorityEvent,
+    ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent,
+    CancelLaunchpoolsConfigsManagerAuthorityTransferEvent,
+    CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent,
 };
 use launchpool::programs::LAUNCHPOOL_ID;
 use crate::macros::*;
@@ -27,9 +32,15 @@ define_program_events_enum! {
         UpdateLaunchpoolsConfigProtocolRewardShareEvent = [28, 94, 107, 85, 139, 71, 180, 59],
         UpdateLaunchpoolsConfigPositionSizesEvent = [190, 75, 204, 106, 214, 22, 190, 193],
         UpdateLaunchpoolsConfigDurationEvent = [207, 214, 158, 69, 198, 68, 179, 48],
+        ProposeLaunchpoolsConfigsManagerAuthorityEvent = [222, 137, 250, 54, 240, 75, 196, 111],
+        CancelLaunchpoolsConfigsManagerAuthorityTransferEvent = [6, 20, 198, 59, 203, 166, 132, 177],
         UpdateLaunchpoolsConfigsManagerAuthorityEvent = [2, 12, 242, 131, 70, 205, 239, 249],
+        ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent = [149, 7, 207, 75, 74, 163, 199, 127],
+        CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent = [51, 205, 77, 91, 91, 207, 181, 35],
         UpdateLaunchpoolsConfigsManagerHeadAuthorityEvent = [58, 215, 167, 123, 90, 21, 139, 104],
         InitializeLaunchpoolsConfigsManagerEvent = [73, 78, 194, 10, 22, 3, 125, 192],
+        InitializeLaunchpoolMigrationEvent = [130, 47, 109, 167, 84, 175, 207, 194],
+        MigrateLaunchpoolToCpAmmEvent = [142, 223, 44, 247, 26, 181, 196, 122],
     }
 }
 
@@ -334,6 +345,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_propose_launchpools_configs_manager_authority_event() {
+        let event = ProposeLaunchpoolsConfigsManagerAuthorityEvent {
+            authority: Pubkey::new_unique(),
+            pending_authority: Pubkey::new_unique(),
+            timestamp: 123456789,
+        };
+
+        let mut serialized = Vec::from(LaunchpoolProgram::ProposeLaunchpoolsConfigsManagerAuthorityEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LaunchpoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LaunchpoolProgram::ProposeLaunchpoolsConfigsManagerAuthorityEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
     #[test]
     fn test_deserialize_update_launchpools_configs_manager_head_authority_event() {
         let event = UpdateLaunchpoolsConfigsManagerHeadAuthorityEvent {
@@ -374,4 +405,56 @@ mod tests {
             _ => panic!("Unexpected variant"),
         }
     }
+
+    #[test]
+    fn test_deserialize_initialize_launchpool_migration_event() {
+        let event = InitializeLaunchpoolMigrationEvent {
+            authority: Pubkey::new_unique(),
+            launchpool: Pubkey::new_unique(),
+            launchpool_migration: Pubkey::new_unique(),
+            migration_authority: Pubkey::new_unique(),
+            cp_amm: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            base_share_basis_points: 42,
+            base_amount: 100,
+            quote_amount: 100,
+            protocol_reward_amount: 100,
+            timestamp: 123456789,
+        };
+
+        let mut serialized = Vec::from(LaunchpoolProgram::InitializeLaunchpoolMigrationEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LaunchpoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LaunchpoolProgram::InitializeLaunchpoolMigrationEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_migrate_launchpool_to_cp_amm_event() {
+        let event = MigrateLaunchpoolToCpAmmEvent {
+            signer: Pubkey::new_unique(),
+            launchpool: Pubkey::new_unique(),
+            launchpool_migration: Pubkey::new_unique(),
+            cp_amm: Pubkey::new_unique(),
+            base_amount: 100,
+            quote_amount: 100,
+            timestamp: 123456789,
+        };
+
+        let mut serialized = Vec::from(LaunchpoolProgram::MigrateLaunchpoolToCpAmmEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LaunchpoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LaunchpoolProgram::MigrateLaunchpoolToCpAmmEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
 }
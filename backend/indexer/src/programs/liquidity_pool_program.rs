@@ -1,9 +1,23 @@
 #![allow(non_snake_case, non_upper_case_globals, dead_code)]
 use crate::define_program_events_enum;
-use liquidity_pool::types::{InitializeAmmsConfigsManagerEvent, CollectFeesFromCpAmmEvent, InitializeAmmsConfigEvent, InitializeCpAmmEvent, LaunchCpAmmEvent, ProvideToCpAmmEvent, SwapInCpAmmEvent, UpdateAmmsConfigFeeAuthorityEvent, UpdateAmmsConfigProtocolFeeRateEvent, UpdateAmmsConfigProvidersFeeRateEvent, UpdateAmmsConfigsManagerAuthorityEvent, UpdateAmmsConfigsManagerHeadAuthorityEvent, WithdrawFromCpAmmEvent};
+use liquidity_pool::types::{InitializeAmmsConfigsManagerEvent, CollectFeesFromCpAmmEvent, InitializeAmmsConfigEvent, InitializeCpAmmEvent, LaunchCpAmmEvent, ProvideToCpAmmEvent, SwapInCpAmmEvent, UpdateAmmsConfigFeeAuthorityEvent, UpdateAmmsConfigProtocolFeeRateEvent, UpdateAmmsConfigProvidersFeeRateEvent, UpdateAmmsConfigPauseEvent, UpdateCpAmmPauseEvent, UpdateAmmsConfigProtocolFeeModeEvent, UpdateAmmsConfigFlashLoanFeeRateEvent, UpdateAmmsConfigPoolCreationFeeEvent, UpdateAmmsConfigCreatorRestrictionEvent, UpdateAmmsConfigReferrerFeeShareEvent, ClaimLpFeesFromCpAmmEvent, LockCpAmmLpEvent, ClaimFromCpAmmLpLockEvent, InitializeCpAmmFarmEvent, AddCpAmmFarmRewardEvent, DepositToCpAmmFarmEvent, WithdrawFromCpAmmFarmEvent, HarvestFromCpAmmFarmEvent, PlaceCpAmmLimitOrderEvent, CancelCpAmmLimitOrderEvent, FillCpAmmLimitOrderEvent, SyncCpAmmEvent, SkimCpAmmEvent, SwapInClAmmEvent, UpdateAmmsConfigsManagerAuthorityEvent, UpdateAmmsConfigsManagerHeadAuthorityEvent, ProposeAmmsConfigsManagerAuthorityEvent, ProposeAmmsConfigsManagerHeadAuthorityEvent, CancelAmmsConfigsManagerAuthorityTransferEvent, CancelAmmsConfigsManagerHeadAuthorityTransferEvent, UpdateAmmsConfigFeeRatesTimelockEvent, ScheduleAmmsConfigFeeRatesChangeEvent, CancelAmmsConfigFeeRatesChangeEvent, WithdrawFromCpAmmEvent};
 use liquidity_pool::programs::LIQUIDITY_POOL_ID;
 use crate::macros::*;
 
+// StableAmm events share the layout of their CpAmm counterparts, so they are decoded into the same types
+// and saved into the same tables.
+type SwapInStableAmmEvent = SwapInCpAmmEvent;
+type ProvideToStableAmmEvent = ProvideToCpAmmEvent;
+type WithdrawFromStableAmmEvent = WithdrawFromCpAmmEvent;
+type CollectFeesFromStableAmmEvent = CollectFeesFromCpAmmEvent;
+type LaunchStableAmmEvent = LaunchCpAmmEvent;
+type InitializeStableAmmEvent = InitializeCpAmmEvent;
+// ClAmm protocol fees collection shares the layout of the CpAmm one.
+type CollectFeesFromClAmmEvent = CollectFeesFromCpAmmEvent;
+// Partial and swept fee claims share the layout of the full collection, with the destination accounts
+// saved in place of the fee authority accounts.
+type ClaimFeesFromCpAmmEvent = CollectFeesFromCpAmmEvent;
+
 define_program_events_enum! {
     LIQUIDITY_POOL_ID,
     #[derive(Debug)]
@@ -12,12 +26,48 @@ define_program_events_enum! {
         ProvideToCpAmmEvent = [169, 179, 105, 2, 40, 101, 75, 46],
         WithdrawFromCpAmmEvent = [20, 17, 220, 146, 91, 169, 183, 30],
         CollectFeesFromCpAmmEvent = [136, 202, 5, 125, 123, 107, 91, 113],
+        ClaimFeesFromCpAmmEvent = [132, 194, 170, 41, 218, 205, 63, 5],
+        ClaimLpFeesFromCpAmmEvent = [210, 81, 202, 154, 57, 201, 118, 163],
         LaunchCpAmmEvent = [185, 17, 120, 196, 33, 27, 224, 149],
+        LockCpAmmLpEvent = [120, 11, 120, 109, 215, 125, 171, 211],
+        ClaimFromCpAmmLpLockEvent = [127, 54, 107, 202, 6, 40, 186, 192],
+        InitializeCpAmmFarmEvent = [225, 153, 18, 130, 102, 112, 186, 127],
+        AddCpAmmFarmRewardEvent = [142, 206, 185, 201, 27, 137, 38, 194],
+        DepositToCpAmmFarmEvent = [77, 6, 35, 78, 87, 136, 201, 7],
+        WithdrawFromCpAmmFarmEvent = [102, 195, 33, 182, 33, 185, 180, 141],
+        HarvestFromCpAmmFarmEvent = [56, 76, 243, 47, 16, 254, 42, 254],
+        PlaceCpAmmLimitOrderEvent = [195, 211, 29, 180, 179, 66, 40, 153],
+        CancelCpAmmLimitOrderEvent = [46, 97, 108, 32, 119, 253, 195, 151],
+        FillCpAmmLimitOrderEvent = [124, 95, 242, 149, 179, 174, 4, 248],
+        SyncCpAmmEvent = [20, 240, 24, 65, 62, 3, 154, 227],
+        SkimCpAmmEvent = [59, 229, 2, 127, 227, 139, 87, 182],
         InitializeCpAmmEvent = [169, 188, 54, 67, 1, 145, 213, 80],
+        SwapInStableAmmEvent = [171, 222, 125, 166, 45, 92, 209, 219],
+        ProvideToStableAmmEvent = [84, 176, 137, 12, 156, 113, 156, 125],
+        WithdrawFromStableAmmEvent = [57, 55, 82, 51, 166, 45, 2, 135],
+        CollectFeesFromStableAmmEvent = [215, 204, 39, 205, 243, 159, 2, 146],
+        LaunchStableAmmEvent = [245, 229, 139, 216, 72, 128, 7, 55],
+        InitializeStableAmmEvent = [236, 170, 48, 207, 162, 205, 147, 139],
+        SwapInClAmmEvent = [188, 14, 215, 195, 228, 109, 102, 142],
+        CollectFeesFromClAmmEvent = [73, 38, 104, 198, 156, 105, 116, 145],
         UpdateAmmsConfigFeeAuthorityEvent = [145, 84, 143, 149, 33, 46, 208, 235],
         UpdateAmmsConfigProtocolFeeRateEvent = [122, 157, 87, 60, 236, 113, 198, 207],
         UpdateAmmsConfigProvidersFeeRateEvent = [182, 212, 34, 247, 179, 94, 71, 148],
+        UpdateAmmsConfigPauseEvent = [17, 222, 226, 153, 209, 156, 3, 124],
+        UpdateCpAmmPauseEvent = [175, 36, 132, 90, 55, 194, 62, 121],
+        UpdateAmmsConfigProtocolFeeModeEvent = [81, 24, 125, 183, 28, 221, 5, 211],
+        UpdateAmmsConfigFlashLoanFeeRateEvent = [67, 19, 43, 34, 154, 242, 102, 190],
+        UpdateAmmsConfigPoolCreationFeeEvent = [23, 73, 21, 94, 172, 12, 220, 223],
+        UpdateAmmsConfigCreatorRestrictionEvent = [177, 187, 143, 175, 60, 216, 40, 133],
+        UpdateAmmsConfigReferrerFeeShareEvent = [69, 94, 202, 24, 6, 248, 228, 3],
+        UpdateAmmsConfigFeeRatesTimelockEvent = [254, 125, 119, 176, 7, 236, 77, 149],
+        ScheduleAmmsConfigFeeRatesChangeEvent = [74, 184, 98, 235, 38, 11, 63, 161],
+        CancelAmmsConfigFeeRatesChangeEvent = [198, 20, 151, 191, 170, 206, 224, 213],
+        ProposeAmmsConfigsManagerAuthorityEvent = [101, 189, 209, 42, 92, 179, 208, 165],
+        CancelAmmsConfigsManagerAuthorityTransferEvent = [66, 221, 202, 227, 226, 65, 84, 144],
         UpdateAmmsConfigsManagerAuthorityEvent = [87, 111, 229, 185, 38, 229, 136, 227],
+        ProposeAmmsConfigsManagerHeadAuthorityEvent = [186, 66, 144, 217, 74, 8, 67, 23],
+        CancelAmmsConfigsManagerHeadAuthorityTransferEvent = [199, 48, 98, 138, 85, 216, 39, 219],
         UpdateAmmsConfigsManagerHeadAuthorityEvent = [36, 151, 67, 108, 246, 99, 170, 92],
         InitializeAmmsConfigEvent = [138, 41, 61, 174, 151, 6, 209, 181],
         InitializeAmmsConfigsManagerEvent = [99, 45, 79, 86, 159, 151, 244, 154]
@@ -49,6 +99,8 @@ mod tests {
             protocol_quote_fees_to_redeem: 25,
             constant_product_sqrt: Q64128 { value: [1, 2, 3] },
             base_quote_ratio_sqrt: Q64128 { value: [4, 5, 6] },
+            referrer_account: Some(Pubkey::new_unique()),
+            referrer_fee_amount: 2,
             timestamp: 1_654_321_000,
         };
 
@@ -64,6 +116,100 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_fill_cp_amm_limit_order_event() {
+        let event = FillCpAmmLimitOrderEvent {
+            cranker: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            cp_amm: Pubkey::new_unique(),
+            cp_amm_limit_order: Pubkey::new_unique(),
+            id: 3,
+            is_in_out: false,
+            swapped_amount: 1_000,
+            received_amount: 999,
+            crank_fee: 1,
+            base_liquidity: 10_000,
+            quote_liquidity: 8_000,
+            protocol_base_fees_to_redeem: 15,
+            protocol_quote_fees_to_redeem: 25,
+            constant_product_sqrt: Q64128 { value: [1, 2, 3] },
+            base_quote_ratio_sqrt: Q64128 { value: [4, 5, 6] },
+            timestamp: 1_654_321_000,
+        };
+
+        let mut serialized = Vec::from(LiquidityPoolProgram::FillCpAmmLimitOrderEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LiquidityPoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LiquidityPoolProgram::FillCpAmmLimitOrderEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_swap_in_stable_amm_event() {
+        let event = SwapInCpAmmEvent {
+            swapper: Pubkey::new_unique(),
+            cp_amm: Pubkey::new_unique(),
+            is_in_out: false,
+            swapped_amount: 1_000,
+            received_amount: 998,
+            estimated_result: 998,
+            allowed_slippage: 1,
+            base_liquidity: 10_002,
+            quote_liquidity: 9_000,
+            protocol_base_fees_to_redeem: 0,
+            protocol_quote_fees_to_redeem: 1,
+            constant_product_sqrt: Q64128 { value: [1, 2, 3] },
+            base_quote_ratio_sqrt: Q64128 { value: [4, 5, 6] },
+            referrer_account: None,
+            referrer_fee_amount: 0,
+            timestamp: 1_654_321_001,
+        };
+
+        let mut serialized = Vec::from(LiquidityPoolProgram::SwapInStableAmmEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LiquidityPoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LiquidityPoolProgram::SwapInStableAmmEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_skim_cp_amm_event() {
+        let event = SkimCpAmmEvent {
+            signer: Pubkey::new_unique(),
+            cp_amm: Pubkey::new_unique(),
+            destination_base_account: Pubkey::new_unique(),
+            destination_quote_account: Pubkey::new_unique(),
+            base_excess: 500,
+            quote_excess: 0,
+            base_liquidity: 10_000,
+            quote_liquidity: 8_000,
+            constant_product_sqrt: Q64128 { value: [1, 2, 3] },
+            base_quote_ratio_sqrt: Q64128 { value: [4, 5, 6] },
+            timestamp: 1_654_321_002,
+        };
+
+        let mut serialized = Vec::from(LiquidityPoolProgram::SkimCpAmmEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LiquidityPoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LiquidityPoolProgram::SkimCpAmmEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
     #[test]
     fn test_deserialize_provide_to_cp_amm_event() {
         let event = ProvideToCpAmmEvent {
@@ -281,6 +427,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_propose_amms_configs_manager_authority_event() {
+        let event = ProposeAmmsConfigsManagerAuthorityEvent {
+            authority: Pubkey::new_unique(),
+            pending_authority: Pubkey::new_unique(),
+            timestamp: 1_654_321_999,
+        };
+
+        let mut serialized = Vec::from(LiquidityPoolProgram::ProposeAmmsConfigsManagerAuthorityEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LiquidityPoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LiquidityPoolProgram::ProposeAmmsConfigsManagerAuthorityEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_schedule_amms_config_fee_rates_change_event() {
+        let event = ScheduleAmmsConfigFeeRatesChangeEvent {
+            authority: Pubkey::new_unique(),
+            amms_config: Pubkey::new_unique(),
+            pending_providers_fee_rate_basis_points: 25,
+            pending_protocol_fee_rate_basis_points: 5,
+            effective_timestamp: 1_654_408_399,
+            timestamp: 1_654_321_999,
+        };
+
+        let mut serialized = Vec::from(LiquidityPoolProgram::ScheduleAmmsConfigFeeRatesChangeEvent_DISCRIMINATOR);
+        event.serialize(&mut serialized).unwrap();
+
+        let deserialized = LiquidityPoolProgram::try_deserialize(&serialized).unwrap();
+        match deserialized {
+            LiquidityPoolProgram::ScheduleAmmsConfigFeeRatesChangeEvent(inner) => {
+                assert_eq!(*inner, event);
+            },
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
     #[test]
     fn test_deserialize_update_amms_configs_manager_head_authority_event() {
         let event = UpdateAmmsConfigsManagerHeadAuthorityEvent {
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use axum::{Router, ServiceExt};
 use axum::routing::{post, IntoMakeService};
-use crate::launchpool::api::endpoints::transactions::{get_close_stake_position_tx, get_collect_protocol_reward_tx, get_increase_stake_position_tx, get_initialize_launchpool_tx, get_initialize_launchpools_config_manager_tx, get_initialize_launchpools_config_tx, get_launch_launchpool_tx, get_open_stake_position_tx, get_update_launchpools_config_duration_tx, get_update_launchpools_config_position_sizes_tx, get_update_launchpools_config_protocol_reward_share_tx, get_update_launchpools_config_reward_authority_tx, get_update_launchpools_configs_manager_authority_tx, get_update_launchpools_configs_manager_head_authority_tx};
+use crate::launchpool::api::endpoints::transactions::{get_close_stake_position_tx, get_collect_protocol_reward_tx, get_increase_stake_position_tx, get_initialize_launchpool_migration_tx, get_initialize_launchpool_tx, get_initialize_launchpools_config_manager_tx, get_initialize_launchpools_config_tx, get_launch_launchpool_tx, get_migrate_launchpool_to_cp_amm_tx, get_open_stake_position_tx, get_update_launchpools_config_duration_tx, get_update_launchpools_config_position_sizes_tx, get_update_launchpools_config_protocol_reward_share_tx, get_update_launchpools_config_reward_authority_tx, get_propose_launchpools_configs_manager_authority_tx, get_propose_launchpools_configs_manager_head_authority_tx, get_accept_launchpools_configs_manager_authority_tx, get_accept_launchpools_configs_manager_head_authority_tx, get_cancel_launchpools_configs_manager_authority_transfer_tx, get_cancel_launchpools_configs_manager_head_authority_transfer_tx};
 use crate::launchpool::context::LaunchpoolContext;
 pub struct LaunchpoolApp {
     pub context: Arc<LaunchpoolContext>,
@@ -19,8 +19,12 @@ impl LaunchpoolApp {
 
         let scoped = Router::new()
             .route(&r.initialize_config_manager, post(get_initialize_launchpools_config_manager_tx))
-            .route(&r.update_manager_authority, post(get_update_launchpools_configs_manager_authority_tx))
-            .route(&r.update_manager_head_authority, post(get_update_launchpools_configs_manager_head_authority_tx))
+            .route(&r.propose_manager_authority, post(get_propose_launchpools_configs_manager_authority_tx))
+            .route(&r.propose_manager_head_authority, post(get_propose_launchpools_configs_manager_head_authority_tx))
+            .route(&r.accept_manager_authority, post(get_accept_launchpools_configs_manager_authority_tx))
+            .route(&r.accept_manager_head_authority, post(get_accept_launchpools_configs_manager_head_authority_tx))
+            .route(&r.cancel_manager_authority_transfer, post(get_cancel_launchpools_configs_manager_authority_transfer_tx))
+            .route(&r.cancel_manager_head_authority_transfer, post(get_cancel_launchpools_configs_manager_head_authority_transfer_tx))
             .route(&r.initialize_config, post(get_initialize_launchpools_config_tx))
             .route(&r.update_reward_authority, post(get_update_launchpools_config_reward_authority_tx))
             .route(&r.update_protocol_reward_share, post(get_update_launchpools_config_protocol_reward_share_tx))
@@ -32,6 +36,8 @@ impl LaunchpoolApp {
             .route(&r.increase, post(get_increase_stake_position_tx))
             .route(&r.close, post(get_close_stake_position_tx))
             .route(&r.collect, post(get_collect_protocol_reward_tx))
+            .route(&r.initialize_migration, post(get_initialize_launchpool_migration_tx))
+            .route(&r.migrate, post(get_migrate_launchpool_to_cp_amm_tx))
             .with_state(state);
 
         Router::new().nest(&r.scope_path, scoped).into_make_service()
@@ -42,8 +48,12 @@ impl LaunchpoolApp {
 pub struct LaunchpoolRoutes {
     scope_path: String,
     initialize_config_manager: String,
-    update_manager_authority: String,
-    update_manager_head_authority: String,
+    propose_manager_authority: String,
+    propose_manager_head_authority: String,
+    accept_manager_authority: String,
+    accept_manager_head_authority: String,
+    cancel_manager_authority_transfer: String,
+    cancel_manager_head_authority_transfer: String,
     initialize_config: String,
     update_reward_authority: String,
     update_protocol_reward_share: String,
@@ -55,14 +65,20 @@ pub struct LaunchpoolRoutes {
     increase: String,
     close: String,
     collect: String,
+    initialize_migration: String,
+    migrate: String,
 }
 
 impl LaunchpoolRoutes {
     pub fn new(
         scope_path: String,
         initialize_config_manager: String,
-        update_manager_authority: String,
-        update_manager_head_authority: String,
+        propose_manager_authority: String,
+        propose_manager_head_authority: String,
+        accept_manager_authority: String,
+        accept_manager_head_authority: String,
+        cancel_manager_authority_transfer: String,
+        cancel_manager_head_authority_transfer: String,
         initialize_config: String,
         update_reward_authority: String,
         update_protocol_reward_share: String,
@@ -74,12 +90,18 @@ impl LaunchpoolRoutes {
         increase: String,
         close: String,
         collect: String,
+        initialize_migration: String,
+        migrate: String,
     ) -> Self {
         Self {
             scope_path,
             initialize_config_manager,
-            update_manager_authority,
-            update_manager_head_authority,
+            propose_manager_authority,
+            propose_manager_head_authority,
+            accept_manager_authority,
+            accept_manager_head_authority,
+            cancel_manager_authority_transfer,
+            cancel_manager_head_authority_transfer,
             initialize_config,
             update_reward_authority,
             update_protocol_reward_share,
@@ -91,6 +113,8 @@ impl LaunchpoolRoutes {
             increase,
             close,
             collect,
+            initialize_migration,
+            migrate,
         }
     }
 }
\ No newline at end of file
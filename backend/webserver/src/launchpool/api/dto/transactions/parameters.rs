@@ -62,4 +62,16 @@ pub struct CloseStakePositionParams {
 pub struct CollectProtocolRewardParams {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub launchpool: Pubkey,
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize)]
+pub struct InitializeLaunchpoolMigrationParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub launchpool: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct MigrateLaunchpoolToCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub launchpool: Pubkey,
+}
@@ -12,7 +12,7 @@ pub struct InitializeLaunchpoolsConfigsManagerPayload {
 }
 
 #[derive(Deserialize)]
-pub struct UpdateLaunchpoolsConfigsManagerAuthorityPayload {
+pub struct ProposeLaunchpoolsConfigsManagerAuthorityPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub authority: Pubkey,
     #[serde(deserialize_with = "pubkey_from_str")]
@@ -20,13 +20,37 @@ pub struct UpdateLaunchpoolsConfigsManagerAuthorityPayload {
 }
 
 #[derive(Deserialize)]
-pub struct UpdateLaunchpoolsConfigsManagerHeadAuthorityPayload {
+pub struct ProposeLaunchpoolsConfigsManagerHeadAuthorityPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub head_authority: Pubkey,
     #[serde(deserialize_with = "pubkey_from_str")]
     pub new_head_authority: Pubkey,
 }
 
+#[derive(Deserialize)]
+pub struct AcceptLaunchpoolsConfigsManagerAuthorityPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub new_authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct CancelLaunchpoolsConfigsManagerAuthorityTransferPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct AcceptLaunchpoolsConfigsManagerHeadAuthorityPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub new_head_authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct CancelLaunchpoolsConfigsManagerHeadAuthorityTransferPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub head_authority: Pubkey,
+}
+
 #[derive(Deserialize)]
 pub struct InitializeLaunchpoolsConfigPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
@@ -127,4 +151,27 @@ pub struct CloseStakePositionPayload {
 pub struct CollectProtocolRewardPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub signer: Pubkey,
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize)]
+pub struct InitializeLaunchpoolMigrationPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub authority: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub authority_quote_account: Option<Pubkey>,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub quote_mint: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub amms_config: Pubkey,
+    pub base_share_basis_points: u16,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub quote_amount: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub funding_lamports: u64,
+}
+
+#[derive(Deserialize)]
+pub struct MigrateLaunchpoolToCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+}
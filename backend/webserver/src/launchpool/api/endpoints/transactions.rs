@@ -1,28 +1,35 @@
 use crate::launchpool::api::dto::transactions::parameters::{
     CloseStakePositionParams, CollectProtocolRewardParams, IncreaseStakePositionParams,
-    InitializeLaunchpoolParams, InitializeLaunchpoolsConfigParams, LaunchLaunchpoolParams,
+    InitializeLaunchpoolMigrationParams, InitializeLaunchpoolParams,
+    InitializeLaunchpoolsConfigParams, LaunchLaunchpoolParams, MigrateLaunchpoolToCpAmmParams,
     OpenStakePositionParams, UpdateLaunchpoolsConfigDurationParams,
     UpdateLaunchpoolsConfigPositionSizesParams, UpdateLaunchpoolsConfigProtocolRewardShareParams,
     UpdateLaunchpoolsConfigRewardAuthorityParams,
 };
 use crate::launchpool::api::dto::transactions::payloads::{
     CloseStakePositionPayload, CollectProtocolRewardPayload, IncreaseStakePositionPayload,
-    InitializeLaunchpoolPayload, InitializeLaunchpoolsConfigPayload,
-    InitializeLaunchpoolsConfigsManagerPayload, LaunchLaunchpoolPayload, OpenStakePositionPayload,
+    InitializeLaunchpoolMigrationPayload, InitializeLaunchpoolPayload,
+    InitializeLaunchpoolsConfigPayload, InitializeLaunchpoolsConfigsManagerPayload,
+    LaunchLaunchpoolPayload, MigrateLaunchpoolToCpAmmPayload, OpenStakePositionPayload,
     UpdateLaunchpoolsConfigDurationPayload, UpdateLaunchpoolsConfigPositionSizesPayload,
     UpdateLaunchpoolsConfigProtocolRewardSharePayload,
-    UpdateLaunchpoolsConfigRewardAuthorityPayload, UpdateLaunchpoolsConfigsManagerAuthorityPayload,
-    UpdateLaunchpoolsConfigsManagerHeadAuthorityPayload,
+    UpdateLaunchpoolsConfigRewardAuthorityPayload, ProposeLaunchpoolsConfigsManagerAuthorityPayload,
+    ProposeLaunchpoolsConfigsManagerHeadAuthorityPayload, AcceptLaunchpoolsConfigsManagerAuthorityPayload,
+    AcceptLaunchpoolsConfigsManagerHeadAuthorityPayload, CancelLaunchpoolsConfigsManagerAuthorityTransferPayload,
+    CancelLaunchpoolsConfigsManagerHeadAuthorityTransferPayload,
 };
 use crate::launchpool::context::LaunchpoolContext;
 use crate::launchpool::core::{
     close_stake_position_tx, collect_protocol_reward_tx, increase_stake_position_tx,
-    initialize_launchpool_tx, initialize_launchpools_config_tx,
-    initialize_launchpools_configs_manager_tx, launch_launchpool_tx, open_stake_position_tx,
+    initialize_launchpool_migration_tx, initialize_launchpool_tx, initialize_launchpools_config_tx,
+    initialize_launchpools_configs_manager_tx, launch_launchpool_tx,
+    migrate_launchpool_to_cp_amm_tx, open_stake_position_tx,
     update_launchpools_config_duration_tx, update_launchpools_config_position_sizes_tx,
     update_launchpools_config_protocol_reward_share_tx,
-    update_launchpools_config_reward_authority_tx, update_launchpools_configs_manager_authority_tx,
-    update_launchpools_configs_manager_head_authority_tx,
+    update_launchpools_config_reward_authority_tx, propose_launchpools_configs_manager_authority_tx,
+    propose_launchpools_configs_manager_head_authority_tx, accept_launchpools_configs_manager_authority_tx,
+    accept_launchpools_configs_manager_head_authority_tx, cancel_launchpools_configs_manager_authority_transfer_tx,
+    cancel_launchpools_configs_manager_head_authority_transfer_tx,
 };
 use crate::utils::web::send_result;
 use axum::extract::{Path, State};
@@ -61,40 +68,40 @@ pub async fn get_initialize_launchpools_config_manager_tx(
     send_result(result)
 }
 
-pub async fn get_update_launchpools_configs_manager_authority_tx(
+pub async fn get_propose_launchpools_configs_manager_authority_tx(
     State(context): State<Arc<LaunchpoolContext>>,
-    Json(payload): Json<UpdateLaunchpoolsConfigsManagerAuthorityPayload>,
+    Json(payload): Json<ProposeLaunchpoolsConfigsManagerAuthorityPayload>,
 ) -> impl IntoResponse {
-    let UpdateLaunchpoolsConfigsManagerAuthorityPayload {
+    let ProposeLaunchpoolsConfigsManagerAuthorityPayload {
         authority,
         new_authority,
     } = payload;
     debug!(
         ?authority,
         ?new_authority,
-        "Calling update_launchpools_configs_manager_authority_tx"
+        "Calling propose_launchpools_configs_manager_authority_tx"
     );
     let result =
-        update_launchpools_configs_manager_authority_tx(context.as_ref(), authority, new_authority)
+        propose_launchpools_configs_manager_authority_tx(context.as_ref(), authority, new_authority)
             .await
             .and_then(|tx| tx.to_base64());
     send_result(result)
 }
 
-pub async fn get_update_launchpools_configs_manager_head_authority_tx(
+pub async fn get_propose_launchpools_configs_manager_head_authority_tx(
     State(context): State<Arc<LaunchpoolContext>>,
-    Json(payload): Json<UpdateLaunchpoolsConfigsManagerHeadAuthorityPayload>,
+    Json(payload): Json<ProposeLaunchpoolsConfigsManagerHeadAuthorityPayload>,
 ) -> impl IntoResponse {
-    let UpdateLaunchpoolsConfigsManagerHeadAuthorityPayload {
+    let ProposeLaunchpoolsConfigsManagerHeadAuthorityPayload {
         head_authority,
         new_head_authority,
     } = payload;
     debug!(
         ?head_authority,
         ?new_head_authority,
-        "Calling update_launchpools_configs_manager_head_authority_tx"
+        "Calling propose_launchpools_configs_manager_head_authority_tx"
     );
-    let result = update_launchpools_configs_manager_head_authority_tx(
+    let result = propose_launchpools_configs_manager_head_authority_tx(
         context.as_ref(),
         head_authority,
         new_head_authority,
@@ -104,6 +111,66 @@ pub async fn get_update_launchpools_configs_manager_head_authority_tx(
     send_result(result)
 }
 
+pub async fn get_accept_launchpools_configs_manager_authority_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Json(payload): Json<AcceptLaunchpoolsConfigsManagerAuthorityPayload>,
+) -> impl IntoResponse {
+    let AcceptLaunchpoolsConfigsManagerAuthorityPayload { new_authority } = payload;
+    debug!(
+        ?new_authority,
+        "Calling accept_launchpools_configs_manager_authority_tx"
+    );
+    let result = accept_launchpools_configs_manager_authority_tx(context.as_ref(), new_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub async fn get_cancel_launchpools_configs_manager_authority_transfer_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Json(payload): Json<CancelLaunchpoolsConfigsManagerAuthorityTransferPayload>,
+) -> impl IntoResponse {
+    let CancelLaunchpoolsConfigsManagerAuthorityTransferPayload { authority } = payload;
+    debug!(
+        ?authority,
+        "Calling cancel_launchpools_configs_manager_authority_transfer_tx"
+    );
+    let result = cancel_launchpools_configs_manager_authority_transfer_tx(context.as_ref(), authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub async fn get_accept_launchpools_configs_manager_head_authority_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Json(payload): Json<AcceptLaunchpoolsConfigsManagerHeadAuthorityPayload>,
+) -> impl IntoResponse {
+    let AcceptLaunchpoolsConfigsManagerHeadAuthorityPayload { new_head_authority } = payload;
+    debug!(
+        ?new_head_authority,
+        "Calling accept_launchpools_configs_manager_head_authority_tx"
+    );
+    let result = accept_launchpools_configs_manager_head_authority_tx(context.as_ref(), new_head_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub async fn get_cancel_launchpools_configs_manager_head_authority_transfer_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Json(payload): Json<CancelLaunchpoolsConfigsManagerHeadAuthorityTransferPayload>,
+) -> impl IntoResponse {
+    let CancelLaunchpoolsConfigsManagerHeadAuthorityTransferPayload { head_authority } = payload;
+    debug!(
+        ?head_authority,
+        "Calling cancel_launchpools_configs_manager_head_authority_transfer_tx"
+    );
+    let result = cancel_launchpools_configs_manager_head_authority_transfer_tx(context.as_ref(), head_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
 pub async fn get_initialize_launchpools_config_tx(
     State(context): State<Arc<LaunchpoolContext>>,
     Path(params): Path<InitializeLaunchpoolsConfigParams>,
@@ -417,3 +484,62 @@ pub async fn get_collect_protocol_reward_tx(
         .and_then(|tx| tx.to_base64());
     send_result(result)
 }
+
+pub async fn get_initialize_launchpool_migration_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Path(params): Path<InitializeLaunchpoolMigrationParams>,
+    Json(payload): Json<InitializeLaunchpoolMigrationPayload>,
+) -> impl IntoResponse {
+    let InitializeLaunchpoolMigrationPayload {
+        authority,
+        authority_quote_account,
+        quote_mint,
+        amms_config,
+        base_share_basis_points,
+        quote_amount,
+        funding_lamports,
+    } = payload;
+    let InitializeLaunchpoolMigrationParams { launchpool } = params;
+    debug!(
+        ?authority,
+        ?authority_quote_account,
+        ?quote_mint,
+        ?amms_config,
+        base_share_basis_points,
+        quote_amount,
+        funding_lamports,
+        ?launchpool,
+        "Calling initialize_launchpool_migration_tx"
+    );
+    let result = initialize_launchpool_migration_tx(
+        context.as_ref(),
+        authority,
+        authority_quote_account,
+        launchpool,
+        quote_mint,
+        amms_config,
+        base_share_basis_points,
+        quote_amount,
+        funding_lamports,
+    )
+    .await
+    .and_then(|(tx, cp_amm)| {
+        tx.to_base64()
+            .map(|tx_str| (tx_str, cp_amm.to_string()))
+    });
+    send_result(result)
+}
+
+pub async fn get_migrate_launchpool_to_cp_amm_tx(
+    State(context): State<Arc<LaunchpoolContext>>,
+    Path(params): Path<MigrateLaunchpoolToCpAmmParams>,
+    Json(payload): Json<MigrateLaunchpoolToCpAmmPayload>,
+) -> impl IntoResponse {
+    let MigrateLaunchpoolToCpAmmPayload { signer } = payload;
+    let MigrateLaunchpoolToCpAmmParams { launchpool } = params;
+    debug!(?signer, ?launchpool, "Calling migrate_launchpool_to_cp_amm_tx");
+    let result = migrate_launchpool_to_cp_amm_tx(context.as_ref(), signer, launchpool)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
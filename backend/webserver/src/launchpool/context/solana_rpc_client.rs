@@ -8,7 +8,8 @@ use anyhow::Result as AnyResult;
 use async_trait::async_trait;
 use solana_sdk::program_error::ProgramError;
 use tracing::debug;
-use launchpool::accounts::{Launchpool, LaunchpoolsConfig, LaunchpoolsConfigsManager, StakePosition};
+use launchpool::accounts::{Launchpool, LaunchpoolMigration, LaunchpoolsConfig, LaunchpoolsConfigsManager, StakePosition};
+use liquidity_pool::accounts::{AmmsConfig, CpAmm};
 use launchpool::programs::LAUNCHPOOL_ID;
 use liquidity_pool::programs::LIQUIDITY_POOL_ID;
 use crate::utils::clients::SolanaRpcClient;
@@ -71,6 +72,39 @@ impl LaunchpoolSolanaRpcClient {
         debug!(?stake_position, "Parsed StakePosition");
         Ok(parsed)
     }
+
+    pub async fn fetch_launchpool_migration(&self, launchpool_migration: &Pubkey) -> AnyResult<LaunchpoolMigration> {
+        let account = self.rpc_client.get_account(launchpool_migration).await?;
+        if account.owner != LAUNCHPOOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        debug!(?launchpool_migration, "Fetched LaunchpoolMigration account");
+        let parsed = LaunchpoolMigration::from_bytes(account.data.as_slice())?;
+        debug!(?launchpool_migration, "Parsed LaunchpoolMigration");
+        Ok(parsed)
+    }
+
+    pub async fn fetch_amms_config(&self, amms_config: &Pubkey) -> AnyResult<AmmsConfig> {
+        let account = self.rpc_client.get_account(amms_config).await?;
+        if account.owner != LIQUIDITY_POOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        debug!(?amms_config, "Fetched AmmsConfig account");
+        let parsed = AmmsConfig::from_bytes(account.data.as_slice())?;
+        debug!(?amms_config, "Parsed AmmsConfig");
+        Ok(parsed)
+    }
+
+    pub async fn fetch_cp_amm(&self, cp_amm: &Pubkey) -> AnyResult<CpAmm> {
+        let account = self.rpc_client.get_account(cp_amm).await?;
+        if account.owner != LIQUIDITY_POOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        debug!(?cp_amm, "Fetched CpAmm account");
+        let parsed = CpAmm::from_bytes(account.data.as_slice())?;
+        debug!(?cp_amm, "Parsed CpAmm");
+        Ok(parsed)
+    }
 }
 
 #[async_trait]
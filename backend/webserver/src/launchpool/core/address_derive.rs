@@ -31,4 +31,14 @@ pub fn get_stake_position_vault_pda(stake_position: &Pubkey) -> (Pubkey, u8){
     let seed = b"vault";
     let seeds = &[seed.as_ref(), stake_position.as_ref()];
     Pubkey::find_program_address(seeds, &LAUNCHPOOL_ID)
+}
+pub fn get_launchpool_migration_pda(launchpool: &Pubkey) -> (Pubkey, u8){
+    let seed = b"launchpool_migration";
+    let seeds = &[seed.as_ref(), launchpool.as_ref()];
+    Pubkey::find_program_address(seeds, &LAUNCHPOOL_ID)
+}
+pub fn get_migration_authority_pda(launchpool: &Pubkey) -> (Pubkey, u8){
+    let seed = b"migration_authority";
+    let seeds = &[seed.as_ref(), launchpool.as_ref()];
+    Pubkey::find_program_address(seeds, &LAUNCHPOOL_ID)
 }
\ No newline at end of file
@@ -1,21 +1,32 @@
 use super::address_derive::{
-    get_launchpool_pda, get_launchpool_vault_pda, get_launchpools_config_pda,
-    get_launchpools_configs_manager_pda, get_stake_position_pda, get_stake_position_vault_pda,
+    get_launchpool_migration_pda, get_launchpool_pda, get_launchpool_vault_pda,
+    get_launchpools_config_pda, get_launchpools_configs_manager_pda, get_migration_authority_pda,
+    get_stake_position_pda, get_stake_position_vault_pda,
+};
+use crate::liquidity_pool::core::address_derive::{
+    get_cp_amm_pair_pda, get_cp_amm_pda, get_cp_amm_vault_pda,
 };
 use crate::utils::address_derive::{get_ata, get_program_data};
-use crate::utils::constants::{ASSOCIATED_TOKEN_PROGRAM_ID, RENT, SYSTEM_PROGRAM_ID};
+use crate::utils::constants::{
+    ASSOCIATED_TOKEN_PROGRAM_ID, RENT, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_2022,
+};
 use launchpool::instructions::{
     CloseStakePositionBuilder, CollectProtocolRewardBuilder, IncreaseStakePositionBuilder,
-    InitializeLaunchpoolBuilder, InitializeLaunchpoolsConfigBuilder,
-    InitializeLaunchpoolsConfigsManagerBuilder, LaunchLaunchpoolBuilder, OpenStakePositionBuilder,
+    InitializeLaunchpoolBuilder, InitializeLaunchpoolMigrationBuilder,
+    InitializeLaunchpoolsConfigBuilder, InitializeLaunchpoolsConfigsManagerBuilder,
+    LaunchLaunchpoolBuilder, MigrateLaunchpoolToCpAmmBuilder, OpenStakePositionBuilder,
     UpdateLaunchpoolsConfigDurationBuilder, UpdateLaunchpoolsConfigPositionSizesBuilder,
     UpdateLaunchpoolsConfigProtocolRewardShareBuilder,
-    UpdateLaunchpoolsConfigRewardAuthorityBuilder, UpdateLaunchpoolsConfigsManagerAuthorityBuilder,
-    UpdateLaunchpoolsConfigsManagerHeadAuthorityBuilder,
+    UpdateLaunchpoolsConfigRewardAuthorityBuilder, ProposeLaunchpoolsConfigsManagerAuthorityBuilder,
+    ProposeLaunchpoolsConfigsManagerHeadAuthorityBuilder, AcceptLaunchpoolsConfigsManagerAuthorityBuilder,
+    AcceptLaunchpoolsConfigsManagerHeadAuthorityBuilder, CancelLaunchpoolsConfigsManagerAuthorityTransferBuilder,
+    CancelLaunchpoolsConfigsManagerHeadAuthorityTransferBuilder,
 };
 use launchpool::programs::LAUNCHPOOL_ID;
 use solana_sdk::instruction::Instruction;
+use liquidity_pool::programs::LIQUIDITY_POOL_ID;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
 
 pub fn initialize_launchpools_configs_manager_ix(
     signer: Pubkey,
@@ -34,26 +45,50 @@ pub fn initialize_launchpools_configs_manager_ix(
     builder.system_program(SYSTEM_PROGRAM_ID);
     (builder.instruction(), launchpools_configs_manager)
 }
-pub fn update_launchpools_configs_manager_authority_ix(
+pub fn propose_launchpools_configs_manager_authority_ix(
     authority: Pubkey,
     new_authority: Pubkey,
 ) -> Instruction {
-    let mut builder = UpdateLaunchpoolsConfigsManagerAuthorityBuilder::new();
+    let mut builder = ProposeLaunchpoolsConfigsManagerAuthorityBuilder::new();
     builder.authority(authority);
     builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
     builder.new_authority(new_authority);
     builder.instruction()
 }
-pub fn update_launchpools_configs_manager_head_authority_ix(
+pub fn propose_launchpools_configs_manager_head_authority_ix(
     head_authority: Pubkey,
     new_head_authority: Pubkey,
 ) -> Instruction {
-    let mut builder = UpdateLaunchpoolsConfigsManagerHeadAuthorityBuilder::new();
+    let mut builder = ProposeLaunchpoolsConfigsManagerHeadAuthorityBuilder::new();
     builder.head_authority(head_authority);
     builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
     builder.new_head_authority(new_head_authority);
     builder.instruction()
 }
+pub fn accept_launchpools_configs_manager_authority_ix(new_authority: Pubkey) -> Instruction {
+    let mut builder = AcceptLaunchpoolsConfigsManagerAuthorityBuilder::new();
+    builder.new_authority(new_authority);
+    builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn cancel_launchpools_configs_manager_authority_transfer_ix(authority: Pubkey) -> Instruction {
+    let mut builder = CancelLaunchpoolsConfigsManagerAuthorityTransferBuilder::new();
+    builder.authority(authority);
+    builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn accept_launchpools_configs_manager_head_authority_ix(new_head_authority: Pubkey) -> Instruction {
+    let mut builder = AcceptLaunchpoolsConfigsManagerHeadAuthorityBuilder::new();
+    builder.new_head_authority(new_head_authority);
+    builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn cancel_launchpools_configs_manager_head_authority_transfer_ix(head_authority: Pubkey) -> Instruction {
+    let mut builder = CancelLaunchpoolsConfigsManagerHeadAuthorityTransferBuilder::new();
+    builder.head_authority(head_authority);
+    builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
+    builder.instruction()
+}
 pub fn initialize_launchpools_config_ix(
     authority: Pubkey,
     id: u64,
@@ -275,3 +310,99 @@ pub fn collect_protocol_reward_ix(
     builder.reward_token_program(reward_token_program);
     builder.instruction()
 }
+pub fn initialize_launchpool_migration_ix(
+    authority: Pubkey,
+    authority_quote_account: Option<Pubkey>,
+    lp_mint_keypair: &Keypair,
+    launchpool: Pubkey,
+    reward_mint: Pubkey,
+    quote_mint: Pubkey,
+    amms_config: Pubkey,
+    fee_authority: Pubkey,
+    reward_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    base_share_basis_points: u16,
+    quote_amount: u64,
+    funding_lamports: u64,
+) -> (Instruction, Pubkey) {
+    let mut builder = InitializeLaunchpoolMigrationBuilder::new();
+    let lp_mint = lp_mint_keypair.pubkey();
+    let cp_amm = get_cp_amm_pda(&lp_mint).0;
+    let migration_authority = get_migration_authority_pda(&launchpool).0;
+    builder.authority_quote_account(
+        authority_quote_account.unwrap_or(get_ata(&authority, &quote_mint, &quote_token_program).0),
+    );
+    builder.authority(authority);
+    builder.launchpools_configs_manager(get_launchpools_configs_manager_pda().0);
+    builder.launchpool_migration(get_launchpool_migration_pda(&launchpool).0);
+    builder.migration_authority_quote_account(
+        get_ata(&migration_authority, &quote_mint, &quote_token_program).0,
+    );
+    builder.migration_authority(migration_authority);
+    builder.launchpool(launchpool);
+    builder.fee_authority(fee_authority);
+    builder.amms_config(amms_config);
+    builder.lp_mint(lp_mint);
+    builder.cp_amm(cp_amm);
+    builder.cp_amm_pair(get_cp_amm_pair_pda(&amms_config, &reward_mint, &quote_mint).0);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &reward_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm_locked_lp_vault(get_cp_amm_vault_pda(&cp_amm, &lp_mint).0);
+    builder.reward_mint(reward_mint);
+    builder.quote_mint(quote_mint);
+    builder.rent(RENT);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.lp_token_program(TOKEN_PROGRAM_2022);
+    builder.reward_token_program(reward_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.liquidity_pool_program(LIQUIDITY_POOL_ID);
+    builder.base_share_basis_points(base_share_basis_points);
+    builder.quote_amount(quote_amount);
+    builder.funding_lamports(funding_lamports);
+    (builder.instruction(), cp_amm)
+}
+pub fn migrate_launchpool_to_cp_amm_ix(
+    signer: Pubkey,
+    launchpool: Pubkey,
+    reward_mint: Pubkey,
+    quote_mint: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    lp_mint: Pubkey,
+    reward_token_program: Pubkey,
+    quote_token_program: Pubkey,
+) -> Instruction {
+    let mut builder = MigrateLaunchpoolToCpAmmBuilder::new();
+    let migration_authority = get_migration_authority_pda(&launchpool).0;
+    builder.signer(signer);
+    builder.reward_vault(get_launchpool_vault_pda(&launchpool).0);
+    builder.launchpool_migration(get_launchpool_migration_pda(&launchpool).0);
+    builder.migration_authority_base_account(
+        get_ata(&migration_authority, &reward_mint, &reward_token_program).0,
+    );
+    builder.migration_authority_quote_account(
+        get_ata(&migration_authority, &quote_mint, &quote_token_program).0,
+    );
+    builder.migration_authority_lp_account(
+        get_ata(&migration_authority, &lp_mint, &TOKEN_PROGRAM_2022).0,
+    );
+    builder.migration_authority(migration_authority);
+    builder.launchpool(launchpool);
+    builder.reward_mint(reward_mint);
+    builder.quote_mint(quote_mint);
+    builder.lp_mint(lp_mint);
+    builder.amms_config(amms_config);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &reward_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm_locked_lp_vault(get_cp_amm_vault_pda(&cp_amm, &lp_mint).0);
+    builder.cp_amm(cp_amm);
+    builder.rent(RENT);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.lp_token_program(TOKEN_PROGRAM_2022);
+    builder.reward_token_program(reward_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.liquidity_pool_program(LIQUIDITY_POOL_ID);
+    builder.instruction()
+}
@@ -1,17 +1,27 @@
 use crate::launchpool::context::LaunchpoolContext;
+use crate::launchpool::core::address_derive::{
+    get_launchpool_migration_pda, get_launchpool_vault_pda, get_migration_authority_pda,
+    get_stake_position_vault_pda,
+};
 use crate::launchpool::core::instructions::{
     close_stake_position_ix, collect_protocol_reward_ix, increase_stake_position_ix,
-    initialize_launchpool_ix, initialize_launchpools_config_ix,
-    initialize_launchpools_configs_manager_ix, launch_launchpool_ix, open_stake_position_ix,
+    initialize_launchpool_ix, initialize_launchpool_migration_ix, initialize_launchpools_config_ix,
+    initialize_launchpools_configs_manager_ix, launch_launchpool_ix,
+    migrate_launchpool_to_cp_amm_ix, open_stake_position_ix,
     update_launchpools_config_duration_ix, update_launchpools_config_position_sizes_ix,
     update_launchpools_config_protocol_reward_share_ix,
-    update_launchpools_config_reward_authority_ix, update_launchpools_configs_manager_authority_ix,
-    update_launchpools_configs_manager_head_authority_ix,
+    update_launchpools_config_reward_authority_ix, propose_launchpools_configs_manager_authority_ix,
+    propose_launchpools_configs_manager_head_authority_ix, accept_launchpools_configs_manager_authority_ix,
+    accept_launchpools_configs_manager_head_authority_ix, cancel_launchpools_configs_manager_authority_transfer_ix,
+    cancel_launchpools_configs_manager_head_authority_transfer_ix,
 };
+use crate::liquidity_pool::core::address_derive::get_cp_amm_vault_pda;
+use crate::utils::address_derive::get_ata;
 use crate::utils::clients::{ProgramContext, SolanaRpcClient};
-use crate::utils::types::{build_unsigned_transaction, UnsignedTransaction};
+use crate::utils::types::{build_unsigned_transaction, TokenTransfer, UnsignedTransaction};
 use anyhow::Result as AnyResult;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 
 pub async fn initialize_launchpools_configs_manager_tx(
     context: &LaunchpoolContext,
@@ -23,23 +33,23 @@ pub async fn initialize_launchpools_configs_manager_tx(
     let (ix, launchpools_configs_manager_pubkey) = initialize_launchpools_configs_manager_ix(signer, authority, head_authority);
     Ok((build_unsigned_transaction(&signer, [ix], blockhash, []), launchpools_configs_manager_pubkey))
 }
-pub async fn update_launchpools_configs_manager_authority_tx(
+pub async fn propose_launchpools_configs_manager_authority_tx(
     context: &LaunchpoolContext,
     authority: Pubkey,
     new_authority: Pubkey,
 ) -> AnyResult<UnsignedTransaction> {
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = update_launchpools_configs_manager_authority_ix(authority, new_authority);
+    let ix = propose_launchpools_configs_manager_authority_ix(authority, new_authority);
     Ok(build_unsigned_transaction(&authority, [ix], blockhash, []))
 }
-pub async fn update_launchpools_configs_manager_head_authority_tx(
+pub async fn propose_launchpools_configs_manager_head_authority_tx(
     context: &LaunchpoolContext,
     head_authority: Pubkey,
     new_head_authority: Pubkey,
 ) -> AnyResult<UnsignedTransaction> {
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
     let ix =
-        update_launchpools_configs_manager_head_authority_ix(head_authority, new_head_authority);
+        propose_launchpools_configs_manager_head_authority_ix(head_authority, new_head_authority);
     Ok(build_unsigned_transaction(
         &head_authority,
         [ix],
@@ -47,6 +57,38 @@ pub async fn update_launchpools_configs_manager_head_authority_tx(
         [],
     ))
 }
+pub async fn accept_launchpools_configs_manager_authority_tx(
+    context: &LaunchpoolContext,
+    new_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = accept_launchpools_configs_manager_authority_ix(new_authority);
+    Ok(build_unsigned_transaction(&new_authority, [ix], blockhash, []))
+}
+pub async fn cancel_launchpools_configs_manager_authority_transfer_tx(
+    context: &LaunchpoolContext,
+    authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = cancel_launchpools_configs_manager_authority_transfer_ix(authority);
+    Ok(build_unsigned_transaction(&authority, [ix], blockhash, []))
+}
+pub async fn accept_launchpools_configs_manager_head_authority_tx(
+    context: &LaunchpoolContext,
+    new_head_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = accept_launchpools_configs_manager_head_authority_ix(new_head_authority);
+    Ok(build_unsigned_transaction(&new_head_authority, [ix], blockhash, []))
+}
+pub async fn cancel_launchpools_configs_manager_head_authority_transfer_tx(
+    context: &LaunchpoolContext,
+    head_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = cancel_launchpools_configs_manager_head_authority_transfer_ix(head_authority);
+    Ok(build_unsigned_transaction(&head_authority, [ix], blockhash, []))
+}
 pub async fn initialize_launchpools_config_tx(
     context: &LaunchpoolContext,
     authority: Pubkey,
@@ -183,7 +225,7 @@ pub async fn open_stake_position_tx(
         .get_token_mint(&launchpools_config_keys.stakable_mint)
         .await?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let (ix, stake_position_pubkey) = open_stake_position_ix(
+    let (mut ix, stake_position_pubkey) = open_stake_position_ix(
         signer,
         signer_stakable_account,
         launchpool_keys.launchpools_config,
@@ -192,6 +234,21 @@ pub async fn open_stake_position_tx(
         *stakable_token_account.program(),
         stake_amount,
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    launchpools_config_keys.stakable_mint,
+                    &stakable_token_account,
+                    signer_stakable_account.unwrap_or(get_ata(&signer, &launchpools_config_keys.stakable_mint, stakable_token_account.program()).0),
+                    get_stake_position_vault_pda(&stake_position_pubkey).0,
+                    signer,
+                ),
+            ],
+        )
+        .await?;
     Ok((build_unsigned_transaction(&signer, [ix], blockhash, []), stake_position_pubkey))
 }
 
@@ -213,7 +270,7 @@ pub async fn increase_stake_position_tx(
         .get_token_mint(&launchpools_config_keys.stakable_mint)
         .await?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = increase_stake_position_ix(
+    let mut ix = increase_stake_position_ix(
         signer,
         signer_stakable_account,
         launchpool_keys.launchpools_config,
@@ -223,6 +280,21 @@ pub async fn increase_stake_position_tx(
         *stakable_token_account.program(),
         stake_increase_amount,
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    launchpools_config_keys.stakable_mint,
+                    &stakable_token_account,
+                    signer_stakable_account.unwrap_or(get_ata(&signer, &launchpools_config_keys.stakable_mint, stakable_token_account.program()).0),
+                    get_stake_position_vault_pda(&stake_position).0,
+                    signer,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
 }
 
@@ -244,7 +316,7 @@ pub async fn close_stake_position_tx(
         context.get_token_mint(&launchpool_keys.reward_mint),
     )?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = close_stake_position_ix(
+    let mut ix = close_stake_position_ix(
         signer,
         signer_stakable_account,
         launchpool_keys.launchpools_config,
@@ -255,6 +327,28 @@ pub async fn close_stake_position_tx(
         *stakable_token_account.program(),
         *reward_token_account.program(),
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    launchpools_config_keys.stakable_mint,
+                    &stakable_token_account,
+                    get_stake_position_vault_pda(&stake_position).0,
+                    signer_stakable_account.unwrap_or(get_ata(&signer, &launchpools_config_keys.stakable_mint, stakable_token_account.program()).0),
+                    stake_position,
+                ),
+                TokenTransfer::new(
+                    launchpool_keys.reward_mint,
+                    &reward_token_account,
+                    get_launchpool_vault_pda(&stake_position_keys.launchpool).0,
+                    get_ata(&signer, &launchpool_keys.reward_mint, reward_token_account.program()).0,
+                    stake_position_keys.launchpool,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
 }
 
@@ -270,7 +364,7 @@ pub async fn collect_protocol_reward_tx(
         context.get_token_mint(&launchpool_keys.reward_mint)
     )?;
     let blockhash = solana_rpc_client.get_blockhash().await?;
-    let ix = collect_protocol_reward_ix(
+    let mut ix = collect_protocol_reward_ix(
         signer,
         launchpools_config_account.reward_authority,
         launchpool_keys.launchpools_config,
@@ -278,5 +372,138 @@ pub async fn collect_protocol_reward_tx(
         launchpool,
         *reward_mint_account.program(),
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    launchpool_keys.reward_mint,
+                    &reward_mint_account,
+                    get_launchpool_vault_pda(&launchpool).0,
+                    get_ata(&launchpools_config_account.reward_authority, &launchpool_keys.reward_mint, reward_mint_account.program()).0,
+                    launchpool,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
+}
+
+pub async fn initialize_launchpool_migration_tx(
+    context: &LaunchpoolContext,
+    authority: Pubkey,
+    authority_quote_account: Option<Pubkey>,
+    launchpool: Pubkey,
+    quote_mint: Pubkey,
+    amms_config: Pubkey,
+    base_share_basis_points: u16,
+    quote_amount: u64,
+    funding_lamports: u64,
+) -> AnyResult<(UnsignedTransaction, Pubkey)> {
+    let lp_mint_keypair = Keypair::new();
+    let launchpool_keys = context.get_launchpool_keys(&launchpool).await?;
+    let solana_rpc_client = context.solana_rpc_client();
+    let (amms_config_account, reward_mint_account, quote_mint_account) = tokio::try_join!(
+        solana_rpc_client.fetch_amms_config(&amms_config),
+        context.get_token_mint(&launchpool_keys.reward_mint),
+        context.get_token_mint(&quote_mint),
+    )?;
+    let blockhash = solana_rpc_client.get_blockhash().await?;
+    let (mut ix, cp_amm) = initialize_launchpool_migration_ix(
+        authority,
+        authority_quote_account,
+        &lp_mint_keypair,
+        launchpool,
+        launchpool_keys.reward_mint,
+        quote_mint,
+        amms_config,
+        amms_config_account.fee_authority,
+        *reward_mint_account.program(),
+        *quote_mint_account.program(),
+        base_share_basis_points,
+        quote_amount,
+        funding_lamports,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    quote_mint,
+                    &quote_mint_account,
+                    authority_quote_account.unwrap_or(get_ata(&authority, &quote_mint, quote_mint_account.program()).0),
+                    get_ata(&get_migration_authority_pda(&launchpool).0, &quote_mint, quote_mint_account.program()).0,
+                    authority,
+                ),
+            ],
+        )
+        .await?;
+    Ok((
+        build_unsigned_transaction(&authority, [ix], blockhash, [&lp_mint_keypair]),
+        cp_amm,
+    ))
+}
+
+pub async fn migrate_launchpool_to_cp_amm_tx(
+    context: &LaunchpoolContext,
+    signer: Pubkey,
+    launchpool: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let launchpool_keys = context.get_launchpool_keys(&launchpool).await?;
+    let solana_rpc_client = context.solana_rpc_client();
+    let launchpool_migration_account = solana_rpc_client
+        .fetch_launchpool_migration(&get_launchpool_migration_pda(&launchpool).0)
+        .await?;
+    let (cp_amm_account, reward_mint_account, quote_mint_account) = tokio::try_join!(
+        solana_rpc_client.fetch_cp_amm(&launchpool_migration_account.cp_amm),
+        context.get_token_mint(&launchpool_keys.reward_mint),
+        context.get_token_mint(&launchpool_migration_account.quote_mint),
+    )?;
+    let blockhash = solana_rpc_client.get_blockhash().await?;
+    let mut ix = migrate_launchpool_to_cp_amm_ix(
+        signer,
+        launchpool,
+        launchpool_keys.reward_mint,
+        launchpool_migration_account.quote_mint,
+        cp_amm_account.amms_config,
+        launchpool_migration_account.cp_amm,
+        cp_amm_account.lp_mint,
+        *reward_mint_account.program(),
+        *quote_mint_account.program(),
+    );
+    let migration_authority = get_migration_authority_pda(&launchpool).0;
+    let cp_amm = launchpool_migration_account.cp_amm;
+    let quote_mint = launchpool_migration_account.quote_mint;
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    launchpool_keys.reward_mint,
+                    &reward_mint_account,
+                    get_launchpool_vault_pda(&launchpool).0,
+                    get_ata(&migration_authority, &launchpool_keys.reward_mint, reward_mint_account.program()).0,
+                    launchpool,
+                ),
+                TokenTransfer::new(
+                    launchpool_keys.reward_mint,
+                    &reward_mint_account,
+                    get_ata(&migration_authority, &launchpool_keys.reward_mint, reward_mint_account.program()).0,
+                    get_cp_amm_vault_pda(&cp_amm, &launchpool_keys.reward_mint).0,
+                    migration_authority,
+                ),
+                TokenTransfer::new(
+                    quote_mint,
+                    &quote_mint_account,
+                    get_ata(&migration_authority, &quote_mint, quote_mint_account.program()).0,
+                    get_cp_amm_vault_pda(&cp_amm, &quote_mint).0,
+                    migration_authority,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
 }
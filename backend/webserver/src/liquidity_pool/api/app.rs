@@ -9,52 +9,103 @@ use crate::liquidity_pool::context::LiquidityPoolContext;
 pub struct LiquidityPoolRoutes {
     pub scope_path: String,
     pub initialize_config_manager: String,
-    pub update_manager_authority: String,
-    pub update_manager_head_authority: String,
+    pub propose_manager_authority: String,
+    pub propose_manager_head_authority: String,
+    pub accept_manager_authority: String,
+    pub accept_manager_head_authority: String,
+    pub cancel_manager_authority_transfer: String,
+    pub cancel_manager_head_authority_transfer: String,
     pub initialize_config: String,
     pub update_fee_authority: String,
     pub update_protocol_fee_rate: String,
     pub update_providers_fee_rate: String,
     pub initialize_cp_amm: String,
     pub launch_cp_amm: String,
+    pub launch_cp_amm_with_lp_lock: String,
     pub provide: String,
     pub withdraw: String,
     pub swap: String,
+    pub routed_swap: String,
+    pub zap_in: String,
+    pub zap_out: String,
     pub collect_fees: String,
+    pub claim_fees: String,
+    pub sweep_fees: String,
+    pub sync: String,
+    pub skim: String,
+    pub launch_stable_amm: String,
+    pub provide_to_stable_amm: String,
+    pub withdraw_from_stable_amm: String,
+    pub provide_to_cl_amm: String,
+    pub withdraw_from_cl_amm: String,
 }
 
 impl LiquidityPoolRoutes {
     pub fn new(
         scope_path: String,
         initialize_config_manager: String,
-        update_manager_authority: String,
-        update_manager_head_authority: String,
+        propose_manager_authority: String,
+        propose_manager_head_authority: String,
+        accept_manager_authority: String,
+        accept_manager_head_authority: String,
+        cancel_manager_authority_transfer: String,
+        cancel_manager_head_authority_transfer: String,
         initialize_config: String,
         update_fee_authority: String,
         update_protocol_fee_rate: String,
         update_providers_fee_rate: String,
         initialize_cp_amm: String,
         launch_cp_amm: String,
+        launch_cp_amm_with_lp_lock: String,
         provide: String,
         withdraw: String,
         swap: String,
+        routed_swap: String,
+        zap_in: String,
+        zap_out: String,
         collect_fees: String,
+        claim_fees: String,
+        sweep_fees: String,
+        sync: String,
+        skim: String,
+        launch_stable_amm: String,
+        provide_to_stable_amm: String,
+        withdraw_from_stable_amm: String,
+        provide_to_cl_amm: String,
+        withdraw_from_cl_amm: String,
     ) -> Self {
         Self {
             scope_path,
             initialize_config_manager,
-            update_manager_authority,
-            update_manager_head_authority,
+            propose_manager_authority,
+            propose_manager_head_authority,
+            accept_manager_authority,
+            accept_manager_head_authority,
+            cancel_manager_authority_transfer,
+            cancel_manager_head_authority_transfer,
             initialize_config,
             update_fee_authority,
             update_protocol_fee_rate,
             update_providers_fee_rate,
             initialize_cp_amm,
             launch_cp_amm,
+            launch_cp_amm_with_lp_lock,
             provide,
             withdraw,
             swap,
+            routed_swap,
+            zap_in,
+            zap_out,
             collect_fees,
+            claim_fees,
+            sweep_fees,
+            sync,
+            skim,
+            launch_stable_amm,
+            provide_to_stable_amm,
+            withdraw_from_stable_amm,
+            provide_to_cl_amm,
+            withdraw_from_cl_amm,
         }
     }
 }
@@ -75,18 +126,38 @@ impl LiquidityPoolApp {
 
         let scoped = Router::new()
             .route(&r.initialize_config_manager, post(get_initialize_amms_configs_manager_tx))
-            .route(&r.update_manager_authority, post(get_update_amms_configs_manager_authority_tx))
-            .route(&r.update_manager_head_authority, post(get_update_amms_configs_manager_head_authority_tx))
+            .route(&r.propose_manager_authority, post(get_propose_amms_configs_manager_authority_tx))
+            .route(&r.propose_manager_head_authority, post(get_propose_amms_configs_manager_head_authority_tx))
+            .route(&r.accept_manager_authority, post(get_accept_amms_configs_manager_authority_tx))
+            .route(&r.accept_manager_head_authority, post(get_accept_amms_configs_manager_head_authority_tx))
+            .route(&r.cancel_manager_authority_transfer, post(get_cancel_amms_configs_manager_authority_transfer_tx))
+            .route(&r.cancel_manager_head_authority_transfer, post(get_cancel_amms_configs_manager_head_authority_transfer_tx))
             .route(&r.initialize_config, post(get_initialize_amms_config_tx))
             .route(&r.update_fee_authority, post(get_update_amms_config_fee_authority_tx))
             .route(&r.update_protocol_fee_rate, post(get_update_amms_config_protocol_fee_rate_tx))
             .route(&r.update_providers_fee_rate, post(get_update_amms_config_providers_fee_rate_tx))
             .route(&r.initialize_cp_amm, post(get_initialize_cp_amm_tx))
             .route(&r.launch_cp_amm, post(get_launch_cp_amm_tx))
+            .route(&r.launch_cp_amm_with_lp_lock, post(get_launch_cp_amm_with_lp_lock_tx))
             .route(&r.provide, post(get_provide_to_cp_amm_tx))
             .route(&r.withdraw, post(get_withdraw_from_cp_amm_tx))
             .route(&r.swap, post(get_swap_in_cp_amm_tx))
+            .route(&r.routed_swap, post(get_routed_swap_in_cp_amms_tx))
+            .route(&r.zap_in, post(get_zap_in_to_cp_amm_tx))
+            .route(&r.zap_out, post(get_zap_out_from_cp_amm_tx))
             .route(&r.collect_fees, post(get_collect_fees_from_cp_amm_tx))
+            .route(&r.claim_fees, post(get_claim_fees_from_cp_amm_tx))
+            .route(&r.sweep_fees, post(get_sweep_fees_from_cp_amms_tx))
+            .route(&r.sync, post(get_sync_cp_amm_tx))
+            .route(&r.skim, post(get_skim_cp_amm_tx))
+            .route(&r.launch_stable_amm, post(get_launch_stable_amm_tx))
+            .route(&r.provide_to_stable_amm, post(get_provide_to_stable_amm_tx))
+            .route(
+                &r.withdraw_from_stable_amm,
+                post(get_withdraw_from_stable_amm_tx),
+            )
+            .route(&r.provide_to_cl_amm, post(get_provide_to_cl_amm_tx))
+            .route(&r.withdraw_from_cl_amm, post(get_withdraw_from_cl_amm_tx))
             .with_state(state);
 
         Router::new()
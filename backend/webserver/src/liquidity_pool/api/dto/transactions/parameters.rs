@@ -56,8 +56,74 @@ pub struct SwapInCpAmmParams {
     pub cp_amm: Pubkey,
 }
 
+#[derive(Deserialize)]
+pub struct ZapInToCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cp_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct ZapOutFromCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cp_amm: Pubkey,
+}
+
 #[derive(Deserialize)]
 pub struct CollectFeesFromCpAmmParams {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub cp_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct ClaimFeesFromCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cp_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct SweepFeesFromCpAmmsParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub amms_config: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct SyncCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cp_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct SkimCpAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cp_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct LaunchStableAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub stable_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct ProvideToStableAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub stable_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawFromStableAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub stable_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct ProvideToClAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cl_amm: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawFromClAmmParams {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub cl_amm: Pubkey,
 }
\ No newline at end of file
@@ -12,7 +12,7 @@ pub struct InitializeAmmsConfigsManagerPayload {
 }
 
 #[derive(Deserialize)]
-pub struct UpdateAmmsConfigsManagerAuthorityPayload {
+pub struct ProposeAmmsConfigsManagerAuthorityPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub authority: Pubkey,
     #[serde(deserialize_with = "pubkey_from_str")]
@@ -20,13 +20,37 @@ pub struct UpdateAmmsConfigsManagerAuthorityPayload {
 }
 
 #[derive(Deserialize)]
-pub struct UpdateAmmsConfigsManagerHeadAuthorityPayload {
+pub struct ProposeAmmsConfigsManagerHeadAuthorityPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub head_authority: Pubkey,
     #[serde(deserialize_with = "pubkey_from_str")]
     pub new_head_authority: Pubkey,
 }
 
+#[derive(Deserialize)]
+pub struct AcceptAmmsConfigsManagerAuthorityPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub new_authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct CancelAmmsConfigsManagerAuthorityTransferPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct AcceptAmmsConfigsManagerHeadAuthorityPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub new_head_authority: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct CancelAmmsConfigsManagerHeadAuthorityTransferPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub head_authority: Pubkey,
+}
+
 #[derive(Deserialize)]
 pub struct InitializeAmmsConfigPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
@@ -81,6 +105,29 @@ pub struct LaunchCpAmmPayload {
     pub base_liquidity: u64,
     #[serde(deserialize_with = "u64_from_str")]
     pub quote_liquidity: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct LaunchCpAmmWithLpLockPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub creator: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub creator_base_account: Option<Pubkey>,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub creator_quote_account: Option<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub base_liquidity: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub quote_liquidity: u64,
+    pub locked_lp_share_basis_points: u16,
+    #[serde(deserialize_with = "i64_from_str")]
+    pub cliff_timestamp: i64,
+    #[serde(deserialize_with = "i64_from_str")]
+    pub end_timestamp: i64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -95,6 +142,10 @@ pub struct ProvideToCpAmmPayload {
     pub base_liquidity: u64,
     #[serde(deserialize_with = "u64_from_str")]
     pub quote_liquidity: u64,
+    #[serde(default, deserialize_with = "u64_from_str")]
+    pub min_lp_tokens: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -105,6 +156,12 @@ pub struct WithdrawFromCpAmmPayload {
     pub signer_lp_account: Option<Pubkey>,
     #[serde(deserialize_with = "u64_from_str")]
     pub lp_tokens: u64,
+    #[serde(default, deserialize_with = "u64_from_str")]
+    pub min_base_out: u64,
+    #[serde(default, deserialize_with = "u64_from_str")]
+    pub min_quote_out: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
 }
 
 #[derive(Deserialize)]
@@ -118,10 +175,174 @@ pub struct SwapInCpAmmPayload {
     #[serde(deserialize_with = "u64_from_str")]
     pub allowed_slippage: u64,
     pub is_in_out: bool,
+    #[serde(default, deserialize_with = "option_pubkey_from_str")]
+    pub referrer_account: Option<Pubkey>,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct RoutedSwapInCpAmmsPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub input_mint: Pubkey,
+    #[serde(deserialize_with = "vec_pubkey_from_str")]
+    pub route: Vec<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub swap_amount: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub min_result: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct ZapInToCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub zap_amount: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub min_lp_tokens: u64,
+    pub is_base_in: bool,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct ZapOutFromCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_lp_account: Option<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub lp_tokens: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub min_result: u64,
+    pub is_base_out: bool,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
 }
 
 #[derive(Deserialize)]
 pub struct CollectFeesFromCpAmmPayload {
     #[serde(deserialize_with = "pubkey_from_str")]
     pub signer: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct ClaimFeesFromCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub fee_authority: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub destination: Pubkey,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub base_fees: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub quote_fees: u64,
+}
+
+#[derive(Deserialize)]
+pub struct SweepFeesFromCpAmmsPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub fee_authority: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub destination: Pubkey,
+    #[serde(deserialize_with = "vec_pubkey_from_str")]
+    pub cp_amms: Vec<Pubkey>,
+}
+
+#[derive(Deserialize)]
+pub struct SyncCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct SkimCpAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub fee_authority: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub destination: Pubkey,
+}
+
+#[derive(Deserialize)]
+pub struct LaunchStableAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub creator: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub creator_base_account: Option<Pubkey>,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub creator_quote_account: Option<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub base_liquidity: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub quote_liquidity: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct ProvideToStableAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_base_account: Option<Pubkey>,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_quote_account: Option<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub base_liquidity: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub quote_liquidity: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawFromStableAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_lp_account: Option<Pubkey>,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub lp_tokens: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct ProvideToClAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_base_account: Option<Pubkey>,
+    #[serde(deserialize_with = "option_pubkey_from_str")]
+    pub signer_quote_account: Option<Pubkey>,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub position: Pubkey,
+    #[serde(deserialize_with = "u128_from_str")]
+    pub liquidity: u128,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub max_base_amount: u64,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub max_quote_amount: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawFromClAmmPayload {
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub signer: Pubkey,
+    #[serde(deserialize_with = "pubkey_from_str")]
+    pub position: Pubkey,
+    #[serde(deserialize_with = "u128_from_str")]
+    pub liquidity: u128,
+    #[serde(default, deserialize_with = "u64_from_str")]
+    pub min_base_amount: u64,
+    #[serde(default, deserialize_with = "u64_from_str")]
+    pub min_quote_amount: u64,
+    #[serde(default, deserialize_with = "option_i64_from_str")]
+    pub deadline: Option<i64>,
 }
\ No newline at end of file
@@ -28,35 +28,79 @@ pub(crate) async fn get_initialize_amms_configs_manager_tx(
     send_result(result)
 }
 
-pub(crate) async fn get_update_amms_configs_manager_authority_tx(
+pub(crate) async fn get_propose_amms_configs_manager_authority_tx(
     State(context): State<Arc<LiquidityPoolContext>>,
-    Json(payload): Json<UpdateAmmsConfigsManagerAuthorityPayload>,
+    Json(payload): Json<ProposeAmmsConfigsManagerAuthorityPayload>,
 ) -> impl IntoResponse {
-    let UpdateAmmsConfigsManagerAuthorityPayload {
+    let ProposeAmmsConfigsManagerAuthorityPayload {
         authority,
         new_authority,
     } = payload;
-    let result = update_amms_configs_manager_authority_tx(&context, authority, new_authority)
+    let result = propose_amms_configs_manager_authority_tx(&context, authority, new_authority)
         .await
         .and_then(|tx| tx.to_base64());
     send_result(result)
 }
 
-pub(crate) async fn get_update_amms_configs_manager_head_authority_tx(
+pub(crate) async fn get_propose_amms_configs_manager_head_authority_tx(
     State(context): State<Arc<LiquidityPoolContext>>,
-    Json(payload): Json<UpdateAmmsConfigsManagerHeadAuthorityPayload>,
+    Json(payload): Json<ProposeAmmsConfigsManagerHeadAuthorityPayload>,
 ) -> impl IntoResponse {
-    let UpdateAmmsConfigsManagerHeadAuthorityPayload {
+    let ProposeAmmsConfigsManagerHeadAuthorityPayload {
         head_authority,
         new_head_authority,
     } = payload;
     let result =
-        update_amms_configs_manager_head_authority_tx(&context, head_authority, new_head_authority)
+        propose_amms_configs_manager_head_authority_tx(&context, head_authority, new_head_authority)
             .await
             .and_then(|tx| tx.to_base64());
     send_result(result)
 }
 
+pub(crate) async fn get_accept_amms_configs_manager_authority_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Json(payload): Json<AcceptAmmsConfigsManagerAuthorityPayload>,
+) -> impl IntoResponse {
+    let AcceptAmmsConfigsManagerAuthorityPayload { new_authority } = payload;
+    let result = accept_amms_configs_manager_authority_tx(&context, new_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_cancel_amms_configs_manager_authority_transfer_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Json(payload): Json<CancelAmmsConfigsManagerAuthorityTransferPayload>,
+) -> impl IntoResponse {
+    let CancelAmmsConfigsManagerAuthorityTransferPayload { authority } = payload;
+    let result = cancel_amms_configs_manager_authority_transfer_tx(&context, authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_accept_amms_configs_manager_head_authority_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Json(payload): Json<AcceptAmmsConfigsManagerHeadAuthorityPayload>,
+) -> impl IntoResponse {
+    let AcceptAmmsConfigsManagerHeadAuthorityPayload { new_head_authority } = payload;
+    let result = accept_amms_configs_manager_head_authority_tx(&context, new_head_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_cancel_amms_configs_manager_head_authority_transfer_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Json(payload): Json<CancelAmmsConfigsManagerHeadAuthorityTransferPayload>,
+) -> impl IntoResponse {
+    let CancelAmmsConfigsManagerHeadAuthorityTransferPayload { head_authority } = payload;
+    let result = cancel_amms_configs_manager_head_authority_transfer_tx(&context, head_authority)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
 pub(crate) async fn get_initialize_amms_config_tx(
     State(context): State<Arc<LiquidityPoolContext>>,
     Path(params): Path<InitializeAmmsConfigParams>,
@@ -174,6 +218,7 @@ pub(crate) async fn get_launch_cp_amm_tx(
         creator_quote_account,
         base_liquidity,
         quote_liquidity,
+        deadline,
     } = payload;
     let LaunchCpAmmParams { cp_amm } = params;
     let result = launch_cp_amm_tx(
@@ -184,6 +229,42 @@ pub(crate) async fn get_launch_cp_amm_tx(
         cp_amm,
         base_liquidity,
         quote_liquidity,
+        deadline,
+    )
+    .await
+    .and_then(|(tx, cp_amm)| tx.to_base64().map(|tx_str| (tx_str, cp_amm.to_string())));
+    send_result(result)
+}
+
+pub(crate) async fn get_launch_cp_amm_with_lp_lock_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<LaunchCpAmmParams>,
+    Json(payload): Json<LaunchCpAmmWithLpLockPayload>,
+) -> impl IntoResponse {
+    let LaunchCpAmmWithLpLockPayload {
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        base_liquidity,
+        quote_liquidity,
+        locked_lp_share_basis_points,
+        cliff_timestamp,
+        end_timestamp,
+        deadline,
+    } = payload;
+    let LaunchCpAmmParams { cp_amm } = params;
+    let result = launch_cp_amm_with_lp_lock_tx(
+        &context,
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        cp_amm,
+        base_liquidity,
+        quote_liquidity,
+        locked_lp_share_basis_points,
+        cliff_timestamp,
+        end_timestamp,
+        deadline,
     )
     .await
     .and_then(|(tx, cp_amm)| tx.to_base64().map(|tx_str| (tx_str, cp_amm.to_string())));
@@ -201,6 +282,8 @@ pub(crate) async fn get_provide_to_cp_amm_tx(
         signer_quote_account,
         base_liquidity,
         quote_liquidity,
+        min_lp_tokens,
+        deadline,
     } = payload;
     let ProvideToCpAmmParams { cp_amm } = params;
     let result = provide_to_cp_amm_tx(
@@ -211,6 +294,8 @@ pub(crate) async fn get_provide_to_cp_amm_tx(
         cp_amm,
         base_liquidity,
         quote_liquidity,
+        min_lp_tokens,
+        deadline,
     )
     .await
     .and_then(|tx| tx.to_base64());
@@ -226,11 +311,23 @@ pub(crate) async fn get_withdraw_from_cp_amm_tx(
         signer,
         signer_lp_account,
         lp_tokens,
+        min_base_out,
+        min_quote_out,
+        deadline,
     } = payload;
     let WithdrawFromCpAmmParams { cp_amm } = params;
-    let result = withdraw_from_cp_amm_tx(&context, signer, signer_lp_account, cp_amm, lp_tokens)
-        .await
-        .and_then(|tx| tx.to_base64());
+    let result = withdraw_from_cp_amm_tx(
+        &context,
+        signer,
+        signer_lp_account,
+        cp_amm,
+        lp_tokens,
+        min_base_out,
+        min_quote_out,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
     send_result(result)
 }
 
@@ -245,6 +342,8 @@ pub(crate) async fn get_swap_in_cp_amm_tx(
         estimated_result,
         allowed_slippage,
         is_in_out,
+        referrer_account,
+        deadline,
     } = payload;
     let SwapInCpAmmParams { cp_amm } = params;
     let result = swap_in_cp_amm_tx(
@@ -255,6 +354,90 @@ pub(crate) async fn get_swap_in_cp_amm_tx(
         estimated_result,
         allowed_slippage,
         is_in_out,
+        referrer_account,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_routed_swap_in_cp_amms_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Json(payload): Json<RoutedSwapInCpAmmsPayload>,
+) -> impl IntoResponse {
+    let RoutedSwapInCpAmmsPayload {
+        signer,
+        input_mint,
+        route,
+        swap_amount,
+        min_result,
+        deadline,
+    } = payload;
+    let result = routed_swap_in_cp_amms_tx(
+        &context,
+        signer,
+        input_mint,
+        route,
+        swap_amount,
+        min_result,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_zap_in_to_cp_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<ZapInToCpAmmParams>,
+    Json(payload): Json<ZapInToCpAmmPayload>,
+) -> impl IntoResponse {
+    let ZapInToCpAmmPayload {
+        signer,
+        zap_amount,
+        min_lp_tokens,
+        is_base_in,
+        deadline,
+    } = payload;
+    let ZapInToCpAmmParams { cp_amm } = params;
+    let result = zap_in_to_cp_amm_tx(
+        &context,
+        signer,
+        cp_amm,
+        zap_amount,
+        min_lp_tokens,
+        is_base_in,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_zap_out_from_cp_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<ZapOutFromCpAmmParams>,
+    Json(payload): Json<ZapOutFromCpAmmPayload>,
+) -> impl IntoResponse {
+    let ZapOutFromCpAmmPayload {
+        signer,
+        signer_lp_account,
+        lp_tokens,
+        min_result,
+        is_base_out,
+        deadline,
+    } = payload;
+    let ZapOutFromCpAmmParams { cp_amm } = params;
+    let result = zap_out_from_cp_amm_tx(
+        &context,
+        signer,
+        signer_lp_account,
+        cp_amm,
+        lp_tokens,
+        min_result,
+        is_base_out,
+        deadline,
     )
     .await
     .and_then(|tx| tx.to_base64());
@@ -273,3 +456,220 @@ pub(crate) async fn get_collect_fees_from_cp_amm_tx(
         .and_then(|tx| tx.to_base64());
     send_result(result)
 }
+
+pub(crate) async fn get_claim_fees_from_cp_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<ClaimFeesFromCpAmmParams>,
+    Json(payload): Json<ClaimFeesFromCpAmmPayload>,
+) -> impl IntoResponse {
+    let ClaimFeesFromCpAmmPayload {
+        fee_authority,
+        destination,
+        base_fees,
+        quote_fees,
+    } = payload;
+    let ClaimFeesFromCpAmmParams { cp_amm } = params;
+    let result = claim_fees_from_cp_amm_tx(
+        &context,
+        fee_authority,
+        destination,
+        cp_amm,
+        base_fees,
+        quote_fees,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_sweep_fees_from_cp_amms_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<SweepFeesFromCpAmmsParams>,
+    Json(payload): Json<SweepFeesFromCpAmmsPayload>,
+) -> impl IntoResponse {
+    let SweepFeesFromCpAmmsPayload {
+        fee_authority,
+        destination,
+        cp_amms,
+    } = payload;
+    let SweepFeesFromCpAmmsParams { amms_config } = params;
+    let result =
+        sweep_fees_from_cp_amms_tx(&context, fee_authority, destination, amms_config, cp_amms)
+            .await
+            .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_sync_cp_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<SyncCpAmmParams>,
+    Json(payload): Json<SyncCpAmmPayload>,
+) -> impl IntoResponse {
+    let SyncCpAmmPayload { signer } = payload;
+    let SyncCpAmmParams { cp_amm } = params;
+    let result = sync_cp_amm_tx(&context, signer, cp_amm)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_skim_cp_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<SkimCpAmmParams>,
+    Json(payload): Json<SkimCpAmmPayload>,
+) -> impl IntoResponse {
+    let SkimCpAmmPayload {
+        fee_authority,
+        destination,
+    } = payload;
+    let SkimCpAmmParams { cp_amm } = params;
+    let result = skim_cp_amm_tx(&context, fee_authority, destination, cp_amm)
+        .await
+        .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_launch_stable_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<LaunchStableAmmParams>,
+    Json(payload): Json<LaunchStableAmmPayload>,
+) -> impl IntoResponse {
+    let LaunchStableAmmPayload {
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    } = payload;
+    let LaunchStableAmmParams { stable_amm } = params;
+    let result = launch_stable_amm_tx(
+        &context,
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        stable_amm,
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_provide_to_stable_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<ProvideToStableAmmParams>,
+    Json(payload): Json<ProvideToStableAmmPayload>,
+) -> impl IntoResponse {
+    let ProvideToStableAmmPayload {
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    } = payload;
+    let ProvideToStableAmmParams { stable_amm } = params;
+    let result = provide_to_stable_amm_tx(
+        &context,
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        stable_amm,
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_withdraw_from_stable_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<WithdrawFromStableAmmParams>,
+    Json(payload): Json<WithdrawFromStableAmmPayload>,
+) -> impl IntoResponse {
+    let WithdrawFromStableAmmPayload {
+        signer,
+        signer_lp_account,
+        lp_tokens,
+        deadline,
+    } = payload;
+    let WithdrawFromStableAmmParams { stable_amm } = params;
+    let result = withdraw_from_stable_amm_tx(
+        &context,
+        signer,
+        signer_lp_account,
+        stable_amm,
+        lp_tokens,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_provide_to_cl_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<ProvideToClAmmParams>,
+    Json(payload): Json<ProvideToClAmmPayload>,
+) -> impl IntoResponse {
+    let ProvideToClAmmPayload {
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        position,
+        liquidity,
+        max_base_amount,
+        max_quote_amount,
+        deadline,
+    } = payload;
+    let ProvideToClAmmParams { cl_amm } = params;
+    let result = provide_to_cl_amm_tx(
+        &context,
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        cl_amm,
+        position,
+        liquidity,
+        max_base_amount,
+        max_quote_amount,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
+
+pub(crate) async fn get_withdraw_from_cl_amm_tx(
+    State(context): State<Arc<LiquidityPoolContext>>,
+    Path(params): Path<WithdrawFromClAmmParams>,
+    Json(payload): Json<WithdrawFromClAmmPayload>,
+) -> impl IntoResponse {
+    let WithdrawFromClAmmPayload {
+        signer,
+        position,
+        liquidity,
+        min_base_amount,
+        min_quote_amount,
+        deadline,
+    } = payload;
+    let WithdrawFromClAmmParams { cl_amm } = params;
+    let result = withdraw_from_cl_amm_tx(
+        &context,
+        signer,
+        cl_amm,
+        position,
+        liquidity,
+        min_base_amount,
+        min_quote_amount,
+        deadline,
+    )
+    .await
+    .and_then(|tx| tx.to_base64());
+    send_result(result)
+}
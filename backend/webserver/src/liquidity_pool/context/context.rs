@@ -1,7 +1,7 @@
 use super::{LiquidityPoolScyllaDbClient, LiquidityPoolSolanaRpcClient};
-use crate::liquidity_pool::models::CpAmmKeys;
+use crate::liquidity_pool::models::{ClAmmPositionKeys, CpAmmKeys, StableAmmKeys};
 use crate::utils::clients::{CacheRegistry, ProgramContext};
-use anyhow::Result as AnyResult;
+use anyhow::{bail, Result as AnyResult};
 use async_trait::async_trait;
 use solana_sdk::pubkey::Pubkey;
 use std::sync::Arc;
@@ -54,6 +54,36 @@ impl LiquidityPoolContext {
 
         Ok(cp_amm_keys)
     }
+    pub async fn get_stable_amm_keys(&self, stable_amm_key: &Pubkey) -> AnyResult<StableAmmKeys> {
+        let stable_amm_account = self
+            .solana_rpc_client()
+            .fetch_stable_amm(stable_amm_key)
+            .await?;
+        Ok(StableAmmKeys::from(stable_amm_account))
+    }
+    pub async fn get_cl_amm_position_keys(
+        &self,
+        cl_amm_key: &Pubkey,
+        position_key: &Pubkey,
+    ) -> AnyResult<ClAmmPositionKeys> {
+        let solana_rpc_client = self.solana_rpc_client();
+        let (cl_amm_account, position_account) = tokio::try_join!(
+            solana_rpc_client.fetch_cl_amm(cl_amm_key),
+            solana_rpc_client.fetch_cl_amm_position(position_key),
+        )?;
+        if position_account.cl_amm != *cl_amm_key {
+            bail!(
+                "position {} does not belong to cl_amm {}",
+                position_key,
+                cl_amm_key
+            );
+        }
+        Ok(ClAmmPositionKeys::new(
+            *position_key,
+            position_account,
+            cl_amm_account,
+        ))
+    }
 }
 #[async_trait]
 impl ProgramContext<LiquidityPoolScyllaDbClient, LiquidityPoolSolanaRpcClient>
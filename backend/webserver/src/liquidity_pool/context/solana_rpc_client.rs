@@ -4,7 +4,7 @@ use moka::future::Cache;
 use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
-use liquidity_pool::accounts::{AmmsConfig, AmmsConfigsManager, CpAmm};
+use liquidity_pool::accounts::{AmmsConfig, AmmsConfigsManager, ClAmm, ClAmmPosition, CpAmm, StableAmm};
 use anyhow::Result as AnyResult;
 use async_trait::async_trait;
 use solana_sdk::program_error::ProgramError;
@@ -55,6 +55,33 @@ impl LiquidityPoolSolanaRpcClient {
         let result = CpAmm::from_bytes(account.data.as_slice())?;
         Ok(result)
     }
+
+    pub async fn fetch_stable_amm(&self, stable_amm: &Pubkey) -> AnyResult<StableAmm> {
+        let account = self.rpc_client().get_account(stable_amm).await?;
+        if account.owner != LIQUIDITY_POOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        let result = StableAmm::from_bytes(account.data.as_slice())?;
+        Ok(result)
+    }
+
+    pub async fn fetch_cl_amm(&self, cl_amm: &Pubkey) -> AnyResult<ClAmm> {
+        let account = self.rpc_client().get_account(cl_amm).await?;
+        if account.owner != LIQUIDITY_POOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        let result = ClAmm::from_bytes(account.data.as_slice())?;
+        Ok(result)
+    }
+
+    pub async fn fetch_cl_amm_position(&self, position: &Pubkey) -> AnyResult<ClAmmPosition> {
+        let account = self.rpc_client().get_account(position).await?;
+        if account.owner != LIQUIDITY_POOL_ID {
+            return Err(ProgramError::IncorrectProgramId.into());
+        }
+        let result = ClAmmPosition::from_bytes(account.data.as_slice())?;
+        Ok(result)
+    }
 }
 
 #[async_trait]
@@ -21,4 +21,24 @@ pub fn get_cp_amm_vault_pda(cp_amm: &Pubkey, mint: &Pubkey) -> (Pubkey, u8){
     let seed = b"vault";
     let seeds = &[seed.as_ref(), cp_amm.as_ref(), mint.as_ref()];
     Pubkey::find_program_address(seeds, &LIQUIDITY_POOL_ID)
+}
+pub fn get_cp_amm_pair_pda(amms_config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> (Pubkey, u8){
+    let seed = b"cp_amm_pair";
+    let (mint_0, mint_1) = if mint_a < mint_b { (mint_a, mint_b) } else { (mint_b, mint_a) };
+    let seeds = &[seed.as_ref(), amms_config.as_ref(), mint_0.as_ref(), mint_1.as_ref()];
+    Pubkey::find_program_address(seeds, &LIQUIDITY_POOL_ID)
+}
+pub fn get_cp_amm_lp_lock_pda(cp_amm: &Pubkey, owner: &Pubkey) -> (Pubkey, u8){
+    let seed = b"cp_amm_lp_lock";
+    let seeds = &[seed.as_ref(), cp_amm.as_ref(), owner.as_ref()];
+    Pubkey::find_program_address(seeds, &LIQUIDITY_POOL_ID)
+}
+pub fn get_cl_amm_tick_array_pda(cl_amm: &Pubkey, tick: i32, tick_spacing: u16) -> (Pubkey, u8){
+    let seed = b"cl_amm_tick_array";
+    // Tick arrays hold 16 ticks and are seeded by the first tick they cover
+    let ticks_span = 16 * tick_spacing as i32;
+    let start_tick = tick.div_euclid(ticks_span) * ticks_span;
+    let dynamic_seed: [u8; 4] = i32::to_le_bytes(start_tick);
+    let seeds = &[seed.as_ref(), cl_amm.as_ref(), dynamic_seed.as_ref()];
+    Pubkey::find_program_address(seeds, &LIQUIDITY_POOL_ID)
 }
\ No newline at end of file
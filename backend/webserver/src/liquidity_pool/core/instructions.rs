@@ -1,19 +1,28 @@
 use super::address_derive::{
-    get_amms_config_pda, get_amms_configs_manager_pda, get_cp_amm_pda, get_cp_amm_vault_pda,
+    get_amms_config_pda, get_amms_configs_manager_pda, get_cl_amm_tick_array_pda,
+    get_cp_amm_lp_lock_pda, get_cp_amm_pair_pda, get_cp_amm_pda, get_cp_amm_vault_pda,
+};
+use crate::liquidity_pool::models::{
+    ClAmmPositionKeys, FeesSweepCpAmmKeys, RoutedSwapHopKeys, StableAmmKeys,
 };
 use crate::utils::address_derive::{get_ata, get_program_data};
 use crate::utils::constants::{
-    ASSOCIATED_TOKEN_PROGRAM_ID, RENT, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM,
+    ASSOCIATED_TOKEN_PROGRAM_ID, RENT, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_2022,
 };
 use liquidity_pool::instructions::{
-    CollectFeesFromCpAmmBuilder, InitializeAmmsConfigBuilder, InitializeAmmsConfigsManagerBuilder,
-    InitializeCpAmmBuilder, LaunchCpAmmBuilder, ProvideToCpAmmBuilder, SwapInCpAmmBuilder,
+    ClaimFeesFromCpAmmBuilder, CollectFeesFromCpAmmBuilder, InitializeAmmsConfigBuilder, InitializeAmmsConfigsManagerBuilder,
+    InitializeCpAmmBuilder, LaunchCpAmmWithDeadlineBuilder, LaunchCpAmmWithLpLockWithDeadlineBuilder, ProvideToCpAmmWithDeadlineBuilder, RoutedSwapInCpAmmsWithDeadlineBuilder,
+    SkimCpAmmBuilder, SwapInCpAmmWithDeadlineBuilder, SwapInCpAmmWithReferrerBuilder, SweepFeesFromCpAmmsBuilder, SyncCpAmmBuilder,
     UpdateAmmsConfigFeeAuthorityBuilder, UpdateAmmsConfigProtocolFeeRateBuilder,
-    UpdateAmmsConfigProvidersFeeRateBuilder, UpdateAmmsConfigsManagerAuthorityBuilder,
-    UpdateAmmsConfigsManagerHeadAuthorityBuilder, WithdrawFromCpAmmBuilder,
+    UpdateAmmsConfigProvidersFeeRateBuilder, ProposeAmmsConfigsManagerAuthorityBuilder,
+    ProposeAmmsConfigsManagerHeadAuthorityBuilder, AcceptAmmsConfigsManagerAuthorityBuilder,
+    AcceptAmmsConfigsManagerHeadAuthorityBuilder, CancelAmmsConfigsManagerAuthorityTransferBuilder,
+    CancelAmmsConfigsManagerHeadAuthorityTransferBuilder, WithdrawFromCpAmmWithDeadlineBuilder, ZapInToCpAmmWithDeadlineBuilder,
+    ZapOutFromCpAmmWithDeadlineBuilder, LaunchStableAmmWithDeadlineBuilder, ProvideToStableAmmWithDeadlineBuilder,
+    WithdrawFromStableAmmWithDeadlineBuilder, ProvideToClAmmWithDeadlineBuilder, WithdrawFromClAmmWithDeadlineBuilder,
 };
 use liquidity_pool::programs::LIQUIDITY_POOL_ID;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
@@ -35,26 +44,50 @@ pub fn initialize_amms_configs_manager_ix(
     builder.system_program(SYSTEM_PROGRAM_ID);
     (builder.instruction(), amms_configs_manager)
 }
-pub fn update_amms_configs_manager_authority_ix(
+pub fn propose_amms_configs_manager_authority_ix(
     authority: Pubkey,
     new_authority: Pubkey,
 ) -> Instruction {
-    let mut builder = UpdateAmmsConfigsManagerAuthorityBuilder::new();
+    let mut builder = ProposeAmmsConfigsManagerAuthorityBuilder::new();
     builder.authority(authority);
     builder.amms_configs_manager(get_amms_configs_manager_pda().0);
     builder.new_authority(new_authority);
     builder.instruction()
 }
-pub fn update_amms_configs_manager_head_authority_ix(
+pub fn propose_amms_configs_manager_head_authority_ix(
     head_authority: Pubkey,
     new_head_authority: Pubkey,
 ) -> Instruction {
-    let mut builder = UpdateAmmsConfigsManagerHeadAuthorityBuilder::new();
+    let mut builder = ProposeAmmsConfigsManagerHeadAuthorityBuilder::new();
     builder.head_authority(head_authority);
     builder.amms_configs_manager(get_amms_configs_manager_pda().0);
     builder.new_head_authority(new_head_authority);
     builder.instruction()
 }
+pub fn accept_amms_configs_manager_authority_ix(new_authority: Pubkey) -> Instruction {
+    let mut builder = AcceptAmmsConfigsManagerAuthorityBuilder::new();
+    builder.new_authority(new_authority);
+    builder.amms_configs_manager(get_amms_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn cancel_amms_configs_manager_authority_transfer_ix(authority: Pubkey) -> Instruction {
+    let mut builder = CancelAmmsConfigsManagerAuthorityTransferBuilder::new();
+    builder.authority(authority);
+    builder.amms_configs_manager(get_amms_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn accept_amms_configs_manager_head_authority_ix(new_head_authority: Pubkey) -> Instruction {
+    let mut builder = AcceptAmmsConfigsManagerHeadAuthorityBuilder::new();
+    builder.new_head_authority(new_head_authority);
+    builder.amms_configs_manager(get_amms_configs_manager_pda().0);
+    builder.instruction()
+}
+pub fn cancel_amms_configs_manager_head_authority_transfer_ix(head_authority: Pubkey) -> Instruction {
+    let mut builder = CancelAmmsConfigsManagerHeadAuthorityTransferBuilder::new();
+    builder.head_authority(head_authority);
+    builder.amms_configs_manager(get_amms_configs_manager_pda().0);
+    builder.instruction()
+}
 pub fn initialize_amms_config_ix(
     authority: Pubkey,
     id: u64,
@@ -131,12 +164,13 @@ pub fn initialize_cp_amm_ix(
     builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
     builder.cp_amm_locked_lp_vault(get_cp_amm_vault_pda(&cp_amm, &lp_mint_pubkey).0);
     builder.cp_amm(cp_amm.clone());
+    builder.cp_amm_pair(get_cp_amm_pair_pda(&amms_config, &base_mint, &quote_mint).0);
     builder.base_mint(base_mint);
     builder.quote_mint(quote_mint);
     builder.lp_mint(lp_mint_pubkey);
     builder.rent(RENT);
     builder.system_program(SYSTEM_PROGRAM_ID);
-    builder.lp_token_program(TOKEN_PROGRAM);
+    builder.lp_token_program(TOKEN_PROGRAM_2022);
     builder.base_token_program(base_token_program);
     builder.quote_token_program(quote_token_program);
     (builder.instruction(), cp_amm)
@@ -155,8 +189,9 @@ pub fn launch_cp_amm_ix(
     lp_token_program: Pubkey,
     base_liquidity: u64,
     quote_liquidity: u64,
+    deadline: Option<i64>,
 ) -> (Instruction, Pubkey) {
-    let mut builder = LaunchCpAmmBuilder::new();
+    let mut builder = LaunchCpAmmWithDeadlineBuilder::new();
     builder.amms_config(amms_config);
     builder.creator_base_account(
         creator_base_account.unwrap_or(get_ata(&creator, &base_mint, &base_token_program).0),
@@ -180,6 +215,59 @@ pub fn launch_cp_amm_ix(
     builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
     builder.base_liquidity(base_liquidity);
     builder.quote_liquidity(quote_liquidity);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    (builder.instruction(), cp_amm)
+}
+pub fn launch_cp_amm_with_lp_lock_ix(
+    creator: Pubkey,
+    creator_base_account: Option<Pubkey>,
+    creator_quote_account: Option<Pubkey>,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    lp_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    locked_lp_share_basis_points: u16,
+    cliff_timestamp: i64,
+    end_timestamp: i64,
+    deadline: Option<i64>,
+) -> (Instruction, Pubkey) {
+    let mut builder = LaunchCpAmmWithLpLockWithDeadlineBuilder::new();
+    builder.amms_config(amms_config);
+    builder.creator_base_account(
+        creator_base_account.unwrap_or(get_ata(&creator, &base_mint, &base_token_program).0),
+    );
+    builder.creator_quote_account(
+        creator_quote_account.unwrap_or(get_ata(&creator, &quote_mint, &quote_token_program).0),
+    );
+    builder.creator_lp_account(get_ata(&creator, &lp_mint, &lp_token_program).0);
+    builder.creator(creator);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm_locked_lp_vault(get_cp_amm_vault_pda(&cp_amm, &lp_mint).0);
+    let cp_amm_lp_lock = get_cp_amm_lp_lock_pda(&cp_amm, &creator).0;
+    builder.cp_amm_lp_lock(cp_amm_lp_lock);
+    builder.cp_amm_lp_lock_vault(get_ata(&cp_amm_lp_lock, &lp_mint, &lp_token_program).0);
+    builder.cp_amm(cp_amm.clone());
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.lp_mint(lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.base_liquidity(base_liquidity);
+    builder.quote_liquidity(quote_liquidity);
+    builder.locked_lp_share_basis_points(locked_lp_share_basis_points);
+    builder.cliff_timestamp(cliff_timestamp);
+    builder.end_timestamp(end_timestamp);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
     (builder.instruction(), cp_amm)
 }
 
@@ -197,8 +285,10 @@ pub fn provide_to_cp_amm_ix(
     lp_token_program: Pubkey,
     base_liquidity: u64,
     quote_liquidity: u64,
+    min_lp_tokens: u64,
+    deadline: Option<i64>,
 ) -> Instruction {
-    let mut builder = ProvideToCpAmmBuilder::new();
+    let mut builder = ProvideToCpAmmWithDeadlineBuilder::new();
     builder.amms_config(amms_config);
     builder.signer_base_account(
         signer_base_account.unwrap_or(get_ata(&signer, &base_mint, &base_token_program).0),
@@ -221,6 +311,8 @@ pub fn provide_to_cp_amm_ix(
     builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
     builder.base_liquidity(base_liquidity);
     builder.quote_liquidity(quote_liquidity);
+    builder.min_lp_tokens(min_lp_tokens);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
     builder.instruction()
 }
 pub fn withdraw_from_cp_amm_ix(
@@ -235,8 +327,11 @@ pub fn withdraw_from_cp_amm_ix(
     quote_token_program: Pubkey,
     lp_token_program: Pubkey,
     lp_tokens: u64,
+    min_base_out: u64,
+    min_quote_out: u64,
+    deadline: Option<i64>,
 ) -> Instruction {
-    let mut builder = WithdrawFromCpAmmBuilder::new();
+    let mut builder = WithdrawFromCpAmmWithDeadlineBuilder::new();
     builder.amms_config(amms_config);
     builder.signer_base_account(get_ata(&signer, &base_mint, &base_token_program).0);
     builder.signer_quote_account(get_ata(&signer, &quote_mint, &quote_token_program).0);
@@ -256,6 +351,9 @@ pub fn withdraw_from_cp_amm_ix(
     builder.system_program(SYSTEM_PROGRAM_ID);
     builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
     builder.lp_tokens(lp_tokens);
+    builder.min_base_out(min_base_out);
+    builder.min_quote_out(min_quote_out);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
     builder.instruction()
 }
 pub fn swap_in_cp_amm_ix(
@@ -270,8 +368,45 @@ pub fn swap_in_cp_amm_ix(
     estimated_result: u64,
     allowed_slippage: u64,
     is_in_out: bool,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = SwapInCpAmmWithDeadlineBuilder::new();
+    builder.amms_config(amms_config);
+    builder.signer_base_account(get_ata(&signer, &base_mint, &base_token_program).0);
+    builder.signer_quote_account(get_ata(&signer, &quote_mint, &quote_token_program).0);
+    builder.signer(signer);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm(cp_amm);
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.swap_amount(swap_amount);
+    builder.estimated_result(estimated_result);
+    builder.allowed_slippage(allowed_slippage);
+    builder.is_in_out(is_in_out);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn swap_in_cp_amm_with_referrer_ix(
+    signer: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    swap_amount: u64,
+    estimated_result: u64,
+    allowed_slippage: u64,
+    is_in_out: bool,
+    referrer_account: Pubkey,
+    deadline: Option<i64>,
 ) -> Instruction {
-    let mut builder = SwapInCpAmmBuilder::new();
+    let mut builder = SwapInCpAmmWithReferrerBuilder::new();
     builder.amms_config(amms_config);
     builder.signer_base_account(get_ata(&signer, &base_mint, &base_token_program).0);
     builder.signer_quote_account(get_ata(&signer, &quote_mint, &quote_token_program).0);
@@ -289,6 +424,115 @@ pub fn swap_in_cp_amm_ix(
     builder.estimated_result(estimated_result);
     builder.allowed_slippage(allowed_slippage);
     builder.is_in_out(is_in_out);
+    builder.referrer_account(referrer_account);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn routed_swap_in_cp_amms_ix(
+    signer: Pubkey,
+    hops: &[RoutedSwapHopKeys],
+    swap_amount: u64,
+    min_result: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = RoutedSwapInCpAmmsWithDeadlineBuilder::new();
+    builder.signer(signer);
+    for hop in hops {
+        builder.add_remaining_accounts(&[
+            AccountMeta::new_readonly(hop.amms_config, false),
+            AccountMeta::new(hop.cp_amm, false),
+            AccountMeta::new_readonly(hop.in_mint, false),
+            AccountMeta::new_readonly(hop.out_mint, false),
+            AccountMeta::new(get_cp_amm_vault_pda(&hop.cp_amm, &hop.in_mint).0, false),
+            AccountMeta::new(get_cp_amm_vault_pda(&hop.cp_amm, &hop.out_mint).0, false),
+            AccountMeta::new(get_ata(&signer, &hop.in_mint, &hop.in_token_program).0, false),
+            AccountMeta::new(get_ata(&signer, &hop.out_mint, &hop.out_token_program).0, false),
+            AccountMeta::new_readonly(hop.in_token_program, false),
+            AccountMeta::new_readonly(hop.out_token_program, false),
+        ]);
+    }
+    builder.swap_amount(swap_amount);
+    builder.min_result(min_result);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn zap_in_to_cp_amm_ix(
+    signer: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    lp_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    zap_amount: u64,
+    min_lp_tokens: u64,
+    is_base_in: bool,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = ZapInToCpAmmWithDeadlineBuilder::new();
+    builder.amms_config(amms_config);
+    builder.signer_base_account(get_ata(&signer, &base_mint, &base_token_program).0);
+    builder.signer_quote_account(get_ata(&signer, &quote_mint, &quote_token_program).0);
+    builder.signer_lp_account(get_ata(&signer, &lp_mint, &lp_token_program).0);
+    builder.signer(signer);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm(cp_amm);
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.lp_mint(lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.zap_amount(zap_amount);
+    builder.min_lp_tokens(min_lp_tokens);
+    builder.is_base_in(is_base_in);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn zap_out_from_cp_amm_ix(
+    signer: Pubkey,
+    signer_lp_account: Option<Pubkey>,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    lp_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    lp_tokens: u64,
+    min_result: u64,
+    is_base_out: bool,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = ZapOutFromCpAmmWithDeadlineBuilder::new();
+    builder.amms_config(amms_config);
+    builder.signer_base_account(get_ata(&signer, &base_mint, &base_token_program).0);
+    builder.signer_quote_account(get_ata(&signer, &quote_mint, &quote_token_program).0);
+    builder.signer_lp_account(
+        signer_lp_account.unwrap_or(get_ata(&signer, &lp_mint, &lp_token_program).0),
+    );
+    builder.signer(signer);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.cp_amm(cp_amm);
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.lp_mint(lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.lp_tokens(lp_tokens);
+    builder.min_result(min_result);
+    builder.is_base_out(is_base_out);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
     builder.instruction()
 }
 pub fn collect_fees_from_cp_amm_ix(
@@ -319,3 +563,293 @@ pub fn collect_fees_from_cp_amm_ix(
     builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
     builder.instruction()
 }
+pub fn claim_fees_from_cp_amm_ix(
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    base_fees: u64,
+    quote_fees: u64,
+) -> Instruction {
+    let mut builder = ClaimFeesFromCpAmmBuilder::new();
+    builder.fee_authority(fee_authority);
+    builder.amms_config(amms_config);
+    builder.destination_base_account(get_ata(&destination, &base_mint, &base_token_program).0);
+    builder.destination_quote_account(get_ata(&destination, &quote_mint, &quote_token_program).0);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.cp_amm(cp_amm);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.base_fees(base_fees);
+    builder.quote_fees(quote_fees);
+    builder.instruction()
+}
+pub fn sync_cp_amm_ix(
+    signer: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+) -> Instruction {
+    let mut builder = SyncCpAmmBuilder::new();
+    builder.signer(signer);
+    builder.amms_config(amms_config);
+    builder.cp_amm(cp_amm);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.instruction()
+}
+pub fn skim_cp_amm_ix(
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    amms_config: Pubkey,
+    cp_amm: Pubkey,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+) -> Instruction {
+    let mut builder = SkimCpAmmBuilder::new();
+    builder.fee_authority(fee_authority);
+    builder.amms_config(amms_config);
+    builder.destination_base_account(get_ata(&destination, &base_mint, &base_token_program).0);
+    builder.destination_quote_account(get_ata(&destination, &quote_mint, &quote_token_program).0);
+    builder.cp_amm_base_vault(get_cp_amm_vault_pda(&cp_amm, &base_mint).0);
+    builder.cp_amm_quote_vault(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0);
+    builder.base_mint(base_mint);
+    builder.quote_mint(quote_mint);
+    builder.cp_amm(cp_amm);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.instruction()
+}
+pub fn sweep_fees_from_cp_amms_ix(
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    amms_config: Pubkey,
+    cp_amms: &[FeesSweepCpAmmKeys],
+) -> Instruction {
+    let mut builder = SweepFeesFromCpAmmsBuilder::new();
+    builder.fee_authority(fee_authority);
+    builder.amms_config(amms_config);
+    for keys in cp_amms {
+        let (cp_amm, base_mint, quote_mint) = (keys.cp_amm, keys.base_mint, keys.quote_mint);
+        builder.add_remaining_accounts(&[
+            AccountMeta::new(cp_amm, false),
+            AccountMeta::new_readonly(base_mint, false),
+            AccountMeta::new_readonly(quote_mint, false),
+            AccountMeta::new(get_cp_amm_vault_pda(&cp_amm, &base_mint).0, false),
+            AccountMeta::new(get_cp_amm_vault_pda(&cp_amm, &quote_mint).0, false),
+            AccountMeta::new(
+                get_ata(&destination, &base_mint, &keys.base_token_program).0,
+                false,
+            ),
+            AccountMeta::new(
+                get_ata(&destination, &quote_mint, &keys.quote_token_program).0,
+                false,
+            ),
+            AccountMeta::new_readonly(keys.base_token_program, false),
+            AccountMeta::new_readonly(keys.quote_token_program, false),
+        ]);
+    }
+    builder.instruction()
+}
+pub fn launch_stable_amm_ix(
+    creator: Pubkey,
+    creator_base_account: Option<Pubkey>,
+    creator_quote_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    keys: &StableAmmKeys,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = LaunchStableAmmWithDeadlineBuilder::new();
+    builder.amms_config(keys.amms_config);
+    builder.creator_base_account(
+        creator_base_account.unwrap_or(get_ata(&creator, &keys.base_mint, &base_token_program).0),
+    );
+    builder.creator_quote_account(
+        creator_quote_account
+            .unwrap_or(get_ata(&creator, &keys.quote_mint, &quote_token_program).0),
+    );
+    builder.creator_lp_account(get_ata(&creator, &keys.lp_mint, &lp_token_program).0);
+    builder.creator(creator);
+    builder.stable_amm_base_vault(keys.base_vault);
+    builder.stable_amm_quote_vault(keys.quote_vault);
+    builder.stable_amm_locked_lp_vault(keys.locked_lp_vault);
+    builder.stable_amm(stable_amm);
+    builder.base_mint(keys.base_mint);
+    builder.quote_mint(keys.quote_mint);
+    builder.lp_mint(keys.lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.base_liquidity(base_liquidity);
+    builder.quote_liquidity(quote_liquidity);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn provide_to_stable_amm_ix(
+    signer: Pubkey,
+    signer_base_account: Option<Pubkey>,
+    signer_quote_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    keys: &StableAmmKeys,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = ProvideToStableAmmWithDeadlineBuilder::new();
+    builder.amms_config(keys.amms_config);
+    builder.signer_base_account(
+        signer_base_account.unwrap_or(get_ata(&signer, &keys.base_mint, &base_token_program).0),
+    );
+    builder.signer_quote_account(
+        signer_quote_account.unwrap_or(get_ata(&signer, &keys.quote_mint, &quote_token_program).0),
+    );
+    builder.signer_lp_account(get_ata(&signer, &keys.lp_mint, &lp_token_program).0);
+    builder.signer(signer);
+    builder.stable_amm_base_vault(keys.base_vault);
+    builder.stable_amm_quote_vault(keys.quote_vault);
+    builder.stable_amm(stable_amm);
+    builder.base_mint(keys.base_mint);
+    builder.quote_mint(keys.quote_mint);
+    builder.lp_mint(keys.lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.base_liquidity(base_liquidity);
+    builder.quote_liquidity(quote_liquidity);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn withdraw_from_stable_amm_ix(
+    signer: Pubkey,
+    signer_lp_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    keys: &StableAmmKeys,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    lp_token_program: Pubkey,
+    lp_tokens: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = WithdrawFromStableAmmWithDeadlineBuilder::new();
+    builder.amms_config(keys.amms_config);
+    builder.signer_base_account(get_ata(&signer, &keys.base_mint, &base_token_program).0);
+    builder.signer_quote_account(get_ata(&signer, &keys.quote_mint, &quote_token_program).0);
+    builder.signer_lp_account(
+        signer_lp_account.unwrap_or(get_ata(&signer, &keys.lp_mint, &lp_token_program).0),
+    );
+    builder.signer(signer);
+    builder.stable_amm_base_vault(keys.base_vault);
+    builder.stable_amm_quote_vault(keys.quote_vault);
+    builder.stable_amm(stable_amm);
+    builder.base_mint(keys.base_mint);
+    builder.quote_mint(keys.quote_mint);
+    builder.lp_mint(keys.lp_mint);
+    builder.lp_token_program(lp_token_program);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.lp_tokens(lp_tokens);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn provide_to_cl_amm_ix(
+    signer: Pubkey,
+    signer_base_account: Option<Pubkey>,
+    signer_quote_account: Option<Pubkey>,
+    keys: &ClAmmPositionKeys,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    liquidity: u128,
+    max_base_amount: u64,
+    max_quote_amount: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = ProvideToClAmmWithDeadlineBuilder::new();
+    builder.amms_config(keys.amms_config);
+    builder.signer_base_account(
+        signer_base_account.unwrap_or(get_ata(&signer, &keys.base_mint, &base_token_program).0),
+    );
+    builder.signer_quote_account(
+        signer_quote_account.unwrap_or(get_ata(&signer, &keys.quote_mint, &quote_token_program).0),
+    );
+    builder.signer(signer);
+    builder.cl_amm(keys.cl_amm);
+    builder.position(keys.position);
+    builder.tick_array_lower(
+        get_cl_amm_tick_array_pda(&keys.cl_amm, keys.tick_lower, keys.tick_spacing).0,
+    );
+    builder.tick_array_upper(
+        get_cl_amm_tick_array_pda(&keys.cl_amm, keys.tick_upper, keys.tick_spacing).0,
+    );
+    builder.cl_amm_base_vault(keys.base_vault);
+    builder.cl_amm_quote_vault(keys.quote_vault);
+    builder.base_mint(keys.base_mint);
+    builder.quote_mint(keys.quote_mint);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.liquidity(liquidity);
+    builder.max_base_amount(max_base_amount);
+    builder.max_quote_amount(max_quote_amount);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
+pub fn withdraw_from_cl_amm_ix(
+    signer: Pubkey,
+    keys: &ClAmmPositionKeys,
+    base_token_program: Pubkey,
+    quote_token_program: Pubkey,
+    liquidity: u128,
+    min_base_amount: u64,
+    min_quote_amount: u64,
+    deadline: Option<i64>,
+) -> Instruction {
+    let mut builder = WithdrawFromClAmmWithDeadlineBuilder::new();
+    builder.amms_config(keys.amms_config);
+    builder.signer_base_account(get_ata(&signer, &keys.base_mint, &base_token_program).0);
+    builder.signer_quote_account(get_ata(&signer, &keys.quote_mint, &quote_token_program).0);
+    builder.signer(signer);
+    builder.cl_amm(keys.cl_amm);
+    builder.position(keys.position);
+    builder.tick_array_lower(
+        get_cl_amm_tick_array_pda(&keys.cl_amm, keys.tick_lower, keys.tick_spacing).0,
+    );
+    builder.tick_array_upper(
+        get_cl_amm_tick_array_pda(&keys.cl_amm, keys.tick_upper, keys.tick_spacing).0,
+    );
+    builder.cl_amm_base_vault(keys.base_vault);
+    builder.cl_amm_quote_vault(keys.quote_vault);
+    builder.base_mint(keys.base_mint);
+    builder.quote_mint(keys.quote_mint);
+    builder.base_token_program(base_token_program);
+    builder.quote_token_program(quote_token_program);
+    builder.system_program(SYSTEM_PROGRAM_ID);
+    builder.associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID);
+    builder.liquidity(liquidity);
+    builder.min_base_amount(min_base_amount);
+    builder.min_quote_amount(min_quote_amount);
+    builder.deadline(deadline.unwrap_or(i64::MAX));
+    builder.instruction()
+}
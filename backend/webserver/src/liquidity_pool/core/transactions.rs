@@ -1,17 +1,25 @@
 use crate::liquidity_pool::context::LiquidityPoolContext;
+use crate::liquidity_pool::core::address_derive::get_cp_amm_vault_pda;
 use crate::liquidity_pool::core::instructions::{
-    collect_fees_from_cp_amm_ix, initialize_amms_config_ix, initialize_amms_configs_manager_ix,
-    initialize_cp_amm_ix, launch_cp_amm_ix, provide_to_cp_amm_ix, swap_in_cp_amm_ix,
+    claim_fees_from_cp_amm_ix, collect_fees_from_cp_amm_ix, initialize_amms_config_ix, initialize_amms_configs_manager_ix,
+    initialize_cp_amm_ix, launch_cp_amm_ix, launch_cp_amm_with_lp_lock_ix, provide_to_cp_amm_ix, routed_swap_in_cp_amms_ix,
+    skim_cp_amm_ix, swap_in_cp_amm_ix, swap_in_cp_amm_with_referrer_ix, sweep_fees_from_cp_amms_ix, sync_cp_amm_ix,
     update_amms_config_fee_authority_ix, update_amms_config_protocol_fee_rate_ix,
-    update_amms_config_providers_fee_rate_ix, update_amms_configs_manager_authority_ix,
-    update_amms_configs_manager_head_authority_ix, withdraw_from_cp_amm_ix,
+    update_amms_config_providers_fee_rate_ix, propose_amms_configs_manager_authority_ix,
+    propose_amms_configs_manager_head_authority_ix, accept_amms_configs_manager_authority_ix,
+    accept_amms_configs_manager_head_authority_ix, cancel_amms_configs_manager_authority_transfer_ix,
+    cancel_amms_configs_manager_head_authority_transfer_ix, withdraw_from_cp_amm_ix, zap_in_to_cp_amm_ix,
+    zap_out_from_cp_amm_ix, launch_stable_amm_ix, provide_to_stable_amm_ix, withdraw_from_stable_amm_ix,
+    provide_to_cl_amm_ix, withdraw_from_cl_amm_ix,
 };
 use crate::utils::clients::{ProgramContext, SolanaRpcClient};
-use crate::utils::instructions::set_compute_budget_ix;
+use crate::liquidity_pool::models::{FeesSweepCpAmmKeys, RoutedSwapHopKeys};
+use crate::utils::address_derive::get_ata;
+use crate::utils::instructions::{create_ata_idempotent_ix, set_compute_budget_ix};
 use crate::utils::types::{
-    build_unsigned_transaction, UnsignedTransaction, UnsignedTransactionBuilder,
+    build_unsigned_transaction, TokenTransfer, UnsignedTransaction, UnsignedTransactionBuilder,
 };
-use anyhow::Result as AnyResult;
+use anyhow::{bail, Result as AnyResult};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Keypair;
 
@@ -29,22 +37,22 @@ pub async fn initialize_amms_configs_manager_tx(
         amms_configs_manager_pubkey,
     ))
 }
-pub async fn update_amms_configs_manager_authority_tx(
+pub async fn propose_amms_configs_manager_authority_tx(
     context: &LiquidityPoolContext,
     authority: Pubkey,
     new_authority: Pubkey,
 ) -> AnyResult<UnsignedTransaction> {
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = update_amms_configs_manager_authority_ix(authority, new_authority);
+    let ix = propose_amms_configs_manager_authority_ix(authority, new_authority);
     Ok(build_unsigned_transaction(&authority, [ix], blockhash, []))
 }
-pub async fn update_amms_configs_manager_head_authority_tx(
+pub async fn propose_amms_configs_manager_head_authority_tx(
     context: &LiquidityPoolContext,
     head_authority: Pubkey,
     new_head_authority: Pubkey,
 ) -> AnyResult<UnsignedTransaction> {
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = update_amms_configs_manager_head_authority_ix(head_authority, new_head_authority);
+    let ix = propose_amms_configs_manager_head_authority_ix(head_authority, new_head_authority);
     Ok(build_unsigned_transaction(
         &head_authority,
         [ix],
@@ -52,6 +60,38 @@ pub async fn update_amms_configs_manager_head_authority_tx(
         [],
     ))
 }
+pub async fn accept_amms_configs_manager_authority_tx(
+    context: &LiquidityPoolContext,
+    new_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = accept_amms_configs_manager_authority_ix(new_authority);
+    Ok(build_unsigned_transaction(&new_authority, [ix], blockhash, []))
+}
+pub async fn cancel_amms_configs_manager_authority_transfer_tx(
+    context: &LiquidityPoolContext,
+    authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = cancel_amms_configs_manager_authority_transfer_ix(authority);
+    Ok(build_unsigned_transaction(&authority, [ix], blockhash, []))
+}
+pub async fn accept_amms_configs_manager_head_authority_tx(
+    context: &LiquidityPoolContext,
+    new_head_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = accept_amms_configs_manager_head_authority_ix(new_head_authority);
+    Ok(build_unsigned_transaction(&new_head_authority, [ix], blockhash, []))
+}
+pub async fn cancel_amms_configs_manager_head_authority_transfer_tx(
+    context: &LiquidityPoolContext,
+    head_authority: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = cancel_amms_configs_manager_head_authority_transfer_ix(head_authority);
+    Ok(build_unsigned_transaction(&head_authority, [ix], blockhash, []))
+}
 pub async fn initialize_amms_config_tx(
     context: &LiquidityPoolContext,
     authority: Pubkey,
@@ -163,6 +203,7 @@ pub async fn launch_cp_amm_tx(
     cp_amm: Pubkey,
     base_liquidity: u64,
     quote_liquidity: u64,
+    deadline: Option<i64>,
 ) -> AnyResult<(UnsignedTransaction, Pubkey)> {
     let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
     let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
@@ -171,7 +212,7 @@ pub async fn launch_cp_amm_tx(
         context.get_token_mint(&cp_amm_keys.quote_mint),
     )?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let (ix, cp_amm_pubkey) = launch_cp_amm_ix(
+    let (mut ix, cp_amm_pubkey) = launch_cp_amm_ix(
         creator,
         creator_base_account,
         creator_quote_account,
@@ -185,7 +226,30 @@ pub async fn launch_cp_amm_tx(
         *lp_mint_account.program(),
         base_liquidity,
         quote_liquidity,
+        deadline,
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    creator_base_account.unwrap_or(get_ata(&creator, &cp_amm_keys.base_mint, base_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    creator,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    creator_quote_account.unwrap_or(get_ata(&creator, &cp_amm_keys.quote_mint, quote_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    creator,
+                ),
+            ],
+        )
+        .await?;
     Ok((build_unsigned_transaction(
         &creator,
         [set_compute_budget_ix(250_000), ix],
@@ -193,6 +257,74 @@ pub async fn launch_cp_amm_tx(
         [],
     ), cp_amm_pubkey))
 }
+pub async fn launch_cp_amm_with_lp_lock_tx(
+    context: &LiquidityPoolContext,
+    creator: Pubkey,
+    creator_base_account: Option<Pubkey>,
+    creator_quote_account: Option<Pubkey>,
+    cp_amm: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    locked_lp_share_basis_points: u16,
+    cliff_timestamp: i64,
+    end_timestamp: i64,
+    deadline: Option<i64>,
+) -> AnyResult<(UnsignedTransaction, Pubkey)> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&cp_amm_keys.lp_mint),
+        context.get_token_mint(&cp_amm_keys.base_mint),
+        context.get_token_mint(&cp_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let (mut ix, cp_amm_pubkey) = launch_cp_amm_with_lp_lock_ix(
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        cp_amm_keys.amms_config,
+        cp_amm,
+        cp_amm_keys.base_mint,
+        cp_amm_keys.quote_mint,
+        cp_amm_keys.lp_mint,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        *lp_mint_account.program(),
+        base_liquidity,
+        quote_liquidity,
+        locked_lp_share_basis_points,
+        cliff_timestamp,
+        end_timestamp,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    creator_base_account.unwrap_or(get_ata(&creator, &cp_amm_keys.base_mint, base_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    creator,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    creator_quote_account.unwrap_or(get_ata(&creator, &cp_amm_keys.quote_mint, quote_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    creator,
+                ),
+            ],
+        )
+        .await?;
+    Ok((build_unsigned_transaction(
+        &creator,
+        [set_compute_budget_ix(300_000), ix],
+        blockhash,
+        [],
+    ), cp_amm_pubkey))
+}
 pub async fn provide_to_cp_amm_tx(
     context: &LiquidityPoolContext,
     signer: Pubkey,
@@ -201,6 +333,8 @@ pub async fn provide_to_cp_amm_tx(
     cp_amm: Pubkey,
     base_liquidity: u64,
     quote_liquidity: u64,
+    min_lp_tokens: u64,
+    deadline: Option<i64>,
 ) -> AnyResult<UnsignedTransaction> {
     let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
     let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
@@ -209,7 +343,7 @@ pub async fn provide_to_cp_amm_tx(
         context.get_token_mint(&cp_amm_keys.quote_mint),
     )?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = provide_to_cp_amm_ix(
+    let mut ix = provide_to_cp_amm_ix(
         signer,
         signer_base_account,
         signer_quote_account,
@@ -223,7 +357,31 @@ pub async fn provide_to_cp_amm_tx(
         *lp_mint_account.program(),
         base_liquidity,
         quote_liquidity,
+        min_lp_tokens,
+        deadline,
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    signer_base_account.unwrap_or(get_ata(&signer, &cp_amm_keys.base_mint, base_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    signer,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    signer_quote_account.unwrap_or(get_ata(&signer, &cp_amm_keys.quote_mint, quote_mint_account.program()).0),
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    signer,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(
         &signer,
         [set_compute_budget_ix(250_000), ix],
@@ -238,6 +396,9 @@ pub async fn withdraw_from_cp_amm_tx(
     signer_lp_account: Option<Pubkey>,
     cp_amm: Pubkey,
     lp_tokens: u64,
+    min_base_out: u64,
+    min_quote_out: u64,
+    deadline: Option<i64>,
 ) -> AnyResult<UnsignedTransaction> {
     let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
     let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
@@ -246,7 +407,7 @@ pub async fn withdraw_from_cp_amm_tx(
         context.get_token_mint(&cp_amm_keys.quote_mint),
     )?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = withdraw_from_cp_amm_ix(
+    let mut ix = withdraw_from_cp_amm_ix(
         signer,
         signer_lp_account,
         cp_amm_keys.amms_config,
@@ -258,7 +419,32 @@ pub async fn withdraw_from_cp_amm_tx(
         *quote_mint_account.program(),
         *lp_mint_account.program(),
         lp_tokens,
+        min_base_out,
+        min_quote_out,
+        deadline,
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    get_ata(&signer, &cp_amm_keys.base_mint, base_mint_account.program()).0,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    get_ata(&signer, &cp_amm_keys.quote_mint, quote_mint_account.program()).0,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(
         &signer,
         [set_compute_budget_ix(250_000), ix],
@@ -275,6 +461,8 @@ pub async fn swap_in_cp_amm_tx(
     estimated_result: u64,
     allowed_slippage: u64,
     is_in_out: bool,
+    referrer_account: Option<Pubkey>,
+    deadline: Option<i64>,
 ) -> AnyResult<UnsignedTransaction> {
     let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
     let (base_mint_account, quote_mint_account) = tokio::try_join!(
@@ -282,22 +470,302 @@ pub async fn swap_in_cp_amm_tx(
         context.get_token_mint(&cp_amm_keys.quote_mint),
     )?;
     let blockhash = context.solana_rpc_client().get_blockhash().await?;
-    let ix = swap_in_cp_amm_ix(
+    let mut ix = match referrer_account {
+        Some(referrer_account) => swap_in_cp_amm_with_referrer_ix(
+            signer,
+            cp_amm_keys.amms_config,
+            cp_amm,
+            cp_amm_keys.base_mint,
+            cp_amm_keys.quote_mint,
+            *base_mint_account.program(),
+            *quote_mint_account.program(),
+            swap_amount,
+            estimated_result,
+            allowed_slippage,
+            is_in_out,
+            referrer_account,
+            deadline,
+        ),
+        None => swap_in_cp_amm_ix(
+            signer,
+            cp_amm_keys.amms_config,
+            cp_amm,
+            cp_amm_keys.base_mint,
+            cp_amm_keys.quote_mint,
+            *base_mint_account.program(),
+            *quote_mint_account.program(),
+            swap_amount,
+            estimated_result,
+            allowed_slippage,
+            is_in_out,
+            deadline,
+        ),
+    };
+    let ((in_mint, in_mint_account), (out_mint, out_mint_account)) = if is_in_out {
+        (
+            (cp_amm_keys.base_mint, &base_mint_account),
+            (cp_amm_keys.quote_mint, &quote_mint_account),
+        )
+    } else {
+        (
+            (cp_amm_keys.quote_mint, &quote_mint_account),
+            (cp_amm_keys.base_mint, &base_mint_account),
+        )
+    };
+    let mut transfers = vec![
+        TokenTransfer::new(
+            in_mint,
+            in_mint_account,
+            get_ata(&signer, &in_mint, in_mint_account.program()).0,
+            get_cp_amm_vault_pda(&cp_amm, &in_mint).0,
+            signer,
+        ),
+        TokenTransfer::new(
+            out_mint,
+            out_mint_account,
+            get_cp_amm_vault_pda(&cp_amm, &out_mint).0,
+            get_ata(&signer, &out_mint, out_mint_account.program()).0,
+            cp_amm,
+        ),
+    ];
+    if let Some(referrer_account) = referrer_account {
+        transfers.push(TokenTransfer::new(
+            in_mint,
+            in_mint_account,
+            get_cp_amm_vault_pda(&cp_amm, &in_mint).0,
+            referrer_account,
+            cp_amm,
+        ));
+    }
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(&mut ix, &transfers)
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
+
+pub async fn routed_swap_in_cp_amms_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    input_mint: Pubkey,
+    route: Vec<Pubkey>,
+    swap_amount: u64,
+    min_result: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    if route.is_empty() {
+        bail!("Route must contain at least one CpAmm");
+    }
+    let mut hops = Vec::with_capacity(route.len());
+    let mut hop_mint_accounts = Vec::with_capacity(route.len());
+    let mut in_mint = input_mint;
+    for cp_amm in route {
+        let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+        let out_mint = if in_mint == cp_amm_keys.base_mint {
+            cp_amm_keys.quote_mint
+        } else if in_mint == cp_amm_keys.quote_mint {
+            cp_amm_keys.base_mint
+        } else {
+            bail!("CpAmm {} does not trade {}", cp_amm, in_mint);
+        };
+        let (in_mint_account, out_mint_account) = tokio::try_join!(
+            context.get_token_mint(&in_mint),
+            context.get_token_mint(&out_mint),
+        )?;
+        hops.push(RoutedSwapHopKeys {
+            amms_config: cp_amm_keys.amms_config,
+            cp_amm,
+            in_mint,
+            out_mint,
+            in_token_program: *in_mint_account.program(),
+            out_token_program: *out_mint_account.program(),
+        });
+        hop_mint_accounts.push((in_mint_account, out_mint_account));
+        in_mint = out_mint;
+    }
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+
+    let mut instructions = vec![set_compute_budget_ix(250_000 * hops.len() as u32)];
+    // Routed swap does not create token accounts, so make sure every received token has one
+    instructions.extend(hops.iter().map(|hop| {
+        create_ata_idempotent_ix(&signer, &signer, &hop.out_mint, &hop.out_token_program)
+    }));
+    let mut ix = routed_swap_in_cp_amms_ix(signer, &hops, swap_amount, min_result, deadline);
+    let transfers: Vec<TokenTransfer> = hops
+        .iter()
+        .zip(&hop_mint_accounts)
+        .flat_map(|(hop, (in_mint_account, out_mint_account))| {
+            [
+                TokenTransfer::new(
+                    hop.in_mint,
+                    in_mint_account,
+                    get_ata(&signer, &hop.in_mint, &hop.in_token_program).0,
+                    get_cp_amm_vault_pda(&hop.cp_amm, &hop.in_mint).0,
+                    signer,
+                ),
+                TokenTransfer::new(
+                    hop.out_mint,
+                    out_mint_account,
+                    get_cp_amm_vault_pda(&hop.cp_amm, &hop.out_mint).0,
+                    get_ata(&signer, &hop.out_mint, &hop.out_token_program).0,
+                    hop.cp_amm,
+                ),
+            ]
+        })
+        .collect();
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(&mut ix, &transfers)
+        .await?;
+    instructions.push(ix);
+    Ok(build_unsigned_transaction(&signer, instructions, blockhash, []))
+}
+
+pub async fn zap_in_to_cp_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    cp_amm: Pubkey,
+    zap_amount: u64,
+    min_lp_tokens: u64,
+    is_base_in: bool,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&cp_amm_keys.lp_mint),
+        context.get_token_mint(&cp_amm_keys.base_mint),
+        context.get_token_mint(&cp_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = zap_in_to_cp_amm_ix(
         signer,
         cp_amm_keys.amms_config,
         cp_amm,
         cp_amm_keys.base_mint,
         cp_amm_keys.quote_mint,
+        cp_amm_keys.lp_mint,
         *base_mint_account.program(),
         *quote_mint_account.program(),
-        swap_amount,
-        estimated_result,
-        allowed_slippage,
-        is_in_out,
+        *lp_mint_account.program(),
+        zap_amount,
+        min_lp_tokens,
+        is_base_in,
+        deadline,
     );
+    let ((in_mint, in_mint_account), (other_mint, other_mint_account)) = if is_base_in {
+        (
+            (cp_amm_keys.base_mint, &base_mint_account),
+            (cp_amm_keys.quote_mint, &quote_mint_account),
+        )
+    } else {
+        (
+            (cp_amm_keys.quote_mint, &quote_mint_account),
+            (cp_amm_keys.base_mint, &base_mint_account),
+        )
+    };
+    let signer_in_account = get_ata(&signer, &in_mint, in_mint_account.program()).0;
+    let signer_other_account = get_ata(&signer, &other_mint, other_mint_account.program()).0;
+    // The dust left after the internal swap and provide is returned in both tokens
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    in_mint,
+                    in_mint_account,
+                    signer_in_account,
+                    get_cp_amm_vault_pda(&cp_amm, &in_mint).0,
+                    signer,
+                ),
+                TokenTransfer::new(
+                    in_mint,
+                    in_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &in_mint).0,
+                    signer_in_account,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    other_mint,
+                    other_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &other_mint).0,
+                    signer_other_account,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(
         &signer,
-        [set_compute_budget_ix(250_000), ix],
+        [set_compute_budget_ix(400_000), ix],
+        blockhash,
+        [],
+    ))
+}
+
+pub async fn zap_out_from_cp_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    signer_lp_account: Option<Pubkey>,
+    cp_amm: Pubkey,
+    lp_tokens: u64,
+    min_result: u64,
+    is_base_out: bool,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&cp_amm_keys.lp_mint),
+        context.get_token_mint(&cp_amm_keys.base_mint),
+        context.get_token_mint(&cp_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = zap_out_from_cp_amm_ix(
+        signer,
+        signer_lp_account,
+        cp_amm_keys.amms_config,
+        cp_amm,
+        cp_amm_keys.base_mint,
+        cp_amm_keys.quote_mint,
+        cp_amm_keys.lp_mint,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        *lp_mint_account.program(),
+        lp_tokens,
+        min_result,
+        is_base_out,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    get_ata(&signer, &cp_amm_keys.base_mint, base_mint_account.program()).0,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    get_ata(&signer, &cp_amm_keys.quote_mint, quote_mint_account.program()).0,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(400_000), ix],
         blockhash,
         [],
     ))
@@ -316,7 +784,7 @@ pub async fn collect_fees_from_cp_amm_tx(
         context.get_token_mint(&cp_amm_keys.quote_mint),
     )?;
     let blockhash = solana_rpc_client.get_blockhash().await?;
-    let ix = collect_fees_from_cp_amm_ix(
+    let mut ix = collect_fees_from_cp_amm_ix(
         signer,
         amms_config_account.fee_authority,
         cp_amm_keys.amms_config,
@@ -326,5 +794,625 @@ pub async fn collect_fees_from_cp_amm_tx(
         *base_mint_account.program(),
         *quote_mint_account.program(),
     );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    get_ata(&amms_config_account.fee_authority, &cp_amm_keys.base_mint, base_mint_account.program()).0,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    get_ata(&amms_config_account.fee_authority, &cp_amm_keys.quote_mint, quote_mint_account.program()).0,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
     Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
 }
+
+pub async fn claim_fees_from_cp_amm_tx(
+    context: &LiquidityPoolContext,
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    cp_amm: Pubkey,
+    base_fees: u64,
+    quote_fees: u64,
+) -> AnyResult<UnsignedTransaction> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let (base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&cp_amm_keys.base_mint),
+        context.get_token_mint(&cp_amm_keys.quote_mint),
+    )?;
+    let (base_token_program, quote_token_program) =
+        (*base_mint_account.program(), *quote_mint_account.program());
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut claim_fees_ix = claim_fees_from_cp_amm_ix(
+        fee_authority,
+        destination,
+        cp_amm_keys.amms_config,
+        cp_amm,
+        cp_amm_keys.base_mint,
+        cp_amm_keys.quote_mint,
+        base_token_program,
+        quote_token_program,
+        base_fees,
+        quote_fees,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut claim_fees_ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    get_ata(&destination, &cp_amm_keys.base_mint, &base_token_program).0,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    get_ata(&destination, &cp_amm_keys.quote_mint, &quote_token_program).0,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
+    let instructions = [
+        create_ata_idempotent_ix(
+            &fee_authority,
+            &destination,
+            &cp_amm_keys.base_mint,
+            &base_token_program,
+        ),
+        create_ata_idempotent_ix(
+            &fee_authority,
+            &destination,
+            &cp_amm_keys.quote_mint,
+            &quote_token_program,
+        ),
+        claim_fees_ix,
+    ];
+    Ok(build_unsigned_transaction(
+        &fee_authority,
+        instructions,
+        blockhash,
+        [],
+    ))
+}
+
+pub async fn sweep_fees_from_cp_amms_tx(
+    context: &LiquidityPoolContext,
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    amms_config: Pubkey,
+    cp_amms: Vec<Pubkey>,
+) -> AnyResult<UnsignedTransaction> {
+    if cp_amms.is_empty() {
+        bail!("Sweep must contain at least one CpAmm");
+    }
+    let mut sweep = Vec::with_capacity(cp_amms.len());
+    let mut sweep_mint_accounts = Vec::with_capacity(cp_amms.len());
+    for cp_amm in cp_amms {
+        let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+        if cp_amm_keys.amms_config != amms_config {
+            bail!(
+                "CpAmm {} does not belong to AmmsConfig {}",
+                cp_amm,
+                amms_config
+            );
+        }
+        let (base_mint_account, quote_mint_account) = tokio::try_join!(
+            context.get_token_mint(&cp_amm_keys.base_mint),
+            context.get_token_mint(&cp_amm_keys.quote_mint),
+        )?;
+        sweep.push(FeesSweepCpAmmKeys {
+            cp_amm,
+            base_mint: cp_amm_keys.base_mint,
+            quote_mint: cp_amm_keys.quote_mint,
+            base_token_program: *base_mint_account.program(),
+            quote_token_program: *quote_mint_account.program(),
+        });
+        sweep_mint_accounts.push((base_mint_account, quote_mint_account));
+    }
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+
+    let mut instructions = vec![set_compute_budget_ix(60_000 * sweep.len() as u32)];
+    // Sweep does not create token accounts, so make sure the destination has one for every swept token
+    instructions.extend(sweep.iter().flat_map(|cp_amm| {
+        [
+            create_ata_idempotent_ix(
+                &fee_authority,
+                &destination,
+                &cp_amm.base_mint,
+                &cp_amm.base_token_program,
+            ),
+            create_ata_idempotent_ix(
+                &fee_authority,
+                &destination,
+                &cp_amm.quote_mint,
+                &cp_amm.quote_token_program,
+            ),
+        ]
+    }));
+    let mut ix = sweep_fees_from_cp_amms_ix(fee_authority, destination, amms_config, &sweep);
+    let transfers: Vec<TokenTransfer> = sweep
+        .iter()
+        .zip(&sweep_mint_accounts)
+        .flat_map(|(cp_amm, (base_mint_account, quote_mint_account))| {
+            [
+                TokenTransfer::new(
+                    cp_amm.base_mint,
+                    base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm.cp_amm, &cp_amm.base_mint).0,
+                    get_ata(&destination, &cp_amm.base_mint, &cp_amm.base_token_program).0,
+                    cp_amm.cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm.quote_mint,
+                    quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm.cp_amm, &cp_amm.quote_mint).0,
+                    get_ata(&destination, &cp_amm.quote_mint, &cp_amm.quote_token_program).0,
+                    cp_amm.cp_amm,
+                ),
+            ]
+        })
+        .collect();
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(&mut ix, &transfers)
+        .await?;
+    instructions.push(ix);
+    Ok(build_unsigned_transaction(
+        &fee_authority,
+        instructions,
+        blockhash,
+        [],
+    ))
+}
+
+pub async fn sync_cp_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    cp_amm: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let ix = sync_cp_amm_ix(
+        signer,
+        cp_amm_keys.amms_config,
+        cp_amm,
+        cp_amm_keys.base_mint,
+        cp_amm_keys.quote_mint,
+    );
+    Ok(build_unsigned_transaction(&signer, [ix], blockhash, []))
+}
+
+pub async fn skim_cp_amm_tx(
+    context: &LiquidityPoolContext,
+    fee_authority: Pubkey,
+    destination: Pubkey,
+    cp_amm: Pubkey,
+) -> AnyResult<UnsignedTransaction> {
+    let cp_amm_keys = context.get_cp_amm_keys(&cp_amm).await?;
+    let (base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&cp_amm_keys.base_mint),
+        context.get_token_mint(&cp_amm_keys.quote_mint),
+    )?;
+    let (base_token_program, quote_token_program) =
+        (*base_mint_account.program(), *quote_mint_account.program());
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut skim_ix = skim_cp_amm_ix(
+        fee_authority,
+        destination,
+        cp_amm_keys.amms_config,
+        cp_amm,
+        cp_amm_keys.base_mint,
+        cp_amm_keys.quote_mint,
+        base_token_program,
+        quote_token_program,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut skim_ix,
+            &[
+                TokenTransfer::new(
+                    cp_amm_keys.base_mint,
+                    &base_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.base_mint).0,
+                    get_ata(&destination, &cp_amm_keys.base_mint, &base_token_program).0,
+                    cp_amm,
+                ),
+                TokenTransfer::new(
+                    cp_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    get_cp_amm_vault_pda(&cp_amm, &cp_amm_keys.quote_mint).0,
+                    get_ata(&destination, &cp_amm_keys.quote_mint, &quote_token_program).0,
+                    cp_amm,
+                ),
+            ],
+        )
+        .await?;
+    let instructions = [
+        create_ata_idempotent_ix(
+            &fee_authority,
+            &destination,
+            &cp_amm_keys.base_mint,
+            &base_token_program,
+        ),
+        create_ata_idempotent_ix(
+            &fee_authority,
+            &destination,
+            &cp_amm_keys.quote_mint,
+            &quote_token_program,
+        ),
+        skim_ix,
+    ];
+    Ok(build_unsigned_transaction(
+        &fee_authority,
+        instructions,
+        blockhash,
+        [],
+    ))
+}
+pub async fn launch_stable_amm_tx(
+    context: &LiquidityPoolContext,
+    creator: Pubkey,
+    creator_base_account: Option<Pubkey>,
+    creator_quote_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let stable_amm_keys = context.get_stable_amm_keys(&stable_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&stable_amm_keys.lp_mint),
+        context.get_token_mint(&stable_amm_keys.base_mint),
+        context.get_token_mint(&stable_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = launch_stable_amm_ix(
+        creator,
+        creator_base_account,
+        creator_quote_account,
+        stable_amm,
+        &stable_amm_keys,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        *lp_mint_account.program(),
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    stable_amm_keys.base_mint,
+                    &base_mint_account,
+                    creator_base_account.unwrap_or(
+                        get_ata(
+                            &creator,
+                            &stable_amm_keys.base_mint,
+                            base_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    stable_amm_keys.base_vault,
+                    creator,
+                ),
+                TokenTransfer::new(
+                    stable_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    creator_quote_account.unwrap_or(
+                        get_ata(
+                            &creator,
+                            &stable_amm_keys.quote_mint,
+                            quote_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    stable_amm_keys.quote_vault,
+                    creator,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &creator,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
+pub async fn provide_to_stable_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    signer_base_account: Option<Pubkey>,
+    signer_quote_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let stable_amm_keys = context.get_stable_amm_keys(&stable_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&stable_amm_keys.lp_mint),
+        context.get_token_mint(&stable_amm_keys.base_mint),
+        context.get_token_mint(&stable_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = provide_to_stable_amm_ix(
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        stable_amm,
+        &stable_amm_keys,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        *lp_mint_account.program(),
+        base_liquidity,
+        quote_liquidity,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    stable_amm_keys.base_mint,
+                    &base_mint_account,
+                    signer_base_account.unwrap_or(
+                        get_ata(
+                            &signer,
+                            &stable_amm_keys.base_mint,
+                            base_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    stable_amm_keys.base_vault,
+                    signer,
+                ),
+                TokenTransfer::new(
+                    stable_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    signer_quote_account.unwrap_or(
+                        get_ata(
+                            &signer,
+                            &stable_amm_keys.quote_mint,
+                            quote_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    stable_amm_keys.quote_vault,
+                    signer,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
+pub async fn withdraw_from_stable_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    signer_lp_account: Option<Pubkey>,
+    stable_amm: Pubkey,
+    lp_tokens: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let stable_amm_keys = context.get_stable_amm_keys(&stable_amm).await?;
+    let (lp_mint_account, base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&stable_amm_keys.lp_mint),
+        context.get_token_mint(&stable_amm_keys.base_mint),
+        context.get_token_mint(&stable_amm_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = withdraw_from_stable_amm_ix(
+        signer,
+        signer_lp_account,
+        stable_amm,
+        &stable_amm_keys,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        *lp_mint_account.program(),
+        lp_tokens,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    stable_amm_keys.base_mint,
+                    &base_mint_account,
+                    stable_amm_keys.base_vault,
+                    get_ata(
+                        &signer,
+                        &stable_amm_keys.base_mint,
+                        base_mint_account.program(),
+                    )
+                    .0,
+                    stable_amm,
+                ),
+                TokenTransfer::new(
+                    stable_amm_keys.quote_mint,
+                    &quote_mint_account,
+                    stable_amm_keys.quote_vault,
+                    get_ata(
+                        &signer,
+                        &stable_amm_keys.quote_mint,
+                        quote_mint_account.program(),
+                    )
+                    .0,
+                    stable_amm,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
+pub async fn provide_to_cl_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    signer_base_account: Option<Pubkey>,
+    signer_quote_account: Option<Pubkey>,
+    cl_amm: Pubkey,
+    position: Pubkey,
+    liquidity: u128,
+    max_base_amount: u64,
+    max_quote_amount: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let position_keys = context.get_cl_amm_position_keys(&cl_amm, &position).await?;
+    let (base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&position_keys.base_mint),
+        context.get_token_mint(&position_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = provide_to_cl_amm_ix(
+        signer,
+        signer_base_account,
+        signer_quote_account,
+        &position_keys,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        liquidity,
+        max_base_amount,
+        max_quote_amount,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    position_keys.base_mint,
+                    &base_mint_account,
+                    signer_base_account.unwrap_or(
+                        get_ata(
+                            &signer,
+                            &position_keys.base_mint,
+                            base_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    position_keys.base_vault,
+                    signer,
+                ),
+                TokenTransfer::new(
+                    position_keys.quote_mint,
+                    &quote_mint_account,
+                    signer_quote_account.unwrap_or(
+                        get_ata(
+                            &signer,
+                            &position_keys.quote_mint,
+                            quote_mint_account.program(),
+                        )
+                        .0,
+                    ),
+                    position_keys.quote_vault,
+                    signer,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
+pub async fn withdraw_from_cl_amm_tx(
+    context: &LiquidityPoolContext,
+    signer: Pubkey,
+    cl_amm: Pubkey,
+    position: Pubkey,
+    liquidity: u128,
+    min_base_amount: u64,
+    min_quote_amount: u64,
+    deadline: Option<i64>,
+) -> AnyResult<UnsignedTransaction> {
+    let position_keys = context.get_cl_amm_position_keys(&cl_amm, &position).await?;
+    let (base_mint_account, quote_mint_account) = tokio::try_join!(
+        context.get_token_mint(&position_keys.base_mint),
+        context.get_token_mint(&position_keys.quote_mint),
+    )?;
+    let blockhash = context.solana_rpc_client().get_blockhash().await?;
+    let mut ix = withdraw_from_cl_amm_ix(
+        signer,
+        &position_keys,
+        *base_mint_account.program(),
+        *quote_mint_account.program(),
+        liquidity,
+        min_base_amount,
+        min_quote_amount,
+        deadline,
+    );
+    context
+        .solana_rpc_client()
+        .add_transfer_hook_accounts(
+            &mut ix,
+            &[
+                TokenTransfer::new(
+                    position_keys.base_mint,
+                    &base_mint_account,
+                    position_keys.base_vault,
+                    get_ata(
+                        &signer,
+                        &position_keys.base_mint,
+                        base_mint_account.program(),
+                    )
+                    .0,
+                    cl_amm,
+                ),
+                TokenTransfer::new(
+                    position_keys.quote_mint,
+                    &quote_mint_account,
+                    position_keys.quote_vault,
+                    get_ata(
+                        &signer,
+                        &position_keys.quote_mint,
+                        quote_mint_account.program(),
+                    )
+                    .0,
+                    cl_amm,
+                ),
+            ],
+        )
+        .await?;
+    Ok(build_unsigned_transaction(
+        &signer,
+        [set_compute_budget_ix(250_000), ix],
+        blockhash,
+        [],
+    ))
+}
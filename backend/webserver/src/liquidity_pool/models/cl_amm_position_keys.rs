@@ -0,0 +1,32 @@
+use liquidity_pool::accounts::{ClAmm, ClAmmPosition};
+use solana_sdk::pubkey::Pubkey;
+
+pub struct ClAmmPositionKeys {
+    pub amms_config: Pubkey,
+    pub cl_amm: Pubkey,
+    pub position: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub tick_spacing: u16,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+}
+
+impl ClAmmPositionKeys {
+    pub fn new(position: Pubkey, position_account: ClAmmPosition, cl_amm_account: ClAmm) -> Self {
+        Self {
+            amms_config: cl_amm_account.amms_config,
+            cl_amm: position_account.cl_amm,
+            position,
+            base_mint: cl_amm_account.base_mint,
+            quote_mint: cl_amm_account.quote_mint,
+            base_vault: cl_amm_account.base_vault,
+            quote_vault: cl_amm_account.quote_vault,
+            tick_spacing: cl_amm_account.tick_spacing,
+            tick_lower: position_account.tick_lower,
+            tick_upper: position_account.tick_upper,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub struct FeesSweepCpAmmKeys {
+    pub cp_amm: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_token_program: Pubkey,
+    pub quote_token_program: Pubkey,
+}
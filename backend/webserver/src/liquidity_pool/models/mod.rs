@@ -1,2 +1,10 @@
 mod cp_amm_keys;
-pub use cp_amm_keys::*;
\ No newline at end of file
+mod routed_swap_hop_keys;
+mod fees_sweep_cp_amm_keys;
+mod stable_amm_keys;
+mod cl_amm_position_keys;
+pub use cp_amm_keys::*;
+pub use routed_swap_hop_keys::*;
+pub use fees_sweep_cp_amm_keys::*;
+pub use stable_amm_keys::*;
+pub use cl_amm_position_keys::*;
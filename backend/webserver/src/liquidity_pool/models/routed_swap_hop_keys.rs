@@ -0,0 +1,10 @@
+use solana_sdk::pubkey::Pubkey;
+
+pub struct RoutedSwapHopKeys {
+    pub amms_config: Pubkey,
+    pub cp_amm: Pubkey,
+    pub in_mint: Pubkey,
+    pub out_mint: Pubkey,
+    pub in_token_program: Pubkey,
+    pub out_token_program: Pubkey,
+}
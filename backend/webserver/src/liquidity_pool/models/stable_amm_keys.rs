@@ -0,0 +1,26 @@
+use liquidity_pool::accounts::StableAmm;
+use solana_sdk::pubkey::Pubkey;
+
+pub struct StableAmmKeys {
+    pub amms_config: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub locked_lp_vault: Pubkey,
+}
+
+impl From<StableAmm> for StableAmmKeys {
+    fn from(value: StableAmm) -> Self {
+        Self {
+            amms_config: value.amms_config,
+            base_mint: value.base_mint,
+            quote_mint: value.quote_mint,
+            lp_mint: value.lp_mint,
+            base_vault: value.base_vault,
+            quote_vault: value.quote_vault,
+            locked_lp_vault: value.locked_lp_vault,
+        }
+    }
+}
@@ -44,10 +44,18 @@ async fn main() -> AnyResult<()> {
         env::var("LAUNCHPOOL_SCOPE").expect("LAUNCHPOOL_SCOPE must be set"),
         env::var("INIT_LAUNCHPOOL_CONFIGS_MANAGER")
             .expect("INIT_LAUNCHPOOL_CONFIGS_MANAGER must be set"),
-        env::var("UPDATE_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY")
-            .expect("UPDATE_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY must be set"),
-        env::var("UPDATE_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY")
-            .expect("UPDATE_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("PROPOSE_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY")
+            .expect("PROPOSE_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY must be set"),
+        env::var("PROPOSE_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY")
+            .expect("PROPOSE_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("ACCEPT_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY")
+            .expect("ACCEPT_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY must be set"),
+        env::var("ACCEPT_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY")
+            .expect("ACCEPT_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("CANCEL_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY_TRANSFER")
+            .expect("CANCEL_LAUNCHPOOL_CONFIGS_MANAGER_AUTHORITY_TRANSFER must be set"),
+        env::var("CANCEL_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY_TRANSFER")
+            .expect("CANCEL_LAUNCHPOOL_CONFIGS_MANAGER_HEAD_AUTHORITY_TRANSFER must be set"),
         env::var("INIT_LAUNCHPOOLS_CONFIG").expect("INIT_LAUNCHPOOLS_CONFIG must be set"),
         env::var("UPDATE_LAUNCHPOOLS_CONFIG_REWARD_AUTHORITY")
             .expect("UPDATE_LAUNCHPOOLS_CONFIG_REWARD_AUTHORITY must be set"),
@@ -63,15 +71,25 @@ async fn main() -> AnyResult<()> {
         env::var("INCREASE_STAKE_POSITION").expect("INCREASE_STAKE_POSITION must be set"),
         env::var("CLOSE_STAKE_POSITION").expect("CLOSE_STAKE_POSITION must be set"),
         env::var("COLLECT_PROTOCOL_REWARD").expect("COLLECT_PROTOCOL_REWARD must be set"),
+        env::var("INIT_LAUNCHPOOL_MIGRATION").expect("INIT_LAUNCHPOOL_MIGRATION must be set"),
+        env::var("MIGRATE_LAUNCHPOOL_TO_CP_AMM").expect("MIGRATE_LAUNCHPOOL_TO_CP_AMM must be set"),
     );
 
     let liquidity_pool_routes = LiquidityPoolRoutes::new(
         env::var("LIQUIDITY_POOL_SCOPE").expect("LIQUIDITY_POOL_SCOPE must be set"),
         env::var("INIT_AMMS_CONFIGS_MANAGER").expect("INIT_AMMS_CONFIGS_MANAGER must be set"),
-        env::var("UPDATE_AMMS_CONFIGS_MANAGER_AUTHORITY")
-            .expect("UPDATE_AMMS_CONFIGS_MANAGER_AUTHORITY must be set"),
-        env::var("UPDATE_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY")
-            .expect("UPDATE_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("PROPOSE_AMMS_CONFIGS_MANAGER_AUTHORITY")
+            .expect("PROPOSE_AMMS_CONFIGS_MANAGER_AUTHORITY must be set"),
+        env::var("PROPOSE_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY")
+            .expect("PROPOSE_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("ACCEPT_AMMS_CONFIGS_MANAGER_AUTHORITY")
+            .expect("ACCEPT_AMMS_CONFIGS_MANAGER_AUTHORITY must be set"),
+        env::var("ACCEPT_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY")
+            .expect("ACCEPT_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY must be set"),
+        env::var("CANCEL_AMMS_CONFIGS_MANAGER_AUTHORITY_TRANSFER")
+            .expect("CANCEL_AMMS_CONFIGS_MANAGER_AUTHORITY_TRANSFER must be set"),
+        env::var("CANCEL_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY_TRANSFER")
+            .expect("CANCEL_AMMS_CONFIGS_MANAGER_HEAD_AUTHORITY_TRANSFER must be set"),
         env::var("INIT_AMMS_CONFIG").expect("INIT_AMMS_CONFIG must be set"),
         env::var("UPDATE_AMMS_CONFIG_FEE_AUTHORITY")
             .expect("UPDATE_AMMS_CONFIG_FEE_AUTHORITY must be set"),
@@ -81,10 +99,23 @@ async fn main() -> AnyResult<()> {
             .expect("UPDATE_AMMS_CONFIG_PROVIDERS_FEE_RATE must be set"),
         env::var("INIT_CP_AMM").expect("INIT_CP_AMM must be set"),
         env::var("LAUNCH_CP_AMM").expect("LAUNCH_CP_AMM must be set"),
+        env::var("LAUNCH_CP_AMM_WITH_LP_LOCK").expect("LAUNCH_CP_AMM_WITH_LP_LOCK must be set"),
         env::var("PROVIDE_TO_CP_AMM").expect("PROVIDE_TO_CP_AMM must be set"),
         env::var("WITHDRAW_FROM_CP_AMM").expect("WITHDRAW_FROM_CP_AMM must be set"),
         env::var("SWAP_IN_CP_AMM").expect("SWAP_IN_CP_AMM must be set"),
+        env::var("ROUTED_SWAP_IN_CP_AMMS").expect("ROUTED_SWAP_IN_CP_AMMS must be set"),
+        env::var("ZAP_IN_TO_CP_AMM").expect("ZAP_IN_TO_CP_AMM must be set"),
+        env::var("ZAP_OUT_FROM_CP_AMM").expect("ZAP_OUT_FROM_CP_AMM must be set"),
         env::var("COLLECT_FEES_FROM_CP_AMM").expect("COLLECT_FEES_FROM_CP_AMM must be set"),
+        env::var("CLAIM_FEES_FROM_CP_AMM").expect("CLAIM_FEES_FROM_CP_AMM must be set"),
+        env::var("SWEEP_FEES_FROM_CP_AMMS").expect("SWEEP_FEES_FROM_CP_AMMS must be set"),
+        env::var("SYNC_CP_AMM").expect("SYNC_CP_AMM must be set"),
+        env::var("SKIM_CP_AMM").expect("SKIM_CP_AMM must be set"),
+        env::var("LAUNCH_STABLE_AMM").expect("LAUNCH_STABLE_AMM must be set"),
+        env::var("PROVIDE_TO_STABLE_AMM").expect("PROVIDE_TO_STABLE_AMM must be set"),
+        env::var("WITHDRAW_FROM_STABLE_AMM").expect("WITHDRAW_FROM_STABLE_AMM must be set"),
+        env::var("PROVIDE_TO_CL_AMM").expect("PROVIDE_TO_CL_AMM must be set"),
+        env::var("WITHDRAW_FROM_CL_AMM").expect("WITHDRAW_FROM_CL_AMM must be set"),
     );
 
     let liquidity_pool_solana_rpc_client = Arc::new(LiquidityPoolSolanaRpcClient::new(
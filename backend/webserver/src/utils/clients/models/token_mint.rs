@@ -4,11 +4,14 @@ use solana_sdk::program_error::ProgramError;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
 use spl_token::state::Mint;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::extension::transfer_hook::TransferHook;
 use crate::utils::constants::{TOKEN_PROGRAM, TOKEN_PROGRAM_2022};
 
 pub struct TokenMint{
     mint: Mint,
-    program: Pubkey
+    program: Pubkey,
+    transfer_hook_program: Option<Pubkey>
 }
 
 impl TryFrom<Account> for TokenMint {
@@ -21,7 +24,15 @@ impl TryFrom<Account> for TokenMint {
         }
         let mint_data = data.get(..Mint::LEN).ok_or_else(|| ProgramError::InvalidAccountData)?;
         let mint = Mint::unpack(&mint_data)?;
-        Ok(TokenMint{mint, program: owner})
+        let transfer_hook_program = if owner == TOKEN_PROGRAM_2022 {
+            StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?
+                .get_extension::<TransferHook>()
+                .ok()
+                .and_then(|transfer_hook| Option::<Pubkey>::from(transfer_hook.program_id))
+        } else {
+            None
+        };
+        Ok(TokenMint{mint, program: owner, transfer_hook_program})
     }
 }
 impl Deref for TokenMint {
@@ -34,4 +45,7 @@ impl TokenMint{
     pub fn program(&self) -> &Pubkey {
         &self.program
     }
-}
\ No newline at end of file
+    pub fn transfer_hook_program(&self) -> Option<&Pubkey> {
+        self.transfer_hook_program.as_ref()
+    }
+}
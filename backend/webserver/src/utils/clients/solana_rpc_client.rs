@@ -1,12 +1,18 @@
-use std::sync::Arc;
-use moka::future::Cache;
-use solana_sdk::hash::Hash;
+use crate::utils::clients::models::TokenMint;
+use crate::utils::types::TokenTransfer;
+use anyhow::anyhow;
 use anyhow::Result as AnyResult;
 use async_trait::async_trait;
-use solana_sdk::pubkey::Pubkey;
+use moka::future::Cache;
 use solana_client::nonblocking::rpc_client::RpcClient as RpcClientNonBlocking;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use spl_transfer_hook_interface::offchain::{
+    add_extra_account_metas_for_execute, AccountDataResult,
+};
+use std::sync::Arc;
 use tracing::debug;
-use crate::utils::clients::models::TokenMint;
 
 #[async_trait]
 pub trait SolanaRpcClient: Send + Sync {
@@ -27,4 +33,76 @@ pub trait SolanaRpcClient: Send + Sync {
         debug!(?mint, "Parsed token mint");
         Ok(token_mint)
     }
-}
\ No newline at end of file
+    async fn fetch_account_data(&self, address: Pubkey) -> AccountDataResult {
+        let accounts = self.rpc_client().get_multiple_accounts(&[address]).await?;
+        Ok(accounts
+            .into_iter()
+            .next()
+            .flatten()
+            .map(|account| account.data))
+    }
+    /// Appends the accounts required by the transfer hooks of the `transfers` made by the `instruction`,
+    /// resolved from the `ExtraAccountMetaList` of every hook program.
+    ///
+    /// Transfer amounts are only settled on-chain, so the accounts are resolved for the smallest and the
+    /// largest amounts and mints whose hook derives different accounts from the amount are refused.
+    async fn add_transfer_hook_accounts(
+        &self,
+        instruction: &mut Instruction,
+        transfers: &[TokenTransfer<'_>],
+    ) -> AnyResult<()> {
+        let instruction_accounts_count = instruction.accounts.len();
+        for transfer in transfers {
+            let Some(transfer_hook_program) = transfer.token_mint.transfer_hook_program() else {
+                continue;
+            };
+            let mut resolved_accounts = Vec::with_capacity(2);
+            for amount in [0, u64::MAX] {
+                let mut resolving_instruction = instruction.clone();
+                add_extra_account_metas_for_execute(
+                    &mut resolving_instruction,
+                    transfer_hook_program,
+                    &transfer.source,
+                    &transfer.mint,
+                    &transfer.destination,
+                    &transfer.authority,
+                    amount,
+                    |address| self.fetch_account_data(address),
+                )
+                .await
+                .map_err(|error| anyhow!(error))?;
+                resolved_accounts.push(
+                    resolving_instruction
+                        .accounts
+                        .split_off(instruction.accounts.len()),
+                );
+            }
+            if resolved_accounts[0] != resolved_accounts[1] {
+                return Err(anyhow!(
+                    "Transfer hook of mint {} resolves accounts from the transfer amount, which is not supported",
+                    transfer.mint
+                ));
+            }
+            instruction.accounts.append(&mut resolved_accounts[0]);
+            debug!(mint = ?transfer.mint, ?transfer_hook_program, "Resolved transfer hook accounts");
+        }
+        // Transfers of the same mint resolve to the same accounts, keep every account once
+        let mut transfer_hook_accounts = instruction.accounts.split_off(instruction_accounts_count);
+        let mut index = 0;
+        while index < transfer_hook_accounts.len() {
+            let account = transfer_hook_accounts[index].clone();
+            if let Some(first) = transfer_hook_accounts[..index]
+                .iter_mut()
+                .find(|first| first.pubkey == account.pubkey)
+            {
+                first.is_writable |= account.is_writable;
+                first.is_signer |= account.is_signer;
+                transfer_hook_accounts.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+        instruction.accounts.extend(transfer_hook_accounts);
+        Ok(())
+    }
+}
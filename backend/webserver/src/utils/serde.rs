@@ -21,10 +21,43 @@ where
         None => Ok(None),
     }
 }
+pub fn vec_pubkey_from_str<'de, D>(deserializer: D) -> Result<Vec<Pubkey>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let vec = Vec::<String>::deserialize(deserializer)?;
+    vec.iter()
+        .map(|s| Pubkey::from_str(s).map_err(de::Error::custom))
+        .collect()
+}
 pub fn u64_from_str<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
     s.parse::<u64>().map_err(de::Error::custom)
-}
\ No newline at end of file
+}
+pub fn u128_from_str<'de, D>(deserializer: D) -> Result<u128, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse::<u128>().map_err(de::Error::custom)
+}
+pub fn i64_from_str<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse::<i64>().map_err(de::Error::custom)
+}
+pub fn option_i64_from_str<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::<String>::deserialize(deserializer)?;
+    match opt {
+        Some(s) => s.parse::<i64>().map(Some).map_err(de::Error::custom),
+        None => Ok(None),
+    }
+}
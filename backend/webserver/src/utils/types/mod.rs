@@ -1,2 +1,4 @@
 mod unsigned_transaction;
-pub use unsigned_transaction::*;
\ No newline at end of file
+mod token_transfer;
+pub use unsigned_transaction::*;
+pub use token_transfer::*;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use solana_sdk::pubkey::Pubkey;
+use crate::utils::clients::models::TokenMint;
+
+/// Token transfer performed by a program instruction, resolved against the transfer hook of its mint.
+///
+/// The amount is left out as it is only settled on-chain, hooks depending on it are refused.
+pub struct TokenTransfer<'a> {
+    pub mint: Pubkey,
+    pub token_mint: &'a TokenMint,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl<'a> TokenTransfer<'a> {
+    pub fn new(
+        mint: Pubkey,
+        token_mint: &'a TokenMint,
+        source: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+    ) -> Self {
+        Self {
+            mint,
+            token_mint,
+            source,
+            destination,
+            authority,
+        }
+    }
+}
@@ -20,11 +20,15 @@ pub authority: Pubkey,
 pub head_authority: Pubkey,
 pub configs_count: u64,
 pub bump: u8,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_authority: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_head_authority: Pubkey,
 }
 
 
 impl LaunchpoolsConfigsManager {
-      pub const LEN: usize = 81;
+      pub const LEN: usize = 145;
   
   
   
@@ -6,11 +6,13 @@
 //!
 
   pub(crate) mod r#launchpool;
+  pub(crate) mod r#launchpool_migration;
   pub(crate) mod r#launchpools_config;
   pub(crate) mod r#launchpools_configs_manager;
   pub(crate) mod r#stake_position;
 
   pub use self::r#launchpool::*;
+  pub use self::r#launchpool_migration::*;
   pub use self::r#launchpools_config::*;
   pub use self::r#launchpools_configs_manager::*;
   pub use self::r#stake_position::*;
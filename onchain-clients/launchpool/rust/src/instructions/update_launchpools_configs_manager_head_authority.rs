@@ -74,20 +74,19 @@ impl Default for UpdateLaunchpoolsConfigsManagerHeadAuthorityInstructionData {
 }
 
 
-
 /// Instruction builder for `UpdateLaunchpoolsConfigsManagerHeadAuthority`.
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` head_authority
-                ///   1. `[writable]` launchpools_configs_manager
+          ///   0. `[writable, signer]` head_authority
+          ///   1. `[writable]` launchpools_configs_manager
           ///   2. `[]` new_head_authority
 #[derive(Clone, Debug, Default)]
 pub struct UpdateLaunchpoolsConfigsManagerHeadAuthorityBuilder {
             head_authority: Option<solana_program::pubkey::Pubkey>,
                 launchpools_configs_manager: Option<solana_program::pubkey::Pubkey>,
                 new_head_authority: Option<solana_program::pubkey::Pubkey>,
-                __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+        __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
 impl UpdateLaunchpoolsConfigsManagerHeadAuthorityBuilder {
@@ -109,7 +108,7 @@ impl UpdateLaunchpoolsConfigsManagerHeadAuthorityBuilder {
                         self.new_head_authority = Some(new_head_authority);
                     self
     }
-            /// Add an additional account to the instruction.
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: solana_program::instruction::AccountMeta) -> &mut Self {
     self.__remaining_accounts.push(account);
@@ -239,8 +238,8 @@ impl<'a, 'b> UpdateLaunchpoolsConfigsManagerHeadAuthorityCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` head_authority
-                ///   1. `[writable]` launchpools_configs_manager
+          ///   0. `[writable, signer]` head_authority
+          ///   1. `[writable]` launchpools_configs_manager
           ///   2. `[]` new_head_authority
 #[derive(Clone, Debug)]
 pub struct UpdateLaunchpoolsConfigsManagerHeadAuthorityCpiBuilder<'a, 'b> {
@@ -254,7 +253,7 @@ impl<'a, 'b> UpdateLaunchpoolsConfigsManagerHeadAuthorityCpiBuilder<'a, 'b> {
               head_authority: None,
               launchpools_configs_manager: None,
               new_head_authority: None,
-                                __remaining_accounts: Vec::new(),
+                    __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
@@ -273,7 +272,7 @@ impl<'a, 'b> UpdateLaunchpoolsConfigsManagerHeadAuthorityCpiBuilder<'a, 'b> {
                         self.instruction.new_head_authority = Some(new_head_authority);
                     self
     }
-            /// Add an additional account to the instruction.
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: &'b solana_program::account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
     self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
@@ -314,7 +313,7 @@ struct UpdateLaunchpoolsConfigsManagerHeadAuthorityCpiBuilderInstruction<'a, 'b>
             head_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 launchpools_configs_manager: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 new_head_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
 
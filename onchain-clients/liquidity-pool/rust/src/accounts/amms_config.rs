@@ -25,11 +25,52 @@ pub fee_authority: Pubkey,
 pub providers_fee_rate_basis_points: u16,
 /// The protocol's fee rate, measured in basis points (1 basis point = 0.01%).
 pub protocol_fee_rate_basis_points: u16,
+/// Whether the providers fee rate of CpAmm swaps is driven by the pool volatility
+/// instead of `providers_fee_rate_basis_points`.
+pub dynamic_fee_enabled: bool,
+/// The lower bound of the dynamic providers fee rate, measured in basis points.
+pub min_providers_fee_rate_basis_points: u16,
+/// The upper bound of the dynamic providers fee rate, measured in basis points.
+pub max_providers_fee_rate_basis_points: u16,
+/// Whether swaps are halted in all pools of this configuration.
+pub swaps_paused: bool,
+/// Whether liquidity provision is halted in all pools of this configuration.
+/// Withdrawals are never paused.
+pub provides_paused: bool,
+/// Whether the protocol fee of CpAmm swaps stays in the pool and is collected as LP tokens
+/// minted proportionally to the constant product growth, instead of being skimmed in base and quote tokens.
+/// StableAmm swaps always skim the protocol fee.
+pub protocol_fee_on_lp_enabled: bool,
+/// The fee rate of CpAmm flash loans, measured in basis points (1 basis point = 0.01%).
+/// Charged on top of the lent amounts and credited to liquidity providers.
+pub flash_loan_fee_rate_basis_points: u16,
+/// The fee paid to the fee authority for creating a pool with this configuration.
+/// Measured in lamports, or in tokens of `pool_creation_fee_mint` when it is set.
+pub pool_creation_fee_amount: u64,
+/// The mint of the token the pool creation fee is paid in.
+/// The default public key stands for native SOL.
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pool_creation_fee_mint: Pubkey,
+/// Whether only creators from the `AllowedCreator` allow-list can create pools with this configuration.
+pub creator_restricted: bool,
+/// The share of the protocol fee of a swap paid to the referrer token account passed with it,
+/// measured in basis points of the protocol fee. Capped by `MAX_REFERRER_FEE_SHARE_BASIS_POINTS`.
+pub referrer_fee_share_basis_points: u16,
+/// The delay, in seconds, between scheduling a providers or protocol fee rate change and its taking effect.
+/// Zero applies the changes immediately. Capped by `MAX_FEE_RATES_TIMELOCK_DURATION`.
+pub fee_rates_timelock_duration: i64,
+/// The providers fee rate scheduled to replace `providers_fee_rate_basis_points`.
+pub pending_providers_fee_rate_basis_points: u16,
+/// The protocol fee rate scheduled to replace `protocol_fee_rate_basis_points`.
+pub pending_protocol_fee_rate_basis_points: u16,
+/// The timestamp from which the pending fee rates are in effect.
+/// Zero stands for no scheduled change.
+pub pending_fee_rates_effective_timestamp: i64,
 }
 
 
 impl AmmsConfig {
-      pub const LEN: usize = 53;
+      pub const LEN: usize = 126;
   
   
   
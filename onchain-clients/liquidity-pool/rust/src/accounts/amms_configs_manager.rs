@@ -24,11 +24,19 @@ pub head_authority: Pubkey,
 pub configs_count: u64,
 /// The canonical bump seed used for the account's PDA.
 pub bump: u8,
+/// The authority proposed to replace `authority`, which takes over once it accepts the transfer.
+/// The default public key stands for no pending transfer.
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_authority: Pubkey,
+/// The head authority proposed to replace `head_authority`, which takes over once it accepts the transfer.
+/// The default public key stands for no pending transfer.
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_head_authority: Pubkey,
 }
 
 
 impl AmmsConfigsManager {
-      pub const LEN: usize = 81;
+      pub const LEN: usize = 145;
   
   
   
@@ -69,11 +69,43 @@ pub amms_config: Pubkey,
 /// Public key of the CpAmm creator account.
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub creator: Pubkey,
+/// Time-weighted sum of the base-per-quote price, stored as a Q64.128 fixed-point number.
+/// Wraps on overflow, so only the difference between two observations is meaningful.
+pub cumulative_base_per_quote_price: Q64128,
+/// Time-weighted sum of the quote-per-base price, stored as a Q64.128 fixed-point number.
+/// Wraps on overflow, so only the difference between two observations is meaningful.
+pub cumulative_quote_per_base_price: Q64128,
+/// Unix timestamp of the last cumulative prices update.
+pub last_price_update_timestamp: i64,
+/// Decaying accumulator of the recent price movement, measured in basis points.
+/// Drives the providers fee rate when the `AmmsConfig` dynamic fee mode is enabled.
+pub volatility_accumulator: u64,
+/// Unix timestamp of the last volatility accumulator update.
+pub last_volatility_update_timestamp: i64,
+/// Whether swaps are halted in this pool, independently of the `AmmsConfig` pause state.
+pub swaps_paused: bool,
+/// Whether liquidity provision is halted in this pool, independently of the `AmmsConfig` pause state.
+/// Withdrawals are never paused.
+pub provides_paused: bool,
+/// LP tokens accrued to the protocol in the `AmmsConfig` fee-on-LP mode, which are not minted yet.
+/// Already included in `lp_tokens_supply`.
+pub protocol_lp_tokens_to_redeem: u64,
+/// The square root of the constant product at the last protocol LP fees checkpoint.
+/// Zero while the `AmmsConfig` fee-on-LP mode is disabled.
+pub last_constant_product_sqrt: Q64128,
+/// Base tokens lent by the flash loan in progress. Zero when there is no flash loan.
+pub flash_loan_base_amount: u64,
+/// Quote tokens lent by the flash loan in progress. Zero when there is no flash loan.
+pub flash_loan_quote_amount: u64,
+/// Base tokens fee owed on top of `flash_loan_base_amount` by the flash loan in progress.
+pub flash_loan_base_fee_amount: u64,
+/// Quote tokens fee owed on top of `flash_loan_quote_amount` by the flash loan in progress.
+pub flash_loan_quote_fee_amount: u64,
 }
 
 
 impl CpAmm {
-      pub const LEN: usize = 366;
+      pub const LEN: usize = 504;
   
   
   
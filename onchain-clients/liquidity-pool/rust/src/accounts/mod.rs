@@ -5,11 +5,33 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#allowed_creator;
   pub(crate) mod r#amms_config;
   pub(crate) mod r#amms_configs_manager;
+  pub(crate) mod r#cl_amm;
+  pub(crate) mod r#cl_amm_position;
+  pub(crate) mod r#cl_amm_tick_array;
   pub(crate) mod r#cp_amm;
+  pub(crate) mod r#cp_amm_farm;
+  pub(crate) mod r#cp_amm_farm_position;
+  pub(crate) mod r#cp_amm_limit_order;
+  pub(crate) mod r#cp_amm_lp_lock;
+  pub(crate) mod r#cp_amm_observations;
+  pub(crate) mod r#cp_amm_pair;
+  pub(crate) mod r#stable_amm;
 
+  pub use self::r#allowed_creator::*;
   pub use self::r#amms_config::*;
   pub use self::r#amms_configs_manager::*;
+  pub use self::r#cl_amm::*;
+  pub use self::r#cl_amm_position::*;
+  pub use self::r#cl_amm_tick_array::*;
   pub use self::r#cp_amm::*;
+  pub use self::r#cp_amm_farm::*;
+  pub use self::r#cp_amm_farm_position::*;
+  pub use self::r#cp_amm_limit_order::*;
+  pub use self::r#cp_amm_lp_lock::*;
+  pub use self::r#cp_amm_observations::*;
+  pub use self::r#cp_amm_pair::*;
+  pub use self::r#stable_amm::*;
 
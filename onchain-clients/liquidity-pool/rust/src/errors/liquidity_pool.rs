@@ -106,6 +106,279 @@ pub enum LiquidityPoolError {
     /// 6031 - Liquidity ratio tolerance exceeded.
     #[error("Liquidity ratio tolerance exceeded.")]
     LiquidityRatioToleranceExceeded = 0x178F,
+    /// 6032 - Failed to calculate required swap input due to invalid input or overflow.
+    #[error("Failed to calculate required swap input due to invalid input or overflow.")]
+    BeforeswapCalculationFailed = 0x1790,
+    /// 6033 - Required swap input exceeds the maximum allowed input.
+    #[error("Required swap input exceeds the maximum allowed input.")]
+    SwapMaxInputExceeded = 0x1791,
+    /// 6034 - Routed swap accounts must describe between 1 and the maximal allowed number of hops, 10 accounts per hop.
+    #[error("Routed swap accounts must describe between 1 and the maximal allowed number of hops, 10 accounts per hop.")]
+    InvalidRouteLength = 0x1792,
+    /// 6035 - Routed swap hop accounts do not match the CpAmm state.
+    #[error("Routed swap hop accounts do not match the CpAmm state.")]
+    InvalidRouteHopAccounts = 0x1793,
+    /// 6036 - Routed swap hops are not chained: output of a hop must be the input of the next one.
+    #[error("Routed swap hops are not chained: output of a hop must be the input of the next one.")]
+    RouteIsNotChained = 0x1794,
+    /// 6037 - Routed swap result is less than the minimal expected result.
+    #[error("Routed swap result is less than the minimal expected result.")]
+    RoutedSwapResultBelowMinimum = 0x1795,
+    /// 6038 - Price observation is too close to the previous one.
+    #[error("Price observation is too close to the previous one.")]
+    ObservationIntervalTooShort = 0x1796,
+    /// 6039 - No price observation old enough for the requested TWAP window.
+    #[error("No price observation old enough for the requested TWAP window.")]
+    TwapObservationNotFound = 0x1797,
+    /// 6040 - Failed to calculate TWAP due to invalid observations or overflow.
+    #[error("Failed to calculate TWAP due to invalid observations or overflow.")]
+    TwapCalculationFailed = 0x1798,
+    /// 6041 - StableAmm is already initialized.
+    #[error("StableAmm is already initialized.")]
+    StableAmmAlreadyInitialized = 0x1799,
+    /// 6042 - StableAmm is not initialized.
+    #[error("StableAmm is not initialized.")]
+    StableAmmNotInitialized = 0x179A,
+    /// 6043 - StableAmm is already launched.
+    #[error("StableAmm is already launched.")]
+    StableAmmAlreadyLaunched = 0x179B,
+    /// 6044 - StableAmm is not launched.
+    #[error("StableAmm is not launched.")]
+    StableAmmNotLaunched = 0x179C,
+    /// 6045 - StableAmm base and quote mints must have the same decimals.
+    #[error("StableAmm base and quote mints must have the same decimals.")]
+    StableAmmMintsDecimalsMismatch = 0x179D,
+    /// 6046 - Amplification coefficient is out of the allowed range.
+    #[error("Amplification coefficient is out of the allowed range.")]
+    InvalidAmplification = 0x179E,
+    /// 6047 - Amplification ramp duration is shorter than the minimal allowed duration.
+    #[error("Amplification ramp duration is shorter than the minimal allowed duration.")]
+    AmplificationRampTooShort = 0x179F,
+    /// 6048 - Amplification coefficient change exceeds the maximal allowed change.
+    #[error("Amplification coefficient change exceeds the maximal allowed change.")]
+    AmplificationChangeTooLarge = 0x17A0,
+    /// 6049 - Failed to calculate the StableSwap invariant due to divergence or overflow.
+    #[error("Failed to calculate the StableSwap invariant due to divergence or overflow.")]
+    InvariantCalculationFailed = 0x17A1,
+    /// 6050 - StableSwap invariant decreased beyond the allowed tolerance.
+    #[error("StableSwap invariant decreased beyond the allowed tolerance.")]
+    InvariantToleranceExceeded = 0x17A2,
+    /// 6051 - Dynamic providers fee rate lower bound exceeds the upper bound.
+    #[error("Dynamic providers fee rate lower bound exceeds the upper bound.")]
+    InvalidDynamicFeeBounds = 0x17A3,
+    /// 6052 - Zap amount can't be zero.
+    #[error("Zap amount can't be zero.")]
+    ZapAmountIsZero = 0x17A4,
+    /// 6053 - Failed to calculate the zap swap amount due to an invalid fee rate or overflow.
+    #[error("Failed to calculate the zap swap amount due to an invalid fee rate or overflow.")]
+    ZapSwapAmountCalculationFailed = 0x17A5,
+    /// 6054 - Zap minted LP tokens are less than the minimal expected amount.
+    #[error("Zap minted LP tokens are less than the minimal expected amount.")]
+    ZapLpTokensBelowMinimum = 0x17A6,
+    /// 6055 - Zap result is less than the minimal expected result.
+    #[error("Zap result is less than the minimal expected result.")]
+    ZapResultBelowMinimum = 0x17A7,
+    /// 6056 - Provided liquidity mints less LP tokens than the minimal expected amount.
+    #[error("Provided liquidity mints less LP tokens than the minimal expected amount.")]
+    ProvideLpTokensBelowMinimum = 0x17A8,
+    /// 6057 - Withdrawn base liquidity is less than the minimal expected amount.
+    #[error("Withdrawn base liquidity is less than the minimal expected amount.")]
+    WithdrawBaseBelowMinimum = 0x17A9,
+    /// 6058 - Withdrawn quote liquidity is less than the minimal expected amount.
+    #[error("Withdrawn quote liquidity is less than the minimal expected amount.")]
+    WithdrawQuoteBelowMinimum = 0x17AA,
+    /// 6059 - Swaps are paused.
+    #[error("Swaps are paused.")]
+    SwapsPaused = 0x17AB,
+    /// 6060 - Liquidity provision is paused.
+    #[error("Liquidity provision is paused.")]
+    ProvidesPaused = 0x17AC,
+    /// 6061 - Requested protocol fees exceed the fees available for redemption.
+    #[error("Requested protocol fees exceed the fees available for redemption.")]
+    CollectFeesAmountExceeded = 0x17AD,
+    /// 6062 - Invalid number of fees sweep remaining accounts.
+    #[error("Invalid number of fees sweep remaining accounts.")]
+    InvalidFeesSweepLength = 0x17AE,
+    /// 6063 - Fees sweep accounts don't match the CpAmm.
+    #[error("Fees sweep accounts don't match the CpAmm.")]
+    InvalidFeesSweepAccounts = 0x17AF,
+    /// 6064 - Protocol LP fees to claim are zero.
+    #[error("Protocol LP fees to claim are zero.")]
+    ProtocolLpFeesIsZero = 0x17B0,
+    /// 6065 - Requested protocol LP tokens exceed the accrued protocol LP tokens.
+    #[error("Requested protocol LP tokens exceed the accrued protocol LP tokens.")]
+    ProtocolLpFeesAmountExceeded = 0x17B1,
+    /// 6066 - ClAmm is already initialized.
+    #[error("ClAmm is already initialized.")]
+    ClAmmAlreadyInitialized = 0x17B2,
+    /// 6067 - ClAmm is not initialized.
+    #[error("ClAmm is not initialized.")]
+    ClAmmNotInitialized = 0x17B3,
+    /// 6068 - Tick spacing is out of the allowed range.
+    #[error("Tick spacing is out of the allowed range.")]
+    InvalidTickSpacing = 0x17B4,
+    /// 6069 - Initial square root price is out of the allowed range.
+    #[error("Initial square root price is out of the allowed range.")]
+    InvalidInitialSqrtPrice = 0x17B5,
+    /// 6070 - Tick range is invalid or not aligned to the tick spacing.
+    #[error("Tick range is invalid or not aligned to the tick spacing.")]
+    InvalidTickRange = 0x17B6,
+    /// 6071 - Tick array start tick is not aligned to the tick array span or is out of range.
+    #[error("Tick array start tick is not aligned to the tick array span or is out of range.")]
+    InvalidTickArrayStartTick = 0x17B7,
+    /// 6072 - Tick is not covered by the tick array.
+    #[error("Tick is not covered by the tick array.")]
+    TickNotInTickArray = 0x17B8,
+    /// 6073 - Tick arrays don't cover the swap path from the current tick.
+    #[error("Tick arrays don't cover the swap path from the current tick.")]
+    InvalidTickArrays = 0x17B9,
+    /// 6074 - Provided liquidity is zero.
+    #[error("Provided liquidity is zero.")]
+    ProvidedClAmmLiquidityIsZero = 0x17BA,
+    /// 6075 - Required token amounts exceed the maximal amounts.
+    #[error("Required token amounts exceed the maximal amounts.")]
+    ClAmmProvideAmountsExceeded = 0x17BB,
+    /// 6076 - Withdrawn liquidity exceeds the position liquidity.
+    #[error("Withdrawn liquidity exceeds the position liquidity.")]
+    ClAmmPositionLiquidityExceeded = 0x17BC,
+    /// 6077 - Liquidity calculation failed.
+    #[error("Liquidity calculation failed.")]
+    ClAmmLiquidityCalculationFailed = 0x17BD,
+    /// 6078 - Swap calculation failed.
+    #[error("Swap calculation failed.")]
+    ClAmmSwapCalculationFailed = 0x17BE,
+    /// 6079 - Swap exhausted the liquidity of the provided tick arrays.
+    #[error("Swap exhausted the liquidity of the provided tick arrays.")]
+    ClAmmSwapLiquidityExhausted = 0x17BF,
+    /// 6080 - Position fees to collect are zero.
+    #[error("Position fees to collect are zero.")]
+    PositionFeesIsZero = 0x17C0,
+    /// 6081 - Flash loan is in progress in this pool.
+    #[error("Flash loan is in progress in this pool.")]
+    FlashLoanInProgress = 0x17C1,
+    /// 6082 - There is no flash loan in progress in this pool.
+    #[error("There is no flash loan in progress in this pool.")]
+    FlashLoanNotActive = 0x17C2,
+    /// 6083 - Flash loan amounts are zero.
+    #[error("Flash loan amounts are zero.")]
+    FlashLoanAmountIsZero = 0x17C3,
+    /// 6084 - Flash loan amount exceeds the pool liquidity.
+    #[error("Flash loan amount exceeds the pool liquidity.")]
+    FlashLoanAmountExceeded = 0x17C4,
+    /// 6085 - Flash loan fees are zero.
+    #[error("Flash loan fees are zero.")]
+    FlashLoanFeesAreZero = 0x17C5,
+    /// 6086 - Flash loan is not repaid with fees.
+    #[error("Flash loan is not repaid with fees.")]
+    FlashLoanNotRepaid = 0x17C6,
+    /// 6087 - Flash loan repay instruction for this pool is missing later in the transaction.
+    #[error("Flash loan repay instruction for this pool is missing later in the transaction.")]
+    FlashLoanRepayNotFound = 0x17C7,
+    /// 6088 - Flash loan can only be taken by a top-level instruction.
+    #[error("Flash loan can only be taken by a top-level instruction.")]
+    FlashLoanCpiNotAllowed = 0x17C8,
+    /// 6089 - Creator is not allowed to create pools with this config.
+    #[error("Creator is not allowed to create pools with this config.")]
+    CreatorNotAllowed = 0x17C9,
+    /// 6090 - Creator has reached the pools cap of this config.
+    #[error("Creator has reached the pools cap of this config.")]
+    CreatorPoolsCapReached = 0x17CA,
+    /// 6091 - Pool creation fee accounts are missing.
+    #[error("Pool creation fee accounts are missing.")]
+    PoolCreationFeeAccountsMissing = 0x17CB,
+    /// 6092 - Pool creation fee accounts are invalid.
+    #[error("Pool creation fee accounts are invalid.")]
+    InvalidPoolCreationFeeAccounts = 0x17CC,
+    /// 6093 - The pair already has a canonical CpAmm in this config.
+    #[error("The pair already has a canonical CpAmm in this config.")]
+    CpAmmPairAlreadyRegistered = 0x17CD,
+    /// 6094 - CpAmm is not the canonical pool of the pair.
+    #[error("CpAmm is not the canonical pool of the pair.")]
+    CpAmmIsNotCanonical = 0x17CE,
+    /// 6095 - Locked LP share must be between 1 and 10000 basis points.
+    #[error("Locked LP share must be between 1 and 10000 basis points.")]
+    InvalidLpLockShare = 0x17CF,
+    /// 6096 - Locked LP tokens amount is zero.
+    #[error("Locked LP tokens amount is zero.")]
+    LpLockAmountIsZero = 0x17D0,
+    /// 6097 - LP lock schedule is invalid.
+    #[error("LP lock schedule is invalid.")]
+    InvalidLpLockSchedule = 0x17D1,
+    /// 6098 - There are no unlocked LP tokens to claim.
+    #[error("There are no unlocked LP tokens to claim.")]
+    NothingToClaimFromLpLock = 0x17D2,
+    /// 6099 - Farm already streams the maximal number of reward mints.
+    #[error("Farm already streams the maximal number of reward mints.")]
+    FarmRewardsLimitReached = 0x17D3,
+    /// 6100 - The emission period of this reward mint has not ended yet.
+    #[error("The emission period of this reward mint has not ended yet.")]
+    FarmRewardScheduleActive = 0x17D4,
+    /// 6101 - Farm reward schedule is invalid.
+    #[error("Farm reward schedule is invalid.")]
+    InvalidFarmRewardSchedule = 0x17D5,
+    /// 6102 - Farm reward amount is zero.
+    #[error("Farm reward amount is zero.")]
+    FarmRewardAmountIsZero = 0x17D6,
+    /// 6103 - Farm reward calculation overflowed.
+    #[error("Farm reward calculation overflowed.")]
+    FarmRewardCalculationOverflow = 0x17D7,
+    /// 6104 - LP tokens amount is zero.
+    #[error("LP tokens amount is zero.")]
+    FarmLpTokensAmountIsZero = 0x17D8,
+    /// 6105 - Position has fewer staked LP tokens than requested.
+    #[error("Position has fewer staked LP tokens than requested.")]
+    InsufficientFarmStake = 0x17D9,
+    /// 6106 - Farm reward index is invalid.
+    #[error("Farm reward index is invalid.")]
+    InvalidFarmRewardIndex = 0x17DA,
+    /// 6107 - There are no earned rewards to harvest.
+    #[error("There are no earned rewards to harvest.")]
+    NothingToHarvest = 0x17DB,
+    /// 6108 - Limit order amounts must be greater than zero.
+    #[error("Limit order amounts must be greater than zero.")]
+    LimitOrderAmountIsZero = 0x17DC,
+    /// 6109 - Limit order target ratio must be greater than zero.
+    #[error("Limit order target ratio must be greater than zero.")]
+    InvalidLimitOrderTarget = 0x17DD,
+    /// 6110 - Limit order mints don't match the CpAmm.
+    #[error("Limit order mints don't match the CpAmm.")]
+    InvalidLimitOrderMints = 0x17DE,
+    /// 6111 - Limit orders fill accounts must describe between 1 and the maximal allowed number of orders, 4 accounts per order.
+    #[error("Limit orders fill accounts must describe between 1 and the maximal allowed number of orders, 4 accounts per order.")]
+    InvalidLimitOrdersFillLength = 0x17DF,
+    /// 6112 - Limit order fill accounts don't match the order.
+    #[error("Limit order fill accounts don't match the order.")]
+    InvalidLimitOrderFillAccounts = 0x17E0,
+    /// 6113 - None of the limit orders could be filled.
+    #[error("None of the limit orders could be filled.")]
+    NoLimitOrdersFilled = 0x17E1,
+    /// 6114 - CpAmm vaults hold no tokens above the stored liquidity and protocol fees.
+    #[error("CpAmm vaults hold no tokens above the stored liquidity and protocol fees.")]
+    VaultsExcessIsZero = 0x17E2,
+    /// 6115 - Referrer fee share exceeds the maximal allowed share of the protocol fee.
+    #[error("Referrer fee share exceeds the maximal allowed share of the protocol fee.")]
+    ReferrerFeeShareExceeded = 0x17E3,
+    /// 6116 - Referrer account mint doesn't match the swap input mint.
+    #[error("Referrer account mint doesn't match the swap input mint.")]
+    InvalidReferrerAccount = 0x17E4,
+    /// 6117 - There is no pending authority transfer.
+    #[error("There is no pending authority transfer.")]
+    NoPendingAuthorityTransfer = 0x17E5,
+    /// 6118 - Fee rates timelock can't be shortened or exceed the maximal allowed duration.
+    #[error("Fee rates timelock can't be shortened or exceed the maximal allowed duration.")]
+    InvalidFeeRatesTimelockDuration = 0x17E6,
+    /// 6119 - There is no pending fee rates change to cancel.
+    #[error("There is no pending fee rates change to cancel.")]
+    NoPendingFeeRatesChange = 0x17E7,
+    /// 6120 - Transaction deadline has passed.
+    #[error("Transaction deadline has passed.")]
+    DeadlineExceeded = 0x17E8,
+    /// 6121 - Limit order minimal received amount is below the amount implied by its target ratio.
+    #[error("Limit order minimal received amount is below the amount implied by its target ratio.")]
+    LimitOrderMinimumBelowTarget = 0x17E9,
+    /// 6122 - CpAmm swaps can't be referred while the protocol fee is collected as LP tokens.
+    #[error("CpAmm swaps can't be referred while the protocol fee is collected as LP tokens.")]
+    ReferrerUnsupportedWithProtocolFeeOnLp = 0x17EA,
 }
 
 impl solana_program::program_error::PrintProgramError for LiquidityPoolError {
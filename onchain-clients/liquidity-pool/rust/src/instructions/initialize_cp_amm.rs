@@ -34,6 +34,9 @@ pub struct InitializeCpAmm {
           pub cp_amm: solana_program::pubkey::Pubkey,
           
               
+          pub cp_amm_pair: solana_program::pubkey::Pubkey,
+          
+              
           pub cp_amm_base_vault: solana_program::pubkey::Pubkey,
           
               
@@ -56,6 +59,27 @@ pub struct InitializeCpAmm {
           
               
           pub quote_token_program: solana_program::pubkey::Pubkey,
+          
+              
+          pub allowed_creator: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub pool_creation_fee_mint: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub signer_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub fee_authority_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub pool_creation_fee_token_program: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub base_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>,
+          
+              
+          pub quote_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>,
       }
 
 impl InitializeCpAmm {
@@ -65,7 +89,7 @@ impl InitializeCpAmm {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_program::instruction::AccountMeta]) -> solana_program::instruction::Instruction {
-    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(23+ remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             self.signer,
             true
@@ -93,6 +117,10 @@ impl InitializeCpAmm {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             self.cp_amm,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            self.cp_amm_pair,
+            false
           ));
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             self.cp_amm_base_vault,
@@ -126,6 +154,83 @@ impl InitializeCpAmm {
             self.quote_token_program,
             false
           ));
+                                          if let Some(allowed_creator) = self.allowed_creator {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          allowed_creator,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(pool_creation_fee_mint) = self.pool_creation_fee_mint {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          pool_creation_fee_mint,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(signer_pool_creation_fee_account) = self.signer_pool_creation_fee_account {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          signer_pool_creation_fee_account,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(fee_authority_pool_creation_fee_account) = self.fee_authority_pool_creation_fee_account {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          fee_authority_pool_creation_fee_account,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(pool_creation_fee_token_program) = self.pool_creation_fee_token_program {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          pool_creation_fee_token_program,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(base_mint_metaplex_metadata) = self.base_mint_metaplex_metadata {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          base_mint_metaplex_metadata,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(quote_mint_metaplex_metadata) = self.quote_mint_metaplex_metadata {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          quote_mint_metaplex_metadata,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
                       accounts.extend_from_slice(remaining_accounts);
     let data = borsh::to_vec(&InitializeCpAmmInstructionData::new()).unwrap();
     
@@ -158,26 +263,33 @@ impl Default for InitializeCpAmmInstructionData {
 }
 
 
-
 /// Instruction builder for `InitializeCpAmm`.
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
-                ///   1. `[writable]` fee_authority
+          ///   0. `[writable, signer]` signer
+          ///   1. `[writable]` fee_authority
           ///   2. `[]` base_mint
           ///   3. `[]` quote_mint
-                      ///   4. `[writable, signer]` lp_mint
+          ///   4. `[writable, signer]` lp_mint
           ///   5. `[]` amms_config
-                ///   6. `[writable]` cp_amm
-                ///   7. `[writable]` cp_amm_base_vault
-                ///   8. `[writable]` cp_amm_quote_vault
-                ///   9. `[writable]` cp_amm_locked_lp_vault
-                ///   10. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
-                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
-                ///   12. `[optional]` lp_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-          ///   13. `[]` base_token_program
-          ///   14. `[]` quote_token_program
+          ///   6. `[writable]` cp_amm
+          ///   7. `[writable]` cp_amm_pair
+          ///   8. `[writable]` cp_amm_base_vault
+          ///   9. `[writable]` cp_amm_quote_vault
+          ///   10. `[writable]` cp_amm_locked_lp_vault
+                ///   11. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+                ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   13. `[optional]` lp_token_program (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+          ///   14. `[]` base_token_program
+          ///   15. `[]` quote_token_program
+                ///   16. `[writable, optional]` allowed_creator
+                ///   17. `[optional]` pool_creation_fee_mint
+                ///   18. `[writable, optional]` signer_pool_creation_fee_account
+                ///   19. `[writable, optional]` fee_authority_pool_creation_fee_account
+                ///   20. `[optional]` pool_creation_fee_token_program
+                ///   21. `[optional]` base_mint_metaplex_metadata
+                ///   22. `[optional]` quote_mint_metaplex_metadata
 #[derive(Clone, Debug, Default)]
 pub struct InitializeCpAmmBuilder {
             signer: Option<solana_program::pubkey::Pubkey>,
@@ -187,6 +299,7 @@ pub struct InitializeCpAmmBuilder {
                 lp_mint: Option<solana_program::pubkey::Pubkey>,
                 amms_config: Option<solana_program::pubkey::Pubkey>,
                 cp_amm: Option<solana_program::pubkey::Pubkey>,
+                cp_amm_pair: Option<solana_program::pubkey::Pubkey>,
                 cp_amm_base_vault: Option<solana_program::pubkey::Pubkey>,
                 cp_amm_quote_vault: Option<solana_program::pubkey::Pubkey>,
                 cp_amm_locked_lp_vault: Option<solana_program::pubkey::Pubkey>,
@@ -195,7 +308,14 @@ pub struct InitializeCpAmmBuilder {
                 lp_token_program: Option<solana_program::pubkey::Pubkey>,
                 base_token_program: Option<solana_program::pubkey::Pubkey>,
                 quote_token_program: Option<solana_program::pubkey::Pubkey>,
-                __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+                allowed_creator: Option<solana_program::pubkey::Pubkey>,
+                pool_creation_fee_mint: Option<solana_program::pubkey::Pubkey>,
+                signer_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>,
+                fee_authority_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>,
+                pool_creation_fee_token_program: Option<solana_program::pubkey::Pubkey>,
+                base_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>,
+                quote_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>,
+        __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
 impl InitializeCpAmmBuilder {
@@ -238,6 +358,11 @@ impl InitializeCpAmmBuilder {
                     self
     }
             #[inline(always)]
+    pub fn cp_amm_pair(&mut self, cp_amm_pair: solana_program::pubkey::Pubkey) -> &mut Self {
+                        self.cp_amm_pair = Some(cp_amm_pair);
+                    self
+    }
+            #[inline(always)]
     pub fn cp_amm_base_vault(&mut self, cp_amm_base_vault: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.cp_amm_base_vault = Some(cp_amm_base_vault);
                     self
@@ -264,7 +389,7 @@ impl InitializeCpAmmBuilder {
                         self.system_program = Some(system_program);
                     self
     }
-            /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+            /// `[optional account, default to 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']`
 #[inline(always)]
     pub fn lp_token_program(&mut self, lp_token_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.lp_token_program = Some(lp_token_program);
@@ -280,7 +405,49 @@ impl InitializeCpAmmBuilder {
                         self.quote_token_program = Some(quote_token_program);
                     self
     }
-            /// Add an additional account to the instruction.
+                /// `[optional account]`
+#[inline(always)]
+    pub fn allowed_creator(&mut self, allowed_creator: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.allowed_creator = allowed_creator;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn pool_creation_fee_mint(&mut self, pool_creation_fee_mint: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.pool_creation_fee_mint = pool_creation_fee_mint;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn signer_pool_creation_fee_account(&mut self, signer_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.signer_pool_creation_fee_account = signer_pool_creation_fee_account;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn fee_authority_pool_creation_fee_account(&mut self, fee_authority_pool_creation_fee_account: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.fee_authority_pool_creation_fee_account = fee_authority_pool_creation_fee_account;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn pool_creation_fee_token_program(&mut self, pool_creation_fee_token_program: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.pool_creation_fee_token_program = pool_creation_fee_token_program;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn base_mint_metaplex_metadata(&mut self, base_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.base_mint_metaplex_metadata = base_mint_metaplex_metadata;
+                    self
+    }
+                /// `[optional account]`
+#[inline(always)]
+    pub fn quote_mint_metaplex_metadata(&mut self, quote_mint_metaplex_metadata: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+                        self.quote_mint_metaplex_metadata = quote_mint_metaplex_metadata;
+                    self
+    }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: solana_program::instruction::AccountMeta) -> &mut Self {
     self.__remaining_accounts.push(account);
@@ -302,14 +469,22 @@ impl InitializeCpAmmBuilder {
                                         lp_mint: self.lp_mint.expect("lp_mint is not set"),
                                         amms_config: self.amms_config.expect("amms_config is not set"),
                                         cp_amm: self.cp_amm.expect("cp_amm is not set"),
+                                        cp_amm_pair: self.cp_amm_pair.expect("cp_amm_pair is not set"),
                                         cp_amm_base_vault: self.cp_amm_base_vault.expect("cp_amm_base_vault is not set"),
                                         cp_amm_quote_vault: self.cp_amm_quote_vault.expect("cp_amm_quote_vault is not set"),
                                         cp_amm_locked_lp_vault: self.cp_amm_locked_lp_vault.expect("cp_amm_locked_lp_vault is not set"),
                                         rent: self.rent.unwrap_or(solana_program::pubkey!("SysvarRent111111111111111111111111111111111")),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
-                                        lp_token_program: self.lp_token_program.unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        lp_token_program: self.lp_token_program.unwrap_or(solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")),
                                         base_token_program: self.base_token_program.expect("base_token_program is not set"),
                                         quote_token_program: self.quote_token_program.expect("quote_token_program is not set"),
+                                        allowed_creator: self.allowed_creator,
+                                        pool_creation_fee_mint: self.pool_creation_fee_mint,
+                                        signer_pool_creation_fee_account: self.signer_pool_creation_fee_account,
+                                        fee_authority_pool_creation_fee_account: self.fee_authority_pool_creation_fee_account,
+                                        pool_creation_fee_token_program: self.pool_creation_fee_token_program,
+                                        base_mint_metaplex_metadata: self.base_mint_metaplex_metadata,
+                                        quote_mint_metaplex_metadata: self.quote_mint_metaplex_metadata,
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -341,6 +516,9 @@ impl InitializeCpAmmBuilder {
               pub cp_amm: &'b solana_program::account_info::AccountInfo<'a>,
                 
                     
+              pub cp_amm_pair: &'b solana_program::account_info::AccountInfo<'a>,
+                
+                    
               pub cp_amm_base_vault: &'b solana_program::account_info::AccountInfo<'a>,
                 
                     
@@ -363,6 +541,27 @@ impl InitializeCpAmmBuilder {
                 
                     
               pub quote_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+                
+                    
+              pub allowed_creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub pool_creation_fee_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub signer_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub fee_authority_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub pool_creation_fee_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub base_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                
+                    
+              pub quote_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
             }
 
 /// `initialize_cp_amm` CPI instruction.
@@ -392,6 +591,9 @@ pub struct InitializeCpAmmCpi<'a, 'b> {
           pub cp_amm: &'b solana_program::account_info::AccountInfo<'a>,
           
               
+          pub cp_amm_pair: &'b solana_program::account_info::AccountInfo<'a>,
+          
+              
           pub cp_amm_base_vault: &'b solana_program::account_info::AccountInfo<'a>,
           
               
@@ -414,6 +616,27 @@ pub struct InitializeCpAmmCpi<'a, 'b> {
           
               
           pub quote_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+          
+              
+          pub allowed_creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub pool_creation_fee_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub signer_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub fee_authority_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub pool_creation_fee_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub base_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+          
+              
+          pub quote_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
         }
 
 impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
@@ -430,6 +653,7 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
               lp_mint: accounts.lp_mint,
               amms_config: accounts.amms_config,
               cp_amm: accounts.cp_amm,
+              cp_amm_pair: accounts.cp_amm_pair,
               cp_amm_base_vault: accounts.cp_amm_base_vault,
               cp_amm_quote_vault: accounts.cp_amm_quote_vault,
               cp_amm_locked_lp_vault: accounts.cp_amm_locked_lp_vault,
@@ -438,6 +662,13 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
               lp_token_program: accounts.lp_token_program,
               base_token_program: accounts.base_token_program,
               quote_token_program: accounts.quote_token_program,
+              allowed_creator: accounts.allowed_creator,
+              pool_creation_fee_mint: accounts.pool_creation_fee_mint,
+              signer_pool_creation_fee_account: accounts.signer_pool_creation_fee_account,
+              fee_authority_pool_creation_fee_account: accounts.fee_authority_pool_creation_fee_account,
+              pool_creation_fee_token_program: accounts.pool_creation_fee_token_program,
+              base_mint_metaplex_metadata: accounts.base_mint_metaplex_metadata,
+              quote_mint_metaplex_metadata: accounts.quote_mint_metaplex_metadata,
                 }
   }
   #[inline(always)]
@@ -460,7 +691,7 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program::entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(23+ remaining_accounts.len());
                             accounts.push(solana_program::instruction::AccountMeta::new(
             *self.signer.key,
             true
@@ -488,6 +719,10 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             *self.cp_amm.key,
             false
+          ));
+                                          accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.cp_amm_pair.key,
+            false
           ));
                                           accounts.push(solana_program::instruction::AccountMeta::new(
             *self.cp_amm_base_vault.key,
@@ -521,6 +756,83 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
             *self.quote_token_program.key,
             false
           ));
+                                          if let Some(allowed_creator) = self.allowed_creator {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          *allowed_creator.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(pool_creation_fee_mint) = self.pool_creation_fee_mint {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          *pool_creation_fee_mint.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(signer_pool_creation_fee_account) = self.signer_pool_creation_fee_account {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          *signer_pool_creation_fee_account.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(fee_authority_pool_creation_fee_account) = self.fee_authority_pool_creation_fee_account {
+              accounts.push(solana_program::instruction::AccountMeta::new(
+          *fee_authority_pool_creation_fee_account.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(pool_creation_fee_token_program) = self.pool_creation_fee_token_program {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          *pool_creation_fee_token_program.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(base_mint_metaplex_metadata) = self.base_mint_metaplex_metadata {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          *base_mint_metaplex_metadata.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
+                                          if let Some(quote_mint_metaplex_metadata) = self.quote_mint_metaplex_metadata {
+              accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          *quote_mint_metaplex_metadata.key,
+          false,
+        ));
+          } else {
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+          crate::LIQUIDITY_POOL_ID,
+          false,
+        ));
+      }
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_program::instruction::AccountMeta {
           pubkey: *remaining_account.0.key,
@@ -535,7 +847,7 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(24 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.signer.clone());
                         account_infos.push(self.fee_authority.clone());
@@ -544,6 +856,7 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
                         account_infos.push(self.lp_mint.clone());
                         account_infos.push(self.amms_config.clone());
                         account_infos.push(self.cp_amm.clone());
+                        account_infos.push(self.cp_amm_pair.clone());
                         account_infos.push(self.cp_amm_base_vault.clone());
                         account_infos.push(self.cp_amm_quote_vault.clone());
                         account_infos.push(self.cp_amm_locked_lp_vault.clone());
@@ -552,6 +865,27 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
                         account_infos.push(self.lp_token_program.clone());
                         account_infos.push(self.base_token_program.clone());
                         account_infos.push(self.quote_token_program.clone());
+        if let Some(allowed_creator) = self.allowed_creator {
+          account_infos.push(allowed_creator.clone());
+        }
+        if let Some(pool_creation_fee_mint) = self.pool_creation_fee_mint {
+          account_infos.push(pool_creation_fee_mint.clone());
+        }
+        if let Some(signer_pool_creation_fee_account) = self.signer_pool_creation_fee_account {
+          account_infos.push(signer_pool_creation_fee_account.clone());
+        }
+        if let Some(fee_authority_pool_creation_fee_account) = self.fee_authority_pool_creation_fee_account {
+          account_infos.push(fee_authority_pool_creation_fee_account.clone());
+        }
+        if let Some(pool_creation_fee_token_program) = self.pool_creation_fee_token_program {
+          account_infos.push(pool_creation_fee_token_program.clone());
+        }
+        if let Some(base_mint_metaplex_metadata) = self.base_mint_metaplex_metadata {
+          account_infos.push(base_mint_metaplex_metadata.clone());
+        }
+        if let Some(quote_mint_metaplex_metadata) = self.quote_mint_metaplex_metadata {
+          account_infos.push(quote_mint_metaplex_metadata.clone());
+        }
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -566,21 +900,29 @@ impl<'a, 'b> InitializeCpAmmCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
-                ///   1. `[writable]` fee_authority
+          ///   0. `[writable, signer]` signer
+          ///   1. `[writable]` fee_authority
           ///   2. `[]` base_mint
           ///   3. `[]` quote_mint
-                      ///   4. `[writable, signer]` lp_mint
+          ///   4. `[writable, signer]` lp_mint
           ///   5. `[]` amms_config
-                ///   6. `[writable]` cp_amm
-                ///   7. `[writable]` cp_amm_base_vault
-                ///   8. `[writable]` cp_amm_quote_vault
-                ///   9. `[writable]` cp_amm_locked_lp_vault
-          ///   10. `[]` rent
-          ///   11. `[]` system_program
-          ///   12. `[]` lp_token_program
-          ///   13. `[]` base_token_program
-          ///   14. `[]` quote_token_program
+          ///   6. `[writable]` cp_amm
+          ///   7. `[writable]` cp_amm_pair
+          ///   8. `[writable]` cp_amm_base_vault
+          ///   9. `[writable]` cp_amm_quote_vault
+          ///   10. `[writable]` cp_amm_locked_lp_vault
+          ///   11. `[]` rent
+          ///   12. `[]` system_program
+          ///   13. `[]` lp_token_program
+          ///   14. `[]` base_token_program
+          ///   15. `[]` quote_token_program
+          ///   16. `[writable, optional]` allowed_creator
+          ///   17. `[optional]` pool_creation_fee_mint
+          ///   18. `[writable, optional]` signer_pool_creation_fee_account
+          ///   19. `[writable, optional]` fee_authority_pool_creation_fee_account
+          ///   20. `[optional]` pool_creation_fee_token_program
+          ///   21. `[optional]` base_mint_metaplex_metadata
+          ///   22. `[optional]` quote_mint_metaplex_metadata
 #[derive(Clone, Debug)]
 pub struct InitializeCpAmmCpiBuilder<'a, 'b> {
   instruction: Box<InitializeCpAmmCpiBuilderInstruction<'a, 'b>>,
@@ -597,6 +939,7 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
               lp_mint: None,
               amms_config: None,
               cp_amm: None,
+              cp_amm_pair: None,
               cp_amm_base_vault: None,
               cp_amm_quote_vault: None,
               cp_amm_locked_lp_vault: None,
@@ -605,7 +948,14 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
               lp_token_program: None,
               base_token_program: None,
               quote_token_program: None,
-                                __remaining_accounts: Vec::new(),
+              allowed_creator: None,
+              pool_creation_fee_mint: None,
+              signer_pool_creation_fee_account: None,
+              fee_authority_pool_creation_fee_account: None,
+              pool_creation_fee_token_program: None,
+              base_mint_metaplex_metadata: None,
+              quote_mint_metaplex_metadata: None,
+                    __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
@@ -645,6 +995,11 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn cp_amm_pair(&mut self, cp_amm_pair: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.cp_amm_pair = Some(cp_amm_pair);
+                    self
+    }
+      #[inline(always)]
     pub fn cp_amm_base_vault(&mut self, cp_amm_base_vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.cp_amm_base_vault = Some(cp_amm_base_vault);
                     self
@@ -684,7 +1039,49 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
                         self.instruction.quote_token_program = Some(quote_token_program);
                     self
     }
-            /// Add an additional account to the instruction.
+      /// `[optional account]`
+#[inline(always)]
+    pub fn allowed_creator(&mut self, allowed_creator: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.allowed_creator = allowed_creator;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn pool_creation_fee_mint(&mut self, pool_creation_fee_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.pool_creation_fee_mint = pool_creation_fee_mint;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn signer_pool_creation_fee_account(&mut self, signer_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.signer_pool_creation_fee_account = signer_pool_creation_fee_account;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn fee_authority_pool_creation_fee_account(&mut self, fee_authority_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.fee_authority_pool_creation_fee_account = fee_authority_pool_creation_fee_account;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn pool_creation_fee_token_program(&mut self, pool_creation_fee_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.pool_creation_fee_token_program = pool_creation_fee_token_program;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn base_mint_metaplex_metadata(&mut self, base_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.base_mint_metaplex_metadata = base_mint_metaplex_metadata;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn quote_mint_metaplex_metadata(&mut self, quote_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.quote_mint_metaplex_metadata = quote_mint_metaplex_metadata;
+                    self
+    }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: &'b solana_program::account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
     self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
@@ -723,6 +1120,8 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
                   
           cp_amm: self.instruction.cp_amm.expect("cp_amm is not set"),
                   
+          cp_amm_pair: self.instruction.cp_amm_pair.expect("cp_amm_pair is not set"),
+                  
           cp_amm_base_vault: self.instruction.cp_amm_base_vault.expect("cp_amm_base_vault is not set"),
                   
           cp_amm_quote_vault: self.instruction.cp_amm_quote_vault.expect("cp_amm_quote_vault is not set"),
@@ -738,6 +1137,20 @@ impl<'a, 'b> InitializeCpAmmCpiBuilder<'a, 'b> {
           base_token_program: self.instruction.base_token_program.expect("base_token_program is not set"),
                   
           quote_token_program: self.instruction.quote_token_program.expect("quote_token_program is not set"),
+                  
+          allowed_creator: self.instruction.allowed_creator,
+                  
+          pool_creation_fee_mint: self.instruction.pool_creation_fee_mint,
+                  
+          signer_pool_creation_fee_account: self.instruction.signer_pool_creation_fee_account,
+                  
+          fee_authority_pool_creation_fee_account: self.instruction.fee_authority_pool_creation_fee_account,
+                  
+          pool_creation_fee_token_program: self.instruction.pool_creation_fee_token_program,
+                  
+          base_mint_metaplex_metadata: self.instruction.base_mint_metaplex_metadata,
+                  
+          quote_mint_metaplex_metadata: self.instruction.quote_mint_metaplex_metadata,
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -753,6 +1166,7 @@ struct InitializeCpAmmCpiBuilderInstruction<'a, 'b> {
                 lp_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 amms_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 cp_amm: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                cp_amm_pair: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 cp_amm_base_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 cp_amm_quote_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 cp_amm_locked_lp_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
@@ -761,7 +1175,14 @@ struct InitializeCpAmmCpiBuilderInstruction<'a, 'b> {
                 lp_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 base_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
                 quote_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-                /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+                allowed_creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                pool_creation_fee_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                signer_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                fee_authority_pool_creation_fee_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                pool_creation_fee_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                base_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+                quote_mint_metaplex_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_program::account_info::AccountInfo<'a>, bool, bool)>,
 }
 
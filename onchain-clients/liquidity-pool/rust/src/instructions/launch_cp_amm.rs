@@ -157,13 +157,13 @@ impl LaunchCpAmm {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct LaunchCpAmmInstructionData {
             discriminator: [u8; 8],
-                  }
+                              }
 
 impl LaunchCpAmmInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [205, 161, 177, 76, 92, 218, 37, 101],
-                                              }
+                                                                          }
   }
 }
 
@@ -185,19 +185,19 @@ impl Default for LaunchCpAmmInstructionData {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` creator
+          ///   0. `[writable, signer]` creator
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` creator_base_account
-                ///   5. `[writable]` creator_quote_account
-                ///   6. `[writable]` creator_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` creator_base_account
+          ///   5. `[writable]` creator_quote_account
+          ///   6. `[writable]` creator_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
-                ///   11. `[writable]` cp_amm_locked_lp_vault
-                ///   12. `[optional]` lp_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
+          ///   11. `[writable]` cp_amm_locked_lp_vault
+          ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
           ///   14. `[]` quote_token_program
                 ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -290,8 +290,7 @@ impl LaunchCpAmmBuilder {
                         self.cp_amm_locked_lp_vault = Some(cp_amm_locked_lp_vault);
                     self
     }
-            /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
-#[inline(always)]
+            #[inline(always)]
     pub fn lp_token_program(&mut self, lp_token_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.lp_token_program = Some(lp_token_program);
                     self
@@ -318,7 +317,7 @@ impl LaunchCpAmmBuilder {
                         self.associated_token_program = Some(associated_token_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn base_liquidity(&mut self, base_liquidity: u64) -> &mut Self {
         self.base_liquidity = Some(base_liquidity);
         self
@@ -355,7 +354,7 @@ impl LaunchCpAmmBuilder {
                                         cp_amm_base_vault: self.cp_amm_base_vault.expect("cp_amm_base_vault is not set"),
                                         cp_amm_quote_vault: self.cp_amm_quote_vault.expect("cp_amm_quote_vault is not set"),
                                         cp_amm_locked_lp_vault: self.cp_amm_locked_lp_vault.expect("cp_amm_locked_lp_vault is not set"),
-                                        lp_token_program: self.lp_token_program.unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        lp_token_program: self.lp_token_program.expect("lp_token_program is not set"),
                                         base_token_program: self.base_token_program.expect("base_token_program is not set"),
                                         quote_token_program: self.quote_token_program.expect("quote_token_program is not set"),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -363,7 +362,7 @@ impl LaunchCpAmmBuilder {
                       };
           let args = LaunchCpAmmInstructionArgs {
                                                               base_liquidity: self.base_liquidity.clone().expect("base_liquidity is not set"),
-                                                                  quote_liquidity: self.quote_liquidity.clone().expect("quote_liquidity is not set"),
+                                                              quote_liquidity: self.quote_liquidity.clone().expect("quote_liquidity is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -650,18 +649,18 @@ impl<'a, 'b> LaunchCpAmmCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` creator
+          ///   0. `[writable, signer]` creator
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` creator_base_account
-                ///   5. `[writable]` creator_quote_account
-                ///   6. `[writable]` creator_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` creator_base_account
+          ///   5. `[writable]` creator_quote_account
+          ///   6. `[writable]` creator_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
-                ///   11. `[writable]` cp_amm_locked_lp_vault
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
+          ///   11. `[writable]` cp_amm_locked_lp_vault
           ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
           ///   14. `[]` quote_token_program
@@ -784,7 +783,7 @@ impl<'a, 'b> LaunchCpAmmCpiBuilder<'a, 'b> {
                         self.instruction.associated_token_program = Some(associated_token_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn base_liquidity(&mut self, base_liquidity: u64) -> &mut Self {
         self.instruction.base_liquidity = Some(base_liquidity);
         self
@@ -818,7 +817,7 @@ impl<'a, 'b> LaunchCpAmmCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
           let args = LaunchCpAmmInstructionArgs {
                                                               base_liquidity: self.instruction.base_liquidity.clone().expect("base_liquidity is not set"),
-                                                                  quote_liquidity: self.instruction.quote_liquidity.clone().expect("quote_liquidity is not set"),
+                                                              quote_liquidity: self.instruction.quote_liquidity.clone().expect("quote_liquidity is not set"),
                                     };
         let instruction = LaunchCpAmmCpi {
         __program: self.instruction.__program,
@@ -5,31 +5,195 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#accept_amms_configs_manager_authority;
+  pub(crate) mod r#accept_amms_configs_manager_head_authority;
+  pub(crate) mod r#add_cp_amm_farm_reward;
+  pub(crate) mod r#cancel_amms_config_fee_rates_change;
+  pub(crate) mod r#cancel_amms_configs_manager_authority_transfer;
+  pub(crate) mod r#cancel_amms_configs_manager_head_authority_transfer;
+  pub(crate) mod r#cancel_cp_amm_limit_order;
+  pub(crate) mod r#claim_fees_from_cp_amm;
+  pub(crate) mod r#claim_from_cp_amm_lp_lock;
+  pub(crate) mod r#claim_lp_fees_from_cp_amm;
+  pub(crate) mod r#collect_fees_from_cl_amm;
+  pub(crate) mod r#collect_fees_from_cl_amm_position;
   pub(crate) mod r#collect_fees_from_cp_amm;
+  pub(crate) mod r#collect_fees_from_stable_amm;
+  pub(crate) mod r#deposit_to_cp_amm_farm;
+  pub(crate) mod r#fill_cp_amm_limit_orders;
+  pub(crate) mod r#flash_borrow_from_cp_amm;
+  pub(crate) mod r#flash_repay_to_cp_amm;
+  pub(crate) mod r#get_cp_amm_twap;
+  pub(crate) mod r#harvest_from_cp_amm_farm;
   pub(crate) mod r#initialize_amms_config;
   pub(crate) mod r#initialize_amms_configs_manager;
+  pub(crate) mod r#initialize_cl_amm;
+  pub(crate) mod r#initialize_cl_amm_tick_array;
   pub(crate) mod r#initialize_cp_amm;
+  pub(crate) mod r#initialize_cp_amm_farm;
+  pub(crate) mod r#initialize_cp_amm_observations;
+  pub(crate) mod r#initialize_stable_amm;
   pub(crate) mod r#launch_cp_amm;
+  pub(crate) mod r#launch_cp_amm_with_deadline;
+  pub(crate) mod r#launch_cp_amm_with_lp_lock;
+  pub(crate) mod r#launch_cp_amm_with_lp_lock_with_deadline;
+  pub(crate) mod r#launch_stable_amm;
+  pub(crate) mod r#launch_stable_amm_with_deadline;
+  pub(crate) mod r#migrate_amms_config;
+  pub(crate) mod r#migrate_amms_configs_manager;
+  pub(crate) mod r#migrate_cp_amm;
+  pub(crate) mod r#open_cl_amm_position;
+  pub(crate) mod r#place_cp_amm_limit_order;
+  pub(crate) mod r#propose_amms_configs_manager_authority;
+  pub(crate) mod r#propose_amms_configs_manager_head_authority;
+  pub(crate) mod r#provide_to_cl_amm;
+  pub(crate) mod r#provide_to_cl_amm_with_deadline;
   pub(crate) mod r#provide_to_cp_amm;
+  pub(crate) mod r#provide_to_cp_amm_v2;
+  pub(crate) mod r#provide_to_cp_amm_with_deadline;
+  pub(crate) mod r#provide_to_stable_amm;
+  pub(crate) mod r#provide_to_stable_amm_with_deadline;
+  pub(crate) mod r#quote_swap_in_cp_amm;
+  pub(crate) mod r#ramp_stable_amm_amplification;
+  pub(crate) mod r#record_cp_amm_observation;
+  pub(crate) mod r#remove_allowed_creator;
+  pub(crate) mod r#routed_swap_in_cp_amms;
+  pub(crate) mod r#routed_swap_in_cp_amms_with_deadline;
+  pub(crate) mod r#set_allowed_creator;
+  pub(crate) mod r#skim_cp_amm;
+  pub(crate) mod r#swap_exact_out_in_cp_amm;
+  pub(crate) mod r#swap_exact_out_in_cp_amm_with_deadline;
+  pub(crate) mod r#swap_in_cl_amm;
+  pub(crate) mod r#swap_in_cl_amm_with_deadline;
   pub(crate) mod r#swap_in_cp_amm;
+  pub(crate) mod r#swap_in_cp_amm_with_deadline;
+  pub(crate) mod r#swap_in_cp_amm_with_referrer;
+  pub(crate) mod r#swap_in_stable_amm;
+  pub(crate) mod r#swap_in_stable_amm_with_deadline;
+  pub(crate) mod r#swap_in_stable_amm_with_referrer;
+  pub(crate) mod r#sweep_fees_from_cp_amms;
+  pub(crate) mod r#sync_cp_amm;
+  pub(crate) mod r#update_amms_config_creator_restriction;
+  pub(crate) mod r#update_amms_config_dynamic_fee;
   pub(crate) mod r#update_amms_config_fee_authority;
+  pub(crate) mod r#update_amms_config_fee_rates_timelock;
+  pub(crate) mod r#update_amms_config_flash_loan_fee_rate;
+  pub(crate) mod r#update_amms_config_pause;
+  pub(crate) mod r#update_amms_config_pool_creation_fee;
+  pub(crate) mod r#update_amms_config_protocol_fee_mode;
   pub(crate) mod r#update_amms_config_protocol_fee_rate;
   pub(crate) mod r#update_amms_config_providers_fee_rate;
+  pub(crate) mod r#update_amms_config_referrer_fee_share;
   pub(crate) mod r#update_amms_configs_manager_authority;
   pub(crate) mod r#update_amms_configs_manager_head_authority;
+  pub(crate) mod r#update_cp_amm_pair;
+  pub(crate) mod r#update_cp_amm_pause;
+  pub(crate) mod r#withdraw_from_cl_amm;
+  pub(crate) mod r#withdraw_from_cl_amm_with_deadline;
   pub(crate) mod r#withdraw_from_cp_amm;
+  pub(crate) mod r#withdraw_from_cp_amm_farm;
+  pub(crate) mod r#withdraw_from_cp_amm_v2;
+  pub(crate) mod r#withdraw_from_cp_amm_with_deadline;
+  pub(crate) mod r#withdraw_from_stable_amm;
+  pub(crate) mod r#withdraw_from_stable_amm_with_deadline;
+  pub(crate) mod r#zap_in_to_cp_amm;
+  pub(crate) mod r#zap_in_to_cp_amm_with_deadline;
+  pub(crate) mod r#zap_out_from_cp_amm;
+  pub(crate) mod r#zap_out_from_cp_amm_with_deadline;
 
+  pub use self::r#accept_amms_configs_manager_authority::*;
+  pub use self::r#accept_amms_configs_manager_head_authority::*;
+  pub use self::r#add_cp_amm_farm_reward::*;
+  pub use self::r#cancel_amms_config_fee_rates_change::*;
+  pub use self::r#cancel_amms_configs_manager_authority_transfer::*;
+  pub use self::r#cancel_amms_configs_manager_head_authority_transfer::*;
+  pub use self::r#cancel_cp_amm_limit_order::*;
+  pub use self::r#claim_fees_from_cp_amm::*;
+  pub use self::r#claim_from_cp_amm_lp_lock::*;
+  pub use self::r#claim_lp_fees_from_cp_amm::*;
+  pub use self::r#collect_fees_from_cl_amm::*;
+  pub use self::r#collect_fees_from_cl_amm_position::*;
   pub use self::r#collect_fees_from_cp_amm::*;
+  pub use self::r#collect_fees_from_stable_amm::*;
+  pub use self::r#deposit_to_cp_amm_farm::*;
+  pub use self::r#fill_cp_amm_limit_orders::*;
+  pub use self::r#flash_borrow_from_cp_amm::*;
+  pub use self::r#flash_repay_to_cp_amm::*;
+  pub use self::r#get_cp_amm_twap::*;
+  pub use self::r#harvest_from_cp_amm_farm::*;
   pub use self::r#initialize_amms_config::*;
   pub use self::r#initialize_amms_configs_manager::*;
+  pub use self::r#initialize_cl_amm::*;
+  pub use self::r#initialize_cl_amm_tick_array::*;
   pub use self::r#initialize_cp_amm::*;
+  pub use self::r#initialize_cp_amm_farm::*;
+  pub use self::r#initialize_cp_amm_observations::*;
+  pub use self::r#initialize_stable_amm::*;
   pub use self::r#launch_cp_amm::*;
+  pub use self::r#launch_cp_amm_with_deadline::*;
+  pub use self::r#launch_cp_amm_with_lp_lock::*;
+  pub use self::r#launch_cp_amm_with_lp_lock_with_deadline::*;
+  pub use self::r#launch_stable_amm::*;
+  pub use self::r#launch_stable_amm_with_deadline::*;
+  pub use self::r#migrate_amms_config::*;
+  pub use self::r#migrate_amms_configs_manager::*;
+  pub use self::r#migrate_cp_amm::*;
+  pub use self::r#open_cl_amm_position::*;
+  pub use self::r#place_cp_amm_limit_order::*;
+  pub use self::r#propose_amms_configs_manager_authority::*;
+  pub use self::r#propose_amms_configs_manager_head_authority::*;
+  pub use self::r#provide_to_cl_amm::*;
+  pub use self::r#provide_to_cl_amm_with_deadline::*;
   pub use self::r#provide_to_cp_amm::*;
+  pub use self::r#provide_to_cp_amm_v2::*;
+  pub use self::r#provide_to_cp_amm_with_deadline::*;
+  pub use self::r#provide_to_stable_amm::*;
+  pub use self::r#provide_to_stable_amm_with_deadline::*;
+  pub use self::r#quote_swap_in_cp_amm::*;
+  pub use self::r#ramp_stable_amm_amplification::*;
+  pub use self::r#record_cp_amm_observation::*;
+  pub use self::r#remove_allowed_creator::*;
+  pub use self::r#routed_swap_in_cp_amms::*;
+  pub use self::r#routed_swap_in_cp_amms_with_deadline::*;
+  pub use self::r#set_allowed_creator::*;
+  pub use self::r#skim_cp_amm::*;
+  pub use self::r#swap_exact_out_in_cp_amm::*;
+  pub use self::r#swap_exact_out_in_cp_amm_with_deadline::*;
+  pub use self::r#swap_in_cl_amm::*;
+  pub use self::r#swap_in_cl_amm_with_deadline::*;
   pub use self::r#swap_in_cp_amm::*;
+  pub use self::r#swap_in_cp_amm_with_deadline::*;
+  pub use self::r#swap_in_cp_amm_with_referrer::*;
+  pub use self::r#swap_in_stable_amm::*;
+  pub use self::r#swap_in_stable_amm_with_deadline::*;
+  pub use self::r#swap_in_stable_amm_with_referrer::*;
+  pub use self::r#sweep_fees_from_cp_amms::*;
+  pub use self::r#sync_cp_amm::*;
+  pub use self::r#update_amms_config_creator_restriction::*;
+  pub use self::r#update_amms_config_dynamic_fee::*;
   pub use self::r#update_amms_config_fee_authority::*;
+  pub use self::r#update_amms_config_fee_rates_timelock::*;
+  pub use self::r#update_amms_config_flash_loan_fee_rate::*;
+  pub use self::r#update_amms_config_pause::*;
+  pub use self::r#update_amms_config_pool_creation_fee::*;
+  pub use self::r#update_amms_config_protocol_fee_mode::*;
   pub use self::r#update_amms_config_protocol_fee_rate::*;
   pub use self::r#update_amms_config_providers_fee_rate::*;
+  pub use self::r#update_amms_config_referrer_fee_share::*;
   pub use self::r#update_amms_configs_manager_authority::*;
   pub use self::r#update_amms_configs_manager_head_authority::*;
+  pub use self::r#update_cp_amm_pair::*;
+  pub use self::r#update_cp_amm_pause::*;
+  pub use self::r#withdraw_from_cl_amm::*;
+  pub use self::r#withdraw_from_cl_amm_with_deadline::*;
   pub use self::r#withdraw_from_cp_amm::*;
+  pub use self::r#withdraw_from_cp_amm_farm::*;
+  pub use self::r#withdraw_from_cp_amm_v2::*;
+  pub use self::r#withdraw_from_cp_amm_with_deadline::*;
+  pub use self::r#withdraw_from_stable_amm::*;
+  pub use self::r#withdraw_from_stable_amm_with_deadline::*;
+  pub use self::r#zap_in_to_cp_amm::*;
+  pub use self::r#zap_in_to_cp_amm_with_deadline::*;
+  pub use self::r#zap_out_from_cp_amm::*;
+  pub use self::r#zap_out_from_cp_amm_with_deadline::*;
 
@@ -150,13 +150,13 @@ impl ProvideToCpAmm {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct ProvideToCpAmmInstructionData {
             discriminator: [u8; 8],
-                  }
+                              }
 
 impl ProvideToCpAmmInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [228, 147, 253, 251, 242, 255, 110, 245],
-                                              }
+                                                                          }
   }
 }
 
@@ -178,19 +178,19 @@ impl Default for ProvideToCpAmmInstructionData {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
+          ///   0. `[writable, signer]` signer
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` signer_base_account
-                ///   5. `[writable]` signer_quote_account
-                ///   6. `[writable]` signer_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` signer_base_account
+          ///   5. `[writable]` signer_quote_account
+          ///   6. `[writable]` signer_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
                 ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   12. `[optional]` lp_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
           ///   14. `[]` quote_token_program
                 ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -282,8 +282,7 @@ impl ProvideToCpAmmBuilder {
                         self.associated_token_program = Some(associated_token_program);
                     self
     }
-            /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
-#[inline(always)]
+            #[inline(always)]
     pub fn lp_token_program(&mut self, lp_token_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.lp_token_program = Some(lp_token_program);
                     self
@@ -304,7 +303,7 @@ impl ProvideToCpAmmBuilder {
                         self.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn base_liquidity(&mut self, base_liquidity: u64) -> &mut Self {
         self.base_liquidity = Some(base_liquidity);
         self
@@ -341,14 +340,14 @@ impl ProvideToCpAmmBuilder {
                                         cp_amm_base_vault: self.cp_amm_base_vault.expect("cp_amm_base_vault is not set"),
                                         cp_amm_quote_vault: self.cp_amm_quote_vault.expect("cp_amm_quote_vault is not set"),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
-                                        lp_token_program: self.lp_token_program.unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        lp_token_program: self.lp_token_program.expect("lp_token_program is not set"),
                                         base_token_program: self.base_token_program.expect("base_token_program is not set"),
                                         quote_token_program: self.quote_token_program.expect("quote_token_program is not set"),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
                       };
           let args = ProvideToCpAmmInstructionArgs {
                                                               base_liquidity: self.base_liquidity.clone().expect("base_liquidity is not set"),
-                                                                  quote_liquidity: self.quote_liquidity.clone().expect("quote_liquidity is not set"),
+                                                              quote_liquidity: self.quote_liquidity.clone().expect("quote_liquidity is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -623,17 +622,17 @@ impl<'a, 'b> ProvideToCpAmmCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
+          ///   0. `[writable, signer]` signer
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` signer_base_account
-                ///   5. `[writable]` signer_quote_account
-                ///   6. `[writable]` signer_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` signer_base_account
+          ///   5. `[writable]` signer_quote_account
+          ///   6. `[writable]` signer_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
           ///   11. `[]` associated_token_program
           ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
@@ -750,7 +749,7 @@ impl<'a, 'b> ProvideToCpAmmCpiBuilder<'a, 'b> {
                         self.instruction.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn base_liquidity(&mut self, base_liquidity: u64) -> &mut Self {
         self.instruction.base_liquidity = Some(base_liquidity);
         self
@@ -784,7 +783,7 @@ impl<'a, 'b> ProvideToCpAmmCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program::entrypoint::ProgramResult {
           let args = ProvideToCpAmmInstructionArgs {
                                                               base_liquidity: self.instruction.base_liquidity.clone().expect("base_liquidity is not set"),
-                                                                  quote_liquidity: self.instruction.quote_liquidity.clone().expect("quote_liquidity is not set"),
+                                                              quote_liquidity: self.instruction.quote_liquidity.clone().expect("quote_liquidity is not set"),
                                     };
         let instruction = ProvideToCpAmmCpi {
         __program: self.instruction.__program,
@@ -150,13 +150,13 @@ impl WithdrawFromCpAmm {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct WithdrawFromCpAmmInstructionData {
             discriminator: [u8; 8],
-            }
+                              }
 
 impl WithdrawFromCpAmmInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [199, 54, 177, 131, 202, 241, 176, 155],
-                                }
+                                                                          }
   }
 }
 
@@ -177,19 +177,19 @@ impl Default for WithdrawFromCpAmmInstructionData {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
+          ///   0. `[writable, signer]` signer
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` signer_base_account
-                ///   5. `[writable]` signer_quote_account
-                ///   6. `[writable]` signer_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` signer_base_account
+          ///   5. `[writable]` signer_quote_account
+          ///   6. `[writable]` signer_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
                 ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   12. `[optional]` lp_token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
           ///   14. `[]` quote_token_program
                 ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -280,8 +280,7 @@ impl WithdrawFromCpAmmBuilder {
                         self.associated_token_program = Some(associated_token_program);
                     self
     }
-            /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
-#[inline(always)]
+            #[inline(always)]
     pub fn lp_token_program(&mut self, lp_token_program: solana_program::pubkey::Pubkey) -> &mut Self {
                         self.lp_token_program = Some(lp_token_program);
                     self
@@ -302,7 +301,7 @@ impl WithdrawFromCpAmmBuilder {
                         self.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn lp_tokens(&mut self, lp_tokens: u64) -> &mut Self {
         self.lp_tokens = Some(lp_tokens);
         self
@@ -334,7 +333,7 @@ impl WithdrawFromCpAmmBuilder {
                                         cp_amm_base_vault: self.cp_amm_base_vault.expect("cp_amm_base_vault is not set"),
                                         cp_amm_quote_vault: self.cp_amm_quote_vault.expect("cp_amm_quote_vault is not set"),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
-                                        lp_token_program: self.lp_token_program.unwrap_or(solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        lp_token_program: self.lp_token_program.expect("lp_token_program is not set"),
                                         base_token_program: self.base_token_program.expect("base_token_program is not set"),
                                         quote_token_program: self.quote_token_program.expect("quote_token_program is not set"),
                                         system_program: self.system_program.unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -615,17 +614,17 @@ impl<'a, 'b> WithdrawFromCpAmmCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` signer
+          ///   0. `[writable, signer]` signer
           ///   1. `[]` base_mint
           ///   2. `[]` quote_mint
-                ///   3. `[writable]` lp_mint
-                ///   4. `[writable]` signer_base_account
-                ///   5. `[writable]` signer_quote_account
-                ///   6. `[writable]` signer_lp_account
+          ///   3. `[writable]` lp_mint
+          ///   4. `[writable]` signer_base_account
+          ///   5. `[writable]` signer_quote_account
+          ///   6. `[writable]` signer_lp_account
           ///   7. `[]` amms_config
-                ///   8. `[writable]` cp_amm
-                ///   9. `[writable]` cp_amm_base_vault
-                ///   10. `[writable]` cp_amm_quote_vault
+          ///   8. `[writable]` cp_amm
+          ///   9. `[writable]` cp_amm_base_vault
+          ///   10. `[writable]` cp_amm_quote_vault
           ///   11. `[]` associated_token_program
           ///   12. `[]` lp_token_program
           ///   13. `[]` base_token_program
@@ -741,7 +740,7 @@ impl<'a, 'b> WithdrawFromCpAmmCpiBuilder<'a, 'b> {
                         self.instruction.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
+                #[inline(always)]
       pub fn lp_tokens(&mut self, lp_tokens: u64) -> &mut Self {
         self.instruction.lp_tokens = Some(lp_tokens);
         self
@@ -5,35 +5,151 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#add_cp_amm_farm_reward_event;
+  pub(crate) mod r#cancel_amms_config_fee_rates_change_event;
+  pub(crate) mod r#cancel_amms_configs_manager_authority_transfer_event;
+  pub(crate) mod r#cancel_amms_configs_manager_head_authority_transfer_event;
+  pub(crate) mod r#cancel_cp_amm_limit_order_event;
+  pub(crate) mod r#cl_amm_tick;
+  pub(crate) mod r#claim_fees_from_cp_amm_event;
+  pub(crate) mod r#claim_from_cp_amm_lp_lock_event;
+  pub(crate) mod r#claim_lp_fees_from_cp_amm_event;
+  pub(crate) mod r#collect_fees_from_cl_amm_event;
+  pub(crate) mod r#collect_fees_from_cl_amm_position_event;
   pub(crate) mod r#collect_fees_from_cp_amm_event;
+  pub(crate) mod r#collect_fees_from_stable_amm_event;
+  pub(crate) mod r#cp_amm_farm_reward;
+  pub(crate) mod r#deposit_to_cp_amm_farm_event;
+  pub(crate) mod r#fill_cp_amm_limit_order_event;
+  pub(crate) mod r#flash_borrow_from_cp_amm_event;
+  pub(crate) mod r#flash_repay_to_cp_amm_event;
+  pub(crate) mod r#harvest_from_cp_amm_farm_event;
   pub(crate) mod r#initialize_amms_config_event;
   pub(crate) mod r#initialize_amms_configs_manager_event;
+  pub(crate) mod r#initialize_cl_amm_event;
+  pub(crate) mod r#initialize_cl_amm_tick_array_event;
   pub(crate) mod r#initialize_cp_amm_event;
+  pub(crate) mod r#initialize_cp_amm_farm_event;
+  pub(crate) mod r#initialize_cp_amm_observations_event;
+  pub(crate) mod r#initialize_stable_amm_event;
   pub(crate) mod r#launch_cp_amm_event;
+  pub(crate) mod r#launch_stable_amm_event;
+  pub(crate) mod r#lock_cp_amm_lp_event;
+  pub(crate) mod r#open_cl_amm_position_event;
+  pub(crate) mod r#place_cp_amm_limit_order_event;
+  pub(crate) mod r#price_observation;
+  pub(crate) mod r#propose_amms_configs_manager_authority_event;
+  pub(crate) mod r#propose_amms_configs_manager_head_authority_event;
+  pub(crate) mod r#provide_to_cl_amm_event;
   pub(crate) mod r#provide_to_cp_amm_event;
+  pub(crate) mod r#provide_to_stable_amm_event;
   pub(crate) mod r#q64128;
+  pub(crate) mod r#ramp_stable_amm_amplification_event;
+  pub(crate) mod r#record_cp_amm_observation_event;
+  pub(crate) mod r#remove_allowed_creator_event;
+  pub(crate) mod r#routed_swap_in_cp_amm_event;
+  pub(crate) mod r#schedule_amms_config_fee_rates_change_event;
+  pub(crate) mod r#set_allowed_creator_event;
+  pub(crate) mod r#skim_cp_amm_event;
+  pub(crate) mod r#swap_exact_out_in_cp_amm_event;
+  pub(crate) mod r#swap_in_cl_amm_event;
   pub(crate) mod r#swap_in_cp_amm_event;
+  pub(crate) mod r#swap_in_stable_amm_event;
+  pub(crate) mod r#sync_cp_amm_event;
   pub(crate) mod r#u192;
+  pub(crate) mod r#update_amms_config_creator_restriction_event;
+  pub(crate) mod r#update_amms_config_dynamic_fee_event;
   pub(crate) mod r#update_amms_config_fee_authority_event;
+  pub(crate) mod r#update_amms_config_fee_rates_timelock_event;
+  pub(crate) mod r#update_amms_config_flash_loan_fee_rate_event;
+  pub(crate) mod r#update_amms_config_pause_event;
+  pub(crate) mod r#update_amms_config_pool_creation_fee_event;
+  pub(crate) mod r#update_amms_config_protocol_fee_mode_event;
   pub(crate) mod r#update_amms_config_protocol_fee_rate_event;
   pub(crate) mod r#update_amms_config_providers_fee_rate_event;
+  pub(crate) mod r#update_amms_config_referrer_fee_share_event;
   pub(crate) mod r#update_amms_configs_manager_authority_event;
   pub(crate) mod r#update_amms_configs_manager_head_authority_event;
+  pub(crate) mod r#update_cp_amm_pair_event;
+  pub(crate) mod r#update_cp_amm_pause_event;
+  pub(crate) mod r#withdraw_from_cl_amm_event;
   pub(crate) mod r#withdraw_from_cp_amm_event;
+  pub(crate) mod r#withdraw_from_cp_amm_farm_event;
+  pub(crate) mod r#withdraw_from_stable_amm_event;
+  pub(crate) mod r#zap_in_to_cp_amm_event;
+  pub(crate) mod r#zap_out_from_cp_amm_event;
 
+  pub use self::r#add_cp_amm_farm_reward_event::*;
+  pub use self::r#cancel_amms_config_fee_rates_change_event::*;
+  pub use self::r#cancel_amms_configs_manager_authority_transfer_event::*;
+  pub use self::r#cancel_amms_configs_manager_head_authority_transfer_event::*;
+  pub use self::r#cancel_cp_amm_limit_order_event::*;
+  pub use self::r#cl_amm_tick::*;
+  pub use self::r#claim_fees_from_cp_amm_event::*;
+  pub use self::r#claim_from_cp_amm_lp_lock_event::*;
+  pub use self::r#claim_lp_fees_from_cp_amm_event::*;
+  pub use self::r#collect_fees_from_cl_amm_event::*;
+  pub use self::r#collect_fees_from_cl_amm_position_event::*;
   pub use self::r#collect_fees_from_cp_amm_event::*;
+  pub use self::r#collect_fees_from_stable_amm_event::*;
+  pub use self::r#cp_amm_farm_reward::*;
+  pub use self::r#deposit_to_cp_amm_farm_event::*;
+  pub use self::r#fill_cp_amm_limit_order_event::*;
+  pub use self::r#flash_borrow_from_cp_amm_event::*;
+  pub use self::r#flash_repay_to_cp_amm_event::*;
+  pub use self::r#harvest_from_cp_amm_farm_event::*;
   pub use self::r#initialize_amms_config_event::*;
   pub use self::r#initialize_amms_configs_manager_event::*;
+  pub use self::r#initialize_cl_amm_event::*;
+  pub use self::r#initialize_cl_amm_tick_array_event::*;
   pub use self::r#initialize_cp_amm_event::*;
+  pub use self::r#initialize_cp_amm_farm_event::*;
+  pub use self::r#initialize_cp_amm_observations_event::*;
+  pub use self::r#initialize_stable_amm_event::*;
   pub use self::r#launch_cp_amm_event::*;
+  pub use self::r#launch_stable_amm_event::*;
+  pub use self::r#lock_cp_amm_lp_event::*;
+  pub use self::r#open_cl_amm_position_event::*;
+  pub use self::r#place_cp_amm_limit_order_event::*;
+  pub use self::r#price_observation::*;
+  pub use self::r#propose_amms_configs_manager_authority_event::*;
+  pub use self::r#propose_amms_configs_manager_head_authority_event::*;
+  pub use self::r#provide_to_cl_amm_event::*;
   pub use self::r#provide_to_cp_amm_event::*;
+  pub use self::r#provide_to_stable_amm_event::*;
   pub use self::r#q64128::*;
+  pub use self::r#ramp_stable_amm_amplification_event::*;
+  pub use self::r#record_cp_amm_observation_event::*;
+  pub use self::r#remove_allowed_creator_event::*;
+  pub use self::r#routed_swap_in_cp_amm_event::*;
+  pub use self::r#schedule_amms_config_fee_rates_change_event::*;
+  pub use self::r#set_allowed_creator_event::*;
+  pub use self::r#skim_cp_amm_event::*;
+  pub use self::r#swap_exact_out_in_cp_amm_event::*;
+  pub use self::r#swap_in_cl_amm_event::*;
   pub use self::r#swap_in_cp_amm_event::*;
+  pub use self::r#swap_in_stable_amm_event::*;
+  pub use self::r#sync_cp_amm_event::*;
   pub use self::r#u192::*;
+  pub use self::r#update_amms_config_creator_restriction_event::*;
+  pub use self::r#update_amms_config_dynamic_fee_event::*;
   pub use self::r#update_amms_config_fee_authority_event::*;
+  pub use self::r#update_amms_config_fee_rates_timelock_event::*;
+  pub use self::r#update_amms_config_flash_loan_fee_rate_event::*;
+  pub use self::r#update_amms_config_pause_event::*;
+  pub use self::r#update_amms_config_pool_creation_fee_event::*;
+  pub use self::r#update_amms_config_protocol_fee_mode_event::*;
   pub use self::r#update_amms_config_protocol_fee_rate_event::*;
   pub use self::r#update_amms_config_providers_fee_rate_event::*;
+  pub use self::r#update_amms_config_referrer_fee_share_event::*;
   pub use self::r#update_amms_configs_manager_authority_event::*;
   pub use self::r#update_amms_configs_manager_head_authority_event::*;
+  pub use self::r#update_cp_amm_pair_event::*;
+  pub use self::r#update_cp_amm_pause_event::*;
+  pub use self::r#withdraw_from_cl_amm_event::*;
   pub use self::r#withdraw_from_cp_amm_event::*;
+  pub use self::r#withdraw_from_cp_amm_farm_event::*;
+  pub use self::r#withdraw_from_stable_amm_event::*;
+  pub use self::r#zap_in_to_cp_amm_event::*;
+  pub use self::r#zap_out_from_cp_amm_event::*;
 
@@ -28,6 +28,9 @@ pub protocol_base_fees_to_redeem: u64,
 pub protocol_quote_fees_to_redeem: u64,
 pub constant_product_sqrt: Q64128,
 pub base_quote_ratio_sqrt: Q64128,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>"))]
+pub referrer_account: Option<Pubkey>,
+pub referrer_fee_amount: u64,
 pub timestamp: i64,
 }
 
@@ -14,4 +14,21 @@ pub enum ErrorCode {
 
     #[msg("Maximum position size must be greater than or equal to minimum.")]
     InvalidMaxPositionSize,
+
+    // LaunchpoolMigration
+    #[msg("The migration share exceeds the maximum allowed value of 10000 basis points (100%) or is zero.")]
+    InvalidMigrationShare,
+
+    #[msg("Migration base amount must be greater than 0.")]
+    InvalidMigrationBaseAmount,
+
+    #[msg("Migration quote amount must be greater than 0.")]
+    InvalidMigrationQuoteAmount,
+
+    #[msg("Launchpool is already migrated.")]
+    LaunchpoolAlreadyMigrated,
+
+    // LaunchpoolsConfigsManager
+    #[msg("There is no pending authority transfer.")]
+    NoPendingAuthorityTransfer,
 }
\ No newline at end of file
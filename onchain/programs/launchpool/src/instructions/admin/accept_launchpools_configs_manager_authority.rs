@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::LaunchpoolsConfigsManager;
+
+#[derive(Accounts)]
+pub struct AcceptLaunchpoolsConfigsManagerAuthority<'info> {
+    #[account(
+        mut,
+        constraint = launchpools_configs_manager.pending_authority() == Some(&new_authority.key())
+    )]
+    pub new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump = launchpools_configs_manager.bump()
+    )]
+    pub launchpools_configs_manager: Account<'info, LaunchpoolsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<AcceptLaunchpoolsConfigsManagerAuthority>) -> Result<()> {
+    let previous_authority = ctx.accounts.launchpools_configs_manager.authority().key();
+    ctx.accounts.launchpools_configs_manager.accept_authority()?;
+    msg!("Event: UpdateLaunchpoolsConfigsManagerAuthority");
+    emit!(
+        UpdateLaunchpoolsConfigsManagerAuthorityEvent{
+            authority: previous_authority,
+            new_authority: ctx.accounts.launchpools_configs_manager.authority().key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateLaunchpoolsConfigsManagerAuthorityEvent {
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
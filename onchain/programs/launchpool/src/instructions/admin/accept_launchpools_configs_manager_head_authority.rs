@@ -2,29 +2,26 @@ use anchor_lang::prelude::*;
 use crate::state::LaunchpoolsConfigsManager;
 
 #[derive(Accounts)]
-pub struct UpdateLaunchpoolsConfigsManagerHeadAuthority<'info> {
+pub struct AcceptLaunchpoolsConfigsManagerHeadAuthority<'info> {
     #[account(
         mut,
-        constraint = head_authority.key() == launchpools_configs_manager.head_authority().key()
+        constraint = launchpools_configs_manager.pending_head_authority() == Some(&new_head_authority.key())
     )]
-    pub head_authority: Signer<'info>,
+    pub new_head_authority: Signer<'info>,
     #[account(
         mut,
         seeds = [LaunchpoolsConfigsManager::SEED],
         bump = launchpools_configs_manager.bump()
     )]
     pub launchpools_configs_manager: Account<'info, LaunchpoolsConfigsManager>,
-    /// CHECK: New authority can be arbitrary
-    pub new_head_authority: UncheckedAccount<'info>,
 }
-pub(crate) fn handler(ctx: Context<UpdateLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()> {
-    ctx.accounts.launchpools_configs_manager.update_head_authority(
-        ctx.accounts.new_head_authority.key()
-    );
+pub(crate) fn handler(ctx: Context<AcceptLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()> {
+    let previous_head_authority = ctx.accounts.launchpools_configs_manager.head_authority().key();
+    ctx.accounts.launchpools_configs_manager.accept_head_authority()?;
     msg!("Event: UpdateLaunchpoolsConfigsManagerHeadAuthority");
     emit!(
         UpdateLaunchpoolsConfigsManagerHeadAuthorityEvent{
-            head_authority: ctx.accounts.head_authority.key(),
+            head_authority: previous_head_authority,
             new_head_authority: ctx.accounts.launchpools_configs_manager.head_authority().key(),
             timestamp: Clock::get()?.unix_timestamp
         }
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::LaunchpoolsConfigsManager;
+
+#[derive(Accounts)]
+pub struct CancelLaunchpoolsConfigsManagerAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == launchpools_configs_manager.authority().key() || authority.key() == launchpools_configs_manager.head_authority().key())
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump = launchpools_configs_manager.bump()
+    )]
+    pub launchpools_configs_manager: Account<'info, LaunchpoolsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<CancelLaunchpoolsConfigsManagerAuthorityTransfer>) -> Result<()> {
+    let cancelled_authority = ctx.accounts.launchpools_configs_manager.pending_authority().copied().unwrap_or_default();
+    ctx.accounts.launchpools_configs_manager.cancel_authority_transfer()?;
+    msg!("Event: CancelLaunchpoolsConfigsManagerAuthorityTransfer");
+    emit!(
+        CancelLaunchpoolsConfigsManagerAuthorityTransferEvent{
+            authority: ctx.accounts.authority.key(),
+            cancelled_authority,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelLaunchpoolsConfigsManagerAuthorityTransferEvent {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::LaunchpoolsConfigsManager;
+
+#[derive(Accounts)]
+pub struct CancelLaunchpoolsConfigsManagerHeadAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = head_authority.key() == launchpools_configs_manager.head_authority().key()
+    )]
+    pub head_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump = launchpools_configs_manager.bump()
+    )]
+    pub launchpools_configs_manager: Account<'info, LaunchpoolsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<CancelLaunchpoolsConfigsManagerHeadAuthorityTransfer>) -> Result<()> {
+    let cancelled_head_authority = ctx.accounts.launchpools_configs_manager.pending_head_authority().copied().unwrap_or_default();
+    ctx.accounts.launchpools_configs_manager.cancel_head_authority_transfer()?;
+    msg!("Event: CancelLaunchpoolsConfigsManagerHeadAuthorityTransfer");
+    emit!(
+        CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent{
+            head_authority: ctx.accounts.head_authority.key(),
+            cancelled_head_authority,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelLaunchpoolsConfigsManagerHeadAuthorityTransferEvent {
+    pub head_authority: Pubkey,
+    pub cancelled_head_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
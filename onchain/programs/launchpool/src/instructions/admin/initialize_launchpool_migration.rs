@@ -0,0 +1,225 @@
+use anchor_lang::{emit, event, Accounts, Key, ToAccountInfo};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{TokenAccount as InterfaceTokenAccount, TokenInterface, Mint};
+use liquidity_pool::program::LiquidityPool;
+use utilities::system_instructions::TransferLamportsInstruction;
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::state::{Launchpool, LaunchpoolMigration, LaunchpoolsConfigsManager};
+
+/// Configures the migration of an initialized `Launchpool` into a `CpAmm`.
+///
+/// Reserves a share of the protocol reward as the base liquidity, deposits the quote liquidity
+/// and creates the `CpAmm` through a CPI into the liquidity pool program, with the migration authority as its creator.
+/// The migration authority is funded with `funding_lamports` to pay the rent of the pool accounts,
+/// the lamports pool creation fee and the LP token account created at migration.
+#[derive(Accounts)]
+pub struct InitializeLaunchpoolMigration<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == launchpools_configs_manager.authority().key() || authority.key() == launchpools_configs_manager.head_authority().key())
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump = launchpools_configs_manager.bump()
+    )]
+    pub launchpools_configs_manager: Box<Account<'info, LaunchpoolsConfigsManager>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = reward_mint.key() == launchpool.reward_mint,
+        seeds = [Launchpool::SEED, launchpool.reward_mint.as_ref()],
+        bump = launchpool.bump()
+    )]
+    pub launchpool: Box<Account<'info, Launchpool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LaunchpoolMigration::INIT_SPACE,
+        seeds = [LaunchpoolMigration::SEED, launchpool.key().as_ref()],
+        bump
+    )]
+    pub launchpool_migration: Box<Account<'info, LaunchpoolMigration>>,
+
+    #[account(
+        mut,
+        seeds = [LaunchpoolMigration::AUTHORITY_SEED, launchpool.key().as_ref()],
+        bump
+    )]
+    pub migration_authority: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = authority,
+        token::token_program = quote_token_program
+    )]
+    pub authority_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = quote_mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = quote_token_program
+    )]
+    pub migration_authority_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program against the amms config
+    pub fee_authority: UncheckedAccount<'info>,
+    /// CHECK: Validated by the liquidity pool program
+    pub amms_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub lp_mint: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program
+    pub cp_amm: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program
+    pub cp_amm_pair: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program
+    pub cp_amm_base_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program
+    pub cp_amm_quote_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program
+    pub cp_amm_locked_lp_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program, required by creator-restricted amms configs
+    pub allowed_creator: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by the liquidity pool program when reading the symbol
+    pub reward_mint_metaplex_metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by the liquidity pool program when reading the symbol
+    pub quote_mint_metaplex_metadata: Option<UncheckedAccount<'info>>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub lp_token_program: Program<'info, Token2022>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub liquidity_pool_program: Program<'info, LiquidityPool>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeLaunchpoolMigration<'info>>, base_share_basis_points: u16, quote_amount: u64, funding_lamports: u64) -> Result<()> {
+    let base_amount = LaunchpoolMigration::calculate_base_amount(ctx.accounts.launchpool.protocol_reward_amount(), base_share_basis_points)?;
+    ctx.accounts.launchpool.reserve_migration_reward(base_amount)?;
+
+    let deposit_quote_instruction = Box::new(ctx.accounts.get_deposit_quote_transfer_instruction(quote_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let quote_amount_after_fee = deposit_quote_instruction.get_amount_after_fee();
+    deposit_quote_instruction.execute(None)?;
+    Box::new(ctx.accounts.get_fund_migration_authority_instruction(funding_lamports)?).execute()?;
+
+    let launchpool_key = ctx.accounts.launchpool.key();
+    let migration_authority_seeds = [LaunchpoolMigration::AUTHORITY_SEED, launchpool_key.as_ref(), &[ctx.bumps.migration_authority]];
+    ctx.accounts.initialize_cp_amm(&[&migration_authority_seeds])?;
+
+    ctx.accounts.launchpool_migration.initialize(
+        launchpool_key,
+        ctx.accounts.cp_amm.key(),
+        ctx.accounts.quote_mint.key(),
+        base_share_basis_points,
+        base_amount,
+        quote_amount_after_fee,
+        ctx.bumps.launchpool_migration,
+        ctx.bumps.migration_authority
+    )?;
+    let launchpool_migration = &ctx.accounts.launchpool_migration;
+
+    msg!("Event: InitializeLaunchpoolMigration");
+    emit!(
+        InitializeLaunchpoolMigrationEvent{
+            authority: ctx.accounts.authority.key(),
+            launchpool: launchpool_key,
+            launchpool_migration: launchpool_migration.key(),
+            migration_authority: ctx.accounts.migration_authority.key(),
+            cp_amm: launchpool_migration.cp_amm().key(),
+            quote_mint: launchpool_migration.quote_mint().key(),
+            base_share_basis_points,
+            base_amount,
+            quote_amount: quote_amount_after_fee,
+            protocol_reward_amount: ctx.accounts.launchpool.protocol_reward_amount(),
+            timestamp: Clock::get()?.unix_timestamp,
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeLaunchpoolMigrationEvent {
+    pub authority: Pubkey,
+    pub launchpool: Pubkey,
+    pub launchpool_migration: Pubkey,
+    pub migration_authority: Pubkey,
+    pub cp_amm: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_share_basis_points: u16,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub protocol_reward_amount: u64,
+    pub timestamp: i64,
+}
+
+impl<'info> InitializeLaunchpoolMigration<'info> {
+    fn get_deposit_quote_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.authority_quote_account,
+            self.authority.to_account_info(),
+            &self.migration_authority_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_fund_migration_authority_instruction(&self, lamports: u64) -> Result<TransferLamportsInstruction<'_, '_, '_, 'info>> {
+        TransferLamportsInstruction::new(
+            lamports,
+            self.authority.to_account_info(),
+            self.migration_authority.to_account_info(),
+            &self.system_program
+        )
+    }
+    /// Creates the `CpAmm` with the migration authority as its creator and the reward mint as its base mint.
+    #[inline(never)]
+    fn initialize_cp_amm(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = liquidity_pool::cpi::accounts::InitializeCpAmm {
+            signer: self.migration_authority.to_account_info(),
+            fee_authority: self.fee_authority.to_account_info(),
+            base_mint: self.reward_mint.to_account_info(),
+            quote_mint: self.quote_mint.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            amms_config: self.amms_config.to_account_info(),
+            cp_amm: self.cp_amm.to_account_info(),
+            cp_amm_pair: self.cp_amm_pair.to_account_info(),
+            cp_amm_base_vault: self.cp_amm_base_vault.to_account_info(),
+            cp_amm_quote_vault: self.cp_amm_quote_vault.to_account_info(),
+            cp_amm_locked_lp_vault: self.cp_amm_locked_lp_vault.to_account_info(),
+            rent: self.rent.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            lp_token_program: self.lp_token_program.to_account_info(),
+            base_token_program: self.reward_token_program.to_account_info(),
+            quote_token_program: self.quote_token_program.to_account_info(),
+            allowed_creator: self.allowed_creator.as_ref().map(|account| account.to_account_info()),
+            pool_creation_fee_mint: None,
+            signer_pool_creation_fee_account: None,
+            fee_authority_pool_creation_fee_account: None,
+            pool_creation_fee_token_program: None,
+            base_mint_metaplex_metadata: self.reward_mint_metaplex_metadata.as_ref().map(|account| account.to_account_info()),
+            quote_mint_metaplex_metadata: self.quote_mint_metaplex_metadata.as_ref().map(|account| account.to_account_info()),
+        };
+        liquidity_pool::cpi::initialize_cp_amm(
+            CpiContext::new_with_signer(self.liquidity_pool_program.to_account_info(), accounts, signer_seeds)
+        )
+    }
+}
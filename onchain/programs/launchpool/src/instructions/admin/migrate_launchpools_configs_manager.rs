@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use utilities::constants::ANCHOR_DISCRIMINATOR;
+use utilities::helpers::migrate_account_layout;
+use crate::state::LaunchpoolsConfigsManager;
+
+#[derive(Accounts)]
+pub struct MigrateLaunchpoolsConfigsManager<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: Older layouts don't deserialize as `LaunchpoolsConfigsManager`, the owner and the discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump
+    )]
+    pub launchpools_configs_manager: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+pub(crate) fn handler(ctx: Context<MigrateLaunchpoolsConfigsManager>) -> Result<()> {
+    // Appended pending authority fields start zeroed, meaning no transfer is pending
+    migrate_account_layout::<LaunchpoolsConfigsManager>(
+        &ctx.accounts.launchpools_configs_manager.to_account_info(),
+        ANCHOR_DISCRIMINATOR + LaunchpoolsConfigsManager::INIT_SPACE,
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program
+    )
+}
\ No newline at end of file
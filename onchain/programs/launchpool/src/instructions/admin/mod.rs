@@ -5,10 +5,16 @@ pub mod update_launchpools_config_duration;
 pub mod update_launchpools_config_protocol_reward_share;
 pub mod update_launchpools_config_position_sizes;
 pub mod update_launchpools_config_reward_authority;
-pub mod update_launchpools_configs_manager_authority;
-pub mod update_launchpools_configs_manager_head_authority;
+pub mod propose_launchpools_configs_manager_authority;
+pub mod accept_launchpools_configs_manager_authority;
+pub mod cancel_launchpools_configs_manager_authority_transfer;
+pub mod propose_launchpools_configs_manager_head_authority;
+pub mod accept_launchpools_configs_manager_head_authority;
+pub mod cancel_launchpools_configs_manager_head_authority_transfer;
+pub mod migrate_launchpools_configs_manager;
 pub mod initialize_launchpool;
 pub mod launch_launchpool;
+pub mod initialize_launchpool_migration;
 
 pub use initialize_launchpools_config::*;
 pub use initialize_launchpools_configs_manager::*;
@@ -16,7 +22,13 @@ pub use update_launchpools_config_duration::*;
 pub use update_launchpools_config_protocol_reward_share::*;
 pub use update_launchpools_config_position_sizes::*;
 pub use update_launchpools_config_reward_authority::*;
-pub use update_launchpools_configs_manager_authority::*;
-pub use update_launchpools_configs_manager_head_authority::*;
+pub use propose_launchpools_configs_manager_authority::*;
+pub use accept_launchpools_configs_manager_authority::*;
+pub use cancel_launchpools_configs_manager_authority_transfer::*;
+pub use propose_launchpools_configs_manager_head_authority::*;
+pub use accept_launchpools_configs_manager_head_authority::*;
+pub use cancel_launchpools_configs_manager_head_authority_transfer::*;
+pub use migrate_launchpools_configs_manager::*;
 pub use initialize_launchpool::*;
-pub use launch_launchpool::*;
\ No newline at end of file
+pub use launch_launchpool::*;
+pub use initialize_launchpool_migration::*;
\ No newline at end of file
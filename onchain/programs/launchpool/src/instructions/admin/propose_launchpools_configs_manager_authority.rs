@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::LaunchpoolsConfigsManager;
 
 #[derive(Accounts)]
-pub struct UpdateLaunchpoolsConfigsManagerAuthority<'info> {
+pub struct ProposeLaunchpoolsConfigsManagerAuthority<'info> {
     #[account(
         mut,
         constraint = (authority.key() == launchpools_configs_manager.authority().key() || authority.key() == launchpools_configs_manager.head_authority().key())
@@ -17,15 +17,15 @@ pub struct UpdateLaunchpoolsConfigsManagerAuthority<'info> {
     /// CHECK: New authority can be arbitrary
     pub new_authority: UncheckedAccount<'info>,
 }
-pub(crate) fn handler(ctx: Context<UpdateLaunchpoolsConfigsManagerAuthority>) -> Result<()> {
-    ctx.accounts.launchpools_configs_manager.update_authority(
+pub(crate) fn handler(ctx: Context<ProposeLaunchpoolsConfigsManagerAuthority>) -> Result<()> {
+    ctx.accounts.launchpools_configs_manager.propose_authority(
         ctx.accounts.new_authority.key()
     );
-    msg!("Event: UpdateLaunchpoolsConfigsManagerAuthority");
+    msg!("Event: ProposeLaunchpoolsConfigsManagerAuthority");
     emit!(
-        UpdateLaunchpoolsConfigsManagerAuthorityEvent{
+        ProposeLaunchpoolsConfigsManagerAuthorityEvent{
             authority: ctx.accounts.authority.key(),
-            new_authority:  ctx.accounts.launchpools_configs_manager.authority().key(),
+            pending_authority: ctx.accounts.new_authority.key(),
             timestamp: Clock::get()?.unix_timestamp
         }
     );
@@ -33,8 +33,8 @@ pub(crate) fn handler(ctx: Context<UpdateLaunchpoolsConfigsManagerAuthority>) ->
 }
 
 #[event]
-pub struct UpdateLaunchpoolsConfigsManagerAuthorityEvent {
+pub struct ProposeLaunchpoolsConfigsManagerAuthorityEvent {
     pub authority: Pubkey,
-    pub new_authority: Pubkey,
+    pub pending_authority: Pubkey,
     pub timestamp: i64,
 }
\ No newline at end of file
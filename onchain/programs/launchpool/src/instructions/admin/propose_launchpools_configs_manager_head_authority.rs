@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::LaunchpoolsConfigsManager;
+
+#[derive(Accounts)]
+pub struct ProposeLaunchpoolsConfigsManagerHeadAuthority<'info> {
+    #[account(
+        mut,
+        constraint = head_authority.key() == launchpools_configs_manager.head_authority().key()
+    )]
+    pub head_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LaunchpoolsConfigsManager::SEED],
+        bump = launchpools_configs_manager.bump()
+    )]
+    pub launchpools_configs_manager: Account<'info, LaunchpoolsConfigsManager>,
+    /// CHECK: New authority can be arbitrary
+    pub new_head_authority: UncheckedAccount<'info>,
+}
+pub(crate) fn handler(ctx: Context<ProposeLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()> {
+    ctx.accounts.launchpools_configs_manager.propose_head_authority(
+        ctx.accounts.new_head_authority.key()
+    );
+    msg!("Event: ProposeLaunchpoolsConfigsManagerHeadAuthority");
+    emit!(
+        ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent{
+            head_authority: ctx.accounts.head_authority.key(),
+            pending_head_authority: ctx.accounts.new_head_authority.key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct ProposeLaunchpoolsConfigsManagerHeadAuthorityEvent {
+    pub head_authority: Pubkey,
+    pub pending_head_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
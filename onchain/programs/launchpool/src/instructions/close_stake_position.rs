@@ -80,7 +80,7 @@ pub struct CloseStakePosition<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>
 }
 
-pub(crate) fn handler(ctx: Context<CloseStakePosition>) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseStakePosition<'info>>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp as u64;
     let launchpool = &mut ctx.accounts.launchpool;
 
@@ -92,8 +92,8 @@ pub(crate) fn handler(ctx: Context<CloseStakePosition>) -> Result<()> {
     let (stake_amount, pending, reward_amount) = (close_position_payload.stake_amount(), close_position_payload.pending(), close_position_payload.reward_earned());
     ctx.accounts.launchpool.process_position_close(close_position_payload)?;
 
-    let get_receive_stake_instruction = Box::new(ctx.accounts.get_receive_stake_transfer_instruction(stake_amount)?);
-    let get_receive_reward_instruction = Box::new(ctx.accounts.get_receive_reward_transfer_instruction(reward_amount.as_u64())?);
+    let get_receive_stake_instruction = Box::new(ctx.accounts.get_receive_stake_transfer_instruction(stake_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let get_receive_reward_instruction = Box::new(ctx.accounts.get_receive_reward_transfer_instruction(reward_amount.as_u64())?.with_transfer_hook_accounts(ctx.remaining_accounts));
     let stake_amount_after_fee = get_receive_stake_instruction.get_amount_after_fee();
     let reward_amount_after_fee = get_receive_reward_instruction.get_amount_after_fee();
 
@@ -55,14 +55,14 @@ pub struct CollectProtocolReward<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>
 }
 
-pub(crate) fn handler(ctx: Context<CollectProtocolReward>) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectProtocolReward<'info>>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp as u64;
     ctx.accounts.launchpool.accrue_rewards(now)?;
     let collect_protocol_reward_payload = ctx.accounts.launchpool.get_collect_protocol_reward_payload()?;
     let protocol_reward_to_redeem = collect_protocol_reward_payload.protocol_reward_amount();
     ctx.accounts.launchpool.collect_protocol_reward(collect_protocol_reward_payload);
 
-    let get_receive_reward_instruction = Box::new(ctx.accounts.get_receive_reward_transfer_instruction(protocol_reward_to_redeem)?);
+    let get_receive_reward_instruction = Box::new(ctx.accounts.get_receive_reward_transfer_instruction(protocol_reward_to_redeem)?.with_transfer_hook_accounts(ctx.remaining_accounts));
     let protocol_reward_to_redeem_after_fee = get_receive_reward_instruction.get_amount_after_fee();
     let launchpool_seeds = ctx.accounts.launchpool.seeds();
     let receive_reward_instruction_seeds: &[&[&[u8]]] = &[&launchpool_seeds];
@@ -51,7 +51,7 @@ pub struct IncreaseStakePosition<'info> {
     pub stakable_token_program: Interface<'info, TokenInterface>,
 }
 
-pub(crate) fn handler(ctx: Context<IncreaseStakePosition>, stake_increase_amount: u64) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, IncreaseStakePosition<'info>>, stake_increase_amount: u64) -> Result<()> {
     let now = Clock::get()?.unix_timestamp as u64;
     let launchpool = &mut ctx.accounts.launchpool;
 
@@ -60,7 +60,7 @@ pub(crate) fn handler(ctx: Context<IncreaseStakePosition>, stake_increase_amount
 
     let launchpool_snapshot = Launchpool::get_snapshot(launchpool);
 
-    let get_increase_stake_transfer_instruction = Box::new(ctx.accounts.get_increase_stake_transfer_instruction(stake_increase_amount)?);
+    let get_increase_stake_transfer_instruction = Box::new(ctx.accounts.get_increase_stake_transfer_instruction(stake_increase_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
     let stake_increase_amount_after_fee = get_increase_stake_transfer_instruction.get_amount_after_fee();
     get_increase_stake_transfer_instruction.execute(None)?;
 
@@ -0,0 +1,187 @@
+use anchor_lang::{emit, event, Accounts, Key, ToAccountInfo};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{TokenAccount as InterfaceTokenAccount, TokenInterface, Mint};
+use liquidity_pool::program::LiquidityPool;
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::state::{Launchpool, LaunchpoolMigration};
+
+/// Launches the `CpAmm` of a finished `Launchpool` with the reserved base liquidity and the deposited quote liquidity.
+///
+/// Can be called by anyone once the launchpool has ended. The LP tokens are minted to the migration authority,
+/// which has no instruction to move them, so the launch liquidity stays locked.
+/// The transfer hook accounts of the mints are passed as remaining accounts and forwarded to the launch.
+#[derive(Accounts)]
+pub struct MigrateLaunchpoolToCpAmm<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = reward_mint.key() == launchpool.reward_mint,
+        constraint = reward_vault.key() == launchpool.reward_vault().key(),
+        seeds = [Launchpool::SEED, launchpool.reward_mint.as_ref()],
+        bump = launchpool.bump()
+    )]
+    pub launchpool: Box<Account<'info, Launchpool>>,
+
+    #[account(
+        mut,
+        seeds = [Launchpool::VAULT_SEED, launchpool.key().as_ref()],
+        bump = launchpool.reward_vault_bump()
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = quote_mint.key() == launchpool_migration.quote_mint().key(),
+        constraint = cp_amm.key() == launchpool_migration.cp_amm().key(),
+        seeds = [LaunchpoolMigration::SEED, launchpool.key().as_ref()],
+        bump = launchpool_migration.bump()
+    )]
+    pub launchpool_migration: Box<Account<'info, LaunchpoolMigration>>,
+
+    #[account(
+        mut,
+        seeds = [LaunchpoolMigration::AUTHORITY_SEED, launchpool.key().as_ref()],
+        bump = launchpool_migration.authority_bump()
+    )]
+    pub migration_authority: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = reward_token_program
+    )]
+    pub migration_authority_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = migration_authority,
+        associated_token::token_program = quote_token_program
+    )]
+    pub migration_authority_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: Initialized by the liquidity pool program as the migration authority's LP associated token account
+    pub migration_authority_lp_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program against the `CpAmm`
+    pub lp_mint: UncheckedAccount<'info>,
+    /// CHECK: Validated by the liquidity pool program against the `CpAmm`
+    pub amms_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program, matches the launchpool migration
+    pub cp_amm: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program against the `CpAmm`
+    pub cp_amm_base_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program against the `CpAmm`
+    pub cp_amm_quote_vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: Validated by the liquidity pool program against the `CpAmm`
+    pub cp_amm_locked_lp_vault: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub lp_token_program: Program<'info, Token2022>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub liquidity_pool_program: Program<'info, LiquidityPool>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateLaunchpoolToCpAmm<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp as u64;
+    ctx.accounts.launchpool.accrue_rewards(now)?;
+    ctx.accounts.launchpool.check_finished_state(now)?;
+    ctx.accounts.launchpool_migration.migrate()?;
+
+    let base_amount = ctx.accounts.launchpool_migration.base_amount();
+    let quote_amount = ctx.accounts.launchpool_migration.quote_amount();
+
+    let withdraw_base_instruction = Box::new(ctx.accounts.get_withdraw_base_transfer_instruction(base_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let base_amount_after_fee = withdraw_base_instruction.get_amount_after_fee();
+    let launchpool_seeds = ctx.accounts.launchpool.seeds();
+    withdraw_base_instruction.execute(Some(&[&launchpool_seeds]))?;
+
+    let migration_authority_seeds = ctx.accounts.launchpool_migration.authority_seeds();
+    ctx.accounts.launch_cp_amm(base_amount_after_fee, quote_amount, ctx.remaining_accounts, &[&migration_authority_seeds])?;
+
+    msg!("Event: MigrateLaunchpoolToCpAmm");
+    emit!(
+        MigrateLaunchpoolToCpAmmEvent{
+            signer: ctx.accounts.signer.key(),
+            launchpool: ctx.accounts.launchpool.key(),
+            launchpool_migration: ctx.accounts.launchpool_migration.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            base_amount: base_amount_after_fee,
+            quote_amount,
+            timestamp: now
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct MigrateLaunchpoolToCpAmmEvent {
+    pub signer: Pubkey,
+    pub launchpool: Pubkey,
+    pub launchpool_migration: Pubkey,
+    pub cp_amm: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub timestamp: u64
+}
+
+impl<'info> MigrateLaunchpoolToCpAmm<'info> {
+    fn get_withdraw_base_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.reward_mint,
+            &self.reward_vault,
+            self.launchpool.to_account_info(),
+            &self.migration_authority_base_account,
+            &self.reward_token_program
+        )
+    }
+    /// Launches the `CpAmm` signed by the migration authority as its creator.
+    #[inline(never)]
+    fn launch_cp_amm(&self, base_liquidity: u64, quote_liquidity: u64, transfer_hook_accounts: &[AccountInfo<'info>], signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let accounts = liquidity_pool::cpi::accounts::LaunchCpAmm {
+            creator: self.migration_authority.to_account_info(),
+            base_mint: self.reward_mint.to_account_info(),
+            quote_mint: self.quote_mint.to_account_info(),
+            lp_mint: self.lp_mint.to_account_info(),
+            creator_base_account: self.migration_authority_base_account.to_account_info(),
+            creator_quote_account: self.migration_authority_quote_account.to_account_info(),
+            creator_lp_account: self.migration_authority_lp_account.to_account_info(),
+            amms_config: self.amms_config.to_account_info(),
+            cp_amm: self.cp_amm.to_account_info(),
+            cp_amm_base_vault: self.cp_amm_base_vault.to_account_info(),
+            cp_amm_quote_vault: self.cp_amm_quote_vault.to_account_info(),
+            cp_amm_locked_lp_vault: self.cp_amm_locked_lp_vault.to_account_info(),
+            lp_token_program: self.lp_token_program.to_account_info(),
+            base_token_program: self.reward_token_program.to_account_info(),
+            quote_token_program: self.quote_token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            associated_token_program: self.associated_token_program.to_account_info(),
+        };
+        liquidity_pool::cpi::launch_cp_amm(
+            CpiContext::new_with_signer(self.liquidity_pool_program.to_account_info(), accounts, signer_seeds)
+                .with_remaining_accounts(transfer_hook_accounts.to_vec()),
+            base_liquidity,
+            quote_liquidity
+        )
+    }
+}
@@ -4,9 +4,11 @@ pub mod open_stake_position;
 pub mod increase_stake_position;
 pub mod collect_protocol_reward;
 pub mod close_stake_position;
+pub mod migrate_launchpool_to_cp_amm;
 
 pub use admin::*;
 pub use open_stake_position::*;
 pub use increase_stake_position::*;
 pub use collect_protocol_reward::*;
-pub use close_stake_position::*;
\ No newline at end of file
+pub use close_stake_position::*;
+pub use migrate_launchpool_to_cp_amm::*;
\ No newline at end of file
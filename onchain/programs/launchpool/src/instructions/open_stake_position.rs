@@ -56,7 +56,7 @@ pub struct OpenStakePosition<'info> {
     pub stakable_token_program: Interface<'info, TokenInterface>
 }
 
-pub(crate) fn handler(ctx: Context<OpenStakePosition>, stake_amount: u64) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, OpenStakePosition<'info>>, stake_amount: u64) -> Result<()> {
     msg!("Before 1");
     ctx.accounts.stake_position.initialize(
         &ctx.accounts.signer.to_account_info(),
@@ -71,7 +71,7 @@ pub(crate) fn handler(ctx: Context<OpenStakePosition>, stake_amount: u64) -> Res
     launchpool.accrue_rewards(now)?;
     let launchpool_snapshot = Launchpool::get_snapshot(launchpool);
 
-    let get_stake_transfer_instruction = Box::new(ctx.accounts.get_stake_transfer_instruction(stake_amount)?);
+    let get_stake_transfer_instruction = Box::new(ctx.accounts.get_stake_transfer_instruction(stake_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
     let stake_amount_after_fee = get_stake_transfer_instruction.get_amount_after_fee();
     msg!("Before 2");
     get_stake_transfer_instruction.execute(None)?;
@@ -42,14 +42,53 @@ pub mod launchpool {
         update_launchpools_config_reward_authority::handler(ctx)
     }
 
-    pub fn update_launchpools_configs_manager_authority(ctx: Context<UpdateLaunchpoolsConfigsManagerAuthority>) -> Result<()>{
+    pub fn propose_launchpools_configs_manager_authority(ctx: Context<ProposeLaunchpoolsConfigsManagerAuthority>) -> Result<()>{
+        msg!("Instruction: ProposeLaunchpoolsConfigsManagerAuthority");
+        propose_launchpools_configs_manager_authority::handler(ctx)
+    }
+
+    pub fn accept_launchpools_configs_manager_authority(ctx: Context<AcceptLaunchpoolsConfigsManagerAuthority>) -> Result<()>{
+        msg!("Instruction: AcceptLaunchpoolsConfigsManagerAuthority");
+        accept_launchpools_configs_manager_authority::handler(ctx)
+    }
+
+    pub fn cancel_launchpools_configs_manager_authority_transfer(ctx: Context<CancelLaunchpoolsConfigsManagerAuthorityTransfer>) -> Result<()>{
+        msg!("Instruction: CancelLaunchpoolsConfigsManagerAuthorityTransfer");
+        cancel_launchpools_configs_manager_authority_transfer::handler(ctx)
+    }
+
+    pub fn propose_launchpools_configs_manager_head_authority(ctx: Context<ProposeLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()>{
+        msg!("Instruction: ProposeLaunchpoolsConfigsManagerHeadAuthority");
+        propose_launchpools_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn accept_launchpools_configs_manager_head_authority(ctx: Context<AcceptLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()>{
+        msg!("Instruction: AcceptLaunchpoolsConfigsManagerHeadAuthority");
+        accept_launchpools_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn cancel_launchpools_configs_manager_head_authority_transfer(ctx: Context<CancelLaunchpoolsConfigsManagerHeadAuthorityTransfer>) -> Result<()>{
+        msg!("Instruction: CancelLaunchpoolsConfigsManagerHeadAuthorityTransfer");
+        cancel_launchpools_configs_manager_head_authority_transfer::handler(ctx)
+    }
+
+    /// Deprecated, use `propose_launchpools_configs_manager_authority`.
+    /// Only proposes the new authority, which takes over once it calls `accept_launchpools_configs_manager_authority`.
+    pub fn update_launchpools_configs_manager_authority(ctx: Context<ProposeLaunchpoolsConfigsManagerAuthority>) -> Result<()>{
         msg!("Instruction: UpdateLaunchpoolsConfigsManagerAuthority");
-        update_launchpools_configs_manager_authority::handler(ctx)
+        propose_launchpools_configs_manager_authority::handler(ctx)
     }
 
-    pub fn update_launchpools_configs_manager_head_authority(ctx: Context<UpdateLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()>{
+    /// Deprecated, use `propose_launchpools_configs_manager_head_authority`.
+    /// Only proposes the new head authority, which takes over once it calls `accept_launchpools_configs_manager_head_authority`.
+    pub fn update_launchpools_configs_manager_head_authority(ctx: Context<ProposeLaunchpoolsConfigsManagerHeadAuthority>) -> Result<()>{
         msg!("Instruction: UpdateLaunchpoolsConfigsManagerHeadAuthority");
-        update_launchpools_configs_manager_head_authority::handler(ctx)
+        propose_launchpools_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn migrate_launchpools_configs_manager(ctx: Context<MigrateLaunchpoolsConfigsManager>) -> Result<()>{
+        msg!("Instruction: MigrateLaunchpoolsConfigsManager");
+        migrate_launchpools_configs_manager::handler(ctx)
     }
 
     pub fn initialize_launchpool(ctx: Context<InitializeLaunchpool>, initial_reward_amount: u64) -> Result<()>{
@@ -62,23 +101,33 @@ pub mod launchpool {
         launch_launchpool::handler(ctx, start_timestamp)
     }
 
-    pub fn open_stake_position(ctx: Context<OpenStakePosition>, stake_amount: u64) -> Result<()>{
+    pub fn open_stake_position<'info>(ctx: Context<'_, '_, 'info, 'info, OpenStakePosition<'info>>, stake_amount: u64) -> Result<()>{
         msg!("Instruction: OpenStakePosition");
         open_stake_position::handler(ctx, stake_amount)
     }
 
-    pub fn increase_stake_position(ctx: Context<IncreaseStakePosition>, stake_increase_amount: u64) -> Result<()>{
+    pub fn increase_stake_position<'info>(ctx: Context<'_, '_, 'info, 'info, IncreaseStakePosition<'info>>, stake_increase_amount: u64) -> Result<()>{
         msg!("Instruction: IncreaseStakePosition");
         increase_stake_position::handler(ctx, stake_increase_amount)
     }
 
-    pub fn close_stake_position(ctx: Context<CloseStakePosition>) -> Result<()>{
+    pub fn close_stake_position<'info>(ctx: Context<'_, '_, 'info, 'info, CloseStakePosition<'info>>) -> Result<()>{
         msg!("Instruction: CloseStakePosition");
         close_stake_position::handler(ctx)
     }
 
-    pub fn collect_protocol_reward(ctx: Context<CollectProtocolReward>) -> Result<()>{
+    pub fn collect_protocol_reward<'info>(ctx: Context<'_, '_, 'info, 'info, CollectProtocolReward<'info>>) -> Result<()>{
         msg!("Instruction: CollectProtocolReward");
         collect_protocol_reward::handler(ctx)
     }
+
+    pub fn initialize_launchpool_migration<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeLaunchpoolMigration<'info>>, base_share_basis_points: u16, quote_amount: u64, funding_lamports: u64) -> Result<()>{
+        msg!("Instruction: InitializeLaunchpoolMigration");
+        initialize_launchpool_migration::handler(ctx, base_share_basis_points, quote_amount, funding_lamports)
+    }
+
+    pub fn migrate_launchpool_to_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateLaunchpoolToCpAmm<'info>>) -> Result<()>{
+        msg!("Instruction: MigrateLaunchpoolToCpAmm");
+        migrate_launchpool_to_cp_amm::handler(ctx)
+    }
 }
\ No newline at end of file
@@ -55,4 +55,7 @@ pub enum LaunchpoolError {
 
     #[msg("Overflow occurred while redeeming participant rewards.")]
     RewardObtentionOverflow,
+
+    #[msg("Protocol reward is insufficient for the migration reward.")]
+    InsufficientProtocolRewardForMigration,
 }
\ No newline at end of file
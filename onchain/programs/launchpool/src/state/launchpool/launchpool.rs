@@ -301,6 +301,16 @@ impl Launchpool{
         self.status = LaunchpoolStatus::ClaimedProtocolReward;
         self.protocol_reward_left_to_obtain = 0;
     }
+
+    /// Moves part of the protocol reward into the launchpool migration, so it is kept in the reward vault
+    /// after the protocol reward is collected.
+    #[inline(never)]
+    pub(crate) fn reserve_migration_reward(&mut self, migration_reward_amount: u64) -> Result<()> {
+        require_eq!(self.status, LaunchpoolStatus::Initialized, LaunchpoolError::LaunchpoolNotInitialized);
+        self.protocol_reward_amount = self.protocol_reward_amount.checked_sub(migration_reward_amount).ok_or(LaunchpoolError::InsufficientProtocolRewardForMigration)?;
+        self.protocol_reward_left_to_obtain = self.protocol_reward_amount;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -799,5 +809,28 @@ mod tests {
             assert_eq!(launchpool.status(), LaunchpoolStatus::ClaimedProtocolReward);
         }
 
+        #[test]
+        fn test_reserve_migration_reward_success() {
+            let mut launchpool = Launchpool::default();
+            launchpool.status = LaunchpoolStatus::Initialized;
+            launchpool.protocol_reward_amount = 1000;
+            launchpool.protocol_reward_left_to_obtain = 1000;
+            launchpool.reserve_migration_reward(400).unwrap();
+            assert_eq!(launchpool.protocol_reward_amount(), 600);
+            assert_eq!(launchpool.protocol_reward_left_to_obtain(), 600);
+        }
+
+        #[test]
+        fn test_reserve_migration_reward_fails() {
+            let mut launchpool = Launchpool::default();
+            launchpool.status = LaunchpoolStatus::Initialized;
+            launchpool.protocol_reward_amount = 1000;
+            let result = launchpool.reserve_migration_reward(1001);
+            assert!(matches!(result, Err(e) if e == LaunchpoolError::InsufficientProtocolRewardForMigration.into()));
+
+            launchpool.status = LaunchpoolStatus::Launched;
+            let result = launchpool.reserve_migration_reward(100);
+            assert!(matches!(result, Err(e) if e == LaunchpoolError::LaunchpoolNotInitialized.into()));
+        }
     }
 }
\ No newline at end of file
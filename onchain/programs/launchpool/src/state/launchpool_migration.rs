@@ -0,0 +1,209 @@
+use anchor_lang::{account, require, InitSpace};
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Migration of a `Launchpool` into a liquidity pool `CpAmm`.
+///
+/// The base liquidity is a share of the launchpool's protocol reward, kept in the reward vault
+/// until the launchpool is finished. The quote liquidity is deposited upfront by the authority,
+/// so the pool opens at the price `quote_amount / base_amount`.
+/// The `CpAmm` is created and launched by the migration authority PDA, which keeps the LP tokens.
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchpoolMigration {
+    launchpool: Pubkey,
+    cp_amm: Pubkey,
+    quote_mint: Pubkey,
+    base_share_basis_points: u16,
+    base_amount: u64,
+    quote_amount: u64,
+    migrated: bool,
+    bump: [u8; 1],
+    authority_bump: [u8; 1],
+}
+
+impl LaunchpoolMigration {
+    pub const SEED: &'static [u8] = b"launchpool_migration";
+
+    /// Seed used for generating the migration authority PDA.
+    pub const AUTHORITY_SEED: &'static [u8] = b"migration_authority";
+
+    pub fn authority_seeds(&self) -> [&[u8]; 3] {
+        [Self::AUTHORITY_SEED, self.launchpool.as_ref(), self.authority_bump.as_ref()]
+    }
+
+    /// Calculates the share of the protocol reward seeding the `CpAmm`.
+    pub(crate) fn calculate_base_amount(protocol_reward_amount: u64, base_share_basis_points: u16) -> Result<u64> {
+        require!(base_share_basis_points > 0 && base_share_basis_points <= 10000, ErrorCode::InvalidMigrationShare);
+        let base_amount = (protocol_reward_amount as u128 * base_share_basis_points as u128 / 10_000) as u64;
+        require!(base_amount > 0, ErrorCode::InvalidMigrationBaseAmount);
+        Ok(base_amount)
+    }
+
+    pub(crate) fn initialize(&mut self, launchpool: Pubkey, cp_amm: Pubkey, quote_mint: Pubkey, base_share_basis_points: u16, base_amount: u64, quote_amount: u64, bump: u8, authority_bump: u8) -> Result<()> {
+        require!(base_amount > 0, ErrorCode::InvalidMigrationBaseAmount);
+        require!(quote_amount > 0, ErrorCode::InvalidMigrationQuoteAmount);
+
+        self.launchpool = launchpool;
+        self.cp_amm = cp_amm;
+        self.quote_mint = quote_mint;
+        self.base_share_basis_points = base_share_basis_points;
+        self.base_amount = base_amount;
+        self.quote_amount = quote_amount;
+        self.migrated = false;
+        self.bump = [bump];
+        self.authority_bump = [authority_bump];
+
+        Ok(())
+    }
+
+    pub(crate) fn migrate(&mut self) -> Result<()> {
+        require!(!self.migrated, ErrorCode::LaunchpoolAlreadyMigrated);
+        self.migrated = true;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn launchpool(&self) -> &Pubkey {
+        &self.launchpool
+    }
+
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    #[inline]
+    pub fn quote_mint(&self) -> &Pubkey {
+        &self.quote_mint
+    }
+
+    #[inline]
+    pub fn base_share_basis_points(&self) -> u16 {
+        self.base_share_basis_points
+    }
+
+    #[inline]
+    pub fn base_amount(&self) -> u64 {
+        self.base_amount
+    }
+
+    #[inline]
+    pub fn quote_amount(&self) -> u64 {
+        self.quote_amount
+    }
+
+    #[inline]
+    pub fn migrated(&self) -> bool {
+        self.migrated
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn authority_bump(&self) -> u8 {
+        self.authority_bump[0]
+    }
+}
+
+#[cfg(test)]
+mod launchpool_migration_tests {
+    use super::*;
+    use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+
+    fn uninitialized_migration() -> LaunchpoolMigration {
+        LaunchpoolMigration {
+            launchpool: Pubkey::default(),
+            cp_amm: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+            base_share_basis_points: 0,
+            base_amount: 0,
+            quote_amount: 0,
+            migrated: false,
+            bump: [0],
+            authority_bump: [0],
+        }
+    }
+
+    #[test]
+    fn test_calculate_base_amount() {
+        assert_eq!(LaunchpoolMigration::calculate_base_amount(1_000, 2_500).unwrap(), 250);
+        assert_eq!(LaunchpoolMigration::calculate_base_amount(1_000, 10_000).unwrap(), 1_000);
+
+        assert!(LaunchpoolMigration::calculate_base_amount(1_000, 0).is_err());
+        assert!(LaunchpoolMigration::calculate_base_amount(1_000, 10_001).is_err());
+        assert!(LaunchpoolMigration::calculate_base_amount(1, 5_000).is_err());
+    }
+
+    #[test]
+    fn test_launchpool_migration_initialize_and_migrate() {
+        let mut migration = uninitialized_migration();
+        let launchpool = Pubkey::new_unique();
+        let cp_amm = Pubkey::new_unique();
+        let quote_mint = Pubkey::new_unique();
+
+        assert!(migration.initialize(launchpool, cp_amm, quote_mint, 2_500, 0, 100, 1, 2).is_err());
+        assert!(migration.initialize(launchpool, cp_amm, quote_mint, 2_500, 100, 0, 1, 2).is_err());
+        migration.initialize(launchpool, cp_amm, quote_mint, 2_500, 250, 100, 1, 2).unwrap();
+
+        assert_eq!(migration.launchpool(), &launchpool);
+        assert_eq!(migration.cp_amm(), &cp_amm);
+        assert_eq!(migration.quote_mint(), &quote_mint);
+        assert_eq!(migration.base_amount(), 250);
+        assert_eq!(migration.quote_amount(), 100);
+        assert!(!migration.migrated());
+
+        migration.migrate().unwrap();
+        assert!(migration.migrated());
+        assert!(migration.migrate().is_err());
+    }
+
+    #[test]
+    fn test_launchpool_migration_data_layout() {
+        let launchpool = Pubkey::new_unique();
+        let cp_amm = Pubkey::new_unique();
+        let quote_mint = Pubkey::new_unique();
+        let base_share_basis_points: u16 = 2_500;
+        let base_amount: u64 = 1_000;
+        let quote_amount: u64 = 2_000;
+        let migrated = true;
+        let bump: u8 = 42;
+        let authority_bump: u8 = 7;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 117];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&LaunchpoolMigration::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset..offset + 32].copy_from_slice(launchpool.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(quote_mint.as_ref()); offset += 32;
+        data[offset..offset + 2].copy_from_slice(&base_share_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 8].copy_from_slice(&base_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&quote_amount.to_le_bytes()); offset += 8;
+        data[offset] = migrated as u8; offset += 1;
+        data[offset] = bump; offset += 1;
+        data[offset] = authority_bump; offset += 1;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 117);
+
+        let deserialized_migration = LaunchpoolMigration::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(deserialized_migration.launchpool(), &launchpool);
+        assert_eq!(deserialized_migration.cp_amm(), &cp_amm);
+        assert_eq!(deserialized_migration.quote_mint(), &quote_mint);
+        assert_eq!(deserialized_migration.base_share_basis_points(), base_share_basis_points);
+        assert_eq!(deserialized_migration.base_amount(), base_amount);
+        assert_eq!(deserialized_migration.quote_amount(), quote_amount);
+        assert_eq!(deserialized_migration.migrated(), migrated);
+        assert_eq!(deserialized_migration.bump(), bump);
+        assert_eq!(deserialized_migration.authority_bump(), authority_bump);
+
+        let mut serialized_migration = Vec::new();
+        deserialized_migration.try_serialize(&mut serialized_migration).unwrap();
+        assert_eq!(serialized_migration.as_slice(), data.as_ref());
+    }
+}
@@ -1,5 +1,6 @@
 use anchor_lang::{account, InitSpace};
 use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
 
 #[account]
 #[derive(InitSpace)]
@@ -8,10 +9,13 @@ pub struct LaunchpoolsConfigsManager {
     head_authority: Pubkey,
     configs_count: u64,
     bump: u8,
+    pending_authority: Pubkey,
+    pending_head_authority: Pubkey,
 }
 
 impl LaunchpoolsConfigsManager {
     pub const SEED: &'static [u8] = b"launchpools_configs_manager";
+    pub const INITIAL_LAYOUT_LEN: usize = 8 + 73;
 
     pub(crate) fn initialize(&mut self, authority: Pubkey, head_authority: Pubkey, bump: u8) {
         self.bump = bump;
@@ -28,6 +32,40 @@ impl LaunchpoolsConfigsManager {
         self.head_authority = head_authority;
     }
 
+    pub(crate) fn propose_authority(&mut self, pending_authority: Pubkey) {
+        self.pending_authority = pending_authority;
+    }
+
+    pub(crate) fn accept_authority(&mut self) -> Result<()> {
+        require!(self.pending_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.update_authority(self.pending_authority);
+        self.pending_authority = Pubkey::default();
+        Ok(())
+    }
+
+    pub(crate) fn cancel_authority_transfer(&mut self) -> Result<()> {
+        require!(self.pending_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.pending_authority = Pubkey::default();
+        Ok(())
+    }
+
+    pub(crate) fn propose_head_authority(&mut self, pending_head_authority: Pubkey) {
+        self.pending_head_authority = pending_head_authority;
+    }
+
+    pub(crate) fn accept_head_authority(&mut self) -> Result<()> {
+        require!(self.pending_head_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.update_head_authority(self.pending_head_authority);
+        self.pending_head_authority = Pubkey::default();
+        Ok(())
+    }
+
+    pub(crate) fn cancel_head_authority_transfer(&mut self) -> Result<()> {
+        require!(self.pending_head_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.pending_head_authority = Pubkey::default();
+        Ok(())
+    }
+
     pub(crate) fn increment_configs_count(&mut self) {
         self.configs_count = self.configs_count.checked_add(1).unwrap()
     }
@@ -42,6 +80,24 @@ impl LaunchpoolsConfigsManager {
         &self.authority
     }
 
+    #[inline]
+    pub fn pending_authority(&self) -> Option<&Pubkey> {
+        if self.pending_authority == Pubkey::default() {
+            None
+        } else {
+            Some(&self.pending_authority)
+        }
+    }
+
+    #[inline]
+    pub fn pending_head_authority(&self) -> Option<&Pubkey> {
+        if self.pending_head_authority == Pubkey::default() {
+            None
+        } else {
+            Some(&self.pending_head_authority)
+        }
+    }
+
     #[inline]
     pub fn configs_count(&self) -> u64 {
         self.configs_count
@@ -66,6 +122,8 @@ mod launchpools_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 0,
             bump: 0,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let authority = Pubkey::new_unique();
@@ -92,6 +150,8 @@ mod launchpools_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 10,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let new_authority = Pubkey::new_unique();
@@ -107,6 +167,8 @@ mod launchpools_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 10,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let new_head_authority = Pubkey::new_unique();
@@ -115,6 +177,48 @@ mod launchpools_configs_manager_tests {
         assert_eq!(manager.head_authority, new_head_authority);
     }
 
+    #[test]
+    fn test_launchpools_configs_manager_authority_transfers() {
+        let authority = Pubkey::new_unique();
+        let head_authority = Pubkey::new_unique();
+        let mut manager = LaunchpoolsConfigsManager {
+            authority,
+            head_authority,
+            configs_count: 10,
+            bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
+        };
+        assert!(manager.accept_authority().is_err());
+        assert!(manager.cancel_authority_transfer().is_err());
+        assert!(manager.accept_head_authority().is_err());
+        assert!(manager.cancel_head_authority_transfer().is_err());
+
+        let new_authority = Pubkey::new_unique();
+        manager.propose_authority(new_authority);
+        assert_eq!(manager.pending_authority(), Some(&new_authority));
+        assert_eq!(manager.authority, authority);
+        manager.cancel_authority_transfer().unwrap();
+        assert_eq!(manager.pending_authority(), None);
+
+        manager.propose_authority(new_authority);
+        manager.accept_authority().unwrap();
+        assert_eq!(manager.authority, new_authority);
+        assert_eq!(manager.pending_authority(), None);
+
+        let new_head_authority = Pubkey::new_unique();
+        manager.propose_head_authority(new_head_authority);
+        assert_eq!(manager.pending_head_authority(), Some(&new_head_authority));
+        assert_eq!(manager.head_authority, head_authority);
+        manager.cancel_head_authority_transfer().unwrap();
+        assert_eq!(manager.pending_head_authority(), None);
+
+        manager.propose_head_authority(new_head_authority);
+        manager.accept_head_authority().unwrap();
+        assert_eq!(manager.head_authority, new_head_authority);
+        assert_eq!(manager.pending_head_authority(), None);
+    }
+
     #[test]
     fn test_launchpools_configs_manager_increment_configs_count(){
         let mut manager = LaunchpoolsConfigsManager {
@@ -122,6 +226,8 @@ mod launchpools_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 5,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         manager.increment_configs_count();
@@ -135,8 +241,10 @@ mod launchpools_configs_manager_tests {
         let head_authority = Pubkey::new_unique();
         let configs_count = 42u64;
         let bump = 42u8;
+        let pending_authority = Pubkey::new_unique();
+        let pending_head_authority = Pubkey::new_unique();
 
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 137];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&LaunchpoolsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -144,8 +252,10 @@ mod launchpools_configs_manager_tests {
         data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
         data[offset..offset + 8].copy_from_slice(&configs_count.to_le_bytes()); offset += 8;
         data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(pending_authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(pending_head_authority.as_ref()); offset += 32;
 
-        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 73);
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 137);
 
         let deserialized_manager = LaunchpoolsConfigsManager::try_deserialize(&mut data.as_ref()).unwrap();
 
@@ -153,9 +263,36 @@ mod launchpools_configs_manager_tests {
         assert_eq!(deserialized_manager.head_authority().key(), head_authority);
         assert_eq!(deserialized_manager.configs_count(), configs_count);
         assert_eq!(deserialized_manager.bump(), bump);
+        assert_eq!(deserialized_manager.pending_authority(), Some(&pending_authority));
+        assert_eq!(deserialized_manager.pending_head_authority(), Some(&pending_head_authority));
 
         let mut serialized_data = Vec::new();
         deserialized_manager.try_serialize(&mut serialized_data).unwrap();
         assert_eq!(serialized_data.as_slice(), data.as_ref());
     }
+
+    #[test]
+    fn test_launchpools_configs_manager_initial_layout_migration() {
+        let authority = Pubkey::new_unique();
+        let head_authority = Pubkey::new_unique();
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 137];
+        let mut offset = 0;
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&LaunchpoolsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset..offset + 32].copy_from_slice(authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&3u64.to_le_bytes()); offset += 8;
+        data[offset] = 42; offset += 1;
+        assert_eq!(offset, LaunchpoolsConfigsManager::INITIAL_LAYOUT_LEN);
+
+        assert!(LaunchpoolsConfigsManager::try_deserialize(&mut &data[..LaunchpoolsConfigsManager::INITIAL_LAYOUT_LEN]).is_err());
+
+        let migrated_manager = LaunchpoolsConfigsManager::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(data.len(), ANCHOR_DISCRIMINATOR + LaunchpoolsConfigsManager::INIT_SPACE);
+        assert_eq!(migrated_manager.authority().key(), authority);
+        assert_eq!(migrated_manager.head_authority().key(), head_authority);
+        assert_eq!(migrated_manager.configs_count(), 3);
+        assert_eq!(migrated_manager.bump(), 42);
+        assert_eq!(migrated_manager.pending_authority(), None);
+        assert_eq!(migrated_manager.pending_head_authority(), None);
+    }
 }
\ No newline at end of file
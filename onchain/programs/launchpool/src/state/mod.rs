@@ -3,8 +3,10 @@ mod launchpools_config;
 mod launchpools_configs_manager;
 pub mod enums;
 mod stake_position;
+mod launchpool_migration;
 
 pub use launchpools_config::*;
 pub use launchpools_configs_manager::*;
 pub use stake_position::*;
-pub use launchpool::*;
\ No newline at end of file
+pub use launchpool::*;
+pub use launchpool_migration::*;
\ No newline at end of file
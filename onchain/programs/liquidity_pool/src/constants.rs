@@ -1 +1,20 @@
-pub const CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS: u64 = 100_000_000;
\ No newline at end of file
+/// Number of remaining accounts describing a single hop of a routed swap.
+pub const ROUTED_SWAP_HOP_ACCOUNTS_COUNT: usize = 10;
+/// Maximal number of hops in a routed swap.
+pub const ROUTED_SWAP_MAX_HOPS: usize = 4;
+
+/// Number of remaining accounts describing a single CpAmm of a protocol fees sweep.
+pub const FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT: usize = 9;
+/// Maximal number of CpAmms swept in a single protocol fees sweep.
+pub const FEES_SWEEP_MAX_CP_AMMS: usize = 8;
+
+/// Number of remaining accounts describing a single order of a limit orders fill.
+pub const LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT: usize = 4;
+/// Maximal number of limit orders filled in a single crank.
+pub const LIMIT_ORDERS_FILL_MAX_ORDERS: usize = 8;
+
+/// Maximal number of tick arrays a single ClAmm swap can move the price through.
+pub const CL_AMM_SWAP_MAX_TICK_ARRAYS: usize = 3;
+
+/// Position of the `cp_amm` account in the `flash_repay_to_cp_amm` instruction accounts.
+pub const FLASH_REPAY_CP_AMM_ACCOUNT_INDEX: usize = 5;
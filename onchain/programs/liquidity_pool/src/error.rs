@@ -102,4 +102,300 @@ pub enum ErrorCode {
 
     #[msg("Liquidity ratio tolerance exceeded.")]
     LiquidityRatioToleranceExceeded,
-}
\ No newline at end of file
+
+    // CpAmm exact-output swap errors
+    #[msg("Failed to calculate required swap input due to invalid input or overflow.")]
+    BeforeswapCalculationFailed,
+
+    #[msg("Required swap input exceeds the maximum allowed input.")]
+    SwapMaxInputExceeded,
+
+    // CpAmm routed swap errors
+    #[msg("Routed swap accounts must describe between 1 and the maximal allowed number of hops, 10 accounts per hop.")]
+    InvalidRouteLength,
+
+    #[msg("Routed swap hop accounts do not match the CpAmm state.")]
+    InvalidRouteHopAccounts,
+
+    #[msg("Routed swap hops are not chained: output of a hop must be the input of the next one.")]
+    RouteIsNotChained,
+
+    #[msg("Routed swap result is less than the minimal expected result.")]
+    RoutedSwapResultBelowMinimum,
+
+    // CpAmm price oracle errors
+    #[msg("Price observation is too close to the previous one.")]
+    ObservationIntervalTooShort,
+
+    #[msg("No price observation old enough for the requested TWAP window.")]
+    TwapObservationNotFound,
+
+    #[msg("Failed to calculate TWAP due to invalid observations or overflow.")]
+    TwapCalculationFailed,
+
+    // StableAmm errors
+    #[msg("StableAmm is already initialized.")]
+    StableAmmAlreadyInitialized,
+
+    #[msg("StableAmm is not initialized.")]
+    StableAmmNotInitialized,
+
+    #[msg("StableAmm is already launched.")]
+    StableAmmAlreadyLaunched,
+
+    #[msg("StableAmm is not launched.")]
+    StableAmmNotLaunched,
+
+    #[msg("StableAmm base and quote mints must have the same decimals.")]
+    StableAmmMintsDecimalsMismatch,
+
+    #[msg("Amplification coefficient is out of the allowed range.")]
+    InvalidAmplification,
+
+    #[msg("Amplification ramp duration is shorter than the minimal allowed duration.")]
+    AmplificationRampTooShort,
+
+    #[msg("Amplification coefficient change exceeds the maximal allowed change.")]
+    AmplificationChangeTooLarge,
+
+    #[msg("Failed to calculate the StableSwap invariant due to divergence or overflow.")]
+    InvariantCalculationFailed,
+
+    #[msg("StableSwap invariant decreased beyond the allowed tolerance.")]
+    InvariantToleranceExceeded,
+
+    // AmmsConfig dynamic fee errors
+    #[msg("Dynamic providers fee rate lower bound exceeds the upper bound.")]
+    InvalidDynamicFeeBounds,
+
+    // CpAmm zap errors
+    #[msg("Zap amount can't be zero.")]
+    ZapAmountIsZero,
+
+    #[msg("Failed to calculate the zap swap amount due to an invalid fee rate or overflow.")]
+    ZapSwapAmountCalculationFailed,
+
+    #[msg("Zap minted LP tokens are less than the minimal expected amount.")]
+    ZapLpTokensBelowMinimum,
+
+    #[msg("Zap result is less than the minimal expected result.")]
+    ZapResultBelowMinimum,
+
+    // CpAmm liquidity slippage errors
+    #[msg("Provided liquidity mints less LP tokens than the minimal expected amount.")]
+    ProvideLpTokensBelowMinimum,
+
+    #[msg("Withdrawn base liquidity is less than the minimal expected amount.")]
+    WithdrawBaseBelowMinimum,
+
+    #[msg("Withdrawn quote liquidity is less than the minimal expected amount.")]
+    WithdrawQuoteBelowMinimum,
+
+    // Pause errors
+    #[msg("Swaps are paused.")]
+    SwapsPaused,
+
+    #[msg("Liquidity provision is paused.")]
+    ProvidesPaused,
+
+    // Protocol fees collection errors
+    #[msg("Requested protocol fees exceed the fees available for redemption.")]
+    CollectFeesAmountExceeded,
+
+    #[msg("Invalid number of fees sweep remaining accounts.")]
+    InvalidFeesSweepLength,
+
+    #[msg("Fees sweep accounts don't match the CpAmm.")]
+    InvalidFeesSweepAccounts,
+
+    // Protocol LP fees errors
+    #[msg("Protocol LP fees to claim are zero.")]
+    ProtocolLpFeesIsZero,
+
+    #[msg("Requested protocol LP tokens exceed the accrued protocol LP tokens.")]
+    ProtocolLpFeesAmountExceeded,
+
+    // ClAmm errors
+    #[msg("ClAmm is already initialized.")]
+    ClAmmAlreadyInitialized,
+
+    #[msg("ClAmm is not initialized.")]
+    ClAmmNotInitialized,
+
+    #[msg("Tick spacing is out of the allowed range.")]
+    InvalidTickSpacing,
+
+    #[msg("Initial square root price is out of the allowed range.")]
+    InvalidInitialSqrtPrice,
+
+    #[msg("Tick range is invalid or not aligned to the tick spacing.")]
+    InvalidTickRange,
+
+    #[msg("Tick array start tick is not aligned to the tick array span or is out of range.")]
+    InvalidTickArrayStartTick,
+
+    #[msg("Tick is not covered by the tick array.")]
+    TickNotInTickArray,
+
+    #[msg("Tick arrays don't cover the swap path from the current tick.")]
+    InvalidTickArrays,
+
+    #[msg("Provided liquidity is zero.")]
+    ProvidedClAmmLiquidityIsZero,
+
+    #[msg("Required token amounts exceed the maximal amounts.")]
+    ClAmmProvideAmountsExceeded,
+
+    #[msg("Withdrawn liquidity exceeds the position liquidity.")]
+    ClAmmPositionLiquidityExceeded,
+
+    #[msg("Liquidity calculation failed.")]
+    ClAmmLiquidityCalculationFailed,
+
+    #[msg("Swap calculation failed.")]
+    ClAmmSwapCalculationFailed,
+
+    #[msg("Swap exhausted the liquidity of the provided tick arrays.")]
+    ClAmmSwapLiquidityExhausted,
+
+    #[msg("Position fees to collect are zero.")]
+    PositionFeesIsZero,
+
+    // Flash loan errors
+    #[msg("Flash loan is in progress in this pool.")]
+    FlashLoanInProgress,
+
+    #[msg("There is no flash loan in progress in this pool.")]
+    FlashLoanNotActive,
+
+    #[msg("Flash loan amounts are zero.")]
+    FlashLoanAmountIsZero,
+
+    #[msg("Flash loan amount exceeds the pool liquidity.")]
+    FlashLoanAmountExceeded,
+
+    #[msg("Flash loan fees are zero.")]
+    FlashLoanFeesAreZero,
+
+    #[msg("Flash loan is not repaid with fees.")]
+    FlashLoanNotRepaid,
+
+    #[msg("Flash loan repay instruction for this pool is missing later in the transaction.")]
+    FlashLoanRepayNotFound,
+
+    #[msg("Flash loan can only be taken by a top-level instruction.")]
+    FlashLoanCpiNotAllowed,
+
+    // Pool creation errors
+    #[msg("Creator is not allowed to create pools with this config.")]
+    CreatorNotAllowed,
+
+    #[msg("Creator has reached the pools cap of this config.")]
+    CreatorPoolsCapReached,
+
+    #[msg("Pool creation fee accounts are missing.")]
+    PoolCreationFeeAccountsMissing,
+
+    #[msg("Pool creation fee accounts are invalid.")]
+    InvalidPoolCreationFeeAccounts,
+
+    // Pair registry errors
+    #[msg("The pair already has a canonical CpAmm in this config.")]
+    CpAmmPairAlreadyRegistered,
+
+    #[msg("CpAmm is not the canonical pool of the pair.")]
+    CpAmmIsNotCanonical,
+
+    // LP lock errors
+    #[msg("Locked LP share must be between 1 and 10000 basis points.")]
+    InvalidLpLockShare,
+
+    #[msg("Locked LP tokens amount is zero.")]
+    LpLockAmountIsZero,
+
+    #[msg("LP lock schedule is invalid.")]
+    InvalidLpLockSchedule,
+
+    #[msg("There are no unlocked LP tokens to claim.")]
+    NothingToClaimFromLpLock,
+
+    // Farm errors
+    #[msg("Farm already streams the maximal number of reward mints.")]
+    FarmRewardsLimitReached,
+
+    #[msg("The emission period of this reward mint has not ended yet.")]
+    FarmRewardScheduleActive,
+
+    #[msg("Farm reward schedule is invalid.")]
+    InvalidFarmRewardSchedule,
+
+    #[msg("Farm reward amount is zero.")]
+    FarmRewardAmountIsZero,
+
+    #[msg("Farm reward calculation overflowed.")]
+    FarmRewardCalculationOverflow,
+
+    #[msg("LP tokens amount is zero.")]
+    FarmLpTokensAmountIsZero,
+
+    #[msg("Position has fewer staked LP tokens than requested.")]
+    InsufficientFarmStake,
+
+    #[msg("Farm reward index is invalid.")]
+    InvalidFarmRewardIndex,
+
+    #[msg("There are no earned rewards to harvest.")]
+    NothingToHarvest,
+
+    // Limit order errors
+    #[msg("Limit order amounts must be greater than zero.")]
+    LimitOrderAmountIsZero,
+
+    #[msg("Limit order target ratio must be greater than zero.")]
+    InvalidLimitOrderTarget,
+
+    #[msg("Limit order mints don't match the CpAmm.")]
+    InvalidLimitOrderMints,
+
+    #[msg("Limit orders fill accounts must describe between 1 and the maximal allowed number of orders, 4 accounts per order.")]
+    InvalidLimitOrdersFillLength,
+
+    #[msg("Limit order fill accounts don't match the order.")]
+    InvalidLimitOrderFillAccounts,
+
+    #[msg("None of the limit orders could be filled.")]
+    NoLimitOrdersFilled,
+
+    // Vaults reconciliation errors
+    #[msg("CpAmm vaults hold no tokens above the stored liquidity and protocol fees.")]
+    VaultsExcessIsZero,
+
+    // Referral errors
+    #[msg("Referrer fee share exceeds the maximal allowed share of the protocol fee.")]
+    ReferrerFeeShareExceeded,
+
+    #[msg("Referrer account mint doesn't match the swap input mint.")]
+    InvalidReferrerAccount,
+
+    // Administration errors
+    #[msg("There is no pending authority transfer.")]
+    NoPendingAuthorityTransfer,
+
+    #[msg("Fee rates timelock can't be shortened or exceed the maximal allowed duration.")]
+    InvalidFeeRatesTimelockDuration,
+
+    #[msg("There is no pending fee rates change to cancel.")]
+    NoPendingFeeRatesChange,
+
+    // Deadline errors
+    #[msg("Transaction deadline has passed.")]
+    DeadlineExceeded,
+
+    // Limit order pricing errors
+    #[msg("Limit order minimal received amount is below the amount implied by its target ratio.")]
+    LimitOrderMinimumBelowTarget,
+
+    // Referral fee mode errors
+    #[msg("CpAmm swaps can't be referred while the protocol fee is collected as LP tokens.")]
+    ReferrerUnsupportedWithProtocolFeeOnLp,
+}
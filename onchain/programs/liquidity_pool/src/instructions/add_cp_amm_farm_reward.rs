@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::cp_amm::CpAmmFarm;
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Funds a new emission period of a reward mint of a `CpAmmFarm`.
+///
+/// The reward tokens are transferred into the farm's reward vault and streamed
+/// linearly to the stakers between `start_timestamp` and `end_timestamp`.
+#[derive(Accounts)]
+pub struct AddCpAmmFarmReward<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == cp_amm_farm.authority().key(),
+        seeds = [CpAmmFarm::SEED, cp_amm_farm.cp_amm().as_ref(), cp_amm_farm.authority().as_ref()],
+        bump = cp_amm_farm.bump()
+    )]
+    pub cp_amm_farm: Box<Account<'info, CpAmmFarm>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub authority_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = reward_mint,
+        associated_token::authority = cp_amm_farm,
+        associated_token::token_program = reward_token_program
+    )]
+    pub cp_amm_farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AddCpAmmFarmReward<'info>>, amount: u64, start_timestamp: i64, end_timestamp: i64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let reward_transfer_instruction = Box::new(ctx.accounts.get_reward_transfer_instruction(amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let reward_amount = reward_transfer_instruction.get_amount_after_fee();
+    reward_transfer_instruction.execute(None)?;
+
+    let reward_mint = ctx.accounts.reward_mint.key();
+    let cp_amm_farm = &mut ctx.accounts.cp_amm_farm;
+    cp_amm_farm.accrue_rewards(timestamp)?;
+    let reward_index = cp_amm_farm.add_reward(reward_mint, reward_amount, start_timestamp, end_timestamp, timestamp)?;
+
+    msg!("Event: AddCpAmmFarmReward");
+    emit!(
+        AddCpAmmFarmRewardEvent{
+            authority: ctx.accounts.authority.key(),
+            cp_amm_farm: ctx.accounts.cp_amm_farm.key(),
+            reward_mint,
+            reward_index: reward_index as u8,
+            reward_amount,
+            start_timestamp,
+            end_timestamp,
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct AddCpAmmFarmRewardEvent{
+    pub authority: Pubkey,
+    pub cp_amm_farm: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_index: u8,
+    pub reward_amount: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+    pub timestamp: i64
+}
+
+impl<'info> AddCpAmmFarmReward<'info> {
+    #[inline(never)]
+    fn get_reward_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.reward_mint,
+            &self.authority_reward_account,
+            self.authority.to_account_info(),
+            &self.cp_amm_farm_reward_vault,
+            &self.reward_token_program
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+
+#[derive(Accounts)]
+pub struct AcceptAmmsConfigsManagerAuthority<'info> {
+    #[account(
+        mut,
+        constraint = amms_configs_manager.pending_authority() == Some(&new_authority.key())
+    )]
+    new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<AcceptAmmsConfigsManagerAuthority>) -> Result<()> {
+    let previous_authority = ctx.accounts.amms_configs_manager.authority().key();
+    ctx.accounts.amms_configs_manager.accept_authority()?;
+
+    msg!("Event: UpdateAmmsConfigsManagerAuthority");
+    emit!(
+        UpdateAmmsConfigsManagerAuthorityEvent{
+            authority: previous_authority,
+            new_authority: ctx.accounts.amms_configs_manager.authority().key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigsManagerAuthorityEvent {
+    pub authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+
+#[derive(Accounts)]
+pub struct AcceptAmmsConfigsManagerHeadAuthority<'info> {
+    #[account(
+        mut,
+        constraint = amms_configs_manager.pending_head_authority() == Some(&new_head_authority.key())
+    )]
+    new_head_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<AcceptAmmsConfigsManagerHeadAuthority>) -> Result<()> {
+    let previous_head_authority = ctx.accounts.amms_configs_manager.head_authority().key();
+    ctx.accounts.amms_configs_manager.accept_head_authority()?;
+
+    msg!("Event: UpdateAmmsConfigsManagerHeadAuthority");
+    emit!(
+        UpdateAmmsConfigsManagerHeadAuthorityEvent{
+            head_authority: previous_head_authority,
+            new_head_authority: ctx.accounts.amms_configs_manager.head_authority().key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigsManagerHeadAuthorityEvent {
+    pub head_authority: Pubkey,
+    pub new_head_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct CancelAmmsConfigFeeRatesChange<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelAmmsConfigFeeRatesChange>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let cancelled_providers_fee_rate_basis_points = ctx.accounts.amms_config.pending_providers_fee_rate_basis_points();
+    let cancelled_protocol_fee_rate_basis_points = ctx.accounts.amms_config.pending_protocol_fee_rate_basis_points();
+    ctx.accounts.amms_config.cancel_pending_fee_rates(timestamp)?;
+
+    msg!("Event: CancelAmmsConfigFeeRatesChange");
+    emit!(
+        CancelAmmsConfigFeeRatesChangeEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            cancelled_providers_fee_rate_basis_points,
+            cancelled_protocol_fee_rate_basis_points,
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelAmmsConfigFeeRatesChangeEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub cancelled_providers_fee_rate_basis_points: u16,
+    pub cancelled_protocol_fee_rate_basis_points: u16,
+    pub timestamp: i64,
+}
\ No newline at end of file
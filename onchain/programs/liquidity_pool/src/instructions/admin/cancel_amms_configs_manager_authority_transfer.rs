@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+
+#[derive(Accounts)]
+pub struct CancelAmmsConfigsManagerAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<CancelAmmsConfigsManagerAuthorityTransfer>) -> Result<()> {
+    let cancelled_authority = ctx.accounts.amms_configs_manager.pending_authority().copied().unwrap_or_default();
+    ctx.accounts.amms_configs_manager.cancel_authority_transfer()?;
+
+    msg!("Event: CancelAmmsConfigsManagerAuthorityTransfer");
+    emit!(
+        CancelAmmsConfigsManagerAuthorityTransferEvent{
+            authority: ctx.accounts.authority.key(),
+            cancelled_authority,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelAmmsConfigsManagerAuthorityTransferEvent {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+
+#[derive(Accounts)]
+pub struct CancelAmmsConfigsManagerHeadAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = head_authority.key() == amms_configs_manager.head_authority().key()
+    )]
+    head_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+}
+pub(crate) fn handler(ctx: Context<CancelAmmsConfigsManagerHeadAuthorityTransfer>) -> Result<()> {
+    let cancelled_head_authority = ctx.accounts.amms_configs_manager.pending_head_authority().copied().unwrap_or_default();
+    ctx.accounts.amms_configs_manager.cancel_head_authority_transfer()?;
+
+    msg!("Event: CancelAmmsConfigsManagerHeadAuthorityTransfer");
+    emit!(
+        CancelAmmsConfigsManagerHeadAuthorityTransferEvent{
+            head_authority: ctx.accounts.head_authority.key(),
+            cancelled_head_authority,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelAmmsConfigsManagerHeadAuthorityTransferEvent {
+    pub head_authority: Pubkey,
+    pub cancelled_head_authority: Pubkey,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use utilities::constants::ANCHOR_DISCRIMINATOR;
+use utilities::helpers::migrate_account_layout;
+use crate::state::AmmsConfig;
+
+/// Grows an AmmsConfig created with an older layout to the current one.
+/// The appended fields start zeroed: dynamic fee, pauses, fee-on-LP mode, creator restriction
+/// and fee rates timelock are disabled, and flash loan, pool creation and referrer fees are zero.
+///
+/// Permissionless, as the payer only funds the additional rent.
+#[derive(Accounts)]
+pub struct MigrateAmmsConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Older layouts don't deserialize as `AmmsConfig`, the owner and the discriminator are checked in the handler.
+    #[account(mut)]
+    pub amms_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateAmmsConfig>) -> Result<()> {
+    migrate_account_layout::<AmmsConfig>(
+        &ctx.accounts.amms_config.to_account_info(),
+        ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE,
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program
+    )
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use utilities::constants::ANCHOR_DISCRIMINATOR;
+use utilities::helpers::migrate_account_layout;
+use crate::state::AmmsConfigsManager;
+
+/// Grows an AmmsConfigsManager created with an older layout to the current one.
+/// The appended pending authority fields start zeroed, meaning no transfer is pending.
+///
+/// Permissionless, as the payer only funds the additional rent.
+#[derive(Accounts)]
+pub struct MigrateAmmsConfigsManager<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Older layouts don't deserialize as `AmmsConfigsManager`, the owner and the discriminator are checked in the handler.
+    #[account(
+        mut,
+        seeds = [AmmsConfigsManager::SEED],
+        bump
+    )]
+    pub amms_configs_manager: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateAmmsConfigsManager>) -> Result<()> {
+    migrate_account_layout::<AmmsConfigsManager>(
+        &ctx.accounts.amms_configs_manager.to_account_info(),
+        ANCHOR_DISCRIMINATOR + AmmsConfigsManager::INIT_SPACE,
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program
+    )
+}
\ No newline at end of file
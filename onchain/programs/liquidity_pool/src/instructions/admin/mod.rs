@@ -1,16 +1,56 @@
 #![allow(ambiguous_glob_reexports)]
 pub mod initialize_amms_configs_manager;
-pub mod update_amms_configs_manager_authority;
-pub mod update_amms_configs_manager_head_authority;
+pub mod propose_amms_configs_manager_authority;
+pub mod accept_amms_configs_manager_authority;
+pub mod cancel_amms_configs_manager_authority_transfer;
+pub mod propose_amms_configs_manager_head_authority;
+pub mod accept_amms_configs_manager_head_authority;
+pub mod cancel_amms_configs_manager_head_authority_transfer;
+pub mod migrate_amms_configs_manager;
 pub mod initialize_amms_config;
 pub mod update_amms_config_fee_authority;
 pub mod update_amms_config_providers_fee_rate;
 pub mod update_amms_config_protocol_fee_rate;
+pub mod update_amms_config_fee_rates_timelock;
+pub mod cancel_amms_config_fee_rates_change;
+pub mod update_amms_config_dynamic_fee;
+pub mod update_amms_config_pause;
+pub mod update_amms_config_protocol_fee_mode;
+pub mod update_amms_config_flash_loan_fee_rate;
+pub mod update_amms_config_pool_creation_fee;
+pub mod update_amms_config_creator_restriction;
+pub mod update_amms_config_referrer_fee_share;
+pub mod migrate_amms_config;
+pub mod set_allowed_creator;
+pub mod remove_allowed_creator;
+pub mod update_cp_amm_pause;
+pub mod update_cp_amm_pair;
+pub mod ramp_stable_amm_amplification;
 
 pub use initialize_amms_configs_manager::*;
-pub use update_amms_configs_manager_authority::*;
-pub use update_amms_configs_manager_head_authority::*;
+pub use propose_amms_configs_manager_authority::*;
+pub use accept_amms_configs_manager_authority::*;
+pub use cancel_amms_configs_manager_authority_transfer::*;
+pub use propose_amms_configs_manager_head_authority::*;
+pub use accept_amms_configs_manager_head_authority::*;
+pub use cancel_amms_configs_manager_head_authority_transfer::*;
+pub use migrate_amms_configs_manager::*;
 pub use initialize_amms_config::*;
 pub use update_amms_config_fee_authority::*;
 pub use update_amms_config_providers_fee_rate::*;
-pub use update_amms_config_protocol_fee_rate::*;
\ No newline at end of file
+pub use update_amms_config_protocol_fee_rate::*;
+pub use update_amms_config_fee_rates_timelock::*;
+pub use cancel_amms_config_fee_rates_change::*;
+pub use update_amms_config_dynamic_fee::*;
+pub use update_amms_config_pause::*;
+pub use update_amms_config_protocol_fee_mode::*;
+pub use update_amms_config_flash_loan_fee_rate::*;
+pub use update_amms_config_pool_creation_fee::*;
+pub use update_amms_config_creator_restriction::*;
+pub use update_amms_config_referrer_fee_share::*;
+pub use migrate_amms_config::*;
+pub use set_allowed_creator::*;
+pub use remove_allowed_creator::*;
+pub use update_cp_amm_pause::*;
+pub use update_cp_amm_pair::*;
+pub use ramp_stable_amm_amplification::*;
\ No newline at end of file
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::AmmsConfigsManager;
 
 #[derive(Accounts)]
-pub struct UpdateAmmsConfigsManagerAuthority<'info> {
+pub struct ProposeAmmsConfigsManagerAuthority<'info> {
     #[account(
         mut,
         constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
@@ -17,16 +17,16 @@ pub struct UpdateAmmsConfigsManagerAuthority<'info> {
     /// CHECK: New authority can be arbitrary
     new_authority: UncheckedAccount<'info>,
 }
-pub(crate) fn handler(ctx: Context<UpdateAmmsConfigsManagerAuthority>) -> Result<()> {
-    ctx.accounts.amms_configs_manager.update_authority(
+pub(crate) fn handler(ctx: Context<ProposeAmmsConfigsManagerAuthority>) -> Result<()> {
+    ctx.accounts.amms_configs_manager.propose_authority(
         ctx.accounts.new_authority.key()
     );
 
-    msg!("Event: UpdateAmmsConfigsManagerAuthority");
+    msg!("Event: ProposeAmmsConfigsManagerAuthority");
     emit!(
-        UpdateAmmsConfigsManagerAuthorityEvent{
+        ProposeAmmsConfigsManagerAuthorityEvent{
             authority: ctx.accounts.authority.key(),
-            new_authority:  ctx.accounts.amms_configs_manager.authority().key(),
+            pending_authority: ctx.accounts.new_authority.key(),
             timestamp: Clock::get()?.unix_timestamp
         }
     );
@@ -34,8 +34,8 @@ pub(crate) fn handler(ctx: Context<UpdateAmmsConfigsManagerAuthority>) -> Result
 }
 
 #[event]
-pub struct UpdateAmmsConfigsManagerAuthorityEvent {
+pub struct ProposeAmmsConfigsManagerAuthorityEvent {
     pub authority: Pubkey,
-    pub new_authority: Pubkey,
+    pub pending_authority: Pubkey,
     pub timestamp: i64,
 }
\ No newline at end of file
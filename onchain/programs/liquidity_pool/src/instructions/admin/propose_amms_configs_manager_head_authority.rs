@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::AmmsConfigsManager;
 
 #[derive(Accounts)]
-pub struct UpdateAmmsConfigsManagerHeadAuthority<'info> {
+pub struct ProposeAmmsConfigsManagerHeadAuthority<'info> {
     #[account(
         mut,
         constraint = head_authority.key() == amms_configs_manager.head_authority().key()
@@ -17,16 +17,16 @@ pub struct UpdateAmmsConfigsManagerHeadAuthority<'info> {
     /// CHECK: New head authority can be arbitrary
     new_head_authority: UncheckedAccount<'info>,
 }
-pub(crate) fn handler(ctx: Context<UpdateAmmsConfigsManagerHeadAuthority>) -> Result<()> {
-    ctx.accounts.amms_configs_manager.update_head_authority(
+pub(crate) fn handler(ctx: Context<ProposeAmmsConfigsManagerHeadAuthority>) -> Result<()> {
+    ctx.accounts.amms_configs_manager.propose_head_authority(
         ctx.accounts.new_head_authority.key()
     );
 
-    msg!("Event: UpdateAmmsConfigsManagerHeadAuthority");
+    msg!("Event: ProposeAmmsConfigsManagerHeadAuthority");
     emit!(
-        UpdateAmmsConfigsManagerHeadAuthorityEvent{
+        ProposeAmmsConfigsManagerHeadAuthorityEvent{
             head_authority: ctx.accounts.head_authority.key(),
-            new_head_authority:  ctx.accounts.amms_configs_manager.authority().key(),
+            pending_head_authority: ctx.accounts.new_head_authority.key(),
             timestamp: Clock::get()?.unix_timestamp
         }
     );
@@ -34,8 +34,8 @@ pub(crate) fn handler(ctx: Context<UpdateAmmsConfigsManagerHeadAuthority>) -> Re
 }
 
 #[event]
-pub struct UpdateAmmsConfigsManagerHeadAuthorityEvent {
+pub struct ProposeAmmsConfigsManagerHeadAuthorityEvent {
     pub head_authority: Pubkey,
-    pub new_head_authority: Pubkey,
+    pub pending_head_authority: Pubkey,
     pub timestamp: i64,
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+use crate::state::stable_amm::StableAmm;
+
+#[derive(Accounts)]
+pub struct RampStableAmmAmplification<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [StableAmm::SEED, stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.bump()
+    )]
+    stable_amm: Box<Account<'info, StableAmm>>,
+}
+
+pub(crate) fn handler(ctx: Context<RampStableAmmAmplification>, target_amplification: u64, ramp_end_timestamp: i64) -> Result<()> {
+    ctx.accounts.stable_amm.ramp_amplification(target_amplification, Clock::get()?.unix_timestamp, ramp_end_timestamp)?;
+    let stable_amm = &ctx.accounts.stable_amm;
+
+    msg!("Event: RampStableAmmAmplification");
+    emit!(
+        RampStableAmmAmplificationEvent {
+            authority: ctx.accounts.authority.key(),
+            stable_amm: stable_amm.key(),
+            initial_amplification: stable_amm.initial_amplification(),
+            target_amplification: stable_amm.target_amplification(),
+            ramp_start_timestamp: stable_amm.ramp_start_timestamp(),
+            ramp_end_timestamp: stable_amm.ramp_end_timestamp(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct RampStableAmmAmplificationEvent {
+    pub authority: Pubkey,
+    pub stable_amm: Pubkey,
+    pub initial_amplification: u64,
+    pub target_amplification: u64,
+    pub ramp_start_timestamp: i64,
+    pub ramp_end_timestamp: i64,
+    pub timestamp: i64,
+}
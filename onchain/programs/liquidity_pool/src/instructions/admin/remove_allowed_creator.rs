@@ -0,0 +1,51 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AllowedCreator, AmmsConfig, AmmsConfigsManager};
+
+/// Removes the creator from the allow-list of the `AmmsConfig`, returning the rent to the authority.
+#[derive(Accounts)]
+pub struct RemoveAllowedCreator<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [AllowedCreator::SEED, amms_config.key().as_ref(), allowed_creator.creator().as_ref()],
+        bump = allowed_creator.bump()
+    )]
+    allowed_creator: Account<'info, AllowedCreator>,
+}
+
+pub(crate) fn handler(ctx: Context<RemoveAllowedCreator>) -> Result<()> {
+    msg!("Event: RemoveAllowedCreator");
+    emit!(
+        RemoveAllowedCreatorEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            creator: ctx.accounts.allowed_creator.creator().key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct RemoveAllowedCreatorEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
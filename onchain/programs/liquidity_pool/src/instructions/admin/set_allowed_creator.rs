@@ -0,0 +1,65 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AllowedCreator, AmmsConfig, AmmsConfigsManager};
+
+/// Adds the creator to the allow-list of the `AmmsConfig`, or updates its pools cap.
+#[derive(Accounts)]
+pub struct SetAllowedCreator<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+    /// CHECK: Any account can be allowed to create pools
+    creator: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AllowedCreator::INIT_SPACE,
+        seeds = [AllowedCreator::SEED, amms_config.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    allowed_creator: Account<'info, AllowedCreator>,
+    system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<SetAllowedCreator>, max_pools: u64) -> Result<()> {
+    let allowed_creator = &mut ctx.accounts.allowed_creator;
+    if !allowed_creator.is_initialized() {
+        allowed_creator.initialize(ctx.accounts.amms_config.key(), ctx.accounts.creator.key(), ctx.bumps.allowed_creator);
+    }
+    allowed_creator.update_max_pools(max_pools);
+
+    msg!("Event: SetAllowedCreator");
+    emit!(
+        SetAllowedCreatorEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            creator: ctx.accounts.creator.key(),
+            max_pools,
+            pools_count: allowed_creator.pools_count(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct SetAllowedCreatorEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub creator: Pubkey,
+    pub max_pools: u64,
+    pub pools_count: u64,
+    pub timestamp: i64,
+}
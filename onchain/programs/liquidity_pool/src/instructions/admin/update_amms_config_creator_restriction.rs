@@ -0,0 +1,47 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+/// Restricts pool creation with the `AmmsConfig` to the `AllowedCreator` allow-list, or lifts the restriction.
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigCreatorRestriction<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigCreatorRestriction>, creator_restricted: bool) -> Result<()> {
+    ctx.accounts.amms_config.update_creator_restriction(creator_restricted);
+
+    msg!("Event: UpdateAmmsConfigCreatorRestriction");
+    emit!(
+        UpdateAmmsConfigCreatorRestrictionEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            creator_restricted,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigCreatorRestrictionEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub creator_restricted: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigDynamicFee<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigDynamicFee>, dynamic_fee_enabled: bool, min_providers_fee_rate_basis_points: u16, max_providers_fee_rate_basis_points: u16) -> Result<()> {
+    ctx.accounts.amms_config.update_dynamic_fee(dynamic_fee_enabled, min_providers_fee_rate_basis_points, max_providers_fee_rate_basis_points, Clock::get()?.unix_timestamp)?;
+
+    msg!("Event: UpdateAmmsConfigDynamicFee");
+    emit!(
+        UpdateAmmsConfigDynamicFeeEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            dynamic_fee_enabled: ctx.accounts.amms_config.dynamic_fee_enabled(),
+            min_providers_fee_rate_basis_points: ctx.accounts.amms_config.min_providers_fee_rate_basis_points(),
+            max_providers_fee_rate_basis_points: ctx.accounts.amms_config.max_providers_fee_rate_basis_points(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigDynamicFeeEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub dynamic_fee_enabled: bool,
+    pub min_providers_fee_rate_basis_points: u16,
+    pub max_providers_fee_rate_basis_points: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigFeeRatesTimelock<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigFeeRatesTimelock>, new_fee_rates_timelock_duration: i64) -> Result<()> {
+    ctx.accounts.amms_config.update_fee_rates_timelock(new_fee_rates_timelock_duration)?;
+
+    msg!("Event: UpdateAmmsConfigFeeRatesTimelock");
+    emit!(
+        UpdateAmmsConfigFeeRatesTimelockEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            new_fee_rates_timelock_duration: ctx.accounts.amms_config.fee_rates_timelock_duration(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigFeeRatesTimelockEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub new_fee_rates_timelock_duration: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleAmmsConfigFeeRatesChangeEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub pending_providers_fee_rate_basis_points: u16,
+    pub pending_protocol_fee_rate_basis_points: u16,
+    pub effective_timestamp: i64,
+    pub timestamp: i64,
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigFlashLoanFeeRate<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigFlashLoanFeeRate>, new_flash_loan_fee_rate_basis_points: u16) -> Result<()> {
+    ctx.accounts.amms_config.update_flash_loan_fee_rate(new_flash_loan_fee_rate_basis_points)?;
+
+    msg!("Event: UpdateAmmsConfigFlashLoanFeeRate");
+    emit!(
+        UpdateAmmsConfigFlashLoanFeeRateEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            new_flash_loan_fee_rate_basis_points: ctx.accounts.amms_config.flash_loan_fee_rate_basis_points(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigFlashLoanFeeRateEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub new_flash_loan_fee_rate_basis_points: u16,
+    pub timestamp: i64,
+}
\ No newline at end of file
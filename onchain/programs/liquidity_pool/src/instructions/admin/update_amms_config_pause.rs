@@ -0,0 +1,48 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigPause<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigPause>, swaps_paused: bool, provides_paused: bool) -> Result<()> {
+    ctx.accounts.amms_config.update_pause(swaps_paused, provides_paused);
+
+    msg!("Event: UpdateAmmsConfigPause");
+    emit!(
+        UpdateAmmsConfigPauseEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            swaps_paused: ctx.accounts.amms_config.swaps_paused(),
+            provides_paused: ctx.accounts.amms_config.provides_paused(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigPauseEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub swaps_paused: bool,
+    pub provides_paused: bool,
+    pub timestamp: i64,
+}
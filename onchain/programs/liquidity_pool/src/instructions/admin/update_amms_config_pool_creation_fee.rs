@@ -0,0 +1,54 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+/// Updates the pool creation fee of the `AmmsConfig`.
+///
+/// The fee is paid in tokens of `pool_creation_fee_mint` when it is provided, otherwise in lamports.
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigPoolCreationFee<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+    pool_creation_fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigPoolCreationFee>, pool_creation_fee_amount: u64) -> Result<()> {
+    let pool_creation_fee_mint = ctx.accounts.pool_creation_fee_mint.as_ref().map(|mint| mint.key());
+    ctx.accounts.amms_config.update_pool_creation_fee(pool_creation_fee_amount, pool_creation_fee_mint);
+
+    msg!("Event: UpdateAmmsConfigPoolCreationFee");
+    emit!(
+        UpdateAmmsConfigPoolCreationFeeEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            pool_creation_fee_amount,
+            pool_creation_fee_mint,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigPoolCreationFeeEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub pool_creation_fee_amount: u64,
+    pub pool_creation_fee_mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager};
+
+#[derive(Accounts)]
+pub struct UpdateAmmsConfigProtocolFeeMode<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateAmmsConfigProtocolFeeMode>, protocol_fee_on_lp_enabled: bool) -> Result<()> {
+    ctx.accounts.amms_config.update_protocol_fee_mode(protocol_fee_on_lp_enabled);
+
+    msg!("Event: UpdateAmmsConfigProtocolFeeMode");
+    emit!(
+        UpdateAmmsConfigProtocolFeeModeEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            protocol_fee_on_lp_enabled: ctx.accounts.amms_config.protocol_fee_on_lp_enabled(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateAmmsConfigProtocolFeeModeEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub protocol_fee_on_lp_enabled: bool,
+    pub timestamp: i64,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::Accounts;
 use anchor_lang::prelude::*;
 use crate::state::{AmmsConfig, AmmsConfigsManager};
+use crate::instructions::ScheduleAmmsConfigFeeRatesChangeEvent;
 
 #[derive(Accounts)]
 pub struct UpdateAmmsConfigProvidersFeeRate<'info> {
@@ -23,7 +24,23 @@ pub struct UpdateAmmsConfigProvidersFeeRate<'info> {
 }
 
 pub(crate) fn handler(ctx: Context<UpdateAmmsConfigProvidersFeeRate>, new_providers_fee_rate_basis_points: u16) -> Result<()> {
-    ctx.accounts.amms_config.update_providers_fee_rate(new_providers_fee_rate_basis_points)?;
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.amms_config.update_providers_fee_rate(new_providers_fee_rate_basis_points, timestamp)?;
+
+    if ctx.accounts.amms_config.fee_rates_timelock_duration() > 0 {
+        msg!("Event: ScheduleAmmsConfigFeeRatesChange");
+        emit!(
+            ScheduleAmmsConfigFeeRatesChangeEvent {
+                authority: ctx.accounts.authority.key(),
+                amms_config: ctx.accounts.amms_config.key(),
+                pending_providers_fee_rate_basis_points: ctx.accounts.amms_config.pending_providers_fee_rate_basis_points(),
+                pending_protocol_fee_rate_basis_points: ctx.accounts.amms_config.pending_protocol_fee_rate_basis_points(),
+                effective_timestamp: ctx.accounts.amms_config.pending_fee_rates_effective_timestamp().unwrap_or_default(),
+                timestamp
+            }
+        );
+        return Ok(());
+    }
 
     msg!("Event: UpdateAmmsConfigProvidersFeeRate");
     emit!(
@@ -31,7 +48,7 @@ pub(crate) fn handler(ctx: Context<UpdateAmmsConfigProvidersFeeRate>, new_provid
             authority: ctx.accounts.authority.key(),
             amms_config: ctx.accounts.amms_config.key(),
             new_providers_fee_rate_basis_points: ctx.accounts.amms_config.providers_fee_rate_basis_points(),
-            timestamp: Clock::get()?.unix_timestamp
+            timestamp
         }
     );
     Ok(())
@@ -0,0 +1,83 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::{AmmsConfig, AmmsConfigsManager, cp_amm::{CpAmm, CpAmmPair}};
+use crate::error::ErrorCode;
+
+/// Makes the `CpAmm` the canonical pool of its pair in the pair registry, or removes it from there.
+///
+/// Covers pools created before the registry and lets the authority replace abandoned canonical pools.
+#[derive(Accounts)]
+pub struct UpdateCpAmmPair<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    amms_config: Account<'info, AmmsConfig>,
+    #[account(
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CpAmmPair::INIT_SPACE,
+        seeds = [
+            CpAmmPair::SEED,
+            amms_config.key().as_ref(),
+            CpAmmPair::sort_mints(*cp_amm.base_mint(), *cp_amm.quote_mint()).0.as_ref(),
+            CpAmmPair::sort_mints(*cp_amm.base_mint(), *cp_amm.quote_mint()).1.as_ref()
+        ],
+        bump
+    )]
+    cp_amm_pair: Box<Account<'info, CpAmmPair>>,
+    system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateCpAmmPair>, is_canonical: bool) -> Result<()> {
+    let cp_amm = &ctx.accounts.cp_amm;
+    let cp_amm_pair = &mut ctx.accounts.cp_amm_pair;
+    if !cp_amm_pair.is_initialized() {
+        cp_amm_pair.initialize(ctx.accounts.amms_config.key(), *cp_amm.base_mint(), *cp_amm.quote_mint(), ctx.bumps.cp_amm_pair);
+    }
+    if is_canonical {
+        cp_amm_pair.update_cp_amm(Some(cp_amm.key()));
+    } else {
+        require!(cp_amm_pair.cp_amm() == Some(&cp_amm.key()), ErrorCode::CpAmmIsNotCanonical);
+        cp_amm_pair.update_cp_amm(None);
+    }
+
+    msg!("Event: UpdateCpAmmPair");
+    emit!(
+        UpdateCpAmmPairEvent {
+            authority: ctx.accounts.authority.key(),
+            amms_config: ctx.accounts.amms_config.key(),
+            cp_amm_pair: cp_amm_pair.key(),
+            cp_amm: cp_amm.key(),
+            is_canonical,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateCpAmmPairEvent {
+    pub authority: Pubkey,
+    pub amms_config: Pubkey,
+    pub cp_amm_pair: Pubkey,
+    pub cp_amm: Pubkey,
+    pub is_canonical: bool,
+    pub timestamp: i64,
+}
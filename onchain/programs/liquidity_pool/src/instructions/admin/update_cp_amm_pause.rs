@@ -0,0 +1,52 @@
+use anchor_lang::Accounts;
+use anchor_lang::prelude::*;
+use crate::state::AmmsConfigsManager;
+use crate::state::cp_amm::CpAmm;
+
+#[derive(Accounts)]
+pub struct UpdateCpAmmPause<'info> {
+    #[account(
+        mut,
+        constraint = (authority.key() == amms_configs_manager.authority().key() || authority.key() == amms_configs_manager.head_authority().key())
+    )]
+    authority: Signer<'info>,
+    #[account(
+        seeds = [AmmsConfigsManager::SEED],
+        bump = amms_configs_manager.bump()
+    )]
+    amms_configs_manager: Account<'info, AmmsConfigsManager>,
+    #[account(
+        mut,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    cp_amm: Box<Account<'info, CpAmm>>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateCpAmmPause>, swaps_paused: bool, provides_paused: bool) -> Result<()> {
+    ctx.accounts.cp_amm.update_pause(swaps_paused, provides_paused);
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: UpdateCpAmmPause");
+    emit!(
+        UpdateCpAmmPauseEvent {
+            authority: ctx.accounts.authority.key(),
+            cp_amm: cp_amm.key(),
+            amms_config: cp_amm.amms_config().key(),
+            swaps_paused: cp_amm.swaps_paused(),
+            provides_paused: cp_amm.provides_paused(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct UpdateCpAmmPauseEvent {
+    pub authority: Pubkey,
+    pub cp_amm: Pubkey,
+    pub amms_config: Pubkey,
+    pub swaps_paused: bool,
+    pub provides_paused: bool,
+    pub timestamp: i64,
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{close_account, get_mint_extension_data, CloseAccount, Mint, TokenAccount, TokenInterface};
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::cp_amm::{CpAmm, CpAmmLimitOrder};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Cancels a resting limit order, returning the escrowed tokens to the owner
+/// and closing the order with its associated token account.
+#[derive(Accounts)]
+pub struct CancelCpAmmLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = input_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = input_token_program
+    )]
+    pub owner_input_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = input_mint.key() == if cp_amm_limit_order.is_in_out() { cp_amm.base_mint().key() } else { cp_amm.quote_mint().key() },
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = cp_amm.key() == cp_amm_limit_order.cp_amm().key(),
+        seeds = [CpAmmLimitOrder::SEED, cp_amm.key().as_ref(), owner.key().as_ref(), cp_amm_limit_order.id().to_le_bytes().as_ref()],
+        bump = cp_amm_limit_order.bump()
+    )]
+    pub cp_amm_limit_order: Box<Account<'info, CpAmmLimitOrder>>,
+
+    #[account(
+        mut,
+        associated_token::mint = input_mint,
+        associated_token::authority = cp_amm_limit_order,
+        associated_token::token_program = input_token_program
+    )]
+    pub cp_amm_limit_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelCpAmmLimitOrder<'info>>) -> Result<()> {
+    let returned_amount = ctx.accounts.cp_amm_limit_order_vault.amount;
+    let cp_amm_limit_order_seeds = ctx.accounts.cp_amm_limit_order.seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&cp_amm_limit_order_seeds];
+
+    if returned_amount > 0 {
+        ctx.accounts.get_return_transfer_instruction(returned_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(signer_seeds))?;
+    }
+    close_cp_amm_limit_order_vault(
+        &ctx.accounts.input_mint,
+        ctx.accounts.cp_amm_limit_order_vault.to_account_info(),
+        ctx.accounts.cp_amm_limit_order.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        signer_seeds
+    )?;
+    let cp_amm_limit_order = &ctx.accounts.cp_amm_limit_order;
+
+    msg!("Event: CancelCpAmmLimitOrder");
+    emit!(
+        CancelCpAmmLimitOrderEvent{
+            owner: ctx.accounts.owner.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            cp_amm_limit_order: cp_amm_limit_order.key(),
+            id: cp_amm_limit_order.id(),
+            returned_amount,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CancelCpAmmLimitOrderEvent{
+    pub owner: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_limit_order: Pubkey,
+    pub id: u64,
+    pub returned_amount: u64,
+    pub timestamp: i64
+}
+
+impl<'info> CancelCpAmmLimitOrder<'info> {
+    #[inline(never)]
+    fn get_return_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.input_mint,
+            &self.cp_amm_limit_order_vault,
+            self.cp_amm_limit_order.to_account_info(),
+            &self.owner_input_account,
+            &self.input_token_program
+        )
+    }
+}
+
+/// Closes the emptied associated token account of a limit order, sending its rent to `destination`.
+///
+/// Accounts of mints with a transfer fee are left open, as withheld fees would make the closing fail.
+pub(crate) fn close_cp_amm_limit_order_vault<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    cp_amm_limit_order_vault: AccountInfo<'info>,
+    cp_amm_limit_order: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if get_mint_extension_data::<TransferFeeConfig>(&mint.to_account_info()).is_ok() {
+        return Ok(());
+    }
+    close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: cp_amm_limit_order_vault,
+            destination,
+            authority: cp_amm_limit_order,
+        },
+        signer_seeds
+    ))
+}
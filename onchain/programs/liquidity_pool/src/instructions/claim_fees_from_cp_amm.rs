@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::CpAmm;
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Collects the requested part of the protocol fees from a CpAmm into arbitrary token accounts
+/// of the fee authority's choice.
+#[derive(Accounts)]
+pub struct ClaimFeesFromCpAmm<'info> {
+    pub fee_authority: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = destination_base_account.mint == base_mint.key()
+    )]
+    pub destination_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = destination_quote_account.mint == quote_mint.key()
+    )]
+    pub destination_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Account<'info, AmmsConfig>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFeesFromCpAmm<'info>>, base_fees: u64, quote_fees: u64) -> Result<()> {
+    let collect_fees_payload = ctx.accounts.cp_amm.get_partial_collect_fees_payload(base_fees, quote_fees)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    if base_fees > 0{
+        ctx.accounts.get_claim_base_fees_transfer_instruction(base_fees)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+    if quote_fees > 0{
+        ctx.accounts.get_claim_quote_fees_transfer_instruction(quote_fees)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+
+    ctx.accounts.cp_amm.collect_fees(collect_fees_payload);
+
+    msg!("Event: ClaimFeesFromCpAmm");
+    emit!(
+        ClaimFeesFromCpAmmEvent{
+            signer: ctx.accounts.fee_authority.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            fee_authority: ctx.accounts.fee_authority.key(),
+            destination_base_account: ctx.accounts.destination_base_account.key(),
+            destination_quote_account: ctx.accounts.destination_quote_account.key(),
+            withdrawn_protocol_base_fees: base_fees,
+            withdrawn_protocol_quote_fees: quote_fees,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+/// Shares the layout of `CollectFeesFromCpAmmEvent`, with the fee authority accounts replaced by the destination accounts.
+#[event]
+pub struct ClaimFeesFromCpAmmEvent{
+    pub signer: Pubkey,
+    pub cp_amm: Pubkey,
+    pub fee_authority: Pubkey,
+    pub destination_base_account: Pubkey,
+    pub destination_quote_account: Pubkey,
+    pub withdrawn_protocol_base_fees: u64,
+    pub withdrawn_protocol_quote_fees: u64,
+    pub timestamp: i64
+}
+
+impl<'info> ClaimFeesFromCpAmm<'info> {
+    fn get_claim_base_fees_transfer_instruction(&self, base_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_fees,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_claim_quote_fees_transfer_instruction(&self, quote_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_fees,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
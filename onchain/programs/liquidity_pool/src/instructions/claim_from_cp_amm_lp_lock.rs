@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::cp_amm::{CpAmm, CpAmmLpLock};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Transfers the LP tokens unlocked so far by a `CpAmmLpLock` to its owner.
+#[derive(Accounts)]
+pub struct ClaimFromCpAmmLpLock<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = lp_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = lp_token_program
+    )]
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmmLpLock::SEED, cp_amm.key().as_ref(), owner.key().as_ref()],
+        bump = cp_amm_lp_lock.bump()
+    )]
+    pub cp_amm_lp_lock: Box<Account<'info, CpAmmLpLock>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = cp_amm_lp_lock,
+        associated_token::token_program = lp_token_program
+    )]
+    pub cp_amm_lp_lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFromCpAmmLpLock<'info>>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let lp_tokens = ctx.accounts.cp_amm_lp_lock.get_claim_payload(timestamp)?;
+
+    let claim_instruction = Box::new(ctx.accounts.get_claim_transfer_instruction(lp_tokens)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let cp_amm_lp_lock_seeds = ctx.accounts.cp_amm_lp_lock.seeds();
+    claim_instruction.execute(Some(&[&cp_amm_lp_lock_seeds]))?;
+
+    ctx.accounts.cp_amm_lp_lock.claim(lp_tokens);
+    let cp_amm_lp_lock = &ctx.accounts.cp_amm_lp_lock;
+
+    msg!("Event: ClaimFromCpAmmLpLock");
+    emit!(
+        ClaimFromCpAmmLpLockEvent{
+            owner: ctx.accounts.owner.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            cp_amm_lp_lock: cp_amm_lp_lock.key(),
+            lp_tokens,
+            claimed_lp_tokens: cp_amm_lp_lock.claimed_lp_tokens(),
+            locked_lp_tokens: cp_amm_lp_lock.locked_lp_tokens(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct ClaimFromCpAmmLpLockEvent{
+    pub owner: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_lp_lock: Pubkey,
+    pub lp_tokens: u64,
+    pub claimed_lp_tokens: u64,
+    pub locked_lp_tokens: u64,
+    pub timestamp: i64
+}
+
+impl<'info> ClaimFromCpAmmLpLock<'info> {
+    #[inline(never)]
+    fn get_claim_transfer_instruction(&self, lp_tokens: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            lp_tokens,
+            &self.lp_mint,
+            &self.cp_amm_lp_lock_vault,
+            self.cp_amm_lp_lock.to_account_info(),
+            &self.owner_lp_account,
+            &self.lp_token_program
+        )
+    }
+}
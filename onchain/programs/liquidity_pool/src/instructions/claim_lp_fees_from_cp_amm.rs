@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::CpAmm;
+use crate::state::cp_amm::CpAmmCore;
+use utilities::token_instructions::MintTokensInstructions;
+
+/// Mints the requested part of the protocol LP tokens, accrued in the `AmmsConfig` fee-on-LP mode,
+/// into an arbitrary LP token account of the fee authority's choice.
+#[derive(Accounts)]
+pub struct ClaimLpFeesFromCpAmm<'info> {
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = destination_lp_account.mint == lp_mint.key()
+    )]
+    pub destination_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Account<'info, AmmsConfig>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimLpFeesFromCpAmm>, lp_tokens: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let protocol_fee_on_lp_enabled = ctx.accounts.amms_config.protocol_fee_on_lp_enabled();
+    let providers_fee_rate_basis_points = ctx.accounts.amms_config.effective_providers_fee_rate_basis_points(ctx.accounts.cp_amm.volatility_accumulator(timestamp), timestamp);
+    let protocol_fee_rate_basis_points = ctx.accounts.amms_config.current_protocol_fee_rate_basis_points(timestamp);
+    ctx.accounts.cp_amm.accrue_protocol_lp_fees(protocol_fee_on_lp_enabled, providers_fee_rate_basis_points, protocol_fee_rate_basis_points)?;
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(protocol_fee_on_lp_enabled);
+
+    let protocol_lp_tokens_to_redeem = ctx.accounts.cp_amm.get_claim_lp_fees_payload(lp_tokens)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+    ctx.accounts.get_lp_fees_mint_instruction(lp_tokens).execute(Some(mint_instruction_seeds))?;
+
+    ctx.accounts.cp_amm.claim_lp_fees(protocol_lp_tokens_to_redeem);
+
+    msg!("Event: ClaimLpFeesFromCpAmm");
+    emit!(
+        ClaimLpFeesFromCpAmmEvent{
+            fee_authority: ctx.accounts.fee_authority.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            destination_lp_account: ctx.accounts.destination_lp_account.key(),
+            lp_tokens_minted: lp_tokens,
+            protocol_lp_tokens_to_redeem,
+            lp_tokens_supply: ctx.accounts.cp_amm.lp_tokens_supply(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct ClaimLpFeesFromCpAmmEvent{
+    pub fee_authority: Pubkey,
+    pub cp_amm: Pubkey,
+    pub destination_lp_account: Pubkey,
+    pub lp_tokens_minted: u64,
+    pub protocol_lp_tokens_to_redeem: u64,
+    pub lp_tokens_supply: u64,
+    pub timestamp: i64
+}
+
+impl<'info> ClaimLpFeesFromCpAmm<'info> {
+    fn get_lp_fees_mint_instruction(&self, lp_tokens: u64) -> MintTokensInstructions<'_, '_, '_, 'info> {
+        MintTokensInstructions::new(
+            lp_tokens,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.destination_lp_account.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+}
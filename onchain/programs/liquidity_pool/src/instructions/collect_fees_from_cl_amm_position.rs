@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::cl_amm::{ClAmm, ClAmmCore, ClAmmPosition, ClAmmTickArray};
+use utilities::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct CollectFeesFromClAmmPosition<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = cl_amm.is_initialized(),
+        constraint = base_mint.key() == cl_amm.base_mint().key(),
+        constraint = quote_mint.key() == cl_amm.quote_mint().key(),
+        constraint = cl_amm_base_vault.key() == cl_amm.base_vault().key(),
+        constraint = cl_amm_quote_vault.key() == cl_amm.quote_vault().key(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        mut,
+        constraint = position.cl_amm().key() == cl_amm.key(),
+        constraint = position.owner().key() == signer.key(),
+    )]
+    pub position: Box<Account<'info, ClAmmPosition>>,
+
+    #[account(
+        constraint = tick_array_lower.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_lower: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        constraint = tick_array_upper.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_upper: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.base_mint().as_ref()],
+        bump = cl_amm.base_vault_bump()
+    )]
+    pub cl_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.quote_mint().as_ref()],
+        bump = cl_amm.quote_vault_bump()
+    )]
+    pub cl_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromClAmmPosition<'info>>) -> Result<()> {
+    let tick_spacing = ctx.accounts.cl_amm.tick_spacing();
+    let tick_lower_state = *ctx.accounts.tick_array_lower.tick(ctx.accounts.position.tick_lower(), tick_spacing)?;
+    let tick_upper_state = *ctx.accounts.tick_array_upper.tick(ctx.accounts.position.tick_upper(), tick_spacing)?;
+
+    let collect_payload = ctx.accounts.cl_amm.get_collect_position_fees_payload(&ctx.accounts.position, &tick_lower_state, &tick_upper_state)?;
+    let (base_fees, quote_fees) = (collect_payload.base_fees(), collect_payload.quote_fees());
+
+    let cl_amm_seeds = ctx.accounts.cl_amm.seeds();
+    let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&cl_amm_seeds];
+    if base_fees > 0 {
+        ctx.accounts.get_collect_base_fees_transfer_instruction(base_fees)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+    if quote_fees > 0 {
+        ctx.accounts.get_collect_quote_fees_transfer_instruction(quote_fees)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+
+    ctx.accounts.position.update(collect_payload.position());
+    ctx.accounts.cl_amm.collect_position_fees(collect_payload);
+
+    msg!("Event: CollectFeesFromClAmmPosition");
+    emit!(
+        CollectFeesFromClAmmPositionEvent{
+            owner: ctx.accounts.signer.key(),
+            cl_amm: ctx.accounts.cl_amm.key(),
+            position: ctx.accounts.position.key(),
+            collected_base_fees: base_fees,
+            collected_quote_fees: quote_fees,
+            base_liquidity: ctx.accounts.cl_amm.base_liquidity(),
+            quote_liquidity: ctx.accounts.cl_amm.quote_liquidity(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CollectFeesFromClAmmPositionEvent{
+    pub owner: Pubkey,
+    pub cl_amm: Pubkey,
+    pub position: Pubkey,
+    pub collected_base_fees: u64,
+    pub collected_quote_fees: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub timestamp: i64
+}
+
+impl<'info> CollectFeesFromClAmmPosition<'info> {
+    fn get_collect_base_fees_transfer_instruction(&self, base_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_fees,
+            &self.base_mint,
+            &self.cl_amm_base_vault,
+            self.cl_amm.to_account_info(),
+            &self.signer_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_collect_quote_fees_transfer_instruction(&self, quote_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_fees,
+            &self.quote_mint,
+            &self.cl_amm_quote_vault,
+            self.cl_amm.to_account_info(),
+            &self.signer_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
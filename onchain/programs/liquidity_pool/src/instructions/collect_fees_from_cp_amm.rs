@@ -73,7 +73,7 @@ pub struct CollectFeesFromCpAmm<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub(crate) fn handler(ctx: Context<CollectFeesFromCpAmm>) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromCpAmm<'info>>) -> Result<()> {
     let collect_fees_payload = ctx.accounts.cp_amm.get_collect_fees_payload()?;
     let (protocol_base_fees_to_redeem, protocol_quote_fees_to_redeem) = (collect_fees_payload.protocol_base_fees_to_redeem(), collect_fees_payload.protocol_quote_fees_to_redeem());
 
@@ -81,10 +81,10 @@ pub(crate) fn handler(ctx: Context<CollectFeesFromCpAmm>) -> Result<()> {
     let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
 
     if protocol_base_fees_to_redeem > 0{
-        ctx.accounts.get_collect_base_fees_transfer_instruction(protocol_base_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+        ctx.accounts.get_collect_base_fees_transfer_instruction(protocol_base_fees_to_redeem)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
     }
     if protocol_quote_fees_to_redeem > 0{
-        ctx.accounts.get_collect_quote_fees_transfer_instruction(protocol_quote_fees_to_redeem)?.execute(Some(collect_fees_instruction_seeds))?;
+        ctx.accounts.get_collect_quote_fees_transfer_instruction(protocol_quote_fees_to_redeem)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
     }
 
     ctx.accounts.cp_amm.collect_fees(collect_fees_payload);
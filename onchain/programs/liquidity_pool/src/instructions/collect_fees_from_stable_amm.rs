@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::AmmsConfig;
+use crate::state::stable_amm::StableAmm;
+use utilities::token_instructions::TransferTokensInstruction;
+
+#[derive(Accounts)]
+pub struct CollectFeesFromStableAmm<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Amms config's fee authority can be arbitrary type
+    pub fee_authority: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = fee_authority,
+        associated_token::token_program = base_token_program
+    )]
+    pub fee_authority_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = fee_authority,
+        associated_token::token_program = quote_token_program
+    )]
+    pub fee_authority_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Account<'info, AmmsConfig>,
+
+    #[account(
+        mut,
+        constraint = stable_amm.is_launched(),
+        constraint = amms_config.key() == stable_amm.amms_config().key(),
+        constraint = base_mint.key() == stable_amm.base_mint().key(),
+        constraint = quote_mint.key() == stable_amm.quote_mint().key(),
+        constraint = stable_amm_base_vault.key() == stable_amm.base_vault().key(),
+        constraint = stable_amm_quote_vault.key() == stable_amm.quote_vault().key(),
+        seeds = [StableAmm::SEED, stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.bump()
+    )]
+    pub stable_amm: Box<Account<'info, StableAmm>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.base_mint().as_ref()],
+        bump = stable_amm.base_vault_bump()
+    )]
+    pub stable_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.quote_mint().as_ref()],
+        bump = stable_amm.quote_vault_bump()
+    )]
+    pub stable_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromStableAmm<'info>>) -> Result<()> {
+    let collect_fees_payload = ctx.accounts.stable_amm.get_collect_fees_payload()?;
+    let (protocol_base_fees_to_redeem, protocol_quote_fees_to_redeem) = (collect_fees_payload.protocol_base_fees_to_redeem(), collect_fees_payload.protocol_quote_fees_to_redeem());
+
+    let stable_amm_seeds = ctx.accounts.stable_amm.seeds();
+    let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&stable_amm_seeds];
+
+    if protocol_base_fees_to_redeem > 0{
+        ctx.accounts.get_collect_base_fees_transfer_instruction(protocol_base_fees_to_redeem)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+    if protocol_quote_fees_to_redeem > 0{
+        ctx.accounts.get_collect_quote_fees_transfer_instruction(protocol_quote_fees_to_redeem)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(collect_fees_instruction_seeds))?;
+    }
+
+    ctx.accounts.stable_amm.collect_fees(collect_fees_payload);
+
+    msg!("Event: CollectFeesFromStableAmm");
+    emit!(
+        CollectFeesFromStableAmmEvent{
+            signer: ctx.accounts.signer.key(),
+            stable_amm: ctx.accounts.stable_amm.key(),
+            fee_authority: ctx.accounts.fee_authority.key(),
+            fee_authority_base_account: ctx.accounts.fee_authority_base_account.key(),
+            fee_authority_quote_account: ctx.accounts.fee_authority_quote_account.key(),
+            withdrawn_protocol_base_fees: protocol_base_fees_to_redeem,
+            withdrawn_protocol_quote_fees: protocol_quote_fees_to_redeem,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct CollectFeesFromStableAmmEvent{
+    pub signer: Pubkey,
+    pub stable_amm: Pubkey,
+    pub fee_authority: Pubkey,
+    pub fee_authority_base_account: Pubkey,
+    pub fee_authority_quote_account: Pubkey,
+    pub withdrawn_protocol_base_fees: u64,
+    pub withdrawn_protocol_quote_fees: u64,
+    pub timestamp: i64
+}
+
+impl<'info> CollectFeesFromStableAmm<'info> {
+    fn get_collect_base_fees_transfer_instruction(&self, base_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_fees,
+            &self.base_mint,
+            &self.stable_amm_base_vault,
+            self.stable_amm.to_account_info(),
+            &self.fee_authority_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_collect_quote_fees_transfer_instruction(&self, quote_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_fees,
+            &self.quote_mint,
+            &self.stable_amm_quote_vault,
+            self.stable_amm.to_account_info(),
+            &self.fee_authority_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
+
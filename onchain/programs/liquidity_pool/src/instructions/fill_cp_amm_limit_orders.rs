@@ -0,0 +1,294 @@
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::constants::{LIMIT_ORDERS_FILL_MAX_ORDERS, LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT};
+use crate::error::ErrorCode;
+use crate::instructions::close_cp_amm_limit_order_vault;
+use crate::state::{AmmsConfig, cp_amm::{CpAmm, CpAmmLimitOrder}};
+use crate::state::cp_amm::CpAmmCore;
+use utilities::token_instructions::TransferTokensInstruction;
+use utilities::helpers::split_remaining_accounts;
+
+/// Permissionless crank filling the triggered limit orders of a `CpAmm`.
+///
+/// Every order is swapped against the pool as a whole, the result is sent to the order owner
+/// minus `CpAmmLimitOrder::CRANK_FEE_BASIS_POINTS` paid to the signer. Filled orders are closed, their rent returned to the owner.
+/// Orders whose target is not crossed, or whose result doesn't cover `min_received_amount`, are skipped.
+///
+/// Every order is described by `LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT` remaining accounts in the following order:
+/// 0. `cp_amm_limit_order` - `[writable]` Limit order to fill.
+/// 1. `cp_amm_limit_order_vault` - `[writable]` Associated token account of the order holding the escrowed tokens.
+/// 2. `owner` - `[writable]` Owner of the order, receiving the rent.
+/// 3. `owner_output_account` - `[writable]` Owner token account of the output mint.
+///
+/// The transfer hook accounts of the mints follow the orders.
+#[derive(Accounts)]
+pub struct FillCpAmmLimitOrders<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FillCpAmmLimitOrders<'info>>) -> Result<()> {
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+
+    let (orders_accounts, transfer_hook_accounts) = split_remaining_accounts::<CpAmmLimitOrder>(ctx.remaining_accounts, LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT);
+    let orders_count = orders_accounts.len() / LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT;
+    require!(
+        orders_count > 0 &&
+        orders_count <= LIMIT_ORDERS_FILL_MAX_ORDERS,
+        ErrorCode::InvalidLimitOrdersFillLength
+    );
+    let mut filled_orders_count = 0;
+
+    for order_accounts in orders_accounts.chunks(LIMIT_ORDERS_FILL_ORDER_ACCOUNTS_COUNT) {
+        let fill = Box::new(LimitOrderFill::try_from_accounts(order_accounts, ctx.accounts)?);
+        let is_in_out = fill.cp_amm_limit_order.is_in_out();
+        if !fill.cp_amm_limit_order.is_triggered(ctx.accounts.cp_amm.base_quote_ratio_sqrt()) {
+            continue;
+        }
+
+        let in_transfer_instruction = Box::new(ctx.accounts.get_order_in_transfer_instruction(&fill, is_in_out)?.with_transfer_hook_accounts(transfer_hook_accounts));
+        let swap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.effective_swap_fee_rates_basis_points(ctx.accounts.cp_amm.volatility_accumulator(timestamp), timestamp);
+        let swap_payload = ctx.accounts.cp_amm.get_routed_swap_payload(
+            swap_amount_after_fee,
+            providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points,
+            is_in_out
+        )?;
+        let amount_to_withdraw = swap_payload.amount_to_withdraw();
+        let (owner_amount, crank_fee) = CpAmmLimitOrder::split_received_amount(amount_to_withdraw)?;
+        // The minimum covers what the owner actually receives, after the output mint transfer fee
+        let owner_out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(owner_amount, &fill.owner_output_account, is_in_out)?.with_transfer_hook_accounts(transfer_hook_accounts));
+        let received_amount = owner_out_transfer_instruction.get_amount_after_fee();
+        if received_amount < fill.cp_amm_limit_order.min_received_amount() {
+            continue;
+        }
+
+        let cp_amm_limit_order_seeds = fill.cp_amm_limit_order.seeds();
+        let order_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_limit_order_seeds];
+        in_transfer_instruction.execute(Some(order_instruction_seeds))?;
+
+        let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+        let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+        owner_out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+        if crank_fee > 0 {
+            let signer_output_account = if is_in_out { &ctx.accounts.signer_quote_account } else { &ctx.accounts.signer_base_account };
+            ctx.accounts.get_out_transfer_instruction(crank_fee, signer_output_account, is_in_out)?.with_transfer_hook_accounts(transfer_hook_accounts).execute(Some(out_instruction_seeds))?;
+        }
+
+        let (input_mint, input_token_program) = if is_in_out {
+            (&ctx.accounts.base_mint, &ctx.accounts.base_token_program)
+        } else {
+            (&ctx.accounts.quote_mint, &ctx.accounts.quote_token_program)
+        };
+        close_cp_amm_limit_order_vault(
+            input_mint,
+            fill.cp_amm_limit_order_vault.to_account_info(),
+            fill.cp_amm_limit_order.to_account_info(),
+            fill.owner.clone(),
+            input_token_program.to_account_info(),
+            order_instruction_seeds
+        )?;
+
+        let cp_amm = &mut ctx.accounts.cp_amm;
+        cp_amm.update_cumulative_prices(timestamp);
+        let (previous_base_liquidity, previous_quote_liquidity) = (cp_amm.base_liquidity(), cp_amm.quote_liquidity());
+        cp_amm.swap(swap_payload);
+        cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
+
+        msg!("Event: FillCpAmmLimitOrder");
+        emit!(
+            FillCpAmmLimitOrderEvent{
+                cranker: ctx.accounts.signer.key(),
+                owner: fill.owner.key(),
+                cp_amm: cp_amm.key(),
+                cp_amm_limit_order: fill.cp_amm_limit_order.key(),
+                id: fill.cp_amm_limit_order.id(),
+                is_in_out,
+                swapped_amount: swap_amount_after_fee,
+                received_amount,
+                crank_fee,
+                base_liquidity: cp_amm.base_liquidity(),
+                quote_liquidity: cp_amm.quote_liquidity(),
+                protocol_base_fees_to_redeem: cp_amm.protocol_base_fees_to_redeem(),
+                protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem(),
+                constant_product_sqrt: cp_amm.constant_product_sqrt(),
+                base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+                timestamp
+            }
+        );
+
+        fill.cp_amm_limit_order.close(fill.owner.clone())?;
+        filled_orders_count += 1;
+    }
+
+    require!(filled_orders_count > 0, ErrorCode::NoLimitOrdersFilled);
+
+    Ok(())
+}
+#[event]
+pub struct FillCpAmmLimitOrderEvent{
+    pub cranker: Pubkey,
+    pub owner: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_limit_order: Pubkey,
+    pub id: u64,
+    pub is_in_out: bool,
+    pub swapped_amount: u64,
+    pub received_amount: u64,
+    pub crank_fee: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+
+impl<'info> FillCpAmmLimitOrders<'info> {
+    fn get_order_in_transfer_instruction<'a>(&'a self, fill: &'a LimitOrderFill<'info>, is_in_out: bool) -> Result<TransferTokensInstruction<'a, 'a, 'a, 'info>>{
+        let (mint, cp_amm_vault, token_program) = if is_in_out {
+            (&self.base_mint, &self.cp_amm_base_vault, &self.base_token_program)
+        } else {
+            (&self.quote_mint, &self.cp_amm_quote_vault, &self.quote_token_program)
+        };
+        TransferTokensInstruction::try_new(
+            fill.cp_amm_limit_order.amount(),
+            mint,
+            &fill.cp_amm_limit_order_vault,
+            fill.cp_amm_limit_order.to_account_info(),
+            cp_amm_vault,
+            token_program
+        )
+    }
+    fn get_out_transfer_instruction<'a>(&'a self, amount: u64, destination: &'a InterfaceAccount<'info, TokenAccount>, is_in_out: bool) -> Result<TransferTokensInstruction<'a, 'a, 'a, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                amount,
+                &self.quote_mint,
+                &self.cp_amm_quote_vault,
+                self.cp_amm.to_account_info(),
+                destination,
+                &self.quote_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                amount,
+                &self.base_mint,
+                &self.cp_amm_base_vault,
+                self.cp_amm.to_account_info(),
+                destination,
+                &self.base_token_program
+            )
+        }
+    }
+}
+
+/// Validated accounts of a single order of a limit orders fill.
+struct LimitOrderFill<'info>{
+    cp_amm_limit_order: Box<Account<'info, CpAmmLimitOrder>>,
+    cp_amm_limit_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    owner: AccountInfo<'info>,
+    owner_output_account: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> LimitOrderFill<'info>{
+    fn try_from_accounts(accounts: &'info [AccountInfo<'info>], fill_accounts: &FillCpAmmLimitOrders<'info>) -> Result<Self>{
+        let fill = Self{
+            cp_amm_limit_order: Box::new(Account::try_from(&accounts[0])?),
+            cp_amm_limit_order_vault: Box::new(InterfaceAccount::try_from(&accounts[1])?),
+            owner: accounts[2].clone(),
+            owner_output_account: Box::new(InterfaceAccount::try_from(&accounts[3])?),
+        };
+        fill.validate(fill_accounts)?;
+        Ok(fill)
+    }
+
+    fn validate(&self, fill_accounts: &FillCpAmmLimitOrders<'info>) -> Result<()>{
+        let cp_amm_limit_order = &self.cp_amm_limit_order;
+        let (input_mint, output_mint) = if cp_amm_limit_order.is_in_out() {
+            (fill_accounts.base_mint.key(), fill_accounts.quote_mint.key())
+        } else {
+            (fill_accounts.quote_mint.key(), fill_accounts.base_mint.key())
+        };
+        require!(cp_amm_limit_order.to_account_info().is_writable, ErrorCode::InvalidLimitOrderFillAccounts);
+        require!(*cp_amm_limit_order.cp_amm() == fill_accounts.cp_amm.key(), ErrorCode::InvalidLimitOrderFillAccounts);
+        require!(
+            self.cp_amm_limit_order_vault.owner == cp_amm_limit_order.key() && self.cp_amm_limit_order_vault.mint == input_mint,
+            ErrorCode::InvalidLimitOrderFillAccounts
+        );
+        require!(
+            self.owner.is_writable && self.owner.key() == *cp_amm_limit_order.owner(),
+            ErrorCode::InvalidLimitOrderFillAccounts
+        );
+        require!(
+            self.owner_output_account.owner == *cp_amm_limit_order.owner() && self.owner_output_account.mint == output_mint,
+            ErrorCode::InvalidLimitOrderFillAccounts
+        );
+        Ok(())
+    }
+}
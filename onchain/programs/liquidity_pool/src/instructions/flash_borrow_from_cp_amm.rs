@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::FLASH_REPAY_CP_AMM_ACCOUNT_INDEX;
+use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::error::ErrorCode;
+
+/// Flash loan from the CpAmm vaults.
+///
+/// Must be a top-level instruction followed later in the same transaction by
+/// `flash_repay_to_cp_amm` for the same pool, which is checked via the instructions sysvar.
+/// All other operations on the pool are rejected until the loan is repaid.
+#[derive(Accounts)]
+pub struct FlashBorrowFromCpAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Instructions sysvar, checked by address
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FlashBorrowFromCpAmm<'info>>, base_amount: u64, quote_amount: u64) -> Result<()> {
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+    ctx.accounts.check_repay_instruction()?;
+
+    let flash_loan_payload = ctx.accounts.cp_amm.get_flash_loan_payload(
+        base_amount,
+        quote_amount,
+        ctx.accounts.amms_config.flash_loan_fee_rate_basis_points()
+    )?;
+    let (base_fee_amount, quote_fee_amount) = (flash_loan_payload.base_fee_amount(), flash_loan_payload.quote_fee_amount());
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let borrow_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+    if base_amount > 0 {
+        ctx.accounts.get_borrow_base_transfer_instruction(base_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(borrow_instruction_seeds))?;
+    }
+    if quote_amount > 0 {
+        ctx.accounts.get_borrow_quote_transfer_instruction(quote_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(borrow_instruction_seeds))?;
+    }
+
+    ctx.accounts.cp_amm.flash_loan(flash_loan_payload);
+
+    msg!("Event: FlashBorrowFromCpAmm");
+    emit!(
+        FlashBorrowFromCpAmmEvent{
+            borrower: ctx.accounts.signer.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            base_amount,
+            quote_amount,
+            base_fee_amount,
+            quote_fee_amount,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct FlashBorrowFromCpAmmEvent{
+    pub borrower: Pubkey,
+    pub cp_amm: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_fee_amount: u64,
+    pub quote_fee_amount: u64,
+    pub timestamp: i64
+}
+
+impl<'info> FlashBorrowFromCpAmm<'info>{
+    /// Checks that this instruction is not invoked through CPI and that `flash_repay_to_cp_amm`
+    /// for the same pool follows it in the transaction.
+    fn check_repay_instruction(&self) -> Result<()> {
+        let instructions_sysvar = self.instructions_sysvar.to_account_info();
+        let current_index = load_current_index_checked(&instructions_sysvar)? as usize;
+        let current_instruction = load_instruction_at_checked(current_index, &instructions_sysvar)?;
+        require!(current_instruction.program_id == crate::ID, ErrorCode::FlashLoanCpiNotAllowed);
+
+        let mut index = current_index + 1;
+        while let Ok(instruction) = load_instruction_at_checked(index, &instructions_sysvar) {
+            if instruction.program_id == crate::ID
+                && instruction.data.get(..8) == Some(crate::instruction::FlashRepayToCpAmm::DISCRIMINATOR.as_ref())
+                && instruction.accounts.get(FLASH_REPAY_CP_AMM_ACCOUNT_INDEX).is_some_and(|account| account.pubkey == self.cp_amm.key())
+            {
+                return Ok(());
+            }
+            index += 1;
+        }
+        err!(ErrorCode::FlashLoanRepayNotFound)
+    }
+    fn get_borrow_base_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.signer_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_borrow_quote_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.signer_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::cp_amm::{CpAmm, CpAmmCore};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Repays the flash loan in progress in the CpAmm together with its fees.
+///
+/// The position of `cp_amm` in the accounts is fixed by `FLASH_REPAY_CP_AMM_ACCOUNT_INDEX`,
+/// as `flash_borrow_from_cp_amm` looks the repayment up through the instructions sysvar.
+#[derive(Accounts)]
+pub struct FlashRepayToCpAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FlashRepayToCpAmm<'info>>) -> Result<()> {
+    let cp_amm = &ctx.accounts.cp_amm;
+    let (base_fee_amount, quote_fee_amount) = (cp_amm.flash_loan_base_fee_amount(), cp_amm.flash_loan_quote_fee_amount());
+    let (base_amount_to_repay, quote_amount_to_repay) = cp_amm.flash_loan_amounts_to_repay()?;
+
+    // Transfer fees are charged on top, the vaults balance changes are checked against the owed amounts
+    let (base_vault_balance, quote_vault_balance) = (ctx.accounts.cp_amm_base_vault.amount, ctx.accounts.cp_amm_quote_vault.amount);
+    if base_amount_to_repay > 0 {
+        let base_amount_before_fee = TransferTokensInstruction::calculate_amount_before_fee(base_amount_to_repay, &ctx.accounts.base_mint)?;
+        ctx.accounts.get_repay_base_transfer_instruction(base_amount_before_fee)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(None)?;
+    }
+    if quote_amount_to_repay > 0 {
+        let quote_amount_before_fee = TransferTokensInstruction::calculate_amount_before_fee(quote_amount_to_repay, &ctx.accounts.quote_mint)?;
+        ctx.accounts.get_repay_quote_transfer_instruction(quote_amount_before_fee)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(None)?;
+    }
+    ctx.accounts.cp_amm_base_vault.reload()?;
+    ctx.accounts.cp_amm_quote_vault.reload()?;
+    let base_amount_repaid = ctx.accounts.cp_amm_base_vault.amount.saturating_sub(base_vault_balance);
+    let quote_amount_repaid = ctx.accounts.cp_amm_quote_vault.amount.saturating_sub(quote_vault_balance);
+
+    let flash_repay_payload = ctx.accounts.cp_amm.get_flash_repay_payload(base_amount_repaid, quote_amount_repaid)?;
+    ctx.accounts.cp_amm.update_cumulative_prices(Clock::get()?.unix_timestamp);
+    ctx.accounts.cp_amm.flash_repay(flash_repay_payload);
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: FlashRepayToCpAmm");
+    emit!(
+        FlashRepayToCpAmmEvent{
+            payer: ctx.accounts.signer.key(),
+            cp_amm: cp_amm.key(),
+            repaid_base_amount: base_amount_repaid,
+            repaid_quote_amount: quote_amount_repaid,
+            base_fee_amount,
+            quote_fee_amount,
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct FlashRepayToCpAmmEvent{
+    pub payer: Pubkey,
+    pub cp_amm: Pubkey,
+    pub repaid_base_amount: u64,
+    pub repaid_quote_amount: u64,
+    pub base_fee_amount: u64,
+    pub quote_fee_amount: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub timestamp: i64
+}
+
+impl<'info> FlashRepayToCpAmm<'info>{
+    fn get_repay_base_transfer_instruction(&self, base_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_amount,
+            &self.base_mint,
+            &self.signer_base_account,
+            self.signer.to_account_info(),
+            &self.cp_amm_base_vault,
+            &self.base_token_program
+        )
+    }
+    fn get_repay_quote_transfer_instruction(&self, quote_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_amount,
+            &self.quote_mint,
+            &self.signer_quote_account,
+            self.signer.to_account_info(),
+            &self.cp_amm_quote_vault,
+            &self.quote_token_program
+        )
+    }
+}
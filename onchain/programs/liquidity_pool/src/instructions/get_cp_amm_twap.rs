@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use crate::error::ErrorCode;
+use crate::state::cp_amm::{CpAmm, CpAmmObservations};
+
+/// Read-only instruction returning the `CpAmm` TWAP over at least `window` seconds.
+///
+/// The result is a borsh-serialized `Twap` passed via return data,
+/// so other programs can consume it through CPI.
+#[derive(Accounts)]
+pub struct GetCpAmmTwap<'info> {
+    #[account(
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        seeds = [CpAmmObservations::SEED, cp_amm.key().as_ref()],
+        bump = cp_amm_observations.bump()
+    )]
+    pub cp_amm_observations: Box<Account<'info, CpAmmObservations>>,
+}
+
+pub(crate) fn handler(ctx: Context<GetCpAmmTwap>, window: u32) -> Result<()> {
+    let current_observation = ctx.accounts.cp_amm.observe(Clock::get()?.unix_timestamp);
+    let start_observation = ctx.accounts.cp_amm_observations
+        .find_observation_before(current_observation.timestamp() - window as i64)
+        .ok_or(ErrorCode::TwapObservationNotFound)?;
+
+    let twap = current_observation.calculate_twap(start_observation).ok_or(ErrorCode::TwapCalculationFailed)?;
+    set_return_data(&twap.try_to_vec()?);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::error::ErrorCode;
+use crate::state::cp_amm::{CpAmmFarm, CpAmmFarmPosition};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Transfers the rewards of one reward mint earned by a `CpAmmFarmPosition` to its owner.
+#[derive(Accounts)]
+pub struct HarvestFromCpAmmFarm<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CpAmmFarm::SEED, cp_amm_farm.cp_amm().as_ref(), cp_amm_farm.authority().as_ref()],
+        bump = cp_amm_farm.bump()
+    )]
+    pub cp_amm_farm: Box<Account<'info, CpAmmFarm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmmFarmPosition::SEED, cp_amm_farm.key().as_ref(), owner.key().as_ref()],
+        bump = cp_amm_farm_position.bump()
+    )]
+    pub cp_amm_farm_position: Box<Account<'info, CpAmmFarmPosition>>,
+
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = reward_token_program
+    )]
+    pub owner_reward_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = cp_amm_farm,
+        associated_token::token_program = reward_token_program
+    )]
+    pub cp_amm_farm_reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, HarvestFromCpAmmFarm<'info>>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let reward_mint = ctx.accounts.reward_mint.key();
+
+    let cp_amm_farm = &mut ctx.accounts.cp_amm_farm;
+    let reward_index = cp_amm_farm.reward_index(&reward_mint).ok_or(ErrorCode::InvalidFarmRewardIndex)?;
+    cp_amm_farm.accrue_rewards(timestamp)?;
+    let reward_amount = ctx.accounts.cp_amm_farm_position.harvest(reward_index, &cp_amm_farm.rewards_per_token())?;
+
+    let cp_amm_farm_seeds = ctx.accounts.cp_amm_farm.seeds();
+    ctx.accounts.get_harvest_transfer_instruction(reward_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(&[&cp_amm_farm_seeds]))?;
+
+    msg!("Event: HarvestFromCpAmmFarm");
+    emit!(
+        HarvestFromCpAmmFarmEvent{
+            owner: ctx.accounts.owner.key(),
+            cp_amm_farm: ctx.accounts.cp_amm_farm.key(),
+            cp_amm_farm_position: ctx.accounts.cp_amm_farm_position.key(),
+            reward_mint,
+            reward_amount,
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct HarvestFromCpAmmFarmEvent{
+    pub owner: Pubkey,
+    pub cp_amm_farm: Pubkey,
+    pub cp_amm_farm_position: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_amount: u64,
+    pub timestamp: i64
+}
+
+impl<'info> HarvestFromCpAmmFarm<'info> {
+    #[inline(never)]
+    fn get_harvest_transfer_instruction(&self, reward_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            reward_amount,
+            &self.reward_mint,
+            &self.cp_amm_farm_reward_vault,
+            self.cp_amm_farm.to_account_info(),
+            &self.owner_reward_account,
+            &self.reward_token_program
+        )
+    }
+}
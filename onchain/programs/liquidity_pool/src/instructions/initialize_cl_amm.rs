@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::TokenInterface;
+use utilities::math::Q64_128;
+use crate::state::{AllowedCreator, AmmsConfig, cl_amm::{ClAmm, ClAmmCore}};
+use utilities::system_instructions::TransferLamportsInstruction;
+use utilities::token_instructions::TransferTokensInstruction;
+use utilities::token_accounts_instructions::CreatePdaTokenAccountInstruction;
+use utilities::helpers::validate_tradable_mint;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(tick_spacing: u16)]
+pub struct InitializeClAmm<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Amms config's fee authority can be arbitrary type
+    pub fee_authority: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        constraint = base_mint.key() != quote_mint.key()
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ClAmm::INIT_SPACE,
+        seeds = [ClAmm::SEED, amms_config.key().as_ref(), base_mint.key().as_ref(), quote_mint.key().as_ref(), tick_spacing.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), base_mint.key().as_ref()],
+        bump
+    )]
+    pub cl_amm_base_vault: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub cl_amm_quote_vault: AccountInfo<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [AllowedCreator::SEED, amms_config.key().as_ref(), signer.key().as_ref()],
+        bump = allowed_creator.bump()
+    )]
+    pub allowed_creator: Option<Box<Account<'info, AllowedCreator>>>,
+    pub pool_creation_fee_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
+    #[account(mut)]
+    pub signer_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    #[account(mut)]
+    pub fee_authority_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    pub pool_creation_fee_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeClAmm<'info>>, tick_spacing: u16, initial_sqrt_price: Q64_128) -> Result<()> {
+    ctx.accounts.validate_base_mint()?;
+    ctx.accounts.validate_quote_mint()?;
+    {
+        let cl_amm_key = ctx.accounts.cl_amm.key();
+        {
+            let base_mint_key = ctx.accounts.base_mint.key();
+            let create_cl_amm_base_vault = Box::new(ctx.accounts.get_create_cl_amm_base_vault_instruction()?);
+            let cl_amm_base_vault_seeds = [ClAmm::VAULT_SEED, cl_amm_key.as_ref(), base_mint_key.as_ref(), &[ctx.bumps.cl_amm_base_vault]];
+            create_cl_amm_base_vault.execute(&[&cl_amm_base_vault_seeds])?;
+        }
+        {
+            let quote_mint_key = ctx.accounts.quote_mint.key();
+            let create_cl_amm_quote_vault = Box::new(ctx.accounts.get_create_cl_amm_quote_vault_instruction()?);
+            let cl_amm_quote_vault_seeds = [ClAmm::VAULT_SEED, cl_amm_key.as_ref(), quote_mint_key.as_ref(), &[ctx.bumps.cl_amm_quote_vault]];
+            create_cl_amm_quote_vault.execute(&[&cl_amm_quote_vault_seeds])?;
+        }
+    }
+    let accounts = ctx.accounts;
+
+    accounts.check_allowed_creator()?;
+    accounts.pay_pool_creation_fee(ctx.remaining_accounts)?;
+
+    accounts.cl_amm.initialize(
+        &accounts.base_mint,
+        &accounts.quote_mint,
+        &accounts.amms_config,
+        &accounts.signer.to_account_info(),
+        &accounts.cl_amm_base_vault,
+        &accounts.cl_amm_quote_vault,
+        tick_spacing,
+        initial_sqrt_price,
+        ctx.bumps.cl_amm,
+        ctx.bumps.cl_amm_base_vault,
+        ctx.bumps.cl_amm_quote_vault
+    )?;
+
+    let cl_amm = &accounts.cl_amm;
+
+    msg!("Event: InitializeClAmm");
+    emit!(
+        InitializeClAmmEvent{
+            creator: cl_amm.creator().key(),
+            cl_amm: cl_amm.key(),
+            amms_config: cl_amm.amms_config().key(),
+            base_mint: cl_amm.base_mint().key(),
+            quote_mint: cl_amm.quote_mint().key(),
+            cl_amm_base_vault: cl_amm.base_vault().key(),
+            cl_amm_quote_vault: cl_amm.quote_vault().key(),
+            tick_spacing,
+            sqrt_price: cl_amm.sqrt_price(),
+            tick_current: cl_amm.tick_current(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeClAmmEvent {
+    pub creator: Pubkey,
+    pub cl_amm: Pubkey,
+    pub amms_config: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub cl_amm_base_vault: Pubkey,
+    pub cl_amm_quote_vault: Pubkey,
+    pub tick_spacing: u16,
+    pub sqrt_price: Q64_128,
+    pub tick_current: i32,
+    pub timestamp: i64
+}
+impl<'info> InitializeClAmm<'info>{
+    fn validate_base_mint(&self) -> Result<()> {
+        let base_mint = self.base_mint.as_ref();
+        validate_tradable_mint(base_mint)
+    }
+    fn validate_quote_mint(&self) -> Result<()> {
+        let quote_mint = self.quote_mint.as_ref();
+        validate_tradable_mint(quote_mint)
+    }
+    /// Checks that the signer is allowed to create pools with a creator-restricted config
+    /// and counts the pool against the signer's cap.
+    fn check_allowed_creator(&mut self) -> Result<()> {
+        if !self.amms_config.creator_restricted() {
+            return Ok(());
+        }
+        let allowed_creator = self.allowed_creator.as_mut().ok_or(ErrorCode::CreatorNotAllowed)?;
+        allowed_creator.increment_pools_count()
+    }
+    /// Pays the config's pool creation fee to the fee authority, in lamports or in SPL tokens.
+    ///
+    /// `remaining_accounts` must hold the transfer hook accounts of a fee mint with the `TransferHook` extension.
+    fn pay_pool_creation_fee(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let pool_creation_fee_amount = self.amms_config.pool_creation_fee_amount();
+        if pool_creation_fee_amount == 0 {
+            return Ok(());
+        }
+        match self.amms_config.pool_creation_fee_mint() {
+            None => Box::new(self.get_pay_initial_lamports_instruction(pool_creation_fee_amount)?).execute(),
+            Some(pool_creation_fee_mint) => {
+                let (Some(mint), Some(signer_account), Some(fee_authority_account), Some(token_program)) = (
+                    self.pool_creation_fee_mint.as_ref(),
+                    self.signer_pool_creation_fee_account.as_ref(),
+                    self.fee_authority_pool_creation_fee_account.as_ref(),
+                    self.pool_creation_fee_token_program.as_ref()
+                ) else {
+                    return err!(ErrorCode::PoolCreationFeeAccountsMissing);
+                };
+                require!(
+                    mint.key() == *pool_creation_fee_mint
+                        && fee_authority_account.mint == *pool_creation_fee_mint
+                        && fee_authority_account.owner == self.fee_authority.key(),
+                    ErrorCode::InvalidPoolCreationFeeAccounts
+                );
+                Box::new(TransferTokensInstruction::try_new(
+                    pool_creation_fee_amount,
+                    mint,
+                    signer_account,
+                    self.signer.to_account_info(),
+                    fee_authority_account,
+                    token_program
+                )?.with_transfer_hook_accounts(remaining_accounts)).execute(None)
+            }
+        }
+    }
+    fn get_pay_initial_lamports_instruction(&self, lamports: u64) -> Result<TransferLamportsInstruction<'_, '_, '_, 'info>>{
+        TransferLamportsInstruction::new(
+            lamports,
+            self.signer.to_account_info(),
+            self.fee_authority.to_account_info(),
+            &self.system_program
+        )
+    }
+    #[inline(never)]
+    fn get_create_cl_amm_base_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
+        CreatePdaTokenAccountInstruction::try_new(
+            self.signer.to_account_info(),
+            self.cl_amm_base_vault.to_account_info(),
+            self.cl_amm.to_account_info(),
+            self.base_mint.to_account_info(),
+            self.base_token_program.to_account_info(),
+            self.system_program.to_account_info()
+        )
+    }
+    #[inline(never)]
+    fn get_create_cl_amm_quote_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
+        CreatePdaTokenAccountInstruction::try_new(
+            self.signer.to_account_info(),
+            self.cl_amm_quote_vault.to_account_info(),
+            self.cl_amm.to_account_info(),
+            self.quote_mint.to_account_info(),
+            self.quote_token_program.to_account_info(),
+            self.system_program.to_account_info()
+        )
+    }
+}
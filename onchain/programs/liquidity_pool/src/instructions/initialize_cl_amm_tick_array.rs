@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::cl_amm::{ClAmm, ClAmmCalculate, ClAmmCore, ClAmmTickArray};
+
+#[derive(Accounts)]
+#[instruction(start_tick: i32)]
+pub struct InitializeClAmmTickArray<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = cl_amm.is_initialized(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ClAmmTickArray::INIT_SPACE,
+        seeds = [ClAmmTickArray::SEED, cl_amm.key().as_ref(), start_tick.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tick_array: Box<Account<'info, ClAmmTickArray>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializeClAmmTickArray>, start_tick: i32) -> Result<()> {
+    ctx.accounts.cl_amm.validate_tick_array_start_tick(start_tick)?;
+    ctx.accounts.tick_array.initialize(ctx.accounts.cl_amm.key(), start_tick, ctx.bumps.tick_array);
+
+    msg!("Event: InitializeClAmmTickArray");
+    emit!(
+        InitializeClAmmTickArrayEvent{
+            signer: ctx.accounts.signer.key(),
+            cl_amm: ctx.accounts.cl_amm.key(),
+            tick_array: ctx.accounts.tick_array.key(),
+            start_tick,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeClAmmTickArrayEvent {
+    pub signer: Pubkey,
+    pub cl_amm: Pubkey,
+    pub tick_array: Pubkey,
+    pub start_tick: i32,
+    pub timestamp: i64
+}
@@ -1,15 +1,22 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{token::{Mint, Token}, token_interface};
+use anchor_spl::{token_2022::Token2022, token_interface};
 use anchor_spl::token_interface::TokenInterface;
-use crate::constants::CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS;
-use crate::state::{AmmsConfig, cp_amm::{
+use crate::state::{AllowedCreator, AmmsConfig, cp_amm::{
     CpAmm, 
-    CpAmmCalculate
+    CpAmmCalculate,
+    CpAmmPair
 }};
 use utilities::system_instructions::TransferLamportsInstruction;
+use utilities::token_instructions::{InitializeTokenMetadataInstruction, TransferTokensInstruction};
 use utilities::token_accounts_instructions::CreatePdaTokenAccountInstruction;
-use utilities::helpers::validate_tradable_mint;
+use utilities::helpers::{get_mint_symbol, validate_tradable_mint};
+use crate::error::ErrorCode;
 
+/// Creates a `CpAmm` pool.
+///
+/// The LP mint is created under Token 2022 with the MetadataPointer and TokenMetadata extensions,
+/// named after the base and quote symbols. The symbols are taken from the TokenMetadata extension
+/// of Token 2022 mints, or from the optional Metaplex metadata accounts of SPL Token mints.
 #[derive(Accounts)]
 pub struct InitializeCpAmm<'info> {
     #[account(mut)]
@@ -28,9 +35,11 @@ pub struct InitializeCpAmm<'info> {
         payer = signer,
         mint::decimals = CpAmm::LP_MINT_INITIAL_DECIMALS,
         mint::authority = cp_amm,
-        mint::token_program = lp_token_program
+        mint::token_program = lp_token_program,
+        extensions::metadata_pointer::authority = cp_amm,
+        extensions::metadata_pointer::metadata_address = lp_mint
     )]
-    pub lp_mint: Box<Account<'info, Mint>>,
+    pub lp_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
     
     #[account(
         constraint = amms_config.fee_authority().key() == fee_authority.key(),
@@ -48,6 +57,21 @@ pub struct InitializeCpAmm<'info> {
     )]
     pub cp_amm: Box<Account<'info, CpAmm>>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + CpAmmPair::INIT_SPACE,
+        seeds = [
+            CpAmmPair::SEED,
+            amms_config.key().as_ref(),
+            // Dereferenced keys, as the IDL builder can't resolve the sorted mints and would evaluate them as a constant
+            CpAmmPair::sort_mints(*base_mint.to_account_info().key, *quote_mint.to_account_info().key).0.as_ref(),
+            CpAmmPair::sort_mints(*base_mint.to_account_info().key, *quote_mint.to_account_info().key).1.as_ref()
+        ],
+        bump
+    )]
+    pub cp_amm_pair: Box<Account<'info, CpAmmPair>>,
+
     /// CHECK:
     #[account(
         mut,
@@ -74,12 +98,30 @@ pub struct InitializeCpAmm<'info> {
     
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
-    pub lp_token_program: Program<'info, Token>,
+    pub lp_token_program: Program<'info, Token2022>,
     pub base_token_program: Interface<'info, TokenInterface>,
     pub quote_token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [AllowedCreator::SEED, amms_config.key().as_ref(), signer.key().as_ref()],
+        bump = allowed_creator.bump()
+    )]
+    pub allowed_creator: Option<Box<Account<'info, AllowedCreator>>>,
+    pub pool_creation_fee_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
+    #[account(mut)]
+    pub signer_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    #[account(mut)]
+    pub fee_authority_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    pub pool_creation_fee_token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Metaplex metadata of the base mint, validated by owner and mint when reading the symbol
+    pub base_mint_metaplex_metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Metaplex metadata of the quote mint, validated by owner and mint when reading the symbol
+    pub quote_mint_metaplex_metadata: Option<UncheckedAccount<'info>>,
 }
 
-pub(crate) fn handler(ctx: Context<InitializeCpAmm>) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeCpAmm<'info>>) -> Result<()> {
     ctx.accounts.validate_base_mint()?;
     ctx.accounts.validate_quote_mint()?;
     {
@@ -105,8 +147,9 @@ pub(crate) fn handler(ctx: Context<InitializeCpAmm>) -> Result<()> {
     }
     let accounts = ctx.accounts;
 
-    let pay_initial_lamports_instruction = Box::new(accounts.get_pay_initial_lamports_instruction(CP_AMM_INITIALIZE_PRICE_IN_LAMPORTS)?);
-    pay_initial_lamports_instruction.execute()?;
+    accounts.check_allowed_creator()?;
+    accounts.register_cp_amm_pair(ctx.bumps.cp_amm_pair);
+    accounts.pay_pool_creation_fee(ctx.remaining_accounts)?;
     
     accounts.cp_amm.initialize(
         &accounts.base_mint,
@@ -122,6 +165,7 @@ pub(crate) fn handler(ctx: Context<InitializeCpAmm>) -> Result<()> {
         ctx.bumps.cp_amm_quote_vault,
         ctx.bumps.cp_amm_locked_lp_vault
     )?;
+    accounts.initialize_lp_token_metadata()?;
 
     let cp_amm = &accounts.cp_amm;
 
@@ -165,6 +209,79 @@ impl<'info> InitializeCpAmm<'info>{
         let quote_mint = self.quote_mint.as_ref();
         validate_tradable_mint(quote_mint)
     }
+    /// Checks that the signer is allowed to create pools with a creator-restricted config
+    /// and counts the pool against the signer's cap.
+    fn check_allowed_creator(&mut self) -> Result<()> {
+        if !self.amms_config.creator_restricted() {
+            return Ok(());
+        }
+        let allowed_creator = self.allowed_creator.as_mut().ok_or(ErrorCode::CreatorNotAllowed)?;
+        allowed_creator.increment_pools_count()
+    }
+    /// Registers the pool as the canonical `CpAmm` of its pair in the config, if the pair has none yet.
+    /// Otherwise the pool is created unregistered, the admin can make it canonical with `update_cp_amm_pair`.
+    fn register_cp_amm_pair(&mut self, bump: u8) {
+        let cp_amm_pair = &mut self.cp_amm_pair;
+        if !cp_amm_pair.is_initialized() {
+            cp_amm_pair.initialize(self.amms_config.key(), self.base_mint.key(), self.quote_mint.key(), bump);
+        }
+        cp_amm_pair.register_cp_amm(self.cp_amm.key());
+    }
+    /// Initializes the LP token metadata, signed by the `CpAmm` as the LP mint authority.
+    fn initialize_lp_token_metadata(&self) -> Result<()> {
+        let base_symbol = get_mint_symbol(&self.base_mint, self.base_mint_metaplex_metadata.as_deref());
+        let quote_symbol = get_mint_symbol(&self.quote_mint, self.quote_mint_metaplex_metadata.as_deref());
+        let (name, symbol) = CpAmm::lp_token_metadata(&base_symbol, &quote_symbol);
+
+        let cp_amm_seeds = self.cp_amm.seeds();
+        Box::new(InitializeTokenMetadataInstruction::try_new(
+            self.signer.to_account_info(),
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.cp_amm.to_account_info(),
+            self.lp_token_program.to_account_info(),
+            self.system_program.to_account_info(),
+            name,
+            symbol,
+            String::new()
+        )?).execute(&[&cp_amm_seeds])
+    }
+    /// Pays the config's pool creation fee to the fee authority, in lamports or in SPL tokens.
+    ///
+    /// `remaining_accounts` must hold the transfer hook accounts of a fee mint with the `TransferHook` extension.
+    fn pay_pool_creation_fee(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let pool_creation_fee_amount = self.amms_config.pool_creation_fee_amount();
+        if pool_creation_fee_amount == 0 {
+            return Ok(());
+        }
+        match self.amms_config.pool_creation_fee_mint() {
+            None => Box::new(self.get_pay_initial_lamports_instruction(pool_creation_fee_amount)?).execute(),
+            Some(pool_creation_fee_mint) => {
+                let (Some(mint), Some(signer_account), Some(fee_authority_account), Some(token_program)) = (
+                    self.pool_creation_fee_mint.as_ref(),
+                    self.signer_pool_creation_fee_account.as_ref(),
+                    self.fee_authority_pool_creation_fee_account.as_ref(),
+                    self.pool_creation_fee_token_program.as_ref()
+                ) else {
+                    return err!(ErrorCode::PoolCreationFeeAccountsMissing);
+                };
+                require!(
+                    mint.key() == *pool_creation_fee_mint
+                        && fee_authority_account.mint == *pool_creation_fee_mint
+                        && fee_authority_account.owner == self.fee_authority.key(),
+                    ErrorCode::InvalidPoolCreationFeeAccounts
+                );
+                Box::new(TransferTokensInstruction::try_new(
+                    pool_creation_fee_amount,
+                    mint,
+                    signer_account,
+                    self.signer.to_account_info(),
+                    fee_authority_account,
+                    token_program
+                )?.with_transfer_hook_accounts(remaining_accounts)).execute(None)
+            }
+        }
+    }
     fn get_pay_initial_lamports_instruction(&self, lamports: u64) -> Result<TransferLamportsInstruction<'_, '_, '_, 'info>>{
         TransferLamportsInstruction::new(
             lamports,
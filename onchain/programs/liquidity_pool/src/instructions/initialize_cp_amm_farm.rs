@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::cp_amm::{CpAmm, CpAmmFarm};
+
+/// Creates a liquidity mining farm for the LP tokens of a launched `CpAmm`.
+///
+/// Anyone can create a farm, its authority being the only one able to fund its rewards.
+#[derive(Accounts)]
+pub struct InitializeCpAmmFarm<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = cp_amm.is_launched(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CpAmmFarm::INIT_SPACE,
+        seeds = [CpAmmFarm::SEED, cp_amm.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_farm: Box<Account<'info, CpAmmFarm>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = cp_amm_farm,
+        associated_token::token_program = lp_token_program
+    )]
+    pub cp_amm_farm_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializeCpAmmFarm>) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.cp_amm_farm.initialize(
+        ctx.accounts.cp_amm.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.lp_mint.key(),
+        timestamp,
+        ctx.bumps.cp_amm_farm
+    );
+
+    msg!("Event: InitializeCpAmmFarm");
+    emit!(
+        InitializeCpAmmFarmEvent{
+            authority: ctx.accounts.authority.key(),
+            cp_amm: ctx.accounts.cp_amm.key(),
+            cp_amm_farm: ctx.accounts.cp_amm_farm.key(),
+            lp_mint: ctx.accounts.lp_mint.key(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeCpAmmFarmEvent{
+    pub authority: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_farm: Pubkey,
+    pub lp_mint: Pubkey,
+    pub timestamp: i64
+}
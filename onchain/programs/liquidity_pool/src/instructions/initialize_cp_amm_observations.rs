@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::cp_amm::{CpAmm, CpAmmObservations};
+
+#[derive(Accounts)]
+pub struct InitializeCpAmmObservations<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + CpAmmObservations::INIT_SPACE,
+        seeds = [CpAmmObservations::SEED, cp_amm.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_observations: Box<Account<'info, CpAmmObservations>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializeCpAmmObservations>) -> Result<()> {
+    let cp_amm = &ctx.accounts.cp_amm;
+    let cp_amm_observations = &mut ctx.accounts.cp_amm_observations;
+    cp_amm_observations.initialize(cp_amm.key(), ctx.bumps.cp_amm_observations);
+    if cp_amm.is_launched() {
+        cp_amm_observations.record(cp_amm.observe(Clock::get()?.unix_timestamp))?;
+    }
+
+    msg!("Event: InitializeCpAmmObservations");
+    emit!(
+        InitializeCpAmmObservationsEvent{
+            signer: ctx.accounts.signer.key(),
+            cp_amm: cp_amm.key(),
+            cp_amm_observations: cp_amm_observations.key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeCpAmmObservationsEvent {
+    pub signer: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_observations: Pubkey,
+    pub timestamp: i64,
+}
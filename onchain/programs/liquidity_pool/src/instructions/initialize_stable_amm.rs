@@ -0,0 +1,269 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token::{Mint, Token}, token_interface};
+use anchor_spl::token_interface::TokenInterface;
+use crate::state::{AllowedCreator, AmmsConfig, stable_amm::{
+    StableAmm, 
+    StableAmmCalculate
+}};
+use utilities::system_instructions::TransferLamportsInstruction;
+use utilities::token_instructions::TransferTokensInstruction;
+use utilities::token_accounts_instructions::CreatePdaTokenAccountInstruction;
+use utilities::helpers::validate_tradable_mint;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeStableAmm<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: Amms config's fee authority can be arbitrary type
+    pub fee_authority: AccountInfo<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        constraint = base_mint.key() != quote_mint.key()
+    )]
+    pub quote_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    
+    #[account(
+        init,
+        payer = signer,
+        mint::decimals = StableAmm::LP_MINT_INITIAL_DECIMALS,
+        mint::authority = stable_amm,
+        mint::token_program = lp_token_program
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + StableAmm::INIT_SPACE,
+        seeds = [StableAmm::SEED, lp_mint.key().as_ref()],
+        bump
+    )]
+    pub stable_amm: Box<Account<'info, StableAmm>>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), base_mint.key().as_ref()],
+        bump
+    )]
+    pub stable_amm_base_vault: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub stable_amm_quote_vault: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), lp_mint.key().as_ref()],
+        bump
+    )]
+    pub stable_amm_locked_lp_vault: AccountInfo<'info>,
+    
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub lp_token_program: Program<'info, Token>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        mut,
+        seeds = [AllowedCreator::SEED, amms_config.key().as_ref(), signer.key().as_ref()],
+        bump = allowed_creator.bump()
+    )]
+    pub allowed_creator: Option<Box<Account<'info, AllowedCreator>>>,
+    pub pool_creation_fee_mint: Option<Box<InterfaceAccount<'info, token_interface::Mint>>>,
+    #[account(mut)]
+    pub signer_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    #[account(mut)]
+    pub fee_authority_pool_creation_fee_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,
+    pub pool_creation_fee_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeStableAmm<'info>>, amplification: u64) -> Result<()> {
+    ctx.accounts.validate_base_mint()?;
+    ctx.accounts.validate_quote_mint()?;
+    {
+        let stable_amm_key = ctx.accounts.stable_amm.key();
+        {
+            let base_mint_key = ctx.accounts.base_mint.key();
+            let create_stable_amm_base_vault = Box::new(ctx.accounts.get_create_stable_amm_base_vault_instruction()?);
+            let stable_amm_base_vault_seeds = [StableAmm::VAULT_SEED, stable_amm_key.as_ref(), base_mint_key.as_ref(), &[ctx.bumps.stable_amm_base_vault]];
+            create_stable_amm_base_vault.execute(&[&stable_amm_base_vault_seeds])?;
+        }
+        {
+            let quote_mint_key = ctx.accounts.quote_mint.key();
+            let create_stable_amm_quote_vault = Box::new(ctx.accounts.get_create_stable_amm_quote_vault_instruction()?);
+            let stable_amm_quote_vault_seeds = [StableAmm::VAULT_SEED, stable_amm_key.as_ref(),quote_mint_key.as_ref(), &[ctx.bumps.stable_amm_quote_vault]];
+            create_stable_amm_quote_vault.execute(&[&stable_amm_quote_vault_seeds])?;
+        }
+        {
+            let lp_mint_key = ctx.accounts.lp_mint.key();
+            let create_stable_amm_locked_lp_vault = Box::new(ctx.accounts.get_create_stable_amm_locked_lp_vault_instruction()?);
+            let stable_amm_locked_lp_vault_seeds = [StableAmm::VAULT_SEED, stable_amm_key.as_ref(), lp_mint_key.as_ref(), &[ctx.bumps.stable_amm_locked_lp_vault]];
+            create_stable_amm_locked_lp_vault.execute(&[&stable_amm_locked_lp_vault_seeds])?;
+        }
+    }
+    let accounts = ctx.accounts;
+
+    accounts.check_allowed_creator()?;
+    accounts.pay_pool_creation_fee(ctx.remaining_accounts)?;
+    
+    accounts.stable_amm.initialize(
+        &accounts.base_mint,
+        &accounts.quote_mint,
+        &accounts.lp_mint,
+        &accounts.amms_config,
+        &accounts.signer.to_account_info(),
+        &accounts.stable_amm_base_vault,
+        &accounts.stable_amm_quote_vault,
+        &accounts.stable_amm_locked_lp_vault,
+        amplification,
+        ctx.bumps.stable_amm,
+        ctx.bumps.stable_amm_base_vault,
+        ctx.bumps.stable_amm_quote_vault,
+        ctx.bumps.stable_amm_locked_lp_vault
+    )?;
+
+    let stable_amm = &accounts.stable_amm;
+
+    msg!("Event: InitializeStableAmm");
+    emit!(
+        InitializeStableAmmEvent{
+            creator: stable_amm.creator().key(),
+            stable_amm: stable_amm.key(),
+            amms_config: stable_amm.amms_config().key(),
+            base_mint: stable_amm.base_mint().key(),
+            quote_mint: stable_amm.quote_mint().key(),
+            lp_mint: stable_amm.lp_mint.key(),
+            stable_amm_base_vault: stable_amm.base_vault().key(),
+            stable_amm_quote_vault: stable_amm.quote_vault().key(),
+            stable_amm_locked_lp_vault: stable_amm.locked_lp_vault().key(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct InitializeStableAmmEvent {
+    pub creator: Pubkey,
+    pub stable_amm: Pubkey,
+    pub amms_config: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub stable_amm_base_vault: Pubkey,
+    pub stable_amm_quote_vault: Pubkey,
+    pub stable_amm_locked_lp_vault: Pubkey,
+    pub timestamp: i64
+}
+impl<'info> InitializeStableAmm<'info>{
+    fn validate_base_mint(&self) -> Result<()> {
+        let base_mint = self.base_mint.as_ref();
+        validate_tradable_mint(base_mint)
+    }
+    fn validate_quote_mint(&self) -> Result<()> {
+        let quote_mint = self.quote_mint.as_ref();
+        validate_tradable_mint(quote_mint)
+    }
+    /// Checks that the signer is allowed to create pools with a creator-restricted config
+    /// and counts the pool against the signer's cap.
+    fn check_allowed_creator(&mut self) -> Result<()> {
+        if !self.amms_config.creator_restricted() {
+            return Ok(());
+        }
+        let allowed_creator = self.allowed_creator.as_mut().ok_or(ErrorCode::CreatorNotAllowed)?;
+        allowed_creator.increment_pools_count()
+    }
+    /// Pays the config's pool creation fee to the fee authority, in lamports or in SPL tokens.
+    ///
+    /// `remaining_accounts` must hold the transfer hook accounts of a fee mint with the `TransferHook` extension.
+    fn pay_pool_creation_fee(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let pool_creation_fee_amount = self.amms_config.pool_creation_fee_amount();
+        if pool_creation_fee_amount == 0 {
+            return Ok(());
+        }
+        match self.amms_config.pool_creation_fee_mint() {
+            None => Box::new(self.get_pay_initial_lamports_instruction(pool_creation_fee_amount)?).execute(),
+            Some(pool_creation_fee_mint) => {
+                let (Some(mint), Some(signer_account), Some(fee_authority_account), Some(token_program)) = (
+                    self.pool_creation_fee_mint.as_ref(),
+                    self.signer_pool_creation_fee_account.as_ref(),
+                    self.fee_authority_pool_creation_fee_account.as_ref(),
+                    self.pool_creation_fee_token_program.as_ref()
+                ) else {
+                    return err!(ErrorCode::PoolCreationFeeAccountsMissing);
+                };
+                require!(
+                    mint.key() == *pool_creation_fee_mint
+                        && fee_authority_account.mint == *pool_creation_fee_mint
+                        && fee_authority_account.owner == self.fee_authority.key(),
+                    ErrorCode::InvalidPoolCreationFeeAccounts
+                );
+                Box::new(TransferTokensInstruction::try_new(
+                    pool_creation_fee_amount,
+                    mint,
+                    signer_account,
+                    self.signer.to_account_info(),
+                    fee_authority_account,
+                    token_program
+                )?.with_transfer_hook_accounts(remaining_accounts)).execute(None)
+            }
+        }
+    }
+    fn get_pay_initial_lamports_instruction(&self, lamports: u64) -> Result<TransferLamportsInstruction<'_, '_, '_, 'info>>{
+        TransferLamportsInstruction::new(
+            lamports,
+            self.signer.to_account_info(),
+            self.fee_authority.to_account_info(),
+            &self.system_program
+        )
+    }
+    #[inline(never)]
+    fn get_create_stable_amm_base_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
+        CreatePdaTokenAccountInstruction::try_new(
+            self.signer.to_account_info(),
+            self.stable_amm_base_vault.to_account_info(),
+            self.stable_amm.to_account_info(),
+            self.base_mint.to_account_info(),
+            self.base_token_program.to_account_info(),
+            self.system_program.to_account_info()
+        )
+    }
+    #[inline(never)]
+    fn get_create_stable_amm_quote_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
+        CreatePdaTokenAccountInstruction::try_new(
+            self.signer.to_account_info(),
+            self.stable_amm_quote_vault.to_account_info(),
+            self.stable_amm.to_account_info(),
+            self.quote_mint.to_account_info(),
+            self.quote_token_program.to_account_info(),
+            self.system_program.to_account_info()
+        )
+    }
+    #[inline(never)]
+    fn get_create_stable_amm_locked_lp_vault_instruction(&self) -> Result<CreatePdaTokenAccountInstruction<'_, '_, '_, 'info>>{
+        CreatePdaTokenAccountInstruction::try_new(
+            self.signer.to_account_info(),
+            self.stable_amm_locked_lp_vault.to_account_info(),
+            self.stable_amm.to_account_info(),
+            self.lp_mint.to_account_info(),
+            self.lp_token_program.to_account_info(),
+            self.system_program.to_account_info()
+        )
+    }
+}
\ No newline at end of file
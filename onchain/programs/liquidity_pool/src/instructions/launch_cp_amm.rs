@@ -1,14 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token,
-    token::{Token, TokenAccount},
-    token_interface::{TokenAccount as InterfaceTokenAccount, Mint, TokenInterface}
-};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use anchor_spl::associated_token::AssociatedToken;
 use utilities::math::Q64_128;
 use crate::state::{AmmsConfig, cp_amm::CpAmm};
 use utilities::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
 use crate::state::cp_amm::CpAmmCore;
+use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 pub struct LaunchCpAmm<'info>{
@@ -17,13 +14,13 @@ pub struct LaunchCpAmm<'info>{
     pub base_mint: Box<InterfaceAccount<'info, Mint>>,
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
-    pub lp_mint: Box<Account<'info, token::Mint>>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
     // Token program will check mint and authority via token_instructions instruction
-    pub creator_base_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(mut)]
     // Token program will check mint and authority via token_instructions instruction
-    pub creator_quote_account: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         init,
@@ -32,7 +29,7 @@ pub struct LaunchCpAmm<'info>{
         associated_token::authority = creator,
         associated_token::token_program = lp_token_program,
     )]
-    pub creator_lp_account: Box<Account<'info, TokenAccount>>,
+    pub creator_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
@@ -61,33 +58,37 @@ pub struct LaunchCpAmm<'info>{
         seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
         bump = cp_amm.base_vault_bump()
     )]
-    pub cp_amm_base_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
         bump = cp_amm.quote_vault_bump()
     )]
-    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, InterfaceTokenAccount>>,
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.lp_mint.as_ref()],
         bump = cp_amm.locked_lp_vault_bump()
     )]
-    pub cp_amm_locked_lp_vault: Box<Account<'info, TokenAccount>>,
+    pub cp_amm_locked_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     
-    pub lp_token_program: Program<'info, Token>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
     pub base_token_program: Interface<'info, TokenInterface>,
     pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub(crate) fn handler(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()> {
-    
-    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?);
-    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?);
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.provides_paused() && !ctx.accounts.cp_amm.provides_paused(), ErrorCode::ProvidesPaused);
+
+    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
 
     let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
     let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
@@ -106,7 +107,9 @@ pub(crate) fn handler(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liqu
     launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
     initial_locked_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
 
+    ctx.accounts.cp_amm.update_cumulative_prices(Clock::get()?.unix_timestamp);
     ctx.accounts.cp_amm.launch(*launch_payload);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(ctx.accounts.amms_config.protocol_fee_on_lp_enabled());
     let cp_amm = &ctx.accounts.cp_amm;
 
     msg!("Event: LaunchCpAmm");
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::{AmmsConfig, cp_amm::{CpAmm, CpAmmLpLock}};
+use utilities::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
+use crate::state::cp_amm::CpAmmCore;
+use super::LaunchCpAmmEvent;
+use crate::error::ErrorCode;
+
+/// Launches a `CpAmm` like `launch_cp_amm`, locking a share of the creator's launch LP tokens
+/// in a `CpAmmLpLock` released by a cliff and linear vesting schedule.
+#[derive(Accounts)]
+pub struct LaunchCpAmmWithLpLock<'info>{
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = lp_token_program,
+    )]
+    pub creator_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = !cp_amm.is_launched(),
+        constraint = creator.key() == cp_amm.creator().key(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_locked_lp_vault.key() == cp_amm.locked_lp_vault().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+    
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.locked_lp_vault_bump()
+    )]
+    pub cp_amm_locked_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + CpAmmLpLock::INIT_SPACE,
+        seeds = [CpAmmLpLock::SEED, cp_amm.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub cp_amm_lp_lock: Box<Account<'info, CpAmmLpLock>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = cp_amm_lp_lock,
+        associated_token::token_program = lp_token_program,
+    )]
+    pub cp_amm_lp_lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LaunchCpAmmWithLpLock<'info>>,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    locked_lp_share_basis_points: u16,
+    cliff_timestamp: i64,
+    end_timestamp: i64,
+    deadline: Option<i64>
+) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.provides_paused() && !ctx.accounts.cp_amm.provides_paused(), ErrorCode::ProvidesPaused);
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
+    let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
+
+    let launch_payload = Box::new(ctx.accounts.cp_amm.get_launch_payload(base_liquidity_to_provide, quote_liquidity_to_provide)?);
+
+    let locked_lp_tokens = CpAmmLpLock::calculate_locked_lp_tokens(launch_payload.launch_liquidity(), locked_lp_share_basis_points)?;
+    let creator_lp_tokens = launch_payload.launch_liquidity() - locked_lp_tokens;
+
+    let launch_liquidity_mint_instruction = Box::new(ctx.accounts.get_launch_liquidity_mint_instruction(creator_lp_tokens));
+    let locked_launch_liquidity_mint_instruction = Box::new(ctx.accounts.get_locked_launch_liquidity_mint_instruction(locked_lp_tokens));
+    let initial_locked_liquidity_mint_instruction = Box::new(ctx.accounts.get_initial_locked_liquidity_mint_instruction(launch_payload.initial_locked_liquidity()));
+
+    provide_base_liquidity_instruction.execute(None)?;
+    provide_quote_liquidity_instruction.execute(None)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let mint_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    if creator_lp_tokens > 0 {
+        launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+    }
+    locked_launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+    initial_locked_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+
+    let cp_amm_key = ctx.accounts.cp_amm.key();
+    let creator_key = ctx.accounts.creator.key();
+    ctx.accounts.cp_amm_lp_lock.initialize(
+        cp_amm_key,
+        creator_key,
+        locked_lp_tokens,
+        timestamp,
+        cliff_timestamp,
+        end_timestamp,
+        ctx.bumps.cp_amm_lp_lock
+    )?;
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
+    ctx.accounts.cp_amm.launch(*launch_payload);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(ctx.accounts.amms_config.protocol_fee_on_lp_enabled());
+    let cp_amm = &ctx.accounts.cp_amm;
+    let cp_amm_lp_lock = &ctx.accounts.cp_amm_lp_lock;
+
+    msg!("Event: LaunchCpAmm");
+    emit!(
+        LaunchCpAmmEvent{
+            creator: cp_amm.creator().key(),
+            cp_amm: cp_amm.key(),
+            amms_config: cp_amm.amms_config().key(),
+            base_mint: cp_amm.base_mint().key(),
+            quote_mint: cp_amm.quote_mint().key(),
+            lp_mint: cp_amm.lp_mint.key(),
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            initial_locked_liquidity: cp_amm.initial_locked_liquidity(),
+            lp_tokens_supply: cp_amm.lp_tokens_supply(),
+            constant_product_sqrt: cp_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            timestamp
+        }
+    );
+    msg!("Event: LockCpAmmLp");
+    emit!(
+        LockCpAmmLpEvent{
+            owner: cp_amm_lp_lock.owner().key(),
+            cp_amm: cp_amm.key(),
+            cp_amm_lp_lock: cp_amm_lp_lock.key(),
+            lp_mint: cp_amm.lp_mint.key(),
+            locked_lp_tokens: cp_amm_lp_lock.locked_lp_tokens(),
+            start_timestamp: cp_amm_lp_lock.start_timestamp(),
+            cliff_timestamp: cp_amm_lp_lock.cliff_timestamp(),
+            end_timestamp: cp_amm_lp_lock.end_timestamp(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+#[event]
+pub struct LockCpAmmLpEvent{
+    pub owner: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_lp_lock: Pubkey,
+    pub lp_mint: Pubkey,
+    pub locked_lp_tokens: u64,
+    pub start_timestamp: i64,
+    pub cliff_timestamp: i64,
+    pub end_timestamp: i64,
+    pub timestamp: i64
+}
+impl<'info> LaunchCpAmmWithLpLock<'info>{
+    #[inline(never)]
+    fn get_provide_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.creator_base_account,
+            self.creator.to_account_info(),
+            &self.cp_amm_base_vault,
+            &self.base_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_provide_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.creator_quote_account,
+            self.creator.to_account_info(),
+            &self.cp_amm_quote_vault,
+            &self.quote_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_launch_liquidity_mint_instruction(&self, launch_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            launch_liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.creator_lp_account.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_locked_launch_liquidity_mint_instruction(&self, locked_lp_tokens: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            locked_lp_tokens,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.cp_amm_lp_lock_vault.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_initial_locked_liquidity_mint_instruction(&self, initial_locked_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            initial_locked_liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.cp_amm_locked_lp_vault.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, stable_amm::StableAmm};
+use utilities::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
+use crate::state::stable_amm::StableAmmCore;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct LaunchStableAmm<'info>{
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub creator_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = lp_token_program,
+    )]
+    pub creator_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = !stable_amm.is_launched(),
+        constraint = creator.key() == stable_amm.creator().key(),
+        constraint = amms_config.key() == stable_amm.amms_config().key(),
+        constraint = lp_mint.key() == stable_amm.lp_mint,
+        constraint = base_mint.key() == stable_amm.base_mint().key(),
+        constraint = quote_mint.key() == stable_amm.quote_mint().key(),
+        constraint = stable_amm_locked_lp_vault.key() == stable_amm.locked_lp_vault().key(),
+        constraint = stable_amm_base_vault.key() == stable_amm.base_vault().key(),
+        constraint = stable_amm_quote_vault.key() == stable_amm.quote_vault().key(),
+        seeds = [StableAmm::SEED, stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.bump()
+    )]
+    pub stable_amm: Box<Account<'info, StableAmm>>,
+    
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.base_mint().as_ref()],
+        bump = stable_amm.base_vault_bump()
+    )]
+    pub stable_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.quote_mint().as_ref()],
+        bump = stable_amm.quote_vault_bump()
+    )]
+    pub stable_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.locked_lp_vault_bump()
+    )]
+    pub stable_amm_locked_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchStableAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    // StableAmm has no pool-level pause, it is paused through its configuration only
+    require!(!ctx.accounts.amms_config.provides_paused(), ErrorCode::ProvidesPaused);
+
+    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
+    let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
+
+    let launch_payload = Box::new(ctx.accounts.stable_amm.get_launch_payload(base_liquidity_to_provide, quote_liquidity_to_provide, Clock::get()?.unix_timestamp)?);
+
+    let launch_liquidity_mint_instruction = Box::new(ctx.accounts.get_launch_liquidity_mint_instruction(launch_payload.launch_liquidity()));
+    let initial_locked_liquidity_mint_instruction = Box::new(ctx.accounts.get_initial_locked_liquidity_mint_instruction(launch_payload.initial_locked_liquidity()));
+
+    provide_base_liquidity_instruction.execute(None)?;
+    provide_quote_liquidity_instruction.execute(None)?;
+
+    let stable_amm_seeds = ctx.accounts.stable_amm.seeds();
+    let mint_instruction_seeds: &[&[&[u8]]] = &[&stable_amm_seeds];
+
+    launch_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+    initial_locked_liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
+
+    ctx.accounts.stable_amm.launch(*launch_payload);
+    let stable_amm = &ctx.accounts.stable_amm;
+
+    msg!("Event: LaunchStableAmm");
+    emit!(
+        LaunchStableAmmEvent{
+            creator: stable_amm.creator().key(),
+            stable_amm: stable_amm.key(),
+            amms_config: stable_amm.amms_config().key(),
+            base_mint: stable_amm.base_mint().key(),
+            quote_mint: stable_amm.quote_mint().key(),
+            lp_mint: stable_amm.lp_mint.key(),
+            base_liquidity: stable_amm.base_liquidity(),
+            quote_liquidity: stable_amm.quote_liquidity(),
+            initial_locked_liquidity: stable_amm.initial_locked_liquidity(),
+            lp_tokens_supply: stable_amm.lp_tokens_supply(),
+            constant_product_sqrt: stable_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: stable_amm.base_quote_ratio_sqrt(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+#[event]
+pub struct LaunchStableAmmEvent{
+    pub creator: Pubkey,
+    pub stable_amm: Pubkey,
+    pub amms_config: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub initial_locked_liquidity: u64,
+    pub lp_tokens_supply: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+impl<'info> LaunchStableAmm<'info>{
+    #[inline(never)]
+    fn get_provide_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.creator_base_account,
+            self.creator.to_account_info(),
+            &self.stable_amm_base_vault,
+            &self.base_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_provide_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.creator_quote_account,
+            self.creator.to_account_info(),
+            &self.stable_amm_quote_vault,
+            &self.quote_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_launch_liquidity_mint_instruction(&self, launch_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            launch_liquidity,
+            &self.lp_mint,
+            self.stable_amm.to_account_info(),
+            self.creator_lp_account.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+
+    #[inline(never)]
+    fn get_initial_locked_liquidity_mint_instruction(&self, initial_locked_liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info>{
+        MintTokensInstructions::new(
+            initial_locked_liquidity,
+            &self.lp_mint,
+            self.stable_amm.to_account_info(),
+            self.stable_amm_locked_lp_vault.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+}
\ No newline at end of file
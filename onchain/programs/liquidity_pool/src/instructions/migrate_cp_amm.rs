@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use utilities::constants::ANCHOR_DISCRIMINATOR;
+use utilities::helpers::migrate_account_layout;
+use crate::state::cp_amm::CpAmm;
+
+/// Grows a CpAmm created with an older layout to the current one.
+/// The appended fields start zeroed: no price accumulators, volatility, pauses or flash loan in progress.
+///
+/// Permissionless, as the payer only funds the additional rent.
+#[derive(Accounts)]
+pub struct MigrateCpAmm<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Older layouts don't deserialize as `CpAmm`, the owner and the discriminator are checked in the handler.
+    #[account(mut)]
+    pub cp_amm: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<MigrateCpAmm>) -> Result<()> {
+    migrate_account_layout::<CpAmm>(
+        &ctx.accounts.cp_amm.to_account_info(),
+        ANCHOR_DISCRIMINATOR + CpAmm::INIT_SPACE,
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program
+    )
+}
@@ -4,14 +4,98 @@ pub use admin::*;
 
 pub mod initialize_cp_amm;
 pub mod launch_cp_amm;
+pub mod launch_cp_amm_with_lp_lock;
+pub mod claim_from_cp_amm_lp_lock;
 pub mod provide_to_cp_amm;
 pub mod withdraw_from_cp_amm;
 pub mod swap_in_cp_amm;
+pub mod swap_in_cp_amm_with_referrer;
+pub mod quote_swap_in_cp_amm;
+pub mod flash_borrow_from_cp_amm;
+pub mod flash_repay_to_cp_amm;
+pub mod swap_exact_out_in_cp_amm;
+pub mod routed_swap_in_cp_amms;
+pub mod zap_in_to_cp_amm;
+pub mod zap_out_from_cp_amm;
 pub mod collect_fees_from_cp_amm;
+pub mod claim_fees_from_cp_amm;
+pub mod sweep_fees_from_cp_amms;
+pub mod claim_lp_fees_from_cp_amm;
+pub mod sync_cp_amm;
+pub mod skim_cp_amm;
+pub mod migrate_cp_amm;
+pub mod initialize_cp_amm_observations;
+pub mod record_cp_amm_observation;
+pub mod get_cp_amm_twap;
+pub mod initialize_cp_amm_farm;
+pub mod add_cp_amm_farm_reward;
+pub mod deposit_to_cp_amm_farm;
+pub mod withdraw_from_cp_amm_farm;
+pub mod harvest_from_cp_amm_farm;
+pub mod place_cp_amm_limit_order;
+pub mod cancel_cp_amm_limit_order;
+pub mod fill_cp_amm_limit_orders;
+pub mod initialize_stable_amm;
+pub mod launch_stable_amm;
+pub mod provide_to_stable_amm;
+pub mod withdraw_from_stable_amm;
+pub mod swap_in_stable_amm;
+pub mod swap_in_stable_amm_with_referrer;
+pub mod collect_fees_from_stable_amm;
+pub mod initialize_cl_amm;
+pub mod initialize_cl_amm_tick_array;
+pub mod open_cl_amm_position;
+pub mod provide_to_cl_amm;
+pub mod withdraw_from_cl_amm;
+pub mod swap_in_cl_amm;
+pub mod collect_fees_from_cl_amm_position;
+pub mod collect_fees_from_cl_amm;
 
 pub use initialize_cp_amm::*;
 pub use launch_cp_amm::*;
+pub use launch_cp_amm_with_lp_lock::*;
+pub use claim_from_cp_amm_lp_lock::*;
 pub use provide_to_cp_amm::*;
 pub use withdraw_from_cp_amm::*;
 pub use swap_in_cp_amm::*;
-pub use collect_fees_from_cp_amm::*;
\ No newline at end of file
+pub use swap_in_cp_amm_with_referrer::*;
+pub use quote_swap_in_cp_amm::*;
+pub use flash_borrow_from_cp_amm::*;
+pub use flash_repay_to_cp_amm::*;
+pub use swap_exact_out_in_cp_amm::*;
+pub use routed_swap_in_cp_amms::*;
+pub use zap_in_to_cp_amm::*;
+pub use zap_out_from_cp_amm::*;
+pub use collect_fees_from_cp_amm::*;
+pub use claim_fees_from_cp_amm::*;
+pub use sweep_fees_from_cp_amms::*;
+pub use claim_lp_fees_from_cp_amm::*;
+pub use sync_cp_amm::*;
+pub use skim_cp_amm::*;
+pub use migrate_cp_amm::*;
+pub use initialize_cp_amm_observations::*;
+pub use record_cp_amm_observation::*;
+pub use get_cp_amm_twap::*;
+pub use initialize_cp_amm_farm::*;
+pub use add_cp_amm_farm_reward::*;
+pub use deposit_to_cp_amm_farm::*;
+pub use withdraw_from_cp_amm_farm::*;
+pub use harvest_from_cp_amm_farm::*;
+pub use place_cp_amm_limit_order::*;
+pub use cancel_cp_amm_limit_order::*;
+pub use fill_cp_amm_limit_orders::*;
+pub use initialize_stable_amm::*;
+pub use launch_stable_amm::*;
+pub use provide_to_stable_amm::*;
+pub use withdraw_from_stable_amm::*;
+pub use swap_in_stable_amm::*;
+pub use swap_in_stable_amm_with_referrer::*;
+pub use collect_fees_from_stable_amm::*;
+pub use initialize_cl_amm::*;
+pub use initialize_cl_amm_tick_array::*;
+pub use open_cl_amm_position::*;
+pub use provide_to_cl_amm::*;
+pub use withdraw_from_cl_amm::*;
+pub use swap_in_cl_amm::*;
+pub use collect_fees_from_cl_amm_position::*;
+pub use collect_fees_from_cl_amm::*;
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::cl_amm::{ClAmm, ClAmmCalculate, ClAmmCore, ClAmmPosition};
+
+#[derive(Accounts)]
+#[instruction(tick_lower: i32, tick_upper: i32)]
+pub struct OpenClAmmPosition<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = cl_amm.is_initialized(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ClAmmPosition::INIT_SPACE,
+        seeds = [ClAmmPosition::SEED, cl_amm.key().as_ref(), signer.key().as_ref(), tick_lower.to_le_bytes().as_ref(), tick_upper.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub position: Box<Account<'info, ClAmmPosition>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<OpenClAmmPosition>, tick_lower: i32, tick_upper: i32) -> Result<()> {
+    ctx.accounts.cl_amm.validate_tick_range(tick_lower, tick_upper)?;
+    ctx.accounts.position.initialize(ctx.accounts.cl_amm.key(), ctx.accounts.signer.key(), tick_lower, tick_upper, ctx.bumps.position);
+
+    msg!("Event: OpenClAmmPosition");
+    emit!(
+        OpenClAmmPositionEvent{
+            owner: ctx.accounts.signer.key(),
+            cl_amm: ctx.accounts.cl_amm.key(),
+            position: ctx.accounts.position.key(),
+            tick_lower,
+            tick_upper,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct OpenClAmmPositionEvent {
+    pub owner: Pubkey,
+    pub cl_amm: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub timestamp: i64
+}
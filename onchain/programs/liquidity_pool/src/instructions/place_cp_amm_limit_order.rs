@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+use crate::state::cp_amm::{CpAmm, CpAmmLimitOrder};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Places a limit order on top of a `CpAmm`, escrowing `amount` of the `input_mint` in the order's associated token account.
+///
+/// The order sells **base → quote** if `input_mint` is the base mint of the `CpAmm`, **quote → base** otherwise.
+/// The owner output account is created here so the crank can always deliver the result.
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct PlaceCpAmmLimitOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub owner_input_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = output_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = output_token_program
+    )]
+    pub owner_output_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = cp_amm.is_launched(),
+        constraint = (
+            input_mint.key() == cp_amm.base_mint().key() && output_mint.key() == cp_amm.quote_mint().key()
+        ) || (
+            input_mint.key() == cp_amm.quote_mint().key() && output_mint.key() == cp_amm.base_mint().key()
+        ) @ ErrorCode::InvalidLimitOrderMints,
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + CpAmmLimitOrder::INIT_SPACE,
+        seeds = [CpAmmLimitOrder::SEED, cp_amm.key().as_ref(), owner.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub cp_amm_limit_order: Box<Account<'info, CpAmmLimitOrder>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = input_mint,
+        associated_token::authority = cp_amm_limit_order,
+        associated_token::token_program = input_token_program
+    )]
+    pub cp_amm_limit_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+    pub output_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, PlaceCpAmmLimitOrder<'info>>, id: u64, amount: u64, min_received_amount: u64, target_base_quote_ratio_sqrt: Q64_128) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let is_in_out = ctx.accounts.input_mint.key() == *ctx.accounts.cp_amm.base_mint();
+
+    let escrow_instruction = Box::new(ctx.accounts.get_escrow_transfer_instruction(amount)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let escrowed_amount = escrow_instruction.get_amount_after_fee();
+    escrow_instruction.execute(None)?;
+
+    let cp_amm_key = ctx.accounts.cp_amm.key();
+    let owner_key = ctx.accounts.owner.key();
+    ctx.accounts.cp_amm_limit_order.initialize(
+        cp_amm_key,
+        owner_key,
+        id,
+        is_in_out,
+        escrowed_amount,
+        min_received_amount,
+        target_base_quote_ratio_sqrt,
+        timestamp,
+        ctx.bumps.cp_amm_limit_order
+    )?;
+    let cp_amm_limit_order = &ctx.accounts.cp_amm_limit_order;
+
+    msg!("Event: PlaceCpAmmLimitOrder");
+    emit!(
+        PlaceCpAmmLimitOrderEvent{
+            owner: owner_key,
+            cp_amm: cp_amm_key,
+            cp_amm_limit_order: cp_amm_limit_order.key(),
+            id,
+            is_in_out,
+            amount: escrowed_amount,
+            min_received_amount,
+            target_base_quote_ratio_sqrt,
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct PlaceCpAmmLimitOrderEvent{
+    pub owner: Pubkey,
+    pub cp_amm: Pubkey,
+    pub cp_amm_limit_order: Pubkey,
+    pub id: u64,
+    pub is_in_out: bool,
+    pub amount: u64,
+    pub min_received_amount: u64,
+    pub target_base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+
+impl<'info> PlaceCpAmmLimitOrder<'info> {
+    #[inline(never)]
+    fn get_escrow_transfer_instruction(&self, amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            amount,
+            &self.input_mint,
+            &self.owner_input_account,
+            self.owner.to_account_info(),
+            &self.cp_amm_limit_order_vault,
+            &self.input_token_program
+        )
+    }
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, cl_amm::{ClAmm, ClAmmCore, ClAmmPosition, ClAmmTick, ClAmmTickArray}};
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ProvideToClAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cl_amm.is_initialized(),
+        constraint = amms_config.key() == cl_amm.amms_config().key(),
+        constraint = base_mint.key() == cl_amm.base_mint().key(),
+        constraint = quote_mint.key() == cl_amm.quote_mint().key(),
+        constraint = cl_amm_base_vault.key() == cl_amm.base_vault().key(),
+        constraint = cl_amm_quote_vault.key() == cl_amm.quote_vault().key(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        mut,
+        constraint = position.cl_amm().key() == cl_amm.key(),
+        constraint = position.owner().key() == signer.key(),
+    )]
+    pub position: Box<Account<'info, ClAmmPosition>>,
+
+    #[account(
+        mut,
+        constraint = tick_array_lower.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_lower: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        mut,
+        constraint = tick_array_upper.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_upper: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.base_mint().as_ref()],
+        bump = cl_amm.base_vault_bump()
+    )]
+    pub cl_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.quote_mint().as_ref()],
+        bump = cl_amm.quote_vault_bump()
+    )]
+    pub cl_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToClAmm<'info>>, liquidity: u128, max_base_amount: u64, max_quote_amount: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    // ClAmm has no pool-level pause, it is paused through its configuration only
+    require!(!ctx.accounts.amms_config.provides_paused(), ErrorCode::ProvidesPaused);
+
+    let tick_spacing = ctx.accounts.cl_amm.tick_spacing();
+    let (tick_lower, tick_upper) = (ctx.accounts.position.tick_lower(), ctx.accounts.position.tick_upper());
+    let tick_lower_state = *ctx.accounts.tick_array_lower.tick(tick_lower, tick_spacing)?;
+    let tick_upper_state = *ctx.accounts.tick_array_upper.tick(tick_upper, tick_spacing)?;
+
+    let provide_payload = ctx.accounts.cl_amm.get_provide_payload(
+        &ctx.accounts.position,
+        &tick_lower_state,
+        &tick_upper_state,
+        liquidity,
+        max_base_amount,
+        max_quote_amount
+    )?;
+    let (base_amount, quote_amount) = (provide_payload.base_amount(), provide_payload.quote_amount());
+
+    // Transfer fees are charged on top, so the vaults receive exactly the required amounts
+    if base_amount > 0 {
+        let base_amount_before_fee = TransferTokensInstruction::calculate_amount_before_fee(base_amount, &ctx.accounts.base_mint)?;
+        ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_amount_before_fee)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(None)?;
+    }
+    if quote_amount > 0 {
+        let quote_amount_before_fee = TransferTokensInstruction::calculate_amount_before_fee(quote_amount, &ctx.accounts.quote_mint)?;
+        ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_amount_before_fee)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(None)?;
+    }
+
+    ctx.accounts.update_ticks(provide_payload.tick_lower(), provide_payload.tick_upper())?;
+    ctx.accounts.position.update(provide_payload.position());
+    ctx.accounts.cl_amm.modify_liquidity(provide_payload);
+    let cl_amm = &ctx.accounts.cl_amm;
+
+    msg!("Event: ProvideToClAmm");
+    emit!(
+        ProvideToClAmmEvent{
+            provider: ctx.accounts.signer.key(),
+            cl_amm: cl_amm.key(),
+            position: ctx.accounts.position.key(),
+            tick_lower,
+            tick_upper,
+            provided_liquidity: liquidity,
+            provided_base_liquidity: base_amount,
+            provided_quote_liquidity: quote_amount,
+            position_liquidity: ctx.accounts.position.liquidity(),
+            liquidity: cl_amm.liquidity(),
+            base_liquidity: cl_amm.base_liquidity(),
+            quote_liquidity: cl_amm.quote_liquidity(),
+            sqrt_price: cl_amm.sqrt_price(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct ProvideToClAmmEvent{
+    pub provider: Pubkey,
+    pub cl_amm: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub provided_liquidity: u128,
+    pub provided_base_liquidity: u64,
+    pub provided_quote_liquidity: u64,
+    pub position_liquidity: u128,
+    pub liquidity: u128,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub sqrt_price: Q64_128,
+    pub timestamp: i64
+}
+
+impl<'info> ProvideToClAmm<'info> {
+    fn update_ticks(&mut self, tick_lower_state: ClAmmTick, tick_upper_state: ClAmmTick) -> Result<()> {
+        let tick_spacing = self.cl_amm.tick_spacing();
+        let (tick_lower, tick_upper) = (self.position.tick_lower(), self.position.tick_upper());
+        self.tick_array_lower.update_tick(tick_lower, tick_spacing, tick_lower_state)?;
+        self.tick_array_upper.update_tick(tick_upper, tick_spacing, tick_upper_state)?;
+        // Both ticks can belong to the same tick array, both copies are written back on exit
+        if self.tick_array_lower.key() == self.tick_array_upper.key() {
+            self.tick_array_lower.update_tick(tick_upper, tick_spacing, tick_upper_state)?;
+            self.tick_array_upper.update_tick(tick_lower, tick_spacing, tick_lower_state)?;
+        }
+        Ok(())
+    }
+    fn get_provide_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.signer_base_account,
+            self.signer.to_account_info(),
+            &self.cl_amm_base_vault,
+            &self.base_token_program
+        )
+    }
+    fn get_provide_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.signer_quote_account,
+            self.signer.to_account_info(),
+            &self.cl_amm_quote_vault,
+            &self.quote_token_program
+        )
+    }
+}
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use utilities::math::Q64_128;
 use crate::state::{AmmsConfig, cp_amm::CpAmm};
 use utilities::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
 use crate::state::cp_amm::CpAmmCore;
+use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 pub struct ProvideToCpAmm<'info>{
@@ -15,7 +14,7 @@ pub struct ProvideToCpAmm<'info>{
     pub base_mint: Box<InterfaceAccount<'info, Mint>>,
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
-    pub lp_mint: Box<Account<'info, token::Mint>>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
     // Token program will check mint and authority via token_instructions instruction
     pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -30,7 +29,7 @@ pub struct ProvideToCpAmm<'info>{
         associated_token::authority = signer,
         associated_token::token_program = lp_token_program
     )]
-    pub signer_lp_account: Box<Account<'info, token::TokenAccount>>,
+    pub signer_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
@@ -67,21 +66,34 @@ pub struct ProvideToCpAmm<'info>{
     pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub lp_token_program: Program<'info, Token>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
     pub base_token_program: Interface<'info, TokenInterface>,
     pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub(crate) fn handler(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, min_lp_tokens: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.provides_paused() && !ctx.accounts.cp_amm.provides_paused(), ErrorCode::ProvidesPaused);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let volatility_accumulator = ctx.accounts.cp_amm.volatility_accumulator(timestamp);
+    let protocol_fee_on_lp_enabled = ctx.accounts.amms_config.protocol_fee_on_lp_enabled();
+    ctx.accounts.cp_amm.accrue_protocol_lp_fees(
+        protocol_fee_on_lp_enabled,
+        ctx.accounts.amms_config.effective_providers_fee_rate_basis_points(volatility_accumulator, timestamp),
+        ctx.accounts.amms_config.current_protocol_fee_rate_basis_points(timestamp)
+    )?;
 
-    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?);
-    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?);
+    let provide_base_liquidity_instruction = Box::new(ctx.accounts.get_provide_base_liquidity_transfer_instruction(base_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let provide_quote_liquidity_instruction = Box::new(ctx.accounts.get_provide_quote_liquidity_transfer_instruction(quote_liquidity)?.with_transfer_hook_accounts(ctx.remaining_accounts));
 
     let base_liquidity_to_provide = provide_base_liquidity_instruction.get_amount_after_fee();
     let quote_liquidity_to_provide = provide_quote_liquidity_instruction.get_amount_after_fee();
 
-    let provide_payload = ctx.accounts.cp_amm.get_provide_payload(base_liquidity_to_provide, quote_liquidity_to_provide)?;
+    let provide_payload = ctx.accounts.cp_amm.get_provide_payload(base_liquidity_to_provide, quote_liquidity_to_provide, min_lp_tokens)?;
 
     provide_base_liquidity_instruction.execute(None)?;
     provide_quote_liquidity_instruction.execute(None)?;
@@ -94,7 +106,9 @@ pub(crate) fn handler(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_l
 
     liquidity_mint_instruction.execute(Some(mint_instruction_seeds))?;
 
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
     ctx.accounts.cp_amm.provide(provide_payload);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(protocol_fee_on_lp_enabled);
     let cp_amm = &ctx.accounts.cp_amm;
 
     msg!("Event: ProvideToCpAmm");
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use anchor_spl::token_interface::Mint;
+use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::error::ErrorCode;
+
+/// Read-only instruction quoting a swap in a `CpAmm` without moving tokens.
+///
+/// Takes the same arguments as `swap_in_cp_amm` and applies the same fee rates,
+/// slippage check and Token-2022 transfer fees on both sides.
+/// The result is a borsh-serialized `SwapQuote` passed via return data,
+/// so it can be simulated or consumed by other programs through CPI.
+#[derive(Accounts)]
+pub struct QuoteSwapInCpAmm<'info>{
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+}
+
+pub(crate) fn handler(ctx: Context<QuoteSwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()> {
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+
+    let (in_mint, out_mint) = if is_in_out {
+        (&ctx.accounts.base_mint, &ctx.accounts.quote_mint)
+    } else {
+        (&ctx.accounts.quote_mint, &ctx.accounts.base_mint)
+    };
+
+    let swap_amount_after_fee = TransferTokensInstruction::calculate_amount_after_fee(swap_amount, in_mint)?;
+    let timestamp = Clock::get()?.unix_timestamp;
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.effective_swap_fee_rates_basis_points(ctx.accounts.cp_amm.volatility_accumulator(timestamp), timestamp);
+    let swap_payload = ctx.accounts.cp_amm.get_swap_payload(
+        swap_amount_after_fee,
+        estimated_result,
+        allowed_slippage,
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        0,
+        is_in_out
+    )?;
+    let amount_to_withdraw = swap_payload.amount_to_withdraw();
+
+    let quote = SwapQuote {
+        swapped_amount: swap_amount_after_fee,
+        amount_to_withdraw,
+        received_amount: TransferTokensInstruction::calculate_amount_after_fee(amount_to_withdraw, out_mint)?,
+        providers_fee_amount: swap_payload.providers_fee_amount(),
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+    };
+    set_return_data(&quote.try_to_vec()?);
+    Ok(())
+}
+
+/// Result of a quoted `CpAmm` swap.
+///
+/// # Fields
+/// - `swapped_amount`: Amount of input tokens reaching the pool after the input transfer fee.
+/// - `amount_to_withdraw`: Amount of output tokens leaving the pool.
+/// - `received_amount`: Amount of output tokens the swapper receives after the output transfer fee.
+/// - `providers_fee_amount`: Providers fees collected from the swap.
+/// - `providers_fee_rate_basis_points`: Effective providers fee rate applied to the swap.
+/// - `protocol_fee_rate_basis_points`: Effective protocol fee rate applied to the swap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct SwapQuote {
+    pub swapped_amount: u64,
+    pub amount_to_withdraw: u64,
+    pub received_amount: u64,
+    pub providers_fee_amount: u64,
+    pub providers_fee_rate_basis_points: u16,
+    pub protocol_fee_rate_basis_points: u16,
+}
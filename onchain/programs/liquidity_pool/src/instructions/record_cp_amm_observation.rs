@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::state::cp_amm::{CpAmm, CpAmmObservations};
+
+#[derive(Accounts)]
+pub struct RecordCpAmmObservation<'info> {
+    #[account(
+        constraint = cp_amm.is_launched(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmmObservations::SEED, cp_amm.key().as_ref()],
+        bump = cp_amm_observations.bump()
+    )]
+    pub cp_amm_observations: Box<Account<'info, CpAmmObservations>>,
+}
+
+pub(crate) fn handler(ctx: Context<RecordCpAmmObservation>) -> Result<()> {
+    let observation = ctx.accounts.cp_amm.observe(Clock::get()?.unix_timestamp);
+    ctx.accounts.cp_amm_observations.record(observation)?;
+
+    msg!("Event: RecordCpAmmObservation");
+    emit!(
+        RecordCpAmmObservationEvent{
+            cp_amm: ctx.accounts.cp_amm.key(),
+            cumulative_base_per_quote_price: observation.cumulative_base_per_quote_price(),
+            cumulative_quote_per_base_price: observation.cumulative_quote_per_base_price(),
+            timestamp: observation.timestamp()
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct RecordCpAmmObservationEvent {
+    pub cp_amm: Pubkey,
+    pub cumulative_base_per_quote_price: Q64_128,
+    pub cumulative_quote_per_base_price: Q64_128,
+    pub timestamp: i64,
+}
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::constants::{ROUTED_SWAP_HOP_ACCOUNTS_COUNT, ROUTED_SWAP_MAX_HOPS};
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use utilities::token_instructions::{TransferTokensInstruction};
+use utilities::helpers::split_remaining_accounts;
+use crate::state::cp_amm::CpAmmCore;
+
+/// Routed swap through an ordered list of CpAmms.
+///
+/// Every hop is described by `ROUTED_SWAP_HOP_ACCOUNTS_COUNT` remaining accounts in the following order:
+/// 0. `amms_config` - AmmsConfig of the hop CpAmm.
+/// 1. `cp_amm` - `[writable]` CpAmm to swap in.
+/// 2. `in_mint` - Mint of the token sent to the CpAmm.
+/// 3. `out_mint` - Mint of the token received from the CpAmm.
+/// 4. `cp_amm_in_vault` - `[writable]` CpAmm vault of the `in_mint`.
+/// 5. `cp_amm_out_vault` - `[writable]` CpAmm vault of the `out_mint`.
+/// 6. `signer_in_account` - `[writable]` Signer token account of the `in_mint`.
+/// 7. `signer_out_account` - `[writable]` Signer token account of the `out_mint`.
+/// 8. `in_token_program` - Token program of the `in_mint`.
+/// 9. `out_token_program` - Token program of the `out_mint`.
+///
+/// The transfer hook accounts of the route mints follow the hops.
+#[derive(Accounts)]
+pub struct RoutedSwapInCpAmms<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RoutedSwapInCpAmms<'info>>, swap_amount: u64, min_result: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    let (hops_accounts, transfer_hook_accounts) = split_remaining_accounts::<AmmsConfig>(ctx.remaining_accounts, ROUTED_SWAP_HOP_ACCOUNTS_COUNT);
+    let hops_count = hops_accounts.len() / ROUTED_SWAP_HOP_ACCOUNTS_COUNT;
+    require!(
+        hops_count > 0 &&
+        hops_count <= ROUTED_SWAP_MAX_HOPS,
+        ErrorCode::InvalidRouteLength
+    );
+    let signer = ctx.accounts.signer.to_account_info();
+
+    let mut hop_amount = swap_amount;
+    let mut previous_hop_output: Option<(Pubkey, Pubkey)> = None;
+
+    for (hop_index, hop_accounts) in hops_accounts.chunks(ROUTED_SWAP_HOP_ACCOUNTS_COUNT).enumerate() {
+        let mut hop = Box::new(RoutedSwapHop::try_from_accounts(hop_accounts, signer.key)?);
+        if let Some((previous_out_mint, previous_signer_out_account)) = previous_hop_output {
+            require!(
+                previous_out_mint == hop.in_mint.key() && previous_signer_out_account == hop.signer_in_account.key(),
+                ErrorCode::RouteIsNotChained
+            );
+        }
+        let is_in_out = hop.is_in_out();
+
+        let in_transfer_instruction = Box::new(hop.get_in_transfer_instruction(hop_amount, signer.clone())?.with_transfer_hook_accounts(transfer_hook_accounts));
+
+        let swap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = hop.amms_config.effective_swap_fee_rates_basis_points(hop.cp_amm.volatility_accumulator(timestamp), timestamp);
+        let swap_payload = hop.cp_amm.get_routed_swap_payload(
+            swap_amount_after_fee,
+            providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points,
+            is_in_out
+        )?;
+        let amount_to_withdraw = swap_payload.amount_to_withdraw();
+
+        let out_transfer_instruction = Box::new(hop.get_out_transfer_instruction(amount_to_withdraw)?.with_transfer_hook_accounts(transfer_hook_accounts));
+        let received_amount = out_transfer_instruction.get_amount_after_fee();
+        in_transfer_instruction.execute(None)?;
+        let cp_amm_seeds = hop.cp_amm.seeds();
+        let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+        out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+        hop.cp_amm.update_cumulative_prices(timestamp);
+        let (previous_base_liquidity, previous_quote_liquidity) = (hop.cp_amm.base_liquidity(), hop.cp_amm.quote_liquidity());
+        hop.cp_amm.swap(swap_payload);
+        hop.cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
+        hop.cp_amm.exit(&crate::ID)?;
+        let cp_amm = &hop.cp_amm;
+
+        msg!("Event: RoutedSwapInCpAmm");
+        emit!(
+            RoutedSwapInCpAmmEvent{
+                swapper: signer.key(),
+                cp_amm: cp_amm.key(),
+                hop_index: hop_index as u8,
+                is_in_out,
+                swapped_amount: swap_amount_after_fee,
+                received_amount: amount_to_withdraw,
+                base_liquidity: cp_amm.base_liquidity(),
+                quote_liquidity: cp_amm.quote_liquidity(),
+                protocol_base_fees_to_redeem: cp_amm.protocol_base_fees_to_redeem(),
+                protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem(),
+                constant_product_sqrt: cp_amm.constant_product_sqrt(),
+                base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+                timestamp: Clock::get()?.unix_timestamp
+            }
+        );
+
+        hop_amount = received_amount;
+        previous_hop_output = Some((hop.out_mint.key(), hop.signer_out_account.key()));
+    }
+
+    require!(hop_amount >= min_result, ErrorCode::RoutedSwapResultBelowMinimum);
+
+    Ok(())
+}
+#[event]
+pub struct RoutedSwapInCpAmmEvent{
+    pub swapper: Pubkey,
+    pub cp_amm: Pubkey,
+    pub hop_index: u8,
+    pub is_in_out: bool,
+    pub swapped_amount: u64,
+    pub received_amount: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+
+/// Validated accounts of a single routed swap hop.
+struct RoutedSwapHop<'info>{
+    amms_config: Box<Account<'info, AmmsConfig>>,
+    cp_amm: Box<Account<'info, CpAmm>>,
+    in_mint: Box<InterfaceAccount<'info, Mint>>,
+    out_mint: Box<InterfaceAccount<'info, Mint>>,
+    cp_amm_in_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    cp_amm_out_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    signer_in_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    signer_out_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    in_token_program: Interface<'info, TokenInterface>,
+    out_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RoutedSwapHop<'info>{
+    fn try_from_accounts(accounts: &'info [AccountInfo<'info>], signer: &Pubkey) -> Result<Self>{
+        let hop = Self{
+            amms_config: Box::new(Account::try_from(&accounts[0])?),
+            cp_amm: Box::new(Account::try_from(&accounts[1])?),
+            in_mint: Box::new(InterfaceAccount::try_from(&accounts[2])?),
+            out_mint: Box::new(InterfaceAccount::try_from(&accounts[3])?),
+            cp_amm_in_vault: Box::new(InterfaceAccount::try_from(&accounts[4])?),
+            cp_amm_out_vault: Box::new(InterfaceAccount::try_from(&accounts[5])?),
+            signer_in_account: Box::new(InterfaceAccount::try_from(&accounts[6])?),
+            signer_out_account: Box::new(InterfaceAccount::try_from(&accounts[7])?),
+            in_token_program: Interface::try_from(&accounts[8])?,
+            out_token_program: Interface::try_from(&accounts[9])?,
+        };
+        hop.validate(signer)?;
+        Ok(hop)
+    }
+
+    fn validate(&self, signer: &Pubkey) -> Result<()>{
+        let cp_amm = &self.cp_amm;
+        require!(cp_amm.is_launched(), ErrorCode::CpAmmNotLaunched);
+        require!(self.cp_amm.to_account_info().is_writable, ErrorCode::InvalidRouteHopAccounts);
+        require!(self.amms_config.key() == cp_amm.amms_config().key(), ErrorCode::InvalidRouteHopAccounts);
+        require!(!self.amms_config.swaps_paused() && !cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+
+        let (in_mint, out_mint) = (self.in_mint.key(), self.out_mint.key());
+        let (in_vault, out_vault) = if in_mint == *cp_amm.base_mint() && out_mint == *cp_amm.quote_mint() {
+            (cp_amm.base_vault(), cp_amm.quote_vault())
+        }
+        else if in_mint == *cp_amm.quote_mint() && out_mint == *cp_amm.base_mint() {
+            (cp_amm.quote_vault(), cp_amm.base_vault())
+        }
+        else{
+            return err!(ErrorCode::InvalidRouteHopAccounts);
+        };
+        require!(self.cp_amm_in_vault.key() == *in_vault, ErrorCode::InvalidRouteHopAccounts);
+        require!(self.cp_amm_out_vault.key() == *out_vault, ErrorCode::InvalidRouteHopAccounts);
+
+        require!(self.signer_in_account.owner == *signer && self.signer_in_account.mint == in_mint, ErrorCode::InvalidRouteHopAccounts);
+        require!(self.signer_out_account.owner == *signer && self.signer_out_account.mint == out_mint, ErrorCode::InvalidRouteHopAccounts);
+        Ok(())
+    }
+
+    fn is_in_out(&self) -> bool{
+        self.in_mint.key() == *self.cp_amm.base_mint()
+    }
+
+    fn get_in_transfer_instruction(&self, in_amount: u64, signer: AccountInfo<'info>) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            in_amount,
+            &self.in_mint,
+            &self.signer_in_account,
+            signer,
+            &self.cp_amm_in_vault,
+            &self.in_token_program
+        )
+    }
+    fn get_out_transfer_instruction(&self, out_amount: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            out_amount,
+            &self.out_mint,
+            &self.cp_amm_out_vault,
+            self.cp_amm.to_account_info(),
+            &self.signer_out_account,
+            &self.out_token_program
+        )
+    }
+}
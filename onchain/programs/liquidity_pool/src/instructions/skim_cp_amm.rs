@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::{CpAmm, CpAmmCore};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Sends the tokens held by the CpAmm vaults above the stored liquidity and protocol fees
+/// into arbitrary token accounts of the fee authority's choice.
+#[derive(Accounts)]
+pub struct SkimCpAmm<'info> {
+    pub fee_authority: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = destination_base_account.mint == base_mint.key()
+    )]
+    pub destination_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = destination_quote_account.mint == quote_mint.key()
+    )]
+    pub destination_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Account<'info, AmmsConfig>,
+
+    #[account(
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SkimCpAmm<'info>>) -> Result<()> {
+    let skim_payload = ctx.accounts.cp_amm.get_skim_payload(ctx.accounts.cp_amm_base_vault.amount, ctx.accounts.cp_amm_quote_vault.amount)?;
+    let (base_excess, quote_excess) = (skim_payload.base_excess(), skim_payload.quote_excess());
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let skim_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    if base_excess > 0 {
+        ctx.accounts.get_skim_base_transfer_instruction(base_excess)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(skim_instruction_seeds))?;
+    }
+    if quote_excess > 0 {
+        ctx.accounts.get_skim_quote_transfer_instruction(quote_excess)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(skim_instruction_seeds))?;
+    }
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: SkimCpAmm");
+    emit!(
+        SkimCpAmmEvent{
+            signer: ctx.accounts.fee_authority.key(),
+            cp_amm: cp_amm.key(),
+            destination_base_account: ctx.accounts.destination_base_account.key(),
+            destination_quote_account: ctx.accounts.destination_quote_account.key(),
+            base_excess,
+            quote_excess,
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            constant_product_sqrt: cp_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+/// Reconciliation of the CpAmm vaults with the stored liquidity, the excess being transferred to the destination accounts.
+#[event]
+pub struct SkimCpAmmEvent{
+    pub signer: Pubkey,
+    pub cp_amm: Pubkey,
+    pub destination_base_account: Pubkey,
+    pub destination_quote_account: Pubkey,
+    pub base_excess: u64,
+    pub quote_excess: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+
+impl<'info> SkimCpAmm<'info> {
+    fn get_skim_base_transfer_instruction(&self, base_excess: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_excess,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_skim_quote_transfer_instruction(&self, quote_excess: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_excess,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
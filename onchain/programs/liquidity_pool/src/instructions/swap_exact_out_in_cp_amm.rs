@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use utilities::token_instructions::{TransferTokensInstruction};
+use crate::state::cp_amm::CpAmmCore;
+
+#[derive(Accounts)]
+pub struct SwapExactOutInCpAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SwapExactOutInCpAmm<'info>>, swap_result: u64, max_input: u64, is_in_out: bool, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+
+    // Token 2022 transfer fee is charged on top of the amount the signer has to receive
+    let swap_result_before_fee = TransferTokensInstruction::calculate_amount_before_fee(swap_result, ctx.accounts.get_out_mint(is_in_out))?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.effective_swap_fee_rates_basis_points(ctx.accounts.cp_amm.volatility_accumulator(timestamp), timestamp);
+    let swap_exact_out_payload = ctx.accounts.cp_amm.get_swap_exact_out_payload(
+        swap_result_before_fee,
+        max_input,
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        is_in_out
+    )?;
+    let required_swap_amount = swap_exact_out_payload.swap_amount();
+
+    // Token 2022 transfer fee is charged on top of the amount the pool has to receive
+    let swap_amount = TransferTokensInstruction::calculate_amount_before_fee(required_swap_amount, ctx.accounts.get_in_mint(is_in_out))?;
+    require!(swap_amount <= max_input, ErrorCode::SwapMaxInputExceeded);
+
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    require!(in_transfer_instruction.get_amount_after_fee() >= required_swap_amount, ErrorCode::BeforeswapCalculationFailed);
+
+    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_result_before_fee, is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let received_amount = out_transfer_instruction.get_amount_after_fee();
+    require!(received_amount >= swap_result, ErrorCode::BeforeswapCalculationFailed);
+    in_transfer_instruction.execute(None)?;
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+    out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
+    let (previous_base_liquidity, previous_quote_liquidity) = (ctx.accounts.cp_amm.base_liquidity(), ctx.accounts.cp_amm.quote_liquidity());
+    ctx.accounts.cp_amm.swap(swap_exact_out_payload.into_swap_payload());
+    ctx.accounts.cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: SwapExactOutInCpAmm");
+    emit!(
+        SwapExactOutInCpAmmEvent{
+            swapper: ctx.accounts.signer.key(),
+            cp_amm: cp_amm.key(),
+            is_in_out,
+            swapped_amount: swap_amount,
+            received_amount,
+            max_input,
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            protocol_base_fees_to_redeem: cp_amm.protocol_base_fees_to_redeem(),
+            protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem(),
+            constant_product_sqrt: cp_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+
+    Ok(())
+}
+#[event]
+pub struct SwapExactOutInCpAmmEvent{
+    pub swapper: Pubkey,
+    pub cp_amm: Pubkey,
+    pub is_in_out: bool,
+    pub swapped_amount: u64,
+    pub received_amount: u64,
+    pub max_input: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+impl<'info> SwapExactOutInCpAmm<'info>{
+    fn get_in_mint(&self, is_in_out: bool) -> &InterfaceAccount<'info, Mint>{
+        if is_in_out{
+            &self.base_mint
+        }
+        else{
+            &self.quote_mint
+        }
+    }
+    fn get_in_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.signer_base_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_base_vault,
+                &self.base_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.signer_quote_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_quote_vault,
+                &self.quote_token_program
+            )
+        }
+    }
+    fn get_out_mint(&self, is_in_out: bool) -> &InterfaceAccount<'info, Mint>{
+        if is_in_out{
+            &self.quote_mint
+        }
+        else{
+            &self.base_mint
+        }
+    }
+    fn get_out_transfer_instruction(&self, out_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.quote_mint,
+                &self.cp_amm_quote_vault,
+                self.cp_amm.to_account_info(),
+                &self.signer_quote_account,
+                &self.quote_token_program
+            )
+
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.base_mint,
+                &self.cp_amm_base_vault,
+                self.cp_amm.to_account_info(),
+                &self.signer_base_account,
+                &self.base_token_program
+            )
+        }
+    }
+}
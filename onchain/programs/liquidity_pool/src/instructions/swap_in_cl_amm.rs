@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::constants::CL_AMM_SWAP_MAX_TICK_ARRAYS;
+use crate::state::{AmmsConfig, cl_amm::{ClAmm, ClAmmCore, ClAmmTickArray}};
+use utilities::token_instructions::{TransferTokensInstruction};
+use utilities::helpers::split_remaining_accounts;
+use crate::error::ErrorCode;
+
+/// Swap in a ClAmm.
+///
+/// Tick arrays the swap can move the price through are passed as `[writable]` remaining accounts,
+/// starting with the array containing the current tick and following each other in the swap direction
+/// (downwards for base in, upwards for quote in). Up to `CL_AMM_SWAP_MAX_TICK_ARRAYS` arrays are accepted.
+/// The transfer hook accounts of the mints follow the tick arrays.
+#[derive(Accounts)]
+pub struct SwapInClAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cl_amm.is_initialized(),
+        constraint = amms_config.key() == cl_amm.amms_config().key(),
+        constraint = base_mint.key() == cl_amm.base_mint().key(),
+        constraint = quote_mint.key() == cl_amm.quote_mint().key(),
+        constraint = cl_amm_base_vault.key() == cl_amm.base_vault().key(),
+        constraint = cl_amm_quote_vault.key() == cl_amm.quote_vault().key(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.base_mint().as_ref()],
+        bump = cl_amm.base_vault_bump()
+    )]
+    pub cl_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.quote_mint().as_ref()],
+        bump = cl_amm.quote_vault_bump()
+    )]
+    pub cl_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInClAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    // ClAmm has no pool-level pause, it is paused through its configuration only
+    require!(!ctx.accounts.amms_config.swaps_paused(), ErrorCode::SwapsPaused);
+
+    let (tick_arrays_accounts, transfer_hook_accounts) = split_remaining_accounts::<ClAmmTickArray>(ctx.remaining_accounts, 1);
+    require!(
+        !tick_arrays_accounts.is_empty() && tick_arrays_accounts.len() <= CL_AMM_SWAP_MAX_TICK_ARRAYS,
+        ErrorCode::InvalidTickArrays
+    );
+    let mut tick_arrays = Vec::with_capacity(tick_arrays_accounts.len());
+    for tick_array_info in tick_arrays_accounts {
+        require!(tick_array_info.is_writable, ErrorCode::InvalidTickArrays);
+        let tick_array: Box<Account<'info, ClAmmTickArray>> = Box::new(Account::try_from(tick_array_info)?);
+        require!(tick_array.cl_amm().key() == ctx.accounts.cl_amm.key(), ErrorCode::InvalidTickArrays);
+        tick_arrays.push(tick_array);
+    }
+
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?.with_transfer_hook_accounts(transfer_hook_accounts));
+
+    let swap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.current_fee_rates_basis_points(Clock::get()?.unix_timestamp);
+    let swap_payload = ctx.accounts.cl_amm.get_swap_payload(
+        &tick_arrays.iter().map(|tick_array| &***tick_array).collect::<Vec<&ClAmmTickArray>>(),
+        swap_amount_after_fee,
+        estimated_result,
+        allowed_slippage,
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        is_in_out
+    )?;
+    let amount_to_withdraw = swap_payload.amount_to_withdraw();
+
+    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?.with_transfer_hook_accounts(transfer_hook_accounts));
+    in_transfer_instruction.execute(None)?;
+    let cl_amm_seeds = ctx.accounts.cl_amm.seeds();
+    let out_instruction_seeds: &[&[&[u8]]] = &[&cl_amm_seeds];
+    out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+    let tick_spacing = ctx.accounts.cl_amm.tick_spacing();
+    let crossed_ticks_count = swap_payload.crossed_ticks().len();
+    for crossed_tick in swap_payload.crossed_ticks() {
+        let start_tick = ClAmmTickArray::start_tick_for(crossed_tick.tick(), tick_spacing);
+        let tick_array = tick_arrays
+            .iter_mut()
+            .find(|tick_array| tick_array.start_tick() == start_tick)
+            .ok_or(ErrorCode::InvalidTickArrays)?;
+        tick_array.update_tick(crossed_tick.tick(), tick_spacing, crossed_tick.tick_state())?;
+    }
+    for tick_array in tick_arrays.iter() {
+        tick_array.exit(&crate::ID)?;
+    }
+
+    ctx.accounts.cl_amm.swap(swap_payload);
+    let cl_amm = &ctx.accounts.cl_amm;
+
+    msg!("Event: SwapInClAmm");
+    emit!(
+        SwapInClAmmEvent{
+            swapper: ctx.accounts.signer.key(),
+            cl_amm: cl_amm.key(),
+            is_in_out,
+            swapped_amount: swap_amount_after_fee,
+            received_amount: amount_to_withdraw,
+            estimated_result,
+            allowed_slippage,
+            crossed_ticks_count: crossed_ticks_count as u8,
+            sqrt_price: cl_amm.sqrt_price(),
+            tick_current: cl_amm.tick_current(),
+            liquidity: cl_amm.liquidity(),
+            base_liquidity: cl_amm.base_liquidity(),
+            quote_liquidity: cl_amm.quote_liquidity(),
+            protocol_base_fees_to_redeem: cl_amm.protocol_base_fees_to_redeem(),
+            protocol_quote_fees_to_redeem: cl_amm.protocol_quote_fees_to_redeem(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+
+    Ok(())
+}
+#[event]
+pub struct SwapInClAmmEvent{
+    pub swapper: Pubkey,
+    pub cl_amm: Pubkey,
+    pub is_in_out: bool,
+    pub swapped_amount: u64,
+    pub received_amount: u64,
+    pub estimated_result: u64,
+    pub allowed_slippage: u64,
+    pub crossed_ticks_count: u8,
+    pub sqrt_price: Q64_128,
+    pub tick_current: i32,
+    pub liquidity: u128,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub timestamp: i64
+}
+impl<'info> SwapInClAmm<'info>{
+    fn get_in_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.signer_base_account,
+                self.signer.to_account_info(),
+                &self.cl_amm_base_vault,
+                &self.base_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.signer_quote_account,
+                self.signer.to_account_info(),
+                &self.cl_amm_quote_vault,
+                &self.quote_token_program
+            )
+        }
+    }
+    fn get_out_transfer_instruction(&self, out_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.quote_mint,
+                &self.cl_amm_quote_vault,
+                self.cl_amm.to_account_info(),
+                &self.signer_quote_account,
+                &self.quote_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                out_amount,
+                &self.base_mint,
+                &self.cl_amm_base_vault,
+                self.cl_amm.to_account_info(),
+                &self.signer_base_account,
+                &self.base_token_program
+            )
+        }
+    }
+}
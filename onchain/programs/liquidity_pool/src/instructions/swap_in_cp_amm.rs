@@ -5,6 +5,7 @@ use utilities::math::Q64_128;
 use crate::state::{AmmsConfig, cp_amm::CpAmm};
 use utilities::token_instructions::{TransferTokensInstruction};
 use crate::state::cp_amm::CpAmmCore;
+use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 pub struct SwapInCpAmm<'info>{
@@ -70,27 +71,38 @@ pub struct SwapInCpAmm<'info>{
     pub system_program: Program<'info, System>,
 }
 
-pub(crate) fn handler(ctx: Context<SwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()> {
-    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?);
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInCpAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
 
     let swap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+    let timestamp = Clock::get()?.unix_timestamp;
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.effective_swap_fee_rates_basis_points(ctx.accounts.cp_amm.volatility_accumulator(timestamp), timestamp);
     let swap_payload = ctx.accounts.cp_amm.get_swap_payload(
         swap_amount_after_fee,
         estimated_result,
         allowed_slippage,
-        ctx.accounts.amms_config.providers_fee_rate_basis_points(),
-        ctx.accounts.amms_config.protocol_fee_rate_basis_points(),
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        0,
         is_in_out
     )?;
     let amount_to_withdraw = swap_payload.amount_to_withdraw();
 
-    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?);
+    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
     in_transfer_instruction.execute(None)?;
     let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
     let out_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
     out_transfer_instruction.execute(Some(out_instruction_seeds))?;
 
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
+    let (previous_base_liquidity, previous_quote_liquidity) = (ctx.accounts.cp_amm.base_liquidity(), ctx.accounts.cp_amm.quote_liquidity());
     ctx.accounts.cp_amm.swap(swap_payload);
+    ctx.accounts.cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
     let cp_amm = &ctx.accounts.cp_amm;
 
     msg!("Event: SwapInCpAmm");
@@ -109,6 +121,8 @@ pub(crate) fn handler(ctx: Context<SwapInCpAmm>, swap_amount: u64, estimated_res
             protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem(),
             constant_product_sqrt: cp_amm.constant_product_sqrt(),
             base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            referrer_account: None,
+            referrer_fee_amount: 0,
             timestamp: Clock::get()?.unix_timestamp
         }
     );
@@ -130,6 +144,8 @@ pub struct SwapInCpAmmEvent{
     pub protocol_quote_fees_to_redeem: u64,
     pub constant_product_sqrt: Q64_128,
     pub base_quote_ratio_sqrt: Q64_128,
+    pub referrer_account: Option<Pubkey>,
+    pub referrer_fee_amount: u64,
     pub timestamp: i64
 }
 impl<'info> SwapInCpAmm<'info>{
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, stable_amm::StableAmm};
+use utilities::token_instructions::{TransferTokensInstruction};
+use crate::state::stable_amm::StableAmmCore;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SwapInStableAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = stable_amm.is_launched(),
+        constraint = amms_config.key() == stable_amm.amms_config().key(),
+        constraint = base_mint.key() == stable_amm.base_mint().key(),
+        constraint = quote_mint.key() == stable_amm.quote_mint().key(),
+        constraint = stable_amm_base_vault.key() == stable_amm.base_vault().key(),
+        constraint = stable_amm_quote_vault.key() == stable_amm.quote_vault().key(),
+        seeds = [StableAmm::SEED, stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.bump()
+    )]
+    pub stable_amm: Box<Account<'info, StableAmm>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.base_mint().as_ref()],
+        bump = stable_amm.base_vault_bump()
+    )]
+    pub stable_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.quote_mint().as_ref()],
+        bump = stable_amm.quote_vault_bump()
+    )]
+    pub stable_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInStableAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    // StableAmm has no pool-level pause, it is paused through its configuration only
+    require!(!ctx.accounts.amms_config.swaps_paused(), ErrorCode::SwapsPaused);
+
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(swap_amount, is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    let swap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+    let timestamp = Clock::get()?.unix_timestamp;
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.current_fee_rates_basis_points(timestamp);
+    let swap_payload = ctx.accounts.stable_amm.get_swap_payload(
+        swap_amount_after_fee,
+        estimated_result,
+        allowed_slippage,
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        0,
+        is_in_out,
+        timestamp
+    )?;
+    let amount_to_withdraw = swap_payload.amount_to_withdraw();
+
+    let out_transfer_instruction = Box::new(ctx.accounts.get_out_transfer_instruction(swap_payload.amount_to_withdraw(), is_in_out)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    in_transfer_instruction.execute(None)?;
+    let stable_amm_seeds = ctx.accounts.stable_amm.seeds();
+    let out_instruction_seeds: &[&[&[u8]]] = &[&stable_amm_seeds];
+    out_transfer_instruction.execute(Some(out_instruction_seeds))?;
+
+    ctx.accounts.stable_amm.swap(swap_payload);
+    let stable_amm = &ctx.accounts.stable_amm;
+
+    msg!("Event: SwapInStableAmm");
+    emit!(
+        SwapInStableAmmEvent{
+            swapper: ctx.accounts.signer.key(),
+            stable_amm: stable_amm.key(),
+            is_in_out,
+            swapped_amount: swap_amount_after_fee,
+            received_amount: amount_to_withdraw,
+            estimated_result,
+            allowed_slippage,
+            base_liquidity: stable_amm.base_liquidity(),
+            quote_liquidity: stable_amm.quote_liquidity(),
+            protocol_base_fees_to_redeem: stable_amm.protocol_base_fees_to_redeem(),
+            protocol_quote_fees_to_redeem: stable_amm.protocol_quote_fees_to_redeem(),
+            constant_product_sqrt: stable_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: stable_amm.base_quote_ratio_sqrt(),
+            referrer_account: None,
+            referrer_fee_amount: 0,
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+
+    Ok(())
+}
+#[event]
+pub struct SwapInStableAmmEvent{
+    pub swapper: Pubkey,
+    pub stable_amm: Pubkey,
+    pub is_in_out: bool,
+    pub swapped_amount: u64,
+    pub received_amount: u64,
+    pub estimated_result: u64,
+    pub allowed_slippage: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub referrer_account: Option<Pubkey>,
+    pub referrer_fee_amount: u64,
+    pub timestamp: i64
+}
+impl<'info> SwapInStableAmm<'info>{
+    fn get_in_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.signer_base_account,
+                self.signer.to_account_info(),
+                &self.stable_amm_base_vault,
+                &self.base_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.signer_quote_account,
+                self.signer.to_account_info(),
+                &self.stable_amm_quote_vault,
+                &self.quote_token_program
+            )
+        }
+    }
+    fn get_out_transfer_instruction(&self, in_amount: u64, is_in_out: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_in_out{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.stable_amm_quote_vault,
+                self.stable_amm.to_account_info(),
+                &self.signer_quote_account,
+                &self.quote_token_program
+            )
+
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.stable_amm_base_vault,
+                self.stable_amm.to_account_info(),
+                &self.signer_base_account,
+                &self.base_token_program
+            )
+        }
+    }
+}
\ No newline at end of file
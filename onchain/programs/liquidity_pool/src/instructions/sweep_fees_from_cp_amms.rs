@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::constants::{FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT, FEES_SWEEP_MAX_CP_AMMS};
+use crate::error::ErrorCode;
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::CpAmm;
+use crate::instructions::ClaimFeesFromCpAmmEvent;
+use utilities::token_instructions::TransferTokensInstruction;
+use utilities::helpers::split_remaining_accounts;
+
+/// Collects all protocol fees from a list of CpAmms of a single AmmsConfig.
+///
+/// Every CpAmm is described by `FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT` remaining accounts in the following order:
+/// 0. `cp_amm` - `[writable]` CpAmm to collect fees from.
+/// 1. `base_mint` - Base mint of the CpAmm.
+/// 2. `quote_mint` - Quote mint of the CpAmm.
+/// 3. `cp_amm_base_vault` - `[writable]` CpAmm vault of the `base_mint`.
+/// 4. `cp_amm_quote_vault` - `[writable]` CpAmm vault of the `quote_mint`.
+/// 5. `destination_base_account` - `[writable]` Token account of the `base_mint` receiving the fees.
+/// 6. `destination_quote_account` - `[writable]` Token account of the `quote_mint` receiving the fees.
+/// 7. `base_token_program` - Token program of the `base_mint`.
+/// 8. `quote_token_program` - Token program of the `quote_mint`.
+///
+/// The transfer hook accounts of the mints follow the CpAmms.
+/// CpAmms without fees to redeem are skipped.
+#[derive(Accounts)]
+pub struct SweepFeesFromCpAmms<'info> {
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+        constraint = amms_config.fee_authority().key() == fee_authority.key(),
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Account<'info, AmmsConfig>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SweepFeesFromCpAmms<'info>>) -> Result<()> {
+    let (cp_amms_accounts, transfer_hook_accounts) = split_remaining_accounts::<CpAmm>(ctx.remaining_accounts, FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT);
+    let cp_amms_count = cp_amms_accounts.len() / FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT;
+    require!(
+        cp_amms_count > 0 &&
+        cp_amms_count <= FEES_SWEEP_MAX_CP_AMMS,
+        ErrorCode::InvalidFeesSweepLength
+    );
+    let fee_authority = ctx.accounts.fee_authority.key();
+    let amms_config = ctx.accounts.amms_config.key();
+
+    for cp_amm_accounts in cp_amms_accounts.chunks(FEES_SWEEP_CP_AMM_ACCOUNTS_COUNT) {
+        let mut sweep = Box::new(FeesSweepCpAmm::try_from_accounts(cp_amm_accounts, &amms_config)?);
+        let (base_fees, quote_fees) = (sweep.cp_amm.protocol_base_fees_to_redeem(), sweep.cp_amm.protocol_quote_fees_to_redeem());
+        if base_fees == 0 && quote_fees == 0 {
+            continue;
+        }
+        let collect_fees_payload = sweep.cp_amm.get_collect_fees_payload()?;
+
+        let cp_amm_seeds = sweep.cp_amm.seeds();
+        let collect_fees_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+        if base_fees > 0{
+            sweep.get_base_fees_transfer_instruction(base_fees)?.with_transfer_hook_accounts(transfer_hook_accounts).execute(Some(collect_fees_instruction_seeds))?;
+        }
+        if quote_fees > 0{
+            sweep.get_quote_fees_transfer_instruction(quote_fees)?.with_transfer_hook_accounts(transfer_hook_accounts).execute(Some(collect_fees_instruction_seeds))?;
+        }
+
+        sweep.cp_amm.collect_fees(collect_fees_payload);
+        sweep.cp_amm.exit(&crate::ID)?;
+
+        msg!("Event: ClaimFeesFromCpAmm");
+        emit!(
+            ClaimFeesFromCpAmmEvent{
+                signer: fee_authority,
+                cp_amm: sweep.cp_amm.key(),
+                fee_authority,
+                destination_base_account: sweep.destination_base_account.key(),
+                destination_quote_account: sweep.destination_quote_account.key(),
+                withdrawn_protocol_base_fees: base_fees,
+                withdrawn_protocol_quote_fees: quote_fees,
+                timestamp: Clock::get()?.unix_timestamp
+            }
+        );
+    }
+
+    Ok(())
+}
+
+/// Validated accounts of a single CpAmm of a protocol fees sweep.
+struct FeesSweepCpAmm<'info>{
+    cp_amm: Box<Account<'info, CpAmm>>,
+    base_mint: Box<InterfaceAccount<'info, Mint>>,
+    quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    destination_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    destination_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    base_token_program: Interface<'info, TokenInterface>,
+    quote_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FeesSweepCpAmm<'info>{
+    fn try_from_accounts(accounts: &'info [AccountInfo<'info>], amms_config: &Pubkey) -> Result<Self>{
+        let sweep = Self{
+            cp_amm: Box::new(Account::try_from(&accounts[0])?),
+            base_mint: Box::new(InterfaceAccount::try_from(&accounts[1])?),
+            quote_mint: Box::new(InterfaceAccount::try_from(&accounts[2])?),
+            cp_amm_base_vault: Box::new(InterfaceAccount::try_from(&accounts[3])?),
+            cp_amm_quote_vault: Box::new(InterfaceAccount::try_from(&accounts[4])?),
+            destination_base_account: Box::new(InterfaceAccount::try_from(&accounts[5])?),
+            destination_quote_account: Box::new(InterfaceAccount::try_from(&accounts[6])?),
+            base_token_program: Interface::try_from(&accounts[7])?,
+            quote_token_program: Interface::try_from(&accounts[8])?,
+        };
+        sweep.validate(amms_config)?;
+        Ok(sweep)
+    }
+
+    fn validate(&self, amms_config: &Pubkey) -> Result<()>{
+        let cp_amm = &self.cp_amm;
+        require!(cp_amm.is_launched(), ErrorCode::CpAmmNotLaunched);
+        require!(self.cp_amm.to_account_info().is_writable, ErrorCode::InvalidFeesSweepAccounts);
+        require!(cp_amm.amms_config() == amms_config, ErrorCode::InvalidFeesSweepAccounts);
+        require!(
+            self.base_mint.key() == *cp_amm.base_mint() && self.quote_mint.key() == *cp_amm.quote_mint(),
+            ErrorCode::InvalidFeesSweepAccounts
+        );
+        require!(
+            self.cp_amm_base_vault.key() == *cp_amm.base_vault() && self.cp_amm_quote_vault.key() == *cp_amm.quote_vault(),
+            ErrorCode::InvalidFeesSweepAccounts
+        );
+        require!(
+            self.destination_base_account.mint == self.base_mint.key() && self.destination_quote_account.mint == self.quote_mint.key(),
+            ErrorCode::InvalidFeesSweepAccounts
+        );
+        Ok(())
+    }
+
+    fn get_base_fees_transfer_instruction(&self, base_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_fees,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_quote_fees_transfer_instruction(&self, quote_fees: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_fees,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.destination_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
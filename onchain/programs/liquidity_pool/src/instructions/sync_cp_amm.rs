@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use utilities::math::Q64_128;
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::{CpAmm, CpAmmCore};
+use crate::error::ErrorCode;
+
+/// Folds the tokens held by the CpAmm vaults above the stored liquidity and protocol fees into the liquidity,
+/// e.g. tokens transferred to the vaults directly.
+///
+/// Permissionless, as the excess is credited to the liquidity providers.
+#[derive(Accounts)]
+pub struct SyncCpAmm<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub(crate) fn handler(ctx: Context<SyncCpAmm>) -> Result<()> {
+    // Folding the excess in moves the price, so a sync is held back like a swap
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+    let timestamp = Clock::get()?.unix_timestamp;
+    let sync_payload = ctx.accounts.cp_amm.get_sync_payload(ctx.accounts.cp_amm_base_vault.amount, ctx.accounts.cp_amm_quote_vault.amount)?;
+    let (base_excess, quote_excess) = (sync_payload.base_excess(), sync_payload.quote_excess());
+
+    // The excess is not a swap fee, so the protocol share is settled before it is folded in
+    let volatility_accumulator = ctx.accounts.cp_amm.volatility_accumulator(timestamp);
+    let protocol_fee_on_lp_enabled = ctx.accounts.amms_config.protocol_fee_on_lp_enabled();
+    ctx.accounts.cp_amm.accrue_protocol_lp_fees(
+        protocol_fee_on_lp_enabled,
+        ctx.accounts.amms_config.effective_providers_fee_rate_basis_points(volatility_accumulator, timestamp),
+        ctx.accounts.amms_config.current_protocol_fee_rate_basis_points(timestamp)
+    )?;
+
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
+    let (previous_base_liquidity, previous_quote_liquidity) = (ctx.accounts.cp_amm.base_liquidity(), ctx.accounts.cp_amm.quote_liquidity());
+    ctx.accounts.cp_amm.sync(sync_payload);
+    // Donations followed by a sync move the price like a swap, so they feed the dynamic fee
+    ctx.accounts.cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(protocol_fee_on_lp_enabled);
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: SyncCpAmm");
+    emit!(
+        SyncCpAmmEvent{
+            signer: ctx.accounts.signer.key(),
+            cp_amm: cp_amm.key(),
+            base_excess,
+            quote_excess,
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            constant_product_sqrt: cp_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+/// Reconciliation of the CpAmm vaults with the stored liquidity, the excess being folded into the liquidity.
+#[event]
+pub struct SyncCpAmmEvent{
+    pub signer: Pubkey,
+    pub cp_amm: Pubkey,
+    pub base_excess: u64,
+    pub quote_excess: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, cl_amm::{ClAmm, ClAmmCore, ClAmmPosition, ClAmmTick, ClAmmTickArray}};
+use utilities::token_instructions::TransferTokensInstruction;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawFromClAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cl_amm.is_initialized(),
+        constraint = amms_config.key() == cl_amm.amms_config().key(),
+        constraint = base_mint.key() == cl_amm.base_mint().key(),
+        constraint = quote_mint.key() == cl_amm.quote_mint().key(),
+        constraint = cl_amm_base_vault.key() == cl_amm.base_vault().key(),
+        constraint = cl_amm_quote_vault.key() == cl_amm.quote_vault().key(),
+        seeds = [ClAmm::SEED, cl_amm.amms_config().as_ref(), cl_amm.base_mint().as_ref(), cl_amm.quote_mint().as_ref(), cl_amm.tick_spacing().to_le_bytes().as_ref()],
+        bump = cl_amm.bump()
+    )]
+    pub cl_amm: Box<Account<'info, ClAmm>>,
+
+    #[account(
+        mut,
+        constraint = position.cl_amm().key() == cl_amm.key(),
+        constraint = position.owner().key() == signer.key(),
+    )]
+    pub position: Box<Account<'info, ClAmmPosition>>,
+
+    #[account(
+        mut,
+        constraint = tick_array_lower.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_lower: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        mut,
+        constraint = tick_array_upper.cl_amm().key() == cl_amm.key(),
+    )]
+    pub tick_array_upper: Box<Account<'info, ClAmmTickArray>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.base_mint().as_ref()],
+        bump = cl_amm.base_vault_bump()
+    )]
+    pub cl_amm_base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ClAmm::VAULT_SEED, cl_amm.key().as_ref(), cl_amm.quote_mint().as_ref()],
+        bump = cl_amm.quote_vault_bump()
+    )]
+    pub cl_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromClAmm<'info>>, liquidity: u128, min_base_amount: u64, min_quote_amount: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    let tick_spacing = ctx.accounts.cl_amm.tick_spacing();
+    let (tick_lower, tick_upper) = (ctx.accounts.position.tick_lower(), ctx.accounts.position.tick_upper());
+    let tick_lower_state = *ctx.accounts.tick_array_lower.tick(tick_lower, tick_spacing)?;
+    let tick_upper_state = *ctx.accounts.tick_array_upper.tick(tick_upper, tick_spacing)?;
+
+    let withdraw_payload = ctx.accounts.cl_amm.get_withdraw_payload(
+        &ctx.accounts.position,
+        &tick_lower_state,
+        &tick_upper_state,
+        liquidity,
+        min_base_amount,
+        min_quote_amount
+    )?;
+    let (base_amount, quote_amount) = (withdraw_payload.base_amount(), withdraw_payload.quote_amount());
+
+    let cl_amm_seeds = ctx.accounts.cl_amm.seeds();
+    let withdraw_instruction_seeds: &[&[&[u8]]] = &[&cl_amm_seeds];
+    if base_amount > 0 {
+        ctx.accounts.get_withdraw_base_liquidity_transfer_instruction(base_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(withdraw_instruction_seeds))?;
+    }
+    if quote_amount > 0 {
+        ctx.accounts.get_withdraw_quote_liquidity_transfer_instruction(quote_amount)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(withdraw_instruction_seeds))?;
+    }
+
+    ctx.accounts.update_ticks(withdraw_payload.tick_lower(), withdraw_payload.tick_upper())?;
+    ctx.accounts.position.update(withdraw_payload.position());
+    ctx.accounts.cl_amm.modify_liquidity(withdraw_payload);
+    let cl_amm = &ctx.accounts.cl_amm;
+
+    msg!("Event: WithdrawFromClAmm");
+    emit!(
+        WithdrawFromClAmmEvent{
+            provider: ctx.accounts.signer.key(),
+            cl_amm: cl_amm.key(),
+            position: ctx.accounts.position.key(),
+            tick_lower,
+            tick_upper,
+            withdrawn_liquidity: liquidity,
+            withdrawn_base_liquidity: base_amount,
+            withdrawn_quote_liquidity: quote_amount,
+            position_liquidity: ctx.accounts.position.liquidity(),
+            liquidity: cl_amm.liquidity(),
+            base_liquidity: cl_amm.base_liquidity(),
+            quote_liquidity: cl_amm.quote_liquidity(),
+            sqrt_price: cl_amm.sqrt_price(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawFromClAmmEvent{
+    pub provider: Pubkey,
+    pub cl_amm: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub withdrawn_liquidity: u128,
+    pub withdrawn_base_liquidity: u64,
+    pub withdrawn_quote_liquidity: u64,
+    pub position_liquidity: u128,
+    pub liquidity: u128,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub sqrt_price: Q64_128,
+    pub timestamp: i64
+}
+
+impl<'info> WithdrawFromClAmm<'info> {
+    fn update_ticks(&mut self, tick_lower_state: ClAmmTick, tick_upper_state: ClAmmTick) -> Result<()> {
+        let tick_spacing = self.cl_amm.tick_spacing();
+        let (tick_lower, tick_upper) = (self.position.tick_lower(), self.position.tick_upper());
+        self.tick_array_lower.update_tick(tick_lower, tick_spacing, tick_lower_state)?;
+        self.tick_array_upper.update_tick(tick_upper, tick_spacing, tick_upper_state)?;
+        // Both ticks can belong to the same tick array, both copies are written back on exit
+        if self.tick_array_lower.key() == self.tick_array_upper.key() {
+            self.tick_array_lower.update_tick(tick_upper, tick_spacing, tick_upper_state)?;
+            self.tick_array_upper.update_tick(tick_lower, tick_spacing, tick_lower_state)?;
+        }
+        Ok(())
+    }
+    fn get_withdraw_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.cl_amm_base_vault,
+            self.cl_amm.to_account_info(),
+            &self.signer_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_withdraw_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.cl_amm_quote_vault,
+            self.cl_amm.to_account_info(),
+            &self.signer_quote_account,
+            &self.quote_token_program
+        )
+    }
+}
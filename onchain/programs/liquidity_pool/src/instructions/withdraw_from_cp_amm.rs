@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token;
-use anchor_spl::token::Token;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use utilities::math::Q64_128;
 use crate::state::{AmmsConfig, cp_amm::CpAmm};
 use utilities::token_instructions::{BurnTokensInstructions, TransferTokensInstruction};
 use crate::state::cp_amm::CpAmmCore;
+use crate::error::ErrorCode;
 
 #[derive(Accounts)]
 pub struct WithdrawFromCpAmm<'info>{
@@ -15,7 +14,7 @@ pub struct WithdrawFromCpAmm<'info>{
     pub base_mint: Box<InterfaceAccount<'info, Mint>>,
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(mut)]
-    pub lp_mint: Box<Account<'info, token::Mint>>,
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
@@ -37,7 +36,7 @@ pub struct WithdrawFromCpAmm<'info>{
 
     #[account(mut)]
     // Token program will check mint and authority via token_instructions instruction
-    pub signer_lp_account: Box<Account<'info, token::TokenAccount>>,
+    pub signer_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
@@ -74,21 +73,37 @@ pub struct WithdrawFromCpAmm<'info>{
     pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub lp_token_program: Program<'info, Token>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
     pub base_token_program: Interface<'info, TokenInterface>,
     pub quote_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub(crate) fn handler(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result<()> {
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmm<'info>>, lp_tokens: u64, min_base_out: u64, min_quote_out: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    let timestamp = Clock::get()?.unix_timestamp;
+    let volatility_accumulator = ctx.accounts.cp_amm.volatility_accumulator(timestamp);
+    let protocol_fee_on_lp_enabled = ctx.accounts.amms_config.protocol_fee_on_lp_enabled();
+    ctx.accounts.cp_amm.accrue_protocol_lp_fees(
+        protocol_fee_on_lp_enabled,
+        ctx.accounts.amms_config.effective_providers_fee_rate_basis_points(volatility_accumulator, timestamp),
+        ctx.accounts.amms_config.current_protocol_fee_rate_basis_points(timestamp)
+    )?;
+
     let liquidity_burn_instruction = Box::new(ctx.accounts.get_liquidity_burn_instruction(lp_tokens)?);
-    
-    let withdraw_payload = ctx.accounts.cp_amm.get_withdraw_payload(lp_tokens)?;
+
+    let withdraw_payload = ctx.accounts.cp_amm.get_withdraw_payload(lp_tokens, min_base_out, min_quote_out)?;
     let base_liquidity_to_withdraw = withdraw_payload.base_withdraw_amount();
     let quote_liquidity_to_withdraw = withdraw_payload.quote_withdraw_amount();
 
-    let withdraw_base_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_base_liquidity_transfer_instruction(withdraw_payload.base_withdraw_amount())?);
-    let withdraw_quote_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_quote_liquidity_transfer_instruction(withdraw_payload.quote_withdraw_amount())?);
+    let withdraw_base_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_base_liquidity_transfer_instruction(withdraw_payload.base_withdraw_amount())?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let withdraw_quote_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_quote_liquidity_transfer_instruction(withdraw_payload.quote_withdraw_amount())?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    // Minimums are checked against what the signer receives, after the mints transfer fees
+    require!(withdraw_base_liquidity_instruction.get_amount_after_fee() >= min_base_out, ErrorCode::WithdrawBaseBelowMinimum);
+    require!(withdraw_quote_liquidity_instruction.get_amount_after_fee() >= min_quote_out, ErrorCode::WithdrawQuoteBelowMinimum);
 
     liquidity_burn_instruction.execute(None)?;
 
@@ -98,7 +113,9 @@ pub(crate) fn handler(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result
     withdraw_base_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
     withdraw_quote_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
 
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
     ctx.accounts.cp_amm.withdraw(withdraw_payload);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(protocol_fee_on_lp_enabled);
     let cp_amm = &ctx.accounts.cp_amm;
 
     msg!("Event: WithdrawFromCpAmm");
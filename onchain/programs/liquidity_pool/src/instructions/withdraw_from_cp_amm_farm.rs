@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::cp_amm::{CpAmmFarm, CpAmmFarmPosition};
+use utilities::token_instructions::TransferTokensInstruction;
+
+/// Unstakes LP tokens from a `CpAmmFarm`. The rewards earned so far stay harvestable.
+#[derive(Accounts)]
+pub struct WithdrawFromCpAmmFarm<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = lp_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = lp_token_program
+    )]
+    pub owner_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = lp_mint.key() == cp_amm_farm.lp_mint().key(),
+        seeds = [CpAmmFarm::SEED, cp_amm_farm.cp_amm().as_ref(), cp_amm_farm.authority().as_ref()],
+        bump = cp_amm_farm.bump()
+    )]
+    pub cp_amm_farm: Box<Account<'info, CpAmmFarm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmmFarmPosition::SEED, cp_amm_farm.key().as_ref(), owner.key().as_ref()],
+        bump = cp_amm_farm_position.bump()
+    )]
+    pub cp_amm_farm_position: Box<Account<'info, CpAmmFarmPosition>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = cp_amm_farm,
+        associated_token::token_program = lp_token_program
+    )]
+    pub cp_amm_farm_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmmFarm<'info>>, lp_tokens: u64) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    let cp_amm_farm = &mut ctx.accounts.cp_amm_farm;
+    let cp_amm_farm_position = &mut ctx.accounts.cp_amm_farm_position;
+    cp_amm_farm.accrue_rewards(timestamp)?;
+    cp_amm_farm_position.withdraw(lp_tokens, &cp_amm_farm.rewards_per_token())?;
+    cp_amm_farm.withdraw(lp_tokens);
+
+    let cp_amm_farm_seeds = ctx.accounts.cp_amm_farm.seeds();
+    ctx.accounts.get_withdraw_transfer_instruction(lp_tokens)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(&[&cp_amm_farm_seeds]))?;
+
+    msg!("Event: WithdrawFromCpAmmFarm");
+    emit!(
+        WithdrawFromCpAmmFarmEvent{
+            owner: ctx.accounts.owner.key(),
+            cp_amm_farm: ctx.accounts.cp_amm_farm.key(),
+            cp_amm_farm_position: ctx.accounts.cp_amm_farm_position.key(),
+            lp_tokens,
+            position_staked_lp_tokens: ctx.accounts.cp_amm_farm_position.staked_lp_tokens(),
+            farm_staked_lp_tokens: ctx.accounts.cp_amm_farm.staked_lp_tokens(),
+            timestamp
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawFromCpAmmFarmEvent{
+    pub owner: Pubkey,
+    pub cp_amm_farm: Pubkey,
+    pub cp_amm_farm_position: Pubkey,
+    pub lp_tokens: u64,
+    pub position_staked_lp_tokens: u64,
+    pub farm_staked_lp_tokens: u64,
+    pub timestamp: i64
+}
+
+impl<'info> WithdrawFromCpAmmFarm<'info> {
+    #[inline(never)]
+    fn get_withdraw_transfer_instruction(&self, lp_tokens: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>> {
+        TransferTokensInstruction::try_new(
+            lp_tokens,
+            &self.lp_mint,
+            &self.cp_amm_farm_lp_vault,
+            self.cp_amm_farm.to_account_info(),
+            &self.owner_lp_account,
+            &self.lp_token_program
+        )
+    }
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, stable_amm::StableAmm};
+use utilities::token_instructions::{BurnTokensInstructions, TransferTokensInstruction};
+use crate::state::stable_amm::StableAmmCore;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawFromStableAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    // Token program will check mint and authority via token_instructions instruction
+    pub signer_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = stable_amm.is_launched(),
+        constraint = amms_config.key() == stable_amm.amms_config().key(),
+        constraint = lp_mint.key() == stable_amm.lp_mint,
+        constraint = base_mint.key() == stable_amm.base_mint().key(),
+        constraint = quote_mint.key() == stable_amm.quote_mint().key(),
+        constraint = stable_amm_base_vault.key() == stable_amm.base_vault().key(),
+        constraint = stable_amm_quote_vault.key() == stable_amm.quote_vault().key(),
+        seeds = [StableAmm::SEED, stable_amm.lp_mint.as_ref()],
+        bump = stable_amm.bump()
+    )]
+    pub stable_amm: Box<Account<'info, StableAmm>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.base_mint().as_ref()],
+        bump = stable_amm.base_vault_bump()
+    )]
+    pub stable_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [StableAmm::VAULT_SEED, stable_amm.key().as_ref(), stable_amm.quote_mint().as_ref()],
+        bump = stable_amm.quote_vault_bump()
+    )]
+    pub stable_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromStableAmm<'info>>, lp_tokens: u64, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    let liquidity_burn_instruction = Box::new(ctx.accounts.get_liquidity_burn_instruction(lp_tokens)?);
+    
+    let withdraw_payload = ctx.accounts.stable_amm.get_withdraw_payload(lp_tokens)?;
+    let base_liquidity_to_withdraw = withdraw_payload.base_withdraw_amount();
+    let quote_liquidity_to_withdraw = withdraw_payload.quote_withdraw_amount();
+
+    let withdraw_base_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_base_liquidity_transfer_instruction(withdraw_payload.base_withdraw_amount())?.with_transfer_hook_accounts(ctx.remaining_accounts));
+    let withdraw_quote_liquidity_instruction = Box::new(ctx.accounts.get_withdraw_quote_liquidity_transfer_instruction(withdraw_payload.quote_withdraw_amount())?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    liquidity_burn_instruction.execute(None)?;
+
+    let stable_amm_seeds = ctx.accounts.stable_amm.seeds();
+    let withdraw_instruction_seeds: &[&[&[u8]]] = &[&stable_amm_seeds];
+
+    withdraw_base_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
+    withdraw_quote_liquidity_instruction.execute(Some(withdraw_instruction_seeds))?;
+
+    ctx.accounts.stable_amm.withdraw(withdraw_payload);
+    let stable_amm = &ctx.accounts.stable_amm;
+
+    msg!("Event: WithdrawFromStableAmm");
+    emit!(
+        WithdrawFromStableAmmEvent{
+            withdrawer: ctx.accounts.signer.key(),
+            stable_amm: stable_amm.key(),
+            withdrawn_base_liquidity: base_liquidity_to_withdraw,
+            withdrawn_quote_liquidity: quote_liquidity_to_withdraw,
+            lp_tokens_burned: lp_tokens,
+            base_liquidity: stable_amm.base_liquidity(),
+            quote_liquidity: stable_amm.quote_liquidity(),
+            lp_tokens_supply: stable_amm.lp_tokens_supply(),
+            constant_product_sqrt: stable_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: stable_amm.base_quote_ratio_sqrt(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+#[event]
+pub struct WithdrawFromStableAmmEvent{
+    pub withdrawer: Pubkey,
+    pub stable_amm: Pubkey,
+    pub lp_tokens_burned: u64,
+    pub withdrawn_base_liquidity: u64,
+    pub withdrawn_quote_liquidity: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub lp_tokens_supply: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+impl<'info> WithdrawFromStableAmm<'info>{
+    fn get_withdraw_base_liquidity_transfer_instruction(&self, base_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_liquidity,
+            &self.base_mint,
+            &self.stable_amm_base_vault,
+            self.stable_amm.to_account_info(),
+            &self.signer_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_withdraw_quote_liquidity_transfer_instruction(&self, quote_liquidity: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_liquidity,
+            &self.quote_mint,
+            &self.stable_amm_quote_vault,
+            self.stable_amm.to_account_info(),
+            &self.signer_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_liquidity_burn_instruction(&self, liquidity: u64) -> Result<BurnTokensInstructions<'_, '_, '_, 'info>>{
+        BurnTokensInstructions::try_new(
+            liquidity,
+            &self.lp_mint,
+            &self.signer_lp_account,
+            self.signer.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+}
\ No newline at end of file
@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use utilities::math::Q64_128;
+use crate::state::{AmmsConfig, cp_amm::CpAmm};
+use utilities::token_instructions::{MintTokensInstructions, TransferTokensInstruction};
+use crate::state::cp_amm::CpAmmCore;
+use crate::error::ErrorCode;
+
+#[derive(Accounts)]
+pub struct ZapInToCpAmm<'info>{
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = base_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = base_token_program
+    )]
+    pub signer_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = quote_token_program
+    )]
+    pub signer_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = lp_token_program
+    )]
+    pub signer_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [AmmsConfig::SEED, amms_config.id.to_le_bytes().as_ref()],
+        bump = amms_config.bump()
+    )]
+    pub amms_config: Box<Account<'info, AmmsConfig>>,
+
+    #[account(
+        mut,
+        constraint = cp_amm.is_launched(),
+        constraint = amms_config.key() == cp_amm.amms_config().key(),
+        constraint = lp_mint.key() == cp_amm.lp_mint,
+        constraint = base_mint.key() == cp_amm.base_mint().key(),
+        constraint = quote_mint.key() == cp_amm.quote_mint().key(),
+        constraint = cp_amm_base_vault.key() == cp_amm.base_vault().key(),
+        constraint = cp_amm_quote_vault.key() == cp_amm.quote_vault().key(),
+        seeds = [CpAmm::SEED, cp_amm.lp_mint.as_ref()],
+        bump = cp_amm.bump()
+    )]
+    pub cp_amm: Box<Account<'info, CpAmm>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.base_mint().as_ref()],
+        bump = cp_amm.base_vault_bump()
+    )]
+    pub cp_amm_base_vault:Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [CpAmm::VAULT_SEED, cp_amm.key().as_ref(), cp_amm.quote_mint().as_ref()],
+        bump = cp_amm.quote_vault_bump()
+    )]
+    pub cp_amm_quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub lp_token_program: Interface<'info, TokenInterface>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ZapInToCpAmm<'info>>, zap_amount: u64, min_lp_tokens: u64, is_base_in: bool, deadline: Option<i64>) -> Result<()> {
+    if let Some(deadline) = deadline {
+        require!(Clock::get()?.unix_timestamp <= deadline, ErrorCode::DeadlineExceeded);
+    }
+    require!(!ctx.accounts.amms_config.swaps_paused() && !ctx.accounts.cp_amm.swaps_paused(), ErrorCode::SwapsPaused);
+    require!(!ctx.accounts.amms_config.provides_paused() && !ctx.accounts.cp_amm.provides_paused(), ErrorCode::ProvidesPaused);
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let volatility_accumulator = ctx.accounts.cp_amm.volatility_accumulator(timestamp);
+    let protocol_fee_on_lp_enabled = ctx.accounts.amms_config.protocol_fee_on_lp_enabled();
+    ctx.accounts.cp_amm.accrue_protocol_lp_fees(
+        protocol_fee_on_lp_enabled,
+        ctx.accounts.amms_config.effective_providers_fee_rate_basis_points(volatility_accumulator, timestamp),
+        ctx.accounts.amms_config.current_protocol_fee_rate_basis_points(timestamp)
+    )?;
+
+    let in_transfer_instruction = Box::new(ctx.accounts.get_in_transfer_instruction(zap_amount, is_base_in)?.with_transfer_hook_accounts(ctx.remaining_accounts));
+
+    let zap_amount_after_fee = in_transfer_instruction.get_amount_after_fee();
+    let (providers_fee_rate_basis_points, protocol_fee_rate_basis_points) = ctx.accounts.amms_config.effective_swap_fee_rates_basis_points(volatility_accumulator, timestamp);
+    let zap_in_payload = ctx.accounts.cp_amm.get_zap_in_payload(
+        zap_amount_after_fee,
+        min_lp_tokens,
+        providers_fee_rate_basis_points,
+        protocol_fee_rate_basis_points,
+        is_base_in
+    )?;
+    let swapped_amount = zap_in_payload.swap_amount();
+    let provided_base_liquidity = zap_in_payload.provided_base_liquidity();
+    let provided_quote_liquidity = zap_in_payload.provided_quote_liquidity();
+    let base_dust = zap_in_payload.base_dust();
+    let quote_dust = zap_in_payload.quote_dust();
+    let lp_tokens_to_mint = zap_in_payload.lp_tokens_to_mint();
+
+    in_transfer_instruction.execute(None)?;
+
+    let cp_amm_seeds = ctx.accounts.cp_amm.seeds();
+    let cp_amm_instruction_seeds: &[&[&[u8]]] = &[&cp_amm_seeds];
+
+    if base_dust > 0 {
+        ctx.accounts.get_base_dust_transfer_instruction(base_dust)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(cp_amm_instruction_seeds))?;
+    }
+    if quote_dust > 0 {
+        ctx.accounts.get_quote_dust_transfer_instruction(quote_dust)?.with_transfer_hook_accounts(ctx.remaining_accounts).execute(Some(cp_amm_instruction_seeds))?;
+    }
+
+    let liquidity_mint_instruction = Box::new(ctx.accounts.get_liquidity_mint_instruction(lp_tokens_to_mint));
+    liquidity_mint_instruction.execute(Some(cp_amm_instruction_seeds))?;
+
+    ctx.accounts.cp_amm.update_cumulative_prices(timestamp);
+    let (previous_base_liquidity, previous_quote_liquidity) = (ctx.accounts.cp_amm.base_liquidity(), ctx.accounts.cp_amm.quote_liquidity());
+    ctx.accounts.cp_amm.zap_in(zap_in_payload);
+    ctx.accounts.cp_amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, timestamp);
+    ctx.accounts.cp_amm.update_last_constant_product_sqrt(protocol_fee_on_lp_enabled);
+    let cp_amm = &ctx.accounts.cp_amm;
+
+    msg!("Event: ZapInToCpAmm");
+    emit!(
+        ZapInToCpAmmEvent{
+            provider: ctx.accounts.signer.key(),
+            cp_amm: cp_amm.key(),
+            is_base_in,
+            zapped_amount: zap_amount_after_fee,
+            swapped_amount,
+            provided_base_liquidity,
+            provided_quote_liquidity,
+            returned_base_dust: base_dust,
+            returned_quote_dust: quote_dust,
+            lp_tokens_minted: lp_tokens_to_mint,
+            min_lp_tokens,
+            base_liquidity: cp_amm.base_liquidity(),
+            quote_liquidity: cp_amm.quote_liquidity(),
+            lp_tokens_supply: cp_amm.lp_tokens_supply(),
+            protocol_base_fees_to_redeem: cp_amm.protocol_base_fees_to_redeem(),
+            protocol_quote_fees_to_redeem: cp_amm.protocol_quote_fees_to_redeem(),
+            constant_product_sqrt: cp_amm.constant_product_sqrt(),
+            base_quote_ratio_sqrt: cp_amm.base_quote_ratio_sqrt(),
+            timestamp: Clock::get()?.unix_timestamp
+        }
+    );
+    Ok(())
+}
+#[event]
+pub struct ZapInToCpAmmEvent{
+    pub provider: Pubkey,
+    pub cp_amm: Pubkey,
+    pub is_base_in: bool,
+    pub zapped_amount: u64,
+    pub swapped_amount: u64,
+    pub provided_base_liquidity: u64,
+    pub provided_quote_liquidity: u64,
+    pub returned_base_dust: u64,
+    pub returned_quote_dust: u64,
+    pub lp_tokens_minted: u64,
+    pub min_lp_tokens: u64,
+    pub base_liquidity: u64,
+    pub quote_liquidity: u64,
+    pub lp_tokens_supply: u64,
+    pub protocol_base_fees_to_redeem: u64,
+    pub protocol_quote_fees_to_redeem: u64,
+    pub constant_product_sqrt: Q64_128,
+    pub base_quote_ratio_sqrt: Q64_128,
+    pub timestamp: i64
+}
+impl<'info> ZapInToCpAmm<'info>{
+    fn get_in_transfer_instruction(&self, in_amount: u64, is_base_in: bool) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        if is_base_in{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.base_mint,
+                &self.signer_base_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_base_vault,
+                &self.base_token_program
+            )
+        }
+        else{
+            TransferTokensInstruction::try_new(
+                in_amount,
+                &self.quote_mint,
+                &self.signer_quote_account,
+                self.signer.to_account_info(),
+                &self.cp_amm_quote_vault,
+                &self.quote_token_program
+            )
+        }
+    }
+    fn get_base_dust_transfer_instruction(&self, base_dust: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            base_dust,
+            &self.base_mint,
+            &self.cp_amm_base_vault,
+            self.cp_amm.to_account_info(),
+            &self.signer_base_account,
+            &self.base_token_program
+        )
+    }
+    fn get_quote_dust_transfer_instruction(&self, quote_dust: u64) -> Result<TransferTokensInstruction<'_, '_, '_, 'info>>{
+        TransferTokensInstruction::try_new(
+            quote_dust,
+            &self.quote_mint,
+            &self.cp_amm_quote_vault,
+            self.cp_amm.to_account_info(),
+            &self.signer_quote_account,
+            &self.quote_token_program
+        )
+    }
+    fn get_liquidity_mint_instruction(&self, liquidity: u64) -> MintTokensInstructions<'_, '_, '_, 'info> {
+        MintTokensInstructions::new(
+            liquidity,
+            &self.lp_mint,
+            self.cp_amm.to_account_info(),
+            self.signer_lp_account.to_account_info(),
+            &self.lp_token_program
+        )
+    }
+}
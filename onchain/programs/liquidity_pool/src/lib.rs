@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
 
 declare_id!("2M2QKXZuuERizynTpUwfD7FkdhKHWAFVKiCFGBSxXr3X");
 
@@ -17,14 +18,55 @@ pub mod liquidity_pool {
         initialize_amms_configs_manager::handler(ctx)
     }
 
-    pub fn update_amms_configs_manager_authority(ctx: Context<UpdateAmmsConfigsManagerAuthority>) -> Result<()>{
+    pub fn propose_amms_configs_manager_authority(ctx: Context<ProposeAmmsConfigsManagerAuthority>) -> Result<()>{
+        msg!("Instruction: ProposeAmmsConfigsManagerAuthority");
+        propose_amms_configs_manager_authority::handler(ctx)
+    }
+
+    pub fn accept_amms_configs_manager_authority(ctx: Context<AcceptAmmsConfigsManagerAuthority>) -> Result<()>{
+        msg!("Instruction: AcceptAmmsConfigsManagerAuthority");
+        accept_amms_configs_manager_authority::handler(ctx)
+    }
+
+    pub fn cancel_amms_configs_manager_authority_transfer(ctx: Context<CancelAmmsConfigsManagerAuthorityTransfer>) -> Result<()>{
+        msg!("Instruction: CancelAmmsConfigsManagerAuthorityTransfer");
+        cancel_amms_configs_manager_authority_transfer::handler(ctx)
+    }
+
+    pub fn propose_amms_configs_manager_head_authority(ctx: Context<ProposeAmmsConfigsManagerHeadAuthority>) -> Result<()>{
+        msg!("Instruction: ProposeAmmsConfigsManagerHeadAuthority");
+        propose_amms_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn accept_amms_configs_manager_head_authority(ctx: Context<AcceptAmmsConfigsManagerHeadAuthority>) -> Result<()>{
+        msg!("Instruction: AcceptAmmsConfigsManagerHeadAuthority");
+        accept_amms_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn cancel_amms_configs_manager_head_authority_transfer(ctx: Context<CancelAmmsConfigsManagerHeadAuthorityTransfer>) -> Result<()>{
+        msg!("Instruction: CancelAmmsConfigsManagerHeadAuthorityTransfer");
+        cancel_amms_configs_manager_head_authority_transfer::handler(ctx)
+    }
+
+    /// Deprecated, use `propose_amms_configs_manager_authority`.
+    /// Kept with its accounts for existing clients, it only proposes the new authority,
+    /// which takes over once it calls `accept_amms_configs_manager_authority`.
+    pub fn update_amms_configs_manager_authority(ctx: Context<ProposeAmmsConfigsManagerAuthority>) -> Result<()>{
         msg!("Instruction: UpdateAmmsConfigsManagerAuthority");
-        update_amms_configs_manager_authority::handler(ctx)
+        propose_amms_configs_manager_authority::handler(ctx)
     }
 
-    pub fn update_amms_configs_manager_head_authority(ctx: Context<UpdateAmmsConfigsManagerHeadAuthority>) -> Result<()>{
+    /// Deprecated, use `propose_amms_configs_manager_head_authority`.
+    /// Kept with its accounts for existing clients, it only proposes the new head authority,
+    /// which takes over once it calls `accept_amms_configs_manager_head_authority`.
+    pub fn update_amms_configs_manager_head_authority(ctx: Context<ProposeAmmsConfigsManagerHeadAuthority>) -> Result<()>{
         msg!("Instruction: UpdateAmmsConfigsManagerHeadAuthority");
-        update_amms_configs_manager_head_authority::handler(ctx)
+        propose_amms_configs_manager_head_authority::handler(ctx)
+    }
+
+    pub fn migrate_amms_configs_manager(ctx: Context<MigrateAmmsConfigsManager>) -> Result<()>{
+        msg!("Instruction: MigrateAmmsConfigsManager");
+        migrate_amms_configs_manager::handler(ctx)
     }
 
 
@@ -48,28 +90,343 @@ pub mod liquidity_pool {
         update_amms_config_protocol_fee_rate::handler(ctx, new_protocol_fee_rate_basis_points)
     }
 
-    pub fn initialize_cp_amm(ctx: Context<InitializeCpAmm>) -> Result<()>{
+    pub fn update_amms_config_fee_rates_timelock(ctx: Context<UpdateAmmsConfigFeeRatesTimelock>, new_fee_rates_timelock_duration: i64) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigFeeRatesTimelock");
+        update_amms_config_fee_rates_timelock::handler(ctx, new_fee_rates_timelock_duration)
+    }
+
+    pub fn cancel_amms_config_fee_rates_change(ctx: Context<CancelAmmsConfigFeeRatesChange>) -> Result<()>{
+        msg!("Instruction: CancelAmmsConfigFeeRatesChange");
+        cancel_amms_config_fee_rates_change::handler(ctx)
+    }
+
+    pub fn update_amms_config_dynamic_fee(ctx: Context<UpdateAmmsConfigDynamicFee>, dynamic_fee_enabled: bool, min_providers_fee_rate_basis_points: u16, max_providers_fee_rate_basis_points: u16) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigDynamicFee");
+        update_amms_config_dynamic_fee::handler(ctx, dynamic_fee_enabled, min_providers_fee_rate_basis_points, max_providers_fee_rate_basis_points)
+    }
+
+    pub fn update_amms_config_pause(ctx: Context<UpdateAmmsConfigPause>, swaps_paused: bool, provides_paused: bool) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigPause");
+        update_amms_config_pause::handler(ctx, swaps_paused, provides_paused)
+    }
+
+    pub fn update_amms_config_protocol_fee_mode(ctx: Context<UpdateAmmsConfigProtocolFeeMode>, protocol_fee_on_lp_enabled: bool) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigProtocolFeeMode");
+        update_amms_config_protocol_fee_mode::handler(ctx, protocol_fee_on_lp_enabled)
+    }
+
+    pub fn update_amms_config_flash_loan_fee_rate(ctx: Context<UpdateAmmsConfigFlashLoanFeeRate>, new_flash_loan_fee_rate_basis_points: u16) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigFlashLoanFeeRate");
+        update_amms_config_flash_loan_fee_rate::handler(ctx, new_flash_loan_fee_rate_basis_points)
+    }
+
+    pub fn update_amms_config_pool_creation_fee(ctx: Context<UpdateAmmsConfigPoolCreationFee>, pool_creation_fee_amount: u64) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigPoolCreationFee");
+        update_amms_config_pool_creation_fee::handler(ctx, pool_creation_fee_amount)
+    }
+
+    pub fn update_amms_config_creator_restriction(ctx: Context<UpdateAmmsConfigCreatorRestriction>, creator_restricted: bool) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigCreatorRestriction");
+        update_amms_config_creator_restriction::handler(ctx, creator_restricted)
+    }
+
+    pub fn update_amms_config_referrer_fee_share(ctx: Context<UpdateAmmsConfigReferrerFeeShare>, new_referrer_fee_share_basis_points: u16) -> Result<()>{
+        msg!("Instruction: UpdateAmmsConfigReferrerFeeShare");
+        update_amms_config_referrer_fee_share::handler(ctx, new_referrer_fee_share_basis_points)
+    }
+
+    pub fn migrate_amms_config(ctx: Context<MigrateAmmsConfig>) -> Result<()>{
+        msg!("Instruction: MigrateAmmsConfig");
+        migrate_amms_config::handler(ctx)
+    }
+
+    pub fn set_allowed_creator(ctx: Context<SetAllowedCreator>, max_pools: u64) -> Result<()>{
+        msg!("Instruction: SetAllowedCreator");
+        set_allowed_creator::handler(ctx, max_pools)
+    }
+
+    pub fn remove_allowed_creator(ctx: Context<RemoveAllowedCreator>) -> Result<()>{
+        msg!("Instruction: RemoveAllowedCreator");
+        remove_allowed_creator::handler(ctx)
+    }
+
+    pub fn update_cp_amm_pause(ctx: Context<UpdateCpAmmPause>, swaps_paused: bool, provides_paused: bool) -> Result<()>{
+        msg!("Instruction: UpdateCpAmmPause");
+        update_cp_amm_pause::handler(ctx, swaps_paused, provides_paused)
+    }
+
+    pub fn update_cp_amm_pair(ctx: Context<UpdateCpAmmPair>, is_canonical: bool) -> Result<()>{
+        msg!("Instruction: UpdateCpAmmPair");
+        update_cp_amm_pair::handler(ctx, is_canonical)
+    }
+
+    pub fn ramp_stable_amm_amplification(ctx: Context<RampStableAmmAmplification>, target_amplification: u64, ramp_end_timestamp: i64) -> Result<()>{
+        msg!("Instruction: RampStableAmmAmplification");
+        ramp_stable_amm_amplification::handler(ctx, target_amplification, ramp_end_timestamp)
+    }
+
+    pub fn initialize_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeCpAmm<'info>>) -> Result<()>{
         msg!("Instruction: InitializeCpAmm");
         initialize_cp_amm::handler(ctx)
     }
-    pub fn launch_cp_amm(ctx: Context<LaunchCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
+    pub fn launch_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
         msg!("Instruction: LaunchCpAmm");
-        launch_cp_amm::handler(ctx, base_liquidity, quote_liquidity)
+        launch_cp_amm::handler(ctx, base_liquidity, quote_liquidity, None)
+    }
+    pub fn launch_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: LaunchCpAmmWithDeadline");
+        launch_cp_amm::handler(ctx, base_liquidity, quote_liquidity, Some(deadline))
+    }
+    pub fn launch_cp_amm_with_lp_lock<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchCpAmmWithLpLock<'info>>, base_liquidity: u64, quote_liquidity: u64, locked_lp_share_basis_points: u16, cliff_timestamp: i64, end_timestamp: i64) -> Result<()>{
+        msg!("Instruction: LaunchCpAmmWithLpLock");
+        launch_cp_amm_with_lp_lock::handler(ctx, base_liquidity, quote_liquidity, locked_lp_share_basis_points, cliff_timestamp, end_timestamp, None)
     }
-    pub fn provide_to_cp_amm(ctx: Context<ProvideToCpAmm>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
+    pub fn launch_cp_amm_with_lp_lock_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchCpAmmWithLpLock<'info>>, base_liquidity: u64, quote_liquidity: u64, locked_lp_share_basis_points: u16, cliff_timestamp: i64, end_timestamp: i64, deadline: i64) -> Result<()>{
+        msg!("Instruction: LaunchCpAmmWithLpLockWithDeadline");
+        launch_cp_amm_with_lp_lock::handler(ctx, base_liquidity, quote_liquidity, locked_lp_share_basis_points, cliff_timestamp, end_timestamp, Some(deadline))
+    }
+    pub fn claim_from_cp_amm_lp_lock<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFromCpAmmLpLock<'info>>) -> Result<()>{
+        msg!("Instruction: ClaimFromCpAmmLpLock");
+        claim_from_cp_amm_lp_lock::handler(ctx)
+    }
+    pub fn provide_to_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
         msg!("Instruction: ProvideToCpAmm");
-        provide_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity)
+        provide_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity, 0, None)
+    }
+    pub fn provide_to_cp_amm_v2<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, min_lp_tokens: u64) -> Result<()>{
+        msg!("Instruction: ProvideToCpAmmV2");
+        provide_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity, min_lp_tokens, None)
     }
-    pub fn withdraw_from_cp_amm(ctx: Context<WithdrawFromCpAmm>, lp_tokens: u64) -> Result<()>{
+    pub fn provide_to_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToCpAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, min_lp_tokens: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: ProvideToCpAmmWithDeadline");
+        provide_to_cp_amm::handler(ctx, base_liquidity, quote_liquidity, min_lp_tokens, Some(deadline))
+    }
+    pub fn withdraw_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmm<'info>>, lp_tokens: u64) -> Result<()>{
         msg!("Instruction: WithdrawFromCpAmm");
-        withdraw_from_cp_amm::handler(ctx, lp_tokens)
+        withdraw_from_cp_amm::handler(ctx, lp_tokens, 0, 0, None)
+    }
+    pub fn withdraw_from_cp_amm_v2<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmm<'info>>, lp_tokens: u64, min_base_out: u64, min_quote_out: u64) -> Result<()>{
+        msg!("Instruction: WithdrawFromCpAmmV2");
+        withdraw_from_cp_amm::handler(ctx, lp_tokens, min_base_out, min_quote_out, None)
+    }
+    pub fn withdraw_from_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmm<'info>>, lp_tokens: u64, min_base_out: u64, min_quote_out: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: WithdrawFromCpAmmWithDeadline");
+        withdraw_from_cp_amm::handler(ctx, lp_tokens, min_base_out, min_quote_out, Some(deadline))
     }
-    pub fn swap_in_cp_amm(ctx: Context<SwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
+    pub fn swap_in_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInCpAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
         msg!("Instruction: SwapInCpAmm");
-        swap_in_cp_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out)
+        swap_in_cp_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, None)
     }
-    pub fn collect_fees_from_cp_amm(ctx: Context<CollectFeesFromCpAmm>) -> Result<()>{
+    pub fn swap_in_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInCpAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapInCpAmmWithDeadline");
+        swap_in_cp_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, Some(deadline))
+    }
+    pub fn swap_in_cp_amm_with_referrer<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInCpAmmWithReferrer<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapInCpAmmWithReferrer");
+        swap_in_cp_amm_with_referrer::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, deadline)
+    }
+    pub fn quote_swap_in_cp_amm(ctx: Context<QuoteSwapInCpAmm>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
+        msg!("Instruction: QuoteSwapInCpAmm");
+        quote_swap_in_cp_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out)
+    }
+    pub fn flash_borrow_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, FlashBorrowFromCpAmm<'info>>, base_amount: u64, quote_amount: u64) -> Result<()>{
+        msg!("Instruction: FlashBorrowFromCpAmm");
+        flash_borrow_from_cp_amm::handler(ctx, base_amount, quote_amount)
+    }
+    pub fn flash_repay_to_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, FlashRepayToCpAmm<'info>>) -> Result<()>{
+        msg!("Instruction: FlashRepayToCpAmm");
+        flash_repay_to_cp_amm::handler(ctx)
+    }
+    pub fn swap_exact_out_in_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, SwapExactOutInCpAmm<'info>>, swap_result: u64, max_input: u64, is_in_out: bool) -> Result<()>{
+        msg!("Instruction: SwapExactOutInCpAmm");
+        swap_exact_out_in_cp_amm::handler(ctx, swap_result, max_input, is_in_out, None)
+    }
+    pub fn swap_exact_out_in_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, SwapExactOutInCpAmm<'info>>, swap_result: u64, max_input: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapExactOutInCpAmmWithDeadline");
+        swap_exact_out_in_cp_amm::handler(ctx, swap_result, max_input, is_in_out, Some(deadline))
+    }
+    pub fn routed_swap_in_cp_amms<'info>(ctx: Context<'_, '_, 'info, 'info, RoutedSwapInCpAmms<'info>>, swap_amount: u64, min_result: u64) -> Result<()>{
+        msg!("Instruction: RoutedSwapInCpAmms");
+        routed_swap_in_cp_amms::handler(ctx, swap_amount, min_result, None)
+    }
+    pub fn routed_swap_in_cp_amms_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, RoutedSwapInCpAmms<'info>>, swap_amount: u64, min_result: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: RoutedSwapInCpAmmsWithDeadline");
+        routed_swap_in_cp_amms::handler(ctx, swap_amount, min_result, Some(deadline))
+    }
+    pub fn zap_in_to_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ZapInToCpAmm<'info>>, zap_amount: u64, min_lp_tokens: u64, is_base_in: bool) -> Result<()>{
+        msg!("Instruction: ZapInToCpAmm");
+        zap_in_to_cp_amm::handler(ctx, zap_amount, min_lp_tokens, is_base_in, None)
+    }
+    pub fn zap_in_to_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, ZapInToCpAmm<'info>>, zap_amount: u64, min_lp_tokens: u64, is_base_in: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: ZapInToCpAmmWithDeadline");
+        zap_in_to_cp_amm::handler(ctx, zap_amount, min_lp_tokens, is_base_in, Some(deadline))
+    }
+    pub fn zap_out_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ZapOutFromCpAmm<'info>>, lp_tokens: u64, min_result: u64, is_base_out: bool) -> Result<()>{
+        msg!("Instruction: ZapOutFromCpAmm");
+        zap_out_from_cp_amm::handler(ctx, lp_tokens, min_result, is_base_out, None)
+    }
+    pub fn zap_out_from_cp_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, ZapOutFromCpAmm<'info>>, lp_tokens: u64, min_result: u64, is_base_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: ZapOutFromCpAmmWithDeadline");
+        zap_out_from_cp_amm::handler(ctx, lp_tokens, min_result, is_base_out, Some(deadline))
+    }
+    pub fn collect_fees_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromCpAmm<'info>>) -> Result<()>{
         msg!("Instruction: CollectFeesFromCpAmm");
         collect_fees_from_cp_amm::handler(ctx)
     }
+    pub fn claim_fees_from_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimFeesFromCpAmm<'info>>, base_fees: u64, quote_fees: u64) -> Result<()>{
+        msg!("Instruction: ClaimFeesFromCpAmm");
+        claim_fees_from_cp_amm::handler(ctx, base_fees, quote_fees)
+    }
+    pub fn sweep_fees_from_cp_amms<'info>(ctx: Context<'_, '_, 'info, 'info, SweepFeesFromCpAmms<'info>>) -> Result<()>{
+        msg!("Instruction: SweepFeesFromCpAmms");
+        sweep_fees_from_cp_amms::handler(ctx)
+    }
+    pub fn claim_lp_fees_from_cp_amm(ctx: Context<ClaimLpFeesFromCpAmm>, lp_tokens: u64) -> Result<()>{
+        msg!("Instruction: ClaimLpFeesFromCpAmm");
+        claim_lp_fees_from_cp_amm::handler(ctx, lp_tokens)
+    }
+    pub fn sync_cp_amm(ctx: Context<SyncCpAmm>) -> Result<()>{
+        msg!("Instruction: SyncCpAmm");
+        sync_cp_amm::handler(ctx)
+    }
+    pub fn skim_cp_amm<'info>(ctx: Context<'_, '_, 'info, 'info, SkimCpAmm<'info>>) -> Result<()>{
+        msg!("Instruction: SkimCpAmm");
+        skim_cp_amm::handler(ctx)
+    }
+    pub fn migrate_cp_amm(ctx: Context<MigrateCpAmm>) -> Result<()>{
+        msg!("Instruction: MigrateCpAmm");
+        migrate_cp_amm::handler(ctx)
+    }
+    pub fn initialize_cp_amm_observations(ctx: Context<InitializeCpAmmObservations>) -> Result<()>{
+        msg!("Instruction: InitializeCpAmmObservations");
+        initialize_cp_amm_observations::handler(ctx)
+    }
+    pub fn record_cp_amm_observation(ctx: Context<RecordCpAmmObservation>) -> Result<()>{
+        msg!("Instruction: RecordCpAmmObservation");
+        record_cp_amm_observation::handler(ctx)
+    }
+    pub fn get_cp_amm_twap(ctx: Context<GetCpAmmTwap>, window: u32) -> Result<()>{
+        msg!("Instruction: GetCpAmmTwap");
+        get_cp_amm_twap::handler(ctx, window)
+    }
+    pub fn initialize_cp_amm_farm(ctx: Context<InitializeCpAmmFarm>) -> Result<()>{
+        msg!("Instruction: InitializeCpAmmFarm");
+        initialize_cp_amm_farm::handler(ctx)
+    }
+    pub fn add_cp_amm_farm_reward<'info>(ctx: Context<'_, '_, 'info, 'info, AddCpAmmFarmReward<'info>>, amount: u64, start_timestamp: i64, end_timestamp: i64) -> Result<()>{
+        msg!("Instruction: AddCpAmmFarmReward");
+        add_cp_amm_farm_reward::handler(ctx, amount, start_timestamp, end_timestamp)
+    }
+    pub fn deposit_to_cp_amm_farm<'info>(ctx: Context<'_, '_, 'info, 'info, DepositToCpAmmFarm<'info>>, lp_tokens: u64) -> Result<()>{
+        msg!("Instruction: DepositToCpAmmFarm");
+        deposit_to_cp_amm_farm::handler(ctx, lp_tokens)
+    }
+    pub fn withdraw_from_cp_amm_farm<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromCpAmmFarm<'info>>, lp_tokens: u64) -> Result<()>{
+        msg!("Instruction: WithdrawFromCpAmmFarm");
+        withdraw_from_cp_amm_farm::handler(ctx, lp_tokens)
+    }
+    pub fn harvest_from_cp_amm_farm<'info>(ctx: Context<'_, '_, 'info, 'info, HarvestFromCpAmmFarm<'info>>) -> Result<()>{
+        msg!("Instruction: HarvestFromCpAmmFarm");
+        harvest_from_cp_amm_farm::handler(ctx)
+    }
+    pub fn place_cp_amm_limit_order<'info>(ctx: Context<'_, '_, 'info, 'info, PlaceCpAmmLimitOrder<'info>>, id: u64, amount: u64, min_received_amount: u64, target_base_quote_ratio_sqrt: Q64_128) -> Result<()>{
+        msg!("Instruction: PlaceCpAmmLimitOrder");
+        place_cp_amm_limit_order::handler(ctx, id, amount, min_received_amount, target_base_quote_ratio_sqrt)
+    }
+    pub fn cancel_cp_amm_limit_order<'info>(ctx: Context<'_, '_, 'info, 'info, CancelCpAmmLimitOrder<'info>>) -> Result<()>{
+        msg!("Instruction: CancelCpAmmLimitOrder");
+        cancel_cp_amm_limit_order::handler(ctx)
+    }
+    pub fn fill_cp_amm_limit_orders<'info>(ctx: Context<'_, '_, 'info, 'info, FillCpAmmLimitOrders<'info>>) -> Result<()>{
+        msg!("Instruction: FillCpAmmLimitOrders");
+        fill_cp_amm_limit_orders::handler(ctx)
+    }
+    pub fn initialize_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeStableAmm<'info>>, amplification: u64) -> Result<()>{
+        msg!("Instruction: InitializeStableAmm");
+        initialize_stable_amm::handler(ctx, amplification)
+    }
+    pub fn launch_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchStableAmm<'info>>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
+        msg!("Instruction: LaunchStableAmm");
+        launch_stable_amm::handler(ctx, base_liquidity, quote_liquidity, None)
+    }
+    pub fn launch_stable_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, LaunchStableAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: LaunchStableAmmWithDeadline");
+        launch_stable_amm::handler(ctx, base_liquidity, quote_liquidity, Some(deadline))
+    }
+    pub fn provide_to_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToStableAmm<'info>>, base_liquidity: u64, quote_liquidity: u64) -> Result<()>{
+        msg!("Instruction: ProvideToStableAmm");
+        provide_to_stable_amm::handler(ctx, base_liquidity, quote_liquidity, None)
+    }
+    pub fn provide_to_stable_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToStableAmm<'info>>, base_liquidity: u64, quote_liquidity: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: ProvideToStableAmmWithDeadline");
+        provide_to_stable_amm::handler(ctx, base_liquidity, quote_liquidity, Some(deadline))
+    }
+    pub fn withdraw_from_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromStableAmm<'info>>, lp_tokens: u64) -> Result<()>{
+        msg!("Instruction: WithdrawFromStableAmm");
+        withdraw_from_stable_amm::handler(ctx, lp_tokens, None)
+    }
+    pub fn withdraw_from_stable_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromStableAmm<'info>>, lp_tokens: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: WithdrawFromStableAmmWithDeadline");
+        withdraw_from_stable_amm::handler(ctx, lp_tokens, Some(deadline))
+    }
+    pub fn swap_in_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInStableAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
+        msg!("Instruction: SwapInStableAmm");
+        swap_in_stable_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, None)
+    }
+    pub fn swap_in_stable_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInStableAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapInStableAmmWithDeadline");
+        swap_in_stable_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, Some(deadline))
+    }
+    pub fn swap_in_stable_amm_with_referrer<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInStableAmmWithReferrer<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapInStableAmmWithReferrer");
+        swap_in_stable_amm_with_referrer::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, deadline)
+    }
+    pub fn collect_fees_from_stable_amm<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromStableAmm<'info>>) -> Result<()>{
+        msg!("Instruction: CollectFeesFromStableAmm");
+        collect_fees_from_stable_amm::handler(ctx)
+    }
+    pub fn initialize_cl_amm<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeClAmm<'info>>, tick_spacing: u16, initial_sqrt_price: Q64_128) -> Result<()>{
+        msg!("Instruction: InitializeClAmm");
+        initialize_cl_amm::handler(ctx, tick_spacing, initial_sqrt_price)
+    }
+    pub fn initialize_cl_amm_tick_array(ctx: Context<InitializeClAmmTickArray>, start_tick: i32) -> Result<()>{
+        msg!("Instruction: InitializeClAmmTickArray");
+        initialize_cl_amm_tick_array::handler(ctx, start_tick)
+    }
+    pub fn open_cl_amm_position(ctx: Context<OpenClAmmPosition>, tick_lower: i32, tick_upper: i32) -> Result<()>{
+        msg!("Instruction: OpenClAmmPosition");
+        open_cl_amm_position::handler(ctx, tick_lower, tick_upper)
+    }
+    pub fn provide_to_cl_amm<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToClAmm<'info>>, liquidity: u128, max_base_amount: u64, max_quote_amount: u64) -> Result<()>{
+        msg!("Instruction: ProvideToClAmm");
+        provide_to_cl_amm::handler(ctx, liquidity, max_base_amount, max_quote_amount, None)
+    }
+    pub fn provide_to_cl_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, ProvideToClAmm<'info>>, liquidity: u128, max_base_amount: u64, max_quote_amount: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: ProvideToClAmmWithDeadline");
+        provide_to_cl_amm::handler(ctx, liquidity, max_base_amount, max_quote_amount, Some(deadline))
+    }
+    pub fn withdraw_from_cl_amm<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromClAmm<'info>>, liquidity: u128, min_base_amount: u64, min_quote_amount: u64) -> Result<()>{
+        msg!("Instruction: WithdrawFromClAmm");
+        withdraw_from_cl_amm::handler(ctx, liquidity, min_base_amount, min_quote_amount, None)
+    }
+    pub fn withdraw_from_cl_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawFromClAmm<'info>>, liquidity: u128, min_base_amount: u64, min_quote_amount: u64, deadline: i64) -> Result<()>{
+        msg!("Instruction: WithdrawFromClAmmWithDeadline");
+        withdraw_from_cl_amm::handler(ctx, liquidity, min_base_amount, min_quote_amount, Some(deadline))
+    }
+    pub fn swap_in_cl_amm<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInClAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool) -> Result<()>{
+        msg!("Instruction: SwapInClAmm");
+        swap_in_cl_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, None)
+    }
+    pub fn swap_in_cl_amm_with_deadline<'info>(ctx: Context<'_, '_, 'info, 'info, SwapInClAmm<'info>>, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, is_in_out: bool, deadline: i64) -> Result<()>{
+        msg!("Instruction: SwapInClAmmWithDeadline");
+        swap_in_cl_amm::handler(ctx, swap_amount, estimated_result, allowed_slippage, is_in_out, Some(deadline))
+    }
+    pub fn collect_fees_from_cl_amm_position<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromClAmmPosition<'info>>) -> Result<()>{
+        msg!("Instruction: CollectFeesFromClAmmPosition");
+        collect_fees_from_cl_amm_position::handler(ctx)
+    }
+    pub fn collect_fees_from_cl_amm<'info>(ctx: Context<'_, '_, 'info, 'info, CollectFeesFromClAmm<'info>>) -> Result<()>{
+        msg!("Instruction: CollectFeesFromClAmm");
+        collect_fees_from_cl_amm::handler(ctx)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,175 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Represents an entry of the creators allow-list of a creator-restricted `AmmsConfig`.
+///
+/// Only creators with an `AllowedCreator` account for the configuration can create pools
+/// with it, up to `max_pools` pools. This lets partner launchpads get dedicated configurations.
+#[account]
+#[derive(InitSpace)]
+pub struct AllowedCreator {
+    /// The canonical bump seed used for the account's PDA.
+    bump: u8, // 1 byte
+
+    /// The public key of the `AmmsConfig` this entry belongs to.
+    amms_config: Pubkey, // 32 bytes
+
+    /// The public key of the allowed creator.
+    creator: Pubkey, // 32 bytes
+
+    /// The maximal number of pools the creator can create, 0 stands for unlimited.
+    max_pools: u64, // 8 bytes
+
+    /// The number of pools created by the creator with the configuration.
+    pools_count: u64, // 8 bytes
+}
+
+impl AllowedCreator {
+    /// The seed used to derive the account's PDA.
+    pub const SEED: &'static [u8] = b"allowed_creator";
+
+    /// Initializes the `AllowedCreator` with the provided parameters.
+    ///
+    /// # Parameters
+    /// - `amms_config`: The public key of the `AmmsConfig` the entry belongs to.
+    /// - `creator`: The public key of the allowed creator.
+    /// - `bump`: The bump seed for the account's PDA.
+    ///
+    /// # Behavior
+    /// - Sets the initial `pools_count` to 0.
+    pub(crate) fn initialize(&mut self, amms_config: Pubkey, creator: Pubkey, bump: u8) {
+        self.bump = bump;
+        self.amms_config = amms_config;
+        self.creator = creator;
+        self.pools_count = 0;
+    }
+
+    /// Updates the maximal number of pools the creator can create.
+    ///
+    /// # Parameters
+    /// - `max_pools`: The new cap, 0 stands for unlimited.
+    pub(crate) fn update_max_pools(&mut self, max_pools: u64) {
+        self.max_pools = max_pools;
+    }
+
+    /// Increments the `pools_count` field by 1.
+    ///
+    /// # Errors
+    /// - Returns `CreatorPoolsCapReached` if the creator has already created `max_pools` pools.
+    pub(crate) fn increment_pools_count(&mut self) -> Result<()> {
+        require!(self.max_pools == 0 || self.pools_count < self.max_pools, ErrorCode::CreatorPoolsCapReached);
+        self.pools_count = self.pools_count.checked_add(1).unwrap();
+        Ok(())
+    }
+
+    /// Checks whether the account is initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.creator != Pubkey::default()
+    }
+
+    /// Retrieves the bump seed used for the account's PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    /// Retrieves the public key of the `AmmsConfig` this entry belongs to.
+    #[inline]
+    pub fn amms_config(&self) -> &Pubkey {
+        &self.amms_config
+    }
+
+    /// Retrieves the public key of the allowed creator.
+    #[inline]
+    pub fn creator(&self) -> &Pubkey {
+        &self.creator
+    }
+
+    /// Retrieves the maximal number of pools the creator can create, 0 stands for unlimited.
+    #[inline]
+    pub fn max_pools(&self) -> u64 {
+        self.max_pools
+    }
+
+    /// Retrieves the number of pools created by the creator with the configuration.
+    #[inline]
+    pub fn pools_count(&self) -> u64 {
+        self.pools_count
+    }
+}
+
+#[cfg(test)]
+mod allowed_creator_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    /// Tests the `initialize`, `update_max_pools` and `increment_pools_count` methods of the `AllowedCreator` struct.
+    #[test]
+    fn test_allowed_creator_pools_cap() {
+        let mut allowed_creator = AllowedCreator {
+            bump: 0,
+            amms_config: Pubkey::default(),
+            creator: Pubkey::default(),
+            max_pools: 0,
+            pools_count: 0,
+        };
+        assert!(!allowed_creator.is_initialized());
+
+        let amms_config = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        allowed_creator.initialize(amms_config, creator, 42);
+        allowed_creator.update_max_pools(2);
+
+        assert!(allowed_creator.is_initialized());
+        assert_eq!(allowed_creator.amms_config(), &amms_config);
+        assert_eq!(allowed_creator.creator(), &creator);
+        assert_eq!(allowed_creator.bump(), 42);
+        assert_eq!(allowed_creator.max_pools(), 2);
+
+        assert!(allowed_creator.increment_pools_count().is_ok());
+        assert!(allowed_creator.increment_pools_count().is_ok());
+        assert_eq!(allowed_creator.increment_pools_count().ok(), None);
+        assert_eq!(allowed_creator.pools_count(), 2);
+
+        allowed_creator.update_max_pools(0);
+        assert!(allowed_creator.increment_pools_count().is_ok());
+        assert_eq!(allowed_creator.pools_count(), 3);
+    }
+
+    /// Tests the data layout of the `AllowedCreator` struct.
+    #[test]
+    fn test_allowed_creator_data_layout() {
+        let bump = 42u8;
+        let amms_config = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let max_pools = 10u64;
+        let pools_count = 3u64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 81];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AllowedCreator::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(amms_config.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(creator.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&max_pools.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&pools_count.to_le_bytes()); offset += 8;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 81);
+
+        let deserialized_allowed_creator = AllowedCreator::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_allowed_creator.bump, bump);
+        assert_eq!(deserialized_allowed_creator.amms_config, amms_config);
+        assert_eq!(deserialized_allowed_creator.creator, creator);
+        assert_eq!(deserialized_allowed_creator.max_pools, max_pools);
+        assert_eq!(deserialized_allowed_creator.pools_count, pools_count);
+
+        let mut serialized_data = Vec::new();
+        deserialized_allowed_creator.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
@@ -23,12 +23,86 @@ pub struct AmmsConfig {
 
     /// The protocol's fee rate, measured in basis points (1 basis point = 0.01%).
     protocol_fee_rate_basis_points: u16, // 2 bytes
+
+    /// Whether the providers fee rate of CpAmm swaps is driven by the pool volatility
+    /// instead of `providers_fee_rate_basis_points`.
+    dynamic_fee_enabled: bool, // 1 byte
+
+    /// The lower bound of the dynamic providers fee rate, measured in basis points.
+    min_providers_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The upper bound of the dynamic providers fee rate, measured in basis points.
+    max_providers_fee_rate_basis_points: u16, // 2 bytes
+
+    /// Whether swaps are halted in all pools of this configuration.
+    /// CpAmm pools can also be paused one by one, StableAmm and ClAmm pools have no flags of their own
+    /// and are only paused here, so a pool that may need to be halted alone belongs in its own configuration.
+    swaps_paused: bool, // 1 byte
+
+    /// Whether liquidity provision is halted in all pools of this configuration.
+    /// Withdrawals are never paused.
+    provides_paused: bool, // 1 byte
+
+    /// Whether the protocol fee of CpAmm swaps stays in the pool and is collected as LP tokens
+    /// minted proportionally to the constant product growth, instead of being skimmed in base and quote tokens.
+    /// StableAmm swaps always skim the protocol fee.
+    protocol_fee_on_lp_enabled: bool, // 1 byte
+
+    /// The fee rate of CpAmm flash loans, measured in basis points (1 basis point = 0.01%).
+    /// Charged on top of the lent amounts and credited to liquidity providers.
+    flash_loan_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The fee paid to the fee authority for creating a pool with this configuration.
+    /// Measured in lamports, or in tokens of `pool_creation_fee_mint` when it is set.
+    pool_creation_fee_amount: u64, // 8 bytes
+
+    /// The mint of the token the pool creation fee is paid in.
+    /// The default public key stands for native SOL.
+    pool_creation_fee_mint: Pubkey, // 32 bytes
+
+    /// Whether only creators from the `AllowedCreator` allow-list can create pools with this configuration.
+    creator_restricted: bool, // 1 byte
+
+    /// The share of the protocol fee of a swap paid to the referrer token account passed with it,
+    /// measured in basis points of the protocol fee. Capped by `MAX_REFERRER_FEE_SHARE_BASIS_POINTS`.
+    referrer_fee_share_basis_points: u16, // 2 bytes
+
+    /// The delay, in seconds, between scheduling a providers or protocol fee rate change and its taking effect.
+    /// Zero applies the changes immediately. Capped by `MAX_FEE_RATES_TIMELOCK_DURATION`.
+    fee_rates_timelock_duration: i64, // 8 bytes
+
+    /// The providers fee rate scheduled to replace `providers_fee_rate_basis_points`.
+    pending_providers_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The protocol fee rate scheduled to replace `protocol_fee_rate_basis_points`.
+    pending_protocol_fee_rate_basis_points: u16, // 2 bytes
+
+    /// The timestamp from which the pending fee rates are in effect.
+    /// Zero stands for no scheduled change.
+    pending_fee_rates_effective_timestamp: i64, // 8 bytes
 }
 
 impl AmmsConfig {
     /// The seed used to derive the account's PDA.
     pub const SEED: &'static [u8] = b"amms_config";
 
+    /// The pool volatility, measured in basis points of price movement,
+    /// at which the dynamic providers fee rate reaches its upper bound.
+    pub const VOLATILITY_REFERENCE_BASIS_POINTS: u64 = 1_000;
+
+    /// The pool creation fee of a new configuration, paid in lamports.
+    pub const DEFAULT_POOL_CREATION_FEE_IN_LAMPORTS: u64 = 100_000_000;
+
+    /// The maximal share of the protocol fee paid to swap referrers, measured in basis points of the protocol fee.
+    pub const MAX_REFERRER_FEE_SHARE_BASIS_POINTS: u16 = 5_000;
+
+    /// The maximal delay of fee rate changes, in seconds (30 days).
+    pub const MAX_FEE_RATES_TIMELOCK_DURATION: i64 = 2_592_000;
+
+    /// The size of the initial account layout, before the dynamic fee, pause, fee-on-LP, flash loan,
+    /// pool creation, referrer and timelock fields were appended. Such accounts are grown by `migrate_amms_config`.
+    pub const INITIAL_LAYOUT_LEN: usize = 8 + 45;
+
     /// Initializes the `AmmsConfig` with the provided parameters.
     ///
     /// # Parameters
@@ -38,6 +112,8 @@ impl AmmsConfig {
     /// - `id`: A unique identifier for this configuration.
     /// - `bump`: The bump seed for the account's PDA.
     ///
+    /// The pool creation fee is set to `DEFAULT_POOL_CREATION_FEE_IN_LAMPORTS`.
+    ///
     /// # Errors
     /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the sum of `protocol_fee_rate_basis_points`
     ///   and `providers_fee_rate_basis_points` exceeds 10,000 (100%).
@@ -49,6 +125,7 @@ impl AmmsConfig {
         self.protocol_fee_rate_basis_points = protocol_fee_rate_basis_points;
         self.providers_fee_rate_basis_points = providers_fee_rate_basis_points;
         self.fee_authority = fee_authority;
+        self.pool_creation_fee_amount = Self::DEFAULT_POOL_CREATION_FEE_IN_LAMPORTS;
         
         Ok(())
     }
@@ -65,15 +142,22 @@ impl AmmsConfig {
     /// Updates the fee rate for liquidity providers.
     ///
     /// Ensures that the sum of the updated `providers_fee_rate_basis_points` and
-    /// the existing `protocol_fee_rate_basis_points` does not exceed 10,000 basis points (100%).
+    /// the protocol fee rate does not exceed 10,000 basis points (100%).
+    /// With a fee rates timelock set, the new rate is scheduled instead, see `schedule_fee_rates`.
     ///
     /// # Parameters
     /// - `new_providers_fee_rate_basis_points`: The updated fee rate for liquidity providers,
     ///   measured in basis points.
+    /// - `timestamp`: The current timestamp.
     ///
     /// # Errors
     /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the total fee rate exceeds 100%.
-    pub(crate) fn update_providers_fee_rate(&mut self, new_providers_fee_rate_basis_points: u16) -> Result<()> {
+    pub(crate) fn update_providers_fee_rate(&mut self, new_providers_fee_rate_basis_points: u16, timestamp: i64) -> Result<()> {
+        self.apply_pending_fee_rates(timestamp);
+        if self.fee_rates_timelock_duration > 0 {
+            let protocol_fee_rate_basis_points = self.pending_fee_rates_basis_points().1;
+            return self.schedule_fee_rates(new_providers_fee_rate_basis_points, protocol_fee_rate_basis_points, timestamp);
+        }
         require!(
             new_providers_fee_rate_basis_points + self.protocol_fee_rate_basis_points <= 10000,
             ErrorCode::ConfigFeeRateExceeded
@@ -85,22 +169,288 @@ impl AmmsConfig {
     /// Updates the protocol fee rate.
     ///
     /// Ensures that the sum of the updated `protocol_fee_rate_basis_points` and
-    /// the existing `providers_fee_rate_basis_points` does not exceed 10,000 basis points (100%).
+    /// the providers fee rate does not exceed 10,000 basis points (100%).
+    /// With a fee rates timelock set, the new rate is scheduled instead, see `schedule_fee_rates`.
     ///
     /// # Parameters
     /// - `new_protocol_fee_rate_basis_points`: The updated protocol fee rate, measured in basis points.
+    /// - `timestamp`: The current timestamp.
     ///
     /// # Errors
     /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the total fee rate exceeds 100%.
-    pub(crate) fn update_protocol_fee_rate(&mut self, new_protocol_fee_rate_basis_points: u16) -> Result<()> {
+    pub(crate) fn update_protocol_fee_rate(&mut self, new_protocol_fee_rate_basis_points: u16, timestamp: i64) -> Result<()> {
+        self.apply_pending_fee_rates(timestamp);
+        if self.fee_rates_timelock_duration > 0 {
+            let providers_fee_rate_basis_points = self.pending_fee_rates_basis_points().0;
+            return self.schedule_fee_rates(providers_fee_rate_basis_points, new_protocol_fee_rate_basis_points, timestamp);
+        }
         require!(
             new_protocol_fee_rate_basis_points + self.providers_fee_rate_basis_points <= 10000,
             ErrorCode::ConfigFeeRateExceeded
         );
+        require!(
+            !self.dynamic_fee_enabled || new_protocol_fee_rate_basis_points + self.max_providers_fee_rate_basis_points <= 10000,
+            ErrorCode::ConfigFeeRateExceeded
+        );
         self.protocol_fee_rate_basis_points = new_protocol_fee_rate_basis_points;
         Ok(())
     }
 
+    /// Schedules the providers and protocol fee rates to take effect once the fee rates timelock elapses.
+    ///
+    /// Replaces the pending change, if any, and restarts the timelock for both rates.
+    ///
+    /// # Parameters
+    /// - `providers_fee_rate_basis_points`: The scheduled providers fee rate, measured in basis points.
+    /// - `protocol_fee_rate_basis_points`: The scheduled protocol fee rate, measured in basis points.
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the total fee rate exceeds 100%.
+    fn schedule_fee_rates(&mut self, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, timestamp: i64) -> Result<()> {
+        require!(
+            providers_fee_rate_basis_points + protocol_fee_rate_basis_points <= 10000,
+            ErrorCode::ConfigFeeRateExceeded
+        );
+        require!(
+            !self.dynamic_fee_enabled || protocol_fee_rate_basis_points + self.max_providers_fee_rate_basis_points <= 10000,
+            ErrorCode::ConfigFeeRateExceeded
+        );
+        self.pending_providers_fee_rate_basis_points = providers_fee_rate_basis_points;
+        self.pending_protocol_fee_rate_basis_points = protocol_fee_rate_basis_points;
+        self.pending_fee_rates_effective_timestamp = timestamp + self.fee_rates_timelock_duration;
+        Ok(())
+    }
+
+    /// Moves the pending fee rates into `providers_fee_rate_basis_points` and `protocol_fee_rate_basis_points`
+    /// once they are in effect. Swaps read the rates in effect without storing them, see `current_fee_rates_basis_points`.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The current timestamp.
+    pub(crate) fn apply_pending_fee_rates(&mut self, timestamp: i64) {
+        if self.pending_fee_rates_effective_timestamp == 0 || timestamp < self.pending_fee_rates_effective_timestamp {
+            return;
+        }
+        self.providers_fee_rate_basis_points = self.pending_providers_fee_rate_basis_points;
+        self.protocol_fee_rate_basis_points = self.pending_protocol_fee_rate_basis_points;
+        self.clear_pending_fee_rates();
+    }
+
+    /// Cancels the pending fee rates change that is not in effect yet.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::NoPendingFeeRatesChange` if there is no change to cancel.
+    pub(crate) fn cancel_pending_fee_rates(&mut self, timestamp: i64) -> Result<()> {
+        self.apply_pending_fee_rates(timestamp);
+        require!(self.pending_fee_rates_effective_timestamp != 0, ErrorCode::NoPendingFeeRatesChange);
+        self.clear_pending_fee_rates();
+        Ok(())
+    }
+
+    fn clear_pending_fee_rates(&mut self) {
+        self.pending_providers_fee_rate_basis_points = 0;
+        self.pending_protocol_fee_rate_basis_points = 0;
+        self.pending_fee_rates_effective_timestamp = 0;
+    }
+
+    /// Updates the delay of providers and protocol fee rate changes.
+    ///
+    /// The timelock can't be shortened, so it can't be bypassed by the same authority it protects against.
+    ///
+    /// # Parameters
+    /// - `new_fee_rates_timelock_duration`: The updated delay, in seconds.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidFeeRatesTimelockDuration` if the delay is shorter than the current one
+    ///   or exceeds `MAX_FEE_RATES_TIMELOCK_DURATION`.
+    pub(crate) fn update_fee_rates_timelock(&mut self, new_fee_rates_timelock_duration: i64) -> Result<()> {
+        require!(
+            new_fee_rates_timelock_duration >= self.fee_rates_timelock_duration
+                && new_fee_rates_timelock_duration <= Self::MAX_FEE_RATES_TIMELOCK_DURATION,
+            ErrorCode::InvalidFeeRatesTimelockDuration
+        );
+        self.fee_rates_timelock_duration = new_fee_rates_timelock_duration;
+        Ok(())
+    }
+
+    /// Updates the dynamic providers fee mode and its bounds.
+    ///
+    /// Ensures that the bounds are ordered and the sum of the upper bound and
+    /// the existing `protocol_fee_rate_basis_points` does not exceed 10,000 basis points (100%).
+    ///
+    /// # Parameters
+    /// - `dynamic_fee_enabled`: Whether the providers fee rate is driven by the pool volatility.
+    /// - `min_providers_fee_rate_basis_points`: The lower bound of the dynamic providers fee rate.
+    /// - `max_providers_fee_rate_basis_points`: The upper bound of the dynamic providers fee rate.
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::InvalidDynamicFeeBounds` if the lower bound exceeds the upper bound.
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the total fee rate, with either the current
+    ///   or the pending protocol fee rate, exceeds 100%.
+    pub(crate) fn update_dynamic_fee(&mut self, dynamic_fee_enabled: bool, min_providers_fee_rate_basis_points: u16, max_providers_fee_rate_basis_points: u16, timestamp: i64) -> Result<()> {
+        self.apply_pending_fee_rates(timestamp);
+        require!(
+            min_providers_fee_rate_basis_points <= max_providers_fee_rate_basis_points,
+            ErrorCode::InvalidDynamicFeeBounds
+        );
+        require!(
+            max_providers_fee_rate_basis_points + self.protocol_fee_rate_basis_points.max(self.pending_fee_rates_basis_points().1) <= 10000,
+            ErrorCode::ConfigFeeRateExceeded
+        );
+        self.dynamic_fee_enabled = dynamic_fee_enabled;
+        self.min_providers_fee_rate_basis_points = min_providers_fee_rate_basis_points;
+        self.max_providers_fee_rate_basis_points = max_providers_fee_rate_basis_points;
+        Ok(())
+    }
+
+    /// Updates the pause state of all pools of this configuration.
+    ///
+    /// Withdrawals are not affected by the pause state.
+    ///
+    /// # Parameters
+    /// - `swaps_paused`: Whether swaps are halted.
+    /// - `provides_paused`: Whether liquidity provision is halted.
+    pub(crate) fn update_pause(&mut self, swaps_paused: bool, provides_paused: bool) {
+        self.swaps_paused = swaps_paused;
+        self.provides_paused = provides_paused;
+    }
+
+    /// Updates the protocol fee collection mode of CpAmms.
+    ///
+    /// # Parameters
+    /// - `protocol_fee_on_lp_enabled`: Whether the protocol fee is collected as LP tokens.
+    pub(crate) fn update_protocol_fee_mode(&mut self, protocol_fee_on_lp_enabled: bool) {
+        self.protocol_fee_on_lp_enabled = protocol_fee_on_lp_enabled;
+    }
+
+    /// Updates the fee rate of CpAmm flash loans.
+    ///
+    /// # Parameters
+    /// - `new_flash_loan_fee_rate_basis_points`: The updated flash loan fee rate, measured in basis points.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ConfigFeeRateExceeded` if the fee rate exceeds 100%.
+    pub(crate) fn update_flash_loan_fee_rate(&mut self, new_flash_loan_fee_rate_basis_points: u16) -> Result<()> {
+        require!(new_flash_loan_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        self.flash_loan_fee_rate_basis_points = new_flash_loan_fee_rate_basis_points;
+        Ok(())
+    }
+
+    /// Updates the pool creation fee.
+    ///
+    /// # Parameters
+    /// - `pool_creation_fee_amount`: The fee amount, in lamports or in tokens of `pool_creation_fee_mint`.
+    /// - `pool_creation_fee_mint`: The mint of the token the fee is paid in, `None` for native SOL.
+    pub(crate) fn update_pool_creation_fee(&mut self, pool_creation_fee_amount: u64, pool_creation_fee_mint: Option<Pubkey>) {
+        self.pool_creation_fee_amount = pool_creation_fee_amount;
+        self.pool_creation_fee_mint = pool_creation_fee_mint.unwrap_or_default();
+    }
+
+    /// Updates whether pool creation is restricted to the `AllowedCreator` allow-list.
+    ///
+    /// # Parameters
+    /// - `creator_restricted`: Whether only allowed creators can create pools.
+    pub(crate) fn update_creator_restriction(&mut self, creator_restricted: bool) {
+        self.creator_restricted = creator_restricted;
+    }
+
+    /// Updates the share of the protocol fee paid to swap referrers.
+    ///
+    /// # Parameters
+    /// - `new_referrer_fee_share_basis_points`: The updated referrer share, measured in basis points of the protocol fee.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::ReferrerFeeShareExceeded` if the share exceeds `MAX_REFERRER_FEE_SHARE_BASIS_POINTS`.
+    pub(crate) fn update_referrer_fee_share(&mut self, new_referrer_fee_share_basis_points: u16) -> Result<()> {
+        require!(new_referrer_fee_share_basis_points <= Self::MAX_REFERRER_FEE_SHARE_BASIS_POINTS, ErrorCode::ReferrerFeeShareExceeded);
+        self.referrer_fee_share_basis_points = new_referrer_fee_share_basis_points;
+        Ok(())
+    }
+
+    /// Calculates the providers fee rate to apply to a CpAmm swap.
+    ///
+    /// With the dynamic fee mode disabled, returns `providers_fee_rate_basis_points`.
+    /// Otherwise the rate grows linearly from the lower to the upper bound as the pool volatility
+    /// goes from zero to `VOLATILITY_REFERENCE_BASIS_POINTS`, and stays at the upper bound above it.
+    ///
+    /// # Parameters
+    /// - `volatility_accumulator`: The current pool volatility, measured in basis points of price movement.
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Returns
+    /// - The `u16` providers fee rate, measured in basis points.
+    pub fn effective_providers_fee_rate_basis_points(&self, volatility_accumulator: u64, timestamp: i64) -> u16 {
+        if !self.dynamic_fee_enabled {
+            return self.current_providers_fee_rate_basis_points(timestamp);
+        }
+        let fee_range = (self.max_providers_fee_rate_basis_points - self.min_providers_fee_rate_basis_points) as u64;
+        let volatility = volatility_accumulator.min(Self::VOLATILITY_REFERENCE_BASIS_POINTS);
+        self.min_providers_fee_rate_basis_points + (fee_range * volatility / Self::VOLATILITY_REFERENCE_BASIS_POINTS) as u16
+    }
+
+    /// Calculates the providers and protocol fee rates to apply to a CpAmm swap.
+    ///
+    /// With the fee-on-LP mode enabled, the protocol fee rate is added to the providers fee rate,
+    /// so the whole swap fee stays in the pool until it is minted to the protocol as LP tokens.
+    ///
+    /// # Parameters
+    /// - `volatility_accumulator`: The current pool volatility, measured in basis points of price movement.
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Returns
+    /// - The `(u16, u16)` providers and protocol fee rates, measured in basis points.
+    pub fn effective_swap_fee_rates_basis_points(&self, volatility_accumulator: u64, timestamp: i64) -> (u16, u16) {
+        let providers_fee_rate_basis_points = self.effective_providers_fee_rate_basis_points(volatility_accumulator, timestamp);
+        let protocol_fee_rate_basis_points = self.current_protocol_fee_rate_basis_points(timestamp);
+        if self.protocol_fee_on_lp_enabled {
+            (providers_fee_rate_basis_points + protocol_fee_rate_basis_points, 0)
+        }
+        else {
+            (providers_fee_rate_basis_points, protocol_fee_rate_basis_points)
+        }
+    }
+
+    /// Retrieves the providers and protocol fee rates in effect at the given timestamp,
+    /// taking the pending change into account without storing it.
+    ///
+    /// # Parameters
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Returns
+    /// - The `(u16, u16)` providers and protocol fee rates, measured in basis points.
+    pub fn current_fee_rates_basis_points(&self, timestamp: i64) -> (u16, u16) {
+        if self.pending_fee_rates_effective_timestamp != 0 && timestamp >= self.pending_fee_rates_effective_timestamp {
+            (self.pending_providers_fee_rate_basis_points, self.pending_protocol_fee_rate_basis_points)
+        } else {
+            (self.providers_fee_rate_basis_points, self.protocol_fee_rate_basis_points)
+        }
+    }
+
+    /// Retrieves the providers fee rate in effect at the given timestamp.
+    #[inline]
+    pub fn current_providers_fee_rate_basis_points(&self, timestamp: i64) -> u16 {
+        self.current_fee_rates_basis_points(timestamp).0
+    }
+
+    /// Retrieves the protocol fee rate in effect at the given timestamp.
+    #[inline]
+    pub fn current_protocol_fee_rate_basis_points(&self, timestamp: i64) -> u16 {
+        self.current_fee_rates_basis_points(timestamp).1
+    }
+
+    /// Retrieves the fee rates the config ends up with, the pending ones if a change is scheduled.
+    fn pending_fee_rates_basis_points(&self) -> (u16, u16) {
+        if self.pending_fee_rates_effective_timestamp != 0 {
+            (self.pending_providers_fee_rate_basis_points, self.pending_protocol_fee_rate_basis_points)
+        } else {
+            (self.providers_fee_rate_basis_points, self.protocol_fee_rate_basis_points)
+        }
+    }
+
     /// Retrieves the public key of the current fee authority.
     ///
     /// # Returns
@@ -136,6 +486,122 @@ impl AmmsConfig {
     pub fn protocol_fee_rate_basis_points(&self) -> u16 {
         self.protocol_fee_rate_basis_points
     }
+
+    /// Checks whether the dynamic providers fee mode is enabled.
+    #[inline]
+    pub fn dynamic_fee_enabled(&self) -> bool {
+        self.dynamic_fee_enabled
+    }
+
+    /// Retrieves the lower bound of the dynamic providers fee rate, measured in basis points.
+    #[inline]
+    pub fn min_providers_fee_rate_basis_points(&self) -> u16 {
+        self.min_providers_fee_rate_basis_points
+    }
+
+    /// Retrieves the upper bound of the dynamic providers fee rate, measured in basis points.
+    #[inline]
+    pub fn max_providers_fee_rate_basis_points(&self) -> u16 {
+        self.max_providers_fee_rate_basis_points
+    }
+
+    /// Checks whether swaps are halted in all pools of this configuration.
+    #[inline]
+    pub fn swaps_paused(&self) -> bool {
+        self.swaps_paused
+    }
+
+    /// Checks whether liquidity provision is halted in all pools of this configuration.
+    #[inline]
+    pub fn provides_paused(&self) -> bool {
+        self.provides_paused
+    }
+
+    /// Checks whether the protocol fee of CpAmm swaps is collected as LP tokens.
+    #[inline]
+    pub fn protocol_fee_on_lp_enabled(&self) -> bool {
+        self.protocol_fee_on_lp_enabled
+    }
+
+    /// Retrieves the fee rate of CpAmm flash loans, measured in basis points.
+    #[inline]
+    pub fn flash_loan_fee_rate_basis_points(&self) -> u16 {
+        self.flash_loan_fee_rate_basis_points
+    }
+
+    /// Retrieves the pool creation fee amount, in lamports or in tokens of `pool_creation_fee_mint`.
+    #[inline]
+    pub fn pool_creation_fee_amount(&self) -> u64 {
+        self.pool_creation_fee_amount
+    }
+
+    /// Retrieves the mint of the token the pool creation fee is paid in.
+    ///
+    /// # Returns
+    /// - `None` if the fee is paid in native SOL.
+    #[inline]
+    pub fn pool_creation_fee_mint(&self) -> Option<&Pubkey> {
+        if self.pool_creation_fee_mint == Pubkey::default() {
+            None
+        } else {
+            Some(&self.pool_creation_fee_mint)
+        }
+    }
+
+    /// Checks whether pool creation is restricted to the `AllowedCreator` allow-list.
+    #[inline]
+    pub fn creator_restricted(&self) -> bool {
+        self.creator_restricted
+    }
+
+    /// Retrieves the share of the protocol fee paid to swap referrers, measured in basis points of the protocol fee.
+    #[inline]
+    pub fn referrer_fee_share_basis_points(&self) -> u16 {
+        self.referrer_fee_share_basis_points
+    }
+
+    /// Retrieves the share of the protocol fee paid to the referrer of a CpAmm swap.
+    ///
+    /// With the fee-on-LP mode enabled, CpAmm swaps take no protocol fee to share,
+    /// so referred swaps are refused instead of paying the referrer nothing.
+    ///
+    /// # Errors
+    /// - `ReferrerUnsupportedWithProtocolFeeOnLp` if the fee-on-LP mode is enabled.
+    pub fn cp_amm_referrer_fee_share_basis_points(&self) -> Result<u16> {
+        require!(!self.protocol_fee_on_lp_enabled, ErrorCode::ReferrerUnsupportedWithProtocolFeeOnLp);
+        Ok(self.referrer_fee_share_basis_points)
+    }
+
+    /// Retrieves the delay of fee rate changes, in seconds.
+    #[inline]
+    pub fn fee_rates_timelock_duration(&self) -> i64 {
+        self.fee_rates_timelock_duration
+    }
+
+    /// Retrieves the scheduled providers fee rate.
+    #[inline]
+    pub fn pending_providers_fee_rate_basis_points(&self) -> u16 {
+        self.pending_providers_fee_rate_basis_points
+    }
+
+    /// Retrieves the scheduled protocol fee rate.
+    #[inline]
+    pub fn pending_protocol_fee_rate_basis_points(&self) -> u16 {
+        self.pending_protocol_fee_rate_basis_points
+    }
+
+    /// Retrieves the timestamp from which the scheduled fee rates are in effect.
+    ///
+    /// # Returns
+    /// - `None` if there is no scheduled change.
+    #[inline]
+    pub fn pending_fee_rates_effective_timestamp(&self) -> Option<i64> {
+        if self.pending_fee_rates_effective_timestamp == 0 {
+            None
+        } else {
+            Some(self.pending_fee_rates_effective_timestamp)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -153,6 +619,21 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 0,
             protocol_fee_rate_basis_points: 0,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
         };
 
         let fee_authority = Pubkey::new_unique();
@@ -173,6 +654,9 @@ mod amms_config_tests {
         assert_eq!(amms_config.fee_authority().key(), fee_authority);
         assert_eq!(amms_config.protocol_fee_rate_basis_points(), protocol_fee_rate_basis_points);
         assert_eq!(amms_config.providers_fee_rate_basis_points(), providers_fee_rate_basis_points);
+        assert_eq!(amms_config.pool_creation_fee_amount(), AmmsConfig::DEFAULT_POOL_CREATION_FEE_IN_LAMPORTS);
+        assert_eq!(amms_config.pool_creation_fee_mint(), None);
+        assert!(!amms_config.creator_restricted());
 
     }
 
@@ -186,6 +670,21 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
         };
 
         let new_fee_authority = Pubkey::new_unique();
@@ -202,12 +701,27 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
         };
 
         let new_providers_fee_rate = 234;
-        amms_config.update_providers_fee_rate(new_providers_fee_rate).unwrap();
+        amms_config.update_providers_fee_rate(new_providers_fee_rate, 0).unwrap();
         assert_eq!(amms_config.providers_fee_rate_basis_points, new_providers_fee_rate);
-        assert_eq!(amms_config.update_providers_fee_rate(9801).ok(), None);
+        assert_eq!(amms_config.update_providers_fee_rate(9801, 0).ok(), None);
     }
 
     /// Tests the `update_protocol_fee_rate` method of the `AmmsConfig` struct.
@@ -219,12 +733,391 @@ mod amms_config_tests {
             fee_authority: Pubkey::default(),
             providers_fee_rate_basis_points: 300,
             protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
         };
 
         let new_protocol_fee_rate = 234;
-        amms_config.update_protocol_fee_rate(new_protocol_fee_rate).unwrap();
+        amms_config.update_protocol_fee_rate(new_protocol_fee_rate, 0).unwrap();
         assert_eq!(amms_config.protocol_fee_rate_basis_points, new_protocol_fee_rate);
-        assert_eq!(amms_config.update_protocol_fee_rate(9701).ok(), None);
+        assert_eq!(amms_config.update_protocol_fee_rate(9701, 0).ok(), None);
+    }
+
+    /// Tests the `update_dynamic_fee` and `effective_providers_fee_rate_basis_points` methods of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_dynamic_fee() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(u64::MAX, 0), 30);
+
+        assert_eq!(amms_config.update_dynamic_fee(true, 101, 100, 0).ok(), None);
+        assert_eq!(amms_config.update_dynamic_fee(true, 10, 9801, 0).ok(), None);
+        amms_config.update_dynamic_fee(true, 10, 110, 0).unwrap();
+        assert!(amms_config.dynamic_fee_enabled());
+        assert_eq!(amms_config.min_providers_fee_rate_basis_points(), 10);
+        assert_eq!(amms_config.max_providers_fee_rate_basis_points(), 110);
+
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(0, 0), 10);
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(AmmsConfig::VOLATILITY_REFERENCE_BASIS_POINTS / 2, 0), 60);
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(AmmsConfig::VOLATILITY_REFERENCE_BASIS_POINTS, 0), 110);
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(u64::MAX, 0), 110);
+
+        // Protocol fee rate is bounded by the dynamic providers fee rate upper bound
+        assert_eq!(amms_config.update_protocol_fee_rate(9891, 0).ok(), None);
+        amms_config.update_protocol_fee_rate(9890, 0).unwrap();
+
+        amms_config.update_dynamic_fee(false, 10, 110, 0).unwrap();
+        assert_eq!(amms_config.effective_providers_fee_rate_basis_points(u64::MAX, 0), 30);
+    }
+
+    /// Tests the timelocked fee rate changes of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_fee_rates_timelock() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+        assert_eq!(amms_config.update_fee_rates_timelock(AmmsConfig::MAX_FEE_RATES_TIMELOCK_DURATION + 1).ok(), None);
+        amms_config.update_fee_rates_timelock(1_000).unwrap();
+        assert_eq!(amms_config.fee_rates_timelock_duration(), 1_000);
+        assert_eq!(amms_config.update_fee_rates_timelock(999).ok(), None);
+
+        // Changes are scheduled and merged into the pending one
+        amms_config.update_providers_fee_rate(250, 100).unwrap();
+        assert_eq!(amms_config.providers_fee_rate_basis_points(), 300);
+        assert_eq!(amms_config.pending_fee_rates_effective_timestamp(), Some(1_100));
+        amms_config.update_protocol_fee_rate(150, 200).unwrap();
+        assert_eq!(amms_config.pending_providers_fee_rate_basis_points(), 250);
+        assert_eq!(amms_config.pending_protocol_fee_rate_basis_points(), 150);
+        assert_eq!(amms_config.pending_fee_rates_effective_timestamp(), Some(1_200));
+        assert_eq!(amms_config.update_protocol_fee_rate(9751, 300).ok(), None);
+
+        // Rates are in effect from the effective timestamp, without being stored
+        assert_eq!(amms_config.current_fee_rates_basis_points(1_199), (300, 200));
+        assert_eq!(amms_config.current_fee_rates_basis_points(1_200), (250, 150));
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(0, 1_200), (250, 150));
+        assert_eq!(amms_config.protocol_fee_rate_basis_points(), 200);
+
+        // Pending changes are cancellable until they are in effect
+        amms_config.cancel_pending_fee_rates(1_199).unwrap();
+        assert_eq!(amms_config.pending_fee_rates_effective_timestamp(), None);
+        assert_eq!(amms_config.current_fee_rates_basis_points(1_200), (300, 200));
+        assert_eq!(amms_config.cancel_pending_fee_rates(1_200).ok(), None);
+
+        amms_config.update_providers_fee_rate(250, 2_000).unwrap();
+        assert_eq!(amms_config.cancel_pending_fee_rates(3_000).ok(), None);
+        assert_eq!(amms_config.current_fee_rates_basis_points(3_000), (250, 200));
+        assert_eq!(amms_config.providers_fee_rate_basis_points(), 250);
+        assert_eq!(amms_config.pending_fee_rates_effective_timestamp(), None);
+
+        // The dynamic fee upper bound is validated against the pending protocol fee rate
+        amms_config.update_protocol_fee_rate(9_000, 4_000).unwrap();
+        assert_eq!(amms_config.update_dynamic_fee(true, 10, 1_001, 4_000).ok(), None);
+        amms_config.update_dynamic_fee(true, 10, 1_000, 4_000).unwrap();
+    }
+
+    /// Tests the `update_pause` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_pause() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 300,
+            protocol_fee_rate_basis_points: 200,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+
+        amms_config.update_pause(true, false);
+        assert!(amms_config.swaps_paused());
+        assert!(!amms_config.provides_paused());
+
+        amms_config.update_pause(false, true);
+        assert!(!amms_config.swaps_paused());
+        assert!(amms_config.provides_paused());
+
+        amms_config.update_pause(false, false);
+        assert!(!amms_config.swaps_paused());
+        assert!(!amms_config.provides_paused());
+    }
+
+    /// Tests the `update_protocol_fee_mode` and `effective_swap_fee_rates_basis_points` methods of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_protocol_fee_mode() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 20,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(0, 0), (30, 20));
+
+        amms_config.update_protocol_fee_mode(true);
+        assert!(amms_config.protocol_fee_on_lp_enabled());
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(0, 0), (50, 0));
+
+        amms_config.update_dynamic_fee(true, 10, 110, 0).unwrap();
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(u64::MAX, 0), (130, 0));
+
+        amms_config.update_protocol_fee_mode(false);
+        assert!(!amms_config.protocol_fee_on_lp_enabled());
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(u64::MAX, 0), (110, 20));
+    }
+
+    /// Tests the `update_flash_loan_fee_rate` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_flash_loan_fee_rate() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 20,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+
+        amms_config.update_flash_loan_fee_rate(9).unwrap();
+        assert_eq!(amms_config.flash_loan_fee_rate_basis_points(), 9);
+        assert_eq!(amms_config.update_flash_loan_fee_rate(10001).ok(), None);
+        assert_eq!(amms_config.flash_loan_fee_rate_basis_points(), 9);
+    }
+
+    /// Tests the `update_pool_creation_fee` and `update_creator_restriction` methods of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_pool_creation() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 20,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: AmmsConfig::DEFAULT_POOL_CREATION_FEE_IN_LAMPORTS,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+
+        let pool_creation_fee_mint = Pubkey::new_unique();
+        amms_config.update_pool_creation_fee(1_000_000, Some(pool_creation_fee_mint));
+        assert_eq!(amms_config.pool_creation_fee_amount(), 1_000_000);
+        assert_eq!(amms_config.pool_creation_fee_mint(), Some(&pool_creation_fee_mint));
+
+        amms_config.update_pool_creation_fee(0, None);
+        assert_eq!(amms_config.pool_creation_fee_amount(), 0);
+        assert_eq!(amms_config.pool_creation_fee_mint(), None);
+
+        amms_config.update_creator_restriction(true);
+        assert!(amms_config.creator_restricted());
+        amms_config.update_creator_restriction(false);
+        assert!(!amms_config.creator_restricted());
+    }
+
+    /// Tests the `update_referrer_fee_share` method of the `AmmsConfig` struct.
+    #[test]
+    fn test_amms_config_update_referrer_fee_share() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 20,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 0,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+
+        amms_config.update_referrer_fee_share(AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS).unwrap();
+        assert_eq!(amms_config.referrer_fee_share_basis_points(), AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS);
+        assert_eq!(amms_config.update_referrer_fee_share(AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS + 1).ok(), None);
+        amms_config.update_referrer_fee_share(0).unwrap();
+        assert_eq!(amms_config.referrer_fee_share_basis_points(), 0);
+    }
+
+    /// Tests that the `cp_amm_referrer_fee_share_basis_points` method of the `AmmsConfig` struct refuses the fee-on-LP mode.
+    #[test]
+    fn test_amms_config_cp_amm_referrer_fee_share() {
+        let mut amms_config = AmmsConfig {
+            bump: 42,
+            id: 42,
+            fee_authority: Pubkey::default(),
+            providers_fee_rate_basis_points: 30,
+            protocol_fee_rate_basis_points: 20,
+            dynamic_fee_enabled: false,
+            min_providers_fee_rate_basis_points: 0,
+            max_providers_fee_rate_basis_points: 0,
+            swaps_paused: false,
+            provides_paused: false,
+            protocol_fee_on_lp_enabled: false,
+            flash_loan_fee_rate_basis_points: 0,
+            pool_creation_fee_amount: 0,
+            pool_creation_fee_mint: Pubkey::default(),
+            creator_restricted: false,
+            referrer_fee_share_basis_points: 2_000,
+            fee_rates_timelock_duration: 0,
+            pending_providers_fee_rate_basis_points: 0,
+            pending_protocol_fee_rate_basis_points: 0,
+            pending_fee_rates_effective_timestamp: 0,
+        };
+        assert_eq!(amms_config.cp_amm_referrer_fee_share_basis_points().unwrap(), 2_000);
+
+        // No protocol fee is taken at swap time, so there is nothing to share
+        amms_config.update_protocol_fee_mode(true);
+        assert_eq!(amms_config.effective_swap_fee_rates_basis_points(0, 0), (50, 0));
+        assert!(amms_config.cp_amm_referrer_fee_share_basis_points().is_err());
+        assert_eq!(amms_config.referrer_fee_share_basis_points(), 2_000);
+
+        amms_config.update_protocol_fee_mode(false);
+        assert_eq!(amms_config.cp_amm_referrer_fee_share_basis_points().unwrap(), 2_000);
+    }
+
+    /// Tests that an `AmmsConfig` stored with the initial layout loads once grown with zeroed appended fields.
+    #[test]
+    fn test_amms_config_initial_layout_migration() {
+        let fee_authority = Pubkey::new_unique();
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 118];
+        let mut offset = 0;
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfig::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = 42; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&7u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(fee_authority.as_ref()); offset += 32;
+        data[offset..offset + 2].copy_from_slice(&200u16.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&300u16.to_le_bytes()); offset += 2;
+        assert_eq!(offset, AmmsConfig::INITIAL_LAYOUT_LEN);
+
+        assert!(AmmsConfig::try_deserialize(&mut &data[..AmmsConfig::INITIAL_LAYOUT_LEN]).is_err());
+
+        let migrated_amms_config = AmmsConfig::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(data.len(), ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE);
+        assert_eq!(migrated_amms_config.bump(), 42);
+        assert_eq!(migrated_amms_config.id, 7);
+        assert_eq!(migrated_amms_config.fee_authority(), &fee_authority);
+        assert_eq!(migrated_amms_config.providers_fee_rate_basis_points(), 200);
+        assert_eq!(migrated_amms_config.protocol_fee_rate_basis_points(), 300);
+        assert_eq!(migrated_amms_config.effective_swap_fee_rates_basis_points(0, 0), (200, 300));
+        assert!(!migrated_amms_config.swaps_paused());
+        assert!(!migrated_amms_config.provides_paused());
+        assert!(!migrated_amms_config.protocol_fee_on_lp_enabled());
+        assert_eq!(migrated_amms_config.pool_creation_fee_amount(), 0);
+        assert_eq!(migrated_amms_config.pool_creation_fee_mint(), None);
+        assert_eq!(migrated_amms_config.pending_fee_rates_effective_timestamp(), None);
     }
 
     /// Tests `AmmsConfig` account data layout.
@@ -236,7 +1129,26 @@ mod amms_config_tests {
         let providers_fee_rate_basis_points: u16 = 200;
         let protocol_fee_rate_basis_points: u16 = 300;
 
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 45];
+        let dynamic_fee_enabled = true;
+        let min_providers_fee_rate_basis_points: u16 = 10;
+        let max_providers_fee_rate_basis_points: u16 = 500;
+
+        let swaps_paused = true;
+        let provides_paused = false;
+        let protocol_fee_on_lp_enabled = true;
+        let flash_loan_fee_rate_basis_points: u16 = 9;
+
+        let pool_creation_fee_amount = 1_000_000u64;
+        let pool_creation_fee_mint = Pubkey::new_unique();
+        let creator_restricted = true;
+        let referrer_fee_share_basis_points: u16 = 2_000;
+
+        let fee_rates_timelock_duration = 86_400i64;
+        let pending_providers_fee_rate_basis_points: u16 = 150;
+        let pending_protocol_fee_rate_basis_points: u16 = 250;
+        let pending_fee_rates_effective_timestamp = 1_700_000_000i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 118];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfig::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -245,6 +1157,21 @@ mod amms_config_tests {
         data[offset..offset + 32].copy_from_slice(fee_authority.as_ref()); offset += 32;
         data[offset..offset + 2].copy_from_slice(&providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
         data[offset..offset + 2].copy_from_slice(&protocol_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset] = dynamic_fee_enabled as u8; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&min_providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&max_providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset] = swaps_paused as u8; offset += 1;
+        data[offset] = provides_paused as u8; offset += 1;
+        data[offset] = protocol_fee_on_lp_enabled as u8; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&flash_loan_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 8].copy_from_slice(&pool_creation_fee_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(pool_creation_fee_mint.as_ref()); offset += 32;
+        data[offset] = creator_restricted as u8; offset += 1;
+        data[offset..offset + 2].copy_from_slice(&referrer_fee_share_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 8].copy_from_slice(&fee_rates_timelock_duration.to_le_bytes()); offset += 8;
+        data[offset..offset + 2].copy_from_slice(&pending_providers_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&pending_protocol_fee_rate_basis_points.to_le_bytes()); offset += 2;
+        data[offset..offset + 8].copy_from_slice(&pending_fee_rates_effective_timestamp.to_le_bytes()); offset += 8;
 
         assert_eq!(ANCHOR_DISCRIMINATOR + AmmsConfig::INIT_SPACE, offset);
         
@@ -255,6 +1182,21 @@ mod amms_config_tests {
         assert_eq!(deserialized_amms_config.fee_authority, fee_authority);
         assert_eq!(deserialized_amms_config.providers_fee_rate_basis_points, providers_fee_rate_basis_points);
         assert_eq!(deserialized_amms_config.protocol_fee_rate_basis_points, protocol_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.dynamic_fee_enabled, dynamic_fee_enabled);
+        assert_eq!(deserialized_amms_config.min_providers_fee_rate_basis_points, min_providers_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.max_providers_fee_rate_basis_points, max_providers_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.swaps_paused, swaps_paused);
+        assert_eq!(deserialized_amms_config.provides_paused, provides_paused);
+        assert_eq!(deserialized_amms_config.protocol_fee_on_lp_enabled, protocol_fee_on_lp_enabled);
+        assert_eq!(deserialized_amms_config.flash_loan_fee_rate_basis_points, flash_loan_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.pool_creation_fee_amount, pool_creation_fee_amount);
+        assert_eq!(deserialized_amms_config.pool_creation_fee_mint, pool_creation_fee_mint);
+        assert_eq!(deserialized_amms_config.creator_restricted, creator_restricted);
+        assert_eq!(deserialized_amms_config.referrer_fee_share_basis_points, referrer_fee_share_basis_points);
+        assert_eq!(deserialized_amms_config.fee_rates_timelock_duration, fee_rates_timelock_duration);
+        assert_eq!(deserialized_amms_config.pending_providers_fee_rate_basis_points, pending_providers_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.pending_protocol_fee_rate_basis_points, pending_protocol_fee_rate_basis_points);
+        assert_eq!(deserialized_amms_config.pending_fee_rates_effective_timestamp, pending_fee_rates_effective_timestamp);
 
         let mut serialized_amms_config = Vec::new();
         deserialized_amms_config.try_serialize(&mut serialized_amms_config).unwrap();
@@ -1,5 +1,6 @@
 use anchor_lang::{account, InitSpace};
 use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
 
 /// Represents the manager for AMM configurations.
 ///
@@ -20,12 +21,24 @@ pub struct AmmsConfigsManager {
 
     /// The canonical bump seed used for the account's PDA.
     bump: u8,   // 1 byte
+
+    /// The authority proposed to replace `authority`, which takes over once it accepts the transfer.
+    /// The default public key stands for no pending transfer.
+    pending_authority: Pubkey, // 32 bytes
+
+    /// The head authority proposed to replace `head_authority`, which takes over once it accepts the transfer.
+    /// The default public key stands for no pending transfer.
+    pending_head_authority: Pubkey, // 32 bytes
 }
 
 impl AmmsConfigsManager {
     /// The seed used to derive the account's PDA.
     pub const SEED: &'static [u8] = b"amms_configs_manager";
 
+    /// The size of the initial account layout, before the pending authority fields were appended.
+    /// Such accounts are grown by `migrate_amms_configs_manager`.
+    pub const INITIAL_LAYOUT_LEN: usize = 8 + 73;
+
     /// Initializes the `AmmsConfigsManager` with the provided parameters.
     ///
     /// # Parameters
@@ -59,6 +72,68 @@ impl AmmsConfigsManager {
         self.head_authority = head_authority;
     }
 
+    /// Proposes a new authority, which takes over once it accepts the transfer.
+    ///
+    /// A new proposal replaces the pending one.
+    ///
+    /// # Parameters
+    /// - `pending_authority`: The public key of the proposed authority.
+    pub(crate) fn propose_authority(&mut self, pending_authority: Pubkey) {
+        self.pending_authority = pending_authority;
+    }
+
+    /// Completes the pending authority transfer.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::NoPendingAuthorityTransfer` if no authority is proposed.
+    pub(crate) fn accept_authority(&mut self) -> Result<()> {
+        require!(self.pending_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.update_authority(self.pending_authority);
+        self.pending_authority = Pubkey::default();
+        Ok(())
+    }
+
+    /// Cancels the pending authority transfer.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::NoPendingAuthorityTransfer` if no authority is proposed.
+    pub(crate) fn cancel_authority_transfer(&mut self) -> Result<()> {
+        require!(self.pending_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.pending_authority = Pubkey::default();
+        Ok(())
+    }
+
+    /// Proposes a new head authority, which takes over once it accepts the transfer.
+    ///
+    /// A new proposal replaces the pending one.
+    ///
+    /// # Parameters
+    /// - `pending_head_authority`: The public key of the proposed head authority.
+    pub(crate) fn propose_head_authority(&mut self, pending_head_authority: Pubkey) {
+        self.pending_head_authority = pending_head_authority;
+    }
+
+    /// Completes the pending head authority transfer.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::NoPendingAuthorityTransfer` if no head authority is proposed.
+    pub(crate) fn accept_head_authority(&mut self) -> Result<()> {
+        require!(self.pending_head_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.update_head_authority(self.pending_head_authority);
+        self.pending_head_authority = Pubkey::default();
+        Ok(())
+    }
+
+    /// Cancels the pending head authority transfer.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::NoPendingAuthorityTransfer` if no head authority is proposed.
+    pub(crate) fn cancel_head_authority_transfer(&mut self) -> Result<()> {
+        require!(self.pending_head_authority != Pubkey::default(), ErrorCode::NoPendingAuthorityTransfer);
+        self.pending_head_authority = Pubkey::default();
+        Ok(())
+    }
+
     /// Increments the `configs_count` field by 1.
     ///
     /// # Behavior
@@ -86,6 +161,32 @@ impl AmmsConfigsManager {
         &self.authority
     }
 
+    /// Retrieves the authority proposed to replace the current one.
+    ///
+    /// # Returns
+    /// - `None` if there is no pending authority transfer.
+    #[inline]
+    pub fn pending_authority(&self) -> Option<&Pubkey> {
+        if self.pending_authority == Pubkey::default() {
+            None
+        } else {
+            Some(&self.pending_authority)
+        }
+    }
+
+    /// Retrieves the head authority proposed to replace the current one.
+    ///
+    /// # Returns
+    /// - `None` if there is no pending head authority transfer.
+    #[inline]
+    pub fn pending_head_authority(&self) -> Option<&Pubkey> {
+        if self.pending_head_authority == Pubkey::default() {
+            None
+        } else {
+            Some(&self.pending_head_authority)
+        }
+    }
+
     /// Retrieves the total number of AMM configurations managed by this account.
     ///
     /// # Returns
@@ -119,6 +220,8 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 0,
             bump: 0,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let authority = Pubkey::new_unique();
@@ -146,6 +249,8 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 10,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let new_authority = Pubkey::new_unique();
@@ -162,6 +267,8 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::default(),
             configs_count: 10,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         let new_head_authority = Pubkey::new_unique();
@@ -170,6 +277,49 @@ mod amms_configs_manager_tests {
         assert_eq!(manager.head_authority, new_head_authority);
     }
 
+    /// Tests the two-step authority and head authority transfers of the `AmmsConfigsManager` struct.
+    #[test]
+    fn test_amms_configs_manager_authority_transfers() {
+        let authority = Pubkey::new_unique();
+        let head_authority = Pubkey::new_unique();
+        let mut manager = AmmsConfigsManager {
+            authority,
+            head_authority,
+            configs_count: 10,
+            bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
+        };
+        assert!(manager.accept_authority().is_err());
+        assert!(manager.cancel_authority_transfer().is_err());
+        assert!(manager.accept_head_authority().is_err());
+        assert!(manager.cancel_head_authority_transfer().is_err());
+
+        let new_authority = Pubkey::new_unique();
+        manager.propose_authority(new_authority);
+        assert_eq!(manager.pending_authority(), Some(&new_authority));
+        assert_eq!(manager.authority(), &authority);
+        manager.cancel_authority_transfer().unwrap();
+        assert_eq!(manager.pending_authority(), None);
+
+        manager.propose_authority(new_authority);
+        manager.accept_authority().unwrap();
+        assert_eq!(manager.authority(), &new_authority);
+        assert_eq!(manager.pending_authority(), None);
+
+        let new_head_authority = Pubkey::new_unique();
+        manager.propose_head_authority(new_head_authority);
+        assert_eq!(manager.pending_head_authority(), Some(&new_head_authority));
+        assert_eq!(manager.head_authority(), &head_authority);
+        manager.cancel_head_authority_transfer().unwrap();
+        assert_eq!(manager.pending_head_authority(), None);
+
+        manager.propose_head_authority(new_head_authority);
+        manager.accept_head_authority().unwrap();
+        assert_eq!(manager.head_authority(), &new_head_authority);
+        assert_eq!(manager.pending_head_authority(), None);
+    }
+
     /// Tests the `increment_configs_count` method of the `AmmsConfigsManager` struct.
     #[test]
     fn test_amms_configs_manager_increment_configs_count(){
@@ -178,6 +328,8 @@ mod amms_configs_manager_tests {
             head_authority: Pubkey::new_unique(),
             configs_count: 5,
             bump: 42,
+            pending_authority: Pubkey::default(),
+            pending_head_authority: Pubkey::default(),
         };
 
         manager.increment_configs_count();
@@ -192,8 +344,10 @@ mod amms_configs_manager_tests {
         let head_authority = Pubkey::new_unique();
         let configs_count = 42u64;
         let bump = 42u8;
+        let pending_authority = Pubkey::new_unique();
+        let pending_head_authority = Pubkey::new_unique();
 
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 73];
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 137];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -201,8 +355,10 @@ mod amms_configs_manager_tests {
         data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
         data[offset..offset + 8].copy_from_slice(&configs_count.to_le_bytes()); offset += 8;
         data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(pending_authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(pending_head_authority.as_ref()); offset += 32;
 
-        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 73);
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 137);
         
         let deserialized_manager = AmmsConfigsManager::try_deserialize(&mut data.as_ref()).unwrap();
 
@@ -210,9 +366,37 @@ mod amms_configs_manager_tests {
         assert_eq!(deserialized_manager.head_authority, head_authority);
         assert_eq!(deserialized_manager.configs_count, configs_count);
         assert_eq!(deserialized_manager.bump, bump);
+        assert_eq!(deserialized_manager.pending_authority, pending_authority);
+        assert_eq!(deserialized_manager.pending_head_authority, pending_head_authority);
 
         let mut serialized_data = Vec::new();
         deserialized_manager.try_serialize(&mut serialized_data).unwrap();
         assert_eq!(serialized_data.as_slice(), data.as_ref());
     }
+
+    /// Tests that an `AmmsConfigsManager` stored with the initial layout loads once grown with zeroed appended fields.
+    #[test]
+    fn test_amms_configs_manager_initial_layout_migration() {
+        let authority = Pubkey::new_unique();
+        let head_authority = Pubkey::new_unique();
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 137];
+        let mut offset = 0;
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&AmmsConfigsManager::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset..offset + 32].copy_from_slice(authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(head_authority.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&3u64.to_le_bytes()); offset += 8;
+        data[offset] = 42; offset += 1;
+        assert_eq!(offset, AmmsConfigsManager::INITIAL_LAYOUT_LEN);
+
+        assert!(AmmsConfigsManager::try_deserialize(&mut &data[..AmmsConfigsManager::INITIAL_LAYOUT_LEN]).is_err());
+
+        let migrated_manager = AmmsConfigsManager::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(data.len(), ANCHOR_DISCRIMINATOR + AmmsConfigsManager::INIT_SPACE);
+        assert_eq!(migrated_manager.authority(), &authority);
+        assert_eq!(migrated_manager.head_authority(), &head_authority);
+        assert_eq!(migrated_manager.configs_count(), 3);
+        assert_eq!(migrated_manager.bump(), 42);
+        assert_eq!(migrated_manager.pending_authority(), None);
+        assert_eq!(migrated_manager.pending_head_authority(), None);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,651 @@
+use anchor_lang::prelude::*;
+use utilities::math::{Q64_128, U384};
+use crate::error::ErrorCode;
+use crate::state::cl_amm::{ClAmmCore, ClAmmTick, ClAmmTickArray};
+
+/// Q128.128 values of `1 / sqrt(1.0001)^(2^i)`, used to calculate the square root price of a tick bit by bit.
+const SQRT_PRICE_TICK_FACTORS: [u128; 19] = [
+    0xfffcb933bd6fad37aa2d162d1a594001,
+    0xfff97272373d413259a46990580e213a,
+    0xfff2e50f5f656932ef12357cf3c7fdcc,
+    0xffe5caca7e10e4e61c3624eaa0941cd0,
+    0xffcb9843d60f6159c9db58835c926644,
+    0xff973b41fa98c081472e6896dfb254c0,
+    0xff2ea16466c96a3843ec78b326b52861,
+    0xfe5dee046a99a2a811c461f1969c3053,
+    0xfcbe86c7900a88aedcffc83b479aa3a4,
+    0xf987a7253ac413176f2b074cf7815e54,
+    0xf3392b0822b70005940c7a398e4b70f3,
+    0xe7159475a2c29b7443b29c7fa6e889d9,
+    0xd097f3bdfd2022b8845ad8f792aa5825,
+    0xa9f746462d870fdf8a65dc1f90e061e5,
+    0x70d869a156d2a1b890bb3df62baf32f7,
+    0x31be135f97d08fd981231505542fcfa6,
+    0x09aa508b5b7a84e1c677de54f3e99bc9,
+    0x005d6af8dedb81196699c329225ee604,
+    0x00002216e584f5fa1ea926041bedfe98,
+];
+
+/// `log2(sqrt(1.0001))` as a Q64.64 fixed-point number.
+const LOG2_SQRT_TICK_BASE: i128 = 1_330_584_781_654_114;
+
+/// A trait for implementing core calculations and constants for a Concentrated Liquidity Automated Market Maker.
+///
+/// The price range is split into ticks, the square root price of the tick `i` is `sqrt(1.0001)^i`.
+/// Within a range without initialized ticks the pool behaves like a constant product pool with
+/// virtual liquidity `L`, so `base = L / sqrt(P)` and `quote = L * sqrt(P)`, where `P` is the quote-per-base price.
+pub(crate) trait ClAmmCalculate: ClAmmCore {
+    /// The maximum allowable fee rate, expressed in basis points.
+    ///
+    /// - 1 basis point = 0.01%.
+    /// - `FEE_MAX_BASIS_POINTS = 10000` corresponds to a maximum fee rate of 100%.
+    const FEE_MAX_BASIS_POINTS: u128 = 10000;
+
+    /// The minimal tick, its square root price is slightly above `2^-32`.
+    const MIN_TICK: i32 = -443636;
+
+    /// The maximal tick, its square root price is slightly below `2^32`.
+    const MAX_TICK: i32 = 443636;
+
+    /// The maximal allowed tick spacing.
+    const MAX_TICK_SPACING: u16 = 16384;
+
+    /// The number of fractional bits of the logarithm calculated when searching the tick of a price.
+    const LOG2_PRECISION_BITS: u32 = 16;
+
+    /// Validates a position price range.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the ticks are ordered, aligned to the tick spacing and within the tick bounds.
+    /// - `Err(ErrorCode::InvalidTickRange)` otherwise.
+    fn validate_tick_range(&self, tick_lower: i32, tick_upper: i32) -> Result<()> {
+        let tick_spacing = self.tick_spacing() as i32;
+        require!(
+            tick_lower < tick_upper &&
+            tick_lower >= Self::MIN_TICK &&
+            tick_upper <= Self::MAX_TICK &&
+            tick_lower % tick_spacing == 0 &&
+            tick_upper % tick_spacing == 0,
+            ErrorCode::InvalidTickRange
+        );
+        Ok(())
+    }
+
+    /// Validates the start tick of a new tick array.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the start tick is aligned to the tick array span and the array covers usable ticks.
+    /// - `Err(ErrorCode::InvalidTickArrayStartTick)` otherwise.
+    fn validate_tick_array_start_tick(&self, start_tick: i32) -> Result<()> {
+        let tick_spacing = self.tick_spacing();
+        require!(
+            start_tick == ClAmmTickArray::start_tick_for(start_tick, tick_spacing) &&
+            start_tick + ClAmmTickArray::ticks_span(tick_spacing) > Self::MIN_TICK &&
+            start_tick <= Self::MAX_TICK,
+            ErrorCode::InvalidTickArrayStartTick
+        );
+        Ok(())
+    }
+
+    /// Validates the result of a swap against the estimated result and allowed slippage.
+    ///
+    /// # Parameters
+    /// - `swap_result`: The actual result of the swap.
+    /// - `estimated_swap_result`: The estimated result of the swap.
+    /// - `allowed_slippage`: The allowed slippage tolerance.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the swap result is within the allowed slippage.
+    /// - `Err(ErrorCode)` if the result exceeds the slippage tolerance.
+    #[inline]
+    fn check_swap_result(swap_result: u64, estimated_swap_result: u64, allowed_slippage: u64) -> Result<()> {
+        require!(swap_result > 0, ErrorCode::SwapResultIsZero);
+        require!(swap_result.abs_diff(estimated_swap_result) <= allowed_slippage, ErrorCode::SwapSlippageExceeded);
+        Ok(())
+    }
+
+    /// Calculates the square root price of a tick.
+    ///
+    /// # Returns
+    /// - `Some(Q64_128)` with `sqrt(1.0001)^tick`.
+    /// - `None` if the tick is out of bounds.
+    fn calculate_sqrt_price_at_tick(tick: i32) -> Option<Q64_128> {
+        if !(Self::MIN_TICK..=Self::MAX_TICK).contains(&tick) {
+            return None;
+        }
+        let abs_tick = tick.unsigned_abs();
+        let mut ratio = U384::one() << 128;
+        for (bit, factor) in SQRT_PRICE_TICK_FACTORS.iter().enumerate() {
+            if abs_tick & (1 << bit) != 0 {
+                ratio = (ratio * U384::from(*factor)) >> 128;
+            }
+        }
+        if tick > 0 {
+            ratio = (U384::one() << 256) / ratio;
+        }
+        ratio.checked_as_q64_128()
+    }
+
+    /// Calculates the greatest tick whose square root price doesn't exceed the given one.
+    ///
+    /// The tick is estimated with a binary logarithm of the price and then corrected
+    /// against `calculate_sqrt_price_at_tick`, so both functions are always consistent.
+    ///
+    /// # Returns
+    /// - `Some(i32)` with the tick index.
+    /// - `None` if the price is out of the tick bounds.
+    fn calculate_tick_at_sqrt_price(sqrt_price: Q64_128) -> Option<i32> {
+        if sqrt_price < Self::calculate_sqrt_price_at_tick(Self::MIN_TICK)? || sqrt_price > Self::calculate_sqrt_price_at_tick(Self::MAX_TICK)? {
+            return None;
+        }
+        let ratio = U384::from(sqrt_price);
+        let most_significant_bit = 383 - ratio.leading_zeros() as i32;
+
+        // log2(sqrt_price) as a Q64.64 fixed-point number
+        let mut log2 = ((most_significant_bit - 128) as i128) << 64;
+        let mut normalized = if most_significant_bit >= 127 { ratio >> (most_significant_bit - 127) as usize } else { ratio << (127 - most_significant_bit) as usize };
+        for bit in 0..Self::LOG2_PRECISION_BITS {
+            normalized = (normalized * normalized) >> 127;
+            if !(normalized >> 128).is_zero() {
+                log2 |= 1i128 << (63 - bit);
+                normalized >>= 1;
+            }
+        }
+
+        let mut tick = (log2.div_euclid(LOG2_SQRT_TICK_BASE) as i32).clamp(Self::MIN_TICK, Self::MAX_TICK);
+        while tick > Self::MIN_TICK && Self::calculate_sqrt_price_at_tick(tick)? > sqrt_price {
+            tick -= 1;
+        }
+        while tick < Self::MAX_TICK && Self::calculate_sqrt_price_at_tick(tick + 1)? <= sqrt_price {
+            tick += 1;
+        }
+        Some(tick)
+    }
+
+    /// Calculates the amount of base tokens between two prices for the given liquidity.
+    ///
+    /// `base = L * (sqrt(Pb) - sqrt(Pa)) / (sqrt(Pa) * sqrt(Pb))`
+    ///
+    /// # Parameters
+    /// - `sqrt_price_a`, `sqrt_price_b`: Square roots of the range prices, in any order.
+    /// - `liquidity`: The liquidity in the range.
+    /// - `round_up`: Whether to round the amount up, in favour of the pool for deposits.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount of base tokens.
+    /// - `None` if any price is zero or the amount overflows.
+    fn calculate_base_delta(sqrt_price_a: Q64_128, sqrt_price_b: Q64_128, liquidity: u128, round_up: bool) -> Option<u64> {
+        let (sqrt_price_lower, sqrt_price_upper) = if sqrt_price_a < sqrt_price_b { (sqrt_price_a, sqrt_price_b) } else { (sqrt_price_b, sqrt_price_a) };
+        let (sqrt_price_lower, sqrt_price_upper) = (U384::from(sqrt_price_lower), U384::from(sqrt_price_upper));
+        if sqrt_price_lower.is_zero() {
+            return None;
+        }
+        let numerator = U384::from(liquidity).checked_mul(sqrt_price_upper - sqrt_price_lower)? << 96;
+        let amount = if round_up {
+            let partial = Self::div_round_up(numerator, sqrt_price_upper) << 32;
+            Self::div_round_up(partial, sqrt_price_lower)
+        }
+        else{
+            ((numerator / sqrt_price_upper) << 32) / sqrt_price_lower
+        };
+        amount.checked_as_u64()
+    }
+
+    /// Calculates the amount of quote tokens between two prices for the given liquidity.
+    ///
+    /// `quote = L * (sqrt(Pb) - sqrt(Pa))`
+    ///
+    /// # Parameters
+    /// - `sqrt_price_a`, `sqrt_price_b`: Square roots of the range prices, in any order.
+    /// - `liquidity`: The liquidity in the range.
+    /// - `round_up`: Whether to round the amount up, in favour of the pool for deposits.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount of quote tokens.
+    /// - `None` if the amount overflows.
+    fn calculate_quote_delta(sqrt_price_a: Q64_128, sqrt_price_b: Q64_128, liquidity: u128, round_up: bool) -> Option<u64> {
+        let difference = U384::from(sqrt_price_a.abs_diff(sqrt_price_b));
+        let product = U384::from(liquidity).checked_mul(difference)?;
+        let amount = if round_up { Self::div_round_up(product, U384::one() << 128) } else { product >> 128 };
+        amount.checked_as_u64()
+    }
+
+    /// Calculates the square root price after base tokens are added to the pool.
+    ///
+    /// `1 / sqrt(P') = 1 / sqrt(P) + amount / L`, the result is rounded up, so the price moves less.
+    ///
+    /// # Returns
+    /// - `Some(Q64_128)` with the new square root price.
+    /// - `None` if the liquidity or the price is zero.
+    fn calculate_next_sqrt_price_from_base_in(sqrt_price: Q64_128, liquidity: u128, amount: u64) -> Option<Q64_128> {
+        if liquidity == 0 || sqrt_price.is_zero() {
+            return None;
+        }
+        let one = U384::one() << 256;
+        let inverse_sqrt_price = one / U384::from(sqrt_price);
+        let inverse_sqrt_price = inverse_sqrt_price.checked_add((U384::from(amount) << 128) / U384::from(liquidity))?;
+        Self::div_round_up(one, inverse_sqrt_price).checked_as_q64_128()
+    }
+
+    /// Calculates the square root price after quote tokens are added to the pool.
+    ///
+    /// `sqrt(P') = sqrt(P) + amount / L`, the result is rounded down, so the price moves less.
+    ///
+    /// # Returns
+    /// - `Some(Q64_128)` with the new square root price.
+    /// - `None` if the liquidity is zero or the price overflows.
+    fn calculate_next_sqrt_price_from_quote_in(sqrt_price: Q64_128, liquidity: u128, amount: u64) -> Option<Q64_128> {
+        if liquidity == 0 {
+            return None;
+        }
+        let sqrt_price_delta = ((U384::from(amount) << 128) / U384::from(liquidity)).checked_as_q64_128()?;
+        sqrt_price.checked_add(sqrt_price_delta)
+    }
+
+    /// Calculates the base and quote amounts backing the liquidity in a price range at the current price.
+    ///
+    /// # Parameters
+    /// - `sqrt_price_lower`: Square root of the range lower price.
+    /// - `sqrt_price_upper`: Square root of the range upper price.
+    /// - `liquidity`: The liquidity in the range.
+    /// - `round_up`: Whether to round the amounts up, in favour of the pool for deposits.
+    ///
+    /// # Returns
+    /// - `Some((u64, u64))` with the base and quote amounts.
+    /// - `None` if any amount overflows.
+    fn calculate_amounts_for_liquidity(&self, sqrt_price_lower: Q64_128, sqrt_price_upper: Q64_128, liquidity: u128, round_up: bool) -> Option<(u64, u64)> {
+        let sqrt_price = self.sqrt_price();
+        if sqrt_price <= sqrt_price_lower {
+            Some((Self::calculate_base_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?, 0))
+        }
+        else if sqrt_price < sqrt_price_upper {
+            Some((
+                Self::calculate_base_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+                Self::calculate_quote_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?
+            ))
+        }
+        else{
+            Some((0, Self::calculate_quote_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?))
+        }
+    }
+
+    /// Calculates a single exact input swap step towards the target price.
+    ///
+    /// The step either reaches the target price or consumes the whole remaining amount.
+    /// Fees are charged on the input amount, the rounding always favours the pool.
+    ///
+    /// # Parameters
+    /// - `sqrt_price`: The current square root price.
+    /// - `target_sqrt_price`: The square root price of the next tick in the swap direction.
+    /// - `liquidity`: The active liquidity.
+    /// - `amount_remaining`: The input amount left to swap, including fees.
+    /// - `fee_basis_points`: The total swap fee rate in basis points.
+    /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
+    ///
+    /// # Returns
+    /// - `Some(ClAmmSwapStep)` with the step result.
+    /// - `None` if the fee rate is invalid or the calculation overflows.
+    fn calculate_swap_step(sqrt_price: Q64_128, target_sqrt_price: Q64_128, liquidity: u128, amount_remaining: u64, fee_basis_points: u16, is_in_out: bool) -> Option<ClAmmSwapStep> {
+        let fee_basis_points = fee_basis_points as u128;
+        let fee_complement = Self::FEE_MAX_BASIS_POINTS.checked_sub(fee_basis_points)?;
+        if fee_complement == 0 {
+            return None;
+        }
+        let amount_remaining_less_fee = (amount_remaining as u128 * fee_complement / Self::FEE_MAX_BASIS_POINTS) as u64;
+
+        let amount_in_to_target = if is_in_out {
+            Self::calculate_base_delta(target_sqrt_price, sqrt_price, liquidity, true)
+        }
+        else{
+            Self::calculate_quote_delta(sqrt_price, target_sqrt_price, liquidity, true)
+        };
+        let is_target_reached = amount_in_to_target.is_some_and(|amount_in| amount_in <= amount_remaining_less_fee);
+
+        let next_sqrt_price = if is_target_reached {
+            target_sqrt_price
+        }
+        else if is_in_out {
+            Self::calculate_next_sqrt_price_from_base_in(sqrt_price, liquidity, amount_remaining_less_fee)?
+        }
+        else{
+            Self::calculate_next_sqrt_price_from_quote_in(sqrt_price, liquidity, amount_remaining_less_fee)?
+        };
+
+        let (amount_in, amount_out) = if is_in_out {
+            (
+                Self::calculate_base_delta(next_sqrt_price, sqrt_price, liquidity, true)?,
+                Self::calculate_quote_delta(next_sqrt_price, sqrt_price, liquidity, false)?
+            )
+        }
+        else{
+            (
+                Self::calculate_quote_delta(sqrt_price, next_sqrt_price, liquidity, true)?,
+                Self::calculate_base_delta(sqrt_price, next_sqrt_price, liquidity, false)?
+            )
+        };
+
+        let fee_amount = if is_target_reached {
+            (amount_in as u128 * fee_basis_points).div_ceil(fee_complement) as u64
+        }
+        else{
+            amount_remaining.checked_sub(amount_in)?
+        };
+
+        Some(ClAmmSwapStep {
+            sqrt_price: next_sqrt_price,
+            amount_in,
+            amount_out,
+            fee_amount,
+        })
+    }
+
+    /// Calculates the fee growth per unit of liquidity.
+    ///
+    /// # Returns
+    /// - `Some(Q64_128)` with `fee_amount / liquidity`.
+    /// - `None` if the liquidity is zero.
+    fn calculate_fee_growth(fee_amount: u64, liquidity: u128) -> Option<Q64_128> {
+        if liquidity == 0 {
+            return None;
+        }
+        ((U384::from(fee_amount) << 128) / U384::from(liquidity)).checked_as_q64_128()
+    }
+
+    /// Calculates the fees earned by the liquidity over the given fee growth.
+    ///
+    /// # Returns
+    /// - A `u64` amount of fees, saturated at `u64::MAX`.
+    fn calculate_fees_earned(fee_growth: Q64_128, liquidity: u128) -> u64 {
+        let fees = (U384::from(fee_growth) * U384::from(liquidity)) >> 128;
+        fees.checked_as_u64().unwrap_or(u64::MAX)
+    }
+
+    /// Calculates the base and quote fee growth inside a price range.
+    ///
+    /// The growth inside is the global growth minus the growth below the lower tick and above the upper tick.
+    /// All values are wrapping, only the differences between the checkpoints are meaningful.
+    ///
+    /// # Parameters
+    /// - `tick_lower`, `tick_lower_state`: The lower tick index and state.
+    /// - `tick_upper`, `tick_upper_state`: The upper tick index and state.
+    ///
+    /// # Returns
+    /// - A tuple with the base and quote fee growth inside the range.
+    fn calculate_fee_growth_inside(&self, tick_lower: i32, tick_lower_state: &ClAmmTick, tick_upper: i32, tick_upper_state: &ClAmmTick) -> (Q64_128, Q64_128) {
+        let tick_current = self.tick_current();
+        let (global_base, global_quote) = (self.fee_growth_global_base(), self.fee_growth_global_quote());
+
+        let (below_base, below_quote) = if tick_current >= tick_lower {
+            (tick_lower_state.fee_growth_outside_base(), tick_lower_state.fee_growth_outside_quote())
+        }
+        else{
+            (
+                global_base.wrapping_sub(tick_lower_state.fee_growth_outside_base()),
+                global_quote.wrapping_sub(tick_lower_state.fee_growth_outside_quote())
+            )
+        };
+        let (above_base, above_quote) = if tick_current < tick_upper {
+            (tick_upper_state.fee_growth_outside_base(), tick_upper_state.fee_growth_outside_quote())
+        }
+        else{
+            (
+                global_base.wrapping_sub(tick_upper_state.fee_growth_outside_base()),
+                global_quote.wrapping_sub(tick_upper_state.fee_growth_outside_quote())
+            )
+        };
+        (
+            global_base.wrapping_sub(below_base).wrapping_sub(above_base),
+            global_quote.wrapping_sub(below_quote).wrapping_sub(above_quote)
+        )
+    }
+
+    /// Divides two values rounding the result up.
+    #[inline]
+    fn div_round_up(numerator: U384, denominator: U384) -> U384 {
+        let (quotient, remainder) = numerator.div_mod(denominator);
+        if remainder.is_zero() { quotient } else { quotient + U384::one() }
+    }
+}
+
+impl<T: ClAmmCore> ClAmmCalculate for T{}
+
+/// Result of a single `ClAmm` swap step.
+///
+/// # Fields
+/// - `sqrt_price`: The square root price after the step.
+/// - `amount_in`: The input amount swapped in the step, excluding fees.
+/// - `amount_out`: The output amount of the step.
+/// - `fee_amount`: The fees charged on the step input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClAmmSwapStep {
+    pub sqrt_price: Q64_128,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use utilities::math::{Q64_128, U192};
+    use crate::state::cl_amm::{ClAmmCore, ClAmmCalculate, ClAmmTick};
+
+    /// A helper struct for testing the `ClAmmCalculate` trait.
+    struct TestClAmm{
+        sqrt_price: Q64_128,
+        tick_current: i32,
+        tick_spacing: u16,
+        liquidity: u128,
+        fee_growth_global_base: Q64_128,
+        fee_growth_global_quote: Q64_128,
+    }
+
+    impl TestClAmm{
+        /// Creates a new instance of `TestClAmm` at the price of the given tick.
+        fn at_tick(tick: i32, tick_spacing: u16, liquidity: u128) -> Self{
+            Self{
+                sqrt_price: TestClAmm::calculate_sqrt_price_at_tick(tick).unwrap(),
+                tick_current: tick,
+                tick_spacing,
+                liquidity,
+                fee_growth_global_base: Q64_128::default(),
+                fee_growth_global_quote: Q64_128::default(),
+            }
+        }
+    }
+
+    impl ClAmmCore for TestClAmm {
+        fn sqrt_price(&self) -> Q64_128 {
+            self.sqrt_price
+        }
+
+        fn tick_current(&self) -> i32 {
+            self.tick_current
+        }
+
+        fn tick_spacing(&self) -> u16 {
+            self.tick_spacing
+        }
+
+        fn liquidity(&self) -> u128 {
+            self.liquidity
+        }
+
+        fn fee_growth_global_base(&self) -> Q64_128 {
+            self.fee_growth_global_base
+        }
+
+        fn fee_growth_global_quote(&self) -> Q64_128 {
+            self.fee_growth_global_quote
+        }
+    }
+
+    /// Tests the square root price of ticks against floating point values.
+    #[test]
+    fn test_calculate_sqrt_price_at_tick() {
+        assert_eq!(TestClAmm::calculate_sqrt_price_at_tick(0), Some(Q64_128::ONE));
+        for tick in [-443636, -200_000, -1_000, -1, 1, 1_000, 200_000, 443636] {
+            let sqrt_price = f64::from(TestClAmm::calculate_sqrt_price_at_tick(tick).unwrap());
+            let expected_sqrt_price = 1.0001f64.powf(tick as f64 / 2.0);
+            assert!((sqrt_price / expected_sqrt_price - 1.0).abs() < 1e-10, "Unexpected sqrt price {} for tick {}", sqrt_price, tick);
+        }
+        assert!(TestClAmm::calculate_sqrt_price_at_tick(443636).unwrap() < Q64_128::from_u64(1 << 32));
+        assert!(TestClAmm::calculate_sqrt_price_at_tick(-443636).unwrap() > Q64_128::from_bits(0, 1 << 96));
+        assert!(TestClAmm::calculate_sqrt_price_at_tick(443637).is_none());
+        assert!(TestClAmm::calculate_sqrt_price_at_tick(-443637).is_none());
+    }
+
+    /// Tests that the tick of a price is the greatest tick not above the price.
+    #[test]
+    fn test_calculate_tick_at_sqrt_price() {
+        let one_unit = Q64_128::new(U192::one());
+        for tick in [-443636, -300_001, -77_777, -1_000, -1, 0, 1, 999, 77_777, 300_001, 443635] {
+            let sqrt_price = TestClAmm::calculate_sqrt_price_at_tick(tick).unwrap();
+            let next_sqrt_price = TestClAmm::calculate_sqrt_price_at_tick(tick + 1).unwrap();
+            assert_eq!(TestClAmm::calculate_tick_at_sqrt_price(sqrt_price), Some(tick));
+            assert_eq!(TestClAmm::calculate_tick_at_sqrt_price(sqrt_price.checked_add(one_unit).unwrap()), Some(tick));
+            assert_eq!(TestClAmm::calculate_tick_at_sqrt_price(next_sqrt_price.checked_sub(one_unit).unwrap()), Some(tick));
+        }
+        assert_eq!(TestClAmm::calculate_tick_at_sqrt_price(Q64_128::from_u64(2)), Some(13_863));
+        assert!(TestClAmm::calculate_tick_at_sqrt_price(Q64_128::from_u64(1 << 32)).is_none());
+        assert!(TestClAmm::calculate_tick_at_sqrt_price(Q64_128::from_bits(0, 1 << 95)).is_none());
+    }
+
+    /// Tests base and quote amounts between two prices.
+    #[test]
+    fn test_calculate_deltas() {
+        // sqrt prices 1 and 2, liquidity 1_000_000: base = L * (1 - 1/2), quote = L * (2 - 1)
+        let (sqrt_price_a, sqrt_price_b) = (Q64_128::ONE, Q64_128::from_u64(2));
+        assert_eq!(TestClAmm::calculate_base_delta(sqrt_price_a, sqrt_price_b, 1_000_000, false), Some(500_000));
+        assert_eq!(TestClAmm::calculate_base_delta(sqrt_price_b, sqrt_price_a, 1_000_000, true), Some(500_000));
+        assert_eq!(TestClAmm::calculate_quote_delta(sqrt_price_a, sqrt_price_b, 1_000_000, false), Some(1_000_000));
+
+        // sqrt prices 1 and 3: base = L * 2 / 3
+        let sqrt_price_c = Q64_128::from_u64(3);
+        assert_eq!(TestClAmm::calculate_base_delta(sqrt_price_a, sqrt_price_c, 1_000_000, false), Some(666_666));
+        assert_eq!(TestClAmm::calculate_base_delta(sqrt_price_a, sqrt_price_c, 1_000_000, true), Some(666_667));
+
+        assert!(TestClAmm::calculate_quote_delta(Q64_128::default(), Q64_128::from_u64(u32::MAX as u64), u128::MAX, false).is_none());
+        assert!(TestClAmm::calculate_base_delta(Q64_128::default(), sqrt_price_a, 1, false).is_none());
+    }
+
+    /// Tests next prices after adding tokens.
+    #[test]
+    fn test_calculate_next_sqrt_price() {
+        // L = 1_000_000 at price 1: adding 1_000_000 base halves the sqrt price, adding 1_000_000 quote doubles it
+        let sqrt_price = TestClAmm::calculate_next_sqrt_price_from_base_in(Q64_128::ONE, 1_000_000, 1_000_000).unwrap();
+        assert_eq!(sqrt_price, Q64_128::from_bits(0, 1 << 127));
+        let sqrt_price = TestClAmm::calculate_next_sqrt_price_from_quote_in(Q64_128::ONE, 1_000_000, 1_000_000).unwrap();
+        assert_eq!(sqrt_price, Q64_128::from_u64(2));
+
+        assert!(TestClAmm::calculate_next_sqrt_price_from_base_in(Q64_128::ONE, 0, 1).is_none());
+        assert!(TestClAmm::calculate_next_sqrt_price_from_quote_in(Q64_128::ONE, 0, 1).is_none());
+    }
+
+    /// Tests amounts backing the liquidity below, inside and above the current price.
+    #[test]
+    fn test_calculate_amounts_for_liquidity() {
+        let amm = TestClAmm::at_tick(0, 1, 0);
+        let (sqrt_price_lower, sqrt_price_upper) = (Q64_128::from_bits(0, 1 << 127), Q64_128::from_u64(2));
+
+        assert_eq!(amm.calculate_amounts_for_liquidity(sqrt_price_lower, sqrt_price_upper, 1_000_000, false), Some((500_000, 500_000)));
+        assert_eq!(amm.calculate_amounts_for_liquidity(Q64_128::ONE, sqrt_price_upper, 1_000_000, false), Some((500_000, 0)));
+        assert_eq!(amm.calculate_amounts_for_liquidity(sqrt_price_lower, Q64_128::ONE, 1_000_000, false), Some((0, 500_000)));
+    }
+
+    /// Tests swap steps that reach the target price and that consume the whole amount.
+    #[test]
+    fn test_calculate_swap_step() {
+        let (sqrt_price, target_sqrt_price) = (Q64_128::ONE, Q64_128::from_bits(0, 1 << 127));
+
+        // Reaching the target requires 1_000_000 base plus 0.3% fee
+        let step = TestClAmm::calculate_swap_step(sqrt_price, target_sqrt_price, 1_000_000, 2_000_000, 30, true).unwrap();
+        assert_eq!(step.sqrt_price, target_sqrt_price);
+        assert_eq!(step.amount_in, 1_000_000);
+        assert_eq!(step.amount_out, 500_000);
+        assert_eq!(step.fee_amount, 3_010);
+
+        let step = TestClAmm::calculate_swap_step(sqrt_price, target_sqrt_price, 1_000_000, 100_000, 30, true).unwrap();
+        assert!(step.sqrt_price > target_sqrt_price && step.sqrt_price < sqrt_price);
+        assert_eq!(step.amount_in + step.fee_amount, 100_000);
+        // Input is rounded up from the reached price, so the fee is the remainder
+        assert_eq!(step.fee_amount, 299);
+        // Constant product result for 99_700 base in: 1_000_000 - 1_000_000 * 1_000_000 / 1_099_700
+        assert_eq!(step.amount_out, 90_661);
+
+        let step = TestClAmm::calculate_swap_step(sqrt_price, Q64_128::from_u64(2), 1_000_000, 100_000, 0, false).unwrap();
+        assert_eq!(step.amount_in, 100_000);
+        assert_eq!(step.fee_amount, 0);
+        assert_eq!(step.amount_out, 90_909);
+
+        // Without liquidity the price moves to the target for free
+        let step = TestClAmm::calculate_swap_step(sqrt_price, target_sqrt_price, 0, 100_000, 30, true).unwrap();
+        assert_eq!(step.sqrt_price, target_sqrt_price);
+        assert_eq!((step.amount_in, step.amount_out, step.fee_amount), (0, 0, 0));
+
+        assert!(TestClAmm::calculate_swap_step(sqrt_price, target_sqrt_price, 1_000_000, 100_000, 10_000, true).is_none());
+    }
+
+    /// Tests fee growth and earned fees calculations.
+    #[test]
+    fn test_calculate_fees() {
+        let fee_growth = TestClAmm::calculate_fee_growth(3_000, 1_000_000).unwrap();
+        assert_eq!(TestClAmm::calculate_fees_earned(fee_growth, 500_000), 1_499);
+        assert_eq!(TestClAmm::calculate_fees_earned(fee_growth, 1_000_000), 2_999);
+        assert!(TestClAmm::calculate_fee_growth(3_000, 0).is_none());
+        assert_eq!(TestClAmm::calculate_fees_earned(Q64_128::MAX, u128::MAX), u64::MAX);
+    }
+
+    /// Tests that fee growth inside a range only grows while the price is inside the range.
+    #[test]
+    fn test_calculate_fee_growth_inside() {
+        let q = Q64_128::from_u64;
+        let mut amm = TestClAmm::at_tick(0, 10, 0);
+
+        // Ticks initialized at the global growth of 4 and 8, the lower tick is below the price
+        let mut lower = ClAmmTick::default().update(-10, 0, 1, false, q(4), q(8)).unwrap();
+        let mut upper = ClAmmTick::default().update(10, 0, 1, true, q(4), q(8)).unwrap();
+        amm.fee_growth_global_base = q(10);
+        amm.fee_growth_global_quote = q(20);
+        assert_eq!(amm.calculate_fee_growth_inside(-10, &lower, 10, &upper), (q(6), q(12)));
+
+        // The price crosses the upper tick, the growth above the range is not earned
+        upper = upper.cross(q(10), q(20));
+        amm.tick_current = 10;
+        amm.fee_growth_global_base = q(15);
+        amm.fee_growth_global_quote = q(30);
+        assert_eq!(amm.calculate_fee_growth_inside(-10, &lower, 10, &upper), (q(6), q(12)));
+
+        // The price crosses both ticks downwards, the growth below the range is not earned
+        upper = upper.cross(q(15), q(30));
+        lower = lower.cross(q(15), q(30));
+        amm.tick_current = -20;
+        amm.fee_growth_global_base = q(17);
+        amm.fee_growth_global_quote = q(34);
+        assert_eq!(amm.calculate_fee_growth_inside(-10, &lower, 10, &upper), (q(6), q(12)));
+    }
+
+    /// Tests the position price range validation.
+    #[test]
+    fn test_validate_tick_range() {
+        let amm = TestClAmm::at_tick(0, 10, 0);
+        amm.validate_tick_range(-100, 100).unwrap();
+        assert!(amm.validate_tick_range(100, 100).is_err());
+        assert!(amm.validate_tick_range(100, -100).is_err());
+        assert!(amm.validate_tick_range(-105, 100).is_err());
+        assert!(amm.validate_tick_range(-443640, 100).is_err());
+        assert!(amm.validate_tick_range(-100, 443640).is_err());
+    }
+
+    /// Tests validation of tick array start ticks.
+    #[test]
+    fn test_validate_tick_array_start_tick() {
+        let amm = TestClAmm::at_tick(0, 10, 0);
+        amm.validate_tick_array_start_tick(0).unwrap();
+        amm.validate_tick_array_start_tick(-160).unwrap();
+        amm.validate_tick_array_start_tick(-443680).unwrap();
+        amm.validate_tick_array_start_tick(443520).unwrap();
+        assert!(amm.validate_tick_array_start_tick(10).is_err());
+        assert!(amm.validate_tick_array_start_tick(-443840).is_err());
+        assert!(amm.validate_tick_array_start_tick(443680).is_err());
+    }
+}
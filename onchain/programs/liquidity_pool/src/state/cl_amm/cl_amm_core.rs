@@ -0,0 +1,40 @@
+use utilities::math::Q64_128;
+
+/// A trait defining the core parameters of a Concentrated Liquidity Automated Market Maker.
+pub trait ClAmmCore {
+    /// Retrieves the current square root of the quote-per-base price.
+    ///
+    /// # Returns
+    /// - A `Q64_128` value representing the square root of the price.
+    fn sqrt_price(&self) -> Q64_128;
+
+    /// Retrieves the tick containing the current price.
+    ///
+    /// # Returns
+    /// - An `i32` value representing the current tick index.
+    fn tick_current(&self) -> i32;
+
+    /// Retrieves the distance between two usable ticks.
+    ///
+    /// # Returns
+    /// - A `u16` value representing the tick spacing.
+    fn tick_spacing(&self) -> u16;
+
+    /// Retrieves the liquidity of the positions in range of the current price.
+    ///
+    /// # Returns
+    /// - A `u128` value representing the active liquidity.
+    fn liquidity(&self) -> u128;
+
+    /// Retrieves the providers fees earned per unit of liquidity in base tokens over the pool lifetime.
+    ///
+    /// # Returns
+    /// - A wrapping `Q64_128` value representing the global base fee growth.
+    fn fee_growth_global_base(&self) -> Q64_128;
+
+    /// Retrieves the providers fees earned per unit of liquidity in quote tokens over the pool lifetime.
+    ///
+    /// # Returns
+    /// - A wrapping `Q64_128` value representing the global quote fee growth.
+    fn fee_growth_global_quote(&self) -> Q64_128;
+}
@@ -0,0 +1,1072 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::CollectFeesPayload;
+use super::{ClAmmCalculate, ClAmmCore, ClAmmPosition, ClAmmPositionPayload, ClAmmTick, ClAmmTickArray};
+
+/// Represents a Concentrated Liquidity Automated Market Maker (AMM) pool.
+///
+/// Liquidity providers choose a price range for their liquidity, so it only backs
+/// swaps while the price is inside that range and earns fees only for those swaps.
+/// Ranges are bounded by ticks, stored in `ClAmmTickArray` accounts, and each provider's
+/// liquidity is tracked in a `ClAmmPosition` account instead of fungible LP tokens.
+///
+/// Fees use the static `AmmsConfig` providers and protocol fee rates; the dynamic fee and
+/// protocol fee on LP modes only apply to `CpAmm`.
+#[account]
+#[derive(InitSpace)]
+pub struct ClAmm {
+    /// Whether the AMM has been initialized.
+    is_initialized: bool, // 1 byte
+
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Canonical bump seed for the base vault PDA.
+    base_vault_bump: [u8; 1], // 1 byte
+
+    /// Canonical bump seed for the quote vault PDA.
+    quote_vault_bump: [u8; 1], // 1 byte
+
+    /// Little-endian bytes of the tick spacing, used in the account's PDA seeds.
+    tick_spacing_seed: [u8; 2], // 2 bytes
+
+    /// Distance between two usable ticks.
+    tick_spacing: u16, // 2 bytes
+
+    /// Tick containing the current price.
+    tick_current: i32, // 4 bytes
+
+    /// Square root of the current quote-per-base price, stored as a Q64.128 fixed-point number.
+    sqrt_price: Q64_128, // 24 bytes
+
+    /// Liquidity of the positions in range of the current price.
+    liquidity: u128, // 16 bytes
+
+    /// Providers base fees earned per unit of liquidity, stored as a wrapping Q64.128 fixed-point number.
+    fee_growth_global_base: Q64_128, // 24 bytes
+
+    /// Providers quote fees earned per unit of liquidity, stored as a wrapping Q64.128 fixed-point number.
+    fee_growth_global_quote: Q64_128, // 24 bytes
+
+    /// Amount of base tokens in the pool's vault owned by positions, including uncollected fees.
+    base_liquidity: u64, // 8 bytes
+
+    /// Amount of quote tokens in the pool's vault owned by positions, including uncollected fees.
+    quote_liquidity: u64, // 8 bytes
+
+    /// Amount of protocol fees in base tokens available for redemption.
+    protocol_base_fees_to_redeem: u64, // 8 bytes
+
+    /// Amount of protocol fees in quote tokens available for redemption.
+    protocol_quote_fees_to_redeem: u64, // 8 bytes
+
+    /// Public key of the base token's mint.
+    base_mint: Pubkey, // 32 bytes
+
+    /// Public key of the quote token's mint.
+    quote_mint: Pubkey, // 32 bytes
+
+    /// Public key of the vault holding the base tokens.
+    base_vault: Pubkey, // 32 bytes
+
+    /// Public key of the vault holding the quote tokens.
+    quote_vault: Pubkey, // 32 bytes
+
+    /// Public key of the associated `AmmsConfig` account.
+    amms_config: Pubkey, // 32 bytes
+
+    /// Public key of the ClAmm creator account.
+    creator: Pubkey, // 32 bytes
+}
+
+impl ClAmm {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cl_amm";
+
+    /// Seed used for generating the vaults PDAs.
+    pub const VAULT_SEED: &'static [u8] = b"vault";
+
+    /// Returns the seeds for generating the PDA.
+    ///
+    /// The PDA is derived using the `SEED`, the `amms_config`, both mints, the tick spacing and the `bump` value.
+    pub fn seeds(&self) -> [&[u8]; 6] {
+        [
+            Self::SEED,
+            self.amms_config.as_ref(),
+            self.base_mint.as_ref(),
+            self.quote_mint.as_ref(),
+            self.tick_spacing_seed.as_ref(),
+            self.bump.as_ref()
+        ]
+    }
+
+    /// Checks if the AMM has been initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+
+    /// Returns the canonical bump value for the PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the public key of the base token's mint.
+    #[inline]
+    pub fn base_mint(&self) -> &Pubkey {
+        &self.base_mint
+    }
+
+    /// Returns the public key of the quote token's mint.
+    #[inline]
+    pub fn quote_mint(&self) -> &Pubkey { &self.quote_mint }
+
+    /// Returns the public key of the vault holding base tokens.
+    #[inline]
+    pub fn base_vault(&self) -> &Pubkey {
+        &self.base_vault
+    }
+
+    /// Returns the public key of the vault holding quote tokens.
+    #[inline]
+    pub fn quote_vault(&self) -> &Pubkey {
+        &self.quote_vault
+    }
+
+    /// Returns the public key of the associated `AmmsConfig` account.
+    #[inline]
+    pub fn amms_config(&self) -> &Pubkey {
+        &self.amms_config
+    }
+
+    /// Returns the public key of the ClAmm creator account.
+    #[inline]
+    pub fn creator(&self) -> &Pubkey {
+        &self.creator
+    }
+
+    /// Returns the canonical bump value for the base vault PDA.
+    #[inline]
+    pub fn base_vault_bump(&self) -> u8 {
+        self.base_vault_bump[0]
+    }
+
+    /// Returns the canonical bump value for the quote vault PDA.
+    #[inline]
+    pub fn quote_vault_bump(&self) -> u8 {
+        self.quote_vault_bump[0]
+    }
+
+    #[inline]
+    pub fn base_liquidity(&self) -> u64 {
+        self.base_liquidity
+    }
+
+    #[inline]
+    pub fn quote_liquidity(&self) -> u64 {
+        self.quote_liquidity
+    }
+
+    #[inline]
+    pub fn protocol_base_fees_to_redeem(&self) -> u64 {
+        self.protocol_base_fees_to_redeem
+    }
+
+    #[inline]
+    pub fn protocol_quote_fees_to_redeem(&self) -> u64 {
+        self.protocol_quote_fees_to_redeem
+    }
+}
+
+/// Implements the `ClAmmCore` trait for the `ClAmm` struct.
+///
+/// This implementation auto implements 'ClAmmCalculate' trait that defines core logic and calculations for the Concentrated Liquidity AMM.
+impl ClAmmCore for ClAmm {
+    fn sqrt_price(&self) -> Q64_128 {
+        self.sqrt_price
+    }
+
+    fn tick_current(&self) -> i32 {
+        self.tick_current
+    }
+
+    fn tick_spacing(&self) -> u16 {
+        self.tick_spacing
+    }
+
+    fn liquidity(&self) -> u128 {
+        self.liquidity
+    }
+
+    fn fee_growth_global_base(&self) -> Q64_128 {
+        self.fee_growth_global_base
+    }
+
+    fn fee_growth_global_quote(&self) -> Q64_128 {
+        self.fee_growth_global_quote
+    }
+}
+
+impl ClAmm {
+    /// Validates the current state of the AMM to ensure it is ready for operations.
+    #[inline]
+    fn check_state(&self) -> Result<()> {
+        require!(self.is_initialized, ErrorCode::ClAmmNotInitialized);
+        Ok(())
+    }
+
+    /// Checks if the price range contains the current price.
+    #[inline]
+    fn is_in_range(&self, tick_lower: i32, tick_upper: i32) -> bool {
+        tick_lower <= self.tick_current && self.tick_current < tick_upper
+    }
+
+    /// Calculates the new states of the position and its bounding ticks after a liquidity change.
+    ///
+    /// Fees earned by the position since its last update are added to the fees owed.
+    /// Ticks that are no longer used by any position are cleared.
+    ///
+    /// # Parameters
+    /// - `position`: The position to update.
+    /// - `tick_lower_state`: The current state of the position lower tick.
+    /// - `tick_upper_state`: The current state of the position upper tick.
+    /// - `liquidity_delta`: Signed change of the position liquidity.
+    ///
+    /// # Returns
+    /// - `Ok((ClAmmTick, ClAmmTick, ClAmmPositionPayload))` with the new lower tick, upper tick and position states.
+    /// - `Err(ErrorCode)` if the liquidity overflows or exceeds the position liquidity.
+    fn get_position_update(&self, position: &ClAmmPosition, tick_lower_state: &ClAmmTick, tick_upper_state: &ClAmmTick, liquidity_delta: i128) -> Result<(ClAmmTick, ClAmmTick, ClAmmPositionPayload)> {
+        let (tick_lower, tick_upper) = (position.tick_lower(), position.tick_upper());
+        let (global_base, global_quote) = (self.fee_growth_global_base, self.fee_growth_global_quote);
+
+        let position_liquidity = if liquidity_delta >= 0 {
+            position.liquidity().checked_add(liquidity_delta.unsigned_abs()).ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?
+        }
+        else{
+            position.liquidity().checked_sub(liquidity_delta.unsigned_abs()).ok_or(ErrorCode::ClAmmPositionLiquidityExceeded)?
+        };
+        let new_tick_lower_state = tick_lower_state
+            .update(tick_lower, self.tick_current, liquidity_delta, false, global_base, global_quote)
+            .ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?;
+        let new_tick_upper_state = tick_upper_state
+            .update(tick_upper, self.tick_current, liquidity_delta, true, global_base, global_quote)
+            .ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?;
+
+        let (fee_growth_inside_base, fee_growth_inside_quote) = self.calculate_fee_growth_inside(tick_lower, &new_tick_lower_state, tick_upper, &new_tick_upper_state);
+        let base_fees_earned = Self::calculate_fees_earned(fee_growth_inside_base.wrapping_sub(position.fee_growth_inside_base_last()), position.liquidity());
+        let quote_fees_earned = Self::calculate_fees_earned(fee_growth_inside_quote.wrapping_sub(position.fee_growth_inside_quote_last()), position.liquidity());
+
+        let position_payload = ClAmmPositionPayload::new(
+            position_liquidity,
+            fee_growth_inside_base,
+            fee_growth_inside_quote,
+            position.base_fees_owed().saturating_add(base_fees_earned),
+            position.quote_fees_owed().saturating_add(quote_fees_earned)
+        );
+        Ok((new_tick_lower_state.cleared_if_unused(), new_tick_upper_state.cleared_if_unused(), position_payload))
+    }
+
+    /// Calculates the active liquidity after a position liquidity change.
+    fn calculate_active_liquidity(&self, tick_lower: i32, tick_upper: i32, liquidity_delta: i128) -> Result<u128> {
+        if !self.is_in_range(tick_lower, tick_upper) {
+            return Ok(self.liquidity);
+        }
+        let liquidity = if liquidity_delta >= 0 {
+            self.liquidity.checked_add(liquidity_delta.unsigned_abs())
+        }
+        else{
+            self.liquidity.checked_sub(liquidity_delta.unsigned_abs())
+        };
+        Ok(liquidity.ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?)
+    }
+
+    /// Prepares the payload for adding liquidity to a position.
+    ///
+    /// The required token amounts are rounded up, so the pool never backs liquidity it wasn't paid for.
+    ///
+    /// # Parameters
+    /// - `position`: The position to add liquidity to.
+    /// - `tick_lower_state`: The current state of the position lower tick.
+    /// - `tick_upper_state`: The current state of the position upper tick.
+    /// - `liquidity`: The liquidity to add.
+    /// - `max_base_amount`: The maximal amount of base tokens the provider is willing to deposit.
+    /// - `max_quote_amount`: The maximal amount of quote tokens the provider is willing to deposit.
+    ///
+    /// # Returns
+    /// - `Ok(ClAmmLiquidityPayload)` containing the token amounts to deposit and the new pool, ticks and position states.
+    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    #[inline(never)]
+    pub fn get_provide_payload(&self, position: &ClAmmPosition, tick_lower_state: &ClAmmTick, tick_upper_state: &ClAmmTick, liquidity: u128, max_base_amount: u64, max_quote_amount: u64) -> Result<ClAmmLiquidityPayload> {
+        self.check_state()?;
+        require!(liquidity > 0, ErrorCode::ProvidedClAmmLiquidityIsZero);
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| ErrorCode::ClAmmLiquidityCalculationFailed)?;
+
+        let (tick_lower, tick_upper) = (position.tick_lower(), position.tick_upper());
+        let (tick_lower_state, tick_upper_state, position_payload) = self.get_position_update(position, tick_lower_state, tick_upper_state, liquidity_delta)?;
+
+        let sqrt_price_lower = Self::calculate_sqrt_price_at_tick(tick_lower).ok_or(ErrorCode::InvalidTickRange)?;
+        let sqrt_price_upper = Self::calculate_sqrt_price_at_tick(tick_upper).ok_or(ErrorCode::InvalidTickRange)?;
+        let (base_amount, quote_amount) = self.calculate_amounts_for_liquidity(sqrt_price_lower, sqrt_price_upper, liquidity, true).ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?;
+        require!(base_amount <= max_base_amount && quote_amount <= max_quote_amount, ErrorCode::ClAmmProvideAmountsExceeded);
+
+        Ok(ClAmmLiquidityPayload {
+            liquidity: self.calculate_active_liquidity(tick_lower, tick_upper, liquidity_delta)?,
+            base_liquidity: self.base_liquidity.checked_add(base_amount).ok_or(ErrorCode::ProvideOverflowError)?,
+            quote_liquidity: self.quote_liquidity.checked_add(quote_amount).ok_or(ErrorCode::ProvideOverflowError)?,
+            base_amount,
+            quote_amount,
+            tick_lower: tick_lower_state,
+            tick_upper: tick_upper_state,
+            position: position_payload,
+        })
+    }
+
+    /// Prepares the payload for removing liquidity from a position.
+    ///
+    /// The withdrawn token amounts are rounded down, fees earned by the position stay owed until collected.
+    ///
+    /// # Parameters
+    /// - `position`: The position to remove liquidity from.
+    /// - `tick_lower_state`: The current state of the position lower tick.
+    /// - `tick_upper_state`: The current state of the position upper tick.
+    /// - `liquidity`: The liquidity to remove.
+    /// - `min_base_amount`: The minimal amount of base tokens the provider expects to receive.
+    /// - `min_quote_amount`: The minimal amount of quote tokens the provider expects to receive.
+    ///
+    /// # Returns
+    /// - `Ok(ClAmmLiquidityPayload)` containing the token amounts to withdraw and the new pool, ticks and position states.
+    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    #[inline(never)]
+    pub fn get_withdraw_payload(&self, position: &ClAmmPosition, tick_lower_state: &ClAmmTick, tick_upper_state: &ClAmmTick, liquidity: u128, min_base_amount: u64, min_quote_amount: u64) -> Result<ClAmmLiquidityPayload> {
+        self.check_state()?;
+        require!(liquidity > 0, ErrorCode::ProvidedClAmmLiquidityIsZero);
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| ErrorCode::ClAmmPositionLiquidityExceeded)?.checked_neg().ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?;
+
+        let (tick_lower, tick_upper) = (position.tick_lower(), position.tick_upper());
+        let (tick_lower_state, tick_upper_state, position_payload) = self.get_position_update(position, tick_lower_state, tick_upper_state, liquidity_delta)?;
+
+        let sqrt_price_lower = Self::calculate_sqrt_price_at_tick(tick_lower).ok_or(ErrorCode::InvalidTickRange)?;
+        let sqrt_price_upper = Self::calculate_sqrt_price_at_tick(tick_upper).ok_or(ErrorCode::InvalidTickRange)?;
+        let (base_amount, quote_amount) = self.calculate_amounts_for_liquidity(sqrt_price_lower, sqrt_price_upper, liquidity, false).ok_or(ErrorCode::ClAmmLiquidityCalculationFailed)?;
+        require!(base_amount >= min_base_amount, ErrorCode::WithdrawBaseBelowMinimum);
+        require!(quote_amount >= min_quote_amount, ErrorCode::WithdrawQuoteBelowMinimum);
+
+        Ok(ClAmmLiquidityPayload {
+            liquidity: self.calculate_active_liquidity(tick_lower, tick_upper, liquidity_delta)?,
+            base_liquidity: self.base_liquidity.checked_sub(base_amount).ok_or(ErrorCode::WithdrawOverflowError)?,
+            quote_liquidity: self.quote_liquidity.checked_sub(quote_amount).ok_or(ErrorCode::WithdrawOverflowError)?,
+            base_amount,
+            quote_amount,
+            tick_lower: tick_lower_state,
+            tick_upper: tick_upper_state,
+            position: position_payload,
+        })
+    }
+
+    /// Prepares the payload for collecting the fees earned by a position.
+    ///
+    /// # Parameters
+    /// - `position`: The position to collect fees of.
+    /// - `tick_lower_state`: The current state of the position lower tick.
+    /// - `tick_upper_state`: The current state of the position upper tick.
+    ///
+    /// # Returns
+    /// - `Ok(ClAmmCollectPositionFeesPayload)` containing the fees to transfer and the new pool and position states.
+    /// - `Err(ErrorCode::PositionFeesIsZero)` if the position has no fees to collect.
+    #[inline(never)]
+    pub fn get_collect_position_fees_payload(&self, position: &ClAmmPosition, tick_lower_state: &ClAmmTick, tick_upper_state: &ClAmmTick) -> Result<ClAmmCollectPositionFeesPayload> {
+        self.check_state()?;
+        let (_, _, position_payload) = self.get_position_update(position, tick_lower_state, tick_upper_state, 0)?;
+        let (base_fees, quote_fees) = (position_payload.base_fees_owed(), position_payload.quote_fees_owed());
+        require!(base_fees > 0 || quote_fees > 0, ErrorCode::PositionFeesIsZero);
+
+        Ok(ClAmmCollectPositionFeesPayload {
+            base_liquidity: self.base_liquidity.checked_sub(base_fees).ok_or(ErrorCode::WithdrawOverflowError)?,
+            quote_liquidity: self.quote_liquidity.checked_sub(quote_fees).ok_or(ErrorCode::WithdrawOverflowError)?,
+            base_fees,
+            quote_fees,
+            position: ClAmmPositionPayload::new(
+                position_payload.liquidity(),
+                position_payload.fee_growth_inside_base_last(),
+                position_payload.fee_growth_inside_quote_last(),
+                0,
+                0
+            ),
+        })
+    }
+
+    /// Finds the next tick the swap moves the price to.
+    ///
+    /// # Returns
+    /// - A tuple with the tick index and its state if the tick is initialized.
+    ///   If the tick arrays have no initialized ticks in the swap direction, the bound of the last array is returned.
+    fn find_next_swap_tick(&self, tick_arrays: &[&ClAmmTickArray], tick_current: i32, is_in_out: bool) -> Result<(i32, Option<ClAmmTick>)> {
+        for tick_array in tick_arrays {
+            if let Some(tick) = tick_array.next_initialized_tick(tick_current, self.tick_spacing, is_in_out) {
+                return Ok((tick, Some(*tick_array.tick(tick, self.tick_spacing)?)));
+            }
+        }
+        let last_tick_array = tick_arrays.last().ok_or(ErrorCode::InvalidTickArrays)?;
+        let bound_tick = if is_in_out {
+            last_tick_array.start_tick().max(Self::MIN_TICK)
+        }
+        else{
+            (last_tick_array.start_tick() + ClAmmTickArray::ticks_span(self.tick_spacing)).min(Self::MAX_TICK)
+        };
+        Ok((bound_tick, None))
+    }
+
+    /// Validates that the tick arrays start with the array of the current tick and follow each other in the swap direction.
+    fn validate_swap_tick_arrays(&self, tick_arrays: &[&ClAmmTickArray], is_in_out: bool) -> Result<()> {
+        require!(!tick_arrays.is_empty(), ErrorCode::InvalidTickArrays);
+        let ticks_span = ClAmmTickArray::ticks_span(self.tick_spacing);
+        let mut expected_start_tick = ClAmmTickArray::start_tick_for(self.tick_current, self.tick_spacing);
+        for tick_array in tick_arrays {
+            require!(tick_array.start_tick() == expected_start_tick, ErrorCode::InvalidTickArrays);
+            expected_start_tick = if is_in_out { expected_start_tick - ticks_span } else { expected_start_tick + ticks_span };
+        }
+        Ok(())
+    }
+
+    /// Computes the swap payload for exchanging tokens within the AMM.
+    ///
+    /// The swap moves the price tick by tick, crossing initialized ticks changes the active liquidity.
+    /// Fees are charged on every step, the protocol part is accumulated for redemption
+    /// and the providers part is distributed to the active liquidity of the step.
+    ///
+    /// # Parameters
+    /// - `tick_arrays`: Tick arrays covering the swap, starting with the array of the current tick.
+    /// - `swap_amount`: The amount of tokens being swapped (either base or quote).
+    /// - `estimated_result`: Expected amount of tokens to receive after the swap.
+    /// - `allowed_slippage`: Maximum permissible deviation from `estimated_result`.
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
+    ///
+    /// # Returns
+    /// - `Ok(ClAmmSwapPayload)`: Contains the new pool state and the crossed ticks states.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or slippage exceeded).
+    #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_swap_payload(&self, tick_arrays: &[&ClAmmTickArray], swap_amount: u64, estimated_result: u64, allowed_slippage: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_in_out: bool) -> Result<ClAmmSwapPayload> {
+        self.check_state()?;
+        require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
+        require!(estimated_result > 0, ErrorCode::EstimatedResultIsZero);
+        let fee_rate_basis_points = providers_fee_rate_basis_points + protocol_fee_rate_basis_points;
+        require!((fee_rate_basis_points as u128) < Self::FEE_MAX_BASIS_POINTS, ErrorCode::ConfigFeeRateExceeded);
+        self.validate_swap_tick_arrays(tick_arrays, is_in_out)?;
+
+        let mut sqrt_price = self.sqrt_price;
+        let mut tick_current = self.tick_current;
+        let mut liquidity = self.liquidity;
+        let mut fee_growth_global_base = self.fee_growth_global_base;
+        let mut fee_growth_global_quote = self.fee_growth_global_quote;
+        let mut amount_remaining = swap_amount;
+        let mut amount_to_withdraw: u64 = 0;
+        let mut protocol_fee_amount: u64 = 0;
+        let mut crossed_ticks = Vec::new();
+
+        while amount_remaining > 0 {
+            let (target_tick, target_tick_state) = self.find_next_swap_tick(tick_arrays, tick_current, is_in_out)?;
+            let target_sqrt_price = Self::calculate_sqrt_price_at_tick(target_tick).ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+            let is_price_movable = if is_in_out { target_sqrt_price < sqrt_price } else { target_sqrt_price > sqrt_price };
+            require!(is_price_movable || target_tick_state.is_some(), ErrorCode::ClAmmSwapLiquidityExhausted);
+
+            let step = Self::calculate_swap_step(sqrt_price, target_sqrt_price, liquidity, amount_remaining, fee_rate_basis_points, is_in_out).ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+            amount_remaining = amount_remaining
+                .checked_sub(step.amount_in)
+                .and_then(|amount| amount.checked_sub(step.fee_amount))
+                .ok_or(ErrorCode::SwapOverflowError)?;
+            amount_to_withdraw = amount_to_withdraw.checked_add(step.amount_out).ok_or(ErrorCode::SwapOverflowError)?;
+
+            let step_protocol_fee_amount = if fee_rate_basis_points > 0 {
+                (step.fee_amount as u128 * protocol_fee_rate_basis_points as u128 / fee_rate_basis_points as u128) as u64
+            }
+            else{
+                0
+            };
+            protocol_fee_amount = protocol_fee_amount.checked_add(step_protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            let step_providers_fee_amount = step.fee_amount - step_protocol_fee_amount;
+            if step_providers_fee_amount > 0 {
+                let fee_growth = Self::calculate_fee_growth(step_providers_fee_amount, liquidity).ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+                if is_in_out {
+                    fee_growth_global_base = fee_growth_global_base.wrapping_add(fee_growth);
+                }
+                else{
+                    fee_growth_global_quote = fee_growth_global_quote.wrapping_add(fee_growth);
+                }
+            }
+
+            sqrt_price = step.sqrt_price;
+            if sqrt_price == target_sqrt_price {
+                if let Some(target_tick_state) = target_tick_state {
+                    let liquidity_net = if is_in_out { target_tick_state.liquidity_net().checked_neg() } else { Some(target_tick_state.liquidity_net()) };
+                    let liquidity_net = liquidity_net.ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+                    liquidity = if liquidity_net >= 0 {
+                        liquidity.checked_add(liquidity_net.unsigned_abs())
+                    }
+                    else{
+                        liquidity.checked_sub(liquidity_net.unsigned_abs())
+                    }.ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+                    crossed_ticks.push(ClAmmCrossedTick {
+                        tick: target_tick,
+                        tick_state: target_tick_state.cross(fee_growth_global_base, fee_growth_global_quote),
+                    });
+                }
+                tick_current = if is_in_out { target_tick - 1 } else { target_tick };
+            }
+            else{
+                tick_current = Self::calculate_tick_at_sqrt_price(sqrt_price).ok_or(ErrorCode::ClAmmSwapCalculationFailed)?;
+            }
+        }
+
+        Self::check_swap_result(amount_to_withdraw, estimated_result, allowed_slippage)?;
+
+        let amount_to_deposit = swap_amount - protocol_fee_amount;
+        let (base_liquidity, quote_liquidity, protocol_fees_to_redeem) = if is_in_out {
+            (
+                self.base_liquidity.checked_add(amount_to_deposit).ok_or(ErrorCode::SwapOverflowError)?,
+                self.quote_liquidity.checked_sub(amount_to_withdraw).ok_or(ErrorCode::SwapOverflowError)?,
+                self.protocol_base_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?
+            )
+        }
+        else{
+            (
+                self.base_liquidity.checked_sub(amount_to_withdraw).ok_or(ErrorCode::SwapOverflowError)?,
+                self.quote_liquidity.checked_add(amount_to_deposit).ok_or(ErrorCode::SwapOverflowError)?,
+                self.protocol_quote_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?
+            )
+        };
+
+        Ok(ClAmmSwapPayload {
+            sqrt_price,
+            tick_current,
+            liquidity,
+            fee_growth_global_base,
+            fee_growth_global_quote,
+            base_liquidity,
+            quote_liquidity,
+            protocol_fees_to_redeem,
+            amount_to_withdraw,
+            is_in_out,
+            crossed_ticks,
+        })
+    }
+
+    /// Prepares the payload for collecting protocol fees from the AMM.
+    ///
+    /// # Returns
+    /// - `Ok(CollectFeesPayload)`: Contains the protocol fees available for redemption for both base and quote tokens.
+    /// - `Err(ErrorCode::ProvidersFeesIsZero)`: If there are no fees available to collect.
+    #[inline(never)]
+    pub fn get_collect_fees_payload(&self) -> Result<CollectFeesPayload>{
+        require!(self.protocol_base_fees_to_redeem > 0 || self.protocol_quote_fees_to_redeem > 0, ErrorCode::ProvidersFeesIsZero);
+        Ok(CollectFeesPayload::new(
+            self.protocol_base_fees_to_redeem,
+            self.protocol_quote_fees_to_redeem,
+            0,
+            0
+        ))
+    }
+}
+
+impl ClAmm {
+
+    /// Initializes the AMM with the provided token mints, configuration, tick spacing and initial price.
+    ///
+    /// # Parameters
+    /// - `base_mint`: The mint of the base token.
+    /// - `quote_mint`: The mint of the quote token.
+    /// - `amms_config`: The configuration account for the AMM.
+    /// - `creator`: Account info of creator account.
+    /// - `base_vault`: Pubkey of the vault holding the base tokens.
+    /// - `quote_vault`: Pubkey of the vault holding the quote tokens.
+    /// - `tick_spacing`: The distance between two usable ticks.
+    /// - `initial_sqrt_price`: The square root of the initial quote-per-base price.
+    /// - `bump`: The canonical bump seed for the AMM's PDA.
+    /// - `base_vault_bump`: The canonical bump seed for the base vault PDA.
+    /// - `quote_vault_bump`: The canonical bump seed for the quote vault PDA.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the initialization is successful.
+    /// - `Err(ErrorCode)` if the AMM is already initialized, the tick spacing or the initial price is invalid.
+    #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        base_mint: &InterfaceAccount<token_interface::Mint>,
+        quote_mint: &InterfaceAccount<token_interface::Mint>,
+        amms_config: &Account<AmmsConfig>,
+        creator: &AccountInfo,
+        base_vault: &AccountInfo,
+        quote_vault: &AccountInfo,
+        tick_spacing: u16,
+        initial_sqrt_price: Q64_128,
+        bump: u8,
+        base_vault_bump: u8,
+        quote_vault_bump: u8
+    ) -> Result<()>{
+        require!(!self.is_initialized, ErrorCode::ClAmmAlreadyInitialized);
+        require!(tick_spacing > 0 && tick_spacing <= Self::MAX_TICK_SPACING, ErrorCode::InvalidTickSpacing);
+        let tick_current = Self::calculate_tick_at_sqrt_price(initial_sqrt_price).ok_or(ErrorCode::InvalidInitialSqrtPrice)?;
+
+        self.is_initialized = true;
+
+        self.base_mint = base_mint.key();
+        self.quote_mint = quote_mint.key();
+        self.amms_config = amms_config.key();
+        self.creator = creator.key();
+        self.base_vault = base_vault.key();
+        self.quote_vault = quote_vault.key();
+
+        self.tick_spacing = tick_spacing;
+        self.tick_spacing_seed = tick_spacing.to_le_bytes();
+        self.sqrt_price = initial_sqrt_price;
+        self.tick_current = tick_current;
+
+        self.bump = [bump];
+        self.base_vault_bump = [base_vault_bump];
+        self.quote_vault_bump = [quote_vault_bump];
+
+        Ok(())
+    }
+
+    /// Updates the AMM state after a position liquidity change.
+    ///
+    /// # Parameters
+    /// - `liquidity_payload`: Contains the new active liquidity and token amounts.
+    #[inline(never)]
+    pub(crate) fn modify_liquidity(&mut self, liquidity_payload: ClAmmLiquidityPayload) {
+        self.liquidity = liquidity_payload.liquidity;
+        self.base_liquidity = liquidity_payload.base_liquidity;
+        self.quote_liquidity = liquidity_payload.quote_liquidity;
+    }
+
+    /// Updates the AMM state after a position collects its fees.
+    ///
+    /// # Parameters
+    /// - `collect_position_fees_payload`: Contains the new token amounts.
+    #[inline(never)]
+    pub(crate) fn collect_position_fees(&mut self, collect_position_fees_payload: ClAmmCollectPositionFeesPayload) {
+        self.base_liquidity = collect_position_fees_payload.base_liquidity;
+        self.quote_liquidity = collect_position_fees_payload.quote_liquidity;
+    }
+
+    /// Updates the AMM state after a token swap operation.
+    ///
+    /// # Parameters
+    /// - `swap_payload`: Contains the new price, active liquidity, fee growth and token amounts.
+    #[inline(never)]
+    pub(crate) fn swap(&mut self, swap_payload: ClAmmSwapPayload) {
+        self.sqrt_price = swap_payload.sqrt_price;
+        self.tick_current = swap_payload.tick_current;
+        self.liquidity = swap_payload.liquidity;
+        self.fee_growth_global_base = swap_payload.fee_growth_global_base;
+        self.fee_growth_global_quote = swap_payload.fee_growth_global_quote;
+        self.base_liquidity = swap_payload.base_liquidity;
+        self.quote_liquidity = swap_payload.quote_liquidity;
+        if swap_payload.is_in_out{
+            self.protocol_base_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
+        }
+        else{
+            self.protocol_quote_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
+        }
+    }
+
+    /// Updates the protocol fees for the AMM based on the provided payload.
+    ///
+    /// # Parameters
+    /// - `collect_fees_payload`: A `CollectFeesPayload` containing the updated protocol fees
+    ///   for both base and quote tokens.
+    #[inline(never)]
+    pub(crate) fn collect_fees(&mut self, collect_fees_payload: CollectFeesPayload) {
+        self.protocol_base_fees_to_redeem = collect_fees_payload.new_protocol_base_fees_to_redeem();
+        self.protocol_quote_fees_to_redeem = collect_fees_payload.new_protocol_quote_fees_to_redeem();
+    }
+}
+
+#[cfg(test)]
+mod cl_amm_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    /// Builds an initialized empty `ClAmm` at the price of the given tick.
+    fn initialized_cl_amm(tick: i32, tick_spacing: u16) -> ClAmm {
+        ClAmm {
+            is_initialized: true,
+            bump: [0],
+            base_vault_bump: [0],
+            quote_vault_bump: [0],
+            tick_spacing_seed: tick_spacing.to_le_bytes(),
+            tick_spacing,
+            tick_current: tick,
+            sqrt_price: ClAmm::calculate_sqrt_price_at_tick(tick).unwrap(),
+            liquidity: 0,
+            fee_growth_global_base: Q64_128::default(),
+            fee_growth_global_quote: Q64_128::default(),
+            base_liquidity: 0,
+            quote_liquidity: 0,
+            protocol_base_fees_to_redeem: 0,
+            protocol_quote_fees_to_redeem: 0,
+            base_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+            base_vault: Pubkey::default(),
+            quote_vault: Pubkey::default(),
+            amms_config: Pubkey::default(),
+            creator: Pubkey::default(),
+        }
+    }
+
+    /// Deserializes a zeroed account of the given type.
+    fn empty_account<T: AccountDeserialize + Discriminator + Space>() -> T {
+        let mut data = vec![0u8; ANCHOR_DISCRIMINATOR + T::INIT_SPACE];
+        data[..ANCHOR_DISCRIMINATOR].copy_from_slice(&T::discriminator());
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    fn tick_array(start_tick: i32) -> ClAmmTickArray {
+        let mut tick_array: ClAmmTickArray = empty_account();
+        tick_array.initialize(Pubkey::default(), start_tick, 0);
+        tick_array
+    }
+
+    fn position(tick_lower: i32, tick_upper: i32) -> ClAmmPosition {
+        let mut position: ClAmmPosition = empty_account();
+        position.initialize(Pubkey::default(), Pubkey::default(), tick_lower, tick_upper, 0);
+        position
+    }
+
+    /// Applies a liquidity payload to the pool, the position and the tick arrays.
+    fn apply_liquidity_payload(cl_amm: &mut ClAmm, position: &mut ClAmmPosition, lower_array: &mut ClAmmTickArray, upper_array: &mut ClAmmTickArray, payload: ClAmmLiquidityPayload) {
+        lower_array.update_tick(position.tick_lower(), cl_amm.tick_spacing, payload.tick_lower()).unwrap();
+        upper_array.update_tick(position.tick_upper(), cl_amm.tick_spacing, payload.tick_upper()).unwrap();
+        position.update(payload.position());
+        cl_amm.modify_liquidity(payload);
+    }
+
+    /// Tests `ClAmm` account data layout.
+    #[test]
+    fn test_cl_amm_data_layout() {
+        let mut cl_amm = initialized_cl_amm(0, 64);
+        cl_amm.bump = [42];
+        cl_amm.liquidity = 1_000_000;
+        cl_amm.base_liquidity = 500;
+        cl_amm.protocol_quote_fees_to_redeem = 7;
+        cl_amm.base_mint = Pubkey::new_unique();
+        cl_amm.amms_config = Pubkey::new_unique();
+        cl_amm.creator = Pubkey::new_unique();
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 324];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&ClAmm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = 1; offset += 1;
+        data[offset] = 42; offset += 1;
+        offset += 2;
+        data[offset..offset + 2].copy_from_slice(&64u16.to_le_bytes()); offset += 2;
+        data[offset..offset + 2].copy_from_slice(&64u16.to_le_bytes()); offset += 2;
+        data[offset..offset + 4].copy_from_slice(&0i32.to_le_bytes()); offset += 4;
+        data[offset..offset + 24].copy_from_slice(&Q64_128::ONE.to_le_bytes()); offset += 24;
+        data[offset..offset + 16].copy_from_slice(&1_000_000u128.to_le_bytes()); offset += 16;
+        offset += 48;
+        data[offset..offset + 8].copy_from_slice(&500u64.to_le_bytes()); offset += 8;
+        offset += 8;
+        offset += 8;
+        data[offset..offset + 8].copy_from_slice(&7u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(cl_amm.base_mint.as_ref()); offset += 32;
+        offset += 96;
+        data[offset..offset + 32].copy_from_slice(cl_amm.amms_config.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(cl_amm.creator.as_ref()); offset += 32;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + ClAmm::INIT_SPACE, offset);
+
+        let deserialized_cl_amm = ClAmm::try_deserialize(&mut data.as_ref()).unwrap();
+        assert_eq!(deserialized_cl_amm.tick_spacing(), 64);
+        assert_eq!(deserialized_cl_amm.sqrt_price(), Q64_128::ONE);
+        assert_eq!(deserialized_cl_amm.liquidity(), 1_000_000);
+        assert_eq!(deserialized_cl_amm.creator(), &cl_amm.creator);
+
+        let mut serialized_cl_amm = Vec::new();
+        cl_amm.try_serialize(&mut serialized_cl_amm).unwrap();
+        assert_eq!(serialized_cl_amm.as_slice(), data.as_ref());
+    }
+
+    /// Tests providing liquidity inside and outside of the current price.
+    #[test]
+    fn test_provide() {
+        let mut cl_amm = initialized_cl_amm(0, 10);
+        let (mut lower_array, mut upper_array) = (tick_array(-160), tick_array(0));
+        let mut position = position(-100, 100);
+
+        let lower_state = *lower_array.tick(-100, 10).unwrap();
+        let upper_state = *upper_array.tick(100, 10).unwrap();
+        assert!(cl_amm.get_provide_payload(&position, &lower_state, &upper_state, 0, u64::MAX, u64::MAX).is_err());
+        assert!(cl_amm.get_provide_payload(&position, &lower_state, &upper_state, 1_000_000_000, 1_000, u64::MAX).is_err());
+
+        let payload = cl_amm.get_provide_payload(&position, &lower_state, &upper_state, 1_000_000_000, u64::MAX, u64::MAX).unwrap();
+        // L * (1 - 1.0001^-50) for both tokens at price 1
+        assert_eq!(payload.base_amount(), 4_987_273);
+        assert_eq!(payload.quote_amount(), 4_987_273);
+        apply_liquidity_payload(&mut cl_amm, &mut position, &mut lower_array, &mut upper_array, payload);
+
+        assert_eq!(cl_amm.liquidity(), 1_000_000_000);
+        assert_eq!(cl_amm.base_liquidity(), 4_987_273);
+        assert_eq!(position.liquidity(), 1_000_000_000);
+        assert_eq!(lower_array.tick(-100, 10).unwrap().liquidity_net(), 1_000_000_000);
+        assert_eq!(upper_array.tick(100, 10).unwrap().liquidity_net(), -1_000_000_000);
+
+        // A range above the price is backed by base tokens only and doesn't change the active liquidity
+        let mut position_above = self::position(100, 150);
+        let lower_state = *upper_array.tick(100, 10).unwrap();
+        let upper_state = *upper_array.tick(150, 10).unwrap();
+        let payload = cl_amm.get_provide_payload(&position_above, &lower_state, &upper_state, 1_000_000_000, u64::MAX, u64::MAX).unwrap();
+        assert_eq!(payload.quote_amount(), 0);
+        assert!(payload.base_amount() > 0);
+        let mut same_array = upper_array.clone();
+        apply_liquidity_payload(&mut cl_amm, &mut position_above, &mut upper_array, &mut same_array, payload);
+        assert_eq!(cl_amm.liquidity(), 1_000_000_000);
+    }
+
+    /// Tests a swap within a single range, fees distribution, fee collection and withdrawal.
+    #[test]
+    fn test_swap_and_collect_fees() {
+        let mut cl_amm = initialized_cl_amm(0, 10);
+        let (mut lower_array, mut upper_array) = (tick_array(-160), tick_array(0));
+        let mut position = position(-100, 100);
+        let lower_state = *lower_array.tick(-100, 10).unwrap();
+        let upper_state = *upper_array.tick(100, 10).unwrap();
+        let payload = cl_amm.get_provide_payload(&position, &lower_state, &upper_state, 1_000_000_000, u64::MAX, u64::MAX).unwrap();
+        apply_liquidity_payload(&mut cl_amm, &mut position, &mut lower_array, &mut upper_array, payload);
+
+        // Base in moves the price down, so the arrays go downwards
+        assert!(cl_amm.get_swap_payload(&[&lower_array], 1_000_000, 990_000, 10_000, 20, 10, true).is_err());
+        let swap_payload = cl_amm.get_swap_payload(&[&upper_array, &lower_array], 1_000_000, 996_000, 10_000, 20, 10, true).unwrap();
+        let amount_to_withdraw = swap_payload.amount_to_withdraw();
+        assert!(amount_to_withdraw > 996_000 && amount_to_withdraw < 997_000, "Unexpected swap result {}", amount_to_withdraw);
+        assert!(swap_payload.crossed_ticks().is_empty());
+        cl_amm.swap(swap_payload);
+
+        assert!(cl_amm.sqrt_price() < Q64_128::ONE);
+        assert_eq!(cl_amm.tick_current(), -20);
+        assert_eq!(cl_amm.protocol_base_fees_to_redeem(), 999);
+        assert_eq!(cl_amm.base_liquidity(), 4_987_273 + 1_000_000 - 999);
+        assert_eq!(cl_amm.quote_liquidity(), 4_987_273 - amount_to_withdraw);
+
+        let lower_state = *lower_array.tick(-100, 10).unwrap();
+        let upper_state = *upper_array.tick(100, 10).unwrap();
+        let collect_payload = cl_amm.get_collect_position_fees_payload(&position, &lower_state, &upper_state).unwrap();
+        assert_eq!(collect_payload.base_fees(), 1_999);
+        assert_eq!(collect_payload.quote_fees(), 0);
+        position.update(collect_payload.position());
+        cl_amm.collect_position_fees(collect_payload);
+        assert_eq!(position.base_fees_owed(), 0);
+        assert!(cl_amm.get_collect_position_fees_payload(&position, &lower_state, &upper_state).is_err());
+
+        let withdraw_payload = cl_amm.get_withdraw_payload(&position, &lower_state, &upper_state, 1_000_000_000, 0, 0).unwrap();
+        assert!(cl_amm.get_withdraw_payload(&position, &lower_state, &upper_state, 1_000_000_001, 0, 0).is_err());
+        assert!(cl_amm.get_withdraw_payload(&position, &lower_state, &upper_state, 1_000_000_000, u64::MAX, 0).is_err());
+        // Rounding dust stays in the pool
+        assert!(withdraw_payload.base_amount() <= cl_amm.base_liquidity() && cl_amm.base_liquidity() - withdraw_payload.base_amount() <= 3);
+        assert!(withdraw_payload.quote_amount() <= cl_amm.quote_liquidity() && cl_amm.quote_liquidity() - withdraw_payload.quote_amount() <= 2);
+        assert_eq!(withdraw_payload.tick_lower(), ClAmmTick::default());
+        apply_liquidity_payload(&mut cl_amm, &mut position, &mut lower_array, &mut upper_array, withdraw_payload);
+        assert_eq!(cl_amm.liquidity(), 0);
+        assert_eq!(position.liquidity(), 0);
+
+        let collect_fees_payload = cl_amm.get_collect_fees_payload().unwrap();
+        assert_eq!(collect_fees_payload.protocol_base_fees_to_redeem(), 999);
+        cl_amm.collect_fees(collect_fees_payload);
+        assert!(cl_amm.get_collect_fees_payload().is_err());
+    }
+
+    /// Tests a swap crossing ticks of two positions and running out of the provided tick arrays.
+    #[test]
+    fn test_swap_crossing_ticks() {
+        let mut cl_amm = initialized_cl_amm(0, 10);
+        let (mut array_0, mut array_1) = (tick_array(0), tick_array(160));
+        let mut narrow_position = position(0, 50);
+        let mut wide_position = position(0, 200);
+
+        for position in [&mut narrow_position, &mut wide_position] {
+            let upper_array = if position.tick_upper() < 160 { array_0.clone() } else { array_1.clone() };
+            let lower_state = *array_0.tick(0, 10).unwrap();
+            let upper_state = *upper_array.tick(position.tick_upper(), 10).unwrap();
+            let payload = cl_amm.get_provide_payload(position, &lower_state, &upper_state, 1_000_000_000, u64::MAX, u64::MAX).unwrap();
+            array_0.update_tick(0, 10, payload.tick_lower()).unwrap();
+            if position.tick_upper() < 160 {
+                array_0.update_tick(position.tick_upper(), 10, payload.tick_upper()).unwrap();
+            }
+            else{
+                array_1.update_tick(position.tick_upper(), 10, payload.tick_upper()).unwrap();
+            }
+            position.update(payload.position());
+            cl_amm.modify_liquidity(payload);
+        }
+        assert_eq!(cl_amm.liquidity(), 2_000_000_000);
+
+        // Quote in moves the price up through the narrow position upper tick
+        let swap_payload = cl_amm.get_swap_payload(&[&array_0, &array_1], 10_000_000, 10_000_000, 10_000_000, 0, 0, false).unwrap();
+        assert_eq!(swap_payload.crossed_ticks().len(), 1);
+        let crossed_tick = swap_payload.crossed_ticks()[0];
+        assert_eq!(crossed_tick.tick(), 50);
+        cl_amm.swap(swap_payload);
+        assert_eq!(cl_amm.liquidity(), 1_000_000_000);
+        assert!(cl_amm.tick_current() > 50 && cl_amm.tick_current() < 200);
+
+        // The whole remaining liquidity is not enough, the swap runs out of the tick arrays
+        assert_eq!(
+            cl_amm.get_swap_payload(&[&array_0, &array_1], 1_000_000_000, 1, u64::MAX, 0, 0, false).unwrap_err(),
+            ErrorCode::ClAmmSwapLiquidityExhausted.into()
+        );
+    }
+}
+
+/// Represents the data required to change the liquidity of a `ClAmm` position.
+///
+/// # Fields
+/// - `liquidity`: The updated active liquidity of the pool.
+/// - `base_liquidity`: The updated base token amount owned by positions.
+/// - `quote_liquidity`: The updated quote token amount owned by positions.
+/// - `base_amount`: The amount of base tokens to deposit or withdraw.
+/// - `quote_amount`: The amount of quote tokens to deposit or withdraw.
+/// - `tick_lower`: The updated state of the position lower tick.
+/// - `tick_upper`: The updated state of the position upper tick.
+/// - `position`: The updated position state.
+#[derive(Debug)]
+pub struct ClAmmLiquidityPayload {
+    liquidity: u128,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    base_amount: u64,
+    quote_amount: u64,
+    tick_lower: ClAmmTick,
+    tick_upper: ClAmmTick,
+    position: ClAmmPositionPayload,
+}
+impl ClAmmLiquidityPayload {
+    /// Returns the amount of base tokens to deposit or withdraw.
+    pub fn base_amount(&self) -> u64{
+        self.base_amount
+    }
+
+    /// Returns the amount of quote tokens to deposit or withdraw.
+    pub fn quote_amount(&self) -> u64{
+        self.quote_amount
+    }
+
+    /// Returns the updated state of the position lower tick.
+    pub fn tick_lower(&self) -> ClAmmTick{
+        self.tick_lower
+    }
+
+    /// Returns the updated state of the position upper tick.
+    pub fn tick_upper(&self) -> ClAmmTick{
+        self.tick_upper
+    }
+
+    /// Returns the updated position state.
+    pub fn position(&self) -> ClAmmPositionPayload{
+        self.position
+    }
+}
+
+/// Represents the data required to collect the fees of a `ClAmm` position.
+///
+/// # Fields
+/// - `base_liquidity`: The updated base token amount owned by positions.
+/// - `quote_liquidity`: The updated quote token amount owned by positions.
+/// - `base_fees`: The amount of base fees to collect.
+/// - `quote_fees`: The amount of quote fees to collect.
+/// - `position`: The updated position state.
+#[derive(Debug)]
+pub struct ClAmmCollectPositionFeesPayload {
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    base_fees: u64,
+    quote_fees: u64,
+    position: ClAmmPositionPayload,
+}
+impl ClAmmCollectPositionFeesPayload {
+    /// Returns the amount of base fees to collect.
+    pub fn base_fees(&self) -> u64{
+        self.base_fees
+    }
+
+    /// Returns the amount of quote fees to collect.
+    pub fn quote_fees(&self) -> u64{
+        self.quote_fees
+    }
+
+    /// Returns the updated position state.
+    pub fn position(&self) -> ClAmmPositionPayload{
+        self.position
+    }
+}
+
+/// Represents a tick crossed by a `ClAmm` swap.
+///
+/// # Fields
+/// - `tick`: The crossed tick index.
+/// - `tick_state`: The tick state after crossing.
+#[derive(Debug, Clone, Copy)]
+pub struct ClAmmCrossedTick {
+    tick: i32,
+    tick_state: ClAmmTick,
+}
+impl ClAmmCrossedTick {
+    /// Returns the crossed tick index.
+    pub fn tick(&self) -> i32{
+        self.tick
+    }
+
+    /// Returns the tick state after crossing.
+    pub fn tick_state(&self) -> ClAmmTick{
+        self.tick_state
+    }
+}
+
+/// Represents the data required for a token swap operation in the Concentrated Liquidity AMM.
+///
+/// # Fields
+/// - `sqrt_price`: The square root price after the swap.
+/// - `tick_current`: The tick containing the price after the swap.
+/// - `liquidity`: The active liquidity after the swap.
+/// - `fee_growth_global_base`: The updated global base fee growth.
+/// - `fee_growth_global_quote`: The updated global quote fee growth.
+/// - `base_liquidity`: The updated base token amount owned by positions.
+/// - `quote_liquidity`: The updated quote token amount owned by positions.
+/// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
+/// - `amount_to_withdraw`: The amount of tokens to withdraw after the swap.
+/// - `is_in_out`: Indicates whether the swap is "in-to-out" (true) or "out-to-in" (false).
+/// - `crossed_ticks`: The ticks crossed by the swap.
+#[derive(Debug)]
+pub struct ClAmmSwapPayload {
+    sqrt_price: Q64_128,
+    tick_current: i32,
+    liquidity: u128,
+    fee_growth_global_base: Q64_128,
+    fee_growth_global_quote: Q64_128,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    protocol_fees_to_redeem: u64,
+    amount_to_withdraw: u64,
+    is_in_out: bool,
+    crossed_ticks: Vec<ClAmmCrossedTick>,
+}
+impl ClAmmSwapPayload {
+    /// Returns the amount of tokens to withdraw after the swap.
+    pub fn amount_to_withdraw(&self) -> u64{
+        self.amount_to_withdraw
+    }
+
+    /// Returns the ticks crossed by the swap.
+    pub fn crossed_ticks(&self) -> &[ClAmmCrossedTick]{
+        &self.crossed_ticks
+    }
+}
@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+
+/// Liquidity position of a single owner in a `ClAmm` price range.
+///
+/// Positions are PDAs of the owner, so they can't be transferred,
+/// but an owner can hold one position per price range of every `ClAmm`.
+#[account]
+#[derive(InitSpace)]
+pub struct ClAmmPosition {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the `ClAmm` the position belongs to.
+    cl_amm: Pubkey, // 32 bytes
+
+    /// Public key of the position owner.
+    owner: Pubkey, // 32 bytes
+
+    /// Lower tick of the position price range.
+    tick_lower: i32, // 4 bytes
+
+    /// Upper tick of the position price range.
+    tick_upper: i32, // 4 bytes
+
+    /// Liquidity provided by the position.
+    liquidity: u128, // 16 bytes
+
+    /// Base fee growth inside the position range at the last position update.
+    fee_growth_inside_base_last: Q64_128, // 24 bytes
+
+    /// Quote fee growth inside the position range at the last position update.
+    fee_growth_inside_quote_last: Q64_128, // 24 bytes
+
+    /// Base fees earned by the position and not collected yet.
+    base_fees_owed: u64, // 8 bytes
+
+    /// Quote fees earned by the position and not collected yet.
+    quote_fees_owed: u64, // 8 bytes
+}
+
+impl ClAmmPosition {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cl_amm_position";
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cl_amm(&self) -> &Pubkey {
+        &self.cl_amm
+    }
+
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline]
+    pub fn tick_lower(&self) -> i32 {
+        self.tick_lower
+    }
+
+    #[inline]
+    pub fn tick_upper(&self) -> i32 {
+        self.tick_upper
+    }
+
+    #[inline]
+    pub fn liquidity(&self) -> u128 {
+        self.liquidity
+    }
+
+    #[inline]
+    pub fn fee_growth_inside_base_last(&self) -> Q64_128 {
+        self.fee_growth_inside_base_last
+    }
+
+    #[inline]
+    pub fn fee_growth_inside_quote_last(&self) -> Q64_128 {
+        self.fee_growth_inside_quote_last
+    }
+
+    #[inline]
+    pub fn base_fees_owed(&self) -> u64 {
+        self.base_fees_owed
+    }
+
+    #[inline]
+    pub fn quote_fees_owed(&self) -> u64 {
+        self.quote_fees_owed
+    }
+}
+
+impl ClAmmPosition {
+    /// Initializes an empty position.
+    ///
+    /// # Parameters
+    /// - `cl_amm`: Public key of the `ClAmm`.
+    /// - `owner`: Public key of the position owner.
+    /// - `tick_lower`: Lower tick of the position price range.
+    /// - `tick_upper`: Upper tick of the position price range.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, cl_amm: Pubkey, owner: Pubkey, tick_lower: i32, tick_upper: i32, bump: u8) {
+        self.cl_amm = cl_amm;
+        self.owner = owner;
+        self.tick_lower = tick_lower;
+        self.tick_upper = tick_upper;
+        self.bump = [bump];
+    }
+
+    /// Updates the position liquidity and fees.
+    ///
+    /// # Parameters
+    /// - `position_payload`: Contains the new position liquidity, fee growth checkpoints and fees owed.
+    pub(crate) fn update(&mut self, position_payload: ClAmmPositionPayload) {
+        self.liquidity = position_payload.liquidity;
+        self.fee_growth_inside_base_last = position_payload.fee_growth_inside_base_last;
+        self.fee_growth_inside_quote_last = position_payload.fee_growth_inside_quote_last;
+        self.base_fees_owed = position_payload.base_fees_owed;
+        self.quote_fees_owed = position_payload.quote_fees_owed;
+    }
+}
+
+#[cfg(test)]
+mod cl_amm_position_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    /// Tests `ClAmmPosition` account data layout.
+    #[test]
+    fn test_cl_amm_position_data_layout() {
+        let cl_amm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut position = ClAmmPosition {
+            bump: [0],
+            cl_amm: Pubkey::default(),
+            owner: Pubkey::default(),
+            tick_lower: 0,
+            tick_upper: 0,
+            liquidity: 0,
+            fee_growth_inside_base_last: Q64_128::default(),
+            fee_growth_inside_quote_last: Q64_128::default(),
+            base_fees_owed: 0,
+            quote_fees_owed: 0,
+        };
+        position.initialize(cl_amm, owner, -120, 60, 254);
+        position.update(ClAmmPositionPayload::new(1_000_000, Q64_128::from_u64(3), Q64_128::from_u64(4), 10, 20));
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 153];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&ClAmmPosition::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = 254; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cl_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 4].copy_from_slice(&(-120i32).to_le_bytes()); offset += 4;
+        data[offset..offset + 4].copy_from_slice(&60i32.to_le_bytes()); offset += 4;
+        data[offset..offset + 16].copy_from_slice(&1_000_000u128.to_le_bytes()); offset += 16;
+        data[offset..offset + 24].copy_from_slice(&Q64_128::from_u64(3).to_le_bytes()); offset += 24;
+        data[offset..offset + 24].copy_from_slice(&Q64_128::from_u64(4).to_le_bytes()); offset += 24;
+        data[offset..offset + 8].copy_from_slice(&10u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&20u64.to_le_bytes()); offset += 8;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + ClAmmPosition::INIT_SPACE, offset);
+
+        let deserialized_position = ClAmmPosition::try_deserialize(&mut data.as_ref()).unwrap();
+        assert_eq!(deserialized_position.owner(), &owner);
+        assert_eq!(deserialized_position.tick_lower(), -120);
+        assert_eq!(deserialized_position.liquidity(), 1_000_000);
+        assert_eq!(deserialized_position.quote_fees_owed(), 20);
+
+        let mut serialized_position = Vec::new();
+        position.try_serialize(&mut serialized_position).unwrap();
+        assert_eq!(serialized_position.as_slice(), data.as_ref());
+    }
+}
+
+/// Represents the new state of a `ClAmm` position.
+///
+/// # Fields
+/// - `liquidity`: The updated position liquidity.
+/// - `fee_growth_inside_base_last`: The base fee growth inside the position range at the update.
+/// - `fee_growth_inside_quote_last`: The quote fee growth inside the position range at the update.
+/// - `base_fees_owed`: The updated base fees owed to the position.
+/// - `quote_fees_owed`: The updated quote fees owed to the position.
+#[derive(Debug, Clone, Copy)]
+pub struct ClAmmPositionPayload {
+    liquidity: u128,
+    fee_growth_inside_base_last: Q64_128,
+    fee_growth_inside_quote_last: Q64_128,
+    base_fees_owed: u64,
+    quote_fees_owed: u64,
+}
+impl ClAmmPositionPayload {
+    pub(crate) fn new(liquidity: u128, fee_growth_inside_base_last: Q64_128, fee_growth_inside_quote_last: Q64_128, base_fees_owed: u64, quote_fees_owed: u64) -> Self {
+        Self {
+            liquidity,
+            fee_growth_inside_base_last,
+            fee_growth_inside_quote_last,
+            base_fees_owed,
+            quote_fees_owed,
+        }
+    }
+
+    /// Returns the updated position liquidity.
+    pub fn liquidity(&self) -> u128 {
+        self.liquidity
+    }
+
+    /// Returns the base fee growth inside the position range at the update.
+    pub fn fee_growth_inside_base_last(&self) -> Q64_128 {
+        self.fee_growth_inside_base_last
+    }
+
+    /// Returns the quote fee growth inside the position range at the update.
+    pub fn fee_growth_inside_quote_last(&self) -> Q64_128 {
+        self.fee_growth_inside_quote_last
+    }
+
+    /// Returns the updated base fees owed to the position.
+    pub fn base_fees_owed(&self) -> u64 {
+        self.base_fees_owed
+    }
+
+    /// Returns the updated quote fees owed to the position.
+    pub fn quote_fees_owed(&self) -> u64 {
+        self.quote_fees_owed
+    }
+}
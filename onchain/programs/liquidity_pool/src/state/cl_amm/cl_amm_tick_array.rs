@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+
+/// State of a single `ClAmm` tick.
+///
+/// A tick is initialized while any position uses it as a lower or upper bound.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClAmmTick {
+    /// Liquidity added to the active liquidity when the price crosses the tick upwards.
+    liquidity_net: i128, // 16 bytes
+
+    /// Total liquidity of the positions using the tick as a bound.
+    liquidity_gross: u128, // 16 bytes
+
+    /// Base fee growth on the other side of the tick from the current price, stored as a wrapping Q64.128 fixed-point number.
+    fee_growth_outside_base: Q64_128, // 24 bytes
+
+    /// Quote fee growth on the other side of the tick from the current price, stored as a wrapping Q64.128 fixed-point number.
+    fee_growth_outside_quote: Q64_128, // 24 bytes
+}
+
+impl ClAmmTick {
+    #[inline]
+    pub fn liquidity_net(&self) -> i128 {
+        self.liquidity_net
+    }
+
+    #[inline]
+    pub fn liquidity_gross(&self) -> u128 {
+        self.liquidity_gross
+    }
+
+    #[inline]
+    pub fn fee_growth_outside_base(&self) -> Q64_128 {
+        self.fee_growth_outside_base
+    }
+
+    #[inline]
+    pub fn fee_growth_outside_quote(&self) -> Q64_128 {
+        self.fee_growth_outside_quote
+    }
+
+    /// Checks if any position uses the tick as a bound.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.liquidity_gross > 0
+    }
+
+    /// Calculates the tick state after a position liquidity change.
+    ///
+    /// A newly initialized tick at or below the current tick assumes all the fees so far were earned below it.
+    /// The returned state is not cleared when its gross liquidity drops to zero,
+    /// so it can still be used to calculate the position fees.
+    ///
+    /// # Parameters
+    /// - `tick`: Index of the tick.
+    /// - `tick_current`: Index of the tick containing the current price.
+    /// - `liquidity_delta`: Signed change of the position liquidity.
+    /// - `is_upper`: Whether the tick is the upper bound of the position.
+    /// - `fee_growth_global_base`: Current global base fee growth.
+    /// - `fee_growth_global_quote`: Current global quote fee growth.
+    ///
+    /// # Returns
+    /// - `Some(ClAmmTick)` with the updated tick state.
+    /// - `None` if the liquidity overflows or underflows.
+    pub fn update(&self, tick: i32, tick_current: i32, liquidity_delta: i128, is_upper: bool, fee_growth_global_base: Q64_128, fee_growth_global_quote: Q64_128) -> Option<ClAmmTick> {
+        let liquidity_gross = if liquidity_delta >= 0 {
+            self.liquidity_gross.checked_add(liquidity_delta.unsigned_abs())?
+        }
+        else{
+            self.liquidity_gross.checked_sub(liquidity_delta.unsigned_abs())?
+        };
+        let liquidity_net = if is_upper {
+            self.liquidity_net.checked_sub(liquidity_delta)?
+        }
+        else{
+            self.liquidity_net.checked_add(liquidity_delta)?
+        };
+        let (fee_growth_outside_base, fee_growth_outside_quote) = if !self.is_initialized() && tick <= tick_current {
+            (fee_growth_global_base, fee_growth_global_quote)
+        }
+        else{
+            (self.fee_growth_outside_base, self.fee_growth_outside_quote)
+        };
+        Some(ClAmmTick {
+            liquidity_net,
+            liquidity_gross,
+            fee_growth_outside_base,
+            fee_growth_outside_quote,
+        })
+    }
+
+    /// Calculates the tick state after the price crosses it.
+    ///
+    /// # Parameters
+    /// - `fee_growth_global_base`: Global base fee growth at the moment of crossing.
+    /// - `fee_growth_global_quote`: Global quote fee growth at the moment of crossing.
+    pub fn cross(&self, fee_growth_global_base: Q64_128, fee_growth_global_quote: Q64_128) -> ClAmmTick {
+        ClAmmTick {
+            fee_growth_outside_base: fee_growth_global_base.wrapping_sub(self.fee_growth_outside_base),
+            fee_growth_outside_quote: fee_growth_global_quote.wrapping_sub(self.fee_growth_outside_quote),
+            ..*self
+        }
+    }
+
+    /// Returns the tick state to store, clearing the tick if no position uses it anymore.
+    pub fn cleared_if_unused(self) -> ClAmmTick {
+        if self.is_initialized() { self } else { ClAmmTick::default() }
+    }
+}
+
+/// Fixed-size group of consecutive `ClAmm` ticks.
+///
+/// Ticks are stored in separate PDAs, so the pool account keeps a constant size
+/// and swaps only load the ticks around the current price.
+#[account]
+#[derive(InitSpace)]
+pub struct ClAmmTickArray {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the `ClAmm` the ticks belong to.
+    cl_amm: Pubkey, // 32 bytes
+
+    /// Index of the first tick in the array.
+    start_tick: i32, // 4 bytes
+
+    /// Ticks spaced by the `ClAmm` tick spacing, starting at `start_tick`.
+    ticks: [ClAmmTick; ClAmmTickArray::TICKS_COUNT], // 80 * TICKS_COUNT bytes
+}
+
+impl ClAmmTickArray {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cl_amm_tick_array";
+
+    /// Number of ticks stored in a single array.
+    pub const TICKS_COUNT: usize = 16;
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cl_amm(&self) -> &Pubkey {
+        &self.cl_amm
+    }
+
+    #[inline]
+    pub fn start_tick(&self) -> i32 {
+        self.start_tick
+    }
+
+    /// Calculates the number of tick indexes covered by a single array.
+    #[inline]
+    pub fn ticks_span(tick_spacing: u16) -> i32 {
+        Self::TICKS_COUNT as i32 * tick_spacing as i32
+    }
+
+    /// Calculates the start tick of the array containing the given tick.
+    #[inline]
+    pub fn start_tick_for(tick: i32, tick_spacing: u16) -> i32 {
+        let ticks_span = Self::ticks_span(tick_spacing);
+        tick.div_euclid(ticks_span) * ticks_span
+    }
+
+    /// Calculates the position of the tick inside the array.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` with the tick position.
+    /// - `Err(ErrorCode)` if the tick is outside of the array or not aligned to the tick spacing.
+    fn tick_offset(&self, tick: i32, tick_spacing: u16) -> Result<usize> {
+        let tick_spacing = tick_spacing as i32;
+        let distance = tick.checked_sub(self.start_tick).ok_or(ErrorCode::TickNotInTickArray)?;
+        require!(distance >= 0 && distance % tick_spacing == 0, ErrorCode::TickNotInTickArray);
+        let offset = (distance / tick_spacing) as usize;
+        require!(offset < Self::TICKS_COUNT, ErrorCode::TickNotInTickArray);
+        Ok(offset)
+    }
+
+    /// Returns the state of the given tick.
+    ///
+    /// # Returns
+    /// - `Ok(&ClAmmTick)` with the tick state.
+    /// - `Err(ErrorCode)` if the tick is outside of the array or not aligned to the tick spacing.
+    pub fn tick(&self, tick: i32, tick_spacing: u16) -> Result<&ClAmmTick> {
+        let offset = self.tick_offset(tick, tick_spacing)?;
+        Ok(&self.ticks[offset])
+    }
+
+    /// Finds the next initialized tick in the array in the swap direction.
+    ///
+    /// # Parameters
+    /// - `tick`: Index of the tick to start the search from.
+    /// - `tick_spacing`: The `ClAmm` tick spacing.
+    /// - `is_in_out`: `true` to search downwards including `tick`, `false` to search upwards excluding `tick`.
+    ///
+    /// # Returns
+    /// - `Some(i32)` with the index of the found tick.
+    /// - `None` if the array has no initialized ticks in the swap direction.
+    pub fn next_initialized_tick(&self, tick: i32, tick_spacing: u16, is_in_out: bool) -> Option<i32> {
+        let spacing = tick_spacing as i32;
+        let end_tick = self.start_tick + Self::ticks_span(tick_spacing);
+        let offsets = if is_in_out {
+            if tick < self.start_tick {
+                return None;
+            }
+            let last_offset = ((tick.min(end_tick - 1) - self.start_tick) / spacing) as usize;
+            (0..=last_offset).rev().find(|&offset| self.ticks[offset].is_initialized())
+        }
+        else{
+            let first_offset = if tick < self.start_tick { 0 } else { ((tick - self.start_tick) / spacing + 1) as usize };
+            (first_offset..Self::TICKS_COUNT).find(|&offset| self.ticks[offset].is_initialized())
+        };
+        offsets.map(|offset| self.start_tick + offset as i32 * spacing)
+    }
+}
+
+impl ClAmmTickArray {
+    /// Initializes the tick array of a `ClAmm`.
+    ///
+    /// # Parameters
+    /// - `cl_amm`: Public key of the `ClAmm`.
+    /// - `start_tick`: Index of the first tick in the array.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, cl_amm: Pubkey, start_tick: i32, bump: u8) {
+        self.cl_amm = cl_amm;
+        self.start_tick = start_tick;
+        self.bump = [bump];
+    }
+
+    /// Stores the new state of the given tick.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the tick is updated.
+    /// - `Err(ErrorCode)` if the tick is outside of the array or not aligned to the tick spacing.
+    pub(crate) fn update_tick(&mut self, tick: i32, tick_spacing: u16, tick_state: ClAmmTick) -> Result<()> {
+        let offset = self.tick_offset(tick, tick_spacing)?;
+        self.ticks[offset] = tick_state;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cl_amm_tick_array_tests {
+    use super::*;
+
+    fn tick_array(start_tick: i32) -> ClAmmTickArray {
+        ClAmmTickArray {
+            bump: [0],
+            cl_amm: Pubkey::default(),
+            start_tick,
+            ticks: [ClAmmTick::default(); ClAmmTickArray::TICKS_COUNT],
+        }
+    }
+
+    /// Tests the start tick calculation for positive and negative ticks.
+    #[test]
+    fn test_start_tick_for() {
+        assert_eq!(ClAmmTickArray::ticks_span(10), 160);
+        assert_eq!(ClAmmTickArray::start_tick_for(0, 10), 0);
+        assert_eq!(ClAmmTickArray::start_tick_for(159, 10), 0);
+        assert_eq!(ClAmmTickArray::start_tick_for(160, 10), 160);
+        assert_eq!(ClAmmTickArray::start_tick_for(-1, 10), -160);
+        assert_eq!(ClAmmTickArray::start_tick_for(-160, 10), -160);
+        assert_eq!(ClAmmTickArray::start_tick_for(-161, 10), -320);
+    }
+
+    /// Tests tick access bounds and alignment.
+    #[test]
+    fn test_tick_access() {
+        let mut tick_array = tick_array(-160);
+        assert!(tick_array.tick(-160, 10).is_ok());
+        assert!(tick_array.tick(-10, 10).is_ok());
+        assert!(tick_array.tick(0, 10).is_err());
+        assert!(tick_array.tick(-170, 10).is_err());
+        assert!(tick_array.tick(-155, 10).is_err());
+
+        let tick_state = ClAmmTick::default().update(-50, 0, 1_000, false, Q64_128::ONE, Q64_128::ONE).unwrap();
+        tick_array.update_tick(-50, 10, tick_state).unwrap();
+        assert_eq!(*tick_array.tick(-50, 10).unwrap(), tick_state);
+        assert!(tick_array.update_tick(-45, 10, tick_state).is_err());
+    }
+
+    /// Tests searching the next initialized tick in both directions.
+    #[test]
+    fn test_next_initialized_tick() {
+        let mut tick_array = tick_array(0);
+        let tick_state = ClAmmTick::default().update(0, 0, 1_000, false, Q64_128::ONE, Q64_128::ONE).unwrap();
+        tick_array.update_tick(30, 10, tick_state).unwrap();
+        tick_array.update_tick(100, 10, tick_state).unwrap();
+
+        assert_eq!(tick_array.next_initialized_tick(30, 10, true), Some(30));
+        assert_eq!(tick_array.next_initialized_tick(99, 10, true), Some(30));
+        assert_eq!(tick_array.next_initialized_tick(500, 10, true), Some(100));
+        assert_eq!(tick_array.next_initialized_tick(29, 10, true), None);
+        assert_eq!(tick_array.next_initialized_tick(-1, 10, true), None);
+
+        assert_eq!(tick_array.next_initialized_tick(30, 10, false), Some(100));
+        assert_eq!(tick_array.next_initialized_tick(29, 10, false), Some(30));
+        assert_eq!(tick_array.next_initialized_tick(-500, 10, false), Some(30));
+        assert_eq!(tick_array.next_initialized_tick(100, 10, false), None);
+    }
+
+    /// Tests tick updates and crossing.
+    #[test]
+    fn test_tick_update_and_cross() {
+        let global = Q64_128::from_u64(5);
+        let below = ClAmmTick::default().update(-10, 0, 1_000, false, global, global).unwrap();
+        assert_eq!(below.liquidity_net(), 1_000);
+        assert_eq!(below.liquidity_gross(), 1_000);
+        assert_eq!(below.fee_growth_outside_base(), global);
+
+        let above = ClAmmTick::default().update(10, 0, 1_000, true, global, global).unwrap();
+        assert_eq!(above.liquidity_net(), -1_000);
+        assert_eq!(above.fee_growth_outside_quote(), Q64_128::default());
+
+        let crossed = above.cross(Q64_128::from_u64(7), global);
+        assert_eq!(crossed.fee_growth_outside_base(), Q64_128::from_u64(7));
+        assert_eq!(crossed.liquidity_net(), -1_000);
+
+        let removed = above.update(10, 0, -1_000, true, global, global).unwrap();
+        assert!(!removed.is_initialized());
+        assert_eq!(removed.cleared_if_unused(), ClAmmTick::default());
+        assert!(above.update(10, 0, -1_001, true, global, global).is_none());
+    }
+}
@@ -0,0 +1,11 @@
+mod cl_amm_pool;
+mod cl_amm_calculate;
+mod cl_amm_core;
+mod cl_amm_position;
+mod cl_amm_tick_array;
+
+pub use cl_amm_pool::*;
+pub use cl_amm_core::*;
+pub use cl_amm_position::*;
+pub use cl_amm_tick_array::*;
+pub(crate) use cl_amm_calculate::*;
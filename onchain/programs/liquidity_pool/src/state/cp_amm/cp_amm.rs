@@ -1,11 +1,10 @@
 use anchor_lang::{account, InitSpace};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint};
 use anchor_spl::token_interface;
 use utilities::math::Q64_128;
 use crate::error::ErrorCode;
 use crate::state::AmmsConfig;
-use super::{CpAmmCalculate, CpAmmCore};
+use super::{CpAmmCalculate, CpAmmCore, PriceObservation};
 
 /// Represents a Constant Product Automated Market Maker (AMM) pool.
 ///
@@ -84,7 +83,52 @@ pub struct CpAmm {
     amms_config: Pubkey, // 32 bytes
 
     /// Public key of the CpAmm creator account.
-    creator: Pubkey // 32 bytes
+    creator: Pubkey, // 32 bytes
+
+    /// Time-weighted sum of the base-per-quote price, stored as a Q64.128 fixed-point number.
+    /// Wraps on overflow, so only the difference between two observations is meaningful.
+    cumulative_base_per_quote_price: Q64_128, // 24 bytes
+
+    /// Time-weighted sum of the quote-per-base price, stored as a Q64.128 fixed-point number.
+    /// Wraps on overflow, so only the difference between two observations is meaningful.
+    cumulative_quote_per_base_price: Q64_128, // 24 bytes
+
+    /// Unix timestamp of the last cumulative prices update.
+    last_price_update_timestamp: i64, // 8 bytes
+
+    /// Decaying accumulator of the recent price movement, measured in basis points.
+    /// Drives the providers fee rate when the `AmmsConfig` dynamic fee mode is enabled.
+    volatility_accumulator: u64, // 8 bytes
+
+    /// Unix timestamp of the last volatility accumulator update.
+    last_volatility_update_timestamp: i64, // 8 bytes
+
+    /// Whether swaps are halted in this pool, independently of the `AmmsConfig` pause state.
+    swaps_paused: bool, // 1 byte
+
+    /// Whether liquidity provision is halted in this pool, independently of the `AmmsConfig` pause state.
+    /// Withdrawals are never paused.
+    provides_paused: bool, // 1 byte
+
+    /// LP tokens accrued to the protocol in the `AmmsConfig` fee-on-LP mode, which are not minted yet.
+    /// Already included in `lp_tokens_supply`.
+    protocol_lp_tokens_to_redeem: u64, // 8 bytes
+
+    /// The square root of the constant product at the last protocol LP fees checkpoint.
+    /// Zero while the `AmmsConfig` fee-on-LP mode is disabled.
+    last_constant_product_sqrt: Q64_128, // 24 bytes
+
+    /// Base tokens lent by the flash loan in progress. Zero when there is no flash loan.
+    flash_loan_base_amount: u64, // 8 bytes
+
+    /// Quote tokens lent by the flash loan in progress. Zero when there is no flash loan.
+    flash_loan_quote_amount: u64, // 8 bytes
+
+    /// Base tokens fee owed on top of `flash_loan_base_amount` by the flash loan in progress.
+    flash_loan_base_fee_amount: u64, // 8 bytes
+
+    /// Quote tokens fee owed on top of `flash_loan_quote_amount` by the flash loan in progress.
+    flash_loan_quote_fee_amount: u64, // 8 bytes
 }
 
 impl CpAmm {
@@ -94,6 +138,23 @@ impl CpAmm {
     /// Seed used for generating the vaults PDAs.
     pub const VAULT_SEED: &'static [u8] = b"vault";
 
+    /// Period in seconds over which the volatility accumulator decays by half.
+    pub const VOLATILITY_HALF_LIFE: i64 = 300;
+
+    /// Size of the initial account layout, before the price accumulators, volatility, pause,
+    /// fee-on-LP and flash loan fields were appended. Such accounts are grown by `migrate_cp_amm`.
+    pub const INITIAL_LAYOUT_LEN: usize = 8 + 358;
+
+    /// Returns the name and the symbol of the LP token metadata, e.g. "BASE-QUOTE LP" and "BASE-QUOTE".
+    ///
+    /// # Parameters
+    /// - `base_symbol`: Symbol of the base token.
+    /// - `quote_symbol`: Symbol of the quote token.
+    pub fn lp_token_metadata(base_symbol: &str, quote_symbol: &str) -> (String, String) {
+        let symbol = format!("{}-{}", base_symbol, quote_symbol);
+        (format!("{} LP", symbol), symbol)
+    }
+
     /// Returns the seeds for generating the PDA.
     ///
     /// The PDA is derived using the `SEED`, the `lp_mint`, and the `bump` value.
@@ -191,6 +252,90 @@ impl CpAmm {
     pub fn protocol_quote_fees_to_redeem(&self) -> u64 {
         self.protocol_quote_fees_to_redeem
     }
+
+    #[inline]
+    pub fn cumulative_base_per_quote_price(&self) -> Q64_128 {
+        self.cumulative_base_per_quote_price
+    }
+
+    #[inline]
+    pub fn cumulative_quote_per_base_price(&self) -> Q64_128 {
+        self.cumulative_quote_per_base_price
+    }
+
+    #[inline]
+    pub fn last_price_update_timestamp(&self) -> i64 {
+        self.last_price_update_timestamp
+    }
+
+    #[inline]
+    pub fn last_volatility_update_timestamp(&self) -> i64 {
+        self.last_volatility_update_timestamp
+    }
+
+    /// Checks if swaps are halted in this pool.
+    #[inline]
+    pub fn swaps_paused(&self) -> bool {
+        self.swaps_paused
+    }
+
+    /// Checks if liquidity provision is halted in this pool.
+    #[inline]
+    pub fn provides_paused(&self) -> bool {
+        self.provides_paused
+    }
+
+    #[inline]
+    pub fn protocol_lp_tokens_to_redeem(&self) -> u64 {
+        self.protocol_lp_tokens_to_redeem
+    }
+
+    #[inline]
+    pub fn last_constant_product_sqrt(&self) -> Q64_128 {
+        self.last_constant_product_sqrt
+    }
+
+    /// Checks if a flash loan is in progress in this pool.
+    #[inline]
+    pub fn is_flash_loan_active(&self) -> bool {
+        self.flash_loan_base_amount > 0 || self.flash_loan_quote_amount > 0
+    }
+
+    /// Returns the base tokens lent by the flash loan in progress.
+    #[inline]
+    pub fn flash_loan_base_amount(&self) -> u64 {
+        self.flash_loan_base_amount
+    }
+
+    /// Returns the quote tokens lent by the flash loan in progress.
+    #[inline]
+    pub fn flash_loan_quote_amount(&self) -> u64 {
+        self.flash_loan_quote_amount
+    }
+
+    /// Returns the base tokens fee owed by the flash loan in progress.
+    #[inline]
+    pub fn flash_loan_base_fee_amount(&self) -> u64 {
+        self.flash_loan_base_fee_amount
+    }
+
+    /// Returns the quote tokens fee owed by the flash loan in progress.
+    #[inline]
+    pub fn flash_loan_quote_fee_amount(&self) -> u64 {
+        self.flash_loan_quote_fee_amount
+    }
+
+    /// Returns the base and quote tokens owed by the flash loan in progress, the lent amounts plus their fees.
+    ///
+    /// # Errors
+    /// - `SwapOverflowError` if an amount plus its fee overflows.
+    #[inline]
+    pub fn flash_loan_amounts_to_repay(&self) -> Result<(u64, u64)> {
+        Ok((
+            self.flash_loan_base_amount.checked_add(self.flash_loan_base_fee_amount).ok_or(ErrorCode::SwapOverflowError)?,
+            self.flash_loan_quote_amount.checked_add(self.flash_loan_quote_fee_amount).ok_or(ErrorCode::SwapOverflowError)?
+        ))
+    }
 }
 
 /// Implements the `CpAmmCore` trait for the `CpAmm` struct.
@@ -248,6 +393,7 @@ impl CpAmm {
     /// - The AMM has been launched.
     /// - The pool has non-zero base and quote liquidity.
     /// - The pool has a positive supply of LP tokens.
+    /// - There is no flash loan in progress, so the vaults hold the whole liquidity.
     ///
     /// # Returns
     /// - `Ok(())` if the state is valid.
@@ -258,6 +404,7 @@ impl CpAmm {
         require!(self.quote_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
         require!(self.base_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
         require!(self.lp_tokens_supply > 0, ErrorCode::LpTokensSupplyIsZero);
+        require!(!self.is_flash_loan_active(), ErrorCode::FlashLoanInProgress);
         Ok(())
     }
     
@@ -300,12 +447,13 @@ impl CpAmm {
     /// # Parameters
     /// - `base_liquidity`: The amount of base liquidity to provide.
     /// - `quote_liquidity`: The amount of quote liquidity to provide.
+    /// - `min_lp_tokens`: Minimal amount of LP tokens the provider is willing to receive.
     ///
     /// # Returns
     /// - `Ok(ProvidePayload)` containing the updated pool state and LP tokens to mint.
-    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    /// - `Err(ErrorCode)` if any checks fail, calculations encounter errors or LP tokens to mint are below the minimum.
     #[inline(never)]
-    pub fn get_provide_payload(&self, base_liquidity: u64, quote_liquidity: u64) -> Result<ProvidePayload> {
+    pub fn get_provide_payload(&self, base_liquidity: u64, quote_liquidity: u64, min_lp_tokens: u64) -> Result<ProvidePayload> {
         self.check_state()?;
         require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
         require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
@@ -317,6 +465,7 @@ impl CpAmm {
         let new_constant_product_sqrt = Self::calculate_constant_product_sqrt(new_base_liquidity, new_quote_liquidity).unwrap();
         
         let lp_tokens_to_mint = self.calculate_lp_mint_for_provided_liquidity(new_constant_product_sqrt).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        require!(lp_tokens_to_mint >= min_lp_tokens, ErrorCode::ProvideLpTokensBelowMinimum);
 
         let new_lp_tokens_supply = self.lp_tokens_supply.checked_add(lp_tokens_to_mint).ok_or(ErrorCode::ProvideOverflowError)?;
         Ok(ProvidePayload {
@@ -335,18 +484,22 @@ impl CpAmm {
     ///
     /// # Parameters
     /// - `lp_tokens`: The number of LP tokens to redeem for liquidity withdrawal.
+    /// - `min_base_out`: Minimal amount of base tokens the withdrawer is willing to receive.
+    /// - `min_quote_out`: Minimal amount of quote tokens the withdrawer is willing to receive.
     ///
     /// # Returns
     /// - `Ok(WithdrawPayload)` containing the updated pool state and withdrawn liquidity amounts.
-    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    /// - `Err(ErrorCode)` if any checks fail, calculations encounter errors or withdrawn amounts are below the minimums.
     #[inline(never)]
-    pub fn get_withdraw_payload(&self, lp_tokens: u64) -> Result<WithdrawPayload> {
+    pub fn get_withdraw_payload(&self, lp_tokens: u64, min_base_out: u64, min_quote_out: u64) -> Result<WithdrawPayload> {
         self.check_state()?;
         require!(lp_tokens > 0, ErrorCode::ProvidedLpTokensIsZero);
 
         let lp_tokens_left_supply = self.lp_tokens_supply.checked_sub(lp_tokens).ok_or(ErrorCode::WithdrawOverflowError)?;
 
         let (base_withdraw, quote_withdraw) = self.calculate_liquidity_from_share(lp_tokens).ok_or(ErrorCode::WithdrawLiquidityCalculationFailed)?;
+        require!(base_withdraw >= min_base_out, ErrorCode::WithdrawBaseBelowMinimum);
+        require!(quote_withdraw >= min_quote_out, ErrorCode::WithdrawQuoteBelowMinimum);
         
         let new_base_liquidity = self.base_liquidity.checked_sub(base_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
         let new_quote_liquidity = self.quote_liquidity.checked_sub(quote_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
@@ -376,53 +529,250 @@ impl CpAmm {
     /// - `allowed_slippage`: Maximum permissible deviation from `estimated_result`.
     /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
     /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `referrer_fee_share_basis_points`: The share of the protocol fee paid to the swap referrer,
+    ///   in basis points of the protocol fee. Zero for swaps without a referrer.
     /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
     ///
     /// # Returns
     /// - `Ok(SwapPayload)`: Contains the updated liquidity state and fees.
     /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or slippage exceeded).
     #[inline(never)]
-    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_in_out: bool) -> Result<SwapPayload> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, referrer_fee_share_basis_points: u16, is_in_out: bool) -> Result<SwapPayload> {
         self.check_state()?;
         require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
         require!(estimated_result > 0, ErrorCode::EstimatedResultIsZero);
+
+        let swap_payload = self.calculate_swap_payload(swap_amount, providers_fee_rate_basis_points, protocol_fee_rate_basis_points, referrer_fee_share_basis_points, is_in_out)?;
+        Self::check_swap_result(swap_payload.amount_to_withdraw, estimated_result, allowed_slippage)?;
+
+        Ok(swap_payload)
+    }
+
+    /// Computes the swap payload for a single hop of a routed swap.
+    ///
+    /// Works like [`CpAmm::get_swap_payload`], but skips the per-hop slippage check:
+    /// a routed swap only validates the result of its last hop against the minimal output.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The amount of tokens being swapped (either base or quote).
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
+    ///
+    /// # Returns
+    /// - `Ok(SwapPayload)`: Contains the updated liquidity state and fees.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or zero result).
+    #[inline(never)]
+    pub fn get_routed_swap_payload(&self, swap_amount: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_in_out: bool) -> Result<SwapPayload> {
+        self.check_state()?;
+        require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
+
+        let swap_payload = self.calculate_swap_payload(swap_amount, providers_fee_rate_basis_points, protocol_fee_rate_basis_points, 0, is_in_out)?;
+        require!(swap_payload.amount_to_withdraw > 0, ErrorCode::SwapResultIsZero);
+
+        Ok(swap_payload)
+    }
+
+    /// Calculates fees and afterswap liquidity shared by regular and routed swaps.
+    ///
+    /// The referrer fee is carved out of the protocol fee, so it leaves the pool instead of
+    /// being added to the protocol fees to redeem.
+    fn calculate_swap_payload(&self, swap_amount: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, referrer_fee_share_basis_points: u16, is_in_out: bool) -> Result<SwapPayload> {
         require!(providers_fee_rate_basis_points + protocol_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        require!(referrer_fee_share_basis_points <= AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS, ErrorCode::ReferrerFeeShareExceeded);
 
         let providers_fee_amount = Self::calculate_fee_amount(swap_amount, providers_fee_rate_basis_points);
         require!(providers_fee_amount > 0 || providers_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
 
         let protocol_fee_amount = Self::calculate_fee_amount(swap_amount, protocol_fee_rate_basis_points);
         require!(protocol_fee_amount > 0 || protocol_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        let referrer_fee_amount = Self::calculate_fee_amount(protocol_fee_amount, referrer_fee_share_basis_points);
+        let retained_protocol_fee_amount = protocol_fee_amount - referrer_fee_amount;
         
         let (new_base_liquidity, new_quote_liquidity, amount_to_withdraw, protocol_fees_to_redeem);
         
         if is_in_out {
-            protocol_fees_to_redeem = self.protocol_base_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
-            let base_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).unwrap().checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            protocol_fees_to_redeem = self.protocol_base_fees_to_redeem.checked_add(retained_protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            let base_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).ok_or(ErrorCode::SwapOverflowError)?.checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(base_amount_after_fees, true).ok_or(ErrorCode::AfterswapCalculationFailed)?;
             amount_to_withdraw = self.quote_liquidity.checked_sub(new_quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
         }
         else{
-            protocol_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
-            let quote_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).unwrap().checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            protocol_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(retained_protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            let quote_amount_after_fees = swap_amount.checked_sub(providers_fee_amount).ok_or(ErrorCode::SwapOverflowError)?.checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
             (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(quote_amount_after_fees, false).ok_or(ErrorCode::AfterswapCalculationFailed)?;
             amount_to_withdraw = self.base_liquidity.checked_sub(new_base_liquidity).ok_or(ErrorCode::SwapOverflowError)?;
         }
         
         // Check constant product change is in acceptable range
         self.validate_swap_constant_product(new_base_liquidity, new_quote_liquidity)?;
-        Self::check_swap_result(amount_to_withdraw, estimated_result, allowed_slippage)?;
         
         Ok(SwapPayload::new(
             new_base_liquidity,
             new_quote_liquidity,
             protocol_fees_to_redeem,
             providers_fee_amount,
+            referrer_fee_amount,
             amount_to_withdraw,
             is_in_out,
         ))
     }
 
+    /// Computes the swap payload for receiving an exact amount of tokens from the AMM.
+    ///
+    /// This function handles both **base-to-quote** and **quote-to-base** swaps.
+    /// It calculates the input required to receive `swap_result` tokens, grosses it up
+    /// by provider and protocol fees, and validates the constant product invariant.
+    ///
+    /// # Parameters
+    /// - `swap_result`: The exact amount of tokens to receive (either quote or base).
+    /// - `max_input`: Maximum amount of input tokens the swapper is willing to pay.
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
+    ///
+    /// # Returns
+    /// - `Ok(SwapExactOutPayload)`: Contains the required input amount, updated liquidity state and fees.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or max input exceeded).
+    #[inline(never)]
+    pub fn get_swap_exact_out_payload(&self, swap_result: u64, max_input: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_in_out: bool) -> Result<SwapExactOutPayload> {
+        self.check_state()?;
+        require!(swap_result > 0, ErrorCode::SwapResultIsZero);
+        require!(providers_fee_rate_basis_points + protocol_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+
+        let amount_after_fees = self.calculate_beforeswap_amount(swap_result, is_in_out).ok_or(ErrorCode::BeforeswapCalculationFailed)?;
+        let swap_amount = Self::calculate_amount_before_fees(amount_after_fees, providers_fee_rate_basis_points + protocol_fee_rate_basis_points).ok_or(ErrorCode::BeforeswapCalculationFailed)?;
+        require!(swap_amount <= max_input, ErrorCode::SwapMaxInputExceeded);
+
+        let providers_fee_amount = Self::calculate_fee_amount(swap_amount, providers_fee_rate_basis_points);
+        require!(providers_fee_amount > 0 || providers_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        let protocol_fee_amount = Self::calculate_fee_amount(swap_amount, protocol_fee_rate_basis_points);
+        require!(protocol_fee_amount > 0 || protocol_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        let amount_in_after_fees = swap_amount.checked_sub(providers_fee_amount).ok_or(ErrorCode::SwapOverflowError)?.checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+
+        let (new_base_liquidity, new_quote_liquidity, protocol_fees_to_redeem);
+
+        if is_in_out {
+            protocol_fees_to_redeem = self.protocol_base_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            new_base_liquidity = self.base_liquidity.checked_add(amount_in_after_fees).ok_or(ErrorCode::SwapOverflowError)?;
+            new_quote_liquidity = self.quote_liquidity.checked_sub(swap_result).ok_or(ErrorCode::SwapOverflowError)?;
+        }
+        else{
+            protocol_fees_to_redeem = self.protocol_quote_fees_to_redeem.checked_add(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+            new_quote_liquidity = self.quote_liquidity.checked_add(amount_in_after_fees).ok_or(ErrorCode::SwapOverflowError)?;
+            new_base_liquidity = self.base_liquidity.checked_sub(swap_result).ok_or(ErrorCode::SwapOverflowError)?;
+        }
+
+        // Check constant product change is in acceptable range
+        self.validate_swap_constant_product(new_base_liquidity, new_quote_liquidity)?;
+
+        Ok(SwapExactOutPayload::new(
+            swap_amount,
+            SwapPayload::new(
+                new_base_liquidity,
+                new_quote_liquidity,
+                protocol_fees_to_redeem,
+                providers_fee_amount,
+                0,
+                swap_result,
+                is_in_out,
+            )
+        ))
+    }
+
+    /// Computes the payload for providing liquidity with a single token.
+    ///
+    /// The optimal portion of `zap_amount` is swapped through the pool, and the rest together
+    /// with the swap result is provided to the pool at the afterswap ratio.
+    /// Amounts that don't fit the ratio are left as dust and returned to the provider.
+    ///
+    /// # Parameters
+    /// - `zap_amount`: The amount of tokens being deposited (either base or quote).
+    /// - `min_lp_tokens`: Minimal amount of LP tokens the provider is willing to receive.
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `is_base_in`: `true` if depositing **base** tokens, `false` if depositing **quote** tokens.
+    ///
+    /// # Returns
+    /// - `Ok(ZapInPayload)`: Contains the swap and provide payloads together with the dust amounts.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., swap or provide failure, or minted LP tokens below the minimum).
+    #[inline(never)]
+    pub fn get_zap_in_payload(&self, zap_amount: u64, min_lp_tokens: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_base_in: bool) -> Result<ZapInPayload> {
+        self.check_state()?;
+        require!(zap_amount > 0, ErrorCode::ZapAmountIsZero);
+
+        let reserve = if is_base_in { self.base_liquidity } else { self.quote_liquidity };
+        let swap_amount = Self::calculate_zap_swap_amount(reserve, zap_amount, providers_fee_rate_basis_points + protocol_fee_rate_basis_points)
+            .ok_or(ErrorCode::ZapSwapAmountCalculationFailed)?;
+        let swap_payload = self.get_routed_swap_payload(swap_amount, providers_fee_rate_basis_points, protocol_fee_rate_basis_points, is_base_in)?;
+
+        let mut afterswap_cp_amm = self.clone();
+        afterswap_cp_amm.swap(swap_payload.clone());
+
+        let (base_amount, quote_amount) = if is_base_in {
+            (zap_amount - swap_amount, swap_payload.amount_to_withdraw)
+        } else {
+            (swap_payload.amount_to_withdraw, zap_amount - swap_amount)
+        };
+        let (base_liquidity, quote_liquidity) = afterswap_cp_amm.calculate_ratio_fitting_liquidity(base_amount, quote_amount).ok_or(ErrorCode::ZapSwapAmountCalculationFailed)?;
+
+        let provide_payload = afterswap_cp_amm.get_provide_payload(base_liquidity, quote_liquidity, 0)?;
+        require!(provide_payload.lp_tokens_to_mint >= min_lp_tokens, ErrorCode::ZapLpTokensBelowMinimum);
+
+        Ok(ZapInPayload::new(
+            swap_amount,
+            base_liquidity,
+            quote_liquidity,
+            base_amount - base_liquidity,
+            quote_amount - quote_liquidity,
+            swap_payload,
+            provide_payload,
+        ))
+    }
+
+    /// Computes the payload for withdrawing liquidity into a single token.
+    ///
+    /// The liquidity is withdrawn first, then the withdrawn amount of the other token
+    /// is swapped through the pool into the requested one.
+    ///
+    /// # Parameters
+    /// - `lp_tokens`: The number of LP tokens to redeem.
+    /// - `min_result`: Minimal amount of tokens the withdrawer is willing to receive.
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `is_base_out`: `true` if receiving **base** tokens, `false` if receiving **quote** tokens.
+    ///
+    /// # Returns
+    /// - `Ok(ZapOutPayload)`: Contains the withdraw and swap payloads together with the total result.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., withdraw or swap failure, or result below the minimum).
+    #[inline(never)]
+    pub fn get_zap_out_payload(&self, lp_tokens: u64, min_result: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, is_base_out: bool) -> Result<ZapOutPayload> {
+        let withdraw_payload = self.get_withdraw_payload(lp_tokens, 0, 0)?;
+
+        let mut afterwithdraw_cp_amm = self.clone();
+        afterwithdraw_cp_amm.withdraw(withdraw_payload.clone());
+
+        let (withdrawn_amount, swap_amount) = if is_base_out {
+            (withdraw_payload.base_withdraw_amount, withdraw_payload.quote_withdraw_amount)
+        } else {
+            (withdraw_payload.quote_withdraw_amount, withdraw_payload.base_withdraw_amount)
+        };
+        let swap_payload = afterwithdraw_cp_amm.get_routed_swap_payload(swap_amount, providers_fee_rate_basis_points, protocol_fee_rate_basis_points, !is_base_out)?;
+
+        let zap_result = withdrawn_amount.checked_add(swap_payload.amount_to_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
+        require!(zap_result >= min_result, ErrorCode::ZapResultBelowMinimum);
+
+        Ok(ZapOutPayload::new(
+            zap_result,
+            withdraw_payload,
+            swap_payload,
+        ))
+    }
+
     /// Prepares the payload for collecting protocol fees from the AMM.
     ///
     /// This method checks if there are any protocol fees available for redemption and creates
@@ -441,6 +791,219 @@ impl CpAmm {
             0
         ))
     }
+
+    /// Prepares the payload for collecting a part of the protocol fees from the AMM.
+    ///
+    /// # Parameters
+    /// - `base_fees`: The amount of protocol fees in base tokens to collect.
+    /// - `quote_fees`: The amount of protocol fees in quote tokens to collect.
+    ///
+    /// # Returns
+    /// - `Ok(CollectFeesPayload)`: Contains the collected and the remaining protocol fees for both base and quote tokens.
+    /// - `Err(ErrorCode::ProvidersFeesIsZero)`: If both `base_fees` and `quote_fees` are zero.
+    /// - `Err(ErrorCode::CollectFeesAmountExceeded)`: If any of the amounts exceeds the protocol fees available for redemption.
+    #[inline(never)]
+    pub fn get_partial_collect_fees_payload(&self, base_fees: u64, quote_fees: u64) -> Result<CollectFeesPayload>{
+        require!(base_fees > 0 || quote_fees > 0, ErrorCode::ProvidersFeesIsZero);
+        require!(
+            base_fees <= self.protocol_base_fees_to_redeem && quote_fees <= self.protocol_quote_fees_to_redeem,
+            ErrorCode::CollectFeesAmountExceeded
+        );
+        Ok(CollectFeesPayload::new(
+            base_fees,
+            quote_fees,
+            self.protocol_base_fees_to_redeem - base_fees,
+            self.protocol_quote_fees_to_redeem - quote_fees
+        ))
+    }
+
+    /// Prepares the payload for minting a part of the accrued protocol LP tokens.
+    ///
+    /// # Parameters
+    /// - `lp_tokens`: The amount of protocol LP tokens to mint.
+    ///
+    /// # Returns
+    /// - `Ok(u64)`: The protocol LP tokens remaining after the mint.
+    /// - `Err(ErrorCode::ProtocolLpFeesIsZero)`: If `lp_tokens` is zero.
+    /// - `Err(ErrorCode::ProtocolLpFeesAmountExceeded)`: If `lp_tokens` exceeds the accrued protocol LP tokens.
+    #[inline(never)]
+    pub fn get_claim_lp_fees_payload(&self, lp_tokens: u64) -> Result<u64>{
+        require!(lp_tokens > 0, ErrorCode::ProtocolLpFeesIsZero);
+        require!(lp_tokens <= self.protocol_lp_tokens_to_redeem, ErrorCode::ProtocolLpFeesAmountExceeded);
+        Ok(self.protocol_lp_tokens_to_redeem - lp_tokens)
+    }
+
+    /// Prepares the payload for lending tokens from the pool vaults by a flash loan.
+    ///
+    /// The pool liquidity stays untouched until the loan is repaid, while other operations
+    /// on the pool are rejected by `check_state`.
+    ///
+    /// # Parameters
+    /// - `base_amount`: The amount of base tokens to lend.
+    /// - `quote_amount`: The amount of quote tokens to lend.
+    /// - `flash_loan_fee_rate_basis_points`: The flash loan fee rate in basis points.
+    ///
+    /// # Returns
+    /// - `Ok(FlashLoanPayload)`: Contains the lent amounts and the fees owed on top of them.
+    /// - `Err(ErrorCode)`: If the amounts are zero, exceed the pool liquidity or the fees are rounded to zero.
+    #[inline(never)]
+    pub fn get_flash_loan_payload(&self, base_amount: u64, quote_amount: u64, flash_loan_fee_rate_basis_points: u16) -> Result<FlashLoanPayload> {
+        self.check_state()?;
+        require!(base_amount > 0 || quote_amount > 0, ErrorCode::FlashLoanAmountIsZero);
+        require!(base_amount < self.base_liquidity && quote_amount < self.quote_liquidity, ErrorCode::FlashLoanAmountExceeded);
+        require!(flash_loan_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+
+        let base_fee_amount = Self::calculate_fee_amount(base_amount, flash_loan_fee_rate_basis_points);
+        require!(base_fee_amount > 0 || base_amount == 0 || flash_loan_fee_rate_basis_points == 0, ErrorCode::FlashLoanFeesAreZero);
+
+        let quote_fee_amount = Self::calculate_fee_amount(quote_amount, flash_loan_fee_rate_basis_points);
+        require!(quote_fee_amount > 0 || quote_amount == 0 || flash_loan_fee_rate_basis_points == 0, ErrorCode::FlashLoanFeesAreZero);
+
+        Ok(FlashLoanPayload::new(base_amount, quote_amount, base_fee_amount, quote_fee_amount))
+    }
+
+    /// Prepares the payload for repaying the flash loan in progress.
+    ///
+    /// The lent amounts must come back together with the fees. Anything returned above the lent
+    /// amounts is credited to the liquidity providers, after the constant product is validated
+    /// the same way as after a swap.
+    ///
+    /// # Parameters
+    /// - `base_amount_repaid`: The amount of base tokens received by the base vault.
+    /// - `quote_amount_repaid`: The amount of quote tokens received by the quote vault.
+    ///
+    /// # Returns
+    /// - `Ok(FlashRepayPayload)`: Contains the updated pool liquidity.
+    /// - `Err(ErrorCode)`: If there is no flash loan in progress or it is not repaid with fees.
+    #[inline(never)]
+    pub fn get_flash_repay_payload(&self, base_amount_repaid: u64, quote_amount_repaid: u64) -> Result<FlashRepayPayload> {
+        require!(self.is_flash_loan_active(), ErrorCode::FlashLoanNotActive);
+
+        let (base_amount_to_repay, quote_amount_to_repay) = self.flash_loan_amounts_to_repay()?;
+        require!(
+            base_amount_repaid >= base_amount_to_repay && quote_amount_repaid >= quote_amount_to_repay,
+            ErrorCode::FlashLoanNotRepaid
+        );
+
+        // Check constant product is restored before the fees are credited
+        let base_liquidity = self.base_liquidity.checked_add(base_amount_repaid - base_amount_to_repay).ok_or(ErrorCode::SwapOverflowError)?;
+        let quote_liquidity = self.quote_liquidity.checked_add(quote_amount_repaid - quote_amount_to_repay).ok_or(ErrorCode::SwapOverflowError)?;
+        self.validate_swap_constant_product(base_liquidity, quote_liquidity)?;
+
+        Ok(FlashRepayPayload::new(
+            base_liquidity.checked_add(self.flash_loan_base_fee_amount).ok_or(ErrorCode::SwapOverflowError)?,
+            quote_liquidity.checked_add(self.flash_loan_quote_fee_amount).ok_or(ErrorCode::SwapOverflowError)?,
+        ))
+    }
+
+    /// Prepares the payload for folding the tokens held by the vaults above the pool bookkeeping into the liquidity.
+    ///
+    /// # Parameters
+    /// - `base_vault_balance`: The current balance of the base vault.
+    /// - `quote_vault_balance`: The current balance of the quote vault.
+    ///
+    /// # Returns
+    /// - `Ok(SyncPayload)`: Contains the excess amounts and the updated pool liquidity.
+    /// - `Err(ErrorCode)`: If the pool state is invalid, the vaults hold no excess or the calculations overflow.
+    #[inline(never)]
+    pub fn get_sync_payload(&self, base_vault_balance: u64, quote_vault_balance: u64) -> Result<SyncPayload> {
+        let (base_excess, quote_excess) = self.calculate_vaults_excess(base_vault_balance, quote_vault_balance)?;
+
+        let base_liquidity = self.base_liquidity.checked_add(base_excess).ok_or(ErrorCode::SwapOverflowError)?;
+        let quote_liquidity = self.quote_liquidity.checked_add(quote_excess).ok_or(ErrorCode::SwapOverflowError)?;
+
+        Ok(SyncPayload::new(
+            base_excess,
+            quote_excess,
+            base_liquidity,
+            quote_liquidity,
+            Self::calculate_constant_product_sqrt(base_liquidity, quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?,
+            Self::calculate_base_quote_ratio_sqrt(base_liquidity, quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?,
+        ))
+    }
+
+    /// Prepares the payload for sending the tokens held by the vaults above the pool bookkeeping out of the pool.
+    ///
+    /// # Parameters
+    /// - `base_vault_balance`: The current balance of the base vault.
+    /// - `quote_vault_balance`: The current balance of the quote vault.
+    ///
+    /// # Returns
+    /// - `Ok(SkimPayload)`: Contains the excess amounts to send out.
+    /// - `Err(ErrorCode)`: If the pool state is invalid or the vaults hold no excess.
+    #[inline(never)]
+    pub fn get_skim_payload(&self, base_vault_balance: u64, quote_vault_balance: u64) -> Result<SkimPayload> {
+        let (base_excess, quote_excess) = self.calculate_vaults_excess(base_vault_balance, quote_vault_balance)?;
+        Ok(SkimPayload::new(base_excess, quote_excess))
+    }
+
+    /// Calculates the amounts the vaults hold above the liquidity and the protocol fees to redeem.
+    ///
+    /// # Parameters
+    /// - `base_vault_balance`: The current balance of the base vault.
+    /// - `quote_vault_balance`: The current balance of the quote vault.
+    ///
+    /// # Returns
+    /// - `Ok((u64, u64))`: The base and quote excess amounts.
+    /// - `Err(ErrorCode)`: If the pool state is invalid or both excess amounts are zero.
+    fn calculate_vaults_excess(&self, base_vault_balance: u64, quote_vault_balance: u64) -> Result<(u64, u64)> {
+        self.check_state()?;
+        let base_excess = base_vault_balance.saturating_sub(self.base_liquidity.saturating_add(self.protocol_base_fees_to_redeem));
+        let quote_excess = quote_vault_balance.saturating_sub(self.quote_liquidity.saturating_add(self.protocol_quote_fees_to_redeem));
+        require!(base_excess > 0 || quote_excess > 0, ErrorCode::VaultsExcessIsZero);
+        Ok((base_excess, quote_excess))
+    }
+}
+
+impl CpAmm {
+    /// Computes the pool's cumulative prices at the given moment.
+    ///
+    /// Extends the stored cumulative prices by the current base-per-quote and quote-per-base prices
+    /// weighted by the time elapsed since the last update. Accumulators wrap on overflow.
+    ///
+    /// # Parameters
+    /// - `timestamp`: Unix timestamp to compute the cumulative prices at.
+    ///
+    /// # Returns
+    /// - A `PriceObservation` with the cumulative prices at `timestamp`.
+    pub fn observe(&self, timestamp: i64) -> PriceObservation {
+        let elapsed = timestamp.saturating_sub(self.last_price_update_timestamp);
+        if elapsed <= 0 || self.base_liquidity == 0 || self.quote_liquidity == 0 {
+            return PriceObservation::new(
+                timestamp.max(self.last_price_update_timestamp),
+                self.cumulative_base_per_quote_price,
+                self.cumulative_quote_per_base_price
+            );
+        }
+        let base_liquidity = Q64_128::from_u64(self.base_liquidity);
+        let quote_liquidity = Q64_128::from_u64(self.quote_liquidity);
+        // Prices are bounded by u64::MAX, so the divisions cannot overflow
+        let base_per_quote_price = base_liquidity.checked_div(quote_liquidity).unwrap_or(Q64_128::MAX);
+        let quote_per_base_price = quote_liquidity.checked_div(base_liquidity).unwrap_or(Q64_128::MAX);
+
+        PriceObservation::new(
+            timestamp,
+            self.cumulative_base_per_quote_price.wrapping_add(base_per_quote_price.wrapping_mul_u64(elapsed as u64)),
+            self.cumulative_quote_per_base_price.wrapping_add(quote_per_base_price.wrapping_mul_u64(elapsed as u64)),
+        )
+    }
+
+    /// Computes the volatility accumulator decayed up to the given moment.
+    ///
+    /// The accumulator halves every `VOLATILITY_HALF_LIFE` seconds since its last update.
+    ///
+    /// # Parameters
+    /// - `timestamp`: Unix timestamp to compute the volatility accumulator at.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the recent price movement, measured in basis points.
+    pub fn volatility_accumulator(&self, timestamp: i64) -> u64 {
+        let half_lives = timestamp.saturating_sub(self.last_volatility_update_timestamp).max(0) / Self::VOLATILITY_HALF_LIFE;
+        if half_lives >= 64 {
+            return 0;
+        }
+        self.volatility_accumulator >> half_lives
+    }
 }
 
 impl CpAmm {
@@ -468,11 +1031,12 @@ impl CpAmm {
     /// - `Ok(())` if the initialization is successful.
     /// - `Err(ErrorCode)` if the AMM is already initialized.
     #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         base_mint: &InterfaceAccount<token_interface::Mint>,
         quote_mint: &InterfaceAccount<token_interface::Mint>,
-        lp_mint: &Account<Mint>,
+        lp_mint: &InterfaceAccount<token_interface::Mint>,
         amms_config: &Account<AmmsConfig>,
         creator: &AccountInfo,
         base_vault: &AccountInfo,
@@ -591,29 +1155,207 @@ impl CpAmm {
         self.base_quote_ratio_sqrt = Self::calculate_base_quote_ratio_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
     }
 
-    /// Updates the protocol fees for the AMM based on the provided payload.
+    /// Updates the AMM state after a single-sided liquidity provision.
     ///
-    /// This method sets the protocol fees available for redemption to the updated values
-    /// specified in the `CollectFeesPayload`.
+    /// This method applies the internal swap and then the liquidity provision of the zap.
     ///
     /// # Parameters
-    /// - `collect_fees_payload`: A `CollectFeesPayload` containing the updated protocol fees
-    ///   for both base and quote tokens.
+    /// - `zap_in_payload`: Contains the swap and provide payloads.
     ///
     /// # Returns
-    /// - None. This method directly modifies the internal state of the AMM.
+    /// - No return value. Modifies the internal state of the AMM.
     #[inline(never)]
-    pub(crate) fn collect_fees(&mut self, collect_fees_payload: CollectFeesPayload) {
-        self.protocol_base_fees_to_redeem = collect_fees_payload.new_protocol_base_fees_to_redeem;
-        self.protocol_quote_fees_to_redeem = collect_fees_payload.new_protocol_quote_fees_to_redeem;
+    pub(crate) fn zap_in(&mut self, zap_in_payload: ZapInPayload) {
+        self.swap(zap_in_payload.swap_payload);
+        self.provide(zap_in_payload.provide_payload);
     }
 
-}
-
-#[cfg(test)]
-mod cp_amm_tests {
-    use anchor_lang::Discriminator;
-    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    /// Updates the AMM state after a single-sided liquidity withdrawal.
+    ///
+    /// This method applies the liquidity withdrawal and then the internal swap of the zap.
+    ///
+    /// # Parameters
+    /// - `zap_out_payload`: Contains the withdraw and swap payloads.
+    ///
+    /// # Returns
+    /// - No return value. Modifies the internal state of the AMM.
+    #[inline(never)]
+    pub(crate) fn zap_out(&mut self, zap_out_payload: ZapOutPayload) {
+        self.withdraw(zap_out_payload.withdraw_payload);
+        self.swap(zap_out_payload.swap_payload);
+    }
+
+    /// Updates the protocol fees for the AMM based on the provided payload.
+    ///
+    /// This method sets the protocol fees available for redemption to the updated values
+    /// specified in the `CollectFeesPayload`.
+    ///
+    /// # Parameters
+    /// - `collect_fees_payload`: A `CollectFeesPayload` containing the updated protocol fees
+    ///   for both base and quote tokens.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    #[inline(never)]
+    pub(crate) fn collect_fees(&mut self, collect_fees_payload: CollectFeesPayload) {
+        self.protocol_base_fees_to_redeem = collect_fees_payload.new_protocol_base_fees_to_redeem;
+        self.protocol_quote_fees_to_redeem = collect_fees_payload.new_protocol_quote_fees_to_redeem;
+    }
+
+    /// Advances the cumulative prices up to the given moment.
+    ///
+    /// Must be called before the pool liquidity changes, so the elapsed period is
+    /// accounted with the price that was in effect during it.
+    ///
+    /// # Parameters
+    /// - `timestamp`: Current Unix timestamp.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn update_cumulative_prices(&mut self, timestamp: i64) {
+        let observation = self.observe(timestamp);
+        self.cumulative_base_per_quote_price = observation.cumulative_base_per_quote_price();
+        self.cumulative_quote_per_base_price = observation.cumulative_quote_per_base_price();
+        self.last_price_update_timestamp = observation.timestamp();
+    }
+
+    /// Adds the price movement of the last liquidity change to the decayed volatility accumulator.
+    ///
+    /// Must be called after the pool liquidity changes.
+    ///
+    /// # Parameters
+    /// - `previous_base_liquidity`: Base liquidity before the change.
+    /// - `previous_quote_liquidity`: Quote liquidity before the change.
+    /// - `timestamp`: Current Unix timestamp.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn update_volatility_accumulator(&mut self, previous_base_liquidity: u64, previous_quote_liquidity: u64, timestamp: i64) {
+        let price_change = Self::calculate_price_change_basis_points(
+            previous_base_liquidity,
+            previous_quote_liquidity,
+            self.base_liquidity,
+            self.quote_liquidity
+        ).unwrap_or(u64::MAX);
+        self.volatility_accumulator = self.volatility_accumulator(timestamp).saturating_add(price_change);
+        self.last_volatility_update_timestamp = timestamp.max(self.last_volatility_update_timestamp);
+    }
+
+    /// Accrues the protocol LP tokens for the constant product growth since the last checkpoint.
+    ///
+    /// The accrued tokens are added to `lp_tokens_supply` right away, so they dilute the providers
+    /// before any liquidity change, and are minted later by the fee authority.
+    /// Must be called before the pool liquidity changes by provision or withdrawal.
+    ///
+    /// # Parameters
+    /// - `protocol_fee_on_lp_enabled`: Whether the `AmmsConfig` fee-on-LP mode is enabled. Nothing is accrued otherwise.
+    /// - `providers_fee_rate_basis_points`: The providers fee rate, measured in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate, measured in basis points.
+    ///
+    /// # Errors
+    /// - Returns `ErrorCode::LpTokensCalculationFailed` if the calculation overflows.
+    pub(crate) fn accrue_protocol_lp_fees(&mut self, protocol_fee_on_lp_enabled: bool, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16) -> Result<()> {
+        if !protocol_fee_on_lp_enabled {
+            return Ok(());
+        }
+        let protocol_lp_fees = self.calculate_protocol_lp_fees(
+            self.last_constant_product_sqrt,
+            providers_fee_rate_basis_points,
+            protocol_fee_rate_basis_points
+        ).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        self.lp_tokens_supply = self.lp_tokens_supply.checked_add(protocol_lp_fees).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+        self.protocol_lp_tokens_to_redeem += protocol_lp_fees;
+        Ok(())
+    }
+
+    /// Stores the current constant product as the protocol LP fees checkpoint.
+    ///
+    /// Must be called after the pool liquidity changes by provision or withdrawal.
+    ///
+    /// # Parameters
+    /// - `protocol_fee_on_lp_enabled`: Whether the `AmmsConfig` fee-on-LP mode is enabled. The checkpoint is reset otherwise.
+    pub(crate) fn update_last_constant_product_sqrt(&mut self, protocol_fee_on_lp_enabled: bool) {
+        self.last_constant_product_sqrt = if protocol_fee_on_lp_enabled {
+            self.constant_product_sqrt
+        } else {
+            Q64_128::default()
+        };
+    }
+
+    /// Updates the accrued protocol LP tokens after a part of them is minted.
+    ///
+    /// # Parameters
+    /// - `protocol_lp_tokens_to_redeem`: The protocol LP tokens remaining after the mint.
+    pub(crate) fn claim_lp_fees(&mut self, protocol_lp_tokens_to_redeem: u64) {
+        self.protocol_lp_tokens_to_redeem = protocol_lp_tokens_to_redeem;
+    }
+
+    /// Updates the pause state of the AMM.
+    ///
+    /// Withdrawals are not affected by the pause state.
+    ///
+    /// # Parameters
+    /// - `swaps_paused`: Whether swaps are halted.
+    /// - `provides_paused`: Whether liquidity provision is halted.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn update_pause(&mut self, swaps_paused: bool, provides_paused: bool) {
+        self.swaps_paused = swaps_paused;
+        self.provides_paused = provides_paused;
+    }
+
+    /// Records the flash loan in progress.
+    ///
+    /// # Parameters
+    /// - `flash_loan_payload`: Contains the lent amounts and the fees owed on top of them.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn flash_loan(&mut self, flash_loan_payload: FlashLoanPayload) {
+        self.flash_loan_base_amount = flash_loan_payload.base_amount;
+        self.flash_loan_quote_amount = flash_loan_payload.quote_amount;
+        self.flash_loan_base_fee_amount = flash_loan_payload.base_fee_amount;
+        self.flash_loan_quote_fee_amount = flash_loan_payload.quote_fee_amount;
+    }
+
+    /// Closes the flash loan in progress and credits its fees to the pool liquidity.
+    ///
+    /// # Parameters
+    /// - `flash_repay_payload`: Contains the updated pool liquidity.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn flash_repay(&mut self, flash_repay_payload: FlashRepayPayload) {
+        self.base_liquidity = flash_repay_payload.base_liquidity;
+        self.quote_liquidity = flash_repay_payload.quote_liquidity;
+        self.flash_loan_base_amount = 0;
+        self.flash_loan_quote_amount = 0;
+        self.flash_loan_base_fee_amount = 0;
+        self.flash_loan_quote_fee_amount = 0;
+        self.constant_product_sqrt = Self::calculate_constant_product_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
+        self.base_quote_ratio_sqrt = Self::calculate_base_quote_ratio_sqrt(self.base_liquidity, self.quote_liquidity).unwrap();
+    }
+
+    /// Folds the vaults excess into the pool liquidity.
+    ///
+    /// # Parameters
+    /// - `sync_payload`: Contains the updated pool liquidity, constant product and ratio.
+    ///
+    /// # Returns
+    /// - None. This method directly modifies the internal state of the AMM.
+    pub(crate) fn sync(&mut self, sync_payload: SyncPayload) {
+        self.base_liquidity = sync_payload.base_liquidity;
+        self.quote_liquidity = sync_payload.quote_liquidity;
+        self.constant_product_sqrt = sync_payload.constant_product_sqrt;
+        self.base_quote_ratio_sqrt = sync_payload.base_quote_ratio_sqrt;
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
     use super::*;
 
     #[derive(Default)]
@@ -639,7 +1381,20 @@ mod cp_amm_tests {
         bump: [u8; 1],
         base_vault_bump: [u8; 1],
         quote_vault_bump: [u8; 1],
-        locked_lp_vault_bump: [u8; 1]
+        locked_lp_vault_bump: [u8; 1],
+        cumulative_base_per_quote_price: Q64_128,
+        cumulative_quote_per_base_price: Q64_128,
+        last_price_update_timestamp: i64,
+        volatility_accumulator: u64,
+        last_volatility_update_timestamp: i64,
+        swaps_paused: bool,
+        provides_paused: bool,
+        protocol_lp_tokens_to_redeem: u64,
+        last_constant_product_sqrt: Q64_128,
+        flash_loan_base_amount: u64,
+        flash_loan_quote_amount: u64,
+        flash_loan_base_fee_amount: u64,
+        flash_loan_quote_fee_amount: u64
     }
 
     impl CpAmmBuilder {
@@ -758,6 +1513,51 @@ mod cp_amm_tests {
             self
         }
 
+        fn cumulative_base_per_quote_price(mut self, value: Q64_128) -> Self {
+            self.cumulative_base_per_quote_price = value;
+            self
+        }
+
+        fn cumulative_quote_per_base_price(mut self, value: Q64_128) -> Self {
+            self.cumulative_quote_per_base_price = value;
+            self
+        }
+
+        fn last_price_update_timestamp(mut self, value: i64) -> Self {
+            self.last_price_update_timestamp = value;
+            self
+        }
+
+        fn volatility_accumulator(mut self, value: u64) -> Self {
+            self.volatility_accumulator = value;
+            self
+        }
+
+        fn last_volatility_update_timestamp(mut self, value: i64) -> Self {
+            self.last_volatility_update_timestamp = value;
+            self
+        }
+
+        fn swaps_paused(mut self, value: bool) -> Self {
+            self.swaps_paused = value;
+            self
+        }
+
+        fn provides_paused(mut self, value: bool) -> Self {
+            self.provides_paused = value;
+            self
+        }
+
+        fn protocol_lp_tokens_to_redeem(mut self, value: u64) -> Self {
+            self.protocol_lp_tokens_to_redeem = value;
+            self
+        }
+
+        fn last_constant_product_sqrt(mut self, value: Q64_128) -> Self {
+            self.last_constant_product_sqrt = value;
+            self
+        }
+
         fn build(self) -> CpAmm {
             CpAmm {
                 is_initialized: self.is_initialized,
@@ -781,7 +1581,20 @@ mod cp_amm_tests {
                 bump: self.bump,
                 base_vault_bump: self.base_vault_bump,
                 quote_vault_bump: self.quote_vault_bump,
-                locked_lp_vault_bump: self.locked_lp_vault_bump
+                locked_lp_vault_bump: self.locked_lp_vault_bump,
+                cumulative_base_per_quote_price: self.cumulative_base_per_quote_price,
+                cumulative_quote_per_base_price: self.cumulative_quote_per_base_price,
+                last_price_update_timestamp: self.last_price_update_timestamp,
+                volatility_accumulator: self.volatility_accumulator,
+                last_volatility_update_timestamp: self.last_volatility_update_timestamp,
+                swaps_paused: self.swaps_paused,
+                provides_paused: self.provides_paused,
+                protocol_lp_tokens_to_redeem: self.protocol_lp_tokens_to_redeem,
+                last_constant_product_sqrt: self.last_constant_product_sqrt,
+                flash_loan_base_amount: self.flash_loan_base_amount,
+                flash_loan_quote_amount: self.flash_loan_quote_amount,
+                flash_loan_base_fee_amount: self.flash_loan_base_fee_amount,
+                flash_loan_quote_fee_amount: self.flash_loan_quote_fee_amount
             }
         }
     }
@@ -808,8 +1621,21 @@ mod cp_amm_tests {
         let amms_config = Pubkey::new_unique();
         let creator = Pubkey::new_unique();
         let bump = [42u8];
+        let cumulative_base_per_quote_price = Q64_128::from_u64(3_000);
+        let cumulative_quote_per_base_price = Q64_128::from_u64(4_000);
+        let last_price_update_timestamp = 1_700_000_000i64;
+        let volatility_accumulator = 250u64;
+        let last_volatility_update_timestamp = 1_700_000_100i64;
+        let swaps_paused = true;
+        let provides_paused = false;
+        let protocol_lp_tokens_to_redeem = 700u64;
+        let last_constant_product_sqrt = Q64_128::from_u64(1_900_000);
+        let flash_loan_base_amount = 10_000u64;
+        let flash_loan_quote_amount = 20_000u64;
+        let flash_loan_base_fee_amount = 9u64;
+        let flash_loan_quote_fee_amount = 18u64;
         
-        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 358];
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 496];
         let mut offset = 0;
 
         data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
@@ -837,6 +1663,22 @@ mod cp_amm_tests {
         data[offset..offset + 32].copy_from_slice(locked_lp_vault.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(amms_config.as_ref()); offset += 32;
         data[offset..offset + 32].copy_from_slice(creator.as_ref()); offset += 32;
+        data[offset..offset + 16].copy_from_slice(&cumulative_base_per_quote_price.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&cumulative_base_per_quote_price.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 16].copy_from_slice(&cumulative_quote_per_base_price.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&cumulative_quote_per_base_price.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&last_price_update_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&volatility_accumulator.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&last_volatility_update_timestamp.to_le_bytes()); offset += 8;
+        data[offset] = swaps_paused as u8; offset += 1;
+        data[offset] = provides_paused as u8; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&protocol_lp_tokens_to_redeem.to_le_bytes()); offset += 8;
+        data[offset..offset + 16].copy_from_slice(&last_constant_product_sqrt.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&last_constant_product_sqrt.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&flash_loan_base_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&flash_loan_quote_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&flash_loan_base_fee_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&flash_loan_quote_fee_amount.to_le_bytes()); offset += 8;
         
         assert_eq!(ANCHOR_DISCRIMINATOR + CpAmm::INIT_SPACE, offset);
 
@@ -863,12 +1705,65 @@ mod cp_amm_tests {
         assert_eq!(deserialized_cp_amm.base_vault_bump, bump);
         assert_eq!(deserialized_cp_amm.quote_vault_bump, bump);
         assert_eq!(deserialized_cp_amm.locked_lp_vault_bump, bump);
+        assert_eq!(deserialized_cp_amm.cumulative_base_per_quote_price, cumulative_base_per_quote_price);
+        assert_eq!(deserialized_cp_amm.cumulative_quote_per_base_price, cumulative_quote_per_base_price);
+        assert_eq!(deserialized_cp_amm.last_price_update_timestamp, last_price_update_timestamp);
+        assert_eq!(deserialized_cp_amm.volatility_accumulator, volatility_accumulator);
+        assert_eq!(deserialized_cp_amm.last_volatility_update_timestamp, last_volatility_update_timestamp);
+        assert_eq!(deserialized_cp_amm.swaps_paused, swaps_paused);
+        assert_eq!(deserialized_cp_amm.provides_paused, provides_paused);
+        assert_eq!(deserialized_cp_amm.protocol_lp_tokens_to_redeem, protocol_lp_tokens_to_redeem);
+        assert_eq!(deserialized_cp_amm.last_constant_product_sqrt, last_constant_product_sqrt);
+        assert_eq!(deserialized_cp_amm.flash_loan_base_amount, flash_loan_base_amount);
+        assert_eq!(deserialized_cp_amm.flash_loan_quote_amount, flash_loan_quote_amount);
+        assert_eq!(deserialized_cp_amm.flash_loan_base_fee_amount, flash_loan_base_fee_amount);
+        assert_eq!(deserialized_cp_amm.flash_loan_quote_fee_amount, flash_loan_quote_fee_amount);
 
         let mut serialized_cp_amm = Vec::new();
         deserialized_cp_amm.try_serialize(&mut serialized_cp_amm).unwrap();
         assert_eq!(serialized_cp_amm.as_slice(), data.as_ref());
     }
     
+    /// Tests that a `CpAmm` stored with the initial layout loads once grown with zeroed appended fields.
+    #[test]
+    fn test_cp_amm_initial_layout_migration() {
+        let unique_pubkey = Pubkey::new_unique();
+        let amm = CpAmmBuilder::new()
+            .is_initialized(true)
+            .is_launched(true)
+            .initial_locked_liquidity(1000)
+            .constant_product_sqrt(Q64_128::from_u64(2000))
+            .base_quote_ratio_sqrt(Q64_128::from_u64(3000))
+            .base_liquidity(4000)
+            .quote_liquidity(5000)
+            .lp_tokens_supply(6000)
+            .base_mint(unique_pubkey)
+            .creator(unique_pubkey)
+            .bump([253])
+            .build();
+
+        let mut data = Vec::new();
+        amm.try_serialize(&mut data).unwrap();
+        data.truncate(CpAmm::INITIAL_LAYOUT_LEN);
+        assert!(CpAmm::try_deserialize(&mut data.as_ref()).is_err());
+
+        data.resize(ANCHOR_DISCRIMINATOR + CpAmm::INIT_SPACE, 0);
+        let migrated_amm = CpAmm::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert!(migrated_amm.is_launched());
+        assert_eq!(migrated_amm.constant_product_sqrt(), amm.constant_product_sqrt());
+        assert_eq!(migrated_amm.base_liquidity(), 4000);
+        assert_eq!(migrated_amm.quote_liquidity(), 5000);
+        assert_eq!(migrated_amm.lp_tokens_supply(), 6000);
+        assert_eq!(migrated_amm.creator(), &unique_pubkey);
+        assert_eq!(migrated_amm.bump(), 253);
+        assert_eq!(migrated_amm.last_price_update_timestamp(), 0);
+        assert!(!migrated_amm.swaps_paused());
+        assert!(!migrated_amm.provides_paused());
+        assert_eq!(migrated_amm.protocol_lp_tokens_to_redeem(), 0);
+        assert!(!migrated_amm.is_flash_loan_active());
+    }
+
     /// Tests getter methods of the `CpAmm` struct.
     #[test]
     fn test_cp_amm_getters() {
@@ -895,6 +1790,15 @@ mod cp_amm_tests {
             .base_vault_bump([245])
             .quote_vault_bump([212])
             .locked_lp_vault_bump([123])
+            .cumulative_base_per_quote_price(Q64_128::from_u64(7000))
+            .cumulative_quote_per_base_price(Q64_128::from_u64(8000))
+            .last_price_update_timestamp(9000)
+            .volatility_accumulator(10_000)
+            .last_volatility_update_timestamp(9000)
+            .swaps_paused(true)
+            .provides_paused(false)
+            .protocol_lp_tokens_to_redeem(11_000)
+            .last_constant_product_sqrt(Q64_128::from_u64(1900))
             .build();
 
         assert!(amm.is_initialized());
@@ -916,6 +1820,15 @@ mod cp_amm_tests {
         assert_eq!(amm.base_liquidity(), 4000);
         assert_eq!(amm.quote_liquidity(), 5000);
         assert_eq!(amm.lp_tokens_supply(), 6000);
+        assert_eq!(amm.cumulative_base_per_quote_price(), Q64_128::from_u64(7000));
+        assert_eq!(amm.cumulative_quote_per_base_price(), Q64_128::from_u64(8000));
+        assert_eq!(amm.last_price_update_timestamp(), 9000);
+        assert_eq!(amm.volatility_accumulator(9000), 10_000);
+        assert_eq!(amm.last_volatility_update_timestamp(), 9000);
+        assert!(amm.swaps_paused());
+        assert!(!amm.provides_paused());
+        assert_eq!(amm.protocol_lp_tokens_to_redeem(), 11_000);
+        assert_eq!(amm.last_constant_product_sqrt(), Q64_128::from_u64(1900));
     }
     
     mod state_change_tests {
@@ -997,8 +1910,8 @@ mod cp_amm_tests {
         fn test_swap() {
             let mut amm = CpAmmBuilder::new().build();
 
-            let swap_payload_in = SwapPayload::new(3980, 1000, 1, 20, 100, true);
-            let swap_payload_out = SwapPayload::new(1000, 985, 15, 15, 100, false);
+            let swap_payload_in = SwapPayload::new(3980, 1000, 1, 20, 0, 100, true);
+            let swap_payload_out = SwapPayload::new(1000, 985, 15, 15, 0, 100, false);
 
             amm.swap(swap_payload_in);
             assert_eq!(amm.base_liquidity, 4000);
@@ -1027,6 +1940,107 @@ mod cp_amm_tests {
             assert_eq!(amm.protocol_base_fees_to_redeem, 0);
             assert_eq!(amm.protocol_quote_fees_to_redeem, 0);
         }
+
+        /// Tests the `update_cumulative_prices` method of `CpAmm`.
+        #[test]
+        fn test_update_cumulative_prices() {
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(6_000_000)
+                .quote_liquidity(1_500_000)
+                .last_price_update_timestamp(1_000)
+                .build();
+
+            amm.update_cumulative_prices(1_100);
+            assert_eq!(amm.cumulative_base_per_quote_price, Q64_128::from_u64(400));
+            assert_eq!(amm.cumulative_quote_per_base_price, Q64_128::from_u64(25));
+            assert_eq!(amm.last_price_update_timestamp, 1_100);
+
+            // Timestamp going backwards does not change the accumulators
+            amm.update_cumulative_prices(1_050);
+            assert_eq!(amm.cumulative_base_per_quote_price, Q64_128::from_u64(400));
+            assert_eq!(amm.last_price_update_timestamp, 1_100);
+
+            let observation = amm.observe(1_200);
+            let twap = observation.calculate_twap(&PriceObservation::new(1_000, Q64_128::from_u64(0), Q64_128::from_u64(0))).unwrap();
+            assert_eq!(twap.base_per_quote_price, Q64_128::from_u64(4));
+            assert_eq!(twap.quote_per_base_price, Q64_128::from_u64(1) / Q64_128::from_u64(4));
+        }
+
+        /// Tests the `update_volatility_accumulator` method of `CpAmm`.
+        #[test]
+        fn test_update_volatility_accumulator() {
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(1_100_000)
+                .quote_liquidity(1_000_000)
+                .volatility_accumulator(400)
+                .last_volatility_update_timestamp(1_000)
+                .build();
+
+            assert_eq!(amm.volatility_accumulator(1_000 + CpAmm::VOLATILITY_HALF_LIFE - 1), 400);
+            assert_eq!(amm.volatility_accumulator(1_000 + CpAmm::VOLATILITY_HALF_LIFE), 200);
+            assert_eq!(amm.volatility_accumulator(1_000 + CpAmm::VOLATILITY_HALF_LIFE * 64), 0);
+            assert_eq!(amm.volatility_accumulator(0), 400);
+
+            // Quote per base price moved from 1 to 1 / 1.1, i.e. by 909 basis points
+            amm.update_volatility_accumulator(1_000_000, 1_000_000, 1_000 + CpAmm::VOLATILITY_HALF_LIFE);
+            assert_eq!(amm.volatility_accumulator, 200 + 909);
+            assert_eq!(amm.last_volatility_update_timestamp(), 1_000 + CpAmm::VOLATILITY_HALF_LIFE);
+        }
+
+        /// Tests the `update_pause` method of `CpAmm`.
+        #[test]
+        fn test_update_pause() {
+            let mut amm = CpAmmBuilder::new().is_launched(true).build();
+
+            amm.update_pause(true, false);
+            assert!(amm.swaps_paused());
+            assert!(!amm.provides_paused());
+
+            amm.update_pause(false, true);
+            assert!(!amm.swaps_paused());
+            assert!(amm.provides_paused());
+        }
+
+        /// Tests the protocol LP fees accrual, checkpoint and claim methods of `CpAmm`.
+        #[test]
+        fn test_accrue_protocol_lp_fees() {
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(4_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(2_000_000))
+                .lp_tokens_supply(2_000_000)
+                .build();
+
+            // Nothing accrues without a checkpoint
+            amm.accrue_protocol_lp_fees(true, 25, 5).unwrap();
+            assert_eq!(amm.lp_tokens_supply, 2_000_000);
+            assert_eq!(amm.protocol_lp_tokens_to_redeem, 0);
+
+            amm.update_last_constant_product_sqrt(true);
+            assert_eq!(amm.last_constant_product_sqrt, Q64_128::from_u64(2_000_000));
+
+            // The pool keeps 300_000 of the swap fees, so sqrt(k) grows by 10%
+            amm.constant_product_sqrt = Q64_128::from_u64(2_200_000);
+            amm.accrue_protocol_lp_fees(false, 25, 5).unwrap();
+            assert_eq!(amm.protocol_lp_tokens_to_redeem, 0);
+
+            amm.accrue_protocol_lp_fees(true, 25, 5).unwrap();
+            // 2_000_000 * 200_000 * 5 / (25 * 2_200_000 + 5 * 2_000_000)
+            assert_eq!(amm.protocol_lp_tokens_to_redeem, 30_769);
+            assert_eq!(amm.lp_tokens_supply, 2_030_769);
+
+            assert_eq!(amm.get_claim_lp_fees_payload(0).ok(), None);
+            assert_eq!(amm.get_claim_lp_fees_payload(30_770).ok(), None);
+            amm.claim_lp_fees(amm.get_claim_lp_fees_payload(30_000).unwrap());
+            assert_eq!(amm.protocol_lp_tokens_to_redeem, 769);
+            assert_eq!(amm.lp_tokens_supply, 2_030_769);
+
+            amm.update_last_constant_product_sqrt(false);
+            assert!(amm.last_constant_product_sqrt.is_zero());
+        }
     }
     
     mod operations_calculations_tests {
@@ -1096,7 +2110,7 @@ mod cp_amm_tests {
             let provided_base_liquidity = 2_000_000;
             let provided_quote_liquidity = 500_000;
 
-            let payload = amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity).unwrap();
+            let payload = amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity, 0).unwrap();
 
             let expected_base_liquidity = initial_base_liquidity + provided_base_liquidity;
             let expected_quote_liquidity = initial_quote_liquidity + provided_quote_liquidity;
@@ -1110,6 +2124,9 @@ mod cp_amm_tests {
             assert_eq!(payload.constant_product, expected_constant_product_sqrt);
             assert_eq!(payload.lp_tokens_to_mint, expected_lp_tokens_to_mint);
             assert_eq!(payload.lp_tokens_supply, expected_lp_tokens_supply);
+
+            assert!(amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity, expected_lp_tokens_to_mint).is_ok());
+            assert!(amm.get_provide_payload(provided_base_liquidity, provided_quote_liquidity, expected_lp_tokens_to_mint + 1).is_err());
         }
 
         /// Tests the `get_withdraw_payload` method of `CpAmm`.
@@ -1132,7 +2149,7 @@ mod cp_amm_tests {
 
             let lp_tokens_withdraw = 1000000;
 
-            let payload = amm.get_withdraw_payload(lp_tokens_withdraw).unwrap();
+            let payload = amm.get_withdraw_payload(lp_tokens_withdraw, 0, 0).unwrap();
 
             let expected_base_withdraw_amount = 2_000_000;
             let expected_quote_withdraw_amount = 500_000;
@@ -1146,6 +2163,10 @@ mod cp_amm_tests {
             assert_eq!(payload.base_withdraw_amount, expected_base_withdraw_amount);
             assert_eq!(payload.quote_withdraw_amount, expected_quote_withdraw_amount);
             assert_eq!(payload.lp_tokens_supply, expected_lp_tokens_supply);
+
+            assert!(amm.get_withdraw_payload(lp_tokens_withdraw, expected_base_withdraw_amount, expected_quote_withdraw_amount).is_ok());
+            assert!(amm.get_withdraw_payload(lp_tokens_withdraw, expected_base_withdraw_amount + 1, 0).is_err());
+            assert!(amm.get_withdraw_payload(lp_tokens_withdraw, 0, expected_quote_withdraw_amount + 1).is_err());
         }
 
         /// Tests the `get_swap_payload` method of `CpAmm` for in->out swap.
@@ -1175,7 +2196,7 @@ mod cp_amm_tests {
             let allowed_slippage = 0;
 
             
-            let payload = amm.get_swap_payload(base_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, true).unwrap();
+            let payload = amm.get_swap_payload(base_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, 0, true).unwrap();
         
             assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
             assert_eq!(payload.quote_liquidity, initial_quote_liquidity - estimated_result);
@@ -1211,7 +2232,7 @@ mod cp_amm_tests {
             let estimated_result = 1_500_000;
             let allowed_slippage = 0;
 
-            let payload = amm.get_swap_payload(quote_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, false).unwrap();
+            let payload = amm.get_swap_payload(quote_amount, estimated_result, allowed_slippage, providers_fee_basis_points, protocol_fee_basis_points, 0, false).unwrap();
 
             assert_eq!(payload.base_liquidity, initial_base_liquidity - estimated_result);
             assert_eq!(payload.quote_liquidity, initial_quote_liquidity + quote_amount - protocol_fee - providers_fee);
@@ -1221,85 +2242,490 @@ mod cp_amm_tests {
             assert!(!payload.is_in_out);
         }
 
-        /// Tests the `get_collect_fees_payload` method of `CpAmm`.
+        /// Tests the `get_swap_payload` method of `CpAmm` with a referrer share of the protocol fee.
         #[test]
-        fn test_get_collect_fees_payload() {
-            let protocol_base_fees_to_redeem = 1234353;
-            let protocol_quote_fees_to_redeem = 67574567;
+        fn test_get_swap_payload_with_referrer() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let referrer_fee_share_basis_points = 2_500;
+
             let amm = CpAmmBuilder::new()
-                .protocol_base_fees_to_redeem(protocol_base_fees_to_redeem)
-                .protocol_quote_fees_to_redeem(protocol_quote_fees_to_redeem)
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
                 .build();
 
-            let payload = amm.get_collect_fees_payload().unwrap();
+            let base_amount: u64 = 3_061_224;
+            let protocol_fee = base_amount * protocol_fee_basis_points as u64 / 10000;
+            let referrer_fee = protocol_fee * referrer_fee_share_basis_points as u64 / 10000;
+            let estimated_result = 500_000;
 
-            assert_eq!(payload.protocol_base_fees_to_redeem, protocol_base_fees_to_redeem);
-            assert_eq!(payload.protocol_quote_fees_to_redeem, protocol_quote_fees_to_redeem);
-            assert_eq!(payload.new_protocol_base_fees_to_redeem, 0);
-            assert_eq!(payload.new_protocol_quote_fees_to_redeem, 0);
-        }
-    }
-}
+            let payload = amm.get_swap_payload(base_amount, estimated_result, 0, providers_fee_basis_points, protocol_fee_basis_points, referrer_fee_share_basis_points, true).unwrap();
+            let payload_without_referrer = amm.get_swap_payload(base_amount, estimated_result, 0, providers_fee_basis_points, protocol_fee_basis_points, 0, true).unwrap();
 
-/// Represents the data required to launch the AMM.
-///
-/// This struct contains the initial parameters for the pool, including
-/// the locked liquidity, initial ratios, and the supply of LP tokens.
-///
-/// # Fields
-/// - `initial_locked_liquidity`: The amount of liquidity that will be locked in the pool upon launch.
-/// - `constant_product_sqrt`: The square root of the constant product invariant.
-/// - `base_quote_ratio_sqrt`: The square root of the ratio between base and quote liquidity.
-/// - `base_liquidity`: The initial base token liquidity in the pool.
-/// - `quote_liquidity`: The initial quote token liquidity in the pool.
-/// - `lp_tokens_supply`: The total supply of LP tokens minted upon launch.
-#[derive(Debug)]
-pub struct LaunchPayload {
-    initial_locked_liquidity: u64,
-    constant_product_sqrt: Q64_128,
-    base_quote_ratio_sqrt: Q64_128,
-    base_liquidity: u64,
-    quote_liquidity: u64,
-    lp_tokens_supply: u64,
-}
-impl LaunchPayload {
-    /// Creates a new `LaunchPayload` instance with the specified parameters.
-    ///
-    /// # Parameters
-    /// - `initial_locked_liquidity`: The locked liquidity amount.
-    /// - `constant_product_sqrt`: The square root of the constant product.
-    /// - `base_quote_ratio_sqrt`: The square root of the base-to-quote liquidity ratio.
-    /// - `base_liquidity`: The base token liquidity.
-    /// - `quote_liquidity`: The quote token liquidity.
-    /// - `lp_tokens_supply`: The total LP token supply.
-    pub fn new(
-        initial_locked_liquidity: u64,
-        constant_product_sqrt: Q64_128,
-        base_quote_ratio_sqrt: Q64_128,
-        base_liquidity: u64,
-        quote_liquidity: u64,
-        lp_tokens_supply: u64,
-    ) -> Self {
-        Self {
-            initial_locked_liquidity,
-            constant_product_sqrt,
-            base_quote_ratio_sqrt,
-            base_liquidity,
-            quote_liquidity,
-            lp_tokens_supply,
-        }
-    }
+            // The referrer fee only reduces the protocol fees, the swap result and liquidity stay the same
+            assert_eq!(payload.referrer_fee_amount(), referrer_fee);
+            assert_eq!(payload.protocol_fees_to_redeem, protocol_fee - referrer_fee);
+            assert_eq!(payload.base_liquidity, payload_without_referrer.base_liquidity);
+            assert_eq!(payload.quote_liquidity, payload_without_referrer.quote_liquidity);
+            assert_eq!(payload.providers_fee_amount, payload_without_referrer.providers_fee_amount);
+            assert_eq!(payload.amount_to_withdraw, estimated_result);
+            assert_eq!(payload_without_referrer.referrer_fee_amount(), 0);
 
-    /// Returns the amount of initially locked liquidity.
-    pub fn initial_locked_liquidity(&self) -> u64{
-        self.initial_locked_liquidity
-    }
+            assert!(amm.get_swap_payload(base_amount, estimated_result, 0, providers_fee_basis_points, protocol_fee_basis_points, AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS + 1, true).is_err());
+        }
 
-    /// Returns the total liquidity available for use after subtracting the locked liquidity.
-    pub fn launch_liquidity(&self) -> u64{
-        self.lp_tokens_supply.checked_sub(self.initial_locked_liquidity).unwrap()
-    }
-}
+        /// Tests the `get_routed_swap_payload` method of `CpAmm` for in->out hop.
+        #[test]
+        fn test_get_routed_swap_payload() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let initial_constant_product_sqrt = Q64_128::from_u64(3_000_000);
+            let initial_base_quote_ratio_sqrt = Q64_128::from_u64(2);
+            let initial_lp_tokens_supply = 3_000_000;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(initial_constant_product_sqrt)
+                .base_quote_ratio_sqrt(initial_base_quote_ratio_sqrt)
+                .lp_tokens_supply(initial_lp_tokens_supply)
+                .build();
+
+            let base_amount: u64 = 3_061_224;
+            let protocol_fee = base_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = base_amount * providers_fee_basis_points as u64 / 10000;
+            let expected_result = 500_000;
+
+            let payload = amm.get_routed_swap_payload(base_amount, providers_fee_basis_points, protocol_fee_basis_points, true).unwrap();
+
+            assert_eq!(payload.base_liquidity, initial_base_liquidity + base_amount - protocol_fee - providers_fee);
+            assert_eq!(payload.quote_liquidity, initial_quote_liquidity - expected_result);
+            assert_eq!(payload.protocol_fees_to_redeem, protocol_fee);
+            assert_eq!(payload.providers_fee_amount, providers_fee);
+            assert_eq!(payload.amount_to_withdraw, expected_result);
+            assert!(payload.is_in_out);
+
+            assert!(amm.get_routed_swap_payload(0, providers_fee_basis_points, protocol_fee_basis_points, true).is_err());
+        }
+
+        /// Tests the `get_swap_exact_out_payload` method of `CpAmm` for in->out swap.
+        #[test]
+        fn test_get_base_to_quote_swap_exact_out_payload() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let initial_constant_product_sqrt = Q64_128::from_u64(3_000_000);
+            let initial_base_quote_ratio_sqrt = Q64_128::from_u64(2);
+            let initial_lp_tokens_supply = 3_000_000;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(initial_constant_product_sqrt)
+                .base_quote_ratio_sqrt(initial_base_quote_ratio_sqrt)
+                .lp_tokens_supply(initial_lp_tokens_supply)
+                .build();
+
+            let swap_result = 500_000;
+            let expected_swap_amount: u64 = 3_061_225;
+            let protocol_fee = expected_swap_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = expected_swap_amount * providers_fee_basis_points as u64 / 10000;
+
+            let payload = amm.get_swap_exact_out_payload(swap_result, expected_swap_amount, providers_fee_basis_points, protocol_fee_basis_points, true).unwrap();
+
+            assert_eq!(payload.swap_amount(), expected_swap_amount);
+            assert_eq!(payload.amount_to_withdraw(), swap_result);
+
+            let swap_payload = payload.into_swap_payload();
+            assert_eq!(swap_payload.base_liquidity, initial_base_liquidity + expected_swap_amount - protocol_fee - providers_fee);
+            assert_eq!(swap_payload.quote_liquidity, initial_quote_liquidity - swap_result);
+            assert_eq!(swap_payload.protocol_fees_to_redeem, protocol_fee);
+            assert_eq!(swap_payload.providers_fee_amount, providers_fee);
+            assert!(swap_payload.is_in_out);
+
+            assert!(amm.get_swap_exact_out_payload(swap_result, expected_swap_amount - 1, providers_fee_basis_points, protocol_fee_basis_points, true).is_err());
+            assert!(amm.get_swap_exact_out_payload(initial_quote_liquidity, u64::MAX, providers_fee_basis_points, protocol_fee_basis_points, true).is_err());
+        }
+
+        /// Tests the `get_swap_exact_out_payload` method of `CpAmm` for out->in swap.
+        #[test]
+        fn test_get_quote_to_base_swap_exact_out_payload() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+            let initial_constant_product_sqrt = Q64_128::from_u64(3_000_000);
+            let initial_base_quote_ratio_sqrt = Q64_128::from_u64(2);
+            let initial_lp_tokens_supply = 3_000_000;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(initial_constant_product_sqrt)
+                .base_quote_ratio_sqrt(initial_base_quote_ratio_sqrt)
+                .lp_tokens_supply(initial_lp_tokens_supply)
+                .build();
+
+            let swap_result = 1_500_000;
+            let expected_swap_amount: u64 = 510_205;
+            let protocol_fee = expected_swap_amount * protocol_fee_basis_points as u64 / 10000;
+            let providers_fee = expected_swap_amount * providers_fee_basis_points as u64 / 10000;
+
+            let payload = amm.get_swap_exact_out_payload(swap_result, u64::MAX, providers_fee_basis_points, protocol_fee_basis_points, false).unwrap();
+
+            assert_eq!(payload.swap_amount(), expected_swap_amount);
+            assert_eq!(payload.amount_to_withdraw(), swap_result);
+
+            let swap_payload = payload.into_swap_payload();
+            assert_eq!(swap_payload.base_liquidity, initial_base_liquidity - swap_result);
+            assert_eq!(swap_payload.quote_liquidity, initial_quote_liquidity + expected_swap_amount - protocol_fee - providers_fee);
+            assert_eq!(swap_payload.protocol_fees_to_redeem, protocol_fee);
+            assert_eq!(swap_payload.providers_fee_amount, providers_fee);
+            assert!(!swap_payload.is_in_out);
+        }
+
+        /// Tests that an exact-output swap grossed up by the Token 2022 transfer fee of the output mint
+        /// delivers at least the requested amount.
+        #[test]
+        fn test_get_swap_exact_out_payload_with_output_transfer_fee() {
+            use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(6_000_000)
+                .quote_liquidity(1_500_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .build();
+            let transfer_fee = TransferFee {
+                epoch: 0.into(),
+                maximum_fee: u64::MAX.into(),
+                transfer_fee_basis_points: 250.into(),
+            };
+
+            let requested_result = 500_000;
+            let swap_result = requested_result + transfer_fee.calculate_inverse_fee(requested_result).unwrap();
+            let payload = amm.get_swap_exact_out_payload(swap_result, u64::MAX, 100, 100, true).unwrap();
+
+            assert_eq!(payload.amount_to_withdraw(), swap_result);
+            assert!(swap_result - transfer_fee.calculate_fee(swap_result).unwrap() >= requested_result);
+
+            let payload_without_fee = amm.get_swap_exact_out_payload(requested_result, u64::MAX, 100, 100, true).unwrap();
+            assert!(payload.swap_amount() > payload_without_fee.swap_amount());
+        }
+
+        /// Tests the `get_collect_fees_payload` method of `CpAmm`.
+        #[test]
+        fn test_get_collect_fees_payload() {
+            let protocol_base_fees_to_redeem = 1234353;
+            let protocol_quote_fees_to_redeem = 67574567;
+            let amm = CpAmmBuilder::new()
+                .protocol_base_fees_to_redeem(protocol_base_fees_to_redeem)
+                .protocol_quote_fees_to_redeem(protocol_quote_fees_to_redeem)
+                .build();
+
+            let payload = amm.get_collect_fees_payload().unwrap();
+
+            assert_eq!(payload.protocol_base_fees_to_redeem, protocol_base_fees_to_redeem);
+            assert_eq!(payload.protocol_quote_fees_to_redeem, protocol_quote_fees_to_redeem);
+            assert_eq!(payload.new_protocol_base_fees_to_redeem, 0);
+            assert_eq!(payload.new_protocol_quote_fees_to_redeem, 0);
+        }
+
+        /// Tests the `get_partial_collect_fees_payload` method of `CpAmm`.
+        #[test]
+        fn test_get_partial_collect_fees_payload() {
+            let mut amm = CpAmmBuilder::new()
+                .protocol_base_fees_to_redeem(1_000)
+                .protocol_quote_fees_to_redeem(2_000)
+                .build();
+
+            assert!(amm.get_partial_collect_fees_payload(0, 0).is_err());
+            assert!(amm.get_partial_collect_fees_payload(1_001, 0).is_err());
+            assert!(amm.get_partial_collect_fees_payload(0, 2_001).is_err());
+
+            let payload = amm.get_partial_collect_fees_payload(400, 0).unwrap();
+            assert_eq!(payload.protocol_base_fees_to_redeem, 400);
+            assert_eq!(payload.protocol_quote_fees_to_redeem, 0);
+            assert_eq!(payload.new_protocol_base_fees_to_redeem, 600);
+            assert_eq!(payload.new_protocol_quote_fees_to_redeem, 2_000);
+
+            amm.collect_fees(payload);
+            assert_eq!(amm.protocol_base_fees_to_redeem, 600);
+            assert_eq!(amm.protocol_quote_fees_to_redeem, 2_000);
+
+            let payload = amm.get_partial_collect_fees_payload(600, 2_000).unwrap();
+            amm.collect_fees(payload);
+            assert_eq!(amm.protocol_base_fees_to_redeem, 0);
+            assert_eq!(amm.protocol_quote_fees_to_redeem, 0);
+        }
+
+        /// Tests the `get_flash_loan_payload`, `flash_loan_amounts_to_repay` and `get_flash_repay_payload` methods of `CpAmm`.
+        #[test]
+        fn test_flash_loan() {
+            let flash_loan_fee_basis_points = 9;
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(6_000_000_000)
+                .quote_liquidity(1_500_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000_000)
+                .build();
+
+            assert!(amm.get_flash_repay_payload(0, 0).is_err());
+            assert!(amm.get_flash_loan_payload(0, 0, flash_loan_fee_basis_points).is_err());
+            assert!(amm.get_flash_loan_payload(6_000_000_000, 0, flash_loan_fee_basis_points).is_err());
+            // Fee is rounded to zero
+            assert!(amm.get_flash_loan_payload(1_000, 0, flash_loan_fee_basis_points).is_err());
+
+            let payload = amm.get_flash_loan_payload(1_000_000, 0, flash_loan_fee_basis_points).unwrap();
+            assert_eq!(payload.base_amount(), 1_000_000);
+            assert_eq!(payload.quote_amount(), 0);
+            assert_eq!(payload.base_fee_amount(), 900);
+            assert_eq!(payload.quote_fee_amount(), 0);
+
+            amm.flash_loan(payload);
+            assert!(amm.is_flash_loan_active());
+            assert_eq!(amm.flash_loan_amounts_to_repay().unwrap(), (1_000_900, 0));
+            // Other operations are rejected until the loan is repaid
+            assert!(amm.get_flash_loan_payload(1_000_000, 0, flash_loan_fee_basis_points).is_err());
+            assert!(amm.get_swap_payload(1_000_000, 1, u64::MAX, 20, 10, 0, true).is_err());
+
+            assert!(amm.get_flash_repay_payload(1_000_899, 0).is_err());
+            // Returning much more than owed breaks the constant product
+            assert!(amm.get_flash_repay_payload(2_000_900, 0).is_err());
+
+            let payload = amm.get_flash_repay_payload(1_000_900, 0).unwrap();
+            amm.flash_repay(payload);
+            assert!(!amm.is_flash_loan_active());
+            assert_eq!(amm.flash_loan_amounts_to_repay().unwrap(), (0, 0));
+            assert_eq!(amm.base_liquidity, 6_000_000_900);
+            assert_eq!(amm.quote_liquidity, 1_500_000_000);
+            assert_eq!(amm.constant_product_sqrt, CpAmm::calculate_constant_product_sqrt(6_000_000_900, 1_500_000_000).unwrap());
+            assert!(amm.get_swap_payload(1_000_000, 1, u64::MAX, 20, 10, 0, true).is_ok());
+        }
+
+        /// Tests the `get_sync_payload` and `get_skim_payload` methods of `CpAmm`.
+        #[test]
+        fn test_reconcile_vaults_excess() {
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(6_000_000_000)
+                .quote_liquidity(1_500_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000_000)
+                .protocol_base_fees_to_redeem(1_000)
+                .protocol_quote_fees_to_redeem(500)
+                .build();
+
+            // Vaults holding exactly the bookkept amounts, or less, have nothing to reconcile
+            assert!(amm.get_sync_payload(6_000_001_000, 1_500_000_500).is_err());
+            assert!(amm.get_skim_payload(6_000_000_000, 1_500_000_000).is_err());
+
+            let payload = amm.get_skim_payload(6_000_001_000, 1_500_000_700).unwrap();
+            assert_eq!(payload.base_excess(), 0);
+            assert_eq!(payload.quote_excess(), 200);
+
+            let payload = amm.get_sync_payload(6_000_401_000, 1_500_000_600).unwrap();
+            assert_eq!(payload.base_excess(), 400_000);
+            assert_eq!(payload.quote_excess(), 100);
+            amm.sync(payload);
+            assert_eq!(amm.base_liquidity, 6_000_400_000);
+            assert_eq!(amm.quote_liquidity, 1_500_000_100);
+            assert_eq!(amm.protocol_base_fees_to_redeem, 1_000);
+            assert_eq!(amm.constant_product_sqrt, CpAmm::calculate_constant_product_sqrt(6_000_400_000, 1_500_000_100).unwrap());
+            assert_eq!(amm.base_quote_ratio_sqrt, CpAmm::calculate_base_quote_ratio_sqrt(6_000_400_000, 1_500_000_100).unwrap());
+            assert!(amm.get_sync_payload(6_000_401_000, 1_500_000_600).is_err());
+
+            // Lent tokens are missing from the vaults until the loan is repaid
+            let payload = amm.get_flash_loan_payload(1_000_000, 0, 9).unwrap();
+            amm.flash_loan(payload);
+            assert!(amm.get_sync_payload(u64::MAX, u64::MAX).is_err());
+            assert!(amm.get_skim_payload(u64::MAX, u64::MAX).is_err());
+        }
+
+        /// Tests that a sync of a donation moves the volatility accumulator like a swap.
+        #[test]
+        fn test_sync_volatility_accumulator() {
+            let mut amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(1_000_000)
+                .quote_liquidity(1_000_000)
+                .constant_product_sqrt(Q64_128::from_u64(1_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(1))
+                .lp_tokens_supply(1_000_000)
+                .last_volatility_update_timestamp(1_000)
+                .build();
+
+            let payload = amm.get_sync_payload(1_100_000, 1_000_000).unwrap();
+            let (previous_base_liquidity, previous_quote_liquidity) = (amm.base_liquidity(), amm.quote_liquidity());
+            amm.sync(payload);
+            amm.update_volatility_accumulator(previous_base_liquidity, previous_quote_liquidity, 1_000);
+
+            // Quote per base price moved from 1 to 1 / 1.1, i.e. by 909 basis points
+            assert_eq!(amm.volatility_accumulator(1_000), 909);
+        }
+
+        /// Tests the `get_zap_in_payload` method of `CpAmm` for base and quote deposits.
+        #[test]
+        fn test_get_zap_in_payload() {
+            let initial_base_liquidity = 6_000_000_000;
+            let initial_quote_liquidity = 1_500_000_000;
+            let protocol_fee_basis_points = 10;
+            let providers_fee_basis_points = 20;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000_000)
+                .build();
+
+            for is_base_in in [true, false] {
+                let zap_amount = 100_000_000;
+                let payload = amm.get_zap_in_payload(zap_amount, 0, providers_fee_basis_points, protocol_fee_basis_points, is_base_in).unwrap();
+
+                let (provided_amount, dust) = if is_base_in {
+                    (payload.provided_base_liquidity, payload.base_dust)
+                } else {
+                    (payload.provided_quote_liquidity, payload.quote_dust)
+                };
+                assert_eq!(payload.swap_amount + provided_amount + dust, zap_amount);
+                assert_eq!(payload.provided_base_liquidity + payload.base_dust, if is_base_in { zap_amount - payload.swap_amount } else { payload.swap_payload.amount_to_withdraw });
+                assert_eq!(payload.provided_quote_liquidity + payload.quote_dust, if is_base_in { payload.swap_payload.amount_to_withdraw } else { zap_amount - payload.swap_amount });
+                // Dust is left by rounding and by protocol fees leaving the pool
+                assert!(payload.base_dust + payload.quote_dust <= zap_amount / 10000, "Unexpected dust: {} base, {} quote", payload.base_dust, payload.quote_dust);
+
+                let lp_tokens_to_mint = payload.lp_tokens_to_mint();
+                assert!(lp_tokens_to_mint > 0);
+
+                let mut zapped_amm = amm.clone();
+                zapped_amm.zap_in(payload);
+                assert_eq!(zapped_amm.lp_tokens_supply, 3_000_000_000 + lp_tokens_to_mint);
+
+                assert!(amm.get_zap_in_payload(zap_amount, lp_tokens_to_mint + 1, providers_fee_basis_points, protocol_fee_basis_points, is_base_in).is_err());
+            }
+
+            assert!(amm.get_zap_in_payload(0, 0, providers_fee_basis_points, protocol_fee_basis_points, true).is_err());
+        }
+
+        /// Tests the `get_zap_out_payload` method of `CpAmm` for base and quote results.
+        #[test]
+        fn test_get_zap_out_payload() {
+            let initial_base_liquidity = 6_000_000;
+            let initial_quote_liquidity = 1_500_000;
+            let protocol_fee_basis_points = 100;
+            let providers_fee_basis_points = 100;
+
+            let amm = CpAmmBuilder::new()
+                .is_launched(true)
+                .base_liquidity(initial_base_liquidity)
+                .quote_liquidity(initial_quote_liquidity)
+                .constant_product_sqrt(Q64_128::from_u64(3_000_000))
+                .base_quote_ratio_sqrt(Q64_128::from_u64(2))
+                .lp_tokens_supply(3_000_000)
+                .build();
+
+            let lp_tokens_withdraw = 1_000_000;
+            let payload = amm.get_zap_out_payload(lp_tokens_withdraw, 0, providers_fee_basis_points, protocol_fee_basis_points, true).unwrap();
+
+            assert_eq!(payload.base_withdraw_amount(), 2_000_000);
+            assert_eq!(payload.quote_withdraw_amount(), 500_000);
+            // 500_000 quote minus 2% fees swapped in the 4_000_000 / 1_000_000 pool
+            let expected_swap_result = 4_000_000 - (4_000_000u128 * 1_000_000 / 1_490_000) as u64;
+            assert!(payload.zap_result().abs_diff(2_000_000 + expected_swap_result) <= 1);
+            assert!(!payload.swap_payload.is_in_out);
+
+            let zap_result = payload.zap_result();
+            let mut zapped_amm = amm.clone();
+            zapped_amm.zap_out(payload);
+            assert_eq!(zapped_amm.lp_tokens_supply, 3_000_000 - lp_tokens_withdraw);
+            // Protocol fee leaves the pool liquidity, providers fee stays
+            assert_eq!(zapped_amm.quote_liquidity, 1_500_000 - 5_000);
+            assert_eq!(zapped_amm.base_liquidity, initial_base_liquidity - zap_result);
+
+            assert!(amm.get_zap_out_payload(lp_tokens_withdraw, zap_result + 1, providers_fee_basis_points, protocol_fee_basis_points, true).is_err());
+            assert!(amm.get_zap_out_payload(lp_tokens_withdraw, 0, providers_fee_basis_points, protocol_fee_basis_points, false).is_ok());
+        }
+    }
+}
+
+/// Represents the data required to launch the AMM.
+///
+/// This struct contains the initial parameters for the pool, including
+/// the locked liquidity, initial ratios, and the supply of LP tokens.
+///
+/// # Fields
+/// - `initial_locked_liquidity`: The amount of liquidity that will be locked in the pool upon launch.
+/// - `constant_product_sqrt`: The square root of the constant product invariant.
+/// - `base_quote_ratio_sqrt`: The square root of the ratio between base and quote liquidity.
+/// - `base_liquidity`: The initial base token liquidity in the pool.
+/// - `quote_liquidity`: The initial quote token liquidity in the pool.
+/// - `lp_tokens_supply`: The total supply of LP tokens minted upon launch.
+#[derive(Debug)]
+pub struct LaunchPayload {
+    initial_locked_liquidity: u64,
+    constant_product_sqrt: Q64_128,
+    base_quote_ratio_sqrt: Q64_128,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    lp_tokens_supply: u64,
+}
+impl LaunchPayload {
+    /// Creates a new `LaunchPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `initial_locked_liquidity`: The locked liquidity amount.
+    /// - `constant_product_sqrt`: The square root of the constant product.
+    /// - `base_quote_ratio_sqrt`: The square root of the base-to-quote liquidity ratio.
+    /// - `base_liquidity`: The base token liquidity.
+    /// - `quote_liquidity`: The quote token liquidity.
+    /// - `lp_tokens_supply`: The total LP token supply.
+    pub fn new(
+        initial_locked_liquidity: u64,
+        constant_product_sqrt: Q64_128,
+        base_quote_ratio_sqrt: Q64_128,
+        base_liquidity: u64,
+        quote_liquidity: u64,
+        lp_tokens_supply: u64,
+    ) -> Self {
+        Self {
+            initial_locked_liquidity,
+            constant_product_sqrt,
+            base_quote_ratio_sqrt,
+            base_liquidity,
+            quote_liquidity,
+            lp_tokens_supply,
+        }
+    }
+
+    /// Returns the amount of initially locked liquidity.
+    pub fn initial_locked_liquidity(&self) -> u64{
+        self.initial_locked_liquidity
+    }
+
+    /// Returns the total liquidity available for use after subtracting the locked liquidity.
+    pub fn launch_liquidity(&self) -> u64{
+        self.lp_tokens_supply.checked_sub(self.initial_locked_liquidity).unwrap()
+    }
+}
 
 /// Represents the data required to provide liquidity to the AMM.
 ///
@@ -1368,7 +2794,7 @@ impl ProvidePayload {
 /// - `lp_tokens_supply`: The updated total supply of LP tokens.
 /// - `base_withdraw_amount`: The amount of base tokens withdrawn.
 /// - `quote_withdraw_amount`: The amount of quote tokens withdrawn.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct WithdrawPayload{
     base_quote_ratio_sqrt: Q64_128,
     base_liquidity: u64,
@@ -1427,14 +2853,16 @@ impl WithdrawPayload {
 /// - `quote_liquidity`: The updated quote token liquidity in the pool.
 /// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
 /// - `providers_fee_amount`: The providers fees collected from the swap.
+/// - `referrer_fee_amount`: The part of the protocol fee paid to the swap referrer.
 /// - `amount_to_withdraw`: The amount of tokens to withdraw after the swap.
 /// - `is_in_out`: Indicates whether the swap is "in-to-out" (true) or "out-to-in" (false).
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SwapPayload {
     base_liquidity: u64,
     quote_liquidity: u64,
     protocol_fees_to_redeem: u64,
     providers_fee_amount: u64,
+    referrer_fee_amount: u64,
     amount_to_withdraw: u64,
     is_in_out: bool,
 }
@@ -1447,14 +2875,16 @@ impl SwapPayload {
     /// - `quote_liquidity`: The updated quote token liquidity.
     /// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
     /// - `providers_fee_amount`: The providers fees collected from the swap.
+    /// - `referrer_fee_amount`: The part of the protocol fee paid to the swap referrer.
     /// - `amount_to_withdraw`: The amount of tokens withdrawn.
     /// - `is_in_out`: Indicates the direction of the swap.
-    fn new(base_liquidity: u64, quote_liquidity: u64, protocol_fees_to_redeem: u64, providers_fee_amount: u64, amount_to_withdraw: u64, is_in_out: bool) -> Self {
+    fn new(base_liquidity: u64, quote_liquidity: u64, protocol_fees_to_redeem: u64, providers_fee_amount: u64, referrer_fee_amount: u64, amount_to_withdraw: u64, is_in_out: bool) -> Self {
         Self{
             base_liquidity,
             quote_liquidity,
             protocol_fees_to_redeem,
             providers_fee_amount,
+            referrer_fee_amount,
             amount_to_withdraw,
             is_in_out,
         }
@@ -1464,6 +2894,191 @@ impl SwapPayload {
     pub fn amount_to_withdraw(&self) -> u64{
         self.amount_to_withdraw
     }
+
+    /// Returns the providers fees collected from the swap.
+    pub fn providers_fee_amount(&self) -> u64{
+        self.providers_fee_amount
+    }
+
+    /// Returns the part of the protocol fee paid to the swap referrer, in input tokens.
+    pub fn referrer_fee_amount(&self) -> u64{
+        self.referrer_fee_amount
+    }
+}
+
+/// Represents the data required for an exact-output token swap operation in the AMM.
+///
+/// This struct wraps the resulting `SwapPayload` together with the input amount
+/// required to receive the requested swap result.
+///
+/// # Fields
+/// - `swap_amount`: The amount of input tokens (including swap fees) that must be deposited to the pool.
+/// - `swap_payload`: The updated pool state and fees after the swap.
+#[derive(Debug)]
+pub struct SwapExactOutPayload {
+    swap_amount: u64,
+    swap_payload: SwapPayload,
+}
+
+impl SwapExactOutPayload {
+    /// Creates a new `SwapExactOutPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The required amount of input tokens including swap fees.
+    /// - `swap_payload`: The resulting swap payload.
+    fn new(swap_amount: u64, swap_payload: SwapPayload) -> Self {
+        Self{
+            swap_amount,
+            swap_payload,
+        }
+    }
+
+    /// Returns the amount of input tokens (including swap fees) that must be deposited to the pool.
+    pub fn swap_amount(&self) -> u64{
+        self.swap_amount
+    }
+
+    /// Returns the amount of tokens to withdraw after the swap.
+    pub fn amount_to_withdraw(&self) -> u64{
+        self.swap_payload.amount_to_withdraw
+    }
+
+    /// Consumes the payload and returns the inner `SwapPayload`.
+    pub fn into_swap_payload(self) -> SwapPayload{
+        self.swap_payload
+    }
+}
+
+/// Represents the data required for a single-sided liquidity provision to the AMM.
+///
+/// This struct wraps the internal swap and the following liquidity provision,
+/// together with the amounts that didn't fit the pool ratio.
+///
+/// # Fields
+/// - `swap_amount`: The part of the deposited tokens swapped through the pool.
+/// - `provided_base_liquidity`: The amount of base tokens provided to the pool.
+/// - `provided_quote_liquidity`: The amount of quote tokens provided to the pool.
+/// - `base_dust`: The amount of base tokens returned to the provider.
+/// - `quote_dust`: The amount of quote tokens returned to the provider.
+/// - `swap_payload`: The updated pool state and fees after the internal swap.
+/// - `provide_payload`: The updated pool state and LP tokens to mint after the provision.
+#[derive(Debug)]
+pub struct ZapInPayload {
+    swap_amount: u64,
+    provided_base_liquidity: u64,
+    provided_quote_liquidity: u64,
+    base_dust: u64,
+    quote_dust: u64,
+    swap_payload: SwapPayload,
+    provide_payload: ProvidePayload,
+}
+
+impl ZapInPayload {
+    /// Creates a new `ZapInPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The part of the deposited tokens swapped through the pool.
+    /// - `provided_base_liquidity`: The base tokens provided to the pool.
+    /// - `provided_quote_liquidity`: The quote tokens provided to the pool.
+    /// - `base_dust`: The base tokens returned to the provider.
+    /// - `quote_dust`: The quote tokens returned to the provider.
+    /// - `swap_payload`: The internal swap payload.
+    /// - `provide_payload`: The liquidity provision payload.
+    fn new(
+        swap_amount: u64,
+        provided_base_liquidity: u64,
+        provided_quote_liquidity: u64,
+        base_dust: u64,
+        quote_dust: u64,
+        swap_payload: SwapPayload,
+        provide_payload: ProvidePayload,
+    ) -> Self {
+        Self{
+            swap_amount,
+            provided_base_liquidity,
+            provided_quote_liquidity,
+            base_dust,
+            quote_dust,
+            swap_payload,
+            provide_payload,
+        }
+    }
+
+    /// Returns the part of the deposited tokens swapped through the pool.
+    pub fn swap_amount(&self) -> u64{
+        self.swap_amount
+    }
+
+    /// Returns the amount of base tokens provided to the pool.
+    pub fn provided_base_liquidity(&self) -> u64{
+        self.provided_base_liquidity
+    }
+
+    /// Returns the amount of quote tokens provided to the pool.
+    pub fn provided_quote_liquidity(&self) -> u64{
+        self.provided_quote_liquidity
+    }
+
+    /// Returns the amount of base tokens returned to the provider.
+    pub fn base_dust(&self) -> u64{
+        self.base_dust
+    }
+
+    /// Returns the amount of quote tokens returned to the provider.
+    pub fn quote_dust(&self) -> u64{
+        self.quote_dust
+    }
+
+    /// Returns the number of LP tokens to mint for the liquidity provider.
+    pub fn lp_tokens_to_mint(&self) -> u64{
+        self.provide_payload.lp_tokens_to_mint
+    }
+}
+
+/// Represents the data required for a single-sided liquidity withdrawal from the AMM.
+///
+/// This struct wraps the liquidity withdrawal and the following internal swap.
+///
+/// # Fields
+/// - `zap_result`: The total amount of tokens received by the withdrawer.
+/// - `withdraw_payload`: The updated pool state and withdrawn amounts after the withdrawal.
+/// - `swap_payload`: The updated pool state and fees after the internal swap.
+#[derive(Debug)]
+pub struct ZapOutPayload {
+    zap_result: u64,
+    withdraw_payload: WithdrawPayload,
+    swap_payload: SwapPayload,
+}
+
+impl ZapOutPayload {
+    /// Creates a new `ZapOutPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `zap_result`: The total amount of tokens received by the withdrawer.
+    /// - `withdraw_payload`: The liquidity withdrawal payload.
+    /// - `swap_payload`: The internal swap payload.
+    fn new(zap_result: u64, withdraw_payload: WithdrawPayload, swap_payload: SwapPayload) -> Self {
+        Self{
+            zap_result,
+            withdraw_payload,
+            swap_payload,
+        }
+    }
+
+    /// Returns the total amount of tokens received by the withdrawer.
+    pub fn zap_result(&self) -> u64{
+        self.zap_result
+    }
+
+    /// Returns the amount of base tokens withdrawn from the pool before the swap.
+    pub fn base_withdraw_amount(&self) -> u64{
+        self.withdraw_payload.base_withdraw_amount
+    }
+
+    /// Returns the amount of quote tokens withdrawn from the pool before the swap.
+    pub fn quote_withdraw_amount(&self) -> u64{
+        self.withdraw_payload.quote_withdraw_amount
+    }
 }
 
 /// Represents the data required for collecting protocol fees in the AMM.
@@ -1524,6 +3139,187 @@ impl CollectFeesPayload {
     pub fn protocol_quote_fees_to_redeem(&self) -> u64 {
         self.protocol_quote_fees_to_redeem
     }
+
+    /// Returns the left amount of protocol fees in base tokens available for redemption.
+    pub fn new_protocol_base_fees_to_redeem(&self) -> u64 {
+        self.new_protocol_base_fees_to_redeem
+    }
+
+    /// Returns the left amount of protocol fees in quote tokens available for redemption.
+    pub fn new_protocol_quote_fees_to_redeem(&self) -> u64 {
+        self.new_protocol_quote_fees_to_redeem
+    }
+}
+
+/// Represents the data required for lending tokens from the AMM by a flash loan.
+///
+/// # Fields
+/// - `base_amount`: The amount of base tokens lent.
+/// - `quote_amount`: The amount of quote tokens lent.
+/// - `base_fee_amount`: The base tokens fee owed on top of `base_amount`.
+/// - `quote_fee_amount`: The quote tokens fee owed on top of `quote_amount`.
+#[derive(Debug)]
+pub struct FlashLoanPayload {
+    base_amount: u64,
+    quote_amount: u64,
+    base_fee_amount: u64,
+    quote_fee_amount: u64,
+}
+
+impl FlashLoanPayload {
+    /// Creates a new `FlashLoanPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `base_amount`: The amount of base tokens lent.
+    /// - `quote_amount`: The amount of quote tokens lent.
+    /// - `base_fee_amount`: The base tokens fee owed.
+    /// - `quote_fee_amount`: The quote tokens fee owed.
+    fn new(base_amount: u64, quote_amount: u64, base_fee_amount: u64, quote_fee_amount: u64) -> Self {
+        Self {
+            base_amount,
+            quote_amount,
+            base_fee_amount,
+            quote_fee_amount,
+        }
+    }
+
+    /// Returns the amount of base tokens lent.
+    pub fn base_amount(&self) -> u64 {
+        self.base_amount
+    }
+
+    /// Returns the amount of quote tokens lent.
+    pub fn quote_amount(&self) -> u64 {
+        self.quote_amount
+    }
+
+    /// Returns the base tokens fee owed.
+    pub fn base_fee_amount(&self) -> u64 {
+        self.base_fee_amount
+    }
+
+    /// Returns the quote tokens fee owed.
+    pub fn quote_fee_amount(&self) -> u64 {
+        self.quote_fee_amount
+    }
+}
+
+/// Represents the data required for repaying a flash loan to the AMM.
+///
+/// # Fields
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+#[derive(Debug)]
+pub struct FlashRepayPayload {
+    base_liquidity: u64,
+    quote_liquidity: u64,
+}
+
+impl FlashRepayPayload {
+    /// Creates a new `FlashRepayPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `base_liquidity`: The updated base token liquidity.
+    /// - `quote_liquidity`: The updated quote token liquidity.
+    fn new(base_liquidity: u64, quote_liquidity: u64) -> Self {
+        Self {
+            base_liquidity,
+            quote_liquidity,
+        }
+    }
+}
+
+/// Represents the data required for folding the vaults excess into the AMM liquidity.
+///
+/// # Fields
+/// - `base_excess`: The base tokens held by the vault above the bookkeeping.
+/// - `quote_excess`: The quote tokens held by the vault above the bookkeeping.
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+/// - `constant_product_sqrt`: The updated square root of the constant product.
+/// - `base_quote_ratio_sqrt`: The updated square root of the base-to-quote ratio.
+#[derive(Debug)]
+pub struct SyncPayload {
+    base_excess: u64,
+    quote_excess: u64,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    constant_product_sqrt: Q64_128,
+    base_quote_ratio_sqrt: Q64_128,
+}
+
+impl SyncPayload {
+    /// Creates a new `SyncPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `base_excess`: The base tokens held by the vault above the bookkeeping.
+    /// - `quote_excess`: The quote tokens held by the vault above the bookkeeping.
+    /// - `base_liquidity`: The updated base token liquidity.
+    /// - `quote_liquidity`: The updated quote token liquidity.
+    /// - `constant_product_sqrt`: The updated square root of the constant product.
+    /// - `base_quote_ratio_sqrt`: The updated square root of the base-to-quote ratio.
+    fn new(
+        base_excess: u64,
+        quote_excess: u64,
+        base_liquidity: u64,
+        quote_liquidity: u64,
+        constant_product_sqrt: Q64_128,
+        base_quote_ratio_sqrt: Q64_128,
+    ) -> Self {
+        Self {
+            base_excess,
+            quote_excess,
+            base_liquidity,
+            quote_liquidity,
+            constant_product_sqrt,
+            base_quote_ratio_sqrt,
+        }
+    }
+
+    /// Returns the base tokens folded into the liquidity.
+    pub fn base_excess(&self) -> u64 {
+        self.base_excess
+    }
+
+    /// Returns the quote tokens folded into the liquidity.
+    pub fn quote_excess(&self) -> u64 {
+        self.quote_excess
+    }
+}
+
+/// Represents the data required for sending the vaults excess out of the AMM.
+///
+/// # Fields
+/// - `base_excess`: The base tokens held by the vault above the bookkeeping.
+/// - `quote_excess`: The quote tokens held by the vault above the bookkeeping.
+#[derive(Debug)]
+pub struct SkimPayload {
+    base_excess: u64,
+    quote_excess: u64,
+}
+
+impl SkimPayload {
+    /// Creates a new `SkimPayload` instance with the specified parameters.
+    ///
+    /// # Parameters
+    /// - `base_excess`: The base tokens held by the vault above the bookkeeping.
+    /// - `quote_excess`: The quote tokens held by the vault above the bookkeeping.
+    fn new(base_excess: u64, quote_excess: u64) -> Self {
+        Self {
+            base_excess,
+            quote_excess,
+        }
+    }
+
+    /// Returns the base tokens to send out.
+    pub fn base_excess(&self) -> u64 {
+        self.base_excess
+    }
+
+    /// Returns the quote tokens to send out.
+    pub fn quote_excess(&self) -> u64 {
+        self.quote_excess
+    }
 }
 #[cfg(test)]
 mod payloads_tests {
@@ -1600,18 +3396,51 @@ mod payloads_tests {
     /// Tests the `SwapPayload` struct's creation and getters.
     #[test]
     fn test_swap_payload() {
-        let payload = SwapPayload::new(4000, 5000, 6000, 6500,7000, true);
+        let payload = SwapPayload::new(4000, 5000, 6000, 6500, 400, 7000, true);
 
         assert_eq!(payload.base_liquidity, 4000);
         assert_eq!(payload.quote_liquidity, 5000);
         assert_eq!(payload.protocol_fees_to_redeem, 6000);
         assert_eq!(payload.providers_fee_amount, 6500);
+        assert_eq!(payload.referrer_fee_amount, 400);
         assert_eq!(payload.amount_to_withdraw, 7000);
         assert!(payload.is_in_out);
 
         assert_eq!(payload.amount_to_withdraw(), 7000);
+        assert_eq!(payload.referrer_fee_amount(), 400);
     }
     
+    /// Tests the `SwapExactOutPayload` struct's creation and getters.
+    #[test]
+    fn test_swap_exact_out_payload() {
+        let payload = SwapExactOutPayload::new(8000, SwapPayload::new(4000, 5000, 6000, 6500, 400, 7000, false));
+
+        assert_eq!(payload.swap_amount, 8000);
+        assert_eq!(payload.swap_payload.amount_to_withdraw, 7000);
+
+        assert_eq!(payload.swap_amount(), 8000);
+        assert_eq!(payload.amount_to_withdraw(), 7000);
+        assert!(!payload.into_swap_payload().is_in_out);
+    }
+    
+    /// Tests the `SyncPayload` and `SkimPayload` structs' creation and getters.
+    #[test]
+    fn test_reconcile_payloads() {
+        let payload = SyncPayload::new(100, 200, 1100, 2200, Q64_128::from_u64(3000), Q64_128::from_u64(4000));
+
+        assert_eq!(payload.base_liquidity, 1100);
+        assert_eq!(payload.quote_liquidity, 2200);
+        assert_eq!(payload.constant_product_sqrt, Q64_128::from_u64(3000));
+        assert_eq!(payload.base_quote_ratio_sqrt, Q64_128::from_u64(4000));
+        assert_eq!(payload.base_excess(), 100);
+        assert_eq!(payload.quote_excess(), 200);
+
+        let payload = SkimPayload::new(300, 0);
+
+        assert_eq!(payload.base_excess(), 300);
+        assert_eq!(payload.quote_excess(), 0);
+    }
+
     /// Tests the `CollectFeesPayload` struct's creation and getters.
     #[test]
     fn test_collect_fees_payload() {
@@ -1624,4 +3453,11 @@ mod payloads_tests {
         assert_eq!(payload.protocol_base_fees_to_redeem(), 112314);
         assert_eq!(payload.protocol_quote_fees_to_redeem(), 536454000);
     }
+
+    #[test]
+    fn test_lp_token_metadata() {
+        let (name, symbol) = CpAmm::lp_token_metadata("SOL", "USDC");
+        assert_eq!(name, "SOL-USDC LP");
+        assert_eq!(symbol, "SOL-USDC");
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use utilities::math::Q64_128;
+use utilities::math::{Q64_128, U192};
 use crate::error::ErrorCode;
 use crate::state::cp_amm::CpAmmCore;
 
@@ -75,6 +75,33 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
         Some(tokens_to_mint)
     }
     
+    /// Calculates the amount of LP tokens to mint to the protocol for the constant product growth since the last checkpoint.
+    ///
+    /// The protocol receives the `protocol / (providers + protocol)` part of the growth, following
+    /// `mint = supply * (sqrt(k) - sqrt(k_last)) * protocol / (providers * sqrt(k) + protocol * sqrt(k_last))`.
+    ///
+    /// # Parameters
+    /// - `last_constant_product_sqrt`: The square root of the constant product at the last checkpoint.
+    /// - `providers_fee_rate_basis_points`: The providers fee rate expressed in **basis points**.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate expressed in **basis points**.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount of LP tokens to mint, rounded down. Zero if there is no checkpoint or no growth.
+    /// - `None` if the calculation overflows.
+    fn calculate_protocol_lp_fees(&self, last_constant_product_sqrt: Q64_128, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16) -> Option<u64> {
+        let constant_product_sqrt = self.constant_product_sqrt();
+        if protocol_fee_rate_basis_points == 0 || last_constant_product_sqrt.is_zero() || constant_product_sqrt <= last_constant_product_sqrt {
+            return Some(0);
+        }
+        // Both sides are divided by sqrt(k) to keep the intermediate values in range
+        let protocol_rate = Q64_128::from_u64(protocol_fee_rate_basis_points as u64);
+        let growth_share = constant_product_sqrt.checked_sub(last_constant_product_sqrt)?.checked_div(constant_product_sqrt)?;
+        let last_share = last_constant_product_sqrt.checked_div(constant_product_sqrt)?;
+        let denominator = Q64_128::from_u64(providers_fee_rate_basis_points as u64).checked_add(last_share.checked_mul(protocol_rate)?)?;
+        let protocol_share = growth_share.checked_mul(protocol_rate)?.checked_div(denominator)?;
+        Some(protocol_share.checked_mul(Q64_128::from_u64(self.lp_tokens_supply()))?.as_u64())
+    }
+
     /// Calculates the amount of base and quote liquidity to withdraw for a given share of LP tokens.
     ///
     /// # Parameters
@@ -118,6 +145,38 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
         Some((new_base_liquidity, new_quote_liquidity))
     }
 
+    /// Calculates the amount that must be added to the pool (after fees) to receive exactly `swap_result` tokens.
+    ///
+    /// # Parameters
+    /// - `swap_result`: The amount of tokens to receive from the pool.
+    /// - `is_in_out`: Whether the swap is "in" (true) or "out" (false).
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the required amount of input tokens after fees.
+    /// - `None` if the calculation fails (e.g., the pool can't cover `swap_result`).
+    fn calculate_beforeswap_amount(&self, swap_result: u64, is_in_out: bool) -> Option<u64>{
+        let required_amount = if is_in_out {
+            let new_quote_liquidity = self.quote_liquidity().checked_sub(swap_result)?;
+            if new_quote_liquidity == 0 {
+                return None;
+            }
+            let new_base_liquidity = self.calculate_opposite_liquidity(new_quote_liquidity)?;
+            new_base_liquidity.checked_sub(self.base_liquidity())?
+        }
+        else{
+            let new_base_liquidity = self.base_liquidity().checked_sub(swap_result)?;
+            if new_base_liquidity == 0 {
+                return None;
+            }
+            let new_quote_liquidity = self.calculate_opposite_liquidity(new_base_liquidity)?;
+            new_quote_liquidity.checked_sub(self.quote_liquidity())?
+        };
+        if required_amount == 0 {
+            return None;
+        }
+        Some(required_amount)
+    }
+
     /// Validates and calculates the new liquidity ratio after liquidity change.
     ///
     /// # Parameters
@@ -199,6 +258,27 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
     fn calculate_fee_amount(swap_amount: u64, fee_basis_points: u16) -> u64 {
         ((swap_amount as u128) * (fee_basis_points as u128) / Self::FEE_MAX_BASIS_POINTS) as u64
     }
+
+    /// Calculates the swap amount which is left with at least `amount_after_fees` after deducting the fee.
+    ///
+    /// The result is rounded up, so the deducted fee never leaves less than `amount_after_fees`.
+    ///
+    /// # Parameters
+    /// - `amount_after_fees`: The amount of tokens that must be left after deducting the fee.
+    /// - `fee_basis_points`: The total fee rate expressed in **basis points** (1 basis point = 0.01%).
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the swap amount including fees.
+    /// - `None` if the fee rate is 100% or the result overflows.
+    #[inline]
+    fn calculate_amount_before_fees(amount_after_fees: u64, fee_basis_points: u16) -> Option<u64> {
+        let denominator = Self::FEE_MAX_BASIS_POINTS.checked_sub(fee_basis_points as u128)?;
+        if denominator == 0 {
+            return None;
+        }
+        let amount = ((amount_after_fees as u128) * Self::FEE_MAX_BASIS_POINTS).div_ceil(denominator);
+        u64::try_from(amount).ok()
+    }
     
     /// Calculates the base-to-quote liquidity ratio square root.
     ///
@@ -235,6 +315,87 @@ pub(crate) trait CpAmmCalculate: CpAmmCore {
         }
         Some(constant_product_sqrt)
     }
+
+    /// Calculates the relative change of the quote-per-base price between two liquidity states.
+    ///
+    /// # Parameters
+    /// - `base_liquidity`: The base liquidity before the change.
+    /// - `quote_liquidity`: The quote liquidity before the change.
+    /// - `new_base_liquidity`: The base liquidity after the change.
+    /// - `new_quote_liquidity`: The quote liquidity after the change.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the price change, measured in basis points and rounded down.
+    /// - `None` if any liquidity is zero or the result overflows.
+    #[inline]
+    fn calculate_price_change_basis_points(base_liquidity: u64, quote_liquidity: u64, new_base_liquidity: u64, new_quote_liquidity: u64) -> Option<u64> {
+        // |q1 / b1 - q0 / b0| / (q0 / b0) = |q1 * b0 - q0 * b1| / (q0 * b1)
+        let price_cross_product = new_quote_liquidity as u128 * base_liquidity as u128;
+        let new_price_cross_product = quote_liquidity as u128 * new_base_liquidity as u128;
+        if new_price_cross_product == 0 || base_liquidity == 0 {
+            return None;
+        }
+        let change = U192::from(price_cross_product.abs_diff(new_price_cross_product))
+            .checked_mul(U192::from(Self::FEE_MAX_BASIS_POINTS))?
+            .checked_div(U192::from(new_price_cross_product))?;
+        u64::try_from(change).ok()
+    }
+
+    /// Calculates the part of a single-sided deposit that must be swapped, so the rest matches the pool ratio.
+    ///
+    /// Solves `s = (sqrt(r * (r * (2 - f)^2 + 4 * a * (1 - f))) - r * (2 - f)) / (2 * (1 - f))`,
+    /// where `r` is the reserve of the deposited token, `a` is the deposited amount and `f` is the swap fee rate.
+    ///
+    /// # Parameters
+    /// - `reserve`: The pool liquidity of the deposited token.
+    /// - `amount`: The deposited amount.
+    /// - `fee_basis_points`: The total swap fee rate expressed in **basis points**.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount to swap, rounded down.
+    /// - `None` if the fee rate is 100% or the calculation overflows.
+    #[inline]
+    fn calculate_zap_swap_amount(reserve: u64, amount: u64, fee_basis_points: u16) -> Option<u64> {
+        // Scaled by FEE_MAX_BASIS_POINTS: remaining_rate = 1 - f, fee_factor = 2 - f
+        let remaining_rate = U192::from(Self::FEE_MAX_BASIS_POINTS.checked_sub(fee_basis_points as u128)?);
+        if remaining_rate.is_zero() {
+            return None;
+        }
+        let fee_factor = U192::from(Self::FEE_MAX_BASIS_POINTS) + remaining_rate;
+        let reserve = U192::from(reserve);
+
+        let reserve_term = reserve.checked_mul(fee_factor)?;
+        let discriminant = reserve_term.checked_mul(reserve_term)?.checked_add(
+            U192::from(4) * remaining_rate * U192::from(Self::FEE_MAX_BASIS_POINTS) * U192::from(amount) * reserve
+        )?;
+        let swap_amount = discriminant.integer_sqrt().checked_sub(reserve_term)? / (U192::from(2) * remaining_rate);
+        u64::try_from(swap_amount).ok()
+    }
+
+    /// Calculates the largest base and quote amounts that fit the current pool ratio.
+    ///
+    /// # Parameters
+    /// - `base_amount`: The available base amount.
+    /// - `quote_amount`: The available quote amount.
+    ///
+    /// # Returns
+    /// - `Some((u64, u64))` with the base and quote amounts to provide, rounded down.
+    /// - `None` if the pool is empty or any resulting amount is zero.
+    #[inline]
+    fn calculate_ratio_fitting_liquidity(&self, base_amount: u64, quote_amount: u64) -> Option<(u64, u64)> {
+        if self.base_liquidity() == 0 || self.quote_liquidity() == 0 {
+            return None;
+        }
+        let required_quote_amount = u64::try_from(base_amount as u128 * self.quote_liquidity() as u128 / self.base_liquidity() as u128).ok();
+        let (base_amount, quote_amount) = match required_quote_amount {
+            Some(required_quote_amount) if required_quote_amount <= quote_amount => (base_amount, required_quote_amount),
+            _ => ((quote_amount as u128 * self.base_liquidity() as u128 / self.quote_liquidity() as u128) as u64, quote_amount),
+        };
+        if base_amount == 0 || quote_amount == 0 {
+            return None;
+        }
+        Some((base_amount, quote_amount))
+    }
 }
 
 impl<T: CpAmmCore> CpAmmCalculate for T{}
@@ -503,6 +664,77 @@ mod tests {
             );
         }
 
+        /// Tests `calculate_protocol_lp_fees` for minting protocol LP tokens.
+        #[test]
+        fn test_calculate_protocol_lp_fees() {
+            let amm = TestCpAmm::try_new(4_000_000, 1_000_000).unwrap();
+            let last_constant_product_sqrt = TestCpAmm::calculate_constant_product_sqrt(3_610_000, 1_000_000).unwrap();
+
+            assert_eq!(amm.calculate_protocol_lp_fees(Q64_128::default(), 25, 5), Some(0));
+            assert_eq!(amm.calculate_protocol_lp_fees(amm.constant_product_sqrt, 25, 5), Some(0));
+            assert_eq!(amm.calculate_protocol_lp_fees(last_constant_product_sqrt, 25, 0), Some(0));
+
+            let minted_tokens = amm.calculate_protocol_lp_fees(last_constant_product_sqrt, 25, 5).unwrap();
+            let (root_k, root_k_last) = (f64::from(amm.constant_product_sqrt), f64::from(last_constant_product_sqrt));
+            let expected_minted = amm.lp_tokens_supply as f64 * (root_k - root_k_last) * 5.0 / (25.0 * root_k + 5.0 * root_k_last);
+            assert_eq!(
+                minted_tokens,
+                expected_minted.floor() as u64,
+                "Minted tokens mismatch. Expected: {}, Got: {}",
+                expected_minted.floor() as u64,
+                minted_tokens
+            );
+
+            // The protocol share of the pool after the mint matches its share of the growth
+            let protocol_liquidity = minted_tokens as f64 / (amm.lp_tokens_supply + minted_tokens) as f64 * root_k;
+            let expected_protocol_liquidity = (root_k - root_k_last) * 5.0 / 30.0;
+            assert!((protocol_liquidity - expected_protocol_liquidity).abs() < 1.0);
+        }
+
+        /// Tests `calculate_price_change_basis_points` for correctness.
+        #[test]
+        fn test_calculate_price_change_basis_points() {
+            assert_eq!(TestCpAmm::calculate_price_change_basis_points(1_000_000, 1_000_000, 1_000_000, 1_000_000), Some(0));
+            assert_eq!(TestCpAmm::calculate_price_change_basis_points(1_000_000, 1_000_000, 500_000, 2_000_000), Some(30_000));
+            assert_eq!(TestCpAmm::calculate_price_change_basis_points(1_000_000, 1_000_000, 2_000_000, 500_000), Some(7_500));
+            assert_eq!(TestCpAmm::calculate_price_change_basis_points(u64::MAX, 1, 1, u64::MAX), None);
+            assert_eq!(TestCpAmm::calculate_price_change_basis_points(0, 1_000_000, 1_000_000, 1_000_000), None);
+        }
+
+        /// Tests `calculate_zap_swap_amount` for correctness.
+        #[test]
+        fn test_calculate_zap_swap_amount() {
+            // Without fees the swap amount is sqrt(r * (r + a)) - r
+            assert_eq!(TestCpAmm::calculate_zap_swap_amount(1_000_000, 3_000_000, 0), Some(1_000_000));
+            assert_eq!(TestCpAmm::calculate_zap_swap_amount(1_000_000, 0, 30), Some(0));
+            assert_eq!(TestCpAmm::calculate_zap_swap_amount(1_000_000, 1_000_000, 10000), None);
+
+            // With fees the leftover matches the afterswap pool ratio
+            let (reserve, opposite_reserve, amount, fee_basis_points) = (5_000_000u64, 20_000_000u64, 1_000_000u64, 30u16);
+            let swap_amount = TestCpAmm::calculate_zap_swap_amount(reserve, amount, fee_basis_points).unwrap();
+            let swap_amount_after_fees = swap_amount - TestCpAmm::calculate_fee_amount(swap_amount, fee_basis_points);
+            let new_reserve = (reserve + swap_amount_after_fees) as f64;
+            let new_opposite_reserve = reserve as f64 * opposite_reserve as f64 / new_reserve;
+            let received = opposite_reserve as f64 - new_opposite_reserve;
+            let leftover_ratio = (amount - swap_amount) as f64 / received;
+            let pool_ratio = (reserve + swap_amount) as f64 / new_opposite_reserve;
+            assert!(
+                (leftover_ratio - pool_ratio).abs() / pool_ratio < 1e-5,
+                "Leftover ratio mismatch. Expected: {}, Got: {}",
+                pool_ratio,
+                leftover_ratio
+            );
+        }
+
+        /// Tests `calculate_ratio_fitting_liquidity` for correctness.
+        #[test]
+        fn test_calculate_ratio_fitting_liquidity() {
+            let amm = TestCpAmm::try_new(1_000_000, 4_000_000).unwrap();
+            assert_eq!(amm.calculate_ratio_fitting_liquidity(1_000, 5_000), Some((1_000, 4_000)));
+            assert_eq!(amm.calculate_ratio_fitting_liquidity(1_000, 3_000), Some((750, 3_000)));
+            assert_eq!(amm.calculate_ratio_fitting_liquidity(1_000, 3), None);
+        }
+
         /// Tests `calculate_fee_amount` for correctness.
         #[test]
         fn test_calculate_fee_amount() {
@@ -584,6 +816,45 @@ mod tests {
             );
         }
 
+        /// Tests `calculate_beforeswap_amount` for the amount required to receive an exact swap result.
+        #[test]
+        fn test_calculate_beforeswap_amount() {
+            let base_liquidity: u64 = 6_000_000;
+            let quote_liquidity: u64 = 1_500_000;
+            let amm = TestCpAmm::try_new(base_liquidity, quote_liquidity).unwrap();
+
+            let base_amount = amm.calculate_beforeswap_amount(500_000, true).unwrap();
+            let quote_amount = amm.calculate_beforeswap_amount(1_500_000, false).unwrap();
+
+            assert_eq!(
+                base_amount, 3_000_000,
+                "Required base amount mismatch. Expected: {}, Got: {}",
+                3_000_000, base_amount
+            );
+            assert_eq!(
+                quote_amount, 500_000,
+                "Required quote amount mismatch. Expected: {}, Got: {}",
+                500_000, quote_amount
+            );
+
+            assert!(amm.calculate_beforeswap_amount(quote_liquidity, true).is_none());
+            assert!(amm.calculate_beforeswap_amount(base_liquidity + 1, false).is_none());
+        }
+
+        /// Tests `calculate_amount_before_fees` for correct rounding and edge cases.
+        #[test]
+        fn test_calculate_amount_before_fees() {
+            let amount = TestCpAmm::calculate_amount_before_fees(3_000_000, 200).unwrap();
+            assert_eq!(amount, 3_061_225);
+
+            let fees = TestCpAmm::calculate_fee_amount(amount, 100) * 2;
+            assert!(amount - fees >= 3_000_000);
+
+            assert_eq!(TestCpAmm::calculate_amount_before_fees(3_000_000, 0).unwrap(), 3_000_000);
+            assert!(TestCpAmm::calculate_amount_before_fees(3_000_000, 10000).is_none());
+            assert!(TestCpAmm::calculate_amount_before_fees(u64::MAX, 5000).is_none());
+        }
+
         /// Tests `validate_and_calculate_liquidity_ratio` for correct validation and calculation of liquidity ratio.
         #[test]
         fn test_validate_and_calculate_liquidity_ratio() {
@@ -0,0 +1,359 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+
+/// Emission schedule of a single reward mint of a `CpAmmFarm`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq)]
+pub struct CpAmmFarmReward {
+    /// Public key of the reward mint, the default public key for an unused slot.
+    mint: Pubkey, // 32 bytes
+
+    /// Amount of reward tokens streamed per second, stored as a Q64.128 fixed-point number.
+    reward_rate: Q64_128, // 24 bytes
+
+    /// Accumulated rewards per staked LP token, stored as a Q64.128 fixed-point number.
+    reward_per_token: Q64_128, // 24 bytes
+
+    /// Timestamp the current emission period starts at.
+    start_timestamp: i64, // 8 bytes
+
+    /// Timestamp the current emission period ends at.
+    end_timestamp: i64, // 8 bytes
+}
+
+impl CpAmmFarmReward {
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    #[inline]
+    pub fn reward_rate(&self) -> Q64_128 {
+        self.reward_rate
+    }
+
+    #[inline]
+    pub fn reward_per_token(&self) -> Q64_128 {
+        self.reward_per_token
+    }
+
+    #[inline]
+    pub fn start_timestamp(&self) -> i64 {
+        self.start_timestamp
+    }
+
+    #[inline]
+    pub fn end_timestamp(&self) -> i64 {
+        self.end_timestamp
+    }
+
+    /// Accrues the emissions between two timestamps, clamped to the emission period.
+    fn accrue(&mut self, from_timestamp: i64, to_timestamp: i64, staked_lp_tokens: u64) -> Result<()> {
+        let from_timestamp = from_timestamp.clamp(self.start_timestamp, self.end_timestamp);
+        let to_timestamp = to_timestamp.clamp(self.start_timestamp, self.end_timestamp);
+        if staked_lp_tokens == 0 || to_timestamp <= from_timestamp {
+            return Ok(());
+        }
+        let reward_increment = Q64_128::from_u64((to_timestamp - from_timestamp) as u64)
+            .checked_mul(self.reward_rate)
+            .and_then(|reward| reward.checked_div(Q64_128::from_u64(staked_lp_tokens)))
+            .ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+        self.reward_per_token = self.reward_per_token.checked_add(reward_increment).ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+        Ok(())
+    }
+}
+
+/// Liquidity mining farm streaming up to `MAX_REWARDS` reward mints to the LP tokens of a `CpAmm` staked into it.
+///
+/// Rewards are distributed with the reward-per-token accounting of the launchpools:
+/// each reward accumulates the emissions per staked LP token, and positions keep a reward debt
+/// to only earn what was accumulated while they were staked. Emissions streamed while nothing
+/// is staked are not distributed and stay in the reward vault.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmFarm {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the `CpAmm` whose LP tokens are staked.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// Public key of the farm authority, which funds the rewards.
+    authority: Pubkey, // 32 bytes
+
+    /// Public key of the staked LP mint.
+    lp_mint: Pubkey, // 32 bytes
+
+    /// Total amount of staked LP tokens.
+    staked_lp_tokens: u64, // 8 bytes
+
+    /// Timestamp of the last rewards accrual.
+    last_update_timestamp: i64, // 8 bytes
+
+    /// Emission schedules of the reward mints.
+    rewards: [CpAmmFarmReward; CpAmmFarm::MAX_REWARDS], // 96 * MAX_REWARDS bytes
+}
+
+impl CpAmmFarm {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_farm";
+
+    /// Maximal number of reward mints a farm can stream.
+    pub const MAX_REWARDS: usize = 3;
+
+    /// Returns the seeds for signing with the PDA.
+    ///
+    /// The PDA is derived using the `SEED`, the `cp_amm`, the `authority` and the `bump` value.
+    pub fn seeds(&self) -> [&[u8]; 4] {
+        [Self::SEED, self.cp_amm.as_ref(), self.authority.as_ref(), self.bump.as_ref()]
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    #[inline]
+    pub fn authority(&self) -> &Pubkey {
+        &self.authority
+    }
+
+    #[inline]
+    pub fn lp_mint(&self) -> &Pubkey {
+        &self.lp_mint
+    }
+
+    #[inline]
+    pub fn staked_lp_tokens(&self) -> u64 {
+        self.staked_lp_tokens
+    }
+
+    #[inline]
+    pub fn last_update_timestamp(&self) -> i64 {
+        self.last_update_timestamp
+    }
+
+    #[inline]
+    pub fn rewards(&self) -> &[CpAmmFarmReward; CpAmmFarm::MAX_REWARDS] {
+        &self.rewards
+    }
+
+    /// Returns the accumulated rewards per staked LP token of every reward slot.
+    pub fn rewards_per_token(&self) -> [Q64_128; CpAmmFarm::MAX_REWARDS] {
+        self.rewards.map(|reward| reward.reward_per_token)
+    }
+
+    /// Returns the reward slot streaming the given mint, if any.
+    pub fn reward_index(&self, mint: &Pubkey) -> Option<usize> {
+        self.rewards.iter().position(|reward| reward.is_initialized() && reward.mint == *mint)
+    }
+}
+
+impl CpAmmFarm {
+    /// Initializes the farm.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: Public key of the `CpAmm` whose LP tokens are staked.
+    /// - `authority`: Public key of the farm authority.
+    /// - `lp_mint`: Public key of the staked LP mint.
+    /// - `timestamp`: The current timestamp.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, cp_amm: Pubkey, authority: Pubkey, lp_mint: Pubkey, timestamp: i64, bump: u8) {
+        self.bump = [bump];
+        self.cp_amm = cp_amm;
+        self.authority = authority;
+        self.lp_mint = lp_mint;
+        self.staked_lp_tokens = 0;
+        self.last_update_timestamp = timestamp;
+        self.rewards = [CpAmmFarmReward::default(); CpAmmFarm::MAX_REWARDS];
+    }
+
+    /// Accrues the emissions of every reward since the last update.
+    ///
+    /// Must be called before any change of the staked amount or of the reward schedules.
+    pub(crate) fn accrue_rewards(&mut self, timestamp: i64) -> Result<()> {
+        if timestamp <= self.last_update_timestamp {
+            return Ok(());
+        }
+        for reward in self.rewards.iter_mut().filter(|reward| reward.is_initialized()) {
+            reward.accrue(self.last_update_timestamp, timestamp, self.staked_lp_tokens)?;
+        }
+        self.last_update_timestamp = timestamp;
+        Ok(())
+    }
+
+    /// Schedules a new emission period of a reward mint.
+    ///
+    /// The mint reuses its slot if it was already streamed, otherwise it takes the first unused one.
+    ///
+    /// # Parameters
+    /// - `mint`: Public key of the reward mint.
+    /// - `amount`: Amount of reward tokens streamed over the period.
+    /// - `start_timestamp`, `end_timestamp`: Bounds of the emission period.
+    /// - `timestamp`: The current timestamp.
+    ///
+    /// # Returns
+    /// - The index of the reward slot.
+    ///
+    /// # Errors
+    /// - `FarmRewardAmountIsZero` if `amount` is zero.
+    /// - `InvalidFarmRewardSchedule` unless `timestamp <= start_timestamp < end_timestamp`.
+    /// - `FarmRewardScheduleActive` if the previous period of the mint has not ended yet.
+    /// - `FarmRewardsLimitReached` if all slots stream other mints.
+    pub(crate) fn add_reward(&mut self, mint: Pubkey, amount: u64, start_timestamp: i64, end_timestamp: i64, timestamp: i64) -> Result<usize> {
+        require!(amount > 0, ErrorCode::FarmRewardAmountIsZero);
+        require!(timestamp <= start_timestamp && start_timestamp < end_timestamp, ErrorCode::InvalidFarmRewardSchedule);
+
+        let index = match self.reward_index(&mint) {
+            Some(index) => {
+                require!(self.rewards[index].end_timestamp <= timestamp, ErrorCode::FarmRewardScheduleActive);
+                index
+            }
+            None => self.rewards.iter().position(|reward| !reward.is_initialized()).ok_or(ErrorCode::FarmRewardsLimitReached)?
+        };
+
+        let reward = &mut self.rewards[index];
+        reward.mint = mint;
+        reward.reward_rate = Q64_128::from_u64(amount)
+            .checked_div(Q64_128::from_u64((end_timestamp - start_timestamp) as u64))
+            .ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+        reward.start_timestamp = start_timestamp;
+        reward.end_timestamp = end_timestamp;
+        Ok(index)
+    }
+
+    /// Records LP tokens staked into the farm.
+    pub(crate) fn deposit(&mut self, lp_tokens: u64) {
+        self.staked_lp_tokens = self.staked_lp_tokens.checked_add(lp_tokens).unwrap();
+    }
+
+    /// Records LP tokens withdrawn from the farm.
+    pub(crate) fn withdraw(&mut self, lp_tokens: u64) {
+        self.staked_lp_tokens = self.staked_lp_tokens.checked_sub(lp_tokens).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_farm_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn initialized_farm() -> CpAmmFarm {
+        let mut farm = CpAmmFarm {
+            bump: [0],
+            cp_amm: Pubkey::default(),
+            authority: Pubkey::default(),
+            lp_mint: Pubkey::default(),
+            staked_lp_tokens: 0,
+            last_update_timestamp: 0,
+            rewards: [CpAmmFarmReward::default(); CpAmmFarm::MAX_REWARDS],
+        };
+        farm.initialize(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), 100, 42);
+        farm
+    }
+
+    #[test]
+    fn test_cp_amm_farm_add_reward() {
+        let mut farm = initialized_farm();
+        let mint = Pubkey::new_unique();
+
+        assert!(farm.add_reward(mint, 0, 100, 200, 100).is_err());
+        assert!(farm.add_reward(mint, 1_000, 50, 200, 100).is_err());
+        assert!(farm.add_reward(mint, 1_000, 200, 200, 100).is_err());
+
+        assert_eq!(farm.add_reward(mint, 1_000, 100, 200, 100).unwrap(), 0);
+        assert_eq!(farm.reward_index(&mint), Some(0));
+        assert_eq!(farm.rewards()[0].reward_rate(), Q64_128::from_u64(10));
+
+        assert!(farm.add_reward(mint, 1_000, 150, 250, 150).is_err());
+        assert_eq!(farm.add_reward(mint, 2_000, 200, 300, 200).unwrap(), 0);
+        assert_eq!(farm.rewards()[0].reward_rate(), Q64_128::from_u64(20));
+
+        assert_eq!(farm.add_reward(Pubkey::new_unique(), 1_000, 200, 300, 200).unwrap(), 1);
+        assert_eq!(farm.add_reward(Pubkey::new_unique(), 1_000, 200, 300, 200).unwrap(), 2);
+        assert!(farm.add_reward(Pubkey::new_unique(), 1_000, 200, 300, 200).is_err());
+    }
+
+    #[test]
+    fn test_cp_amm_farm_accrue_rewards() {
+        let mut farm = initialized_farm();
+        farm.add_reward(Pubkey::new_unique(), 1_000, 100, 200, 100).unwrap();
+
+        farm.accrue_rewards(120).unwrap();
+        assert_eq!(farm.rewards_per_token()[0], Q64_128::from_u64(0));
+
+        farm.deposit(10);
+        farm.accrue_rewards(150).unwrap();
+        assert_eq!(farm.rewards_per_token()[0], Q64_128::from_u64(30));
+
+        farm.deposit(40);
+        farm.accrue_rewards(300).unwrap();
+        assert_eq!(farm.rewards_per_token()[0], Q64_128::from_u64(40));
+        assert_eq!(farm.last_update_timestamp(), 300);
+        assert_eq!(farm.rewards_per_token()[1], Q64_128::from_u64(0));
+    }
+
+    #[test]
+    fn test_cp_amm_farm_data_layout() {
+        let bump = 42u8;
+        let cp_amm = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let lp_mint = Pubkey::new_unique();
+        let staked_lp_tokens = 1_000u64;
+        let last_update_timestamp = 100i64;
+        let reward_mint = Pubkey::new_unique();
+        let reward_rate = Q64_128::from_u64(10);
+        let reward_per_token = Q64_128::from_u64(1337);
+        let start_timestamp = 50i64;
+        let end_timestamp = 500i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 401];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmmFarm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(authority.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(lp_mint.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&staked_lp_tokens.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&last_update_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 32].copy_from_slice(reward_mint.as_ref()); offset += 32;
+        data[offset..offset + 24].copy_from_slice(&reward_rate.to_le_bytes()); offset += 24;
+        data[offset..offset + 24].copy_from_slice(&reward_per_token.to_le_bytes()); offset += 24;
+        data[offset..offset + 8].copy_from_slice(&start_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&end_timestamp.to_le_bytes()); offset += 8;
+        offset += 96 * (CpAmmFarm::MAX_REWARDS - 1);
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 401);
+
+        let deserialized_farm = CpAmmFarm::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_farm.bump(), bump);
+        assert_eq!(deserialized_farm.cp_amm, cp_amm);
+        assert_eq!(deserialized_farm.authority, authority);
+        assert_eq!(deserialized_farm.lp_mint, lp_mint);
+        assert_eq!(deserialized_farm.staked_lp_tokens, staked_lp_tokens);
+        assert_eq!(deserialized_farm.last_update_timestamp, last_update_timestamp);
+        assert_eq!(deserialized_farm.rewards[0].mint, reward_mint);
+        assert_eq!(deserialized_farm.rewards[0].reward_rate, reward_rate);
+        assert_eq!(deserialized_farm.rewards[0].reward_per_token, reward_per_token);
+        assert_eq!(deserialized_farm.rewards[0].start_timestamp, start_timestamp);
+        assert_eq!(deserialized_farm.rewards[0].end_timestamp, end_timestamp);
+        assert!(!deserialized_farm.rewards[1].is_initialized());
+
+        let mut serialized_data = Vec::new();
+        deserialized_farm.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
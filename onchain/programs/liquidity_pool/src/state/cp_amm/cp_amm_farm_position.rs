@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+use super::CpAmmFarm;
+
+/// Position of an owner staking LP tokens into a `CpAmmFarm`.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmFarmPosition {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the `CpAmmFarm` the position belongs to.
+    farm: Pubkey, // 32 bytes
+
+    /// Public key of the position owner.
+    owner: Pubkey, // 32 bytes
+
+    /// Amount of LP tokens staked by the owner.
+    staked_lp_tokens: u64, // 8 bytes
+
+    /// Rewards per token already accounted for the staked LP tokens, per reward slot.
+    reward_debts: [Q64_128; CpAmmFarm::MAX_REWARDS], // 24 * MAX_REWARDS bytes
+
+    /// Rewards earned and not harvested yet, per reward slot.
+    rewards_earned: [Q64_128; CpAmmFarm::MAX_REWARDS], // 24 * MAX_REWARDS bytes
+}
+
+impl CpAmmFarmPosition {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_farm_position";
+
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.owner != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn farm(&self) -> &Pubkey {
+        &self.farm
+    }
+
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline]
+    pub fn staked_lp_tokens(&self) -> u64 {
+        self.staked_lp_tokens
+    }
+
+    #[inline]
+    pub fn reward_debts(&self) -> &[Q64_128; CpAmmFarm::MAX_REWARDS] {
+        &self.reward_debts
+    }
+
+    #[inline]
+    pub fn rewards_earned(&self) -> &[Q64_128; CpAmmFarm::MAX_REWARDS] {
+        &self.rewards_earned
+    }
+
+    /// Moves the rewards accumulated since the last update into `rewards_earned`
+    /// and resets the reward debts to the current rewards per token.
+    fn update_rewards(&mut self, rewards_per_token: &[Q64_128; CpAmmFarm::MAX_REWARDS]) -> Result<()> {
+        let staked_lp_tokens = Q64_128::from_u64(self.staked_lp_tokens);
+        for (index, reward_per_token) in rewards_per_token.iter().enumerate() {
+            let accumulated = staked_lp_tokens.checked_mul(*reward_per_token).ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+            let pending = accumulated.checked_sub(self.reward_debts[index]).ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+            self.rewards_earned[index] = self.rewards_earned[index].checked_add(pending).ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+            self.reward_debts[index] = accumulated;
+        }
+        Ok(())
+    }
+
+    /// Resets the reward debts to the rewards per token of the current staked amount.
+    fn reset_reward_debts(&mut self, rewards_per_token: &[Q64_128; CpAmmFarm::MAX_REWARDS]) -> Result<()> {
+        let staked_lp_tokens = Q64_128::from_u64(self.staked_lp_tokens);
+        for (index, reward_per_token) in rewards_per_token.iter().enumerate() {
+            self.reward_debts[index] = staked_lp_tokens.checked_mul(*reward_per_token).ok_or(ErrorCode::FarmRewardCalculationOverflow)?;
+        }
+        Ok(())
+    }
+}
+
+impl CpAmmFarmPosition {
+    /// Initializes the position.
+    ///
+    /// # Parameters
+    /// - `farm`: Public key of the `CpAmmFarm` the position belongs to.
+    /// - `owner`: Public key of the position owner.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, farm: Pubkey, owner: Pubkey, bump: u8) {
+        self.bump = [bump];
+        self.farm = farm;
+        self.owner = owner;
+        self.staked_lp_tokens = 0;
+        self.reward_debts = [Q64_128::from_u64(0); CpAmmFarm::MAX_REWARDS];
+        self.rewards_earned = [Q64_128::from_u64(0); CpAmmFarm::MAX_REWARDS];
+    }
+
+    /// Stakes LP tokens, settling the rewards earned with the previous stake.
+    ///
+    /// # Errors
+    /// - `FarmLpTokensAmountIsZero` if `lp_tokens` is zero.
+    pub(crate) fn deposit(&mut self, lp_tokens: u64, rewards_per_token: &[Q64_128; CpAmmFarm::MAX_REWARDS]) -> Result<()> {
+        require!(lp_tokens > 0, ErrorCode::FarmLpTokensAmountIsZero);
+        self.update_rewards(rewards_per_token)?;
+        self.staked_lp_tokens = self.staked_lp_tokens.checked_add(lp_tokens).unwrap();
+        self.reset_reward_debts(rewards_per_token)
+    }
+
+    /// Unstakes LP tokens, settling the rewards earned with the previous stake.
+    ///
+    /// # Errors
+    /// - `FarmLpTokensAmountIsZero` if `lp_tokens` is zero.
+    /// - `InsufficientFarmStake` if the position holds fewer LP tokens.
+    pub(crate) fn withdraw(&mut self, lp_tokens: u64, rewards_per_token: &[Q64_128; CpAmmFarm::MAX_REWARDS]) -> Result<()> {
+        require!(lp_tokens > 0, ErrorCode::FarmLpTokensAmountIsZero);
+        require!(lp_tokens <= self.staked_lp_tokens, ErrorCode::InsufficientFarmStake);
+        self.update_rewards(rewards_per_token)?;
+        self.staked_lp_tokens -= lp_tokens;
+        self.reset_reward_debts(rewards_per_token)
+    }
+
+    /// Takes the whole earned reward tokens of a reward slot, keeping the fractional remainder.
+    ///
+    /// # Returns
+    /// - The amount of reward tokens to transfer to the owner.
+    ///
+    /// # Errors
+    /// - `InvalidFarmRewardIndex` if the index is out of bounds.
+    /// - `NothingToHarvest` if less than one reward token was earned.
+    pub(crate) fn harvest(&mut self, reward_index: usize, rewards_per_token: &[Q64_128; CpAmmFarm::MAX_REWARDS]) -> Result<u64> {
+        require!(reward_index < CpAmmFarm::MAX_REWARDS, ErrorCode::InvalidFarmRewardIndex);
+        self.update_rewards(rewards_per_token)?;
+        let (reward, remainder) = self.rewards_earned[reward_index].split();
+        require!(reward > 0, ErrorCode::NothingToHarvest);
+        self.rewards_earned[reward_index] = Q64_128::from_bits(0, remainder);
+        Ok(reward)
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_farm_position_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn initialized_position() -> CpAmmFarmPosition {
+        let mut position = CpAmmFarmPosition {
+            bump: [0],
+            farm: Pubkey::default(),
+            owner: Pubkey::default(),
+            staked_lp_tokens: 0,
+            reward_debts: [Q64_128::from_u64(0); CpAmmFarm::MAX_REWARDS],
+            rewards_earned: [Q64_128::from_u64(0); CpAmmFarm::MAX_REWARDS],
+        };
+        assert!(!position.is_initialized());
+        position.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 42);
+        assert!(position.is_initialized());
+        position
+    }
+
+    fn rewards_per_token(first: u64, second: u64) -> [Q64_128; CpAmmFarm::MAX_REWARDS] {
+        [Q64_128::from_u64(first), Q64_128::from_u64(second), Q64_128::from_u64(0)]
+    }
+
+    #[test]
+    fn test_cp_amm_farm_position_deposit_and_withdraw() {
+        let mut position = initialized_position();
+
+        assert!(position.deposit(0, &rewards_per_token(0, 0)).is_err());
+        position.deposit(10, &rewards_per_token(5, 1)).unwrap();
+        assert_eq!(position.staked_lp_tokens(), 10);
+        assert_eq!(position.reward_debts()[0], Q64_128::from_u64(50));
+        assert_eq!(position.rewards_earned()[0], Q64_128::from_u64(0));
+
+        position.deposit(10, &rewards_per_token(8, 2)).unwrap();
+        assert_eq!(position.rewards_earned()[0], Q64_128::from_u64(30));
+        assert_eq!(position.rewards_earned()[1], Q64_128::from_u64(10));
+        assert_eq!(position.reward_debts()[0], Q64_128::from_u64(160));
+
+        assert!(position.withdraw(21, &rewards_per_token(8, 2)).is_err());
+        position.withdraw(20, &rewards_per_token(10, 2)).unwrap();
+        assert_eq!(position.staked_lp_tokens(), 0);
+        assert_eq!(position.rewards_earned()[0], Q64_128::from_u64(70));
+        assert_eq!(position.reward_debts()[0], Q64_128::from_u64(0));
+    }
+
+    #[test]
+    fn test_cp_amm_farm_position_harvest() {
+        let mut position = initialized_position();
+        position.deposit(3, &rewards_per_token(0, 0)).unwrap();
+
+        let half = Q64_128::from_u64(1).checked_div(Q64_128::from_u64(2)).unwrap();
+        let rewards_per_token = [half, Q64_128::from_u64(0), Q64_128::from_u64(0)];
+
+        assert_eq!(position.harvest(0, &rewards_per_token).unwrap(), 1);
+        assert_eq!(position.rewards_earned()[0], half);
+        assert!(position.harvest(1, &rewards_per_token).is_err());
+        assert!(position.harvest(CpAmmFarm::MAX_REWARDS, &rewards_per_token).is_err());
+    }
+
+    #[test]
+    fn test_cp_amm_farm_position_data_layout() {
+        let bump = 42u8;
+        let farm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let staked_lp_tokens = 1_000u64;
+        let reward_debt = Q64_128::from_u64(2_000);
+        let reward_earned = Q64_128::from_u64(300);
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 217];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmmFarmPosition::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(farm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&staked_lp_tokens.to_le_bytes()); offset += 8;
+        data[offset..offset + 24].copy_from_slice(&reward_debt.to_le_bytes()); offset += 24 * CpAmmFarm::MAX_REWARDS;
+        data[offset..offset + 24].copy_from_slice(&reward_earned.to_le_bytes()); offset += 24 * CpAmmFarm::MAX_REWARDS;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 217);
+
+        let deserialized_position = CpAmmFarmPosition::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_position.bump(), bump);
+        assert_eq!(deserialized_position.farm, farm);
+        assert_eq!(deserialized_position.owner, owner);
+        assert_eq!(deserialized_position.staked_lp_tokens, staked_lp_tokens);
+        assert_eq!(deserialized_position.reward_debts[0], reward_debt);
+        assert_eq!(deserialized_position.rewards_earned[0], reward_earned);
+        assert_eq!(deserialized_position.rewards_earned[1], Q64_128::from_u64(0));
+
+        let mut serialized_data = Vec::new();
+        deserialized_position.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
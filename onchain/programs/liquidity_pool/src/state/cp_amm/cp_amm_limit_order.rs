@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+
+/// Limit order resting on top of a `CpAmm`.
+///
+/// The order escrows its input tokens in its associated token account and is executed against the pool
+/// by the permissionless `fill_cp_amm_limit_orders` crank once the pool's `base_quote_ratio_sqrt` crosses the target:
+/// a base → quote order is triggered when the ratio falls to the target or below (the base price rose),
+/// a quote → base order when it rises to the target or above (the base price fell).
+/// The order is filled as a whole and only if the amount received by the owner, after the crank fee and
+/// the output mint transfer fee, covers `min_received_amount`.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmLimitOrder {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the `CpAmm` the order is executed against.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// Public key of the order owner.
+    owner: Pubkey, // 32 bytes
+
+    /// Little-endian bytes of the `id`, used as a PDA seed.
+    id_seed: [u8; 8], // 8 bytes
+
+    /// Identifier of the order, chosen by the owner to hold several orders on the same `CpAmm`.
+    id: u64, // 8 bytes
+
+    /// `true` if the order sells **base → quote**, `false` if it sells **quote → base**.
+    is_in_out: bool, // 1 byte
+
+    /// Amount of escrowed input tokens.
+    amount: u64, // 8 bytes
+
+    /// Minimal amount of output tokens the swap must return for the order to be filled.
+    min_received_amount: u64, // 8 bytes
+
+    /// Square root of the base/quote liquidity ratio triggering the order.
+    target_base_quote_ratio_sqrt: Q64_128, // 24 bytes
+
+    /// Timestamp the order was placed at.
+    placed_timestamp: i64, // 8 bytes
+}
+
+impl CpAmmLimitOrder {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_limit_order";
+
+    /// Share of the swap result, in basis points, paid to the cranker filling the order.
+    pub const CRANK_FEE_BASIS_POINTS: u16 = 10;
+
+    /// Returns the seeds for signing with the PDA.
+    ///
+    /// The PDA is derived using the `SEED`, the `cp_amm`, the `owner`, the `id` and the `bump` value.
+    pub fn seeds(&self) -> [&[u8]; 5] {
+        [Self::SEED, self.cp_amm.as_ref(), self.owner.as_ref(), self.id_seed.as_ref(), self.bump.as_ref()]
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[inline]
+    pub fn is_in_out(&self) -> bool {
+        self.is_in_out
+    }
+
+    #[inline]
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    #[inline]
+    pub fn min_received_amount(&self) -> u64 {
+        self.min_received_amount
+    }
+
+    #[inline]
+    pub fn target_base_quote_ratio_sqrt(&self) -> Q64_128 {
+        self.target_base_quote_ratio_sqrt
+    }
+
+    #[inline]
+    pub fn placed_timestamp(&self) -> i64 {
+        self.placed_timestamp
+    }
+
+    /// Checks whether the pool ratio has crossed the order target.
+    ///
+    /// # Parameters
+    /// - `base_quote_ratio_sqrt`: The current square root of the pool base/quote liquidity ratio.
+    pub fn is_triggered(&self, base_quote_ratio_sqrt: Q64_128) -> bool {
+        if self.is_in_out {
+            base_quote_ratio_sqrt <= self.target_base_quote_ratio_sqrt
+        } else {
+            base_quote_ratio_sqrt >= self.target_base_quote_ratio_sqrt
+        }
+    }
+
+    /// Splits the swap result of a filled order between the owner and the cranker.
+    ///
+    /// # Parameters
+    /// - `received_amount`: The amount of output tokens returned by the swap.
+    ///
+    /// # Returns
+    /// - `Ok((owner_amount, crank_fee))`.
+    /// - `Err(ErrorCode)` if the crank fee exceeds the swap result.
+    pub fn split_received_amount(received_amount: u64) -> Result<(u64, u64)> {
+        let crank_fee = (received_amount as u128 * Self::CRANK_FEE_BASIS_POINTS as u128 / 10_000) as u64;
+        let owner_amount = received_amount.checked_sub(crank_fee).ok_or(ErrorCode::SwapOverflowError)?;
+        Ok((owner_amount, crank_fee))
+    }
+
+    /// Calculates the output tokens the input amount is worth at the target ratio, before any fees.
+    ///
+    /// The ratio is the square root of base/quote liquidity, so the base price in quote tokens is `1 / ratio²`.
+    ///
+    /// # Parameters
+    /// - `amount`: The amount of input tokens.
+    /// - `target_base_quote_ratio_sqrt`: Pool ratio triggering the order.
+    /// - `is_in_out`: Direction of the order.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the rounded down output amount, `None` on overflow or zero target.
+    pub fn calculate_target_received_amount(amount: u64, target_base_quote_ratio_sqrt: Q64_128, is_in_out: bool) -> Option<u64> {
+        let amount = Q64_128::from_u64(amount);
+        let target_received_amount = if is_in_out {
+            amount.checked_div(target_base_quote_ratio_sqrt)?.checked_div(target_base_quote_ratio_sqrt)?
+        } else {
+            amount.checked_mul(target_base_quote_ratio_sqrt)?.checked_mul(target_base_quote_ratio_sqrt)?
+        };
+        Some(target_received_amount.as_u64())
+    }
+}
+
+impl CpAmmLimitOrder {
+    /// Initializes the order.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: Public key of the `CpAmm` the order is executed against.
+    /// - `owner`: Public key of the order owner.
+    /// - `id`: Identifier of the order.
+    /// - `is_in_out`: Direction of the order.
+    /// - `amount`: Amount of escrowed input tokens.
+    /// - `min_received_amount`: Minimal amount of output tokens.
+    /// - `target_base_quote_ratio_sqrt`: Pool ratio triggering the order.
+    /// - `placed_timestamp`: Current timestamp.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    ///
+    /// # Errors
+    /// - `LimitOrderAmountIsZero` if `amount` or `min_received_amount` is zero.
+    /// - `InvalidLimitOrderTarget` if the target ratio is zero or the amount it implies overflows.
+    /// - `LimitOrderMinimumBelowTarget` if `min_received_amount` is below the amount implied by the target ratio,
+    ///   so that a fill pushed past the target within the same transaction can't pay out less than the target price.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn initialize(
+        &mut self,
+        cp_amm: Pubkey,
+        owner: Pubkey,
+        id: u64,
+        is_in_out: bool,
+        amount: u64,
+        min_received_amount: u64,
+        target_base_quote_ratio_sqrt: Q64_128,
+        placed_timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(amount > 0 && min_received_amount > 0, ErrorCode::LimitOrderAmountIsZero);
+        require!(!target_base_quote_ratio_sqrt.is_zero(), ErrorCode::InvalidLimitOrderTarget);
+        let target_received_amount = Self::calculate_target_received_amount(amount, target_base_quote_ratio_sqrt, is_in_out).ok_or(ErrorCode::InvalidLimitOrderTarget)?;
+        require!(min_received_amount >= target_received_amount, ErrorCode::LimitOrderMinimumBelowTarget);
+        self.bump = [bump];
+        self.cp_amm = cp_amm;
+        self.owner = owner;
+        self.id_seed = id.to_le_bytes();
+        self.id = id;
+        self.is_in_out = is_in_out;
+        self.amount = amount;
+        self.min_received_amount = min_received_amount;
+        self.target_base_quote_ratio_sqrt = target_base_quote_ratio_sqrt;
+        self.placed_timestamp = placed_timestamp;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_limit_order_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn empty_order() -> CpAmmLimitOrder {
+        CpAmmLimitOrder {
+            bump: [0],
+            cp_amm: Pubkey::default(),
+            owner: Pubkey::default(),
+            id_seed: [0; 8],
+            id: 0,
+            is_in_out: false,
+            amount: 0,
+            min_received_amount: 0,
+            target_base_quote_ratio_sqrt: Q64_128::default(),
+            placed_timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_cp_amm_limit_order_initialize() {
+        let mut order = empty_order();
+        let target = Q64_128::from_u64(2);
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, true, 0, 10, target, 100, 1).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, true, 10, 0, target, 100, 1).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, true, 10, 10, Q64_128::default(), 100, 1).is_err());
+        // 1_000 base is worth 250 quote at a ratio of 2², 4_000 quote is worth 16_000 base
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, true, 1_000, 249, target, 100, 1).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, false, 4_000, 15_999, target, 100, 1).is_err());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, true, 1_000, 250, target, 100, 1).is_ok());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, false, 4_000, 16_000, target, 100, 1).is_ok());
+        assert!(order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1, false, u64::MAX, u64::MAX, target, 100, 1).is_err());
+
+        let cp_amm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        order.initialize(cp_amm, owner, 7, true, 1_000, 900, target, 100, 42).unwrap();
+        assert_eq!(order.bump(), 42);
+        assert_eq!(order.cp_amm(), &cp_amm);
+        assert_eq!(order.owner(), &owner);
+        assert_eq!(order.id(), 7);
+        assert_eq!(order.seeds()[3], 7u64.to_le_bytes().as_ref());
+        assert!(order.is_in_out());
+        assert_eq!(order.amount(), 1_000);
+        assert_eq!(order.min_received_amount(), 900);
+        assert_eq!(order.target_base_quote_ratio_sqrt(), target);
+        assert_eq!(order.placed_timestamp(), 100);
+    }
+
+    #[test]
+    fn test_cp_amm_limit_order_is_triggered() {
+        let mut order = empty_order();
+        order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0, true, 10, 10, Q64_128::from_u64(4), 0, 1).unwrap();
+        assert!(!order.is_triggered(Q64_128::from_u64(5)));
+        assert!(order.is_triggered(Q64_128::from_u64(4)));
+        assert!(order.is_triggered(Q64_128::from_u64(3)));
+
+        order.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0, false, 10, 160, Q64_128::from_u64(4), 0, 1).unwrap();
+        assert!(!order.is_triggered(Q64_128::from_u64(3)));
+        assert!(order.is_triggered(Q64_128::from_u64(4)));
+        assert!(order.is_triggered(Q64_128::from_u64(5)));
+    }
+
+    #[test]
+    fn test_cp_amm_limit_order_split_received_amount() {
+        assert_eq!(CpAmmLimitOrder::split_received_amount(1_000_000).unwrap(), (999_000, 1_000));
+        assert_eq!(CpAmmLimitOrder::split_received_amount(999).unwrap(), (999, 0));
+        let (owner_amount, crank_fee) = CpAmmLimitOrder::split_received_amount(u64::MAX).unwrap();
+        assert_eq!(owner_amount + crank_fee, u64::MAX);
+    }
+
+    #[test]
+    fn test_cp_amm_limit_order_data_layout() {
+        let bump = 42u8;
+        let cp_amm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let id = 7u64;
+        let is_in_out = true;
+        let amount = 1_000u64;
+        let min_received_amount = 900u64;
+        let target_base_quote_ratio_sqrt = Q64_128::from_u64(3);
+        let placed_timestamp = 100i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 130];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmmLimitOrder::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&id.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&id.to_le_bytes()); offset += 8;
+        data[offset] = is_in_out as u8; offset += 1;
+        data[offset..offset + 8].copy_from_slice(&amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&min_received_amount.to_le_bytes()); offset += 8;
+        data[offset..offset + 16].copy_from_slice(&target_base_quote_ratio_sqrt.get_fractional_bits().to_le_bytes()); offset += 16;
+        data[offset..offset + 8].copy_from_slice(&target_base_quote_ratio_sqrt.get_integer_bits().to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&placed_timestamp.to_le_bytes()); offset += 8;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 130);
+
+        let deserialized_order = CpAmmLimitOrder::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_order.bump(), bump);
+        assert_eq!(deserialized_order.cp_amm, cp_amm);
+        assert_eq!(deserialized_order.owner, owner);
+        assert_eq!(deserialized_order.id_seed, id.to_le_bytes());
+        assert_eq!(deserialized_order.id, id);
+        assert_eq!(deserialized_order.is_in_out, is_in_out);
+        assert_eq!(deserialized_order.amount, amount);
+        assert_eq!(deserialized_order.min_received_amount, min_received_amount);
+        assert_eq!(deserialized_order.target_base_quote_ratio_sqrt, target_base_quote_ratio_sqrt);
+        assert_eq!(deserialized_order.placed_timestamp, placed_timestamp);
+
+        let mut serialized_data = Vec::new();
+        deserialized_order.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
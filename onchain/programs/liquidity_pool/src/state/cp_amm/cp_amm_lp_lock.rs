@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+use crate::error::ErrorCode;
+
+/// Lock of the launch LP tokens of a `CpAmm` creator.
+///
+/// The locked LP tokens are held by the lock's associated token account and are released
+/// linearly between `start_timestamp` and `end_timestamp`, nothing being claimable before `cliff_timestamp`.
+/// A plain time-lock is a schedule with `cliff_timestamp` equal to `end_timestamp`.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmLpLock {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the locked `CpAmm`.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// Public key of the LP tokens owner.
+    owner: Pubkey, // 32 bytes
+
+    /// Total amount of locked LP tokens.
+    locked_lp_tokens: u64, // 8 bytes
+
+    /// Amount of LP tokens already claimed by the owner.
+    claimed_lp_tokens: u64, // 8 bytes
+
+    /// Timestamp the vesting starts at.
+    start_timestamp: i64, // 8 bytes
+
+    /// Timestamp before which no LP tokens can be claimed.
+    cliff_timestamp: i64, // 8 bytes
+
+    /// Timestamp all LP tokens are unlocked at.
+    end_timestamp: i64, // 8 bytes
+}
+
+impl CpAmmLpLock {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_lp_lock";
+
+    /// Maximal share of the launch LP tokens, in basis points, that can be locked.
+    pub const LOCKED_LP_SHARE_MAX_BASIS_POINTS: u16 = 10_000;
+
+    /// Returns the seeds for signing with the PDA.
+    ///
+    /// The PDA is derived using the `SEED`, the `cp_amm`, the `owner` and the `bump` value.
+    pub fn seeds(&self) -> [&[u8]; 4] {
+        [Self::SEED, self.cp_amm.as_ref(), self.owner.as_ref(), self.bump.as_ref()]
+    }
+
+    /// Calculates the part of the launch LP tokens to lock.
+    ///
+    /// # Parameters
+    /// - `launch_liquidity`: The LP tokens minted to the creator at launch.
+    /// - `locked_lp_share_basis_points`: The share to lock, in basis points.
+    ///
+    /// # Errors
+    /// - `InvalidLpLockShare` if the share is zero or exceeds 100%.
+    /// - `LpLockAmountIsZero` if the share rounds down to zero LP tokens.
+    pub fn calculate_locked_lp_tokens(launch_liquidity: u64, locked_lp_share_basis_points: u16) -> Result<u64> {
+        require!(
+            locked_lp_share_basis_points > 0 && locked_lp_share_basis_points <= Self::LOCKED_LP_SHARE_MAX_BASIS_POINTS,
+            ErrorCode::InvalidLpLockShare
+        );
+        let locked_lp_tokens = (launch_liquidity as u128 * locked_lp_share_basis_points as u128
+            / Self::LOCKED_LP_SHARE_MAX_BASIS_POINTS as u128) as u64;
+        require!(locked_lp_tokens > 0, ErrorCode::LpLockAmountIsZero);
+        Ok(locked_lp_tokens)
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    #[inline]
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    #[inline]
+    pub fn locked_lp_tokens(&self) -> u64 {
+        self.locked_lp_tokens
+    }
+
+    #[inline]
+    pub fn claimed_lp_tokens(&self) -> u64 {
+        self.claimed_lp_tokens
+    }
+
+    #[inline]
+    pub fn start_timestamp(&self) -> i64 {
+        self.start_timestamp
+    }
+
+    #[inline]
+    pub fn cliff_timestamp(&self) -> i64 {
+        self.cliff_timestamp
+    }
+
+    #[inline]
+    pub fn end_timestamp(&self) -> i64 {
+        self.end_timestamp
+    }
+
+    /// Calculates the amount of LP tokens vested at the given timestamp, claimed ones included.
+    pub fn vested_lp_tokens(&self, timestamp: i64) -> u64 {
+        if timestamp < self.cliff_timestamp {
+            0
+        } else if timestamp >= self.end_timestamp {
+            self.locked_lp_tokens
+        } else {
+            let elapsed = (timestamp - self.start_timestamp) as u128;
+            let duration = (self.end_timestamp - self.start_timestamp) as u128;
+            (self.locked_lp_tokens as u128 * elapsed / duration) as u64
+        }
+    }
+
+    /// Calculates the amount of LP tokens the owner can claim at the given timestamp.
+    ///
+    /// # Errors
+    /// - `NothingToClaimFromLpLock` if there are no unlocked LP tokens left to claim.
+    pub fn get_claim_payload(&self, timestamp: i64) -> Result<u64> {
+        let claimable_lp_tokens = self.vested_lp_tokens(timestamp).saturating_sub(self.claimed_lp_tokens);
+        require!(claimable_lp_tokens > 0, ErrorCode::NothingToClaimFromLpLock);
+        Ok(claimable_lp_tokens)
+    }
+}
+
+impl CpAmmLpLock {
+    /// Initializes the lock.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: Public key of the locked `CpAmm`.
+    /// - `owner`: Public key of the LP tokens owner.
+    /// - `locked_lp_tokens`: Amount of locked LP tokens.
+    /// - `start_timestamp`: Timestamp the vesting starts at, the launch time.
+    /// - `cliff_timestamp`: Timestamp before which nothing can be claimed.
+    /// - `end_timestamp`: Timestamp all LP tokens are unlocked at.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    ///
+    /// # Errors
+    /// - `InvalidLpLockSchedule` unless `start_timestamp <= cliff_timestamp <= end_timestamp` and `start_timestamp < end_timestamp`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn initialize(
+        &mut self,
+        cp_amm: Pubkey,
+        owner: Pubkey,
+        locked_lp_tokens: u64,
+        start_timestamp: i64,
+        cliff_timestamp: i64,
+        end_timestamp: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            start_timestamp <= cliff_timestamp && cliff_timestamp <= end_timestamp && start_timestamp < end_timestamp,
+            ErrorCode::InvalidLpLockSchedule
+        );
+        self.bump = [bump];
+        self.cp_amm = cp_amm;
+        self.owner = owner;
+        self.locked_lp_tokens = locked_lp_tokens;
+        self.claimed_lp_tokens = 0;
+        self.start_timestamp = start_timestamp;
+        self.cliff_timestamp = cliff_timestamp;
+        self.end_timestamp = end_timestamp;
+        Ok(())
+    }
+
+    /// Records claimed LP tokens.
+    pub(crate) fn claim(&mut self, lp_tokens: u64) {
+        self.claimed_lp_tokens = self.claimed_lp_tokens.checked_add(lp_tokens).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_lp_lock_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    fn empty_lock() -> CpAmmLpLock {
+        CpAmmLpLock {
+            bump: [0],
+            cp_amm: Pubkey::default(),
+            owner: Pubkey::default(),
+            locked_lp_tokens: 0,
+            claimed_lp_tokens: 0,
+            start_timestamp: 0,
+            cliff_timestamp: 0,
+            end_timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_calculate_locked_lp_tokens() {
+        assert_eq!(CpAmmLpLock::calculate_locked_lp_tokens(1_000_000, 2_500).unwrap(), 250_000);
+        assert_eq!(CpAmmLpLock::calculate_locked_lp_tokens(1_000_000, 10_000).unwrap(), 1_000_000);
+        assert!(CpAmmLpLock::calculate_locked_lp_tokens(1_000_000, 0).is_err());
+        assert!(CpAmmLpLock::calculate_locked_lp_tokens(1_000_000, 10_001).is_err());
+        assert!(CpAmmLpLock::calculate_locked_lp_tokens(1, 1).is_err());
+    }
+
+    #[test]
+    fn test_cp_amm_lp_lock_initialize() {
+        let mut lock = empty_lock();
+        assert!(lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 100, 10, 5, 20, 1).is_err());
+        assert!(lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 100, 10, 30, 20, 1).is_err());
+        assert!(lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 100, 10, 10, 10, 1).is_err());
+
+        let cp_amm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        lock.initialize(cp_amm, owner, 100, 10, 15, 20, 42).unwrap();
+        assert_eq!(lock.bump(), 42);
+        assert_eq!(lock.cp_amm(), &cp_amm);
+        assert_eq!(lock.owner(), &owner);
+        assert_eq!(lock.locked_lp_tokens(), 100);
+        assert_eq!(lock.claimed_lp_tokens(), 0);
+        assert_eq!((lock.start_timestamp(), lock.cliff_timestamp(), lock.end_timestamp()), (10, 15, 20));
+    }
+
+    #[test]
+    fn test_cp_amm_lp_lock_linear_vesting() {
+        let mut lock = empty_lock();
+        lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1_000, 100, 150, 200, 1).unwrap();
+
+        assert_eq!(lock.vested_lp_tokens(100), 0);
+        assert_eq!(lock.vested_lp_tokens(149), 0);
+        assert_eq!(lock.vested_lp_tokens(150), 500);
+        assert_eq!(lock.vested_lp_tokens(175), 750);
+        assert_eq!(lock.vested_lp_tokens(200), 1_000);
+        assert_eq!(lock.vested_lp_tokens(1_000), 1_000);
+
+        assert!(lock.get_claim_payload(120).is_err());
+        let claimable = lock.get_claim_payload(160).unwrap();
+        assert_eq!(claimable, 600);
+        lock.claim(claimable);
+        assert!(lock.get_claim_payload(160).is_err());
+        assert_eq!(lock.get_claim_payload(250).unwrap(), 400);
+    }
+
+    #[test]
+    fn test_cp_amm_lp_lock_time_lock() {
+        let mut lock = empty_lock();
+        lock.initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1_000, 100, 200, 200, 1).unwrap();
+
+        assert_eq!(lock.vested_lp_tokens(199), 0);
+        assert_eq!(lock.vested_lp_tokens(200), 1_000);
+    }
+
+    #[test]
+    fn test_cp_amm_lp_lock_data_layout() {
+        let bump = 42u8;
+        let cp_amm = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let locked_lp_tokens = 1_000u64;
+        let claimed_lp_tokens = 300u64;
+        let start_timestamp = 100i64;
+        let cliff_timestamp = 150i64;
+        let end_timestamp = 200i64;
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 105];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmmLpLock::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(owner.as_ref()); offset += 32;
+        data[offset..offset + 8].copy_from_slice(&locked_lp_tokens.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&claimed_lp_tokens.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&start_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&cliff_timestamp.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&end_timestamp.to_le_bytes()); offset += 8;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 105);
+
+        let deserialized_lock = CpAmmLpLock::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_lock.bump(), bump);
+        assert_eq!(deserialized_lock.cp_amm, cp_amm);
+        assert_eq!(deserialized_lock.owner, owner);
+        assert_eq!(deserialized_lock.locked_lp_tokens, locked_lp_tokens);
+        assert_eq!(deserialized_lock.claimed_lp_tokens, claimed_lp_tokens);
+        assert_eq!(deserialized_lock.start_timestamp, start_timestamp);
+        assert_eq!(deserialized_lock.cliff_timestamp, cliff_timestamp);
+        assert_eq!(deserialized_lock.end_timestamp, end_timestamp);
+
+        let mut serialized_data = Vec::new();
+        deserialized_lock.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
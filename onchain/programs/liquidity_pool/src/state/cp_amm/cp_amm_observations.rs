@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+
+/// Snapshot of the `CpAmm` cumulative prices at a specific moment.
+///
+/// Cumulative prices are time-weighted sums of the pool prices, so the time-weighted
+/// average price (TWAP) between two observations is their difference divided by the elapsed time.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PriceObservation {
+    /// Unix timestamp of the observation.
+    timestamp: i64, // 8 bytes
+
+    /// Cumulative base-per-quote price, stored as a wrapping Q64.128 fixed-point number.
+    cumulative_base_per_quote_price: Q64_128, // 24 bytes
+
+    /// Cumulative quote-per-base price, stored as a wrapping Q64.128 fixed-point number.
+    cumulative_quote_per_base_price: Q64_128, // 24 bytes
+}
+
+impl PriceObservation {
+    /// Creates a new `PriceObservation` instance.
+    ///
+    /// # Parameters
+    /// - `timestamp`: Unix timestamp of the observation.
+    /// - `cumulative_base_per_quote_price`: Cumulative base-per-quote price at `timestamp`.
+    /// - `cumulative_quote_per_base_price`: Cumulative quote-per-base price at `timestamp`.
+    pub fn new(timestamp: i64, cumulative_base_per_quote_price: Q64_128, cumulative_quote_per_base_price: Q64_128) -> Self {
+        Self {
+            timestamp,
+            cumulative_base_per_quote_price,
+            cumulative_quote_per_base_price,
+        }
+    }
+
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    #[inline]
+    pub fn cumulative_base_per_quote_price(&self) -> Q64_128 {
+        self.cumulative_base_per_quote_price
+    }
+
+    #[inline]
+    pub fn cumulative_quote_per_base_price(&self) -> Q64_128 {
+        self.cumulative_quote_per_base_price
+    }
+
+    /// Calculates the time-weighted average prices between an older observation and this one.
+    ///
+    /// # Parameters
+    /// - `older_observation`: Observation taken before this one.
+    ///
+    /// # Returns
+    /// - `Some(Twap)` with the average prices over the window.
+    /// - `None` if the observations do not form a positive time window.
+    pub fn calculate_twap(&self, older_observation: &PriceObservation) -> Option<Twap> {
+        if self.timestamp <= older_observation.timestamp {
+            return None;
+        }
+        let window = self.timestamp.checked_sub(older_observation.timestamp)? as u64;
+        let window_q = Q64_128::from_u64(window);
+
+        let base_per_quote_price = self.cumulative_base_per_quote_price
+            .wrapping_sub(older_observation.cumulative_base_per_quote_price)
+            .checked_div(window_q)?;
+        let quote_per_base_price = self.cumulative_quote_per_base_price
+            .wrapping_sub(older_observation.cumulative_quote_per_base_price)
+            .checked_div(window_q)?;
+
+        Some(Twap {
+            window,
+            base_per_quote_price,
+            quote_per_base_price,
+        })
+    }
+}
+
+/// Time-weighted average prices of a `CpAmm` over a time window.
+///
+/// # Fields
+/// - `window`: Length of the averaging window in seconds.
+/// - `base_per_quote_price`: Average amount of base tokens per one quote token.
+/// - `quote_per_base_price`: Average amount of quote tokens per one base token.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct Twap {
+    pub window: u64,
+    pub base_per_quote_price: Q64_128,
+    pub quote_per_base_price: Q64_128,
+}
+
+/// Ring buffer of `CpAmm` price observations.
+///
+/// Stored in a separate PDA, so integrators can compute a TWAP over a past window
+/// without keeping their own snapshots of the pool's cumulative prices.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmObservations {
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Public key of the observed `CpAmm`.
+    cp_amm: Pubkey, // 32 bytes
+
+    /// Index of the most recent observation.
+    last_index: u16, // 2 bytes
+
+    /// Number of recorded observations, capped by the buffer capacity.
+    observations_count: u16, // 2 bytes
+
+    /// Recorded observations.
+    observations: [PriceObservation; CpAmmObservations::CAPACITY], // 56 * CAPACITY bytes
+}
+
+impl CpAmmObservations {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_observations";
+
+    /// Number of observations stored in the ring buffer.
+    pub const CAPACITY: usize = 32;
+
+    /// Minimal number of seconds between two recorded observations.
+    pub const MIN_OBSERVATION_INTERVAL: i64 = 60;
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    #[inline]
+    pub fn cp_amm(&self) -> &Pubkey {
+        &self.cp_amm
+    }
+
+    #[inline]
+    pub fn observations_count(&self) -> u16 {
+        self.observations_count
+    }
+
+    /// Returns the most recent observation, if any.
+    pub fn last_observation(&self) -> Option<&PriceObservation> {
+        if self.observations_count == 0 {
+            return None;
+        }
+        self.observations.get(self.last_index as usize)
+    }
+
+    /// Returns the most recent observation taken at or before `timestamp`, if any.
+    pub fn find_observation_before(&self, timestamp: i64) -> Option<&PriceObservation> {
+        let count = self.observations_count as usize;
+        (0..count)
+            .map(|offset| (self.last_index as usize + Self::CAPACITY - offset) % Self::CAPACITY)
+            .map(|index| &self.observations[index])
+            .find(|observation| observation.timestamp <= timestamp)
+    }
+}
+
+impl CpAmmObservations {
+    /// Initializes the observations buffer for a `CpAmm`.
+    ///
+    /// # Parameters
+    /// - `cp_amm`: Public key of the observed `CpAmm`.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, cp_amm: Pubkey, bump: u8) {
+        self.cp_amm = cp_amm;
+        self.bump = [bump];
+        self.last_index = 0;
+        self.observations_count = 0;
+    }
+
+    /// Records a new observation, overwriting the oldest one when the buffer is full.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the observation is recorded.
+    /// - `Err(ErrorCode)` if the observation is too close to the previous one.
+    pub(crate) fn record(&mut self, observation: PriceObservation) -> Result<()> {
+        if let Some(last_observation) = self.last_observation() {
+            require!(
+                observation.timestamp >= last_observation.timestamp + Self::MIN_OBSERVATION_INTERVAL,
+                ErrorCode::ObservationIntervalTooShort
+            );
+            self.last_index = ((self.last_index as usize + 1) % Self::CAPACITY) as u16;
+        }
+        self.observations[self.last_index as usize] = observation;
+        self.observations_count = (self.observations_count + 1).min(Self::CAPACITY as u16);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_observations_tests {
+    use super::*;
+
+    fn observation(timestamp: i64, cumulative_price: u64) -> PriceObservation {
+        PriceObservation::new(timestamp, Q64_128::from_u64(cumulative_price), Q64_128::from_u64(cumulative_price * 2))
+    }
+
+    /// Tests TWAP calculation between two observations.
+    #[test]
+    fn test_calculate_twap() {
+        let older_observation = observation(1_000, 10_000);
+        let newer_observation = observation(1_100, 30_000);
+
+        let twap = newer_observation.calculate_twap(&older_observation).unwrap();
+        assert_eq!(twap.window, 100);
+        assert_eq!(twap.base_per_quote_price, Q64_128::from_u64(200));
+        assert_eq!(twap.quote_per_base_price, Q64_128::from_u64(400));
+
+        assert!(older_observation.calculate_twap(&newer_observation).is_none());
+        assert!(newer_observation.calculate_twap(&newer_observation).is_none());
+    }
+
+    /// Tests TWAP calculation when cumulative prices wrapped around.
+    #[test]
+    fn test_calculate_twap_wrapped() {
+        let older_observation = PriceObservation::new(0, Q64_128::MAX, Q64_128::MAX);
+        let newer_observation = PriceObservation::new(10, Q64_128::from_u64(100).wrapping_add(Q64_128::MAX), Q64_128::from_u64(10).wrapping_add(Q64_128::MAX));
+
+        let twap = newer_observation.calculate_twap(&older_observation).unwrap();
+        assert_eq!(twap.base_per_quote_price, Q64_128::from_u64(10));
+        assert_eq!(twap.quote_per_base_price, Q64_128::from_u64(1));
+    }
+
+    /// Tests recording observations into the ring buffer.
+    #[test]
+    fn test_record_observations() {
+        let mut observations = CpAmmObservations {
+            bump: [0],
+            cp_amm: Pubkey::default(),
+            last_index: 0,
+            observations_count: 0,
+            observations: [PriceObservation::default(); CpAmmObservations::CAPACITY],
+        };
+        observations.initialize(Pubkey::new_unique(), 255);
+        assert!(observations.last_observation().is_none());
+
+        let interval = CpAmmObservations::MIN_OBSERVATION_INTERVAL;
+        observations.record(observation(interval, 1)).unwrap();
+        assert!(observations.record(observation(interval + 1, 2)).is_err());
+
+        for i in 2..=(CpAmmObservations::CAPACITY as i64 + 5) {
+            observations.record(observation(interval * i, i as u64)).unwrap();
+        }
+        assert_eq!(observations.observations_count() as usize, CpAmmObservations::CAPACITY);
+        assert_eq!(observations.last_observation().unwrap().timestamp(), interval * (CpAmmObservations::CAPACITY as i64 + 5));
+
+        let found = observations.find_observation_before(interval * 20 + 1).unwrap();
+        assert_eq!(found.timestamp(), interval * 20);
+        assert!(observations.find_observation_before(interval * 5).is_none());
+    }
+}
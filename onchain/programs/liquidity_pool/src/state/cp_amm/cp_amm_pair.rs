@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+/// Pair registry entry pointing at the canonical `CpAmm` of a token pair within an `AmmsConfig`.
+///
+/// The PDA is derived from the config and the pair mints sorted by their public keys,
+/// so routers can locate the pool of a pair without knowing its base/quote orientation
+/// and without the indexer.
+#[account]
+#[derive(InitSpace)]
+pub struct CpAmmPair {
+    /// Canonical bump seed for the account's PDA.
+    bump: u8, // 1 byte
+
+    /// Public key of the `AmmsConfig` the pair belongs to.
+    amms_config: Pubkey, // 32 bytes
+
+    /// The smaller of the pair mints.
+    mint_0: Pubkey, // 32 bytes
+
+    /// The greater of the pair mints.
+    mint_1: Pubkey, // 32 bytes
+
+    /// Public key of the canonical `CpAmm`, the default public key if there is none.
+    cp_amm: Pubkey, // 32 bytes
+}
+
+impl CpAmmPair {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"cp_amm_pair";
+
+    /// Sorts the pair mints the way they are used in the PDA seeds.
+    ///
+    /// # Returns
+    /// - The smaller and the greater of the mints.
+    #[inline]
+    pub fn sort_mints(mint_a: Pubkey, mint_b: Pubkey) -> (Pubkey, Pubkey) {
+        if mint_a < mint_b { (mint_a, mint_b) } else { (mint_b, mint_a) }
+    }
+
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.amms_config != Pubkey::default()
+    }
+
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline]
+    pub fn amms_config(&self) -> &Pubkey {
+        &self.amms_config
+    }
+
+    #[inline]
+    pub fn mint_0(&self) -> &Pubkey {
+        &self.mint_0
+    }
+
+    #[inline]
+    pub fn mint_1(&self) -> &Pubkey {
+        &self.mint_1
+    }
+
+    /// Returns the canonical `CpAmm` of the pair, if any.
+    #[inline]
+    pub fn cp_amm(&self) -> Option<&Pubkey> {
+        if self.cp_amm == Pubkey::default() {
+            None
+        } else {
+            Some(&self.cp_amm)
+        }
+    }
+}
+
+impl CpAmmPair {
+    /// Initializes the pair registry entry.
+    ///
+    /// # Parameters
+    /// - `amms_config`: Public key of the `AmmsConfig` the pair belongs to.
+    /// - `mint_a`, `mint_b`: The pair mints in any order.
+    /// - `bump`: The canonical bump seed for the account's PDA.
+    pub(crate) fn initialize(&mut self, amms_config: Pubkey, mint_a: Pubkey, mint_b: Pubkey, bump: u8) {
+        let (mint_0, mint_1) = Self::sort_mints(mint_a, mint_b);
+        self.bump = bump;
+        self.amms_config = amms_config;
+        self.mint_0 = mint_0;
+        self.mint_1 = mint_1;
+        self.cp_amm = Pubkey::default();
+    }
+
+    /// Makes `cp_amm` the canonical `CpAmm` of the pair if the pair has none yet.
+    ///
+    /// A pair can have many pools, later ones are created without replacing the canonical one.
+    ///
+    /// # Returns
+    /// - `true` if `cp_amm` became the canonical `CpAmm`.
+    pub(crate) fn register_cp_amm(&mut self, cp_amm: Pubkey) -> bool {
+        if self.cp_amm().is_some() {
+            return false;
+        }
+        self.cp_amm = cp_amm;
+        true
+    }
+
+    /// Points the registry at a new canonical `CpAmm`, or clears it with `None`.
+    pub(crate) fn update_cp_amm(&mut self, cp_amm: Option<Pubkey>) {
+        self.cp_amm = cp_amm.unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod cp_amm_pair_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    #[test]
+    fn test_cp_amm_pair_initialize() {
+        let mut cp_amm_pair = CpAmmPair {
+            bump: 0,
+            amms_config: Pubkey::default(),
+            mint_0: Pubkey::default(),
+            mint_1: Pubkey::default(),
+            cp_amm: Pubkey::default(),
+        };
+        assert!(!cp_amm_pair.is_initialized());
+
+        let amms_config = Pubkey::new_unique();
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        cp_amm_pair.initialize(amms_config, mint_b, mint_a, 42);
+
+        assert!(cp_amm_pair.is_initialized());
+        assert_eq!(cp_amm_pair.bump(), 42);
+        assert_eq!(cp_amm_pair.amms_config(), &amms_config);
+        assert_eq!((*cp_amm_pair.mint_0(), *cp_amm_pair.mint_1()), CpAmmPair::sort_mints(mint_a, mint_b));
+        assert!(cp_amm_pair.mint_0() < cp_amm_pair.mint_1());
+        assert_eq!(cp_amm_pair.cp_amm(), None);
+
+        let cp_amm = Pubkey::new_unique();
+        cp_amm_pair.update_cp_amm(Some(cp_amm));
+        assert_eq!(cp_amm_pair.cp_amm(), Some(&cp_amm));
+        cp_amm_pair.update_cp_amm(None);
+        assert_eq!(cp_amm_pair.cp_amm(), None);
+    }
+
+    #[test]
+    fn test_cp_amm_pair_register_cp_amm() {
+        let mut cp_amm_pair = CpAmmPair {
+            bump: 0,
+            amms_config: Pubkey::default(),
+            mint_0: Pubkey::default(),
+            mint_1: Pubkey::default(),
+            cp_amm: Pubkey::default(),
+        };
+        cp_amm_pair.initialize(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), 42);
+
+        let first_cp_amm = Pubkey::new_unique();
+        assert!(cp_amm_pair.register_cp_amm(first_cp_amm));
+        assert_eq!(cp_amm_pair.cp_amm(), Some(&first_cp_amm));
+
+        // A second pool for the same pair is created without taking over the registry
+        let second_cp_amm = Pubkey::new_unique();
+        assert!(!cp_amm_pair.register_cp_amm(second_cp_amm));
+        assert_eq!(cp_amm_pair.cp_amm(), Some(&first_cp_amm));
+
+        // Once cleared, the next pool becomes canonical
+        cp_amm_pair.update_cp_amm(None);
+        assert!(cp_amm_pair.register_cp_amm(second_cp_amm));
+        assert_eq!(cp_amm_pair.cp_amm(), Some(&second_cp_amm));
+    }
+
+    #[test]
+    fn test_cp_amm_pair_sort_mints() {
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(CpAmmPair::sort_mints(mint_a, mint_b), CpAmmPair::sort_mints(mint_b, mint_a));
+    }
+
+    #[test]
+    fn test_cp_amm_pair_data_layout() {
+        let bump = 42u8;
+        let amms_config = Pubkey::new_unique();
+        let mint_0 = Pubkey::new_unique();
+        let mint_1 = Pubkey::new_unique();
+        let cp_amm = Pubkey::new_unique();
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 129];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&CpAmmPair::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = bump; offset += 1;
+        data[offset..offset + 32].copy_from_slice(amms_config.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(mint_0.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(mint_1.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(cp_amm.as_ref()); offset += 32;
+
+        assert_eq!(offset, ANCHOR_DISCRIMINATOR + 129);
+
+        let deserialized_cp_amm_pair = CpAmmPair::try_deserialize(&mut data.as_ref()).unwrap();
+
+        assert_eq!(deserialized_cp_amm_pair.bump, bump);
+        assert_eq!(deserialized_cp_amm_pair.amms_config, amms_config);
+        assert_eq!(deserialized_cp_amm_pair.mint_0, mint_0);
+        assert_eq!(deserialized_cp_amm_pair.mint_1, mint_1);
+        assert_eq!(deserialized_cp_amm_pair.cp_amm, cp_amm);
+
+        let mut serialized_data = Vec::new();
+        deserialized_cp_amm_pair.try_serialize(&mut serialized_data).unwrap();
+        assert_eq!(serialized_data.as_slice(), data.as_ref());
+    }
+}
@@ -2,7 +2,19 @@
 mod cp_amm;
 mod cp_amm_calculate;
 mod cp_amm_core;
+mod cp_amm_observations;
+mod cp_amm_pair;
+mod cp_amm_lp_lock;
+mod cp_amm_farm;
+mod cp_amm_farm_position;
+mod cp_amm_limit_order;
 
 pub use cp_amm::*;
 pub use cp_amm_core::*;
+pub use cp_amm_observations::*;
+pub use cp_amm_pair::*;
+pub use cp_amm_lp_lock::*;
+pub use cp_amm_farm::*;
+pub use cp_amm_farm_position::*;
+pub use cp_amm_limit_order::*;
 pub(crate) use cp_amm_calculate::*;
\ No newline at end of file
@@ -1,6 +1,10 @@
+mod allowed_creator;
 mod amms_config;
 mod amms_configs_manager;
+pub mod cl_amm;
 pub mod cp_amm;
+pub mod stable_amm;
 
 pub use amms_configs_manager::*;
-pub use amms_config::*;
\ No newline at end of file
+pub use amms_config::*;
+pub use allowed_creator::*;
\ No newline at end of file
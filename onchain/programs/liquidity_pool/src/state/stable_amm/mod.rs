@@ -0,0 +1,7 @@
+mod stable_amm_pool;
+mod stable_amm_calculate;
+mod stable_amm_core;
+
+pub use stable_amm_pool::*;
+pub use stable_amm_core::*;
+pub(crate) use stable_amm_calculate::*;
@@ -0,0 +1,449 @@
+use anchor_lang::prelude::*;
+use utilities::math::U192;
+use crate::error::ErrorCode;
+use crate::state::stable_amm::StableAmmCore;
+
+/// A trait for implementing core calculations and constants for a StableSwap Automated Market Maker.
+///
+/// The pool follows the Curve StableSwap invariant for two tokens:
+/// `A * n^n * (x + y) + D = A * n^n * D + D^(n + 1) / (n^n * x * y)`, where `n = 2`,
+/// `x` and `y` are the pool liquidities, `A` is the amplification coefficient and `D` is the invariant.
+/// The invariant and the opposite liquidity are found with Newton's method.
+pub(crate) trait StableAmmCalculate: StableAmmCore {
+    /// The number of decimals for the LP token.
+    const LP_MINT_INITIAL_DECIMALS: u8 = 5;
+
+    /// The maximum allowable fee rate, expressed in basis points.
+    ///
+    /// - 1 basis point = 0.01%.
+    /// - `FEE_MAX_BASIS_POINTS = 10000` corresponds to a maximum fee rate of 100%.
+    const FEE_MAX_BASIS_POINTS: u128 = 10000;
+
+    /// The initial amount of locked LP tokens in the pool.
+    ///
+    /// - Calculated as `10^LP_MINT_INITIAL_DECIMALS`.
+    const INITIAL_LOCKED_LP_TOKENS: u64 = 10_u64.pow(Self::LP_MINT_INITIAL_DECIMALS as u32);
+
+    /// The minimal allowed amplification coefficient.
+    const MIN_AMPLIFICATION: u64 = 1;
+
+    /// The maximal allowed amplification coefficient.
+    const MAX_AMPLIFICATION: u64 = 1_000_000;
+
+    /// The maximal factor the amplification coefficient can be changed by within a single ramp.
+    const MAX_AMPLIFICATION_CHANGE: u64 = 10;
+
+    /// The minimal duration of an amplification coefficient ramp in seconds.
+    const MIN_AMPLIFICATION_RAMP_DURATION: i64 = 86_400;
+
+    /// The maximal number of Newton's method iterations.
+    const MAX_NEWTON_ITERATIONS: usize = 64;
+
+    /// The tolerance for the liquidity ratio change after providing liquidity.
+    ///
+    /// - Defined as a denominator of the allowed relative difference, representing a tolerance of `0.00001%`.
+    const ADJUST_LIQUIDITY_RATIO_TOLERANCE_DENOMINATOR: u64 = 10_000_000;
+
+    /// The tolerance for the invariant decrease after a swap, caused by Newton's method precision.
+    const SWAP_INVARIANT_TOLERANCE: u128 = 1;
+
+    /// Calculates the initial LP token supply and locked liquidity during pool launch.
+    ///
+    /// # Parameters
+    /// - `invariant`: The StableSwap invariant of the launch liquidity.
+    ///
+    /// # Returns
+    /// - `Ok((u64, u64))` with the initial LP token supply and locked liquidity.
+    /// - `Err(ErrorCode)` if the supply is too small.
+    fn calculate_launch_lp_tokens(invariant: u128) -> Result<(u64, u64)> {
+        let lp_tokens_supply = u64::try_from(invariant).map_err(|_| ErrorCode::LpTokensCalculationFailed)?;
+        require!(lp_tokens_supply > 0, ErrorCode::LpTokensCalculationFailed);
+        let initial_locked_liquidity = Self::INITIAL_LOCKED_LP_TOKENS;
+        let difference = lp_tokens_supply
+            .checked_sub(initial_locked_liquidity)
+            .ok_or(ErrorCode::LaunchLiquidityTooSmall)?;
+        require!(difference >= initial_locked_liquidity * 3, ErrorCode::LaunchLiquidityTooSmall);
+        Ok((lp_tokens_supply, initial_locked_liquidity))
+    }
+
+    /// Calculates the amount of LP tokens to mint based on the invariant growth.
+    ///
+    /// # Parameters
+    /// - `invariant`: The invariant before providing liquidity.
+    /// - `new_invariant`: The invariant after providing liquidity.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the amount of LP tokens to mint if the calculation is valid.
+    /// - `None` if the calculation fails (e.g., due to underflow or zero tokens).
+    fn calculate_lp_mint_for_provided_liquidity(&self, invariant: u128, new_invariant: u128) -> Option<u64> {
+        let provided_invariant = new_invariant.checked_sub(invariant)?;
+        let tokens_to_mint = U192::from(self.lp_tokens_supply())
+            .checked_mul(U192::from(provided_invariant))?
+            .checked_div(U192::from(invariant))?;
+        let tokens_to_mint = u64::try_from(tokens_to_mint).ok()?;
+        if tokens_to_mint == 0 {
+            return None;
+        }
+        Some(tokens_to_mint)
+    }
+
+    /// Calculates the amount of base and quote liquidity to withdraw for a given share of LP tokens.
+    ///
+    /// # Parameters
+    /// - `lp_tokens`: The number of LP tokens being redeemed.
+    ///
+    /// # Returns
+    /// - `Some((u64, u64))` with the base and quote liquidity amounts.
+    /// - `None` if the calculation fails (e.g., due to zero tokens).
+    fn calculate_liquidity_from_share(&self, lp_tokens: u64) -> Option<(u64, u64)> {
+        let lp_tokens_supply = self.lp_tokens_supply() as u128;
+        if lp_tokens_supply == 0 {
+            return None;
+        }
+        let base_withdraw = (self.base_liquidity() as u128 * lp_tokens as u128 / lp_tokens_supply) as u64;
+        let quote_withdraw = (self.quote_liquidity() as u128 * lp_tokens as u128 / lp_tokens_supply) as u64;
+
+        if base_withdraw == 0 || quote_withdraw == 0 {
+            return None;
+        }
+        Some((base_withdraw, quote_withdraw))
+    }
+
+    /// Calculates the new base and quote liquidity after a swap.
+    ///
+    /// The opposite liquidity is rounded up, so the rounding always favours the pool.
+    ///
+    /// # Parameters
+    /// - `amplification`: The amplification coefficient in effect.
+    /// - `swap_amount`: The amount being swapped.
+    /// - `is_in_out`: Whether the swap is "in" (true) or "out" (false).
+    ///
+    /// # Returns
+    /// - `Some((u64, u64))` with the new base and quote liquidity values.
+    /// - `None` if the calculation fails.
+    fn calculate_afterswap_liquidity(&self, amplification: u64, swap_amount: u64, is_in_out: bool) -> Option<(u64, u64)> {
+        let invariant = Self::calculate_invariant(amplification, self.base_liquidity(), self.quote_liquidity())?;
+        let (new_base_liquidity, new_quote_liquidity);
+        if is_in_out {
+            new_base_liquidity = self.base_liquidity().checked_add(swap_amount)?;
+            new_quote_liquidity = Self::calculate_opposite_liquidity(amplification, new_base_liquidity, invariant)?;
+            if new_quote_liquidity >= self.quote_liquidity() {
+                return None;
+            }
+        }
+        else{
+            new_quote_liquidity = self.quote_liquidity().checked_add(swap_amount)?;
+            new_base_liquidity = Self::calculate_opposite_liquidity(amplification, new_quote_liquidity, invariant)?;
+            if new_base_liquidity >= self.base_liquidity() {
+                return None;
+            }
+        }
+        Some((new_base_liquidity, new_quote_liquidity))
+    }
+
+    /// Validates that providing liquidity keeps the base-to-quote liquidity ratio.
+    ///
+    /// # Parameters
+    /// - `new_base_liquidity`: The new base liquidity.
+    /// - `new_quote_liquidity`: The new quote liquidity.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the new ratio is within the allowed tolerance.
+    /// - `Err(ErrorCode)` if the new ratio exceeds the allowed tolerance.
+    fn validate_liquidity_ratio(&self, new_base_liquidity: u64, new_quote_liquidity: u64) -> Result<()> {
+        require!(new_base_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
+        require!(new_quote_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
+        let cross_product = new_base_liquidity as u128 * self.quote_liquidity() as u128;
+        let new_cross_product = new_quote_liquidity as u128 * self.base_liquidity() as u128;
+        let difference = U192::from(cross_product.abs_diff(new_cross_product)) * U192::from(Self::ADJUST_LIQUIDITY_RATIO_TOLERANCE_DENOMINATOR);
+        require!(difference <= U192::from(cross_product), ErrorCode::LiquidityRatioToleranceExceeded);
+        Ok(())
+    }
+
+    /// Validates the StableSwap invariant after a swap.
+    ///
+    /// # Parameters
+    /// - `amplification`: The amplification coefficient in effect.
+    /// - `new_base_liquidity`: The new base liquidity.
+    /// - `new_quote_liquidity`: The new quote liquidity.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the invariant has not decreased beyond the tolerance.
+    /// - `Err(ErrorCode)` if the invariant decreased or can't be calculated.
+    fn validate_swap_invariant(&self, amplification: u64, new_base_liquidity: u64, new_quote_liquidity: u64) -> Result<()> {
+        let invariant = Self::calculate_invariant(amplification, self.base_liquidity(), self.quote_liquidity()).ok_or(ErrorCode::InvariantCalculationFailed)?;
+        let new_invariant = Self::calculate_invariant(amplification, new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::InvariantCalculationFailed)?;
+        require!(new_invariant + Self::SWAP_INVARIANT_TOLERANCE >= invariant, ErrorCode::InvariantToleranceExceeded);
+        Ok(())
+    }
+
+    /// Validates the result of a swap against the estimated result and allowed slippage.
+    ///
+    /// # Parameters
+    /// - `swap_result`: The actual result of the swap.
+    /// - `estimated_swap_result`: The estimated result of the swap.
+    /// - `allowed_slippage`: The allowed slippage tolerance.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the swap result is within the allowed slippage.
+    /// - `Err(ErrorCode)` if the result exceeds the slippage tolerance.
+    #[inline]
+    fn check_swap_result(swap_result: u64, estimated_swap_result: u64, allowed_slippage: u64) -> Result<()> {
+        require!(swap_result > 0, ErrorCode::SwapResultIsZero);
+        require!(swap_result.abs_diff(estimated_swap_result) <= allowed_slippage, ErrorCode::SwapSlippageExceeded);
+        Ok(())
+    }
+
+    /// Calculates the fee for a given swap amount based on the provided fee rate.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The amount of tokens being swapped.
+    /// - `fee_basis_points`: The fee rate expressed in **basis points** (1 basis point = 0.01%).
+    ///
+    /// # Returns
+    /// - A `u64` representing the **calculated fee amount**.
+    #[inline]
+    fn calculate_fee_amount(swap_amount: u64, fee_basis_points: u16) -> u64 {
+        ((swap_amount as u128) * (fee_basis_points as u128) / Self::FEE_MAX_BASIS_POINTS) as u64
+    }
+
+    /// Calculates the StableSwap invariant `D` for the given liquidity.
+    ///
+    /// # Parameters
+    /// - `amplification`: The amplification coefficient.
+    /// - `base_liquidity`: The base liquidity.
+    /// - `quote_liquidity`: The quote liquidity.
+    ///
+    /// # Returns
+    /// - `Some(u128)` with the invariant.
+    /// - `None` if any liquidity is zero, the calculation overflows or doesn't converge.
+    fn calculate_invariant(amplification: u64, base_liquidity: u64, quote_liquidity: u64) -> Option<u128> {
+        if base_liquidity == 0 || quote_liquidity == 0 || amplification == 0 {
+            return None;
+        }
+        let (two, three) = (U192::from(2), U192::from(3));
+        let base = U192::from(base_liquidity);
+        let quote = U192::from(quote_liquidity);
+        let sum = base + quote;
+        // A * n^n
+        let amplification_n = U192::from(amplification) * U192::from(4);
+
+        let mut invariant = sum;
+        for _ in 0..Self::MAX_NEWTON_ITERATIONS {
+            // D^(n + 1) / (n^n * x * y)
+            let invariant_product = invariant
+                .checked_mul(invariant)?
+                .checked_div(base * two)?
+                .checked_mul(invariant)?
+                .checked_div(quote * two)?;
+            let previous_invariant = invariant;
+
+            let numerator = amplification_n
+                .checked_mul(sum)?
+                .checked_add(invariant_product.checked_mul(two)?)?
+                .checked_mul(invariant)?;
+            let denominator = (amplification_n - U192::one())
+                .checked_mul(invariant)?
+                .checked_add(invariant_product.checked_mul(three)?)?;
+            invariant = numerator.checked_div(denominator)?;
+
+            let difference = if invariant > previous_invariant { invariant - previous_invariant } else { previous_invariant - invariant };
+            if difference <= U192::one() {
+                return u128::try_from(invariant).ok();
+            }
+        }
+        None
+    }
+
+    /// Calculates the opposite liquidity that keeps the StableSwap invariant for the given liquidity.
+    ///
+    /// The result is rounded up, so the rounding always favours the pool.
+    ///
+    /// # Parameters
+    /// - `amplification`: The amplification coefficient.
+    /// - `x_liquidity`: The liquidity for one side (base or quote).
+    /// - `invariant`: The invariant to keep.
+    ///
+    /// # Returns
+    /// - `Some(u64)` with the opposite liquidity value.
+    /// - `None` if the calculation overflows or doesn't converge.
+    fn calculate_opposite_liquidity(amplification: u64, x_liquidity: u64, invariant: u128) -> Option<u64> {
+        if x_liquidity == 0 || amplification == 0 {
+            return None;
+        }
+        let two = U192::from(2);
+        let x = U192::from(x_liquidity);
+        let invariant = U192::from(invariant);
+        // A * n^n
+        let amplification_n = U192::from(amplification) * U192::from(4);
+
+        // c = D^(n + 1) / (n^n * x * A * n^n)
+        let c = invariant
+            .checked_mul(invariant)?
+            .checked_div(x * two)?
+            .checked_mul(invariant)?
+            .checked_div(amplification_n * two)?;
+        let b = x.checked_add(invariant.checked_div(amplification_n)?)?;
+
+        let mut y = invariant;
+        for _ in 0..Self::MAX_NEWTON_ITERATIONS {
+            let previous_y = y;
+            let numerator = y.checked_mul(y)?.checked_add(c)?;
+            let denominator = y.checked_mul(two)?.checked_add(b)?.checked_sub(invariant)?;
+            y = numerator.checked_div(denominator)?;
+
+            let difference = if y > previous_y { y - previous_y } else { previous_y - y };
+            if difference <= U192::one() {
+                return u64::try_from(y).ok()?.checked_add(1);
+            }
+        }
+        None
+    }
+}
+
+impl<T: StableAmmCore> StableAmmCalculate for T{}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::stable_amm::{StableAmmCore, StableAmmCalculate};
+
+    /// A helper struct for testing the `StableAmmCalculate` trait.
+    struct TestStableAmm{
+        amplification: u64,
+        base_liquidity: u64,
+        quote_liquidity: u64,
+        lp_tokens_supply: u64,
+    }
+
+    impl TestStableAmm{
+        /// Creates a new instance of `TestStableAmm` with calculated LP tokens supply.
+        ///
+        /// Returns `None` if any calculation fails.
+        fn try_new(amplification: u64, base_liquidity: u64, quote_liquidity: u64) -> Option<Self>{
+            let invariant = TestStableAmm::calculate_invariant(amplification, base_liquidity, quote_liquidity)?;
+            let (lp_tokens_supply, _) = TestStableAmm::calculate_launch_lp_tokens(invariant).ok()?;
+            Some(
+                Self{
+                    amplification,
+                    base_liquidity,
+                    quote_liquidity,
+                    lp_tokens_supply
+                }
+            )
+        }
+    }
+
+    impl StableAmmCore for TestStableAmm {
+        fn amplification(&self, _timestamp: i64) -> u64 {
+            self.amplification
+        }
+
+        fn base_liquidity(&self) -> u64 {
+            self.base_liquidity
+        }
+
+        fn quote_liquidity(&self) -> u64 {
+            self.quote_liquidity
+        }
+
+        fn lp_tokens_supply(&self) -> u64 {
+            self.lp_tokens_supply
+        }
+    }
+
+    /// Tests that the invariant of a balanced pool equals the sum of liquidities.
+    #[test]
+    fn test_calculate_invariant_balanced() {
+        for amplification in [1, 100, 10_000, 1_000_000] {
+            let invariant = TestStableAmm::calculate_invariant(amplification, 1_000_000_000, 1_000_000_000).unwrap();
+            assert!(invariant.abs_diff(2_000_000_000) <= 1, "Unexpected invariant {} for amplification {}", invariant, amplification);
+        }
+        assert!(TestStableAmm::calculate_invariant(100, 0, 1_000).is_none());
+        assert!(TestStableAmm::calculate_invariant(100, 1_000, 0).is_none());
+        assert!(TestStableAmm::calculate_invariant(0, 1_000, 1_000).is_none());
+    }
+
+    /// Tests that the invariant of an imbalanced pool lies between the constant product and constant sum invariants.
+    #[test]
+    fn test_calculate_invariant_imbalanced() {
+        let (base_liquidity, quote_liquidity) = (1_000_000_000u64, 4_000_000_000u64);
+        let constant_sum = (base_liquidity + quote_liquidity) as u128;
+        let constant_product = 2 * ((base_liquidity as f64) * (quote_liquidity as f64)).sqrt() as u128;
+
+        let low_amplification_invariant = TestStableAmm::calculate_invariant(1, base_liquidity, quote_liquidity).unwrap();
+        let high_amplification_invariant = TestStableAmm::calculate_invariant(10_000, base_liquidity, quote_liquidity).unwrap();
+
+        assert!(low_amplification_invariant > constant_product && low_amplification_invariant < constant_sum);
+        assert!(high_amplification_invariant > low_amplification_invariant && high_amplification_invariant < constant_sum);
+    }
+
+    /// Tests that the opposite liquidity keeps the invariant.
+    #[test]
+    fn test_calculate_opposite_liquidity() {
+        let amplification = 100;
+        let invariant = TestStableAmm::calculate_invariant(amplification, 1_000_000_000, 1_000_000_000).unwrap();
+
+        let opposite_liquidity = TestStableAmm::calculate_opposite_liquidity(amplification, 1_000_000_000, invariant).unwrap();
+        assert!(opposite_liquidity.abs_diff(1_000_000_000) <= 2);
+
+        let opposite_liquidity = TestStableAmm::calculate_opposite_liquidity(amplification, 1_100_000_000, invariant).unwrap();
+        let new_invariant = TestStableAmm::calculate_invariant(amplification, 1_100_000_000, opposite_liquidity).unwrap();
+        assert!(new_invariant >= invariant);
+        assert!(new_invariant - invariant <= 2);
+    }
+
+    /// Tests that swaps in a balanced pool have lower slippage than in a constant product pool.
+    #[test]
+    fn test_calculate_afterswap_liquidity() {
+        let amm = TestStableAmm::try_new(100, 1_000_000_000, 1_000_000_000).unwrap();
+        let swap_amount = 100_000_000;
+
+        let (new_base_liquidity, new_quote_liquidity) = amm.calculate_afterswap_liquidity(100, swap_amount, true).unwrap();
+        assert_eq!(new_base_liquidity, 1_100_000_000);
+        let swap_result = amm.quote_liquidity - new_quote_liquidity;
+        let constant_product_swap_result = 1_000_000_000 - 1_000_000_000u64 * 1_000_000_000 / 1_100_000_000;
+        assert!(swap_result < swap_amount);
+        assert!(swap_result > constant_product_swap_result);
+        assert!(swap_amount - swap_result < 1_000_000, "Unexpected slippage: {}", swap_amount - swap_result);
+        amm.validate_swap_invariant(100, new_base_liquidity, new_quote_liquidity).unwrap();
+
+        let (new_base_liquidity, new_quote_liquidity) = amm.calculate_afterswap_liquidity(100, swap_amount, false).unwrap();
+        assert_eq!(new_quote_liquidity, 1_100_000_000);
+        assert!(amm.base_liquidity - new_base_liquidity < swap_amount);
+        amm.validate_swap_invariant(100, new_base_liquidity, new_quote_liquidity).unwrap();
+
+        assert!(amm.validate_swap_invariant(100, 1_100_000_000, 900_000_000).is_err());
+    }
+
+    /// Tests LP tokens calculations for providing and withdrawing liquidity.
+    #[test]
+    fn test_calculate_lp_tokens() {
+        let amm = TestStableAmm::try_new(100, 1_000_000_000, 1_000_000_000).unwrap();
+        assert_eq!(amm.lp_tokens_supply, 2_000_000_000);
+
+        let invariant = TestStableAmm::calculate_invariant(100, 1_000_000_000, 1_000_000_000).unwrap();
+        let new_invariant = TestStableAmm::calculate_invariant(100, 1_100_000_000, 1_100_000_000).unwrap();
+        assert_eq!(amm.calculate_lp_mint_for_provided_liquidity(invariant, new_invariant), Some(200_000_000));
+        assert!(amm.calculate_lp_mint_for_provided_liquidity(invariant, invariant).is_none());
+
+        assert_eq!(amm.calculate_liquidity_from_share(200_000_000), Some((100_000_000, 100_000_000)));
+        assert!(amm.calculate_liquidity_from_share(1).is_none());
+    }
+
+    /// Tests the liquidity ratio validation.
+    #[test]
+    fn test_validate_liquidity_ratio() {
+        let amm = TestStableAmm::try_new(100, 1_000_000_000, 2_000_000_000).unwrap();
+        amm.validate_liquidity_ratio(1_100_000_000, 2_200_000_000).unwrap();
+        assert!(amm.validate_liquidity_ratio(1_100_000_000, 2_100_000_000).is_err());
+        assert!(amm.validate_liquidity_ratio(0, 2_100_000_000).is_err());
+    }
+
+    /// Tests the launch LP tokens calculation.
+    #[test]
+    fn test_calculate_launch_lp_tokens() {
+        assert_eq!(TestStableAmm::calculate_launch_lp_tokens(400_000).unwrap(), (400_000, 100_000));
+        assert!(TestStableAmm::calculate_launch_lp_tokens(399_999).is_err());
+        assert!(TestStableAmm::calculate_launch_lp_tokens(u64::MAX as u128 + 1).is_err());
+    }
+}
@@ -0,0 +1,29 @@
+/// A trait defining the core parameters of a StableSwap Automated Market Maker.
+pub trait StableAmmCore {
+    /// Calculates the amplification coefficient in effect at the given moment.
+    ///
+    /// # Parameters
+    /// - `timestamp`: Unix timestamp to calculate the amplification coefficient at.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amplification coefficient.
+    fn amplification(&self, timestamp: i64) -> u64;
+
+    /// Retrieves the base liquidity of the AMM pool.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amount of base liquidity in the pool.
+    fn base_liquidity(&self) -> u64;
+
+    /// Retrieves the quote liquidity of the AMM pool.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amount of quote liquidity in the pool.
+    fn quote_liquidity(&self) -> u64;
+
+    /// Retrieves the total supply of LP tokens in the pool.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the total supply of LP tokens.
+    fn lp_tokens_supply(&self) -> u64;
+}
@@ -0,0 +1,931 @@
+use anchor_lang::{account, InitSpace};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint};
+use anchor_spl::token_interface;
+use utilities::math::Q64_128;
+use crate::error::ErrorCode;
+use crate::state::AmmsConfig;
+use crate::state::cp_amm::CollectFeesPayload;
+use super::{StableAmmCalculate, StableAmmCore};
+
+/// Represents a StableSwap Automated Market Maker (AMM) pool.
+///
+/// The `StableAmm` struct encapsulates all the key parameters and state of the pool,
+/// including liquidity, fees, amplification coefficient, tokens, and associated vaults.
+///
+/// The AMM maintains the Curve StableSwap invariant, which behaves like a constant sum
+/// around the balanced state and like a constant product when the pool is imbalanced.
+/// The amplification coefficient controls how flat the curve is and can be ramped
+/// linearly over time by the `AmmsConfigsManager` authorities.
+#[account]
+#[derive(InitSpace)]
+pub struct StableAmm {
+    /// Whether the AMM has been initialized.
+    is_initialized: bool, // 1 byte
+
+    /// Whether the AMM has been launched and is active.
+    is_launched: bool, // 1 byte
+
+    /// Canonical bump seed for the account's PDA.
+    bump: [u8; 1], // 1 byte
+
+    /// Canonical bump seed for the base vault PDA.
+    base_vault_bump: [u8; 1], // 1 byte
+
+    /// Canonical bump seed for the quote vault PDA.
+    quote_vault_bump: [u8; 1], // 1 byte
+
+    /// Canonical bump seed for the locked LP vault PDA.
+    locked_lp_vault_bump: [u8; 1], // 1 byte
+
+    /// Initial liquidity that is permanently locked after the pool launch.
+    /// This stabilizes the pool in case of empty liquidity.
+    initial_locked_liquidity: u64, // 8 bytes
+
+    /// Amount of base tokens currently in the pool's vault.
+    base_liquidity: u64, // 8 bytes
+
+    /// Amount of quote tokens currently in the pool's vault.
+    quote_liquidity: u64, // 8 bytes
+
+    /// Total supply of LP tokens minted to liquidity providers.
+    lp_tokens_supply: u64, // 8 bytes
+
+    /// Accumulated base token fees that can be redeemed by the `AmmsConfig` account's authority.
+    protocol_base_fees_to_redeem: u64, // 8 bytes
+
+    /// Accumulated quote token fees that can be redeemed by the `AmmsConfig` account's authority.
+    protocol_quote_fees_to_redeem: u64, // 8 bytes
+
+    /// Amplification coefficient at the start of the current ramp.
+    initial_amplification: u64, // 8 bytes
+
+    /// Amplification coefficient at the end of the current ramp.
+    target_amplification: u64, // 8 bytes
+
+    /// Unix timestamp of the current ramp start.
+    ramp_start_timestamp: i64, // 8 bytes
+
+    /// Unix timestamp of the current ramp end.
+    ramp_end_timestamp: i64, // 8 bytes
+
+    /// Public key of the base token's mint.
+    base_mint: Pubkey, // 32 bytes
+
+    /// Public key of the quote token's mint.
+    quote_mint: Pubkey, // 32 bytes
+
+    /// Public key of the LP token's mint.
+    pub lp_mint: Pubkey, // 32 bytes
+
+    /// Public key of the vault holding the base tokens.
+    base_vault: Pubkey, // 32 bytes
+
+    /// Public key of the vault holding the quote tokens.
+    quote_vault: Pubkey, // 32 bytes
+
+    /// Public key of the vault holding locked LP tokens.
+    locked_lp_vault: Pubkey, // 32 bytes
+
+    /// Public key of the associated `AmmsConfig` account.
+    amms_config: Pubkey, // 32 bytes
+
+    /// Public key of the StableAmm creator account.
+    creator: Pubkey, // 32 bytes
+}
+
+impl StableAmm {
+    /// Seed used for generating the PDA.
+    pub const SEED: &'static [u8] = b"stable_amm";
+
+    /// Seed used for generating the vaults PDAs.
+    pub const VAULT_SEED: &'static [u8] = b"vault";
+
+    /// Returns the seeds for generating the PDA.
+    ///
+    /// The PDA is derived using the `SEED`, the `lp_mint`, and the `bump` value.
+    pub fn seeds(&self) -> [&[u8]; 3] {
+        [Self::SEED, self.lp_mint.as_ref(), self.bump.as_ref()]
+    }
+
+    /// Checks if the AMM has been initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+
+    /// Checks if the AMM has been launched and is active.
+    #[inline]
+    pub fn is_launched(&self) -> bool {
+        self.is_launched
+    }
+
+    /// Returns the canonical bump value for the PDA.
+    #[inline]
+    pub fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the public key of the base token's mint.
+    #[inline]
+    pub fn base_mint(&self) -> &Pubkey {
+        &self.base_mint
+    }
+
+    /// Returns the public key of the quote token's mint.
+    #[inline]
+    pub fn quote_mint(&self) -> &Pubkey { &self.quote_mint }
+
+    /// Returns the public key of the vault holding base tokens.
+    #[inline]
+    pub fn base_vault(&self) -> &Pubkey {
+        &self.base_vault
+    }
+
+    /// Returns the public key of the vault holding quote tokens.
+    #[inline]
+    pub fn quote_vault(&self) -> &Pubkey {
+        &self.quote_vault
+    }
+
+    /// Returns the public key of the vault holding locked LP tokens.
+    #[inline]
+    pub fn locked_lp_vault(&self) -> &Pubkey {
+        &self.locked_lp_vault
+    }
+
+    /// Returns the public key of the associated `AmmsConfig` account.
+    #[inline]
+    pub fn amms_config(&self) -> &Pubkey {
+        &self.amms_config
+    }
+
+    /// Returns the public key of the StableAmm creator account.
+    #[inline]
+    pub fn creator(&self) -> &Pubkey {
+        &self.creator
+    }
+
+    /// Returns the canonical bump value for the base vault PDA.
+    #[inline]
+    pub fn base_vault_bump(&self) -> u8 {
+        self.base_vault_bump[0]
+    }
+
+    /// Returns the canonical bump value for the quote vault PDA.
+    #[inline]
+    pub fn quote_vault_bump(&self) -> u8 {
+        self.quote_vault_bump[0]
+    }
+
+    /// Returns the canonical bump value for the locked LP vault PDA.
+    #[inline]
+    pub fn locked_lp_vault_bump(&self) -> u8 {
+        self.locked_lp_vault_bump[0]
+    }
+
+    #[inline]
+    pub fn initial_locked_liquidity(&self) -> u64 {
+        self.initial_locked_liquidity
+    }
+
+    #[inline]
+    pub fn protocol_base_fees_to_redeem(&self) -> u64 {
+        self.protocol_base_fees_to_redeem
+    }
+
+    #[inline]
+    pub fn protocol_quote_fees_to_redeem(&self) -> u64 {
+        self.protocol_quote_fees_to_redeem
+    }
+
+    #[inline]
+    pub fn initial_amplification(&self) -> u64 {
+        self.initial_amplification
+    }
+
+    #[inline]
+    pub fn target_amplification(&self) -> u64 {
+        self.target_amplification
+    }
+
+    #[inline]
+    pub fn ramp_start_timestamp(&self) -> i64 {
+        self.ramp_start_timestamp
+    }
+
+    #[inline]
+    pub fn ramp_end_timestamp(&self) -> i64 {
+        self.ramp_end_timestamp
+    }
+
+    /// Calculates the square root of the product of the pool liquidities.
+    ///
+    /// Not an invariant of the pool, but keeps the emitted events in the same shape as the `CpAmm` ones.
+    pub fn constant_product_sqrt(&self) -> Q64_128 {
+        Q64_128::sqrt_from_u128(self.base_liquidity as u128 * self.quote_liquidity as u128)
+    }
+
+    /// Calculates the square root of the base-to-quote liquidity ratio.
+    pub fn base_quote_ratio_sqrt(&self) -> Q64_128 {
+        Q64_128::checked_div_sqrt(Q64_128::from_u64(self.base_liquidity), Q64_128::from_u64(self.quote_liquidity)).unwrap_or_default()
+    }
+}
+
+/// Implements the `StableAmmCore` trait for the `StableAmm` struct.
+///
+/// This implementation auto implements 'StableAmmCalculate' trait that defines core logic and calculations for the StableSwap AMM.
+impl StableAmmCore for StableAmm {
+    /// Calculates the amplification coefficient in effect at the given moment.
+    ///
+    /// The coefficient changes linearly from `initial_amplification` to `target_amplification`
+    /// between `ramp_start_timestamp` and `ramp_end_timestamp`.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amplification coefficient.
+    fn amplification(&self, timestamp: i64) -> u64 {
+        if timestamp >= self.ramp_end_timestamp || self.ramp_end_timestamp <= self.ramp_start_timestamp {
+            return self.target_amplification;
+        }
+        if timestamp <= self.ramp_start_timestamp {
+            return self.initial_amplification;
+        }
+        let elapsed = (timestamp - self.ramp_start_timestamp) as u128;
+        let duration = (self.ramp_end_timestamp - self.ramp_start_timestamp) as u128;
+        let change = (self.target_amplification.abs_diff(self.initial_amplification) as u128 * elapsed / duration) as u64;
+        if self.target_amplification > self.initial_amplification {
+            self.initial_amplification + change
+        }
+        else{
+            self.initial_amplification - change
+        }
+    }
+
+    /// Retrieves the current amount of base token liquidity in the pool's vault.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amount of base tokens available in the pool.
+    fn base_liquidity(&self) -> u64 {
+        self.base_liquidity
+    }
+
+    /// Retrieves the current amount of quote token liquidity in the pool's vault.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the amount of quote tokens available in the pool.
+    fn quote_liquidity(&self) -> u64 {
+        self.quote_liquidity
+    }
+
+    /// Retrieves the total supply of LP tokens minted by the pool.
+    ///
+    /// # Returns
+    /// - A `u64` value representing the total number of LP tokens issued.
+    fn lp_tokens_supply(&self) -> u64 {
+        self.lp_tokens_supply
+    }
+}
+
+impl StableAmm {
+    /// Validates the current state of the AMM to ensure it is ready for operations.
+    ///
+    /// This method ensures that:
+    /// - The AMM has been launched.
+    /// - The pool has non-zero base and quote liquidity.
+    /// - The pool has a positive supply of LP tokens.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the state is valid.
+    /// - `Err(ErrorCode)` if any of the checks fail.
+    #[inline]
+    fn check_state(&self) -> Result<()> {
+        require!(self.is_launched, ErrorCode::StableAmmNotLaunched);
+        require!(self.base_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
+        require!(self.quote_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
+        require!(self.lp_tokens_supply > 0, ErrorCode::LpTokensSupplyIsZero);
+        Ok(())
+    }
+
+    /// Prepares the payload for launching the AMM with the provided base and quote liquidity.
+    ///
+    /// It calculates the initial invariant and the total supply of LP tokens to mint.
+    ///
+    /// # Parameters
+    /// - `base_liquidity`: The amount of base liquidity to add during the launch.
+    /// - `quote_liquidity`: The amount of quote liquidity to add during the launch.
+    /// - `timestamp`: Current Unix timestamp, used to calculate the amplification coefficient.
+    ///
+    /// # Returns
+    /// - `Ok(StableLaunchPayload)` containing the calculated launch details.
+    /// - `Err(ErrorCode)` if any preconditions fail or calculations encounter errors.
+    #[inline(never)]
+    pub fn get_launch_payload(&self, base_liquidity: u64, quote_liquidity: u64, timestamp: i64) -> Result<StableLaunchPayload> {
+        require!(!self.is_launched, ErrorCode::StableAmmAlreadyLaunched);
+        require!(self.is_initialized, ErrorCode::StableAmmNotInitialized);
+        require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
+        require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
+
+        let invariant = Self::calculate_invariant(self.amplification(timestamp), base_liquidity, quote_liquidity).ok_or(ErrorCode::InvariantCalculationFailed)?;
+        let (lp_tokens_supply, initial_locked_liquidity) = Self::calculate_launch_lp_tokens(invariant)?;
+
+        Ok(StableLaunchPayload {
+            initial_locked_liquidity,
+            base_liquidity,
+            quote_liquidity,
+            lp_tokens_supply,
+        })
+    }
+
+    /// Prepares the payload for adding liquidity to the AMM.
+    ///
+    /// Liquidity must be provided in the current base-to-quote ratio,
+    /// LP tokens are minted proportionally to the invariant growth.
+    ///
+    /// # Parameters
+    /// - `base_liquidity`: The amount of base liquidity to provide.
+    /// - `quote_liquidity`: The amount of quote liquidity to provide.
+    /// - `timestamp`: Current Unix timestamp, used to calculate the amplification coefficient.
+    ///
+    /// # Returns
+    /// - `Ok(StableProvidePayload)` containing the updated pool state and LP tokens to mint.
+    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    #[inline(never)]
+    pub fn get_provide_payload(&self, base_liquidity: u64, quote_liquidity: u64, timestamp: i64) -> Result<StableProvidePayload> {
+        self.check_state()?;
+        require!(base_liquidity > 0, ErrorCode::ProvidedBaseLiquidityIsZero);
+        require!(quote_liquidity > 0, ErrorCode::ProvidedQuoteLiquidityIsZero);
+
+        let new_base_liquidity = self.base_liquidity.checked_add(base_liquidity).ok_or(ErrorCode::ProvideOverflowError)?;
+        let new_quote_liquidity = self.quote_liquidity.checked_add(quote_liquidity).ok_or(ErrorCode::ProvideOverflowError)?;
+        self.validate_liquidity_ratio(new_base_liquidity, new_quote_liquidity)?;
+
+        let amplification = self.amplification(timestamp);
+        let invariant = Self::calculate_invariant(amplification, self.base_liquidity, self.quote_liquidity).ok_or(ErrorCode::InvariantCalculationFailed)?;
+        let new_invariant = Self::calculate_invariant(amplification, new_base_liquidity, new_quote_liquidity).ok_or(ErrorCode::InvariantCalculationFailed)?;
+
+        let lp_tokens_to_mint = self.calculate_lp_mint_for_provided_liquidity(invariant, new_invariant).ok_or(ErrorCode::LpTokensCalculationFailed)?;
+
+        let new_lp_tokens_supply = self.lp_tokens_supply.checked_add(lp_tokens_to_mint).ok_or(ErrorCode::ProvideOverflowError)?;
+        Ok(StableProvidePayload {
+            base_liquidity: new_base_liquidity,
+            quote_liquidity: new_quote_liquidity,
+            lp_tokens_supply: new_lp_tokens_supply,
+            lp_tokens_to_mint,
+        })
+    }
+
+    /// Prepares the payload for withdrawing liquidity from the AMM.
+    ///
+    /// Liquidity is withdrawn proportionally to the share of the redeemed LP tokens.
+    ///
+    /// # Parameters
+    /// - `lp_tokens`: The number of LP tokens to redeem for liquidity withdrawal.
+    ///
+    /// # Returns
+    /// - `Ok(StableWithdrawPayload)` containing the updated pool state and withdrawn liquidity amounts.
+    /// - `Err(ErrorCode)` if any checks fail or calculations encounter errors.
+    #[inline(never)]
+    pub fn get_withdraw_payload(&self, lp_tokens: u64) -> Result<StableWithdrawPayload> {
+        self.check_state()?;
+        require!(lp_tokens > 0, ErrorCode::ProvidedLpTokensIsZero);
+
+        let lp_tokens_left_supply = self.lp_tokens_supply.checked_sub(lp_tokens).ok_or(ErrorCode::WithdrawOverflowError)?;
+
+        let (base_withdraw, quote_withdraw) = self.calculate_liquidity_from_share(lp_tokens).ok_or(ErrorCode::WithdrawLiquidityCalculationFailed)?;
+
+        let new_base_liquidity = self.base_liquidity.checked_sub(base_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
+        let new_quote_liquidity = self.quote_liquidity.checked_sub(quote_withdraw).ok_or(ErrorCode::WithdrawOverflowError)?;
+
+        // Checks that new base and quote liquidity don't equal zero and amm won't be drained
+        require!(new_base_liquidity > 0, ErrorCode::BaseLiquidityIsZero);
+        require!(new_quote_liquidity > 0, ErrorCode::QuoteLiquidityIsZero);
+
+        Ok(StableWithdrawPayload{
+            base_liquidity: new_base_liquidity,
+            quote_liquidity: new_quote_liquidity,
+            lp_tokens_supply: lp_tokens_left_supply,
+            base_withdraw_amount: base_withdraw,
+            quote_withdraw_amount: quote_withdraw,
+        })
+    }
+
+    /// Computes the swap payload for exchanging tokens within the AMM.
+    ///
+    /// This function handles both **base-to-quote** and **quote-to-base** swaps.
+    /// It calculates the updated pool state, applies provider and protocol fees,
+    /// and validates the StableSwap invariant.
+    ///
+    /// # Parameters
+    /// - `swap_amount`: The amount of tokens being swapped (either base or quote).
+    /// - `estimated_result`: Expected amount of tokens to receive after the swap.
+    /// - `allowed_slippage`: Maximum permissible deviation from `estimated_result`.
+    /// - `providers_fee_rate_basis_points`: The liquidity provider's fee rate in basis points.
+    /// - `protocol_fee_rate_basis_points`: The protocol fee rate in basis points.
+    /// - `referrer_fee_share_basis_points`: The share of the protocol fee paid to the swap referrer,
+    ///   in basis points of the protocol fee. Zero for swaps without a referrer.
+    /// - `is_in_out`: `true` if swapping **base → quote**, `false` if swapping **quote → base**.
+    /// - `timestamp`: Current Unix timestamp, used to calculate the amplification coefficient.
+    ///
+    /// # Returns
+    /// - `Ok(StableSwapPayload)`: Contains the updated liquidity state and fees.
+    /// - `Err(ErrorCode)`: If any validation fails (e.g., insufficient liquidity, overflow, or slippage exceeded).
+    #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_swap_payload(&self, swap_amount: u64, estimated_result: u64, allowed_slippage: u64, providers_fee_rate_basis_points: u16, protocol_fee_rate_basis_points: u16, referrer_fee_share_basis_points: u16, is_in_out: bool, timestamp: i64) -> Result<StableSwapPayload> {
+        self.check_state()?;
+        require!(swap_amount > 0, ErrorCode::SwapAmountIsZero);
+        require!(estimated_result > 0, ErrorCode::EstimatedResultIsZero);
+        require!(providers_fee_rate_basis_points + protocol_fee_rate_basis_points <= 10000, ErrorCode::ConfigFeeRateExceeded);
+        require!(referrer_fee_share_basis_points <= AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS, ErrorCode::ReferrerFeeShareExceeded);
+
+        let providers_fee_amount = Self::calculate_fee_amount(swap_amount, providers_fee_rate_basis_points);
+        require!(providers_fee_amount > 0 || providers_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        let protocol_fee_amount = Self::calculate_fee_amount(swap_amount, protocol_fee_rate_basis_points);
+        require!(protocol_fee_amount > 0 || protocol_fee_rate_basis_points == 0, ErrorCode::SwapFeesAreZero);
+
+        // The referrer fee is carved out of the protocol fee and leaves the pool
+        let referrer_fee_amount = Self::calculate_fee_amount(protocol_fee_amount, referrer_fee_share_basis_points);
+        let retained_protocol_fee_amount = protocol_fee_amount - referrer_fee_amount;
+
+        let amplification = self.amplification(timestamp);
+        let amount_after_fees = swap_amount.checked_sub(providers_fee_amount).ok_or(ErrorCode::SwapOverflowError)?.checked_sub(protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?;
+        let (new_base_liquidity, new_quote_liquidity) = self.calculate_afterswap_liquidity(amplification, amount_after_fees, is_in_out).ok_or(ErrorCode::AfterswapCalculationFailed)?;
+
+        let (amount_to_withdraw, protocol_fees_to_redeem) = if is_in_out {
+            (
+                self.quote_liquidity.checked_sub(new_quote_liquidity).ok_or(ErrorCode::SwapOverflowError)?,
+                self.protocol_base_fees_to_redeem.checked_add(retained_protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?
+            )
+        }
+        else{
+            (
+                self.base_liquidity.checked_sub(new_base_liquidity).ok_or(ErrorCode::SwapOverflowError)?,
+                self.protocol_quote_fees_to_redeem.checked_add(retained_protocol_fee_amount).ok_or(ErrorCode::SwapOverflowError)?
+            )
+        };
+
+        // Check invariant doesn't decrease
+        self.validate_swap_invariant(amplification, new_base_liquidity, new_quote_liquidity)?;
+        Self::check_swap_result(amount_to_withdraw, estimated_result, allowed_slippage)?;
+
+        Ok(StableSwapPayload {
+            base_liquidity: new_base_liquidity,
+            quote_liquidity: new_quote_liquidity,
+            protocol_fees_to_redeem,
+            providers_fee_amount,
+            referrer_fee_amount,
+            amount_to_withdraw,
+            is_in_out,
+        })
+    }
+
+    /// Prepares the payload for collecting protocol fees from the AMM.
+    ///
+    /// # Returns
+    /// - `Ok(CollectFeesPayload)`: Contains the protocol fees available for redemption for both base and quote tokens.
+    /// - `Err(ErrorCode::ProvidersFeesIsZero)`: If there are no fees available to collect.
+    #[inline(never)]
+    pub fn get_collect_fees_payload(&self) -> Result<CollectFeesPayload>{
+        require!(self.protocol_base_fees_to_redeem > 0 || self.protocol_quote_fees_to_redeem > 0, ErrorCode::ProvidersFeesIsZero);
+        Ok(CollectFeesPayload::new(
+            self.protocol_base_fees_to_redeem,
+            self.protocol_quote_fees_to_redeem,
+            0,
+            0
+        ))
+    }
+}
+
+impl StableAmm {
+
+    /// Initializes the AMM with the provided token mints, configuration and amplification coefficient.
+    ///
+    /// # Parameters
+    /// - `base_mint`: The mint of the base token.
+    /// - `quote_mint`: The mint of the quote token.
+    /// - `lp_mint`: The mint of the LP token.
+    /// - `amms_config`: The configuration account for the AMM.
+    /// - `creator`: Account info of creator account.
+    /// - `base_vault`: Pubkey of the vault holding the base tokens.
+    /// - `quote_vault`: Pubkey of the vault holding the quote tokens.
+    /// - `locked_lp_vault`: Pubkey of the vault holding locked LP tokens.
+    /// - `amplification`: The initial amplification coefficient.
+    /// - `bump`: The canonical bump seed for the AMM's PDA.
+    /// - `base_vault_bump`: The canonical bump seed for the base vault PDA.
+    /// - `quote_vault_bump`: The canonical bump seed for the quote vault PDA.
+    /// - `locked_lp_vault_bump`: The canonical bump seed for the locked LP vault PDA.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the initialization is successful.
+    /// - `Err(ErrorCode)` if the AMM is already initialized, mints decimals differ or the amplification is invalid.
+    #[inline(never)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        base_mint: &InterfaceAccount<token_interface::Mint>,
+        quote_mint: &InterfaceAccount<token_interface::Mint>,
+        lp_mint: &Account<Mint>,
+        amms_config: &Account<AmmsConfig>,
+        creator: &AccountInfo,
+        base_vault: &AccountInfo,
+        quote_vault: &AccountInfo,
+        locked_lp_vault: &AccountInfo,
+        amplification: u64,
+        bump: u8,
+        base_vault_bump: u8,
+        quote_vault_bump: u8,
+        locked_lp_vault_bump: u8
+    ) -> Result<()>{
+        require!(!self.is_initialized, ErrorCode::StableAmmAlreadyInitialized);
+        require!(base_mint.decimals == quote_mint.decimals, ErrorCode::StableAmmMintsDecimalsMismatch);
+        require!((Self::MIN_AMPLIFICATION..=Self::MAX_AMPLIFICATION).contains(&amplification), ErrorCode::InvalidAmplification);
+
+        self.is_initialized = true;
+        self.is_launched = false;
+
+        self.base_mint = base_mint.key();
+        self.quote_mint = quote_mint.key();
+        self.lp_mint = lp_mint.key();
+        self.amms_config = amms_config.key();
+        self.creator = creator.key();
+        self.base_vault = base_vault.key();
+        self.quote_vault = quote_vault.key();
+        self.locked_lp_vault = locked_lp_vault.key();
+
+        self.initial_amplification = amplification;
+        self.target_amplification = amplification;
+        self.ramp_start_timestamp = 0;
+        self.ramp_end_timestamp = 0;
+
+        self.bump = [bump];
+        self.base_vault_bump = [base_vault_bump];
+        self.quote_vault_bump = [quote_vault_bump];
+        self.locked_lp_vault_bump = [locked_lp_vault_bump];
+
+        Ok(())
+    }
+
+    /// Starts a linear ramp of the amplification coefficient.
+    ///
+    /// The ramp starts from the coefficient in effect at `timestamp`,
+    /// so an ongoing ramp can be replaced or stopped by a new one.
+    ///
+    /// # Parameters
+    /// - `target_amplification`: The amplification coefficient at the end of the ramp.
+    /// - `timestamp`: Current Unix timestamp, the ramp start.
+    /// - `ramp_end_timestamp`: Unix timestamp of the ramp end.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the ramp is started.
+    /// - `Err(ErrorCode)` if the target is out of bounds, changes too much or the ramp is too short.
+    pub(crate) fn ramp_amplification(&mut self, target_amplification: u64, timestamp: i64, ramp_end_timestamp: i64) -> Result<()> {
+        require!(self.is_initialized, ErrorCode::StableAmmNotInitialized);
+        require!((Self::MIN_AMPLIFICATION..=Self::MAX_AMPLIFICATION).contains(&target_amplification), ErrorCode::InvalidAmplification);
+        require!(ramp_end_timestamp >= timestamp.saturating_add(Self::MIN_AMPLIFICATION_RAMP_DURATION), ErrorCode::AmplificationRampTooShort);
+
+        let current_amplification = self.amplification(timestamp);
+        require!(
+            target_amplification <= current_amplification.saturating_mul(Self::MAX_AMPLIFICATION_CHANGE) &&
+            target_amplification.saturating_mul(Self::MAX_AMPLIFICATION_CHANGE) >= current_amplification,
+            ErrorCode::AmplificationChangeTooLarge
+        );
+
+        self.initial_amplification = current_amplification;
+        self.target_amplification = target_amplification;
+        self.ramp_start_timestamp = timestamp;
+        self.ramp_end_timestamp = ramp_end_timestamp;
+        Ok(())
+    }
+
+    /// Launches the AMM with the launch liquidity.
+    ///
+    /// # Parameters
+    /// - `launch_payload`: Contains the initial liquidity and LP token supply.
+    #[inline(never)]
+    pub(crate) fn launch(&mut self, launch_payload: StableLaunchPayload) {
+        self.is_launched = true;
+        self.base_liquidity = launch_payload.base_liquidity;
+        self.quote_liquidity = launch_payload.quote_liquidity;
+        self.initial_locked_liquidity = launch_payload.initial_locked_liquidity;
+        self.lp_tokens_supply = launch_payload.lp_tokens_supply;
+    }
+
+    /// Updates the AMM state after liquidity is provided.
+    ///
+    /// # Parameters
+    /// - `provide_payload`: Contains the new liquidity amounts and LP tokens supply.
+    #[inline(never)]
+    pub(crate) fn provide(&mut self, provide_payload: StableProvidePayload) {
+        self.base_liquidity = provide_payload.base_liquidity;
+        self.quote_liquidity = provide_payload.quote_liquidity;
+        self.lp_tokens_supply = provide_payload.lp_tokens_supply;
+    }
+
+    /// Updates the AMM state after liquidity is withdrawn.
+    ///
+    /// # Parameters
+    /// - `withdraw_payload`: Contains the new liquidity amounts and LP tokens supply.
+    #[inline(never)]
+    pub(crate) fn withdraw(&mut self, withdraw_payload: StableWithdrawPayload) {
+        self.base_liquidity = withdraw_payload.base_liquidity;
+        self.quote_liquidity = withdraw_payload.quote_liquidity;
+        self.lp_tokens_supply = withdraw_payload.lp_tokens_supply;
+    }
+
+    /// Updates the AMM state after a token swap operation.
+    ///
+    /// Providers fees are added to the input token liquidity, protocol fees are accumulated for redemption.
+    ///
+    /// # Parameters
+    /// - `swap_payload`: Contains the updated liquidity values, fees, and swap details.
+    #[inline(never)]
+    pub(crate) fn swap(&mut self, swap_payload: StableSwapPayload) {
+        self.base_liquidity = swap_payload.base_liquidity;
+        self.quote_liquidity = swap_payload.quote_liquidity;
+        if swap_payload.is_in_out{
+            self.protocol_base_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
+            self.base_liquidity += swap_payload.providers_fee_amount
+        }
+        else{
+            self.protocol_quote_fees_to_redeem = swap_payload.protocol_fees_to_redeem;
+            self.quote_liquidity += swap_payload.providers_fee_amount
+        }
+    }
+
+    /// Updates the protocol fees for the AMM based on the provided payload.
+    ///
+    /// # Parameters
+    /// - `collect_fees_payload`: A `CollectFeesPayload` containing the updated protocol fees
+    ///   for both base and quote tokens.
+    #[inline(never)]
+    pub(crate) fn collect_fees(&mut self, collect_fees_payload: CollectFeesPayload) {
+        self.protocol_base_fees_to_redeem = collect_fees_payload.new_protocol_base_fees_to_redeem();
+        self.protocol_quote_fees_to_redeem = collect_fees_payload.new_protocol_quote_fees_to_redeem();
+    }
+}
+
+#[cfg(test)]
+mod stable_amm_tests {
+    use anchor_lang::Discriminator;
+    use utilities::constants::ANCHOR_DISCRIMINATOR;
+    use super::*;
+
+    /// Builds a launched balanced `StableAmm` with the given liquidity and amplification.
+    fn launched_stable_amm(liquidity: u64, amplification: u64) -> StableAmm {
+        let invariant = StableAmm::calculate_invariant(amplification, liquidity, liquidity).unwrap();
+        StableAmm {
+            is_initialized: true,
+            is_launched: true,
+            bump: [0],
+            base_vault_bump: [0],
+            quote_vault_bump: [0],
+            locked_lp_vault_bump: [0],
+            initial_locked_liquidity: StableAmm::INITIAL_LOCKED_LP_TOKENS,
+            base_liquidity: liquidity,
+            quote_liquidity: liquidity,
+            lp_tokens_supply: invariant as u64,
+            protocol_base_fees_to_redeem: 0,
+            protocol_quote_fees_to_redeem: 0,
+            initial_amplification: amplification,
+            target_amplification: amplification,
+            ramp_start_timestamp: 0,
+            ramp_end_timestamp: 0,
+            base_mint: Pubkey::default(),
+            quote_mint: Pubkey::default(),
+            lp_mint: Pubkey::default(),
+            base_vault: Pubkey::default(),
+            quote_vault: Pubkey::default(),
+            locked_lp_vault: Pubkey::default(),
+            amms_config: Pubkey::default(),
+            creator: Pubkey::default(),
+        }
+    }
+
+    /// Tests `StableAmm` account data layout.
+    #[test]
+    fn test_stable_amm_data_layout() {
+        let mut stable_amm = launched_stable_amm(1_000_000, 100);
+        stable_amm.protocol_base_fees_to_redeem = 1_000;
+        stable_amm.protocol_quote_fees_to_redeem = 500;
+        stable_amm.target_amplification = 200;
+        stable_amm.ramp_start_timestamp = 1_700_000_000;
+        stable_amm.ramp_end_timestamp = 1_700_086_400;
+        stable_amm.lp_mint = Pubkey::new_unique();
+        stable_amm.amms_config = Pubkey::new_unique();
+        stable_amm.creator = Pubkey::new_unique();
+        stable_amm.bump = [42];
+
+        let mut data = [0u8; ANCHOR_DISCRIMINATOR + 342];
+        let mut offset = 0;
+
+        data[offset..offset + ANCHOR_DISCRIMINATOR].copy_from_slice(&StableAmm::discriminator()); offset += ANCHOR_DISCRIMINATOR;
+        data[offset] = 1; offset += 1;
+        data[offset] = 1; offset += 1;
+        data[offset] = 42; offset += 1;
+        offset += 3;
+        data[offset..offset + 8].copy_from_slice(&stable_amm.initial_locked_liquidity.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&1_000_000u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&1_000_000u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&stable_amm.lp_tokens_supply.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&1_000u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&500u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&100u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&200u64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&1_700_000_000i64.to_le_bytes()); offset += 8;
+        data[offset..offset + 8].copy_from_slice(&1_700_086_400i64.to_le_bytes()); offset += 8;
+        offset += 64;
+        data[offset..offset + 32].copy_from_slice(stable_amm.lp_mint.as_ref()); offset += 32;
+        offset += 96;
+        data[offset..offset + 32].copy_from_slice(stable_amm.amms_config.as_ref()); offset += 32;
+        data[offset..offset + 32].copy_from_slice(stable_amm.creator.as_ref()); offset += 32;
+
+        assert_eq!(ANCHOR_DISCRIMINATOR + StableAmm::INIT_SPACE, offset);
+
+        let deserialized_stable_amm = StableAmm::try_deserialize(&mut data.as_ref()).unwrap();
+        assert_eq!(deserialized_stable_amm.lp_tokens_supply, stable_amm.lp_tokens_supply);
+        assert_eq!(deserialized_stable_amm.target_amplification, 200);
+        assert_eq!(deserialized_stable_amm.ramp_end_timestamp, 1_700_086_400);
+        assert_eq!(deserialized_stable_amm.lp_mint, stable_amm.lp_mint);
+        assert_eq!(deserialized_stable_amm.creator, stable_amm.creator);
+
+        let mut serialized_stable_amm = Vec::new();
+        stable_amm.try_serialize(&mut serialized_stable_amm).unwrap();
+        assert_eq!(serialized_stable_amm.as_slice(), data.as_ref());
+    }
+
+    /// Tests the amplification coefficient ramp.
+    #[test]
+    fn test_ramp_amplification() {
+        let mut stable_amm = launched_stable_amm(1_000_000, 100);
+        let duration = StableAmm::MIN_AMPLIFICATION_RAMP_DURATION;
+
+        assert!(stable_amm.ramp_amplification(200, 1_000, 1_000 + duration - 1).is_err());
+        assert!(stable_amm.ramp_amplification(1_001, 1_000, 1_000 + duration).is_err());
+        assert!(stable_amm.ramp_amplification(9, 1_000, 1_000 + duration).is_err());
+        assert!(stable_amm.ramp_amplification(0, 1_000, 1_000 + duration).is_err());
+
+        stable_amm.ramp_amplification(200, 1_000, 1_000 + duration).unwrap();
+        assert_eq!(stable_amm.amplification(0), 100);
+        assert_eq!(stable_amm.amplification(1_000 + duration / 2), 150);
+        assert_eq!(stable_amm.amplification(1_000 + duration), 200);
+        assert_eq!(stable_amm.amplification(i64::MAX), 200);
+
+        // A new ramp starts from the current coefficient
+        stable_amm.ramp_amplification(50, 1_000 + duration / 2, 1_000 + duration * 2).unwrap();
+        assert_eq!(stable_amm.initial_amplification(), 150);
+        assert_eq!(stable_amm.amplification(1_000 + duration * 2), 50);
+    }
+
+    /// Tests the launch, provide, swap and withdraw payloads and state changes.
+    #[test]
+    fn test_operations() {
+        let mut stable_amm = launched_stable_amm(1_000_000_000, 100);
+        stable_amm.is_launched = false;
+        let launch_payload = stable_amm.get_launch_payload(1_000_000_000, 1_000_000_000, 0).unwrap();
+        assert_eq!(launch_payload.launch_liquidity(), 2_000_000_000 - StableAmm::INITIAL_LOCKED_LP_TOKENS);
+        stable_amm.launch(launch_payload);
+        assert!(stable_amm.get_launch_payload(1_000_000_000, 1_000_000_000, 0).is_err());
+
+        let provide_payload = stable_amm.get_provide_payload(100_000_000, 100_000_000, 0).unwrap();
+        assert_eq!(provide_payload.lp_tokens_to_mint(), 200_000_000);
+        assert!(stable_amm.get_provide_payload(100_000_000, 90_000_000, 0).is_err());
+        stable_amm.provide(provide_payload);
+        assert_eq!(stable_amm.lp_tokens_supply(), 2_200_000_000);
+
+        let swap_payload = stable_amm.get_swap_payload(10_000_000, 9_960_000, 40_000, 30, 10, 0, true, 0).unwrap();
+        let amount_to_withdraw = swap_payload.amount_to_withdraw();
+        assert!(amount_to_withdraw > 9_950_000 && amount_to_withdraw < 9_960_000);
+        assert!(stable_amm.get_swap_payload(10_000_000, 9_000_000, 10, 30, 10, 0, true, 0).is_err());
+
+        // The referrer share reduces the protocol fees only
+        let referred_swap_payload = stable_amm.get_swap_payload(10_000_000, 9_960_000, 40_000, 30, 10, 2_000, true, 0).unwrap();
+        assert_eq!(referred_swap_payload.referrer_fee_amount(), 2_000);
+        assert_eq!(referred_swap_payload.protocol_fees_to_redeem, 8_000);
+        assert_eq!(referred_swap_payload.amount_to_withdraw(), amount_to_withdraw);
+        assert!(stable_amm.get_swap_payload(10_000_000, 9_960_000, 40_000, 30, 10, AmmsConfig::MAX_REFERRER_FEE_SHARE_BASIS_POINTS + 1, true, 0).is_err());
+        stable_amm.swap(swap_payload);
+        assert_eq!(stable_amm.base_liquidity(), 1_100_000_000 + 10_000_000 - 10_000);
+        assert_eq!(stable_amm.quote_liquidity(), 1_100_000_000 - amount_to_withdraw);
+        assert_eq!(stable_amm.protocol_base_fees_to_redeem(), 10_000);
+
+        let withdraw_payload = stable_amm.get_withdraw_payload(220_000_000).unwrap();
+        assert_eq!(withdraw_payload.base_withdraw_amount(), stable_amm.base_liquidity() / 10);
+        assert_eq!(withdraw_payload.quote_withdraw_amount(), stable_amm.quote_liquidity() / 10);
+        stable_amm.withdraw(withdraw_payload);
+        assert_eq!(stable_amm.lp_tokens_supply(), 1_980_000_000);
+
+        let collect_fees_payload = stable_amm.get_collect_fees_payload().unwrap();
+        assert_eq!(collect_fees_payload.protocol_base_fees_to_redeem(), 10_000);
+        stable_amm.collect_fees(collect_fees_payload);
+        assert_eq!(stable_amm.protocol_base_fees_to_redeem(), 0);
+        assert!(stable_amm.get_collect_fees_payload().is_err());
+    }
+}
+
+/// Represents the data required to launch the StableSwap AMM.
+///
+/// # Fields
+/// - `initial_locked_liquidity`: The amount of liquidity that will be locked in the pool upon launch.
+/// - `base_liquidity`: The initial base token liquidity in the pool.
+/// - `quote_liquidity`: The initial quote token liquidity in the pool.
+/// - `lp_tokens_supply`: The total supply of LP tokens minted upon launch.
+#[derive(Debug)]
+pub struct StableLaunchPayload {
+    initial_locked_liquidity: u64,
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    lp_tokens_supply: u64,
+}
+impl StableLaunchPayload {
+    /// Returns the amount of initially locked liquidity.
+    pub fn initial_locked_liquidity(&self) -> u64{
+        self.initial_locked_liquidity
+    }
+
+    /// Returns the total liquidity available for use after subtracting the locked liquidity.
+    pub fn launch_liquidity(&self) -> u64{
+        self.lp_tokens_supply.checked_sub(self.initial_locked_liquidity).unwrap()
+    }
+}
+
+/// Represents the data required to provide liquidity to the StableSwap AMM.
+///
+/// # Fields
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+/// - `lp_tokens_supply`: The updated total supply of LP tokens.
+/// - `lp_tokens_to_mint`: The number of LP tokens to mint for the liquidity provider.
+#[derive(Debug)]
+pub struct StableProvidePayload {
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    lp_tokens_supply: u64,
+    lp_tokens_to_mint: u64,
+}
+impl StableProvidePayload {
+    /// Returns the number of LP tokens to mint for the liquidity provider.
+    pub fn lp_tokens_to_mint(&self) -> u64{
+        self.lp_tokens_to_mint
+    }
+}
+
+/// Represents the data required to withdraw liquidity from the StableSwap AMM.
+///
+/// # Fields
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+/// - `lp_tokens_supply`: The updated total supply of LP tokens.
+/// - `base_withdraw_amount`: The amount of base tokens withdrawn.
+/// - `quote_withdraw_amount`: The amount of quote tokens withdrawn.
+#[derive(Debug)]
+pub struct StableWithdrawPayload {
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    lp_tokens_supply: u64,
+    base_withdraw_amount: u64,
+    quote_withdraw_amount: u64
+}
+impl StableWithdrawPayload {
+    /// Returns the amount of base tokens withdrawn.
+    pub fn base_withdraw_amount(&self) -> u64{
+        self.base_withdraw_amount
+    }
+
+    /// Returns the amount of quote tokens withdrawn.
+    pub fn quote_withdraw_amount(&self) -> u64{
+        self.quote_withdraw_amount
+    }
+}
+
+/// Represents the data required for a token swap operation in the StableSwap AMM.
+///
+/// # Fields
+/// - `base_liquidity`: The updated base token liquidity in the pool.
+/// - `quote_liquidity`: The updated quote token liquidity in the pool.
+/// - `protocol_fees_to_redeem`: The protocol fees to redeem updated with fees collected from the swap.
+/// - `providers_fee_amount`: The providers fees collected from the swap.
+/// - `referrer_fee_amount`: The part of the protocol fee paid to the swap referrer.
+/// - `amount_to_withdraw`: The amount of tokens to withdraw after the swap.
+/// - `is_in_out`: Indicates whether the swap is "in-to-out" (true) or "out-to-in" (false).
+#[derive(Debug)]
+pub struct StableSwapPayload {
+    base_liquidity: u64,
+    quote_liquidity: u64,
+    protocol_fees_to_redeem: u64,
+    providers_fee_amount: u64,
+    referrer_fee_amount: u64,
+    amount_to_withdraw: u64,
+    is_in_out: bool,
+}
+impl StableSwapPayload {
+    /// Returns the amount of tokens to withdraw after the swap.
+    pub fn amount_to_withdraw(&self) -> u64{
+        self.amount_to_withdraw
+    }
+
+    /// Returns the part of the protocol fee paid to the swap referrer, in input tokens.
+    pub fn referrer_fee_amount(&self) -> u64{
+        self.referrer_fee_amount
+    }
+}
@@ -18,5 +18,8 @@ pub(crate) enum ErrorCode {
     InsufficientBalanceForTransfer,
 
     #[msg("Mint and Token Program mismatch")]
-    MintAndTokenProgramMismatch
+    MintAndTokenProgramMismatch,
+
+    #[msg("Account already has the current layout.")]
+    AccountAlreadyMigrated
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use crate::error::ErrorCode;
+use crate::system_instructions::TransferLamportsInstruction;
 use anchor_spl::{
     token::{ID as TOKEN_PROGRAM_ID},
     token_2022::{ID as TOKEN_2022_PROGRAM_ID},
@@ -7,6 +9,16 @@ use anchor_spl::{
 };
 use anchor_spl::token_2022::spl_token_2022;
 use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+
+/// The Metaplex Token Metadata program (`metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`), holding the metadata of SPL Token mints.
+pub const METAPLEX_TOKEN_METADATA_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205,
+    88, 184, 108, 115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70
+]);
+
+/// The maximal number of characters of a mint symbol returned by `get_mint_symbol`.
+pub const MAX_MINT_SYMBOL_LENGTH: usize = 10;
 
 /// A list of allowed token extensions for tradable SPL Token 2022 mints.
 pub const ALLOWED_TRADABLE_TOKEN_EXTENSIONS: &[ExtensionType] = &[
@@ -88,4 +100,109 @@ pub fn validate_stakable_mint(stakable_mint: &InterfaceAccount<Mint>) -> Result<
         TOKEN_PROGRAM_ID => Ok(()),
         _ => Err(ErrorCode::UnsupportedMint.into()),
     }
-}
\ No newline at end of file
+}
+/// Retrieves the symbol of a token mint.
+///
+/// # Parameters
+/// - `mint`: The token mint.
+/// - `metaplex_metadata`: Optional Metaplex metadata account of the mint.
+///
+/// # Returns
+/// The first of the following, capped to `MAX_MINT_SYMBOL_LENGTH` characters:
+/// 1. The symbol of the TokenMetadata extension of a Token 2022 mint.
+/// 2. The symbol of the Metaplex metadata account, if it belongs to the mint.
+/// 3. The first characters of the mint address.
+pub fn get_mint_symbol(mint: &InterfaceAccount<Mint>, metaplex_metadata: Option<&AccountInfo>) -> String {
+    let symbol = get_token_metadata_symbol(mint)
+        .or_else(|| metaplex_metadata.and_then(|metadata| get_metaplex_metadata_symbol(&mint.key(), metadata)))
+        .filter(|symbol| !symbol.is_empty())
+        .unwrap_or_else(|| mint.key().to_string());
+    symbol.chars().take(MAX_MINT_SYMBOL_LENGTH).collect()
+}
+
+fn get_token_metadata_symbol(mint: &InterfaceAccount<Mint>) -> Option<String> {
+    let mint_account_info = mint.to_account_info();
+    if mint_account_info.owner.key() != TOKEN_2022_PROGRAM_ID {
+        return None;
+    }
+    let mint_data = mint_account_info.try_borrow_data().ok()?;
+    let token_metadata = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data).ok()?
+        .get_variable_len_extension::<TokenMetadata>().ok()?;
+    (token_metadata.mint == mint.key()).then(|| token_metadata.symbol.trim().to_string())
+}
+
+fn get_metaplex_metadata_symbol(mint: &Pubkey, metaplex_metadata: &AccountInfo) -> Option<String> {
+    // Metaplex `MetadataV1` layout: key (1), update authority (32), mint (32), name (string), symbol (string)
+    const METADATA_V1_KEY: u8 = 4;
+    const NAME_OFFSET: usize = 65;
+
+    if metaplex_metadata.owner.key() != METAPLEX_TOKEN_METADATA_PROGRAM_ID {
+        return None;
+    }
+    let data = metaplex_metadata.try_borrow_data().ok()?;
+    if *data.first()? != METADATA_V1_KEY || data.get(33..NAME_OFFSET)? != mint.as_ref() {
+        return None;
+    }
+    let mut metadata_strings = data.get(NAME_OFFSET..)?;
+    let _name = String::deserialize(&mut metadata_strings).ok()?;
+    let symbol = String::deserialize(&mut metadata_strings).ok()?;
+    Some(symbol.trim_end_matches('\0').trim().to_string())
+}
+
+/// Splits the remaining accounts of an instruction into the leading chunks of `chunk_len` accounts,
+/// each starting with an account of type `T`, and the trailing accounts.
+///
+/// Lets batch instructions describing their items with remaining accounts also take the accounts
+/// required by the transfer hooks of the transferred mints after the items.
+///
+/// # Parameters
+/// - `remaining_accounts`: The remaining accounts of the instruction.
+/// - `chunk_len`: The number of accounts describing a single item.
+///
+/// # Returns
+/// A tuple of the item accounts and the trailing accounts.
+pub fn split_remaining_accounts<'a, 'info, T: Discriminator + Owner>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    chunk_len: usize
+) -> (&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]) {
+    let chunks_count = remaining_accounts
+        .chunks_exact(chunk_len)
+        .take_while(|chunk| {
+            chunk[0].owner.key() == T::owner()
+                && chunk[0].try_borrow_data().is_ok_and(|data| data.starts_with(&T::DISCRIMINATOR))
+        })
+        .count();
+    remaining_accounts.split_at(chunks_count * chunk_len)
+}
+
+/// Grows a program account of type `T` created with an older, shorter layout to `new_len` bytes.
+///
+/// Account layouts are only extended by appending fields, so every older layout is a prefix of the
+/// current one and the appended fields start zeroed. The payer tops up the rent-exempt balance.
+///
+/// # Parameters
+/// - `account`: The account to grow.
+/// - `new_len`: The size of the current layout, including the discriminator.
+/// - `payer`: The account paying for the additional rent.
+/// - `system_program`: The system program.
+///
+/// # Errors
+/// - Returns `AccountOwnedByWrongProgram` or `AccountDiscriminatorMismatch` if the account is not a `T`.
+/// - Returns `ErrorCode::AccountAlreadyMigrated` if the account already has the current layout.
+pub fn migrate_account_layout<'info, T: Discriminator + Owner>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: AccountInfo<'info>,
+    system_program: &Program<'info, System>
+) -> Result<()> {
+    require!(account.owner.key() == T::owner(), anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+    require!(account.try_borrow_data()?.starts_with(&T::DISCRIMINATOR), anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch);
+    require!(account.data_len() < new_len, ErrorCode::AccountAlreadyMigrated);
+
+    let rent_top_up = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        TransferLamportsInstruction::new(rent_top_up, payer, account.clone(), system_program)?.execute()?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
@@ -404,6 +404,42 @@ impl Q64_128 {
 		result.checked_as_q64_128()
 	}
 
+	/// Performs a wrapping addition of two `Q64_128` values.
+	///
+	/// # Parameters
+	/// - `self`: The first `Q64_128` value.
+	/// - `rhs`: The second `Q64_128` value to add to `self`.
+	///
+	/// # Returns
+	/// The sum of the two values modulo 2^192.
+	pub fn wrapping_add(self, rhs: Self) -> Self {
+		Self::new(self.value.overflowing_add(rhs.value).0)
+	}
+
+	/// Performs a wrapping subtraction of two `Q64_128` values.
+	///
+	/// # Parameters
+	/// - `self`: The first `Q64_128` value.
+	/// - `rhs`: The second `Q64_128` value to subtract from `self`.
+	///
+	/// # Returns
+	/// The difference of the two values modulo 2^192.
+	pub fn wrapping_sub(self, rhs: Self) -> Self {
+		Self::new(self.value.overflowing_sub(rhs.value).0)
+	}
+
+	/// Performs a wrapping multiplication of a `Q64_128` value by an unsigned 64-bit integer.
+	///
+	/// # Parameters
+	/// - `self`: The `Q64_128` value.
+	/// - `rhs`: The integer multiplier.
+	///
+	/// # Returns
+	/// The product of the two values modulo 2^192.
+	pub fn wrapping_mul_u64(self, rhs: u64) -> Self {
+		Self::new(self.value.overflowing_mul(U192::from(rhs)).0)
+	}
+
 	/// Performs a saturating multiplication of two `Q64_128` values.
 	///
 	/// # Parameters
@@ -833,6 +869,35 @@ mod tests {
 				);
 			}
 
+			/// Tests wrapping addition, subtraction and multiplication of `Q64_128` instances.
+			#[test]
+			fn test_wrapping_operations() {
+				let max_value = Q64_128::MAX;
+				let value1 = Q64_128::from_u64(1);
+				let value2 = Q64_128::from_u64(100);
+
+				assert_eq!(
+					max_value.wrapping_add(value1),
+					Q64_128::from_u64(1) - Q64_128::new(U192::one()),
+					"Wrapping addition should wrap around on overflow."
+				);
+				assert_eq!(
+					max_value.wrapping_add(value2).wrapping_sub(max_value),
+					value2,
+					"Wrapping subtraction should restore the value wrapped by addition."
+				);
+				assert_eq!(
+					value2.wrapping_mul_u64(3).as_u64(),
+					300,
+					"Wrapping multiplication failed: expected 300."
+				);
+				assert_eq!(
+					Q64_128::from_u64(u64::MAX).wrapping_mul_u64(2),
+					Q64_128::from_u64(u64::MAX - 1),
+					"Wrapping multiplication should drop overflowing integer bits."
+				);
+			}
+
 			/// Tests checked multiplication of two `Q64_128` instances with overflow handling.
 			#[test]
 			fn test_checked_mul() {
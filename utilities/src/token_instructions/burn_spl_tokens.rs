@@ -1,7 +1,7 @@
 use anchor_lang::context::CpiContext;
 use anchor_lang::prelude::*;
 use anchor_lang::ToAccountInfo;
-use anchor_spl::token::{Mint, Token, Burn, burn, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenInterface, Burn, burn, TokenAccount};
 use crate::error::ErrorCode;
 
 /// Represents an instruction to burn tokens from a token account.
@@ -23,8 +23,8 @@ impl<'at, 'bt, 'ct, 'info> BurnTokensInstructions<'at, 'bt, 'ct, 'info> {
     /// - `mint`: The mint account of the token.
     /// - `from`: The token account from which tokens will be burned.
     /// - `from_authority`: The authority of the token account.
-    /// - `token_program`: The SPL token program responsible for handling the burn operation.
-    pub fn try_new(amount: u64, mint: &Account<'info, Mint>, from: &Account<'info, TokenAccount>, from_authority: AccountInfo<'info>, token_program: &Program<'info, Token>) -> Result<Self>{
+    /// - `token_program`: The token program (SPL Token or Token 2022) responsible for handling the burn operation.
+    pub fn try_new(amount: u64, mint: &InterfaceAccount<'info, Mint>, from: &InterfaceAccount<'info, TokenAccount>, from_authority: AccountInfo<'info>, token_program: &Interface<'info, TokenInterface>) -> Result<Self>{
         require!(from.amount >= amount, ErrorCode::InsufficientBalanceForTransfer);
         let cpi_context = CpiContext::new(
             token_program.to_account_info(),
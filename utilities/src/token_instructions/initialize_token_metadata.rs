@@ -0,0 +1,100 @@
+use anchor_lang::context::CpiContext;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    Mint,
+    TokenMetadataInitialize,
+    token_metadata_initialize,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    spl_token_metadata_interface::state::TokenMetadata
+};
+
+/// Represents an instruction to initialize the TokenMetadata extension of a Token 2022 mint.
+///
+/// The mint must already have the MetadataPointer extension pointing to itself.
+/// Token 2022 reallocates the mint to fit the metadata, so the missing rent is topped up
+/// by the payer before the initialization.
+///
+/// - `rent_cpi_context`: The CPI context transferring the missing rent to the mint.
+/// - `initialize_cpi_context`: The CPI context initializing the metadata.
+/// - `lamports`: The rent lamports missing after the reallocation.
+/// - `name`, `symbol`, `uri`: The metadata fields.
+pub struct InitializeTokenMetadataInstruction<'at, 'bt, 'ct, 'info> {
+    rent_cpi_context: CpiContext<'at, 'bt, 'ct, 'info, Transfer<'info>>,
+    initialize_cpi_context: CpiContext<'at, 'bt, 'ct, 'info, TokenMetadataInitialize<'info>>,
+    lamports: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+impl<'at, 'bt, 'ct, 'info> InitializeTokenMetadataInstruction<'at, 'bt, 'ct, 'info> {
+    /// Creates a new `InitializeTokenMetadataInstruction` instance.
+    ///
+    /// - `payer`: The account paying the rent of the metadata.
+    /// - `mint`: The Token 2022 mint holding the metadata.
+    /// - `mint_authority`: The mint authority, which must sign the initialization.
+    /// - `update_authority`: The authority allowed to update the metadata.
+    /// - `token_program`: The Token 2022 program.
+    /// - `system_program`: The system program used to transfer the rent.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        payer: AccountInfo<'info>,
+        mint: &InterfaceAccount<'info, Mint>,
+        mint_authority: AccountInfo<'info>,
+        update_authority: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        name: String,
+        symbol: String,
+        uri: String
+    ) -> Result<Self> {
+        let mint_account_info = mint.to_account_info();
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(update_authority.key()))?,
+            mint: mint.key(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: vec![],
+        };
+        let space = mint_account_info.data_len() + token_metadata.tlv_size_of()?;
+        let lamports = Rent::get()?.minimum_balance(space).saturating_sub(mint_account_info.lamports());
+
+        let rent_cpi_context = CpiContext::new(
+            system_program,
+            Transfer {
+                from: payer,
+                to: mint_account_info.clone(),
+            }
+        );
+        let initialize_cpi_context = CpiContext::new(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: token_program,
+                metadata: mint_account_info.clone(),
+                update_authority,
+                mint_authority,
+                mint: mint_account_info,
+            }
+        );
+        Ok(Self {
+            rent_cpi_context,
+            initialize_cpi_context,
+            lamports,
+            name,
+            symbol,
+            uri,
+        })
+    }
+
+    /// Executes the rent top-up and the metadata initialization.
+    ///
+    /// - `signers_seeds`: The seeds of the mint authority if it is a PDA.
+    pub fn execute(self, signers_seeds: &'at[&'bt[&'ct[u8]]]) -> Result<()> {
+        if self.lamports > 0 {
+            transfer(self.rent_cpi_context, self.lamports)?;
+        }
+        token_metadata_initialize(self.initialize_cpi_context.with_signer(signers_seeds), self.name, self.symbol, self.uri)
+    }
+}
@@ -1,7 +1,7 @@
 use anchor_lang::context::CpiContext;
 use anchor_lang::prelude::*;
 use anchor_lang::ToAccountInfo;
-use anchor_spl::token::{mint_to, MintTo, Mint, Token};
+use anchor_spl::token_interface::{mint_to, MintTo, Mint, TokenInterface};
 
 
 /// Represents an instruction to mint tokens to a specified token account.
@@ -23,8 +23,8 @@ impl<'at, 'bt, 'ct, 'info> MintTokensInstructions<'at, 'bt, 'ct, 'info> {
     /// - `mint`: The mint account of the token.
     /// - `mint_authority`: The authority allowed to mint tokens.
     /// - `to`: The destination token account where tokens will be minted.
-    /// - `token_program`: The SPL token program responsible for handling the mint operation.
-    pub fn new(amount: u64, mint: &Account<'info, Mint>, mint_authority: AccountInfo<'info>, to: AccountInfo<'info>, token_program: &Program<'info, Token>) -> Self{
+    /// - `token_program`: The token program (SPL Token or Token 2022) responsible for handling the mint operation.
+    pub fn new(amount: u64, mint: &InterfaceAccount<'info, Mint>, mint_authority: AccountInfo<'info>, to: AccountInfo<'info>, token_program: &Interface<'info, TokenInterface>) -> Self{
         let cpi_context = CpiContext::new(
             token_program.to_account_info(), 
             MintTo{
@@ -3,10 +3,12 @@ mod transfer_context_regular;
 mod transfer_context_with_fee;
 mod mint_spl_tokens;
 mod burn_spl_tokens;
+mod initialize_token_metadata;
 
 pub use transfer_tokens::*;
 pub use mint_spl_tokens::*;
 pub use burn_spl_tokens::*;
+pub use initialize_token_metadata::*;
 
 use transfer_context_regular::*;
 use transfer_context_with_fee::*;
\ No newline at end of file
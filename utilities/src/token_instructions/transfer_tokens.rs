@@ -4,21 +4,29 @@ use anchor_spl::{
 };
 use anchor_spl::token_interface::{get_mint_extension_data, transfer_checked, transfer_checked_with_fee, Mint, TokenInterface, TokenAccount};
 use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_hook::TransferHook;
+use anchor_spl::token_interface::spl_token_2022::{self, extension::transfer_fee::instruction::transfer_checked_with_fee as transfer_checked_with_fee_instruction};
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use spl_transfer_hook_interface::onchain::add_extra_accounts_for_execute_cpi;
 use super::{TransferContextRegular, TransferContextWithFee};
 use crate::error::ErrorCode;
 
 /// Represents an instruction to transfer tokens between accounts.
 ///
-/// This struct handles both standard SPL tokens and SPL Token 2022 with transfer fees.
+/// This struct handles both standard SPL tokens and SPL Token 2022 with transfer fees and transfer hooks.
 ///
 /// # Fields
 /// - `amount`: The amount of tokens to transfer.
 /// - `decimals`: Number of decimal places in the token's representation.
 /// - `context`: Encapsulates the transfer context, which can be either a regular transfer or one with fees.
+/// - `transfer_hook_program_id`: The transfer hook program of the mint, if it has the `TransferHook` extension.
+/// - `transfer_hook_accounts`: Accounts to resolve the `ExtraAccountMetaList` of the transfer hook program from.
 pub struct TransferTokensInstruction<'at, 'bt, 'ct, 'info> {
     amount: u64,
     decimals: u8,
     context: TransferContextType<'at, 'bt, 'ct, 'info>,
+    transfer_hook_program_id: Option<Pubkey>,
+    transfer_hook_accounts: Vec<AccountInfo<'info>>,
 }
 impl<'at, 'bt, 'ct, 'info>  TransferTokensInstruction<'at, 'bt, 'ct, 'info>  {
 
@@ -69,13 +77,84 @@ impl<'at, 'bt, 'ct, 'info>  TransferTokensInstruction<'at, 'bt, 'ct, 'info>  {
             )
         };
 
+        let transfer_hook_program_id = get_mint_extension_data::<TransferHook>(&mint.to_account_info())
+            .ok()
+            .and_then(|transfer_hook| Option::<Pubkey>::from(transfer_hook.program_id));
+
         Ok(Self {
             amount,
             decimals: mint.decimals,
             context,
+            transfer_hook_program_id,
+            transfer_hook_accounts: Vec::new(),
         })
     }
 
+    /// Provides the accounts required by the transfer hook program of the mint.
+    ///
+    /// The accounts are looked up by key, so the whole list of remaining accounts of an instruction
+    /// can be passed as long as it contains the hook program, its `ExtraAccountMetaList` account
+    /// and every account the list resolves to. Has no effect for mints without a transfer hook.
+    ///
+    /// - `remaining_accounts`: Accounts to resolve the transfer hook accounts from.
+    ///
+    /// Returns:
+    /// - The instruction with the transfer hook accounts attached.
+    pub fn with_transfer_hook_accounts(mut self, remaining_accounts: &[AccountInfo<'info>]) -> Self {
+        if self.transfer_hook_program_id.is_some() {
+            self.transfer_hook_accounts = remaining_accounts.to_vec();
+        }
+        self
+    }
+
+    /// Calculates the amount of tokens that must be sent so that the receiver gets `amount_after_fee`.
+    ///
+    /// For mints without the `TransferFeeConfig` extension the amount is returned unchanged.
+    ///
+    /// - `amount_after_fee`: The amount of tokens the receiver must get.
+    /// - `mint`: The mint account of the token.
+    ///
+    /// Returns:
+    /// - `Ok(u64)` with the amount of tokens to transfer including the transfer fee.
+    /// - `Err(ErrorCode)` if the fee calculation fails.
+    pub fn calculate_amount_before_fee(
+        amount_after_fee: u64,
+        mint: &'_ InterfaceAccount<'info, Mint>
+    ) -> Result<u64> {
+        if mint.to_account_info().owner.key() == TOKEN_PROGRAM_ID {
+            return Ok(amount_after_fee);
+        }
+        if let Ok(transfer_fee_config) = get_mint_extension_data::<TransferFeeConfig>(&mint.to_account_info()){
+            let fee = transfer_fee_config.calculate_inverse_epoch_fee(Clock::get()?.epoch, amount_after_fee).ok_or(ErrorCode::MintTransferFeeCalculationFailed)?;
+            return Ok(amount_after_fee.checked_add(fee).ok_or(ErrorCode::MintTransferFeeCalculationFailed)?);
+        }
+        Ok(amount_after_fee)
+    }
+
+    /// Calculates the amount of tokens the receiver gets when `amount` is sent.
+    ///
+    /// For mints without the `TransferFeeConfig` extension the amount is returned unchanged.
+    ///
+    /// - `amount`: The amount of tokens to transfer.
+    /// - `mint`: The mint account of the token.
+    ///
+    /// Returns:
+    /// - `Ok(u64)` with the amount of tokens received after the transfer fee.
+    /// - `Err(ErrorCode)` if the fee calculation fails.
+    pub fn calculate_amount_after_fee(
+        amount: u64,
+        mint: &'_ InterfaceAccount<'info, Mint>
+    ) -> Result<u64> {
+        if mint.to_account_info().owner.key() == TOKEN_PROGRAM_ID {
+            return Ok(amount);
+        }
+        if let Ok(transfer_fee_config) = get_mint_extension_data::<TransferFeeConfig>(&mint.to_account_info()){
+            let fee = transfer_fee_config.calculate_epoch_fee(Clock::get()?.epoch, amount).ok_or(ErrorCode::MintTransferFeeCalculationFailed)?;
+            return Ok(amount.checked_sub(fee).ok_or(ErrorCode::MintTransferFeeCalculationFailed)?);
+        }
+        Ok(amount)
+    }
+
     /// Executes the transfer operation.
     ///
     /// - `optional_signers_seeds`: Optional signer seeds for PDA accounts.
@@ -88,6 +167,9 @@ impl<'at, 'bt, 'ct, 'info>  TransferTokensInstruction<'at, 'bt, 'ct, 'info>  {
         if let Some(signer_seeds) = optional_signers_seeds {
             self.context = self.context.add_signers_seeds(signer_seeds);
         }
+        if let Some(transfer_hook_program_id) = self.transfer_hook_program_id {
+            return self.execute_with_transfer_hook(&transfer_hook_program_id);
+        }
         match self.context {
             TransferContextType::Regular(context) => {
                 transfer_checked(context.cpi_context, self.amount, self.decimals)
@@ -98,6 +180,36 @@ impl<'at, 'bt, 'ct, 'info>  TransferTokensInstruction<'at, 'bt, 'ct, 'info>  {
         }
     }
 
+    /// Executes the transfer, appending the accounts resolved from the `ExtraAccountMetaList`
+    /// of the transfer hook program, so the token program can invoke the hook.
+    ///
+    /// - `transfer_hook_program_id`: The transfer hook program of the mint.
+    ///
+    /// Returns:
+    /// - `Ok(())` if the transfer is successful.
+    /// - `Err(ErrorCode)` if the transfer hook accounts are missing or the transfer fails.
+    fn execute_with_transfer_hook(self, transfer_hook_program_id: &Pubkey) -> Result<()>{
+        let (instruction, from, mint, to, authority, signer_seeds) = match self.context {
+            TransferContextType::Regular(context) => {
+                let CpiContext { accounts, program, signer_seeds, .. } = context.cpi_context;
+                let instruction = spl_token_2022::instruction::transfer_checked(
+                    program.key, accounts.from.key, accounts.mint.key, accounts.to.key, accounts.authority.key, &[], self.amount, self.decimals
+                )?;
+                (instruction, accounts.from, accounts.mint, accounts.to, accounts.authority, signer_seeds)
+            },
+            TransferContextType::WithFee(context) => {
+                let CpiContext { accounts, program, signer_seeds, .. } = context.cpi_context;
+                let instruction = transfer_checked_with_fee_instruction(
+                    program.key, accounts.source.key, accounts.mint.key, accounts.destination.key, accounts.authority.key, &[], self.amount, self.decimals, context.fee
+                )?;
+                (instruction, accounts.source, accounts.mint, accounts.destination, accounts.authority, signer_seeds)
+            }
+        };
+        invoke_transfer_with_hook(
+            instruction, transfer_hook_program_id, from, mint, to, authority, self.amount, &self.transfer_hook_accounts, signer_seeds
+        )
+    }
+
     /// Gets the number of decimals for the token.
     ///
     /// Returns:
@@ -168,4 +280,33 @@ impl<'at, 'bt, 'ct> TransferContextType<'at, 'bt, 'ct, '_>{
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Invokes a transfer instruction of SPL Token 2022 with the accounts required by the transfer hook of the mint.
+#[allow(clippy::too_many_arguments)]
+fn invoke_transfer_with_hook<'info>(
+    mut instruction: Instruction,
+    transfer_hook_program_id: &Pubkey,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    transfer_hook_accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut account_infos = vec![from.clone(), mint.clone(), to.clone(), authority.clone()];
+    add_extra_accounts_for_execute_cpi(
+        &mut instruction,
+        &mut account_infos,
+        transfer_hook_program_id,
+        from,
+        mint,
+        to,
+        authority,
+        amount,
+        transfer_hook_accounts,
+    )?;
+    invoke_signed(&instruction, &account_infos, signer_seeds)?;
+    Ok(())
+}